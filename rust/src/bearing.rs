@@ -0,0 +1,120 @@
+// 相对参考点的距离与方位
+// 如 "1,240 tiles west, 310 deep"；搜索结果可按距离排序并只保留最近的 N 个
+
+use serde::{Deserialize, Serialize};
+use crate::search_area::ReferencePoint;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bearing {
+    pub distance: f64, // 直线距离（方块）
+    pub dx: i32,       // 向东为正
+    pub dy: i32,       // 向下为正
+    pub text: String,
+}
+
+impl Bearing {
+    pub fn between(from: (i32, i32), x: f64, y: f64) -> Self {
+        let dx = (x - from.0 as f64).round() as i32;
+        let dy = (y - from.1 as f64).round() as i32;
+        let distance = (x - from.0 as f64).hypot(y - from.1 as f64);
+        Self {
+            distance,
+            dx,
+            dy,
+            text: describe(dx, dy),
+        }
+    }
+}
+
+/// JS 传入的参考点设置，如 { point: "Player", nearest: 10 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceOptions {
+    pub point: ReferencePoint,
+    // 按距离从近到远排序
+    #[serde(default = "default_sort")]
+    pub sort: bool,
+    // 只保留最近的 N 个（隐含排序）
+    #[serde(default)]
+    pub nearest: Option<usize>,
+}
+
+fn default_sort() -> bool {
+    true
+}
+
+// 已解析为方块坐标的参考点
+#[derive(Debug, Clone, Copy)]
+pub struct Reference {
+    pub origin: (i32, i32),
+    pub sort: bool,
+    pub nearest: Option<usize>,
+}
+
+/// 带坐标的搜索结果
+pub trait Located {
+    // 没有世界坐标的结果（如没有重生点时玩家的储物空间）为 None
+    fn location(&self) -> Option<(f64, f64)>;
+    fn set_bearing(&mut self, bearing: Bearing);
+}
+
+impl Reference {
+    /// 按设置排序、截断后再为保留的结果标注方位；没有坐标的结果不标注，排在最后，只保留最近 N 个时去掉
+    pub fn apply<T: Located>(&self, results: &mut Vec<T>) {
+        let (origin_x, origin_y) = (self.origin.0 as f64, self.origin.1 as f64);
+        let mut located = Vec::with_capacity(results.len());
+        let mut unlocated = Vec::new();
+        for result in results.drain(..) {
+            match result.location() {
+                Some((x, y)) => {
+                    let (dx, dy) = (x - origin_x, y - origin_y);
+                    located.push((dx * dx + dy * dy, x, y, result));
+                }
+                None => unlocated.push(result),
+            }
+        }
+        if self.sort || self.nearest.is_some() {
+            located.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        if let Some(nearest) = self.nearest {
+            located.truncate(nearest);
+            unlocated.clear();
+        }
+        results.extend(located.into_iter().map(|(_, x, y, mut result)| {
+            result.set_bearing(Bearing::between(self.origin, x, y));
+            result
+        }));
+        results.extend(unlocated);
+    }
+}
+
+// 东西在前、上下在后，单位 "tiles" 只写在第一段：如 "1,240 tiles west, 310 deep"、"42 tiles up"
+fn describe(dx: i32, dy: i32) -> String {
+    let mut parts = Vec::new();
+    if dx != 0 {
+        parts.push(format!("{} {}", group_digits(dx.unsigned_abs()), if dx > 0 { "east" } else { "west" }));
+    }
+    if dy != 0 {
+        parts.push(format!("{} {}", group_digits(dy.unsigned_abs()), if dy > 0 { "deep" } else { "up" }));
+    }
+    match parts.first_mut() {
+        Some(first) => {
+            let split = first.find(' ').unwrap_or(first.len());
+            first.insert_str(split, " tiles");
+            parts.join(", ")
+        }
+        None => "here".to_string(),
+    }
+}
+
+// 千位分隔：1240 -> "1,240"
+fn group_digits(value: u32) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
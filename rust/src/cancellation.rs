@@ -0,0 +1,46 @@
+// 取消令牌
+// JS 持有令牌并调用 cancel()；wasm 是单线程的，只有分步 API（WorldParser::step、
+// Renderer::prepare_chunks）在两次调用之间检查令牌
+
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+pub const CANCELLED_ERROR_NAME: &str = "CancelledError";
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Rc<Cell<bool>>,
+}
+
+#[wasm_bindgen]
+impl CancellationToken {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[wasm_bindgen]
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+
+    #[wasm_bindgen]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.get()
+    }
+
+    /// 重置令牌以便复用
+    #[wasm_bindgen]
+    pub fn reset(&self) {
+        self.cancelled.set(false);
+    }
+}
+
+/// 取消时返回给 JS 的错误，name 为 "CancelledError"，便于与数据损坏错误区分
+pub fn cancelled_error() -> JsValue {
+    let error = js_sys::Error::new("Operation cancelled");
+    error.set_name(CANCELLED_ERROR_NAME);
+    error.into()
+}
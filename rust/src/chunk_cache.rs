@@ -0,0 +1,104 @@
+// 分块渲染缓存
+// 将世界切分为 256×256 方块的区块，每个区块按 1 像素/方块渲染到离屏画布，平移时只需重新贴图
+
+use std::collections::{HashMap, HashSet};
+use web_sys::HtmlCanvasElement;
+use crate::renderer::WireOverlay;
+
+pub const CHUNK_SIZE: usize = 256;
+
+// 缓存有效性的依据：世界身份、数据版本与图层配置，任一变化都需要重建全部区块
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheKey {
+    pub world_id: i32,
+    pub width: i32,
+    pub height: i32,
+    // WorldHandle 的版本号；JS 传入的 World 没有版本号，使用方块内容的哈希
+    pub generation: u64,
+    pub wire_overlay: WireOverlay,
+}
+
+pub struct ChunkCache {
+    key: Option<CacheKey>,
+    chunks: HashMap<(usize, usize), HtmlCanvasElement>,
+    // 已经烘焙进区块的高亮位置
+    highlights: HashSet<(i32, i32)>,
+}
+
+impl Default for ChunkCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChunkCache {
+    pub fn new() -> Self {
+        Self {
+            key: None,
+            chunks: HashMap::new(),
+            highlights: HashSet::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.highlights.clear();
+    }
+
+    // 世界或图层配置变化时清空缓存
+    pub fn validate(&mut self, key: CacheKey) {
+        if self.key != Some(key) {
+            self.clear();
+            self.key = Some(key);
+        }
+    }
+
+    pub fn get(&self, chunk_x: usize, chunk_y: usize) -> Option<&HtmlCanvasElement> {
+        self.chunks.get(&(chunk_x, chunk_y))
+    }
+
+    pub fn insert(&mut self, chunk_x: usize, chunk_y: usize, canvas: HtmlCanvasElement) {
+        self.chunks.insert((chunk_x, chunk_y), canvas);
+    }
+
+    // 使覆盖给定方块区域的区块失效
+    pub fn invalidate_region(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let start_x = x.max(0) as usize / CHUNK_SIZE;
+        let start_y = y.max(0) as usize / CHUNK_SIZE;
+        let end_x = (x + width - 1).max(0) as usize / CHUNK_SIZE;
+        let end_y = (y + height - 1).max(0) as usize / CHUNK_SIZE;
+
+        self.chunks.retain(|&(cx, cy), _| {
+            cx < start_x || cx > end_x || cy < start_y || cy > end_y
+        });
+    }
+
+    pub fn invalidate_tile(&mut self, x: i32, y: i32) {
+        self.invalidate_region(x, y, 1, 1);
+    }
+
+    // 更新烘焙的高亮集合，只让增减了高亮的区块失效
+    pub fn update_highlights(&mut self, highlights: HashSet<(i32, i32)>) {
+        let changed: Vec<(i32, i32)> = self
+            .highlights
+            .symmetric_difference(&highlights)
+            .cloned()
+            .collect();
+        for (x, y) in changed {
+            self.invalidate_tile(x, y);
+        }
+        self.highlights = highlights;
+    }
+}
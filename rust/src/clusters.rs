@@ -0,0 +1,144 @@
+// 搜索结果分组
+// 相连的同种方块合并为一簇（矿脉），多格物体按帧坐标归并到左上角锚点
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use serde::{Deserialize, Serialize};
+use crate::bearing::{Bearing, Located};
+use crate::name_registry::NameRegistry;
+use crate::search::TilePosition;
+use crate::world_loader::World;
+
+// 相邻关系：上下左右，或再加上四个对角
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Neighborhood {
+    Four,
+    #[default]
+    Eight,
+}
+
+impl Neighborhood {
+    fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Neighborhood::Four => &[(1, 0), (-1, 0), (0, 1), (0, -1)],
+            Neighborhood::Eight => &[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TileCluster {
+    pub tile_id: i32,
+    // 包围盒；多格物体为物体占据的范围，x/y 即锚点
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub centroid_x: f64,
+    pub centroid_y: f64,
+    pub count: usize, // 簇内命中的方块数
+    pub object: bool, // 是否为多格物体
+    #[serde(default)]
+    pub bearing: Option<Bearing>,
+}
+
+impl Located for TileCluster {
+    fn location(&self) -> Option<(f64, f64)> {
+        Some((self.centroid_x, self.centroid_y))
+    }
+
+    fn set_bearing(&mut self, bearing: Bearing) {
+        self.bearing = Some(bearing);
+    }
+}
+
+impl TileCluster {
+    fn from_tiles(tile_id: i32, tiles: &[(i32, i32)]) -> Self {
+        let min_x = tiles.iter().map(|p| p.0).min().unwrap_or(0);
+        let min_y = tiles.iter().map(|p| p.1).min().unwrap_or(0);
+        let max_x = tiles.iter().map(|p| p.0).max().unwrap_or(0);
+        let max_y = tiles.iter().map(|p| p.1).max().unwrap_or(0);
+        let count = tiles.len().max(1) as f64;
+        Self {
+            tile_id,
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x + 1,
+            height: max_y - min_y + 1,
+            centroid_x: tiles.iter().map(|p| p.0 as f64).sum::<f64>() / count,
+            centroid_y: tiles.iter().map(|p| p.1 as f64).sum::<f64>() / count,
+            count: tiles.len(),
+            object: false,
+            bearing: None,
+        }
+    }
+}
+
+/// 将命中的方块分组，结果按首个命中方块的顺序排列
+pub fn cluster_tiles(world: &World, positions: &[TilePosition], neighborhood: Neighborhood) -> Vec<TileCluster> {
+    let tiles = &world.tiles;
+    let tile_at = |x: i32, y: i32| tiles.tile_id(y as usize * world.width as usize + x as usize);
+
+    // 多格物体按 (方块 id, 锚点) 归并，其余方块留待连通分组
+    let mut objects: HashMap<(i32, i32, i32), usize> = HashMap::new();
+    let mut loose: HashSet<(i32, i32)> = HashSet::new();
+    // (首次出现的顺序, 簇)
+    let mut clusters: Vec<(usize, TileCluster)> = Vec::new();
+
+    for (order, position) in positions.iter().enumerate() {
+        let (x, y) = (position.x, position.y);
+        let idx = y as usize * world.width as usize + x as usize;
+        let tile_id = tiles.tile_id(idx);
+        let Some(size) = NameRegistry::tile_size(tile_id) else {
+            loose.insert((x, y));
+            continue;
+        };
+
+        let [u, v, ..] = tiles.frame(idx);
+        let (dx, dy) = size.anchor_offset(u as i32, v as i32);
+        let (anchor_x, anchor_y) = (x - dx, y - dy);
+        match objects.get(&(tile_id, anchor_x, anchor_y)) {
+            Some(&pos) => {
+                let cluster = &mut clusters[pos].1;
+                cluster.count += 1;
+                let count = cluster.count as f64;
+                cluster.centroid_x += (x as f64 - cluster.centroid_x) / count;
+                cluster.centroid_y += (y as f64 - cluster.centroid_y) / count;
+            }
+            None => {
+                objects.insert((tile_id, anchor_x, anchor_y), clusters.len());
+                let mut cluster = TileCluster::from_tiles(tile_id, &[(x, y)]);
+                cluster.x = anchor_x;
+                cluster.y = anchor_y;
+                cluster.width = size.width;
+                cluster.height = size.height;
+                cluster.object = true;
+                clusters.push((order, cluster));
+            }
+        }
+    }
+
+    // 同种方块的连通分量
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    for (order, position) in positions.iter().enumerate() {
+        let start = (position.x, position.y);
+        if !loose.contains(&start) || !visited.insert(start) {
+            continue;
+        }
+        let tile_id = tile_at(start.0, start.1);
+        let mut members = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some((x, y)) = queue.pop_front() {
+            members.push((x, y));
+            for &(dx, dy) in neighborhood.offsets() {
+                let next = (x + dx, y + dy);
+                if loose.contains(&next) && tile_at(next.0, next.1) == tile_id && visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        clusters.push((order, TileCluster::from_tiles(tile_id, &members)));
+    }
+
+    clusters.sort_by_key(|(order, _)| *order);
+    clusters.into_iter().map(|(_, cluster)| cluster).collect()
+}
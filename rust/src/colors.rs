@@ -18,6 +18,54 @@ impl Rgb {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn to_css_string(&self) -> String {
+        format!("rgba({}, {}, {}, {:.2})", self.r, self.g, self.b, self.a as f64 / 255.0)
+    }
+
+    pub fn to_array(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    // 以 source-over 方式叠加到目标像素上
+    pub fn blend_over(&self, dst: [u8; 4]) -> [u8; 4] {
+        let sa = self.a as f32 / 255.0;
+        let da = dst[3] as f32 / 255.0;
+        let out_a = sa + da * (1.0 - sa);
+        if out_a <= 0.0 {
+            return [0, 0, 0, 0];
+        }
+
+        let mix = |s: u8, d: u8| -> u8 {
+            ((s as f32 * sa + d as f32 * da * (1.0 - sa)) / out_a).round() as u8
+        };
+        [
+            mix(self.r, dst[0]),
+            mix(self.g, dst[1]),
+            mix(self.b, dst[2]),
+            (out_a * 255.0).round() as u8,
+        ]
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(color: Rgb) -> Self {
+        Self::new(color.r, color.g, color.b, 255)
+    }
+}
+
 pub struct TileColors;
 
 impl TileColors {
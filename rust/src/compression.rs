@@ -0,0 +1,212 @@
+// 压缩数据处理
+// gzip 与 zip 容器的解析，解压使用 miniz_oxide 的 Deflate 实现
+
+use miniz_oxide::inflate::{decompress_to_vec_with_limit, TINFLStatus};
+use crate::world_loader::WorldLoadError;
+
+// 解压后的最大长度，防止压缩炸弹耗尽 wasm 内存；最大的原版世界远小于该值
+pub const MAX_DECOMPRESSED_SIZE: usize = 512 * 1024 * 1024;
+
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+const GZIP_DEFLATE: u8 = 8;
+
+// gzip 头部标志位
+const FLAG_HCRC: u8 = 1 << 1;
+const FLAG_EXTRA: u8 = 1 << 2;
+const FLAG_NAME: u8 = 1 << 3;
+const FLAG_COMMENT: u8 = 1 << 4;
+
+pub fn is_gzip(data: &[u8]) -> bool {
+    data.len() >= 2 && data[..2] == GZIP_MAGIC
+}
+
+/// 解压 gzip 数据（单个成员）
+pub fn gunzip(data: &[u8]) -> Result<Vec<u8>, String> {
+    let invalid = |message: &str| -> String {
+        WorldLoadError::InvalidFormat {
+            expected: "gzip data".to_string(),
+            found: message.to_string(),
+        }.into()
+    };
+
+    if !is_gzip(data) || data.len() < 18 {
+        return Err(invalid("missing gzip header"));
+    }
+    if data[2] != GZIP_DEFLATE {
+        return Err(invalid(&format!("compression method {}", data[2])));
+    }
+
+    // 跳过固定头部与可选字段
+    let flags = data[3];
+    let mut pos = 10;
+    if flags & FLAG_EXTRA != 0 {
+        let len = data.get(pos..pos + 2).ok_or_else(|| invalid("truncated header"))?;
+        pos += 2 + u16::from_le_bytes([len[0], len[1]]) as usize;
+    }
+    for flag in [FLAG_NAME, FLAG_COMMENT] {
+        if flags & flag != 0 {
+            let end = data
+                .get(pos..)
+                .and_then(|rest| rest.iter().position(|&b| b == 0))
+                .ok_or_else(|| invalid("truncated header"))?;
+            pos += end + 1;
+        }
+    }
+    if flags & FLAG_HCRC != 0 {
+        pos += 2;
+    }
+    if pos + 8 > data.len() {
+        return Err(invalid("truncated header"));
+    }
+
+    let output = inflate(&data[pos..]).map_err(|message| -> String {
+        WorldLoadError::CorruptedData {
+            position: pos,
+            message: format!("Failed to decompress gzip data: {}", message),
+        }.into()
+    })?;
+
+    // 尾部记录原始长度（对 2^32 取模）
+    let size = &data[data.len() - 4..];
+    let expected = u32::from_le_bytes([size[0], size[1], size[2], size[3]]);
+    if output.len() as u32 != expected {
+        return Err(WorldLoadError::CorruptedData {
+            position: data.len() - 4,
+            message: format!("Decompressed size {} does not match {}", output.len(), expected),
+        }.into());
+    }
+
+    Ok(output)
+}
+
+pub(crate) const ZIP_LOCAL_HEADER: u32 = 0x0403_4B50;
+pub(crate) const ZIP_CENTRAL_HEADER: u32 = 0x0201_4B50;
+pub(crate) const ZIP_END_OF_DIRECTORY: u32 = 0x0605_4B50;
+// 目录结尾记录长度加上最大注释长度
+const ZIP_MAX_END_SEARCH: usize = 22 + u16::MAX as usize;
+
+const ZIP_STORED: u16 = 0;
+const ZIP_DEFLATED: u16 = 8;
+const ZIP_FLAG_ENCRYPTED: u16 = 1;
+
+pub struct ZipEntry {
+    pub name: String,
+    method: u16,
+    flags: u16,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    local_header_offset: usize,
+}
+
+// 空压缩包只有目录结尾记录
+pub fn is_zip(data: &[u8]) -> bool {
+    matches!(read_u32(data, 0), Some(ZIP_LOCAL_HEADER | ZIP_END_OF_DIRECTORY))
+}
+
+/// 读取 zip 中央目录中的文件列表（不支持 Zip64）
+pub fn zip_entries(data: &[u8]) -> Result<Vec<ZipEntry>, String> {
+    let invalid = |message: &str| -> String {
+        WorldLoadError::InvalidFormat {
+            expected: "zip archive".to_string(),
+            found: message.to_string(),
+        }.into()
+    };
+
+    let search_start = data.len().saturating_sub(ZIP_MAX_END_SEARCH);
+    let end = (search_start..data.len().saturating_sub(21))
+        .rev()
+        .find(|&pos| read_u32(data, pos) == Some(ZIP_END_OF_DIRECTORY))
+        .ok_or_else(|| invalid("missing end of central directory"))?;
+
+    let count = read_u16(data, end + 10).ok_or_else(|| invalid("truncated directory"))? as usize;
+    let mut pos = read_u32(data, end + 16).ok_or_else(|| invalid("truncated directory"))? as usize;
+    if pos == u32::MAX as usize {
+        return Err(invalid("Zip64 archives are not supported"));
+    }
+
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..count {
+        if read_u32(data, pos) != Some(ZIP_CENTRAL_HEADER) {
+            return Err(invalid("corrupted central directory"));
+        }
+        let field = |offset: usize| read_u16(data, pos + offset).ok_or_else(|| invalid("truncated directory"));
+        let size = |offset: usize| read_u32(data, pos + offset).ok_or_else(|| invalid("truncated directory"));
+
+        let name_len = field(28)? as usize;
+        let extra_len = field(30)? as usize;
+        let comment_len = field(32)? as usize;
+        let name = data
+            .get(pos + 46..pos + 46 + name_len)
+            .ok_or_else(|| invalid("truncated directory"))?;
+
+        entries.push(ZipEntry {
+            name: String::from_utf8_lossy(name).into_owned(),
+            method: field(10)?,
+            flags: field(8)?,
+            compressed_size: size(20)? as usize,
+            uncompressed_size: size(24)? as usize,
+            local_header_offset: size(42)? as usize,
+        });
+        pos += 46 + name_len + extra_len + comment_len;
+    }
+
+    Ok(entries)
+}
+
+/// 解压 zip 中的单个文件
+pub fn zip_extract(data: &[u8], entry: &ZipEntry) -> Result<Vec<u8>, String> {
+    let corrupted = |message: &str| -> String {
+        WorldLoadError::CorruptedData {
+            position: entry.local_header_offset,
+            message: format!("{} ({})", message, entry.name),
+        }.into()
+    };
+
+    if entry.flags & ZIP_FLAG_ENCRYPTED != 0 {
+        return Err(corrupted("Encrypted zip entries are not supported"));
+    }
+
+    let pos = entry.local_header_offset;
+    if read_u32(data, pos) != Some(ZIP_LOCAL_HEADER) {
+        return Err(corrupted("Missing local file header"));
+    }
+    let name_len = read_u16(data, pos + 26).ok_or_else(|| corrupted("Truncated local file header"))? as usize;
+    let extra_len = read_u16(data, pos + 28).ok_or_else(|| corrupted("Truncated local file header"))? as usize;
+    if entry.uncompressed_size > MAX_DECOMPRESSED_SIZE {
+        return Err(corrupted(&format!("Entry is larger than {} bytes", MAX_DECOMPRESSED_SIZE)));
+    }
+    // 目录中的偏移与长度不可信，wasm32 上相加可能溢出
+    let compressed = (pos + 30 + name_len + extra_len)
+        .checked_add(entry.compressed_size)
+        .and_then(|end| data.get(pos + 30 + name_len + extra_len..end))
+        .ok_or_else(|| corrupted("Truncated zip entry"))?;
+
+    let output = match entry.method {
+        ZIP_STORED => compressed.to_vec(),
+        ZIP_DEFLATED => inflate(compressed)
+            .map_err(|message| corrupted(&format!("Failed to decompress zip entry: {}", message)))?,
+        method => return Err(corrupted(&format!("Unsupported compression method {}", method))),
+    };
+    if output.len() != entry.uncompressed_size {
+        return Err(corrupted("Decompressed size does not match the directory"));
+    }
+    Ok(output)
+}
+
+// 解压 Deflate 数据，超过 MAX_DECOMPRESSED_SIZE 时报错
+fn inflate(data: &[u8]) -> Result<Vec<u8>, String> {
+    decompress_to_vec_with_limit(data, MAX_DECOMPRESSED_SIZE).map_err(|e| match e.status {
+        TINFLStatus::HasMoreOutput => format!("output exceeds {} bytes", MAX_DECOMPRESSED_SIZE),
+        status => format!("{:?}", status),
+    })
+}
+
+fn read_u16(data: &[u8], pos: usize) -> Option<u16> {
+    let bytes = data.get(pos..pos.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    let bytes = data.get(pos..pos.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
@@ -30,6 +30,20 @@ impl DataStream {
         self.position < self.buffer.len()
     }
 
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    // 查看当前位置之后第 offset 个字节，不移动位置
+    pub fn peek_byte(&self, offset: usize) -> Option<u8> {
+        self.buffer.get(self.position + offset).copied()
+    }
+
+    // 追加数据（流式解析时使用）
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
     pub fn read_byte(&mut self) -> u8 {
         if self.position >= self.buffer.len() {
             panic!("Attempted to read beyond buffer length");
@@ -89,6 +103,24 @@ impl DataStream {
         ])
     }
 
+    // 不会越界的读取，数据不足时返回 None（用于压缩数据等不可信输入）
+    pub fn try_read_byte(&mut self) -> Option<u8> {
+        let value = self.peek_byte(0)?;
+        self.position += 1;
+        Some(value)
+    }
+
+    pub fn try_read_uint16(&mut self) -> Option<u16> {
+        let low = self.peek_byte(0)?;
+        let high = self.peek_byte(1)?;
+        self.position += 2;
+        Some(u16::from_le_bytes([low, high]))
+    }
+
+    pub fn try_read_int16(&mut self) -> Option<i16> {
+        self.try_read_uint16().map(|value| value as i16)
+    }
+
     pub fn read_bool(&mut self) -> bool {
         self.read_byte() != 0
     }
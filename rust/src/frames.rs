@@ -0,0 +1,249 @@
+// 帧变体选择
+// 根据方块的 U/V 帧坐标（对应 settings.js 中的 Frames 定义）计算 MapHelper 颜色变体
+
+use crate::colors::{Rgb, TileColors};
+use crate::recovery::{DAMAGED_COLOR, DAMAGED_TILE_ID};
+use crate::world_loader::World;
+
+// 向下查找地面方块时的最大步数（树干、仙人掌的高度上限）
+const MAX_GROUND_SEARCH: usize = 64;
+
+pub struct TileFrames;
+
+impl TileFrames {
+    /// 仅根据帧坐标计算颜色变体，无法确定时返回 0
+    pub fn get_variant(tile_id: i32, u: i32, v: i32) -> i32 {
+        match tile_id {
+            // 火把：点亮 / 熄灭
+            4 if u < 66 => 1,
+            // 椅子：马桶
+            15 if v / 40 == 1 || v / 40 == 20 => 1,
+            // 箱子：金箱、暗影箱、冰箱、天域箱等
+            21 | 441 => match u / 36 {
+                1 | 2 | 10 | 13 | 15 => 1,
+                3 | 4 => 2,
+                6 => 3,
+                11 | 17 => 4,
+                _ => 0,
+            },
+            // 恶魔祭坛 / 猩红祭坛
+            26 | 695 if u >= 54 => 1,
+            // 向日葵：花盘 / 茎
+            27 if v < 34 => 1,
+            // 罐子：按生物群系分组
+            28 | 653 => Self::pot_variant(v),
+            // 暗影珠 / 猩红之心
+            31 | 696 if u >= 36 => 1,
+            // 草药：太阳花、月光草、闪耀根……
+            82..=84 => (u / 18).clamp(0, 6),
+            // 雕像
+            105 if (1548..=1654).contains(&u) => 1,
+            105 if (1656..=1798).contains(&u) => 2,
+            // 水晶碎块 / 明胶水晶
+            129 if u >= 324 => 1,
+            // 精金熔炉 / 钛金熔炉
+            133 if u >= 52 => 1,
+            // 秘银砧 / 山铜砧
+            134 if u >= 28 => 1,
+            // 陷阱：飞镖机关 / 丛林蜥蜴机关 / 毒液机关
+            137 => match v / 18 {
+                0 => 0,
+                1..=4 => 1,
+                _ => 2,
+            },
+            // 圣诞彩灯：红、绿、蓝
+            149 => (u / 18).clamp(0, 2),
+            // 钟乳石：冰、石、蜂蜜……
+            165 => match u {
+                0..=53 => 0,
+                54..=105 => 1,
+                106..=161 => 2,
+                162..=215 => 3,
+                _ => 1,
+            },
+            // 宝石：紫晶、黄玉、蓝玉、翡翠、红玉、钻石、琥珀
+            178 => (u / 18).clamp(0, 6),
+            // 苔藓植物
+            184 => (u / 22).clamp(0, 10),
+            // 小型 / 大型装饰堆
+            185 => Self::pile_variant(u, v),
+            186 | 187 => Self::large_pile_variant(u),
+            // 染料植物
+            227 => (u / 34).clamp(0, 11),
+            // 奖杯
+            240 => match u / 54 + v / 54 * 36 {
+                0..=11 | 47..=53 => 1,
+                _ => 0,
+            },
+            242 if (22..=24).contains(&(v / 72)) => 1,
+            // 逻辑门灯、逻辑门、逻辑感应器
+            419 => (u / 18).clamp(0, 2),
+            420 => (v / 18).clamp(0, 5),
+            423 => (v / 18).clamp(0, 6),
+            // 宝石锁
+            440 => (u / 54).clamp(0, 6),
+            453 => (u / 36).clamp(0, 2),
+            457 => (u / 36).clamp(0, 4),
+            // 箱子（第二组）：水晶箱、金箱、蜘蛛箱……沙漠箱
+            467 | 468 => (u / 36).clamp(0, 12),
+            487 => (u / 72).clamp(0, 1),
+            493 => (u / 18).clamp(0, 5),
+            // 睡莲、香蒲、海燕麦、绿洲植物：按生物群系
+            518 => (v / 18).clamp(0, 2),
+            519 => (v / 18).clamp(0, 5),
+            529 => (v / 34).clamp(0, 4),
+            530 | 705 => (v / 36).clamp(0, 3),
+            548 if u / 54 >= 7 => 1,
+            560 => (u / 36).clamp(0, 2),
+            572 => (v / 36).clamp(0, 5),
+            591 => (u / 36).clamp(0, 8),
+            // 晶塔
+            597 => (u / 54).clamp(0, 10),
+            _ => 0,
+        }
+    }
+
+    /// 结合周围方块计算颜色变体（树、仙人掌的种类由其生长的地面决定）
+    pub fn get_world_variant(world: &World, x: usize, y: usize) -> i32 {
+        let idx = y * world.width as usize + x;
+        let tile_id = world.tiles.tile_id(idx);
+
+        match tile_id {
+            // 树：蘑菇树
+            5 => match Self::find_ground(world, x, y, 5) {
+                Some(70) => 1,
+                _ => 0,
+            },
+            // 仙人掌：黑檀沙、珍珠沙、猩红沙
+            80 => match Self::find_ground(world, x, y, 80) {
+                Some(112) => 1,
+                Some(116) => 2,
+                Some(234) => 3,
+                _ => 0,
+            },
+            _ => {
+                let [u, v] = world.tiles.frame(idx);
+                Self::get_variant(tile_id, u as i32, v as i32)
+            }
+        }
+    }
+
+    // 沿着同类方块向下查找地面方块
+    fn find_ground(world: &World, x: usize, y: usize, tile_id: i32) -> Option<i32> {
+        let width = world.width as usize;
+        let height = world.height as usize;
+
+        let mut cy = y;
+        for _ in 0..MAX_GROUND_SEARCH {
+            cy += 1;
+            if cy >= height {
+                return None;
+            }
+            let below = cy * width + x;
+            if !world.tiles.is_active(below) {
+                return None;
+            }
+            if world.tiles.tile_id(below) != tile_id {
+                return Some(world.tiles.tile_id(below));
+            }
+        }
+
+        None
+    }
+
+    fn pot_variant(v: i32) -> i32 {
+        if v < 144 {
+            0
+        } else if v < 252 {
+            1
+        } else if v < 360 || (900..1008).contains(&v) {
+            2
+        } else if v < 468 {
+            3
+        } else if v < 576 {
+            4
+        } else if v < 684 {
+            5
+        } else if v < 792 {
+            6
+        } else if v < 898 {
+            8
+        } else if v < 1006 {
+            7
+        } else if v < 1114 {
+            0
+        } else if v < 1222 {
+            3
+        } else {
+            7
+        }
+    }
+
+    fn pile_variant(u: i32, v: i32) -> i32 {
+        if v < 18 {
+            // 1x1 小装饰：石块、土块、骨头、硬币……
+            match u / 18 {
+                0..=5 | 28..=32 => 0,
+                6..=11 | 33..=35 => 1,
+                12..=27 => 2,
+                36..=47 => 3,
+                48..=53 => 4,
+                72 => 1,
+                _ => 0,
+            }
+        } else {
+            // 2x1 小装饰
+            match u / 36 + (v / 18 - 1) * 18 {
+                0..=5 | 19..=24 | 33 | 38..=40 => 0,
+                6..=15 => 2,
+                16..=18 | 31 | 32 => 1,
+                25..=30 => 3,
+                34..=37 => 4,
+                59..=61 => 1,
+                _ => 0,
+            }
+        }
+    }
+
+    fn large_pile_variant(u: i32) -> i32 {
+        match u / 54 {
+            0..=6 => 2,
+            7..=21 => 0,
+            22..=23 => 1,
+            24..=25 => 3,
+            26..=31 => 4,
+            32..=34 => 5,
+            35..=40 => 6,
+            41..=45 => 7,
+            46..=47 => 8,
+            48..=49 => 9,
+            50..=52 => 10,
+            53..=55 => 11,
+            _ => 0,
+        }
+    }
+}
+
+impl TileColors {
+    /// 按帧坐标取颜色，变体不存在时回退到基础颜色
+    pub fn get_frame_color(tile_id: i32, u: i32, v: i32) -> Rgb {
+        if tile_id == DAMAGED_TILE_ID {
+            return DAMAGED_COLOR;
+        }
+        let variant = TileFrames::get_variant(tile_id, u, v);
+        Self::get_color_variant(tile_id, variant).unwrap_or_else(|| Self::get_color(tile_id))
+    }
+
+    /// 取世界中某个位置方块的颜色（考虑帧坐标与地面类型）
+    pub fn get_world_color(world: &World, x: usize, y: usize) -> Rgb {
+        let tile_id = world.tiles.tile_id(y * world.width as usize + x);
+        if tile_id == DAMAGED_TILE_ID {
+            return DAMAGED_COLOR;
+        }
+        if let Some(color) = world.mods.tile_color(tile_id) {
+            return color;
+        }
+        let variant = TileFrames::get_world_variant(world, x, y);
+        Self::get_color_variant(tile_id, variant).unwrap_or_else(|| Self::get_color(tile_id))
+    }
+}
@@ -1,17 +1,68 @@
 use wasm_bindgen::prelude::*;
 
+mod bearing;
+mod cancellation;
+mod chunk_cache;
+mod clusters;
 mod colors;
+mod compression;
 mod data_stream;
+mod frames;
+mod map_file;
+mod player_loader;
+mod query;
+mod mipmap;
+mod name_data;
+mod name_match;
+mod name_registry;
+mod nbt;
+mod recovery;
+mod tile_storage;
+mod twld_loader;
+mod wall_colors;
+mod world_handle;
+mod world_input;
 mod world_loader;
+mod world_parser;
 mod renderer;
 mod search;
+mod search_area;
+mod sections;
+mod set_data;
+mod sets;
+mod spatial_index;
+mod text_search;
+#[cfg(test)]
+mod test_fixtures;
 
-pub use colors::Rgb;
+pub use bearing::{Bearing, Located, Reference, ReferenceOptions};
+pub use cancellation::CancellationToken;
+pub use chunk_cache::ChunkCache;
+pub use clusters::{cluster_tiles, Neighborhood, TileCluster};
+pub use colors::{Rgb, Rgba};
 pub use colors::TileColors;
 pub use data_stream::DataStream;
-pub use world_loader::{World, WorldLoader, Tile, Chest, ChestItem, NPC};
-pub use renderer::Renderer;
-pub use search::Searcher;
+pub use frames::TileFrames;
+pub use map_file::{MapFile, MapFileLoader};
+pub use player_loader::{Player, PlayerLoader, SpawnPoint};
+pub use query::{Query, QueryError, QueryResult};
+pub use mipmap::{MipLevel, MipmapPyramid, ReductionMode};
+pub use name_match::{MatchQuality, NameMatch};
+pub use name_registry::{FrameEntry, NameEntry, NameKind, NameRegistry, TileSize};
+pub use recovery::{DamagedArea, ParseWarning, DAMAGED_TILE_ID};
+pub use tile_storage::{MemoryUsage, TileStorage};
+pub use twld_loader::{ModData, ModEntry, TwldLoader};
+pub use wall_colors::WallColors;
+pub use world_handle::{WorldHandle, WorldMetadata};
+pub use world_input::{unpack_world_data, WorldInput};
+pub use world_loader::{World, WorldLoader, WorldSummary, Tile, Chest, ChestItem, NPC, Sign, TileEntity};
+pub use world_parser::{ParseProgress, ParseSection, WorldParser};
+pub use renderer::{Renderer, TileShape, WireOverlay};
+pub use search::{Searcher, TilePosition};
+pub use search_area::{AreaFilter, Landmark, ReferencePoint, SearchArea};
+pub use sets::{SetEntry, TileSet};
+pub use spatial_index::SpatialIndex;
+pub use text_search::{TextMatch, TextMatcher, TextSearchOptions, TextSource};
 
 #[cfg(feature = "console_error_panic_hook")]
 pub use console_error_panic_hook::set_once;
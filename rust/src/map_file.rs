@@ -0,0 +1,520 @@
+// 小地图文件加载器
+// 解析玩家的 .map 文件（每个世界一份），记录玩家已探索的区域与光照
+
+use wasm_bindgen::prelude::*;
+use miniz_oxide::inflate::decompress_to_vec;
+use crate::colors::{Rgb, TileColors};
+use crate::data_stream::DataStream;
+use crate::wall_colors::WallColors;
+use crate::world_loader::{World, WorldLoadError, WorldLoader};
+use crate::world_parser::WorldParser;
+
+// "relogic" 魔数与 .map 文件类型
+const MAGIC: u64 = 0x0063_6967_6F6C_6572;
+const MAP_FILE_TYPE: u64 = 1;
+
+// 更早的版本使用旧格式，不再支持
+const MIN_MAP_VERSION: i32 = 93;
+// 从该版本开始文件头带有魔数、修订号与收藏标记
+const METADATA_VERSION: i32 = 135;
+
+// 方块记录中的分组
+const GROUP_EMPTY: u8 = 0;
+const GROUP_TILE: u8 = 1;
+const GROUP_WALL: u8 = 2;
+const GROUP_WATER: u8 = 3;
+const GROUP_HONEY: u8 = 5;
+const GROUP_SKY: u8 = 6;
+const GROUP_BACKGROUND: u8 = 7;
+
+// 存储中的类型，低 3 位；高位为方块或墙体的变体
+const KIND_UNEXPLORED: u8 = 0;
+const KIND_TILE: u8 = 1;
+const KIND_WALL: u8 = 2;
+const KIND_LIQUID: u8 = 3;
+// 天空或地狱背景，按深度区分
+const KIND_SKY: u8 = 4;
+// 泥土或岩石背景，按深度区分
+const KIND_BACKGROUND: u8 = 5;
+const KIND_MASK: u8 = 0x07;
+
+// 液体：水、岩浆、蜂蜜、微光
+const LIQUID_COLORS: [Rgb; 4] = [
+    Rgb::new(9, 61, 191),
+    Rgb::new(253, 32, 3),
+    Rgb::new(254, 194, 20),
+    Rgb::new(161, 127, 255),
+];
+
+// 背景渐变的起止颜色，与 MapHelper.js 一致
+const SKY_TOP: Rgb = Rgb::new(50, 40, 255);
+const SKY_BOTTOM: Rgb = Rgb::new(145, 185, 255);
+const DIRT_START: Rgb = Rgb::new(88, 61, 46);
+const DIRT_END: Rgb = Rgb::new(37, 78, 123);
+const ROCK_START: Rgb = Rgb::new(74, 67, 60);
+const ROCK_END: Rgb = Rgb::new(53, 70, 97);
+const HELL_COLOR: Rgb = Rgb::new(50, 44, 38);
+
+// 没有颜色定义的墙体
+const DEFAULT_WALL_COLOR: Rgb = Rgb::new(52, 52, 52);
+
+// 未设置地层时按世界高度估算
+const ESTIMATED_SURFACE_RATIO: f64 = 0.3;
+const ESTIMATED_ROCK_RATIO: f64 = 0.4;
+
+#[wasm_bindgen]
+pub struct MapFile {
+    name: String,
+    world_id: i32,
+    width: i32,
+    height: i32,
+    version: i32,
+    surface_level: f64,
+    rock_level: f64,
+    // 方块、墙体 ID，液体编号或背景渐变序号
+    types: Vec<u16>,
+    // 低 3 位为类型，其余为变体
+    info: Vec<u8>,
+    // 0 表示未探索
+    lights: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl MapFile {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn world_id(&self) -> i32 {
+        self.world_id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// 设置地表与岩石层高度，用于背景颜色（.map 与本格式的世界头都不含这两个值）
+    #[wasm_bindgen]
+    pub fn set_layers(&mut self, surface_level: f64, rock_level: f64) {
+        self.surface_level = surface_level;
+        self.rock_level = rock_level;
+    }
+
+    #[wasm_bindgen]
+    pub fn is_explored(&self, x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return false;
+        }
+        self.info[y as usize * self.width as usize + x as usize] & KIND_MASK != KIND_UNEXPLORED
+    }
+
+    #[wasm_bindgen]
+    pub fn explored_count(&self) -> usize {
+        self.info.iter().filter(|&&info| info & KIND_MASK != KIND_UNEXPLORED).count()
+    }
+}
+
+impl MapFile {
+    fn new(name: String, world_id: i32, width: i32, height: i32, version: i32) -> Self {
+        let count = width as usize * height as usize;
+        Self {
+            name,
+            world_id,
+            width,
+            height,
+            version,
+            surface_level: height as f64 * ESTIMATED_SURFACE_RATIO,
+            rock_level: height as f64 * ESTIMATED_ROCK_RATIO,
+            types: vec![0; count],
+            info: vec![0; count],
+            lights: vec![0; count],
+        }
+    }
+
+    /// 是否与世界对应（名称、ID 与尺寸都一致）
+    pub fn matches(&self, world: &World) -> bool {
+        self.name == world.name && self.world_id == world.world_id && self.width == world.width && self.height == world.height
+    }
+
+    /// 已探索区域的颜色（按光照变暗），未探索的位置为 None
+    pub fn color_at(&self, x: usize, y: usize) -> Option<Rgb> {
+        let idx = y * self.width as usize + x;
+        let info = *self.info.get(idx)?;
+        let ty = self.types[idx];
+        let option = (info >> 3) as i32;
+
+        let color = match info & KIND_MASK {
+            KIND_TILE => TileColors::get_color_variant(ty as i32, option)
+                .unwrap_or_else(|| TileColors::get_color(ty as i32)),
+            KIND_WALL => WallColors::get_color(ty as i32).unwrap_or(DEFAULT_WALL_COLOR),
+            KIND_LIQUID => LIQUID_COLORS[(ty as usize).min(LIQUID_COLORS.len() - 1)],
+            KIND_SKY if (y as f64) < self.surface_level => {
+                Self::gradient(SKY_TOP, SKY_BOTTOM, y as f64 / self.surface_level.max(1.0))
+            }
+            KIND_SKY => HELL_COLOR,
+            KIND_BACKGROUND if (y as f64) < self.rock_level => {
+                Self::gradient(DIRT_START, DIRT_END, ty as f64 / 255.0)
+            }
+            KIND_BACKGROUND => Self::gradient(ROCK_START, ROCK_END, ty as f64 / 255.0),
+            _ => return None,
+        };
+
+        let light = self.lights[idx] as u16;
+        Some(Rgb::new(
+            (color.r as u16 * light / 255) as u8,
+            (color.g as u16 * light / 255) as u8,
+            (color.b as u16 * light / 255) as u8,
+        ))
+    }
+
+    /// 只包含已探索区域的 RGBA 图像（未探索处透明），用于无剧透分享
+    pub fn explored_pixels(&self) -> Vec<u8> {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let mut pixels = vec![0u8; width * height * 4];
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(color) = self.color_at(x, y) {
+                    let offset = (y * width + x) * 4;
+                    pixels[offset..offset + 4].copy_from_slice(&[color.r, color.g, color.b, 255]);
+                }
+            }
+        }
+        pixels
+    }
+
+    /// 战争迷雾遮罩：未探索处为不透明黑色，已探索处透明
+    pub fn fog_pixels(&self) -> Vec<u8> {
+        let mut pixels = vec![0u8; self.info.len() * 4];
+        for (idx, info) in self.info.iter().enumerate() {
+            if info & KIND_MASK == KIND_UNEXPLORED {
+                pixels[idx * 4 + 3] = 255;
+            }
+        }
+        pixels
+    }
+
+    fn set(&mut self, x: usize, y: usize, kind: u8, option: u8, ty: u16, light: u8) {
+        if x >= self.width as usize {
+            return;
+        }
+        let idx = y * self.width as usize + x;
+        self.types[idx] = ty;
+        self.info[idx] = kind | (option << 3);
+        self.lights[idx] = light;
+    }
+
+    fn gradient(start: Rgb, end: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 * (1.0 - t) + b as f64 * t) as u8;
+        Rgb::new(mix(start.r, end.r), mix(start.g, end.g), mix(start.b, end.b))
+    }
+}
+
+#[wasm_bindgen]
+pub struct MapFileLoader {}
+
+impl Default for MapFileLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl MapFileLoader {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[wasm_bindgen]
+    pub fn load_from_data(&self, data: &[u8]) -> Result<MapFile, JsValue> {
+        self.parse_map(data)
+            .map_err(|e| JsValue::from_str(&format!("Failed to load map: {}", e)))
+    }
+}
+
+impl MapFileLoader {
+    pub fn parse_map(&self, data: &[u8]) -> Result<MapFile, String> {
+        if data.is_empty() {
+            return Err(WorldLoadError::InvalidData {
+                message: "Map file is empty".to_string(),
+            }.into());
+        }
+
+        let mut stream = DataStream::new(data.to_vec());
+        let truncated = |stream: &DataStream| -> String {
+            WorldLoadError::CorruptedData {
+                position: stream.position(),
+                message: "Map header is truncated".to_string(),
+            }.into()
+        };
+
+        if stream.remaining() < 4 {
+            return Err(truncated(&stream));
+        }
+        let version = stream.read_int32();
+        if version < MIN_MAP_VERSION {
+            return Err(WorldLoadError::UnsupportedVersion { version }.into());
+        }
+
+        if version >= METADATA_VERSION {
+            if stream.remaining() < 8 + 4 + 8 {
+                return Err(truncated(&stream));
+            }
+            let low = stream.read_uint32() as u64;
+            let high = stream.read_uint32() as u64;
+            let metadata = low | (high << 32);
+            if metadata & 0x00FF_FFFF_FFFF_FFFF != MAGIC || metadata >> 56 != MAP_FILE_TYPE {
+                return Err(WorldLoadError::InvalidFormat {
+                    expected: "relogic map file".to_string(),
+                    found: format!("{:#018x}", metadata),
+                }.into());
+            }
+            let _revision = stream.read_uint32();
+            let _favorite = stream.read_int64();
+        }
+
+        match WorldParser::string_len(&stream, 0) {
+            Some(len) if len + 4 * 3 + 2 * 6 <= stream.remaining() => {}
+            _ => return Err(truncated(&stream)),
+        }
+        let name = stream.read_string();
+        let world_id = stream.read_int32();
+        let height = stream.read_int32();
+        let width = stream.read_int32();
+        WorldLoader::validate_dimensions(width, height)?;
+
+        let tile_count = stream.read_int16().max(0) as usize;
+        let wall_count = stream.read_int16().max(0) as usize;
+        let _liquid_count = stream.read_int16();
+        let _sky_count = stream.read_int16();
+        let _dirt_count = stream.read_int16();
+        let _rock_count = stream.read_int16();
+
+        // 每种方块、墙体的变体数：位图标记有多个变体的 ID，随后各用一个字节记录数量
+        let tile_has_options = Self::read_bit_array(&mut stream, tile_count).ok_or_else(|| truncated(&stream))?;
+        let wall_has_options = Self::read_bit_array(&mut stream, wall_count).ok_or_else(|| truncated(&stream))?;
+        let tile_options = Self::read_option_table(&mut stream, &tile_has_options).ok_or_else(|| truncated(&stream))?;
+        let wall_options = Self::read_option_table(&mut stream, &wall_has_options).ok_or_else(|| truncated(&stream))?;
+
+        // 方块数据使用 Deflate 压缩
+        let header_end = stream.position();
+        let body = decompress_to_vec(&data[header_end..]).map_err(|e| -> String {
+            WorldLoadError::CorruptedData {
+                position: header_end,
+                message: format!("Failed to decompress map data: {:?}", e.status),
+            }.into()
+        })?;
+
+        let mut map = MapFile::new(name, world_id, width, height, version);
+        Self::read_tiles(&mut map, DataStream::new(body), &tile_options, &wall_options)?;
+        Ok(map)
+    }
+
+    // 按位读取的布尔数组，每字节 8 位，低位在前
+    fn read_bit_array(stream: &mut DataStream, count: usize) -> Option<Vec<bool>> {
+        let mut values = Vec::with_capacity(count);
+        let mut byte = 0u8;
+        let mut bit = 128u8;
+        for _ in 0..count {
+            if bit == 128 {
+                byte = stream.try_read_byte()?;
+                bit = 1;
+            } else {
+                bit <<= 1;
+            }
+            values.push(byte & bit == bit);
+        }
+        Some(values)
+    }
+
+    // 展开为变体序号 -> (ID, 变体) 的查找表
+    fn read_option_table(stream: &mut DataStream, has_options: &[bool]) -> Option<Vec<(u16, u8)>> {
+        let mut table = Vec::with_capacity(has_options.len());
+        for (id, &has) in has_options.iter().enumerate() {
+            let count = if has { stream.try_read_byte()? } else { 1 };
+            for option in 0..count {
+                table.push((id as u16, option));
+            }
+        }
+        Some(table)
+    }
+
+    // 逐行读取方块记录，相同的相邻方块以游程编码存储
+    fn read_tiles(
+        map: &mut MapFile,
+        mut stream: DataStream,
+        tile_options: &[(u16, u8)],
+        wall_options: &[(u16, u8)],
+    ) -> Result<(), String> {
+        let width = map.width as usize;
+        let height = map.height as usize;
+        for y in 0..height {
+            let mut x = 0;
+            while x < width {
+                let (position, start_x) = (stream.position(), x);
+                let corrupted = move |message: &str| -> String {
+                    WorldLoadError::CorruptedData {
+                        position,
+                        message: format!("{} at tile ({}, {})", message, start_x, y),
+                    }.into()
+                };
+                let record = Self::read_record(&mut stream).ok_or_else(|| corrupted("Unexpected end of map data"))?;
+
+                if record.group == GROUP_EMPTY {
+                    x += record.run + 1;
+                    continue;
+                }
+
+                let (kind, option, ty) = match record.group {
+                    GROUP_TILE => {
+                        let &(id, option) = tile_options
+                            .get(record.ty as usize)
+                            .ok_or_else(|| corrupted("Unknown tile option"))?;
+                        (KIND_TILE, option, id)
+                    }
+                    GROUP_WALL => {
+                        let &(id, option) = wall_options
+                            .get(record.ty as usize)
+                            .ok_or_else(|| corrupted("Unknown wall option"))?;
+                        (KIND_WALL, option, id)
+                    }
+                    GROUP_WATER..=GROUP_HONEY => (KIND_LIQUID, 0, (record.group - GROUP_WATER) as u16),
+                    GROUP_SKY => (KIND_SKY, 0, 0),
+                    GROUP_BACKGROUND => (KIND_BACKGROUND, 0, record.ty),
+                    _ => return Err(corrupted("Unknown map tile group")),
+                };
+
+                // 光照为 255 时整段共用，否则每个重复的方块各自带有光照字节
+                let mut light = record.light;
+                map.set(x, y, kind, option, ty, light);
+                for _ in 0..record.run {
+                    x += 1;
+                    if record.light != 255 {
+                        light = stream.try_read_byte().ok_or_else(|| corrupted("Unexpected end of map data"))?;
+                    }
+                    map.set(x, y, kind, option, ty, light);
+                }
+                x += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_record(stream: &mut DataStream) -> Option<MapRecord> {
+        let header = stream.try_read_byte()?;
+        // 扩展字节：油漆颜色等，第二个扩展字节由其最低位标记
+        if header & 0x01 != 0 {
+            let extra = stream.try_read_byte()?;
+            if extra & 0x01 != 0 {
+                stream.try_read_byte()?;
+            }
+        }
+
+        let group = (header & 0x0E) >> 1;
+        let ty = match group {
+            GROUP_TILE | GROUP_WALL | GROUP_BACKGROUND if header & 0x10 != 0 => stream.try_read_uint16()?,
+            GROUP_TILE | GROUP_WALL | GROUP_BACKGROUND => stream.try_read_byte()? as u16,
+            _ => 0,
+        };
+        let light = if header & 0x20 != 0 { stream.try_read_byte()? } else { 255 };
+        let run = match (header & 0xC0) >> 6 {
+            1 => stream.try_read_byte()? as usize,
+            2 => stream.try_read_int16()?.max(0) as usize,
+            _ => 0,
+        };
+
+        Some(MapRecord { group, ty, light, run })
+    }
+}
+
+struct MapRecord {
+    group: u8,
+    ty: u16,
+    light: u8,
+    // 之后重复的方块数量
+    run: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miniz_oxide::deflate::compress_to_vec;
+
+    // 5×2 的小地图：方块 3 有两个变体，其余方块一个
+    fn map_file(body: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend(279i32.to_le_bytes());
+        out.extend((MAGIC | MAP_FILE_TYPE << 56).to_le_bytes());
+        out.extend(1u32.to_le_bytes()); // 修订号
+        out.extend(0i64.to_le_bytes()); // 收藏
+        out.push(4);
+        out.extend(b"Test");
+        out.extend(7i32.to_le_bytes());
+        out.extend(2i32.to_le_bytes()); // 高
+        out.extend(5i32.to_le_bytes()); // 宽
+        for count in [10i16, 0, 0, 0, 0, 0] {
+            out.extend(count.to_le_bytes());
+        }
+        out.extend([0b0000_1000, 0b0000_0000]); // 方块有变体的位图
+        out.push(2); // 方块 3 的变体数
+        out.extend(compress_to_vec(body, 6));
+        out
+    }
+
+    fn body() -> Vec<u8> {
+        vec![
+            // 第 0 行：方块 3 的变体 1（序号 4）重复 2 次，之后 2 格未探索
+            0x42, 4, 2,
+            0x40, 1,
+            // 第 1 行：水，光照 100，重复 4 次且各带光照
+            0x66, 100, 4, 90, 80, 70, 60,
+        ]
+    }
+
+    #[test]
+    fn decodes_run_length_records() {
+        let map = MapFileLoader::new().parse_map(&map_file(&body())).unwrap();
+        assert_eq!((map.name(), map.world_id(), map.width(), map.height()), ("Test".to_string(), 7, 5, 2));
+        assert_eq!(map.explored_count(), 8);
+
+        for x in 0..3 {
+            assert_eq!(map.info[x], KIND_TILE | 1 << 3);
+            assert_eq!(map.types[x], 3);
+            assert_eq!(map.lights[x], 255);
+        }
+        assert!(!map.is_explored(3, 0) && !map.is_explored(4, 0));
+        assert!((0..5).all(|x| map.info[5 + x] == KIND_LIQUID));
+        assert_eq!(map.lights[5..], [100, 90, 80, 70, 60]);
+    }
+
+    #[test]
+    fn reports_where_the_data_runs_out() {
+        let mut body = body();
+        body.pop();
+        let error = MapFileLoader::new().parse_map(&map_file(&body)).err().unwrap();
+        assert!(error.contains("Unexpected end of map data at tile (0, 1)"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_other_file_types() {
+        let mut data = map_file(&body());
+        data[11] = 2; // 文件类型在元数据的最高字节
+        let error = MapFileLoader::new().parse_map(&data).err().unwrap();
+        assert!(error.contains("relogic map file"), "{}", error);
+    }
+}
@@ -0,0 +1,253 @@
+// 多分辨率金字塔
+// 加载后一次性生成 2×、4×、8×…… 降采样的 RGBA 图像，缩小视图时直接贴图
+
+use serde::{Deserialize, Serialize};
+use crate::colors::{Rgba, TileColors};
+use crate::world_loader::World;
+
+// 降采样方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReductionMode {
+    // 取 2×2 中出现最多的颜色，保留方块的原色
+    #[default]
+    Majority,
+    // 取 2×2 的平均颜色，过渡更平滑
+    Average,
+}
+
+pub struct MipLevel {
+    // 每个像素覆盖的方块边长
+    pub factor: usize,
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl MipLevel {
+    fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        let offset = (y * self.width + x) * 4;
+        [
+            self.pixels[offset],
+            self.pixels[offset + 1],
+            self.pixels[offset + 2],
+            self.pixels[offset + 3],
+        ]
+    }
+}
+
+// 金字塔本身不记录对应的世界，由使用者（Renderer）判断是否过期
+pub struct MipmapPyramid {
+    pub width: usize,
+    pub height: usize,
+    pub mode: ReductionMode,
+    levels: Vec<MipLevel>,
+}
+
+impl MipmapPyramid {
+    /// 从世界方块生成金字塔，第一层即为 2× 降采样
+    pub fn build(world: &World, mode: ReductionMode) -> Self {
+        Self::build_from(world.width.max(0) as usize, world.height.max(0) as usize, mode, |x, y| {
+            Self::tile_pixel(world, x, y)
+        })
+    }
+
+    // 由逐方块像素生成金字塔
+    fn build_from<F>(width: usize, height: usize, mode: ReductionMode, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> [u8; 4],
+    {
+        let mut levels = Vec::new();
+        if width > 1 || height > 1 {
+            let first = Self::reduce(width, height, 2, mode, &source);
+            levels.push(first);
+
+            while let Some(last) = levels.last() {
+                if last.width <= 1 && last.height <= 1 {
+                    break;
+                }
+                let next = Self::reduce(last.width, last.height, last.factor * 2, mode, |x, y| last.pixel(x, y));
+                levels.push(next);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            mode,
+            levels,
+        }
+    }
+
+    /// 重新计算覆盖方块区域 [x, x + width) × [y, y + height) 的各层像素（编辑世界后调用）
+    pub fn refresh_region(&mut self, world: &World, x: usize, y: usize, width: usize, height: usize) {
+        let source = |x, y| Self::tile_pixel(world, x, y);
+        if width == 0 || height == 0 || x >= self.width || y >= self.height {
+            return;
+        }
+        let mode = self.mode;
+        // 当前层中受影响的像素范围 [x0, x1) × [y0, y1)，以及上一层（源图）的尺寸
+        let (mut x0, mut y0) = (x, y);
+        let (mut x1, mut y1) = ((x + width).min(self.width), (y + height).min(self.height));
+        let (mut source_width, mut source_height) = (self.width, self.height);
+        for i in 0..self.levels.len() {
+            (x0, y0, x1, y1) = (x0 / 2, y0 / 2, x1.div_ceil(2), y1.div_ceil(2));
+            let (lower, upper) = self.levels.split_at_mut(i);
+            let level = &mut upper[0];
+            for py in y0..y1.min(level.height) {
+                for px in x0..x1.min(level.width) {
+                    let pixel = match lower.last() {
+                        None => Self::reduce_pixel(px, py, source_width, source_height, mode, source),
+                        Some(previous) => {
+                            Self::reduce_pixel(px, py, source_width, source_height, mode, |sx, sy| previous.pixel(sx, sy))
+                        }
+                    };
+                    let offset = (py * level.width + px) * 4;
+                    level.pixels[offset..offset + 4].copy_from_slice(&pixel);
+                }
+            }
+            (source_width, source_height) = (level.width, level.height);
+        }
+    }
+
+    pub fn levels(&self) -> &[MipLevel] {
+        &self.levels
+    }
+
+    /// 选择不超过 1/scale 的最大层级，scale >= 1 时返回 None
+    pub fn level_for_scale(&self, scale: f64) -> Option<&MipLevel> {
+        if scale >= 1.0 || scale <= 0.0 {
+            return None;
+        }
+
+        let tiles_per_pixel = 1.0 / scale;
+        self.levels
+            .iter()
+            .take_while(|level| level.factor as f64 <= tiles_per_pixel)
+            .last()
+    }
+
+    pub fn memory_usage(&self) -> usize {
+        self.levels.iter().map(|level| level.pixels.len()).sum()
+    }
+
+    fn tile_pixel(world: &World, x: usize, y: usize) -> [u8; 4] {
+        if world.tiles.is_active(y * world.width as usize + x) {
+            Rgba::from(TileColors::get_world_color(world, x, y)).to_array()
+        } else {
+            [0, 0, 0, 0]
+        }
+    }
+
+    // 将 source_width × source_height 的源图按 2×2 合并为一层
+    fn reduce<F>(
+        source_width: usize,
+        source_height: usize,
+        factor: usize,
+        mode: ReductionMode,
+        source: F,
+    ) -> MipLevel
+    where
+        F: Fn(usize, usize) -> [u8; 4],
+    {
+        let width = source_width.div_ceil(2);
+        let height = source_height.div_ceil(2);
+        let mut pixels = vec![0u8; width * height * 4];
+
+        for y in 0..height {
+            for x in 0..width {
+                let pixel = Self::reduce_pixel(x, y, source_width, source_height, mode, &source);
+                let offset = (y * width + x) * 4;
+                pixels[offset..offset + 4].copy_from_slice(&pixel);
+            }
+        }
+
+        MipLevel {
+            factor,
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // 合并源图中 (2x, 2y) 起的 2×2 像素
+    fn reduce_pixel<F>(x: usize, y: usize, source_width: usize, source_height: usize, mode: ReductionMode, source: F) -> [u8; 4]
+    where
+        F: Fn(usize, usize) -> [u8; 4],
+    {
+        let mut block = Vec::with_capacity(4);
+        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let (sx, sy) = (x * 2 + dx, y * 2 + dy);
+            if sx < source_width && sy < source_height {
+                block.push(source(sx, sy));
+            }
+        }
+
+        match mode {
+            ReductionMode::Majority => Self::majority(&block),
+            ReductionMode::Average => Self::average(&block),
+        }
+    }
+
+    // 出现次数最多的颜色，次数相同时优先不透明的颜色
+    fn majority(block: &[[u8; 4]]) -> [u8; 4] {
+        let mut best = [0, 0, 0, 0];
+        let mut best_count = 0;
+        for candidate in block {
+            let count = block.iter().filter(|pixel| *pixel == candidate).count();
+            if count > best_count || (count == best_count && candidate[3] > best[3]) {
+                best = *candidate;
+                best_count = count;
+            }
+        }
+        best
+    }
+
+    // 按透明度加权的平均颜色
+    fn average(block: &[[u8; 4]]) -> [u8; 4] {
+        let mut sum = [0u32; 3];
+        let mut alpha_sum = 0u32;
+        for pixel in block {
+            let alpha = pixel[3] as u32;
+            for channel in 0..3 {
+                sum[channel] += pixel[channel] as u32 * alpha;
+            }
+            alpha_sum += alpha;
+        }
+
+        if alpha_sum == 0 {
+            return [0, 0, 0, 0];
+        }
+        [
+            (sum[0] / alpha_sum) as u8,
+            (sum[1] / alpha_sum) as u8,
+            (sum[2] / alpha_sum) as u8,
+            (alpha_sum / block.len() as u32) as u8,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{tile, world};
+
+    #[test]
+    fn refreshed_region_matches_a_rebuild() {
+        for mode in [ReductionMode::Majority, ReductionMode::Average] {
+            let tiles: Vec<_> = (0..5 * 3).map(|i| tile(i % 4)).collect();
+            let mut world = world(5, 3, &tiles);
+            let mut pyramid = MipmapPyramid::build(&world, mode);
+            assert_eq!(pyramid.levels().iter().map(|level| level.factor).collect::<Vec<_>>(), [2, 4, 8]);
+
+            // 编辑 (3, 1) 与 (4, 2)
+            world.tiles.set(5 + 3, &tile(9));
+            world.tiles.set(5 * 2 + 4, &tile(9));
+            let rebuilt = MipmapPyramid::build(&world, mode);
+            assert_ne!(pyramid.levels()[0].pixels, rebuilt.levels()[0].pixels);
+            pyramid.refresh_region(&world, 3, 1, 2, 2);
+            for (refreshed, rebuilt) in pyramid.levels().iter().zip(rebuilt.levels()) {
+                assert_eq!(refreshed.pixels, rebuilt.pixels, "factor {}", refreshed.factor);
+            }
+        }
+    }
+}
@@ -0,0 +1,11390 @@
+// 名称表
+// 从 names.js 与 itemKeys/tileKeys/wallKeys.js 迁移（由 scripts/extract_names.cjs 自动生成），按 id 排序
+
+use crate::name_registry::{FrameEntry, NameEntry, TileSize};
+
+pub const ITEM_NAMES: &[NameEntry] = &[
+    NameEntry::new(-48, "PlatinumBowOld", "Platinum Bow Old"),
+    NameEntry::new(-47, "PlatinumHammerOld", "Platinum Hammer Old"),
+    NameEntry::new(-46, "PlatinumAxeOld", "Platinum Axe Old"),
+    NameEntry::new(-45, "PlatinumShortswordOld", "Platinum Shortsword Old"),
+    NameEntry::new(-44, "PlatinumBroadswordOld", "Platinum Broadsword Old"),
+    NameEntry::new(-43, "PlatinumPickaxeOld", "Platinum Pickaxe Old"),
+    NameEntry::new(-42, "TungstenBowOld", "Tungsten Bow Old"),
+    NameEntry::new(-41, "TungstenHammerOld", "Tungsten Hammer Old"),
+    NameEntry::new(-40, "TungstenAxeOld", "Tungsten Axe Old"),
+    NameEntry::new(-39, "TungstenShortswordOld", "Tungsten Shortsword Old"),
+    NameEntry::new(-38, "TungstenBroadswordOld", "Tungsten Broadsword Old"),
+    NameEntry::new(-37, "TungstenPickaxeOld", "Tungsten Pickaxe Old"),
+    NameEntry::new(-36, "LeadBowOld", "Lead Bow Old"),
+    NameEntry::new(-35, "LeadHammerOld", "Lead Hammer Old"),
+    NameEntry::new(-34, "LeadAxeOld", "Lead Axe Old"),
+    NameEntry::new(-33, "LeadShortswordOld", "Lead Shortsword Old"),
+    NameEntry::new(-32, "LeadBroadswordOld", "Lead Broadsword Old"),
+    NameEntry::new(-31, "LeadPickaxeOld", "Lead Pickaxe Old"),
+    NameEntry::new(-30, "TinBowOld", "Tin Bow Old"),
+    NameEntry::new(-29, "TinHammerOld", "Tin Hammer Old"),
+    NameEntry::new(-28, "TinAxeOld", "Tin Axe Old"),
+    NameEntry::new(-27, "TinShortswordOld", "Tin Shortsword Old"),
+    NameEntry::new(-26, "TinBroadswordOld", "Tin Broadsword Old"),
+    NameEntry::new(-25, "TinPickaxeOld", "Tin Pickaxe Old"),
+    NameEntry::new(-24, "YellowPhasesaberOld", "Yellow Phasesaber (Old)"),
+    NameEntry::new(-23, "WhitePhasesaberOld", "White Phasesaber (Old)"),
+    NameEntry::new(-22, "PurplePhasesaberOld", "Purple Phasesaber (Old)"),
+    NameEntry::new(-21, "GreenPhasesaberOld", "Green Phasesaber (Old)"),
+    NameEntry::new(-20, "RedPhasesaberOld", "Red Phasesaber (Old)"),
+    NameEntry::new(-19, "BluePhasesaberOld", "Blue Phasesaber (Old)"),
+    NameEntry::new(-18, "CopperBowOld", "Copper Bow Old"),
+    NameEntry::new(-17, "CopperHammerOld", "Copper Hammer Old"),
+    NameEntry::new(-16, "CopperAxeOld", "Copper Axe Old"),
+    NameEntry::new(-15, "CopperShortswordOld", "Copper Shortsword Old"),
+    NameEntry::new(-14, "CopperBroadswordOld", "Copper Broadsword Old"),
+    NameEntry::new(-13, "CopperPickaxeOld", "Copper Pickaxe Old"),
+    NameEntry::new(-12, "SilverBowOld", "Silver Bow Old"),
+    NameEntry::new(-11, "SilverHammerOld", "Silver Hammer Old"),
+    NameEntry::new(-10, "SilverAxeOld", "Silver Axe Old"),
+    NameEntry::new(-9, "SilverShortswordOld", "Silver Shortsword Old"),
+    NameEntry::new(-8, "SilverBroadswordOld", "Silver Broadsword Old"),
+    NameEntry::new(-7, "SilverPickaxeOld", "Silver Pickaxe Old"),
+    NameEntry::new(-6, "GoldBowOld", "Gold Bow Old"),
+    NameEntry::new(-5, "GoldHammerOld", "Gold Hammer Old"),
+    NameEntry::new(-4, "GoldAxeOld", "Gold Axe Old"),
+    NameEntry::new(-3, "GoldShortswordOld", "Gold Shortsword Old"),
+    NameEntry::new(-2, "GoldBroadswordOld", "Gold Broadsword Old"),
+    NameEntry::new(-1, "GoldPickaxeOld", "Gold Pickaxe Old"),
+    NameEntry::new(0, "None", "[empty]"),
+    NameEntry::new(1, "IronPickaxe", "Iron Pickaxe"),
+    NameEntry::new(2, "DirtBlock", "Dirt Block"),
+    NameEntry::new(3, "StoneBlock", "Stone Block"),
+    NameEntry::new(4, "IronBroadsword", "Iron Broadsword"),
+    NameEntry::new(5, "Mushroom", "Mushroom"),
+    NameEntry::new(6, "IronShortsword", "Iron Shortsword"),
+    NameEntry::new(7, "IronHammer", "Iron Hammer"),
+    NameEntry::new(8, "Torch", "Torch"),
+    NameEntry::new(9, "Wood", "Wood"),
+    NameEntry::new(10, "IronAxe", "Iron Axe"),
+    NameEntry::new(11, "IronOre", "Iron Ore"),
+    NameEntry::new(12, "CopperOre", "Copper Ore"),
+    NameEntry::new(13, "GoldOre", "Gold Ore"),
+    NameEntry::new(14, "SilverOre", "Silver Ore"),
+    NameEntry::new(15, "CopperWatch", "Copper Watch"),
+    NameEntry::new(16, "SilverWatch", "Silver Watch"),
+    NameEntry::new(17, "GoldWatch", "Gold Watch"),
+    NameEntry::new(18, "DepthMeter", "Depth Meter"),
+    NameEntry::new(19, "GoldBar", "Gold Bar"),
+    NameEntry::new(20, "CopperBar", "Copper Bar"),
+    NameEntry::new(21, "SilverBar", "Silver Bar"),
+    NameEntry::new(22, "IronBar", "Iron Bar"),
+    NameEntry::new(23, "Gel", "Gel"),
+    NameEntry::new(24, "WoodenSword", "Wooden Sword"),
+    NameEntry::new(25, "WoodenDoor", "Wooden Door"),
+    NameEntry::new(26, "StoneWall", "Stone Wall"),
+    NameEntry::new(27, "Acorn", "Acorn"),
+    NameEntry::new(28, "LesserHealingPotion", "Lesser Healing Potion"),
+    NameEntry::new(29, "LifeCrystal", "Life Crystal"),
+    NameEntry::new(30, "DirtWall", "Dirt Wall"),
+    NameEntry::new(31, "Bottle", "Bottle"),
+    NameEntry::new(32, "WoodenTable", "Wooden Table"),
+    NameEntry::new(33, "Furnace", "Furnace"),
+    NameEntry::new(34, "WoodenChair", "Wooden Chair"),
+    NameEntry::new(35, "IronAnvil", "Iron Anvil"),
+    NameEntry::new(36, "WorkBench", "Work Bench"),
+    NameEntry::new(37, "Goggles", "Goggles"),
+    NameEntry::new(38, "Lens", "Lens"),
+    NameEntry::new(39, "WoodenBow", "Wooden Bow"),
+    NameEntry::new(40, "WoodenArrow", "Wooden Arrow"),
+    NameEntry::new(41, "FlamingArrow", "Flaming Arrow"),
+    NameEntry::new(42, "Shuriken", "Shuriken"),
+    NameEntry::new(43, "SuspiciousLookingEye", "Suspicious Looking Eye"),
+    NameEntry::new(44, "DemonBow", "Demon Bow"),
+    NameEntry::new(45, "WarAxeoftheNight", "War Axe of the Night"),
+    NameEntry::new(46, "LightsBane", "Light's Bane"),
+    NameEntry::new(47, "UnholyArrow", "Unholy Arrow"),
+    NameEntry::new(48, "Chest", "Chest"),
+    NameEntry::new(49, "BandofRegeneration", "Band of Regeneration"),
+    NameEntry::new(50, "MagicMirror", "Magic Mirror"),
+    NameEntry::new(51, "JestersArrow", "Jester's Arrow"),
+    NameEntry::new(52, "AngelStatue", "Angel Statue"),
+    NameEntry::new(53, "CloudinaBottle", "Cloud in a Bottle"),
+    NameEntry::new(54, "HermesBoots", "Hermes Boots"),
+    NameEntry::new(55, "EnchantedBoomerang", "Enchanted Boomerang"),
+    NameEntry::new(56, "DemoniteOre", "Demonite Ore"),
+    NameEntry::new(57, "DemoniteBar", "Demonite Bar"),
+    NameEntry::new(58, "Heart", "Heart"),
+    NameEntry::new(59, "CorruptSeeds", "Corrupt Seeds"),
+    NameEntry::new(60, "VileMushroom", "Vile Mushroom"),
+    NameEntry::new(61, "EbonstoneBlock", "Ebonstone Block"),
+    NameEntry::new(62, "GrassSeeds", "Grass Seeds"),
+    NameEntry::new(63, "Sunflower", "Sunflower"),
+    NameEntry::new(64, "Vilethorn", "Vilethorn"),
+    NameEntry::new(65, "Starfury", "Starfury"),
+    NameEntry::new(66, "PurificationPowder", "Purification Powder"),
+    NameEntry::new(67, "VilePowder", "Vile Powder"),
+    NameEntry::new(68, "RottenChunk", "Rotten Chunk"),
+    NameEntry::new(69, "WormTooth", "Worm Tooth"),
+    NameEntry::new(70, "WormFood", "Worm Food"),
+    NameEntry::new(71, "CopperCoin", "Copper Coin"),
+    NameEntry::new(72, "SilverCoin", "Silver Coin"),
+    NameEntry::new(73, "GoldCoin", "Gold Coin"),
+    NameEntry::new(74, "PlatinumCoin", "Platinum Coin"),
+    NameEntry::new(75, "FallenStar", "Fallen Star"),
+    NameEntry::new(76, "CopperGreaves", "Copper Greaves"),
+    NameEntry::new(77, "IronGreaves", "Iron Greaves"),
+    NameEntry::new(78, "SilverGreaves", "Silver Greaves"),
+    NameEntry::new(79, "GoldGreaves", "Gold Greaves"),
+    NameEntry::new(80, "CopperChainmail", "Copper Chainmail"),
+    NameEntry::new(81, "IronChainmail", "Iron Chainmail"),
+    NameEntry::new(82, "SilverChainmail", "Silver Chainmail"),
+    NameEntry::new(83, "GoldChainmail", "Gold Chainmail"),
+    NameEntry::new(84, "GrapplingHook", "Grappling Hook"),
+    NameEntry::new(85, "Chain", "Chain"),
+    NameEntry::new(86, "ShadowScale", "Shadow Scale"),
+    NameEntry::new(87, "PiggyBank", "Piggy Bank"),
+    NameEntry::new(88, "MiningHelmet", "Mining Helmet"),
+    NameEntry::new(89, "CopperHelmet", "Copper Helmet"),
+    NameEntry::new(90, "IronHelmet", "Iron Helmet"),
+    NameEntry::new(91, "SilverHelmet", "Silver Helmet"),
+    NameEntry::new(92, "GoldHelmet", "Gold Helmet"),
+    NameEntry::new(93, "WoodWall", "Wood Wall"),
+    NameEntry::new(94, "WoodPlatform", "Wood Platform"),
+    NameEntry::new(95, "FlintlockPistol", "Flintlock Pistol"),
+    NameEntry::new(96, "Musket", "Musket"),
+    NameEntry::new(97, "MusketBall", "Musket Ball"),
+    NameEntry::new(98, "Minishark", "Minishark"),
+    NameEntry::new(99, "IronBow", "Iron Bow"),
+    NameEntry::new(100, "ShadowGreaves", "Shadow Greaves"),
+    NameEntry::new(101, "ShadowScalemail", "Shadow Scalemail"),
+    NameEntry::new(102, "ShadowHelmet", "Shadow Helmet"),
+    NameEntry::new(103, "NightmarePickaxe", "Nightmare Pickaxe"),
+    NameEntry::new(104, "TheBreaker", "The Breaker"),
+    NameEntry::new(105, "Candle", "Candle"),
+    NameEntry::new(106, "CopperChandelier", "Copper Chandelier"),
+    NameEntry::new(107, "SilverChandelier", "Silver Chandelier"),
+    NameEntry::new(108, "GoldChandelier", "Gold Chandelier"),
+    NameEntry::new(109, "ManaCrystal", "Mana Crystal"),
+    NameEntry::new(110, "LesserManaPotion", "Lesser Mana Potion"),
+    NameEntry::new(111, "BandofStarpower", "Band of Starpower"),
+    NameEntry::new(112, "FlowerofFire", "Flower of Fire"),
+    NameEntry::new(113, "MagicMissile", "Magic Missile"),
+    NameEntry::new(114, "DirtRod", "Dirt Rod"),
+    NameEntry::new(115, "ShadowOrb", "Shadow Orb"),
+    NameEntry::new(116, "Meteorite", "Meteorite"),
+    NameEntry::new(117, "MeteoriteBar", "Meteorite Bar"),
+    NameEntry::new(118, "Hook", "Hook"),
+    NameEntry::new(119, "Flamarang", "Flamarang"),
+    NameEntry::new(120, "MoltenFury", "Molten Fury"),
+    NameEntry::new(121, "FieryGreatsword", "Volcano"),
+    NameEntry::new(122, "MoltenPickaxe", "Molten Pickaxe"),
+    NameEntry::new(123, "MeteorHelmet", "Meteor Helmet"),
+    NameEntry::new(124, "MeteorSuit", "Meteor Suit"),
+    NameEntry::new(125, "MeteorLeggings", "Meteor Leggings"),
+    NameEntry::new(126, "BottledWater", "Bottled Water"),
+    NameEntry::new(127, "SpaceGun", "Space Gun"),
+    NameEntry::new(128, "RocketBoots", "Rocket Boots"),
+    NameEntry::new(129, "GrayBrick", "Gray Brick"),
+    NameEntry::new(130, "GrayBrickWall", "Gray Brick Wall"),
+    NameEntry::new(131, "RedBrick", "Red Brick"),
+    NameEntry::new(132, "RedBrickWall", "Red Brick Wall"),
+    NameEntry::new(133, "ClayBlock", "Clay Block"),
+    NameEntry::new(134, "BlueBrick", "Blue Brick"),
+    NameEntry::new(135, "BlueBrickWall", "Blue Brick Wall"),
+    NameEntry::new(136, "ChainLantern", "Chain Lantern"),
+    NameEntry::new(137, "GreenBrick", "Green Brick"),
+    NameEntry::new(138, "GreenBrickWall", "Green Brick Wall"),
+    NameEntry::new(139, "PinkBrick", "Pink Brick"),
+    NameEntry::new(140, "PinkBrickWall", "Pink Brick Wall"),
+    NameEntry::new(141, "GoldBrick", "Gold Brick"),
+    NameEntry::new(142, "GoldBrickWall", "Gold Brick Wall"),
+    NameEntry::new(143, "SilverBrick", "Silver Brick"),
+    NameEntry::new(144, "SilverBrickWall", "Silver Brick Wall"),
+    NameEntry::new(145, "CopperBrick", "Copper Brick"),
+    NameEntry::new(146, "CopperBrickWall", "Copper Brick Wall"),
+    NameEntry::new(147, "Spike", "Spike"),
+    NameEntry::new(148, "WaterCandle", "Water Candle"),
+    NameEntry::new(149, "Book", "Book"),
+    NameEntry::new(150, "Cobweb", "Cobweb"),
+    NameEntry::new(151, "NecroHelmet", "Necro Helmet"),
+    NameEntry::new(152, "NecroBreastplate", "Necro Breastplate"),
+    NameEntry::new(153, "NecroGreaves", "Necro Greaves"),
+    NameEntry::new(154, "Bone", "Bone"),
+    NameEntry::new(155, "Muramasa", "Muramasa"),
+    NameEntry::new(156, "CobaltShield", "Cobalt Shield"),
+    NameEntry::new(157, "AquaScepter", "Aqua Scepter"),
+    NameEntry::new(158, "LuckyHorseshoe", "Lucky Horseshoe"),
+    NameEntry::new(159, "ShinyRedBalloon", "Shiny Red Balloon"),
+    NameEntry::new(160, "Harpoon", "Harpoon"),
+    NameEntry::new(161, "SpikyBall", "Spiky Ball"),
+    NameEntry::new(162, "BallOHurt", "Ball O' Hurt"),
+    NameEntry::new(163, "BlueMoon", "Blue Moon"),
+    NameEntry::new(164, "Handgun", "Handgun"),
+    NameEntry::new(165, "WaterBolt", "Water Bolt"),
+    NameEntry::new(166, "Bomb", "Bomb"),
+    NameEntry::new(167, "Dynamite", "Dynamite"),
+    NameEntry::new(168, "Grenade", "Grenade"),
+    NameEntry::new(169, "SandBlock", "Sand Block"),
+    NameEntry::new(170, "Glass", "Glass"),
+    NameEntry::new(171, "Sign", "Sign"),
+    NameEntry::new(172, "AshBlock", "Ash Block"),
+    NameEntry::new(173, "Obsidian", "Obsidian"),
+    NameEntry::new(174, "Hellstone", "Hellstone"),
+    NameEntry::new(175, "HellstoneBar", "Hellstone Bar"),
+    NameEntry::new(176, "MudBlock", "Mud Block"),
+    NameEntry::new(177, "Sapphire", "Sapphire"),
+    NameEntry::new(178, "Ruby", "Ruby"),
+    NameEntry::new(179, "Emerald", "Emerald"),
+    NameEntry::new(180, "Topaz", "Topaz"),
+    NameEntry::new(181, "Amethyst", "Amethyst"),
+    NameEntry::new(182, "Diamond", "Diamond"),
+    NameEntry::new(183, "GlowingMushroom", "Glowing Mushroom"),
+    NameEntry::new(184, "Star", "Star"),
+    NameEntry::new(185, "IvyWhip", "Ivy Whip"),
+    NameEntry::new(186, "BreathingReed", "Breathing Reed"),
+    NameEntry::new(187, "Flipper", "Flipper"),
+    NameEntry::new(188, "HealingPotion", "Healing Potion"),
+    NameEntry::new(189, "ManaPotion", "Mana Potion"),
+    NameEntry::new(190, "BladeofGrass", "Blade of Grass"),
+    NameEntry::new(191, "ThornChakram", "Thorn Chakram"),
+    NameEntry::new(192, "ObsidianBrick", "Obsidian Brick"),
+    NameEntry::new(193, "ObsidianSkull", "Obsidian Skull"),
+    NameEntry::new(194, "MushroomGrassSeeds", "Mushroom Grass Seeds"),
+    NameEntry::new(195, "JungleGrassSeeds", "Jungle Grass Seeds"),
+    NameEntry::new(196, "WoodenHammer", "Wooden Hammer"),
+    NameEntry::new(197, "StarCannon", "Star Cannon"),
+    NameEntry::new(198, "BluePhaseblade", "Blue Phaseblade"),
+    NameEntry::new(199, "RedPhaseblade", "Red Phaseblade"),
+    NameEntry::new(200, "GreenPhaseblade", "Green Phaseblade"),
+    NameEntry::new(201, "PurplePhaseblade", "Purple Phaseblade"),
+    NameEntry::new(202, "WhitePhaseblade", "White Phaseblade"),
+    NameEntry::new(203, "YellowPhaseblade", "Yellow Phaseblade"),
+    NameEntry::new(204, "MeteorHamaxe", "Meteor Hamaxe"),
+    NameEntry::new(205, "EmptyBucket", "Empty Bucket"),
+    NameEntry::new(206, "WaterBucket", "Water Bucket"),
+    NameEntry::new(207, "LavaBucket", "Lava Bucket"),
+    NameEntry::new(208, "JungleRose", "Jungle Rose"),
+    NameEntry::new(209, "Stinger", "Stinger"),
+    NameEntry::new(210, "Vine", "Vine"),
+    NameEntry::new(211, "FeralClaws", "Feral Claws"),
+    NameEntry::new(212, "AnkletoftheWind", "Anklet of the Wind"),
+    NameEntry::new(213, "StaffofRegrowth", "Staff of Regrowth"),
+    NameEntry::new(214, "HellstoneBrick", "Hellstone Brick"),
+    NameEntry::new(215, "WhoopieCushion", "Whoopie Cushion"),
+    NameEntry::new(216, "Shackle", "Shackle"),
+    NameEntry::new(217, "MoltenHamaxe", "Molten Hamaxe"),
+    NameEntry::new(218, "Flamelash", "Flamelash"),
+    NameEntry::new(219, "PhoenixBlaster", "Phoenix Blaster"),
+    NameEntry::new(220, "Sunfury", "Sunfury"),
+    NameEntry::new(221, "Hellforge", "Hellforge"),
+    NameEntry::new(222, "ClayPot", "Clay Pot"),
+    NameEntry::new(223, "NaturesGift", "Nature's Gift"),
+    NameEntry::new(224, "Bed", "Bed"),
+    NameEntry::new(225, "Silk", "Silk"),
+    NameEntry::new(226, "LesserRestorationPotion", "Lesser Restoration Potion"),
+    NameEntry::new(227, "RestorationPotion", "Restoration Potion"),
+    NameEntry::new(228, "JungleHat", "Jungle Hat"),
+    NameEntry::new(229, "JungleShirt", "Jungle Shirt"),
+    NameEntry::new(230, "JunglePants", "Jungle Pants"),
+    NameEntry::new(231, "MoltenHelmet", "Molten Helmet"),
+    NameEntry::new(232, "MoltenBreastplate", "Molten Breastplate"),
+    NameEntry::new(233, "MoltenGreaves", "Molten Greaves"),
+    NameEntry::new(234, "MeteorShot", "Meteor Shot"),
+    NameEntry::new(235, "StickyBomb", "Sticky Bomb"),
+    NameEntry::new(236, "BlackLens", "Black Lens"),
+    NameEntry::new(237, "Sunglasses", "Sunglasses"),
+    NameEntry::new(238, "WizardHat", "Wizard Hat"),
+    NameEntry::new(239, "TopHat", "Top Hat"),
+    NameEntry::new(240, "TuxedoShirt", "Tuxedo Shirt"),
+    NameEntry::new(241, "TuxedoPants", "Tuxedo Pants"),
+    NameEntry::new(242, "SummerHat", "Summer Hat"),
+    NameEntry::new(243, "BunnyHood", "Bunny Hood"),
+    NameEntry::new(244, "PlumbersHat", "Plumber's Hat"),
+    NameEntry::new(245, "PlumbersShirt", "Plumber's Shirt"),
+    NameEntry::new(246, "PlumbersPants", "Plumber's Pants"),
+    NameEntry::new(247, "HerosHat", "Hero's Hat"),
+    NameEntry::new(248, "HerosShirt", "Hero's Shirt"),
+    NameEntry::new(249, "HerosPants", "Hero's Pants"),
+    NameEntry::new(250, "FishBowl", "Fish Bowl"),
+    NameEntry::new(251, "ArchaeologistsHat", "Archaeologist's Hat"),
+    NameEntry::new(252, "ArchaeologistsJacket", "Archaeologist's Jacket"),
+    NameEntry::new(253, "ArchaeologistsPants", "Archaeologist's Pants"),
+    NameEntry::new(254, "BlackThread", "Black Thread"),
+    NameEntry::new(255, "GreenThread", "Green Thread"),
+    NameEntry::new(256, "NinjaHood", "Ninja Hood"),
+    NameEntry::new(257, "NinjaShirt", "Ninja Shirt"),
+    NameEntry::new(258, "NinjaPants", "Ninja Pants"),
+    NameEntry::new(259, "Leather", "Leather"),
+    NameEntry::new(260, "RedHat", "Red Hat"),
+    NameEntry::new(261, "Goldfish", "Goldfish"),
+    NameEntry::new(262, "Robe", "Robe"),
+    NameEntry::new(263, "RobotHat", "Robot Hat"),
+    NameEntry::new(264, "GoldCrown", "Gold Crown"),
+    NameEntry::new(265, "HellfireArrow", "Hellfire Arrow"),
+    NameEntry::new(266, "Sandgun", "Sandgun"),
+    NameEntry::new(267, "GuideVoodooDoll", "Guide Voodoo Doll"),
+    NameEntry::new(268, "DivingHelmet", "Diving Helmet"),
+    NameEntry::new(269, "FamiliarShirt", "Familiar Shirt"),
+    NameEntry::new(270, "FamiliarPants", "Familiar Pants"),
+    NameEntry::new(271, "FamiliarWig", "Familiar Wig"),
+    NameEntry::new(272, "DemonScythe", "Demon Scythe"),
+    NameEntry::new(273, "NightsEdge", "Night's Edge"),
+    NameEntry::new(274, "DarkLance", "Dark Lance"),
+    NameEntry::new(275, "Coral", "Coral"),
+    NameEntry::new(276, "Cactus", "Cactus"),
+    NameEntry::new(277, "Trident", "Trident"),
+    NameEntry::new(278, "SilverBullet", "Silver Bullet"),
+    NameEntry::new(279, "ThrowingKnife", "Throwing Knife"),
+    NameEntry::new(280, "Spear", "Spear"),
+    NameEntry::new(281, "Blowpipe", "Blowpipe"),
+    NameEntry::new(282, "Glowstick", "Glowstick"),
+    NameEntry::new(283, "Seed", "Seed"),
+    NameEntry::new(284, "WoodenBoomerang", "Wooden Boomerang"),
+    NameEntry::new(285, "Aglet", "Aglet"),
+    NameEntry::new(286, "StickyGlowstick", "Sticky Glowstick"),
+    NameEntry::new(287, "PoisonedKnife", "Poisoned Knife"),
+    NameEntry::new(288, "ObsidianSkinPotion", "Obsidian Skin Potion"),
+    NameEntry::new(289, "RegenerationPotion", "Regeneration Potion"),
+    NameEntry::new(290, "SwiftnessPotion", "Swiftness Potion"),
+    NameEntry::new(291, "GillsPotion", "Gills Potion"),
+    NameEntry::new(292, "IronskinPotion", "Ironskin Potion"),
+    NameEntry::new(293, "ManaRegenerationPotion", "Mana Regeneration Potion"),
+    NameEntry::new(294, "MagicPowerPotion", "Magic Power Potion"),
+    NameEntry::new(295, "FeatherfallPotion", "Featherfall Potion"),
+    NameEntry::new(296, "SpelunkerPotion", "Spelunker Potion"),
+    NameEntry::new(297, "InvisibilityPotion", "Invisibility Potion"),
+    NameEntry::new(298, "ShinePotion", "Shine Potion"),
+    NameEntry::new(299, "NightOwlPotion", "Night Owl Potion"),
+    NameEntry::new(300, "BattlePotion", "Battle Potion"),
+    NameEntry::new(301, "ThornsPotion", "Thorns Potion"),
+    NameEntry::new(302, "WaterWalkingPotion", "Water Walking Potion"),
+    NameEntry::new(303, "ArcheryPotion", "Archery Potion"),
+    NameEntry::new(304, "HunterPotion", "Hunter Potion"),
+    NameEntry::new(305, "GravitationPotion", "Gravitation Potion"),
+    NameEntry::new(306, "GoldChest", "Gold Chest"),
+    NameEntry::new(307, "DaybloomSeeds", "Daybloom Seeds"),
+    NameEntry::new(308, "MoonglowSeeds", "Moonglow Seeds"),
+    NameEntry::new(309, "BlinkrootSeeds", "Blinkroot Seeds"),
+    NameEntry::new(310, "DeathweedSeeds", "Deathweed Seeds"),
+    NameEntry::new(311, "WaterleafSeeds", "Waterleaf Seeds"),
+    NameEntry::new(312, "FireblossomSeeds", "Fireblossom Seeds"),
+    NameEntry::new(313, "Daybloom", "Daybloom"),
+    NameEntry::new(314, "Moonglow", "Moonglow"),
+    NameEntry::new(315, "Blinkroot", "Blinkroot"),
+    NameEntry::new(316, "Deathweed", "Deathweed"),
+    NameEntry::new(317, "Waterleaf", "Waterleaf"),
+    NameEntry::new(318, "Fireblossom", "Fireblossom"),
+    NameEntry::new(319, "SharkFin", "Shark Fin"),
+    NameEntry::new(320, "Feather", "Feather"),
+    NameEntry::new(321, "Tombstone", "Tombstone"),
+    NameEntry::new(322, "MimeMask", "Mime Mask"),
+    NameEntry::new(323, "AntlionMandible", "Antlion Mandible"),
+    NameEntry::new(324, "IllegalGunParts", "Illegal Gun Parts"),
+    NameEntry::new(325, "TheDoctorsShirt", "The Doctor's Shirt"),
+    NameEntry::new(326, "TheDoctorsPants", "The Doctor's Pants"),
+    NameEntry::new(327, "GoldenKey", "Golden Key"),
+    NameEntry::new(328, "ShadowChest", "Shadow Chest"),
+    NameEntry::new(329, "ShadowKey", "Shadow Key"),
+    NameEntry::new(330, "ObsidianBrickWall", "Obsidian Brick Wall"),
+    NameEntry::new(331, "JungleSpores", "Jungle Spores"),
+    NameEntry::new(332, "Loom", "Loom"),
+    NameEntry::new(333, "Piano", "Piano"),
+    NameEntry::new(334, "Dresser", "Dresser"),
+    NameEntry::new(335, "Bench", "Bench"),
+    NameEntry::new(336, "Bathtub", "Bathtub"),
+    NameEntry::new(337, "RedBanner", "Red Banner"),
+    NameEntry::new(338, "GreenBanner", "Green Banner"),
+    NameEntry::new(339, "BlueBanner", "Blue Banner"),
+    NameEntry::new(340, "YellowBanner", "Yellow Banner"),
+    NameEntry::new(341, "LampPost", "Lamp Post"),
+    NameEntry::new(342, "TikiTorch", "Tiki Torch"),
+    NameEntry::new(343, "Barrel", "Barrel"),
+    NameEntry::new(344, "ChineseLantern", "Chinese Lantern"),
+    NameEntry::new(345, "CookingPot", "Cooking Pot"),
+    NameEntry::new(346, "Safe", "Safe"),
+    NameEntry::new(347, "SkullLantern", "Skull Lantern"),
+    NameEntry::new(348, "TrashCan", "Trash Can"),
+    NameEntry::new(349, "Candelabra", "Candelabra"),
+    NameEntry::new(350, "PinkVase", "Pink Vase"),
+    NameEntry::new(351, "Mug", "Mug"),
+    NameEntry::new(352, "Keg", "Keg"),
+    NameEntry::new(353, "Ale", "Ale"),
+    NameEntry::new(354, "Bookcase", "Bookcase"),
+    NameEntry::new(355, "Throne", "Throne"),
+    NameEntry::new(356, "Bowl", "Bowl"),
+    NameEntry::new(357, "BowlofSoup", "Bowl of Soup"),
+    NameEntry::new(358, "Toilet", "Toilet"),
+    NameEntry::new(359, "GrandfatherClock", "Grandfather Clock"),
+    NameEntry::new(360, "ArmorStatue", "Armor Statue"),
+    NameEntry::new(361, "GoblinBattleStandard", "Goblin Battle Standard"),
+    NameEntry::new(362, "TatteredCloth", "Tattered Cloth"),
+    NameEntry::new(363, "Sawmill", "Sawmill"),
+    NameEntry::new(364, "CobaltOre", "Cobalt Ore"),
+    NameEntry::new(365, "MythrilOre", "Mythril Ore"),
+    NameEntry::new(366, "AdamantiteOre", "Adamantite Ore"),
+    NameEntry::new(367, "Pwnhammer", "Pwnhammer"),
+    NameEntry::new(368, "Excalibur", "Excalibur"),
+    NameEntry::new(369, "HallowedSeeds", "Hallowed Seeds"),
+    NameEntry::new(370, "EbonsandBlock", "Ebonsand Block"),
+    NameEntry::new(371, "CobaltHat", "Cobalt Hat"),
+    NameEntry::new(372, "CobaltHelmet", "Cobalt Helmet"),
+    NameEntry::new(373, "CobaltMask", "Cobalt Mask"),
+    NameEntry::new(374, "CobaltBreastplate", "Cobalt Breastplate"),
+    NameEntry::new(375, "CobaltLeggings", "Cobalt Leggings"),
+    NameEntry::new(376, "MythrilHood", "Mythril Hood"),
+    NameEntry::new(377, "MythrilHelmet", "Mythril Helmet"),
+    NameEntry::new(378, "MythrilHat", "Mythril Hat"),
+    NameEntry::new(379, "MythrilChainmail", "Mythril Chainmail"),
+    NameEntry::new(380, "MythrilGreaves", "Mythril Greaves"),
+    NameEntry::new(381, "CobaltBar", "Cobalt Bar"),
+    NameEntry::new(382, "MythrilBar", "Mythril Bar"),
+    NameEntry::new(383, "CobaltChainsaw", "Cobalt Chainsaw"),
+    NameEntry::new(384, "MythrilChainsaw", "Mythril Chainsaw"),
+    NameEntry::new(385, "CobaltDrill", "Cobalt Drill"),
+    NameEntry::new(386, "MythrilDrill", "Mythril Drill"),
+    NameEntry::new(387, "AdamantiteChainsaw", "Adamantite Chainsaw"),
+    NameEntry::new(388, "AdamantiteDrill", "Adamantite Drill"),
+    NameEntry::new(389, "DaoofPow", "Dao of Pow"),
+    NameEntry::new(390, "MythrilHalberd", "Mythril Halberd"),
+    NameEntry::new(391, "AdamantiteBar", "Adamantite Bar"),
+    NameEntry::new(392, "GlassWall", "Glass Wall"),
+    NameEntry::new(393, "Compass", "Compass"),
+    NameEntry::new(394, "DivingGear", "Diving Gear"),
+    NameEntry::new(395, "GPS", "GPS"),
+    NameEntry::new(396, "ObsidianHorseshoe", "Obsidian Horseshoe"),
+    NameEntry::new(397, "ObsidianShield", "Obsidian Shield"),
+    NameEntry::new(398, "TinkerersWorkshop", "Tinkerer's Workshop"),
+    NameEntry::new(399, "CloudinaBalloon", "Cloud in a Balloon"),
+    NameEntry::new(400, "AdamantiteHeadgear", "Adamantite Headgear"),
+    NameEntry::new(401, "AdamantiteHelmet", "Adamantite Helmet"),
+    NameEntry::new(402, "AdamantiteMask", "Adamantite Mask"),
+    NameEntry::new(403, "AdamantiteBreastplate", "Adamantite Breastplate"),
+    NameEntry::new(404, "AdamantiteLeggings", "Adamantite Leggings"),
+    NameEntry::new(405, "SpectreBoots", "Spectre Boots"),
+    NameEntry::new(406, "AdamantiteGlaive", "Adamantite Glaive"),
+    NameEntry::new(407, "Toolbelt", "Toolbelt"),
+    NameEntry::new(408, "PearlsandBlock", "Pearlsand Block"),
+    NameEntry::new(409, "PearlstoneBlock", "Pearlstone Block"),
+    NameEntry::new(410, "MiningShirt", "Mining Shirt"),
+    NameEntry::new(411, "MiningPants", "Mining Pants"),
+    NameEntry::new(412, "PearlstoneBrick", "Pearlstone Brick"),
+    NameEntry::new(413, "IridescentBrick", "Iridescent Brick"),
+    NameEntry::new(414, "MudstoneBlock", "Mudstone Brick"),
+    NameEntry::new(415, "CobaltBrick", "Cobalt Brick"),
+    NameEntry::new(416, "MythrilBrick", "Mythril Brick"),
+    NameEntry::new(417, "PearlstoneBrickWall", "Pearlstone Brick Wall"),
+    NameEntry::new(418, "IridescentBrickWall", "Iridescent Brick Wall"),
+    NameEntry::new(419, "MudstoneBrickWall", "Mudstone Brick Wall"),
+    NameEntry::new(420, "CobaltBrickWall", "Cobalt Brick Wall"),
+    NameEntry::new(421, "MythrilBrickWall", "Mythril Brick Wall"),
+    NameEntry::new(422, "HolyWater", "Holy Water"),
+    NameEntry::new(423, "UnholyWater", "Unholy Water"),
+    NameEntry::new(424, "SiltBlock", "Silt Block"),
+    NameEntry::new(425, "FairyBell", "Fairy Bell"),
+    NameEntry::new(426, "BreakerBlade", "Breaker Blade"),
+    NameEntry::new(427, "BlueTorch", "Blue Torch"),
+    NameEntry::new(428, "RedTorch", "Red Torch"),
+    NameEntry::new(429, "GreenTorch", "Green Torch"),
+    NameEntry::new(430, "PurpleTorch", "Purple Torch"),
+    NameEntry::new(431, "WhiteTorch", "White Torch"),
+    NameEntry::new(432, "YellowTorch", "Yellow Torch"),
+    NameEntry::new(433, "DemonTorch", "Demon Torch"),
+    NameEntry::new(434, "ClockworkAssaultRifle", "Clockwork Assault Rifle"),
+    NameEntry::new(435, "CobaltRepeater", "Cobalt Repeater"),
+    NameEntry::new(436, "MythrilRepeater", "Mythril Repeater"),
+    NameEntry::new(437, "DualHook", "Dual Hook"),
+    NameEntry::new(438, "StarStatue", "Star Statue"),
+    NameEntry::new(439, "SwordStatue", "Sword Statue"),
+    NameEntry::new(440, "SlimeStatue", "Slime Statue"),
+    NameEntry::new(441, "GoblinStatue", "Goblin Statue"),
+    NameEntry::new(442, "ShieldStatue", "Shield Statue"),
+    NameEntry::new(443, "BatStatue", "Bat Statue"),
+    NameEntry::new(444, "FishStatue", "Fish Statue"),
+    NameEntry::new(445, "BunnyStatue", "Bunny Statue"),
+    NameEntry::new(446, "SkeletonStatue", "Skeleton Statue"),
+    NameEntry::new(447, "ReaperStatue", "Reaper Statue"),
+    NameEntry::new(448, "WomanStatue", "Woman Statue"),
+    NameEntry::new(449, "ImpStatue", "Imp Statue"),
+    NameEntry::new(450, "GargoyleStatue", "Gargoyle Statue"),
+    NameEntry::new(451, "GloomStatue", "Gloom Statue"),
+    NameEntry::new(452, "HornetStatue", "Hornet Statue"),
+    NameEntry::new(453, "BombStatue", "Bomb Statue"),
+    NameEntry::new(454, "CrabStatue", "Crab Statue"),
+    NameEntry::new(455, "HammerStatue", "Hammer Statue"),
+    NameEntry::new(456, "PotionStatue", "Potion Statue"),
+    NameEntry::new(457, "SpearStatue", "Spear Statue"),
+    NameEntry::new(458, "CrossStatue", "Cross Statue"),
+    NameEntry::new(459, "JellyfishStatue", "Jellyfish Statue"),
+    NameEntry::new(460, "BowStatue", "Bow Statue"),
+    NameEntry::new(461, "BoomerangStatue", "Boomerang Statue"),
+    NameEntry::new(462, "BootStatue", "Boot Statue"),
+    NameEntry::new(463, "ChestStatue", "Chest Statue"),
+    NameEntry::new(464, "BirdStatue", "Bird Statue"),
+    NameEntry::new(465, "AxeStatue", "Axe Statue"),
+    NameEntry::new(466, "CorruptStatue", "Corrupt Statue"),
+    NameEntry::new(467, "TreeStatue", "Tree Statue"),
+    NameEntry::new(468, "AnvilStatue", "Anvil Statue"),
+    NameEntry::new(469, "PickaxeStatue", "Pickaxe Statue"),
+    NameEntry::new(470, "MushroomStatue", "Mushroom Statue"),
+    NameEntry::new(471, "EyeballStatue", "Eyeball Statue"),
+    NameEntry::new(472, "PillarStatue", "Pillar Statue"),
+    NameEntry::new(473, "HeartStatue", "Heart Statue"),
+    NameEntry::new(474, "PotStatue", "Pot Statue"),
+    NameEntry::new(475, "SunflowerStatue", "Sunflower Statue"),
+    NameEntry::new(476, "KingStatue", "King Statue"),
+    NameEntry::new(477, "QueenStatue", "Queen Statue"),
+    NameEntry::new(478, "PiranhaStatue", "Piranha Statue"),
+    NameEntry::new(479, "PlankedWall", "Planked Wall"),
+    NameEntry::new(480, "WoodenBeam", "Wooden Beam"),
+    NameEntry::new(481, "AdamantiteRepeater", "Adamantite Repeater"),
+    NameEntry::new(482, "AdamantiteSword", "Adamantite Sword"),
+    NameEntry::new(483, "CobaltSword", "Cobalt Sword"),
+    NameEntry::new(484, "MythrilSword", "Mythril Sword"),
+    NameEntry::new(485, "MoonCharm", "Moon Charm"),
+    NameEntry::new(486, "Ruler", "Ruler"),
+    NameEntry::new(487, "CrystalBall", "Crystal Ball"),
+    NameEntry::new(488, "DiscoBall", "Disco Ball"),
+    NameEntry::new(489, "SorcererEmblem", "Sorcerer Emblem"),
+    NameEntry::new(490, "WarriorEmblem", "Warrior Emblem"),
+    NameEntry::new(491, "RangerEmblem", "Ranger Emblem"),
+    NameEntry::new(492, "DemonWings", "Demon Wings"),
+    NameEntry::new(493, "AngelWings", "Angel Wings"),
+    NameEntry::new(494, "MagicalHarp", "Magical Harp"),
+    NameEntry::new(495, "RainbowRod", "Rainbow Rod"),
+    NameEntry::new(496, "IceRod", "Ice Rod"),
+    NameEntry::new(497, "NeptunesShell", "Neptune's Shell"),
+    NameEntry::new(498, "Mannequin", "Mannequin"),
+    NameEntry::new(499, "GreaterHealingPotion", "Greater Healing Potion"),
+    NameEntry::new(500, "GreaterManaPotion", "Greater Mana Potion"),
+    NameEntry::new(501, "PixieDust", "Pixie Dust"),
+    NameEntry::new(502, "CrystalShard", "Crystal Shard"),
+    NameEntry::new(503, "ClownHat", "Clown Hat"),
+    NameEntry::new(504, "ClownShirt", "Clown Shirt"),
+    NameEntry::new(505, "ClownPants", "Clown Pants"),
+    NameEntry::new(506, "Flamethrower", "Flamethrower"),
+    NameEntry::new(507, "Bell", "Bell"),
+    NameEntry::new(508, "Harp", "Harp"),
+    NameEntry::new(509, "Wrench", "Red Wrench"),
+    NameEntry::new(510, "WireCutter", "Wire Cutter"),
+    NameEntry::new(511, "ActiveStoneBlock", "Active Stone Block"),
+    NameEntry::new(512, "InactiveStoneBlock", "Inactive Stone Block"),
+    NameEntry::new(513, "Lever", "Lever"),
+    NameEntry::new(514, "LaserRifle", "Laser Rifle"),
+    NameEntry::new(515, "CrystalBullet", "Crystal Bullet"),
+    NameEntry::new(516, "HolyArrow", "Holy Arrow"),
+    NameEntry::new(517, "MagicDagger", "Magic Dagger"),
+    NameEntry::new(518, "CrystalStorm", "Crystal Storm"),
+    NameEntry::new(519, "CursedFlames", "Cursed Flames"),
+    NameEntry::new(520, "SoulofLight", "Soul of Light"),
+    NameEntry::new(521, "SoulofNight", "Soul of Night"),
+    NameEntry::new(522, "CursedFlame", "Cursed Flame"),
+    NameEntry::new(523, "CursedTorch", "Cursed Torch"),
+    NameEntry::new(524, "AdamantiteForge", "Adamantite Forge"),
+    NameEntry::new(525, "MythrilAnvil", "Mythril Anvil"),
+    NameEntry::new(526, "UnicornHorn", "Unicorn Horn"),
+    NameEntry::new(527, "DarkShard", "Dark Shard"),
+    NameEntry::new(528, "LightShard", "Light Shard"),
+    NameEntry::new(529, "RedPressurePlate", "Red Pressure Plate"),
+    NameEntry::new(530, "Wire", "Wire"),
+    NameEntry::new(531, "SpellTome", "Spell Tome"),
+    NameEntry::new(532, "StarCloak", "Star Cloak"),
+    NameEntry::new(533, "Megashark", "Megashark"),
+    NameEntry::new(534, "Shotgun", "Shotgun"),
+    NameEntry::new(535, "PhilosophersStone", "Philosopher's Stone"),
+    NameEntry::new(536, "TitanGlove", "Titan Glove"),
+    NameEntry::new(537, "CobaltNaginata", "Cobalt Naginata"),
+    NameEntry::new(538, "Switch", "Switch"),
+    NameEntry::new(539, "DartTrap", "Dart Trap"),
+    NameEntry::new(540, "Boulder", "Boulder"),
+    NameEntry::new(541, "GreenPressurePlate", "Green Pressure Plate"),
+    NameEntry::new(542, "GrayPressurePlate", "Gray Pressure Plate"),
+    NameEntry::new(543, "BrownPressurePlate", "Brown Pressure Plate"),
+    NameEntry::new(544, "MechanicalEye", "Mechanical Eye"),
+    NameEntry::new(545, "CursedArrow", "Cursed Arrow"),
+    NameEntry::new(546, "CursedBullet", "Cursed Bullet"),
+    NameEntry::new(547, "SoulofFright", "Soul of Fright"),
+    NameEntry::new(548, "SoulofMight", "Soul of Might"),
+    NameEntry::new(549, "SoulofSight", "Soul of Sight"),
+    NameEntry::new(550, "Gungnir", "Gungnir"),
+    NameEntry::new(551, "HallowedPlateMail", "Hallowed Plate Mail"),
+    NameEntry::new(552, "HallowedGreaves", "Hallowed Greaves"),
+    NameEntry::new(553, "HallowedHelmet", "Hallowed Helmet"),
+    NameEntry::new(554, "CrossNecklace", "Cross Necklace"),
+    NameEntry::new(555, "ManaFlower", "Mana Flower"),
+    NameEntry::new(556, "MechanicalWorm", "Mechanical Worm"),
+    NameEntry::new(557, "MechanicalSkull", "Mechanical Skull"),
+    NameEntry::new(558, "HallowedHeadgear", "Hallowed Headgear"),
+    NameEntry::new(559, "HallowedMask", "Hallowed Mask"),
+    NameEntry::new(560, "SlimeCrown", "Slime Crown"),
+    NameEntry::new(561, "LightDisc", "Light Disc"),
+    NameEntry::new(562, "MusicBoxOverworldDay", "Music Box (Overworld Day)"),
+    NameEntry::new(563, "MusicBoxEerie", "Music Box (Eerie)"),
+    NameEntry::new(564, "MusicBoxNight", "Music Box (Night)"),
+    NameEntry::new(565, "MusicBoxTitle", "Music Box (Title)"),
+    NameEntry::new(566, "MusicBoxUnderground", "Music Box (Underground)"),
+    NameEntry::new(567, "MusicBoxBoss1", "Music Box (Boss 1)"),
+    NameEntry::new(568, "MusicBoxJungle", "Music Box (Jungle)"),
+    NameEntry::new(569, "MusicBoxCorruption", "Music Box (Corruption)"),
+    NameEntry::new(570, "MusicBoxUndergroundCorruption", "Music Box (Underground Corruption)"),
+    NameEntry::new(571, "MusicBoxTheHallow", "Music Box (The Hallow)"),
+    NameEntry::new(572, "MusicBoxBoss2", "Music Box (Boss 2)"),
+    NameEntry::new(573, "MusicBoxUndergroundHallow", "Music Box (Underground Hallow)"),
+    NameEntry::new(574, "MusicBoxBoss3", "Music Box (Boss 3)"),
+    NameEntry::new(575, "SoulofFlight", "Soul of Flight"),
+    NameEntry::new(576, "MusicBox", "Music Box"),
+    NameEntry::new(577, "DemoniteBrick", "Demonite Brick"),
+    NameEntry::new(578, "HallowedRepeater", "Hallowed Repeater"),
+    NameEntry::new(579, "Drax", "Drax"),
+    NameEntry::new(580, "Explosives", "Explosives"),
+    NameEntry::new(581, "InletPump", "Inlet Pump"),
+    NameEntry::new(582, "OutletPump", "Outlet Pump"),
+    NameEntry::new(583, "Timer1Second", "1 Second Timer"),
+    NameEntry::new(584, "Timer3Second", "3 Second Timer"),
+    NameEntry::new(585, "Timer5Second", "5 Second Timer"),
+    NameEntry::new(586, "CandyCaneBlock", "Candy Cane Block"),
+    NameEntry::new(587, "CandyCaneWall", "Candy Cane Wall"),
+    NameEntry::new(588, "SantaHat", "Santa Hat"),
+    NameEntry::new(589, "SantaShirt", "Santa Shirt"),
+    NameEntry::new(590, "SantaPants", "Santa Pants"),
+    NameEntry::new(591, "GreenCandyCaneBlock", "Green Candy Cane Block"),
+    NameEntry::new(592, "GreenCandyCaneWall", "Green Candy Cane Wall"),
+    NameEntry::new(593, "SnowBlock", "Snow Block"),
+    NameEntry::new(594, "SnowBrick", "Snow Brick"),
+    NameEntry::new(595, "SnowBrickWall", "Snow Brick Wall"),
+    NameEntry::new(596, "BlueLight", "Blue Light"),
+    NameEntry::new(597, "RedLight", "Red Light"),
+    NameEntry::new(598, "GreenLight", "Green Light"),
+    NameEntry::new(599, "BluePresent", "Blue Present"),
+    NameEntry::new(600, "GreenPresent", "Green Present"),
+    NameEntry::new(601, "YellowPresent", "Yellow Present"),
+    NameEntry::new(602, "SnowGlobe", "Snow Globe"),
+    NameEntry::new(603, "Carrot", "Carrot"),
+    NameEntry::new(604, "AdamantiteBeam", "Adamantite Beam"),
+    NameEntry::new(605, "AdamantiteBeamWall", "Adamantite Beam Wall"),
+    NameEntry::new(606, "DemoniteBrickWall", "Demonite Brick Wall"),
+    NameEntry::new(607, "SandstoneBrick", "Sandstone Brick"),
+    NameEntry::new(608, "SandstoneBrickWall", "Sandstone Brick Wall"),
+    NameEntry::new(609, "EbonstoneBrick", "Ebonstone Brick"),
+    NameEntry::new(610, "EbonstoneBrickWall", "Ebonstone Brick Wall"),
+    NameEntry::new(611, "RedStucco", "Red Stucco"),
+    NameEntry::new(612, "YellowStucco", "Yellow Stucco"),
+    NameEntry::new(613, "GreenStucco", "Green Stucco"),
+    NameEntry::new(614, "GrayStucco", "Gray Stucco"),
+    NameEntry::new(615, "RedStuccoWall", "Red Stucco Wall"),
+    NameEntry::new(616, "YellowStuccoWall", "Yellow Stucco Wall"),
+    NameEntry::new(617, "GreenStuccoWall", "Green Stucco Wall"),
+    NameEntry::new(618, "GrayStuccoWall", "Gray Stucco Wall"),
+    NameEntry::new(619, "Ebonwood", "Ebonwood"),
+    NameEntry::new(620, "RichMahogany", "Rich Mahogany"),
+    NameEntry::new(621, "Pearlwood", "Pearlwood"),
+    NameEntry::new(622, "EbonwoodWall", "Ebonwood Wall"),
+    NameEntry::new(623, "RichMahoganyWall", "Rich Mahogany Wall"),
+    NameEntry::new(624, "PearlwoodWall", "Pearlwood Wall"),
+    NameEntry::new(625, "EbonwoodChest", "Ebonwood Chest"),
+    NameEntry::new(626, "RichMahoganyChest", "Rich Mahogany Chest"),
+    NameEntry::new(627, "PearlwoodChest", "Pearlwood Chest"),
+    NameEntry::new(628, "EbonwoodChair", "Ebonwood Chair"),
+    NameEntry::new(629, "RichMahoganyChair", "Rich Mahogany Chair"),
+    NameEntry::new(630, "PearlwoodChair", "Pearlwood Chair"),
+    NameEntry::new(631, "EbonwoodPlatform", "Ebonwood Platform"),
+    NameEntry::new(632, "RichMahoganyPlatform", "Rich Mahogany Platform"),
+    NameEntry::new(633, "PearlwoodPlatform", "Pearlwood Platform"),
+    NameEntry::new(634, "BonePlatform", "Bone Platform"),
+    NameEntry::new(635, "EbonwoodWorkBench", "Ebonwood Work Bench"),
+    NameEntry::new(636, "RichMahoganyWorkBench", "Rich Mahogany Work Bench"),
+    NameEntry::new(637, "PearlwoodWorkBench", "Pearlwood Work Bench"),
+    NameEntry::new(638, "EbonwoodTable", "Ebonwood Table"),
+    NameEntry::new(639, "RichMahoganyTable", "Rich Mahogany Table"),
+    NameEntry::new(640, "PearlwoodTable", "Pearlwood Table"),
+    NameEntry::new(641, "EbonwoodPiano", "Ebonwood Piano"),
+    NameEntry::new(642, "RichMahoganyPiano", "Rich Mahogany Piano"),
+    NameEntry::new(643, "PearlwoodPiano", "Pearlwood Piano"),
+    NameEntry::new(644, "EbonwoodBed", "Ebonwood Bed"),
+    NameEntry::new(645, "RichMahoganyBed", "Rich Mahogany Bed"),
+    NameEntry::new(646, "PearlwoodBed", "Pearlwood Bed"),
+    NameEntry::new(647, "EbonwoodDresser", "Ebonwood Dresser"),
+    NameEntry::new(648, "RichMahoganyDresser", "Rich Mahogany Dresser"),
+    NameEntry::new(649, "PearlwoodDresser", "Pearlwood Dresser"),
+    NameEntry::new(650, "EbonwoodDoor", "Ebonwood Door"),
+    NameEntry::new(651, "RichMahoganyDoor", "Rich Mahogany Door"),
+    NameEntry::new(652, "PearlwoodDoor", "Pearlwood Door"),
+    NameEntry::new(653, "EbonwoodSword", "Ebonwood Sword"),
+    NameEntry::new(654, "EbonwoodHammer", "Ebonwood Hammer"),
+    NameEntry::new(655, "EbonwoodBow", "Ebonwood Bow"),
+    NameEntry::new(656, "RichMahoganySword", "Rich Mahogany Sword"),
+    NameEntry::new(657, "RichMahoganyHammer", "Rich Mahogany Hammer"),
+    NameEntry::new(658, "RichMahoganyBow", "Rich Mahogany Bow"),
+    NameEntry::new(659, "PearlwoodSword", "Pearlwood Sword"),
+    NameEntry::new(660, "PearlwoodHammer", "Pearlwood Hammer"),
+    NameEntry::new(661, "PearlwoodBow", "Pearlwood Bow"),
+    NameEntry::new(662, "RainbowBrick", "Rainbow Brick"),
+    NameEntry::new(663, "RainbowBrickWall", "Rainbow Brick Wall"),
+    NameEntry::new(664, "IceBlock", "Ice Block"),
+    NameEntry::new(665, "RedsWings", "Red's Wings"),
+    NameEntry::new(666, "RedsHelmet", "Red's Helmet"),
+    NameEntry::new(667, "RedsBreastplate", "Red's Breastplate"),
+    NameEntry::new(668, "RedsLeggings", "Red's Leggings"),
+    NameEntry::new(669, "Fish", "Fish"),
+    NameEntry::new(670, "IceBoomerang", "Ice Boomerang"),
+    NameEntry::new(671, "Keybrand", "Keybrand"),
+    NameEntry::new(672, "Cutlass", "Cutlass"),
+    NameEntry::new(673, "BorealWoodWorkBench", "Boreal Wood Work Bench"),
+    NameEntry::new(674, "TrueExcalibur", "True Excalibur"),
+    NameEntry::new(675, "TrueNightsEdge", "True Night's Edge"),
+    NameEntry::new(676, "Frostbrand", "Frostbrand"),
+    NameEntry::new(677, "BorealWoodTable", "Boreal Wood Table"),
+    NameEntry::new(678, "RedPotion", "Red Potion"),
+    NameEntry::new(679, "TacticalShotgun", "Tactical Shotgun"),
+    NameEntry::new(680, "IvyChest", "Ivy Chest"),
+    NameEntry::new(681, "IceChest", "Frozen Chest"),
+    NameEntry::new(682, "Marrow", "Marrow"),
+    NameEntry::new(683, "UnholyTrident", "Unholy Trident"),
+    NameEntry::new(684, "FrostHelmet", "Frost Helmet"),
+    NameEntry::new(685, "FrostBreastplate", "Frost Breastplate"),
+    NameEntry::new(686, "FrostLeggings", "Frost Leggings"),
+    NameEntry::new(687, "TinHelmet", "Tin Helmet"),
+    NameEntry::new(688, "TinChainmail", "Tin Chainmail"),
+    NameEntry::new(689, "TinGreaves", "Tin Greaves"),
+    NameEntry::new(690, "LeadHelmet", "Lead Helmet"),
+    NameEntry::new(691, "LeadChainmail", "Lead Chainmail"),
+    NameEntry::new(692, "LeadGreaves", "Lead Greaves"),
+    NameEntry::new(693, "TungstenHelmet", "Tungsten Helmet"),
+    NameEntry::new(694, "TungstenChainmail", "Tungsten Chainmail"),
+    NameEntry::new(695, "TungstenGreaves", "Tungsten Greaves"),
+    NameEntry::new(696, "PlatinumHelmet", "Platinum Helmet"),
+    NameEntry::new(697, "PlatinumChainmail", "Platinum Chainmail"),
+    NameEntry::new(698, "PlatinumGreaves", "Platinum Greaves"),
+    NameEntry::new(699, "TinOre", "Tin Ore"),
+    NameEntry::new(700, "LeadOre", "Lead Ore"),
+    NameEntry::new(701, "TungstenOre", "Tungsten Ore"),
+    NameEntry::new(702, "PlatinumOre", "Platinum Ore"),
+    NameEntry::new(703, "TinBar", "Tin Bar"),
+    NameEntry::new(704, "LeadBar", "Lead Bar"),
+    NameEntry::new(705, "TungstenBar", "Tungsten Bar"),
+    NameEntry::new(706, "PlatinumBar", "Platinum Bar"),
+    NameEntry::new(707, "TinWatch", "Tin Watch"),
+    NameEntry::new(708, "TungstenWatch", "Tungsten Watch"),
+    NameEntry::new(709, "PlatinumWatch", "Platinum Watch"),
+    NameEntry::new(710, "TinChandelier", "Tin Chandelier"),
+    NameEntry::new(711, "TungstenChandelier", "Tungsten Chandelier"),
+    NameEntry::new(712, "PlatinumChandelier", "Platinum Chandelier"),
+    NameEntry::new(713, "PlatinumCandle", "Platinum Candle"),
+    NameEntry::new(714, "PlatinumCandelabra", "Platinum Candelabra"),
+    NameEntry::new(715, "PlatinumCrown", "Platinum Crown"),
+    NameEntry::new(716, "LeadAnvil", "Lead Anvil"),
+    NameEntry::new(717, "TinBrick", "Tin Brick"),
+    NameEntry::new(718, "TungstenBrick", "Tungsten Brick"),
+    NameEntry::new(719, "PlatinumBrick", "Platinum Brick"),
+    NameEntry::new(720, "TinBrickWall", "Tin Brick Wall"),
+    NameEntry::new(721, "TungstenBrickWall", "Tungsten Brick Wall"),
+    NameEntry::new(722, "PlatinumBrickWall", "Platinum Brick Wall"),
+    NameEntry::new(723, "BeamSword", "Beam Sword"),
+    NameEntry::new(724, "IceBlade", "Ice Blade"),
+    NameEntry::new(725, "IceBow", "Ice Bow"),
+    NameEntry::new(726, "FrostStaff", "Frost Staff"),
+    NameEntry::new(727, "WoodHelmet", "Wood Helmet"),
+    NameEntry::new(728, "WoodBreastplate", "Wood Breastplate"),
+    NameEntry::new(729, "WoodGreaves", "Wood Greaves"),
+    NameEntry::new(730, "EbonwoodHelmet", "Ebonwood Helmet"),
+    NameEntry::new(731, "EbonwoodBreastplate", "Ebonwood Breastplate"),
+    NameEntry::new(732, "EbonwoodGreaves", "Ebonwood Greaves"),
+    NameEntry::new(733, "RichMahoganyHelmet", "Rich Mahogany Helmet"),
+    NameEntry::new(734, "RichMahoganyBreastplate", "Rich Mahogany Breastplate"),
+    NameEntry::new(735, "RichMahoganyGreaves", "Rich Mahogany Greaves"),
+    NameEntry::new(736, "PearlwoodHelmet", "Pearlwood Helmet"),
+    NameEntry::new(737, "PearlwoodBreastplate", "Pearlwood Breastplate"),
+    NameEntry::new(738, "PearlwoodGreaves", "Pearlwood Greaves"),
+    NameEntry::new(739, "AmethystStaff", "Amethyst Staff"),
+    NameEntry::new(740, "TopazStaff", "Topaz Staff"),
+    NameEntry::new(741, "SapphireStaff", "Sapphire Staff"),
+    NameEntry::new(742, "EmeraldStaff", "Emerald Staff"),
+    NameEntry::new(743, "RubyStaff", "Ruby Staff"),
+    NameEntry::new(744, "DiamondStaff", "Diamond Staff"),
+    NameEntry::new(745, "GrassWall", "Grass Wall"),
+    NameEntry::new(746, "JungleWall", "Jungle Wall"),
+    NameEntry::new(747, "FlowerWall", "Flower Wall"),
+    NameEntry::new(748, "Jetpack", "Jetpack"),
+    NameEntry::new(749, "ButterflyWings", "Butterfly Wings"),
+    NameEntry::new(750, "CactusWall", "Cactus Wall"),
+    NameEntry::new(751, "Cloud", "Cloud"),
+    NameEntry::new(752, "CloudWall", "Cloud Wall"),
+    NameEntry::new(753, "Seaweed", "Seaweed"),
+    NameEntry::new(754, "RuneHat", "Rune Hat"),
+    NameEntry::new(755, "RuneRobe", "Rune Robe"),
+    NameEntry::new(756, "MushroomSpear", "Mushroom Spear"),
+    NameEntry::new(757, "TerraBlade", "Terra Blade"),
+    NameEntry::new(758, "GrenadeLauncher", "Grenade Launcher"),
+    NameEntry::new(759, "RocketLauncher", "Rocket Launcher"),
+    NameEntry::new(760, "ProximityMineLauncher", "Proximity Mine Launcher"),
+    NameEntry::new(761, "FairyWings", "Fairy Wings"),
+    NameEntry::new(762, "SlimeBlock", "Slime Block"),
+    NameEntry::new(763, "FleshBlock", "Flesh Block"),
+    NameEntry::new(764, "MushroomWall", "Mushroom Wall"),
+    NameEntry::new(765, "RainCloud", "Rain Cloud"),
+    NameEntry::new(766, "BoneBlock", "Bone Block"),
+    NameEntry::new(767, "FrozenSlimeBlock", "Frozen Slime Block"),
+    NameEntry::new(768, "BoneBlockWall", "Bone Block Wall"),
+    NameEntry::new(769, "SlimeBlockWall", "Slime Block Wall"),
+    NameEntry::new(770, "FleshBlockWall", "Flesh Block Wall"),
+    NameEntry::new(771, "RocketI", "Rocket I"),
+    NameEntry::new(772, "RocketII", "Rocket II"),
+    NameEntry::new(773, "RocketIII", "Rocket III"),
+    NameEntry::new(774, "RocketIV", "Rocket IV"),
+    NameEntry::new(775, "AsphaltBlock", "Asphalt Block"),
+    NameEntry::new(776, "CobaltPickaxe", "Cobalt Pickaxe"),
+    NameEntry::new(777, "MythrilPickaxe", "Mythril Pickaxe"),
+    NameEntry::new(778, "AdamantitePickaxe", "Adamantite Pickaxe"),
+    NameEntry::new(779, "Clentaminator", "Clentaminator"),
+    NameEntry::new(780, "GreenSolution", "Green Solution"),
+    NameEntry::new(781, "BlueSolution", "Blue Solution"),
+    NameEntry::new(782, "PurpleSolution", "Purple Solution"),
+    NameEntry::new(783, "DarkBlueSolution", "Dark Blue Solution"),
+    NameEntry::new(784, "RedSolution", "Red Solution"),
+    NameEntry::new(785, "HarpyWings", "Harpy Wings"),
+    NameEntry::new(786, "BoneWings", "Bone Wings"),
+    NameEntry::new(787, "Hammush", "Hammush"),
+    NameEntry::new(788, "NettleBurst", "Nettle Burst"),
+    NameEntry::new(789, "AnkhBanner", "Ankh Banner"),
+    NameEntry::new(790, "SnakeBanner", "Snake Banner"),
+    NameEntry::new(791, "OmegaBanner", "Omega Banner"),
+    NameEntry::new(792, "CrimsonHelmet", "Crimson Helmet"),
+    NameEntry::new(793, "CrimsonScalemail", "Crimson Scalemail"),
+    NameEntry::new(794, "CrimsonGreaves", "Crimson Greaves"),
+    NameEntry::new(795, "BloodButcherer", "Blood Butcherer"),
+    NameEntry::new(796, "TendonBow", "Tendon Bow"),
+    NameEntry::new(797, "FleshGrinder", "Flesh Grinder"),
+    NameEntry::new(798, "DeathbringerPickaxe", "Deathbringer Pickaxe"),
+    NameEntry::new(799, "BloodLustCluster", "Blood Lust Cluster"),
+    NameEntry::new(800, "TheUndertaker", "The Undertaker"),
+    NameEntry::new(801, "TheMeatball", "The Meatball"),
+    NameEntry::new(802, "TheRottedFork", "The Rotted Fork"),
+    NameEntry::new(803, "EskimoHood", "Snow Hood"),
+    NameEntry::new(804, "EskimoCoat", "Snow Coat"),
+    NameEntry::new(805, "EskimoPants", "Snow Pants"),
+    NameEntry::new(806, "LivingWoodChair", "Living Wood Chair"),
+    NameEntry::new(807, "CactusChair", "Cactus Chair"),
+    NameEntry::new(808, "BoneChair", "Bone Chair"),
+    NameEntry::new(809, "FleshChair", "Flesh Chair"),
+    NameEntry::new(810, "MushroomChair", "Mushroom Chair"),
+    NameEntry::new(811, "BoneWorkBench", "Bone Work Bench"),
+    NameEntry::new(812, "CactusWorkBench", "Cactus Work Bench"),
+    NameEntry::new(813, "FleshWorkBench", "Flesh Work Bench"),
+    NameEntry::new(814, "MushroomWorkBench", "Mushroom Work Bench"),
+    NameEntry::new(815, "SlimeWorkBench", "Slime Work Bench"),
+    NameEntry::new(816, "CactusDoor", "Cactus Door"),
+    NameEntry::new(817, "FleshDoor", "Flesh Door"),
+    NameEntry::new(818, "MushroomDoor", "Mushroom Door"),
+    NameEntry::new(819, "LivingWoodDoor", "Living Wood Door"),
+    NameEntry::new(820, "BoneDoor", "Bone Door"),
+    NameEntry::new(821, "FlameWings", "Flame Wings"),
+    NameEntry::new(822, "FrozenWings", "Frozen Wings"),
+    NameEntry::new(823, "GhostWings", "Spectre Wings"),
+    NameEntry::new(824, "SunplateBlock", "Sunplate Block"),
+    NameEntry::new(825, "DiscWall", "Disc Wall"),
+    NameEntry::new(826, "SkywareChair", "Skyware Chair"),
+    NameEntry::new(827, "BoneTable", "Bone Table"),
+    NameEntry::new(828, "FleshTable", "Flesh Table"),
+    NameEntry::new(829, "LivingWoodTable", "Living Wood Table"),
+    NameEntry::new(830, "SkywareTable", "Skyware Table"),
+    NameEntry::new(831, "LivingWoodChest", "Living Wood Chest"),
+    NameEntry::new(832, "LivingWoodWand", "Living Wood Wand"),
+    NameEntry::new(833, "PurpleIceBlock", "Purple Ice Block"),
+    NameEntry::new(834, "PinkIceBlock", "Pink Ice Block"),
+    NameEntry::new(835, "RedIceBlock", "Red Ice Block"),
+    NameEntry::new(836, "CrimstoneBlock", "Crimstone Block"),
+    NameEntry::new(837, "SkywareDoor", "Skyware Door"),
+    NameEntry::new(838, "SkywareChest", "Skyware Chest"),
+    NameEntry::new(839, "SteampunkHat", "Steampunk Hat"),
+    NameEntry::new(840, "SteampunkShirt", "Steampunk Shirt"),
+    NameEntry::new(841, "SteampunkPants", "Steampunk Pants"),
+    NameEntry::new(842, "BeeHat", "Bee Hat"),
+    NameEntry::new(843, "BeeShirt", "Bee Shirt"),
+    NameEntry::new(844, "BeePants", "Bee Pants"),
+    NameEntry::new(845, "WorldBanner", "World Banner"),
+    NameEntry::new(846, "SunBanner", "Sun Banner"),
+    NameEntry::new(847, "GravityBanner", "Gravity Banner"),
+    NameEntry::new(848, "PharaohsMask", "Pharaoh's Mask"),
+    NameEntry::new(849, "Actuator", "Actuator"),
+    NameEntry::new(850, "BlueWrench", "Blue Wrench"),
+    NameEntry::new(851, "GreenWrench", "Green Wrench"),
+    NameEntry::new(852, "BluePressurePlate", "Blue Pressure Plate"),
+    NameEntry::new(853, "YellowPressurePlate", "Yellow Pressure Plate"),
+    NameEntry::new(854, "DiscountCard", "Discount Card"),
+    NameEntry::new(855, "LuckyCoin", "Lucky Coin"),
+    NameEntry::new(856, "UnicornonaStick", "Unicorn on a Stick"),
+    NameEntry::new(857, "SandstorminaBottle", "Sandstorm in a Bottle"),
+    NameEntry::new(858, "BorealWoodSofa", "Boreal Wood Sofa"),
+    NameEntry::new(859, "BeachBall", "Beach Ball"),
+    NameEntry::new(860, "CharmofMyths", "Charm of Myths"),
+    NameEntry::new(861, "MoonShell", "Moon Shell"),
+    NameEntry::new(862, "StarVeil", "Star Veil"),
+    NameEntry::new(863, "WaterWalkingBoots", "Water Walking Boots"),
+    NameEntry::new(864, "Tiara", "Tiara"),
+    NameEntry::new(865, "PrincessDress", "Princess Dress"),
+    NameEntry::new(866, "PharaohsRobe", "Pharaoh's Robe"),
+    NameEntry::new(867, "GreenCap", "Green Cap"),
+    NameEntry::new(868, "MushroomCap", "Mushroom Cap"),
+    NameEntry::new(869, "TamOShanter", "Tam O' Shanter"),
+    NameEntry::new(870, "MummyMask", "Mummy Mask"),
+    NameEntry::new(871, "MummyShirt", "Mummy Shirt"),
+    NameEntry::new(872, "MummyPants", "Mummy Pants"),
+    NameEntry::new(873, "CowboyHat", "Cowboy Hat"),
+    NameEntry::new(874, "CowboyJacket", "Cowboy Jacket"),
+    NameEntry::new(875, "CowboyPants", "Cowboy Pants"),
+    NameEntry::new(876, "PirateHat", "Pirate Hat"),
+    NameEntry::new(877, "PirateShirt", "Pirate Shirt"),
+    NameEntry::new(878, "PiratePants", "Pirate Pants"),
+    NameEntry::new(879, "VikingHelmet", "Viking Helmet"),
+    NameEntry::new(880, "CrimtaneOre", "Crimtane Ore"),
+    NameEntry::new(881, "CactusSword", "Cactus Sword"),
+    NameEntry::new(882, "CactusPickaxe", "Cactus Pickaxe"),
+    NameEntry::new(883, "IceBrick", "Ice Brick"),
+    NameEntry::new(884, "IceBrickWall", "Ice Brick Wall"),
+    NameEntry::new(885, "AdhesiveBandage", "Adhesive Bandage"),
+    NameEntry::new(886, "ArmorPolish", "Armor Polish"),
+    NameEntry::new(887, "Bezoar", "Bezoar"),
+    NameEntry::new(888, "Blindfold", "Blindfold"),
+    NameEntry::new(889, "FastClock", "Fast Clock"),
+    NameEntry::new(890, "Megaphone", "Megaphone"),
+    NameEntry::new(891, "Nazar", "Nazar"),
+    NameEntry::new(892, "Vitamins", "Vitamins"),
+    NameEntry::new(893, "TrifoldMap", "Trifold Map"),
+    NameEntry::new(894, "CactusHelmet", "Cactus Helmet"),
+    NameEntry::new(895, "CactusBreastplate", "Cactus Breastplate"),
+    NameEntry::new(896, "CactusLeggings", "Cactus Leggings"),
+    NameEntry::new(897, "PowerGlove", "Power Glove"),
+    NameEntry::new(898, "LightningBoots", "Lightning Boots"),
+    NameEntry::new(899, "SunStone", "Sun Stone"),
+    NameEntry::new(900, "MoonStone", "Moon Stone"),
+    NameEntry::new(901, "ArmorBracing", "Armor Bracing"),
+    NameEntry::new(902, "MedicatedBandage", "Medicated Bandage"),
+    NameEntry::new(903, "ThePlan", "The Plan"),
+    NameEntry::new(904, "CountercurseMantra", "Countercurse Mantra"),
+    NameEntry::new(905, "CoinGun", "Coin Gun"),
+    NameEntry::new(906, "LavaCharm", "Lava Charm"),
+    NameEntry::new(907, "ObsidianWaterWalkingBoots", "Obsidian Water Walking Boots"),
+    NameEntry::new(908, "LavaWaders", "Lava Waders"),
+    NameEntry::new(909, "PureWaterFountain", "Pure Water Fountain"),
+    NameEntry::new(910, "DesertWaterFountain", "Desert Water Fountain"),
+    NameEntry::new(911, "Shadewood", "Shadewood"),
+    NameEntry::new(912, "ShadewoodDoor", "Shadewood Door"),
+    NameEntry::new(913, "ShadewoodPlatform", "Shadewood Platform"),
+    NameEntry::new(914, "ShadewoodChest", "Shadewood Chest"),
+    NameEntry::new(915, "ShadewoodChair", "Shadewood Chair"),
+    NameEntry::new(916, "ShadewoodWorkBench", "Shadewood Work Bench"),
+    NameEntry::new(917, "ShadewoodTable", "Shadewood Table"),
+    NameEntry::new(918, "ShadewoodDresser", "Shadewood Dresser"),
+    NameEntry::new(919, "ShadewoodPiano", "Shadewood Piano"),
+    NameEntry::new(920, "ShadewoodBed", "Shadewood Bed"),
+    NameEntry::new(921, "ShadewoodSword", "Shadewood Sword"),
+    NameEntry::new(922, "ShadewoodHammer", "Shadewood Hammer"),
+    NameEntry::new(923, "ShadewoodBow", "Shadewood Bow"),
+    NameEntry::new(924, "ShadewoodHelmet", "Shadewood Helmet"),
+    NameEntry::new(925, "ShadewoodBreastplate", "Shadewood Breastplate"),
+    NameEntry::new(926, "ShadewoodGreaves", "Shadewood Greaves"),
+    NameEntry::new(927, "ShadewoodWall", "Shadewood Wall"),
+    NameEntry::new(928, "Cannon", "Cannon"),
+    NameEntry::new(929, "Cannonball", "Cannonball"),
+    NameEntry::new(930, "FlareGun", "Flare Gun"),
+    NameEntry::new(931, "Flare", "Flare"),
+    NameEntry::new(932, "BoneWand", "Bone Wand"),
+    NameEntry::new(933, "LeafWand", "Leaf Wand"),
+    NameEntry::new(934, "FlyingCarpet", "Flying Carpet"),
+    NameEntry::new(935, "AvengerEmblem", "Avenger Emblem"),
+    NameEntry::new(936, "MechanicalGlove", "Mechanical Glove"),
+    NameEntry::new(937, "LandMine", "Land Mine"),
+    NameEntry::new(938, "PaladinsShield", "Paladin's Shield"),
+    NameEntry::new(939, "WebSlinger", "Web Slinger"),
+    NameEntry::new(940, "JungleWaterFountain", "Jungle Water Fountain"),
+    NameEntry::new(941, "IcyWaterFountain", "Icy Water Fountain"),
+    NameEntry::new(942, "CorruptWaterFountain", "Corrupt Water Fountain"),
+    NameEntry::new(943, "CrimsonWaterFountain", "Crimson Water Fountain"),
+    NameEntry::new(944, "HallowedWaterFountain", "Hallowed Water Fountain"),
+    NameEntry::new(945, "BloodWaterFountain", "Blood Water Fountain"),
+    NameEntry::new(946, "Umbrella", "Umbrella"),
+    NameEntry::new(947, "ChlorophyteOre", "Chlorophyte Ore"),
+    NameEntry::new(948, "SteampunkWings", "Steampunk Wings"),
+    NameEntry::new(949, "Snowball", "Snowball"),
+    NameEntry::new(950, "IceSkates", "Ice Skates"),
+    NameEntry::new(951, "SnowballLauncher", "Snowball Launcher"),
+    NameEntry::new(952, "WebCoveredChest", "Web Covered Chest"),
+    NameEntry::new(953, "ClimbingClaws", "Climbing Claws"),
+    NameEntry::new(954, "AncientIronHelmet", "Ancient Iron Helmet"),
+    NameEntry::new(955, "AncientGoldHelmet", "Ancient Gold Helmet"),
+    NameEntry::new(956, "AncientShadowHelmet", "Ancient Shadow Helmet"),
+    NameEntry::new(957, "AncientShadowScalemail", "Ancient Shadow Scalemail"),
+    NameEntry::new(958, "AncientShadowGreaves", "Ancient Shadow Greaves"),
+    NameEntry::new(959, "AncientNecroHelmet", "Ancient Necro Helmet"),
+    NameEntry::new(960, "AncientCobaltHelmet", "Ancient Cobalt Helmet"),
+    NameEntry::new(961, "AncientCobaltBreastplate", "Ancient Cobalt Breastplate"),
+    NameEntry::new(962, "AncientCobaltLeggings", "Ancient Cobalt Leggings"),
+    NameEntry::new(963, "BlackBelt", "Black Belt"),
+    NameEntry::new(964, "Boomstick", "Boomstick"),
+    NameEntry::new(965, "Rope", "Rope"),
+    NameEntry::new(966, "Campfire", "Campfire"),
+    NameEntry::new(967, "Marshmallow", "Marshmallow"),
+    NameEntry::new(968, "MarshmallowonaStick", "Marshmallow on a Stick"),
+    NameEntry::new(969, "CookedMarshmallow", "Cooked Marshmallow"),
+    NameEntry::new(970, "RedRocket", "Red Rocket"),
+    NameEntry::new(971, "GreenRocket", "Green Rocket"),
+    NameEntry::new(972, "BlueRocket", "Blue Rocket"),
+    NameEntry::new(973, "YellowRocket", "Yellow Rocket"),
+    NameEntry::new(974, "IceTorch", "Ice Torch"),
+    NameEntry::new(975, "ShoeSpikes", "Shoe Spikes"),
+    NameEntry::new(976, "TigerClimbingGear", "Tiger Climbing Gear"),
+    NameEntry::new(977, "Tabi", "Tabi"),
+    NameEntry::new(978, "PinkEskimoHood", "Pink Snow Hood"),
+    NameEntry::new(979, "PinkEskimoCoat", "Pink Snow Coat"),
+    NameEntry::new(980, "PinkEskimoPants", "Pink Snow Pants"),
+    NameEntry::new(981, "PinkThread", "Pink Thread"),
+    NameEntry::new(982, "ManaRegenerationBand", "Mana Regeneration Band"),
+    NameEntry::new(983, "SandstorminaBalloon", "Sandstorm in a Balloon"),
+    NameEntry::new(984, "MasterNinjaGear", "Master Ninja Gear"),
+    NameEntry::new(985, "RopeCoil", "Rope Coil"),
+    NameEntry::new(986, "Blowgun", "Blowgun"),
+    NameEntry::new(987, "BlizzardinaBottle", "Blizzard in a Bottle"),
+    NameEntry::new(988, "FrostburnArrow", "Frostburn Arrow"),
+    NameEntry::new(989, "EnchantedSword", "Enchanted Sword"),
+    NameEntry::new(990, "PickaxeAxe", "Pickaxe Axe"),
+    NameEntry::new(991, "CobaltWaraxe", "Cobalt Waraxe"),
+    NameEntry::new(992, "MythrilWaraxe", "Mythril Waraxe"),
+    NameEntry::new(993, "AdamantiteWaraxe", "Adamantite Waraxe"),
+    NameEntry::new(994, "EatersBone", "Eater's Bone"),
+    NameEntry::new(995, "BlendOMatic", "Blend-O-Matic"),
+    NameEntry::new(996, "MeatGrinder", "Meat Grinder"),
+    NameEntry::new(997, "Extractinator", "Extractinator"),
+    NameEntry::new(998, "Solidifier", "Solidifier"),
+    NameEntry::new(999, "Amber", "Amber"),
+    NameEntry::new(1000, "ConfettiGun", "Confetti Gun"),
+    NameEntry::new(1001, "ChlorophyteMask", "Chlorophyte Mask"),
+    NameEntry::new(1002, "ChlorophyteHelmet", "Chlorophyte Helmet"),
+    NameEntry::new(1003, "ChlorophyteHeadgear", "Chlorophyte Headgear"),
+    NameEntry::new(1004, "ChlorophytePlateMail", "Chlorophyte Plate Mail"),
+    NameEntry::new(1005, "ChlorophyteGreaves", "Chlorophyte Greaves"),
+    NameEntry::new(1006, "ChlorophyteBar", "Chlorophyte Bar"),
+    NameEntry::new(1007, "RedDye", "Red Dye"),
+    NameEntry::new(1008, "OrangeDye", "Orange Dye"),
+    NameEntry::new(1009, "YellowDye", "Yellow Dye"),
+    NameEntry::new(1010, "LimeDye", "Lime Dye"),
+    NameEntry::new(1011, "GreenDye", "Green Dye"),
+    NameEntry::new(1012, "TealDye", "Teal Dye"),
+    NameEntry::new(1013, "CyanDye", "Cyan Dye"),
+    NameEntry::new(1014, "SkyBlueDye", "Sky Blue Dye"),
+    NameEntry::new(1015, "BlueDye", "Blue Dye"),
+    NameEntry::new(1016, "PurpleDye", "Purple Dye"),
+    NameEntry::new(1017, "VioletDye", "Violet Dye"),
+    NameEntry::new(1018, "PinkDye", "Pink Dye"),
+    NameEntry::new(1019, "RedandBlackDye", "Red and Black Dye"),
+    NameEntry::new(1020, "OrangeandBlackDye", "Orange and Black Dye"),
+    NameEntry::new(1021, "YellowandBlackDye", "Yellow and Black Dye"),
+    NameEntry::new(1022, "LimeandBlackDye", "Lime and Black Dye"),
+    NameEntry::new(1023, "GreenandBlackDye", "Green and Black Dye"),
+    NameEntry::new(1024, "TealandBlackDye", "Teal and Black Dye"),
+    NameEntry::new(1025, "CyanandBlackDye", "Cyan and Black Dye"),
+    NameEntry::new(1026, "SkyBlueandBlackDye", "Sky Blue and Black Dye"),
+    NameEntry::new(1027, "BlueandBlackDye", "Blue and Black Dye"),
+    NameEntry::new(1028, "PurpleandBlackDye", "Purple and Black Dye"),
+    NameEntry::new(1029, "VioletandBlackDye", "Violet and Black Dye"),
+    NameEntry::new(1030, "PinkandBlackDye", "Pink and Black Dye"),
+    NameEntry::new(1031, "FlameDye", "Flame Dye"),
+    NameEntry::new(1032, "FlameAndBlackDye", "Flame and Black Dye"),
+    NameEntry::new(1033, "GreenFlameDye", "Green Flame Dye"),
+    NameEntry::new(1034, "GreenFlameAndBlackDye", "Green Flame and Black Dye"),
+    NameEntry::new(1035, "BlueFlameDye", "Blue Flame Dye"),
+    NameEntry::new(1036, "BlueFlameAndBlackDye", "Blue Flame and Black Dye"),
+    NameEntry::new(1037, "SilverDye", "Silver Dye"),
+    NameEntry::new(1038, "BrightRedDye", "Bright Red Dye"),
+    NameEntry::new(1039, "BrightOrangeDye", "Bright Orange Dye"),
+    NameEntry::new(1040, "BrightYellowDye", "Bright Yellow Dye"),
+    NameEntry::new(1041, "BrightLimeDye", "Bright Lime Dye"),
+    NameEntry::new(1042, "BrightGreenDye", "Bright Green Dye"),
+    NameEntry::new(1043, "BrightTealDye", "Bright Teal Dye"),
+    NameEntry::new(1044, "BrightCyanDye", "Bright Cyan Dye"),
+    NameEntry::new(1045, "BrightSkyBlueDye", "Bright Sky Blue Dye"),
+    NameEntry::new(1046, "BrightBlueDye", "Bright Blue Dye"),
+    NameEntry::new(1047, "BrightPurpleDye", "Bright Purple Dye"),
+    NameEntry::new(1048, "BrightVioletDye", "Bright Violet Dye"),
+    NameEntry::new(1049, "BrightPinkDye", "Bright Pink Dye"),
+    NameEntry::new(1050, "BlackDye", "Black Dye"),
+    NameEntry::new(1051, "RedandSilverDye", "Red and Silver Dye"),
+    NameEntry::new(1052, "OrangeandSilverDye", "Orange and Silver Dye"),
+    NameEntry::new(1053, "YellowandSilverDye", "Yellow and Silver Dye"),
+    NameEntry::new(1054, "LimeandSilverDye", "Lime and Silver Dye"),
+    NameEntry::new(1055, "GreenandSilverDye", "Green and Silver Dye"),
+    NameEntry::new(1056, "TealandSilverDye", "Teal and Silver Dye"),
+    NameEntry::new(1057, "CyanandSilverDye", "Cyan and Silver Dye"),
+    NameEntry::new(1058, "SkyBlueandSilverDye", "Sky Blue and Silver Dye"),
+    NameEntry::new(1059, "BlueandSilverDye", "Blue and Silver Dye"),
+    NameEntry::new(1060, "PurpleandSilverDye", "Purple and Silver Dye"),
+    NameEntry::new(1061, "VioletandSilverDye", "Violet and Silver Dye"),
+    NameEntry::new(1062, "PinkandSilverDye", "Pink and Silver Dye"),
+    NameEntry::new(1063, "IntenseFlameDye", "Intense Flame Dye"),
+    NameEntry::new(1064, "IntenseGreenFlameDye", "Intense Green Flame Dye"),
+    NameEntry::new(1065, "IntenseBlueFlameDye", "Intense Blue Flame Dye"),
+    NameEntry::new(1066, "RainbowDye", "Rainbow Dye"),
+    NameEntry::new(1067, "IntenseRainbowDye", "Intense Rainbow Dye"),
+    NameEntry::new(1068, "YellowGradientDye", "Yellow Gradient Dye"),
+    NameEntry::new(1069, "CyanGradientDye", "Cyan Gradient Dye"),
+    NameEntry::new(1070, "VioletGradientDye", "Violet Gradient Dye"),
+    NameEntry::new(1071, "Paintbrush", "Paintbrush"),
+    NameEntry::new(1072, "PaintRoller", "Paint Roller"),
+    NameEntry::new(1073, "RedPaint", "Red Paint"),
+    NameEntry::new(1074, "OrangePaint", "Orange Paint"),
+    NameEntry::new(1075, "YellowPaint", "Yellow Paint"),
+    NameEntry::new(1076, "LimePaint", "Lime Paint"),
+    NameEntry::new(1077, "GreenPaint", "Green Paint"),
+    NameEntry::new(1078, "TealPaint", "Teal Paint"),
+    NameEntry::new(1079, "CyanPaint", "Cyan Paint"),
+    NameEntry::new(1080, "SkyBluePaint", "Sky Blue Paint"),
+    NameEntry::new(1081, "BluePaint", "Blue Paint"),
+    NameEntry::new(1082, "PurplePaint", "Purple Paint"),
+    NameEntry::new(1083, "VioletPaint", "Violet Paint"),
+    NameEntry::new(1084, "PinkPaint", "Pink Paint"),
+    NameEntry::new(1085, "DeepRedPaint", "Deep Red Paint"),
+    NameEntry::new(1086, "DeepOrangePaint", "Deep Orange Paint"),
+    NameEntry::new(1087, "DeepYellowPaint", "Deep Yellow Paint"),
+    NameEntry::new(1088, "DeepLimePaint", "Deep Lime Paint"),
+    NameEntry::new(1089, "DeepGreenPaint", "Deep Green Paint"),
+    NameEntry::new(1090, "DeepTealPaint", "Deep Teal Paint"),
+    NameEntry::new(1091, "DeepCyanPaint", "Deep Cyan Paint"),
+    NameEntry::new(1092, "DeepSkyBluePaint", "Deep Sky Blue Paint"),
+    NameEntry::new(1093, "DeepBluePaint", "Deep Blue Paint"),
+    NameEntry::new(1094, "DeepPurplePaint", "Deep Purple Paint"),
+    NameEntry::new(1095, "DeepVioletPaint", "Deep Violet Paint"),
+    NameEntry::new(1096, "DeepPinkPaint", "Deep Pink Paint"),
+    NameEntry::new(1097, "BlackPaint", "Black Paint"),
+    NameEntry::new(1098, "WhitePaint", "White Paint"),
+    NameEntry::new(1099, "GrayPaint", "Gray Paint"),
+    NameEntry::new(1100, "PaintScraper", "Paint Scraper"),
+    NameEntry::new(1101, "LihzahrdBrick", "Lihzahrd Brick"),
+    NameEntry::new(1102, "LihzahrdBrickWall", "Lihzahrd Brick Wall"),
+    NameEntry::new(1103, "SlushBlock", "Slush Block"),
+    NameEntry::new(1104, "PalladiumOre", "Palladium Ore"),
+    NameEntry::new(1105, "OrichalcumOre", "Orichalcum Ore"),
+    NameEntry::new(1106, "TitaniumOre", "Titanium Ore"),
+    NameEntry::new(1107, "TealMushroom", "Teal Mushroom"),
+    NameEntry::new(1108, "GreenMushroom", "Green Mushroom"),
+    NameEntry::new(1109, "SkyBlueFlower", "Sky Blue Flower"),
+    NameEntry::new(1110, "YellowMarigold", "Yellow Marigold"),
+    NameEntry::new(1111, "BlueBerries", "Blue Berries"),
+    NameEntry::new(1112, "LimeKelp", "Lime Kelp"),
+    NameEntry::new(1113, "PinkPricklyPear", "Pink Prickly Pear"),
+    NameEntry::new(1114, "OrangeBloodroot", "Orange Bloodroot"),
+    NameEntry::new(1115, "RedHusk", "Red Husk"),
+    NameEntry::new(1116, "CyanHusk", "Cyan Husk"),
+    NameEntry::new(1117, "VioletHusk", "Violet Husk"),
+    NameEntry::new(1118, "PurpleMucos", "Purple Mucus"),
+    NameEntry::new(1119, "BlackInk", "Black Ink"),
+    NameEntry::new(1120, "DyeVat", "Dye Vat"),
+    NameEntry::new(1121, "BeeGun", "Bee Gun"),
+    NameEntry::new(1122, "PossessedHatchet", "Possessed Hatchet"),
+    NameEntry::new(1123, "BeeKeeper", "Bee Keeper"),
+    NameEntry::new(1124, "Hive", "Hive"),
+    NameEntry::new(1125, "HoneyBlock", "Honey Block"),
+    NameEntry::new(1126, "HiveWall", "Hive Wall"),
+    NameEntry::new(1127, "CrispyHoneyBlock", "Crispy Honey Block"),
+    NameEntry::new(1128, "HoneyBucket", "Honey Bucket"),
+    NameEntry::new(1129, "HiveWand", "Hive Wand"),
+    NameEntry::new(1130, "Beenade", "Beenade"),
+    NameEntry::new(1131, "GravityGlobe", "Gravity Globe"),
+    NameEntry::new(1132, "HoneyComb", "Honey Comb"),
+    NameEntry::new(1133, "Abeemination", "Abeemination"),
+    NameEntry::new(1134, "BottledHoney", "Bottled Honey"),
+    NameEntry::new(1135, "RainHat", "Rain Hat"),
+    NameEntry::new(1136, "RainCoat", "Rain Coat"),
+    NameEntry::new(1137, "LihzahrdDoor", "Lihzahrd Door"),
+    NameEntry::new(1138, "DungeonDoor", "Dungeon Door"),
+    NameEntry::new(1139, "LeadDoor", "Lead Door"),
+    NameEntry::new(1140, "IronDoor", "Iron Door"),
+    NameEntry::new(1141, "TempleKey", "Temple Key"),
+    NameEntry::new(1142, "LihzahrdChest", "Lihzahrd Chest"),
+    NameEntry::new(1143, "LihzahrdChair", "Lihzahrd Chair"),
+    NameEntry::new(1144, "LihzahrdTable", "Lihzahrd Table"),
+    NameEntry::new(1145, "LihzahrdWorkBench", "Lihzahrd Work Bench"),
+    NameEntry::new(1146, "SuperDartTrap", "Super Dart Trap"),
+    NameEntry::new(1147, "FlameTrap", "Flame Trap"),
+    NameEntry::new(1148, "SpikyBallTrap", "Spiky Ball Trap"),
+    NameEntry::new(1149, "SpearTrap", "Spear Trap"),
+    NameEntry::new(1150, "WoodenSpike", "Wooden Spike"),
+    NameEntry::new(1151, "LihzahrdPressurePlate", "Lihzahrd Pressure Plate"),
+    NameEntry::new(1152, "LihzahrdStatue", "Lihzahrd Statue"),
+    NameEntry::new(1153, "LihzahrdWatcherStatue", "Lihzahrd Watcher Statue"),
+    NameEntry::new(1154, "LihzahrdGuardianStatue", "Lihzahrd Guardian Statue"),
+    NameEntry::new(1155, "WaspGun", "Wasp Gun"),
+    NameEntry::new(1156, "PiranhaGun", "Piranha Gun"),
+    NameEntry::new(1157, "PygmyStaff", "Pygmy Staff"),
+    NameEntry::new(1158, "PygmyNecklace", "Pygmy Necklace"),
+    NameEntry::new(1159, "TikiMask", "Tiki Mask"),
+    NameEntry::new(1160, "TikiShirt", "Tiki Shirt"),
+    NameEntry::new(1161, "TikiPants", "Tiki Pants"),
+    NameEntry::new(1162, "LeafWings", "Leaf Wings"),
+    NameEntry::new(1163, "BlizzardinaBalloon", "Blizzard in a Balloon"),
+    NameEntry::new(1164, "BundleofBalloons", "Bundle of Balloons"),
+    NameEntry::new(1165, "BatWings", "Bat Wings"),
+    NameEntry::new(1166, "BoneSword", "Bone Sword"),
+    NameEntry::new(1167, "HerculesBeetle", "Hercules Beetle"),
+    NameEntry::new(1168, "SmokeBomb", "Smoke Bomb"),
+    NameEntry::new(1169, "BoneKey", "Bone Key"),
+    NameEntry::new(1170, "Nectar", "Nectar"),
+    NameEntry::new(1171, "TikiTotem", "Tiki Totem"),
+    NameEntry::new(1172, "LizardEgg", "Lizard Egg"),
+    NameEntry::new(1173, "GraveMarker", "Grave Marker"),
+    NameEntry::new(1174, "CrossGraveMarker", "Cross Grave Marker"),
+    NameEntry::new(1175, "Headstone", "Headstone"),
+    NameEntry::new(1176, "Gravestone", "Gravestone"),
+    NameEntry::new(1177, "Obelisk", "Obelisk"),
+    NameEntry::new(1178, "LeafBlower", "Leaf Blower"),
+    NameEntry::new(1179, "ChlorophyteBullet", "Chlorophyte Bullet"),
+    NameEntry::new(1180, "ParrotCracker", "Parrot Cracker"),
+    NameEntry::new(1181, "StrangeGlowingMushroom", "Strange Glowing Mushroom"),
+    NameEntry::new(1182, "Seedling", "Seedling"),
+    NameEntry::new(1183, "WispinaBottle", "Wisp in a Bottle"),
+    NameEntry::new(1184, "PalladiumBar", "Palladium Bar"),
+    NameEntry::new(1185, "PalladiumSword", "Palladium Sword"),
+    NameEntry::new(1186, "PalladiumPike", "Palladium Pike"),
+    NameEntry::new(1187, "PalladiumRepeater", "Palladium Repeater"),
+    NameEntry::new(1188, "PalladiumPickaxe", "Palladium Pickaxe"),
+    NameEntry::new(1189, "PalladiumDrill", "Palladium Drill"),
+    NameEntry::new(1190, "PalladiumChainsaw", "Palladium Chainsaw"),
+    NameEntry::new(1191, "OrichalcumBar", "Orichalcum Bar"),
+    NameEntry::new(1192, "OrichalcumSword", "Orichalcum Sword"),
+    NameEntry::new(1193, "OrichalcumHalberd", "Orichalcum Halberd"),
+    NameEntry::new(1194, "OrichalcumRepeater", "Orichalcum Repeater"),
+    NameEntry::new(1195, "OrichalcumPickaxe", "Orichalcum Pickaxe"),
+    NameEntry::new(1196, "OrichalcumDrill", "Orichalcum Drill"),
+    NameEntry::new(1197, "OrichalcumChainsaw", "Orichalcum Chainsaw"),
+    NameEntry::new(1198, "TitaniumBar", "Titanium Bar"),
+    NameEntry::new(1199, "TitaniumSword", "Titanium Sword"),
+    NameEntry::new(1200, "TitaniumTrident", "Titanium Trident"),
+    NameEntry::new(1201, "TitaniumRepeater", "Titanium Repeater"),
+    NameEntry::new(1202, "TitaniumPickaxe", "Titanium Pickaxe"),
+    NameEntry::new(1203, "TitaniumDrill", "Titanium Drill"),
+    NameEntry::new(1204, "TitaniumChainsaw", "Titanium Chainsaw"),
+    NameEntry::new(1205, "PalladiumMask", "Palladium Mask"),
+    NameEntry::new(1206, "PalladiumHelmet", "Palladium Helmet"),
+    NameEntry::new(1207, "PalladiumHeadgear", "Palladium Headgear"),
+    NameEntry::new(1208, "PalladiumBreastplate", "Palladium Breastplate"),
+    NameEntry::new(1209, "PalladiumLeggings", "Palladium Leggings"),
+    NameEntry::new(1210, "OrichalcumMask", "Orichalcum Mask"),
+    NameEntry::new(1211, "OrichalcumHelmet", "Orichalcum Helmet"),
+    NameEntry::new(1212, "OrichalcumHeadgear", "Orichalcum Headgear"),
+    NameEntry::new(1213, "OrichalcumBreastplate", "Orichalcum Breastplate"),
+    NameEntry::new(1214, "OrichalcumLeggings", "Orichalcum Leggings"),
+    NameEntry::new(1215, "TitaniumMask", "Titanium Mask"),
+    NameEntry::new(1216, "TitaniumHelmet", "Titanium Helmet"),
+    NameEntry::new(1217, "TitaniumHeadgear", "Titanium Headgear"),
+    NameEntry::new(1218, "TitaniumBreastplate", "Titanium Breastplate"),
+    NameEntry::new(1219, "TitaniumLeggings", "Titanium Leggings"),
+    NameEntry::new(1220, "OrichalcumAnvil", "Orichalcum Anvil"),
+    NameEntry::new(1221, "TitaniumForge", "Titanium Forge"),
+    NameEntry::new(1222, "PalladiumWaraxe", "Palladium Waraxe"),
+    NameEntry::new(1223, "OrichalcumWaraxe", "Orichalcum Waraxe"),
+    NameEntry::new(1224, "TitaniumWaraxe", "Titanium Waraxe"),
+    NameEntry::new(1225, "HallowedBar", "Hallowed Bar"),
+    NameEntry::new(1226, "ChlorophyteClaymore", "Chlorophyte Claymore"),
+    NameEntry::new(1227, "ChlorophyteSaber", "Chlorophyte Saber"),
+    NameEntry::new(1228, "ChlorophytePartisan", "Chlorophyte Partisan"),
+    NameEntry::new(1229, "ChlorophyteShotbow", "Chlorophyte Shotbow"),
+    NameEntry::new(1230, "ChlorophytePickaxe", "Chlorophyte Pickaxe"),
+    NameEntry::new(1231, "ChlorophyteDrill", "Chlorophyte Drill"),
+    NameEntry::new(1232, "ChlorophyteChainsaw", "Chlorophyte Chainsaw"),
+    NameEntry::new(1233, "ChlorophyteGreataxe", "Chlorophyte Greataxe"),
+    NameEntry::new(1234, "ChlorophyteWarhammer", "Chlorophyte Warhammer"),
+    NameEntry::new(1235, "ChlorophyteArrow", "Chlorophyte Arrow"),
+    NameEntry::new(1236, "AmethystHook", "Amethyst Hook"),
+    NameEntry::new(1237, "TopazHook", "Topaz Hook"),
+    NameEntry::new(1238, "SapphireHook", "Sapphire Hook"),
+    NameEntry::new(1239, "EmeraldHook", "Emerald Hook"),
+    NameEntry::new(1240, "RubyHook", "Ruby Hook"),
+    NameEntry::new(1241, "DiamondHook", "Diamond Hook"),
+    NameEntry::new(1242, "AmberMosquito", "Amber Mosquito"),
+    NameEntry::new(1243, "UmbrellaHat", "Umbrella Hat"),
+    NameEntry::new(1244, "NimbusRod", "Nimbus Rod"),
+    NameEntry::new(1245, "OrangeTorch", "Orange Torch"),
+    NameEntry::new(1246, "CrimsandBlock", "Crimsand Block"),
+    NameEntry::new(1247, "BeeCloak", "Bee Cloak"),
+    NameEntry::new(1248, "EyeoftheGolem", "Eye of the Golem"),
+    NameEntry::new(1249, "HoneyBalloon", "Honey Balloon"),
+    NameEntry::new(1250, "BlueHorseshoeBalloon", "Blue Horseshoe Balloon"),
+    NameEntry::new(1251, "WhiteHorseshoeBalloon", "White Horseshoe Balloon"),
+    NameEntry::new(1252, "YellowHorseshoeBalloon", "Yellow Horseshoe Balloon"),
+    NameEntry::new(1253, "FrozenTurtleShell", "Frozen Turtle Shell"),
+    NameEntry::new(1254, "SniperRifle", "Sniper Rifle"),
+    NameEntry::new(1255, "VenusMagnum", "Venus Magnum"),
+    NameEntry::new(1256, "CrimsonRod", "Crimson Rod"),
+    NameEntry::new(1257, "CrimtaneBar", "Crimtane Bar"),
+    NameEntry::new(1258, "Stynger", "Stynger"),
+    NameEntry::new(1259, "FlowerPow", "Flower Pow"),
+    NameEntry::new(1260, "RainbowGun", "Rainbow Gun"),
+    NameEntry::new(1261, "StyngerBolt", "Stynger Bolt"),
+    NameEntry::new(1262, "ChlorophyteJackhammer", "Chlorophyte Jackhammer"),
+    NameEntry::new(1263, "Teleporter", "Teleporter"),
+    NameEntry::new(1264, "FlowerofFrost", "Flower of Frost"),
+    NameEntry::new(1265, "Uzi", "Uzi"),
+    NameEntry::new(1266, "MagnetSphere", "Magnet Sphere"),
+    NameEntry::new(1267, "PurpleStainedGlass", "Purple Stained Glass"),
+    NameEntry::new(1268, "YellowStainedGlass", "Yellow Stained Glass"),
+    NameEntry::new(1269, "BlueStainedGlass", "Blue Stained Glass"),
+    NameEntry::new(1270, "GreenStainedGlass", "Green Stained Glass"),
+    NameEntry::new(1271, "RedStainedGlass", "Red Stained Glass"),
+    NameEntry::new(1272, "MulticoloredStainedGlass", "Multicolored Stained Glass"),
+    NameEntry::new(1273, "SkeletronHand", "Skeletron Hand"),
+    NameEntry::new(1274, "Skull", "Skull"),
+    NameEntry::new(1275, "BallaHat", "Balla Hat"),
+    NameEntry::new(1276, "GangstaHat", "Gangsta Hat"),
+    NameEntry::new(1277, "SailorHat", "Sailor Hat"),
+    NameEntry::new(1278, "EyePatch", "Eye Patch"),
+    NameEntry::new(1279, "SailorShirt", "Sailor Shirt"),
+    NameEntry::new(1280, "SailorPants", "Sailor Pants"),
+    NameEntry::new(1281, "SkeletronMask", "Skeletron Mask"),
+    NameEntry::new(1282, "AmethystRobe", "Amethyst Robe"),
+    NameEntry::new(1283, "TopazRobe", "Topaz Robe"),
+    NameEntry::new(1284, "SapphireRobe", "Sapphire Robe"),
+    NameEntry::new(1285, "EmeraldRobe", "Emerald Robe"),
+    NameEntry::new(1286, "RubyRobe", "Ruby Robe"),
+    NameEntry::new(1287, "DiamondRobe", "Diamond Robe"),
+    NameEntry::new(1288, "WhiteTuxedoShirt", "White Tuxedo Shirt"),
+    NameEntry::new(1289, "WhiteTuxedoPants", "White Tuxedo Pants"),
+    NameEntry::new(1290, "PanicNecklace", "Panic Necklace"),
+    NameEntry::new(1291, "LifeFruit", "Life Fruit"),
+    NameEntry::new(1292, "LihzahrdAltar", "Lihzahrd Altar"),
+    NameEntry::new(1293, "LihzahrdPowerCell", "Lihzahrd Power Cell"),
+    NameEntry::new(1294, "Picksaw", "Picksaw"),
+    NameEntry::new(1295, "HeatRay", "Heat Ray"),
+    NameEntry::new(1296, "StaffofEarth", "Staff of Earth"),
+    NameEntry::new(1297, "GolemFist", "Golem Fist"),
+    NameEntry::new(1298, "WaterChest", "Water Chest"),
+    NameEntry::new(1299, "Binoculars", "Binoculars"),
+    NameEntry::new(1300, "RifleScope", "Rifle Scope"),
+    NameEntry::new(1301, "DestroyerEmblem", "Destroyer Emblem"),
+    NameEntry::new(1302, "HighVelocityBullet", "High Velocity Bullet"),
+    NameEntry::new(1303, "JellyfishNecklace", "Jellyfish Necklace"),
+    NameEntry::new(1304, "ZombieArm", "Zombie Arm"),
+    NameEntry::new(1305, "TheAxe", "The Axe"),
+    NameEntry::new(1306, "IceSickle", "Ice Sickle"),
+    NameEntry::new(1307, "ClothierVoodooDoll", "Clothier Voodoo Doll"),
+    NameEntry::new(1308, "PoisonStaff", "Poison Staff"),
+    NameEntry::new(1309, "SlimeStaff", "Slime Staff"),
+    NameEntry::new(1310, "PoisonDart", "Poison Dart"),
+    NameEntry::new(1311, "EyeSpring", "Eye Spring"),
+    NameEntry::new(1312, "ToySled", "Toy Sled"),
+    NameEntry::new(1313, "BookofSkulls", "Book of Skulls"),
+    NameEntry::new(1314, "KOCannon", "KO Cannon"),
+    NameEntry::new(1315, "PirateMap", "Pirate Map"),
+    NameEntry::new(1316, "TurtleHelmet", "Turtle Helmet"),
+    NameEntry::new(1317, "TurtleScaleMail", "Turtle Scale Mail"),
+    NameEntry::new(1318, "TurtleLeggings", "Turtle Leggings"),
+    NameEntry::new(1319, "SnowballCannon", "Snowball Cannon"),
+    NameEntry::new(1320, "BonePickaxe", "Bone Pickaxe"),
+    NameEntry::new(1321, "MagicQuiver", "Magic Quiver"),
+    NameEntry::new(1322, "MagmaStone", "Magma Stone"),
+    NameEntry::new(1323, "ObsidianRose", "Obsidian Rose"),
+    NameEntry::new(1324, "Bananarang", "Bananarang"),
+    NameEntry::new(1325, "ChainKnife", "Chain Knife"),
+    NameEntry::new(1326, "RodofDiscord", "Rod of Discord"),
+    NameEntry::new(1327, "DeathSickle", "Death Sickle"),
+    NameEntry::new(1328, "TurtleShell", "Turtle Shell"),
+    NameEntry::new(1329, "TissueSample", "Tissue Sample"),
+    NameEntry::new(1330, "Vertebrae", "Vertebra"),
+    NameEntry::new(1331, "BloodySpine", "Bloody Spine"),
+    NameEntry::new(1332, "Ichor", "Ichor"),
+    NameEntry::new(1333, "IchorTorch", "Ichor Torch"),
+    NameEntry::new(1334, "IchorArrow", "Ichor Arrow"),
+    NameEntry::new(1335, "IchorBullet", "Ichor Bullet"),
+    NameEntry::new(1336, "GoldenShower", "Golden Shower"),
+    NameEntry::new(1337, "BunnyCannon", "Bunny Cannon"),
+    NameEntry::new(1338, "ExplosiveBunny", "Explosive Bunny"),
+    NameEntry::new(1339, "VialofVenom", "Vial of Venom"),
+    NameEntry::new(1340, "FlaskofVenom", "Flask of Venom"),
+    NameEntry::new(1341, "VenomArrow", "Venom Arrow"),
+    NameEntry::new(1342, "VenomBullet", "Venom Bullet"),
+    NameEntry::new(1343, "FireGauntlet", "Fire Gauntlet"),
+    NameEntry::new(1344, "Cog", "Cog"),
+    NameEntry::new(1345, "Confetti", "Confetti"),
+    NameEntry::new(1346, "Nanites", "Nanites"),
+    NameEntry::new(1347, "ExplosivePowder", "Explosive Powder"),
+    NameEntry::new(1348, "GoldDust", "Gold Dust"),
+    NameEntry::new(1349, "PartyBullet", "Party Bullet"),
+    NameEntry::new(1350, "NanoBullet", "Nano Bullet"),
+    NameEntry::new(1351, "ExplodingBullet", "Exploding Bullet"),
+    NameEntry::new(1352, "GoldenBullet", "Golden Bullet"),
+    NameEntry::new(1353, "FlaskofCursedFlames", "Flask of Cursed Flames"),
+    NameEntry::new(1354, "FlaskofFire", "Flask of Fire"),
+    NameEntry::new(1355, "FlaskofGold", "Flask of Gold"),
+    NameEntry::new(1356, "FlaskofIchor", "Flask of Ichor"),
+    NameEntry::new(1357, "FlaskofNanites", "Flask of Nanites"),
+    NameEntry::new(1358, "FlaskofParty", "Flask of Party"),
+    NameEntry::new(1359, "FlaskofPoison", "Flask of Poison"),
+    NameEntry::new(1360, "EyeofCthulhuTrophy", "Eye of Cthulhu Trophy"),
+    NameEntry::new(1361, "EaterofWorldsTrophy", "Eater of Worlds Trophy"),
+    NameEntry::new(1362, "BrainofCthulhuTrophy", "Brain of Cthulhu Trophy"),
+    NameEntry::new(1363, "SkeletronTrophy", "Skeletron Trophy"),
+    NameEntry::new(1364, "QueenBeeTrophy", "Queen Bee Trophy"),
+    NameEntry::new(1365, "WallofFleshTrophy", "Wall of Flesh Trophy"),
+    NameEntry::new(1366, "DestroyerTrophy", "Destroyer Trophy"),
+    NameEntry::new(1367, "SkeletronPrimeTrophy", "Skeletron Prime Trophy"),
+    NameEntry::new(1368, "RetinazerTrophy", "Retinazer Trophy"),
+    NameEntry::new(1369, "SpazmatismTrophy", "Spazmatism Trophy"),
+    NameEntry::new(1370, "PlanteraTrophy", "Plantera Trophy"),
+    NameEntry::new(1371, "GolemTrophy", "Golem Trophy"),
+    NameEntry::new(1372, "BloodMoonRising", "Blood Moon Rising"),
+    NameEntry::new(1373, "TheHangedMan", "The Hanged Man"),
+    NameEntry::new(1374, "GloryoftheFire", "Glory of the Fire"),
+    NameEntry::new(1375, "BoneWarp", "Bone Warp"),
+    NameEntry::new(1376, "WallSkeleton", "Wall Skeleton"),
+    NameEntry::new(1377, "HangingSkeleton", "Hanging Skeleton"),
+    NameEntry::new(1378, "BlueSlabWall", "Blue Slab Wall"),
+    NameEntry::new(1379, "BlueTiledWall", "Blue Tiled Wall"),
+    NameEntry::new(1380, "PinkSlabWall", "Pink Slab Wall"),
+    NameEntry::new(1381, "PinkTiledWall", "Pink Tiled Wall"),
+    NameEntry::new(1382, "GreenSlabWall", "Green Slab Wall"),
+    NameEntry::new(1383, "GreenTiledWall", "Green Tiled Wall"),
+    NameEntry::new(1384, "BlueBrickPlatform", "Blue Brick Platform"),
+    NameEntry::new(1385, "PinkBrickPlatform", "Pink Brick Platform"),
+    NameEntry::new(1386, "GreenBrickPlatform", "Green Brick Platform"),
+    NameEntry::new(1387, "MetalShelf", "Metal Shelf"),
+    NameEntry::new(1388, "BrassShelf", "Brass Shelf"),
+    NameEntry::new(1389, "WoodShelf", "Wood Shelf"),
+    NameEntry::new(1390, "BrassLantern", "Brass Lantern"),
+    NameEntry::new(1391, "CagedLantern", "Caged Lantern"),
+    NameEntry::new(1392, "CarriageLantern", "Carriage Lantern"),
+    NameEntry::new(1393, "AlchemyLantern", "Alchemy Lantern"),
+    NameEntry::new(1394, "DiablostLamp", "Diabolist Lamp"),
+    NameEntry::new(1395, "OilRagSconse", "Oil Rag Sconce"),
+    NameEntry::new(1396, "BlueDungeonChair", "Blue Dungeon Chair"),
+    NameEntry::new(1397, "BlueDungeonTable", "Blue Dungeon Table"),
+    NameEntry::new(1398, "BlueDungeonWorkBench", "Blue Dungeon Work Bench"),
+    NameEntry::new(1399, "GreenDungeonChair", "Green Dungeon Chair"),
+    NameEntry::new(1400, "GreenDungeonTable", "Green Dungeon Table"),
+    NameEntry::new(1401, "GreenDungeonWorkBench", "Green Dungeon Work Bench"),
+    NameEntry::new(1402, "PinkDungeonChair", "Pink Dungeon Chair"),
+    NameEntry::new(1403, "PinkDungeonTable", "Pink Dungeon Table"),
+    NameEntry::new(1404, "PinkDungeonWorkBench", "Pink Dungeon Work Bench"),
+    NameEntry::new(1405, "BlueDungeonCandle", "Blue Dungeon Candle"),
+    NameEntry::new(1406, "GreenDungeonCandle", "Green Dungeon Candle"),
+    NameEntry::new(1407, "PinkDungeonCandle", "Pink Dungeon Candle"),
+    NameEntry::new(1408, "BlueDungeonVase", "Blue Dungeon Vase"),
+    NameEntry::new(1409, "GreenDungeonVase", "Green Dungeon Vase"),
+    NameEntry::new(1410, "PinkDungeonVase", "Pink Dungeon Vase"),
+    NameEntry::new(1411, "BlueDungeonDoor", "Blue Dungeon Door"),
+    NameEntry::new(1412, "GreenDungeonDoor", "Green Dungeon Door"),
+    NameEntry::new(1413, "PinkDungeonDoor", "Pink Dungeon Door"),
+    NameEntry::new(1414, "BlueDungeonBookcase", "Blue Dungeon Bookcase"),
+    NameEntry::new(1415, "GreenDungeonBookcase", "Green Dungeon Bookcase"),
+    NameEntry::new(1416, "PinkDungeonBookcase", "Pink Dungeon Bookcase"),
+    NameEntry::new(1417, "Catacomb", "Catacomb"),
+    NameEntry::new(1418, "DungeonShelf", "Dungeon Shelf"),
+    NameEntry::new(1419, "SkellingtonJSkellingsworth", "Skellington J Skellingsworth"),
+    NameEntry::new(1420, "TheCursedMan", "The Cursed Man"),
+    NameEntry::new(1421, "TheEyeSeestheEnd", "The Eye Sees the End"),
+    NameEntry::new(1422, "SomethingEvilisWatchingYou", "Something Evil is Watching You"),
+    NameEntry::new(1423, "TheTwinsHaveAwoken", "The Twins Have Awoken"),
+    NameEntry::new(1424, "TheScreamer", "The Screamer"),
+    NameEntry::new(1425, "GoblinsPlayingPoker", "Goblins Playing Poker"),
+    NameEntry::new(1426, "Dryadisque", "Dryadisque"),
+    NameEntry::new(1427, "Sunflowers", "Sunflowers"),
+    NameEntry::new(1428, "TerrarianGothic", "Terrarian Gothic"),
+    NameEntry::new(1429, "Beanie", "Beanie"),
+    NameEntry::new(1430, "ImbuingStation", "Imbuing Station"),
+    NameEntry::new(1431, "StarinaBottle", "Star in a Bottle"),
+    NameEntry::new(1432, "EmptyBullet", "Empty Bullet"),
+    NameEntry::new(1433, "Impact", "Impact"),
+    NameEntry::new(1434, "PoweredbyBirds", "Powered by Birds"),
+    NameEntry::new(1435, "TheDestroyer", "The Destroyer"),
+    NameEntry::new(1436, "ThePersistencyofEyes", "The Persistency of Eyes"),
+    NameEntry::new(1437, "UnicornCrossingtheHallows", "Unicorn Crossing the Hallows"),
+    NameEntry::new(1438, "GreatWave", "Great Wave"),
+    NameEntry::new(1439, "StarryNight", "Starry Night"),
+    NameEntry::new(1440, "GuidePicasso", "Guide Picasso"),
+    NameEntry::new(1441, "TheGuardiansGaze", "The Guardian's Gaze"),
+    NameEntry::new(1442, "FatherofSomeone", "Father of Someone"),
+    NameEntry::new(1443, "NurseLisa", "Nurse Lisa"),
+    NameEntry::new(1444, "ShadowbeamStaff", "Shadowbeam Staff"),
+    NameEntry::new(1445, "InfernoFork", "Inferno Fork"),
+    NameEntry::new(1446, "SpectreStaff", "Spectre Staff"),
+    NameEntry::new(1447, "WoodenFence", "Wooden Fence"),
+    NameEntry::new(1448, "LeadFence", "Lead Fence"),
+    NameEntry::new(1449, "BubbleMachine", "Bubble Machine"),
+    NameEntry::new(1450, "BubbleWand", "Bubble Wand"),
+    NameEntry::new(1451, "MarchingBonesBanner", "Marching Bones Banner"),
+    NameEntry::new(1452, "NecromanticSign", "Necromantic Sign"),
+    NameEntry::new(1453, "RustedCompanyStandard", "Rusted Company Standard"),
+    NameEntry::new(1454, "RaggedBrotherhoodSigil", "Ragged Brotherhood Sigil"),
+    NameEntry::new(1455, "MoltenLegionFlag", "Molten Legion Flag"),
+    NameEntry::new(1456, "DiabolicSigil", "Diabolic Sigil"),
+    NameEntry::new(1457, "ObsidianPlatform", "Obsidian Platform"),
+    NameEntry::new(1458, "ObsidianDoor", "Obsidian Door"),
+    NameEntry::new(1459, "ObsidianChair", "Obsidian Chair"),
+    NameEntry::new(1460, "ObsidianTable", "Obsidian Table"),
+    NameEntry::new(1461, "ObsidianWorkBench", "Obsidian Work Bench"),
+    NameEntry::new(1462, "ObsidianVase", "Obsidian Vase"),
+    NameEntry::new(1463, "ObsidianBookcase", "Obsidian Bookcase"),
+    NameEntry::new(1464, "HellboundBanner", "Hellbound Banner"),
+    NameEntry::new(1465, "HellHammerBanner", "Hell Hammer Banner"),
+    NameEntry::new(1466, "HelltowerBanner", "Helltower Banner"),
+    NameEntry::new(1467, "LostHopesofManBanner", "Lost Hopes of Man Banner"),
+    NameEntry::new(1468, "ObsidianWatcherBanner", "Obsidian Watcher Banner"),
+    NameEntry::new(1469, "LavaEruptsBanner", "Lava Erupts Banner"),
+    NameEntry::new(1470, "BlueDungeonBed", "Blue Dungeon Bed"),
+    NameEntry::new(1471, "GreenDungeonBed", "Green Dungeon Bed"),
+    NameEntry::new(1472, "PinkDungeonBed", "Pink Dungeon Bed"),
+    NameEntry::new(1473, "ObsidianBed", "Obsidian Bed"),
+    NameEntry::new(1474, "Waldo", "Waldo"),
+    NameEntry::new(1475, "Darkness", "Darkness"),
+    NameEntry::new(1476, "DarkSoulReaper", "Dark Soul Reaper"),
+    NameEntry::new(1477, "Land", "Land"),
+    NameEntry::new(1478, "TrappedGhost", "Trapped Ghost"),
+    NameEntry::new(1479, "DemonsEye", "Demon's Eye"),
+    NameEntry::new(1480, "FindingGold", "Finding Gold"),
+    NameEntry::new(1481, "FirstEncounter", "First Encounter"),
+    NameEntry::new(1482, "GoodMorning", "Good Morning"),
+    NameEntry::new(1483, "UndergroundReward", "Underground Reward"),
+    NameEntry::new(1484, "ThroughtheWindow", "Through the Window"),
+    NameEntry::new(1485, "PlaceAbovetheClouds", "Place Above the Clouds"),
+    NameEntry::new(1486, "DoNotStepontheGrass", "Do Not Step on the Grass"),
+    NameEntry::new(1487, "ColdWatersintheWhiteLand", "Cold Waters in the White Land"),
+    NameEntry::new(1488, "LightlessChasms", "Lightless Chasms"),
+    NameEntry::new(1489, "TheLandofDeceivingLooks", "The Land of Deceiving Looks"),
+    NameEntry::new(1490, "Daylight", "Daylight"),
+    NameEntry::new(1491, "SecretoftheSands", "Secret of the Sands"),
+    NameEntry::new(1492, "DeadlandComesAlive", "Deadland Comes Alive"),
+    NameEntry::new(1493, "EvilPresence", "Evil Presence"),
+    NameEntry::new(1494, "SkyGuardian", "Sky Guardian"),
+    NameEntry::new(1495, "AmericanExplosive", "American Explosive"),
+    NameEntry::new(1496, "Discover", "Discover"),
+    NameEntry::new(1497, "HandEarth", "Hand Earth"),
+    NameEntry::new(1498, "OldMiner", "Old Miner"),
+    NameEntry::new(1499, "Skelehead", "Skelehead"),
+    NameEntry::new(1500, "FacingtheCerebralMastermind", "Facing the Cerebral Mastermind"),
+    NameEntry::new(1501, "LakeofFire", "Lake of Fire"),
+    NameEntry::new(1502, "TrioSuperHeroes", "Trio Super Heroes"),
+    NameEntry::new(1503, "SpectreHood", "Spectre Hood"),
+    NameEntry::new(1504, "SpectreRobe", "Spectre Robe"),
+    NameEntry::new(1505, "SpectrePants", "Spectre Pants"),
+    NameEntry::new(1506, "SpectrePickaxe", "Spectre Pickaxe"),
+    NameEntry::new(1507, "SpectreHamaxe", "Spectre Hamaxe"),
+    NameEntry::new(1508, "Ectoplasm", "Ectoplasm"),
+    NameEntry::new(1509, "GothicChair", "Gothic Chair"),
+    NameEntry::new(1510, "GothicTable", "Gothic Table"),
+    NameEntry::new(1511, "GothicWorkBench", "Gothic Work Bench"),
+    NameEntry::new(1512, "GothicBookcase", "Gothic Bookcase"),
+    NameEntry::new(1513, "PaladinsHammer", "Paladin's Hammer"),
+    NameEntry::new(1514, "SWATHelmet", "SWAT Helmet"),
+    NameEntry::new(1515, "BeeWings", "Bee Wings"),
+    NameEntry::new(1516, "GiantHarpyFeather", "Giant Harpy Feather"),
+    NameEntry::new(1517, "BoneFeather", "Bone Feather"),
+    NameEntry::new(1518, "FireFeather", "Fire Feather"),
+    NameEntry::new(1519, "IceFeather", "Ice Feather"),
+    NameEntry::new(1520, "BrokenBatWing", "Broken Bat Wing"),
+    NameEntry::new(1521, "TatteredBeeWing", "Tattered Bee Wing"),
+    NameEntry::new(1522, "LargeAmethyst", "Large Amethyst"),
+    NameEntry::new(1523, "LargeTopaz", "Large Topaz"),
+    NameEntry::new(1524, "LargeSapphire", "Large Sapphire"),
+    NameEntry::new(1525, "LargeEmerald", "Large Emerald"),
+    NameEntry::new(1526, "LargeRuby", "Large Ruby"),
+    NameEntry::new(1527, "LargeDiamond", "Large Diamond"),
+    NameEntry::new(1528, "JungleChest", "Jungle Chest"),
+    NameEntry::new(1529, "CorruptionChest", "Corruption Chest"),
+    NameEntry::new(1530, "CrimsonChest", "Crimson Chest"),
+    NameEntry::new(1531, "HallowedChest", "Hallowed Chest"),
+    NameEntry::new(1532, "FrozenChest", "Ice Chest"),
+    NameEntry::new(1533, "JungleKey", "Jungle Key"),
+    NameEntry::new(1534, "CorruptionKey", "Corruption Key"),
+    NameEntry::new(1535, "CrimsonKey", "Crimson Key"),
+    NameEntry::new(1536, "HallowedKey", "Hallowed Key"),
+    NameEntry::new(1537, "FrozenKey", "Frozen Key"),
+    NameEntry::new(1538, "ImpFace", "Imp Face"),
+    NameEntry::new(1539, "OminousPresence", "Ominous Presence"),
+    NameEntry::new(1540, "ShiningMoon", "Shining Moon"),
+    NameEntry::new(1541, "LivingGore", "Living Gore"),
+    NameEntry::new(1542, "FlowingMagma", "Flowing Magma"),
+    NameEntry::new(1543, "SpectrePaintbrush", "Spectre Paintbrush"),
+    NameEntry::new(1544, "SpectrePaintRoller", "Spectre Paint Roller"),
+    NameEntry::new(1545, "SpectrePaintScraper", "Spectre Paint Scraper"),
+    NameEntry::new(1546, "ShroomiteHeadgear", "Shroomite Headgear"),
+    NameEntry::new(1547, "ShroomiteMask", "Shroomite Mask"),
+    NameEntry::new(1548, "ShroomiteHelmet", "Shroomite Helmet"),
+    NameEntry::new(1549, "ShroomiteBreastplate", "Shroomite Breastplate"),
+    NameEntry::new(1550, "ShroomiteLeggings", "Shroomite Leggings"),
+    NameEntry::new(1551, "Autohammer", "Autohammer"),
+    NameEntry::new(1552, "ShroomiteBar", "Shroomite Bar"),
+    NameEntry::new(1553, "SDMG", "S.D.M.G."),
+    NameEntry::new(1554, "CenxsTiara", "Cenx's Tiara"),
+    NameEntry::new(1555, "CenxsBreastplate", "Cenx's Breastplate"),
+    NameEntry::new(1556, "CenxsLeggings", "Cenx's Leggings"),
+    NameEntry::new(1557, "CrownosMask", "Crowno's Mask"),
+    NameEntry::new(1558, "CrownosBreastplate", "Crowno's Breastplate"),
+    NameEntry::new(1559, "CrownosLeggings", "Crowno's Leggings"),
+    NameEntry::new(1560, "WillsHelmet", "Will's Helmet"),
+    NameEntry::new(1561, "WillsBreastplate", "Will's Breastplate"),
+    NameEntry::new(1562, "WillsLeggings", "Will's Leggings"),
+    NameEntry::new(1563, "JimsHelmet", "Jim's Helmet"),
+    NameEntry::new(1564, "JimsBreastplate", "Jim's Breastplate"),
+    NameEntry::new(1565, "JimsLeggings", "Jim's Leggings"),
+    NameEntry::new(1566, "AaronsHelmet", "Aaron's Helmet"),
+    NameEntry::new(1567, "AaronsBreastplate", "Aaron's Breastplate"),
+    NameEntry::new(1568, "AaronsLeggings", "Aaron's Leggings"),
+    NameEntry::new(1569, "VampireKnives", "Vampire Knives"),
+    NameEntry::new(1570, "BrokenHeroSword", "Broken Hero Sword"),
+    NameEntry::new(1571, "ScourgeoftheCorruptor", "Scourge of the Corruptor"),
+    NameEntry::new(1572, "StaffoftheFrostHydra", "Staff of the Frost Hydra"),
+    NameEntry::new(1573, "TheCreationoftheGuide", "The Creation of the Guide"),
+    NameEntry::new(1574, "TheMerchant", "The Merchant"),
+    NameEntry::new(1575, "CrownoDevoursHisLunch", "Crowno Devours His Lunch"),
+    NameEntry::new(1576, "RareEnchantment", "Rare Enchantment"),
+    NameEntry::new(1577, "GloriousNight", "Glorious Night"),
+    NameEntry::new(1578, "SweetheartNecklace", "Sweetheart Necklace"),
+    NameEntry::new(1579, "FlurryBoots", "Flurry Boots"),
+    NameEntry::new(1580, "DTownsHelmet", "D-Town's Helmet"),
+    NameEntry::new(1581, "DTownsBreastplate", "D-Town's Breastplate"),
+    NameEntry::new(1582, "DTownsLeggings", "D-Town's Leggings"),
+    NameEntry::new(1583, "DTownsWings", "D-Town's Wings"),
+    NameEntry::new(1584, "WillsWings", "Will's Wings"),
+    NameEntry::new(1585, "CrownosWings", "Crowno's Wings"),
+    NameEntry::new(1586, "CenxsWings", "Cenx's Wings"),
+    NameEntry::new(1587, "CenxsDress", "Cenx's Dress"),
+    NameEntry::new(1588, "CenxsDressPants", "Cenx's Dress Pants"),
+    NameEntry::new(1589, "PalladiumColumn", "Palladium Column"),
+    NameEntry::new(1590, "PalladiumColumnWall", "Palladium Column Wall"),
+    NameEntry::new(1591, "BubblegumBlock", "Bubblegum Block"),
+    NameEntry::new(1592, "BubblegumBlockWall", "Bubblegum Block Wall"),
+    NameEntry::new(1593, "TitanstoneBlock", "Titanstone Block"),
+    NameEntry::new(1594, "TitanstoneBlockWall", "Titanstone Block Wall"),
+    NameEntry::new(1595, "MagicCuffs", "Magic Cuffs"),
+    NameEntry::new(1596, "MusicBoxSnow", "Music Box (Snow)"),
+    NameEntry::new(1597, "MusicBoxSpace", "Music Box (Space Night)"),
+    NameEntry::new(1598, "MusicBoxCrimson", "Music Box (Crimson)"),
+    NameEntry::new(1599, "MusicBoxBoss4", "Music Box (Boss 4)"),
+    NameEntry::new(1600, "MusicBoxAltOverworldDay", "Music Box (Alt Overworld Day)"),
+    NameEntry::new(1601, "MusicBoxRain", "Music Box (Rain)"),
+    NameEntry::new(1602, "MusicBoxIce", "Music Box (Ice)"),
+    NameEntry::new(1603, "MusicBoxDesert", "Music Box (Desert)"),
+    NameEntry::new(1604, "MusicBoxOcean", "Music Box (Ocean Day)"),
+    NameEntry::new(1605, "MusicBoxDungeon", "Music Box (Dungeon)"),
+    NameEntry::new(1606, "MusicBoxPlantera", "Music Box (Plantera)"),
+    NameEntry::new(1607, "MusicBoxBoss5", "Music Box (Boss 5)"),
+    NameEntry::new(1608, "MusicBoxTemple", "Music Box (Temple)"),
+    NameEntry::new(1609, "MusicBoxEclipse", "Music Box (Eclipse)"),
+    NameEntry::new(1610, "MusicBoxMushrooms", "Music Box (Mushrooms)"),
+    NameEntry::new(1611, "ButterflyDust", "Butterfly Dust"),
+    NameEntry::new(1612, "AnkhCharm", "Ankh Charm"),
+    NameEntry::new(1613, "AnkhShield", "Ankh Shield"),
+    NameEntry::new(1614, "BlueFlare", "Blue Flare"),
+    NameEntry::new(1615, "AnglerFishBanner", "Angler Fish Banner"),
+    NameEntry::new(1616, "AngryNimbusBanner", "Angry Nimbus Banner"),
+    NameEntry::new(1617, "AnomuraFungusBanner", "Anomura Fungus Banner"),
+    NameEntry::new(1618, "AntlionBanner", "Antlion Banner"),
+    NameEntry::new(1619, "ArapaimaBanner", "Arapaima Banner"),
+    NameEntry::new(1620, "ArmoredSkeletonBanner", "Armored Skeleton Banner"),
+    NameEntry::new(1621, "BatBanner", "Cave Bat Banner"),
+    NameEntry::new(1622, "BirdBanner", "Bird Banner"),
+    NameEntry::new(1623, "BlackRecluseBanner", "Black Recluse Banner"),
+    NameEntry::new(1624, "BloodFeederBanner", "Blood Feeder Banner"),
+    NameEntry::new(1625, "BloodJellyBanner", "Blood Jelly Banner"),
+    NameEntry::new(1626, "BloodCrawlerBanner", "Blood Crawler Banner"),
+    NameEntry::new(1627, "BoneSerpentBanner", "Bone Serpent Banner"),
+    NameEntry::new(1628, "BunnyBanner", "Bunny Banner"),
+    NameEntry::new(1629, "ChaosElementalBanner", "Chaos Elemental Banner"),
+    NameEntry::new(1630, "MimicBanner", "Mimic Banner"),
+    NameEntry::new(1631, "ClownBanner", "Clown Banner"),
+    NameEntry::new(1632, "CorruptBunnyBanner", "Corrupt Bunny Banner"),
+    NameEntry::new(1633, "CorruptGoldfishBanner", "Corrupt Goldfish Banner"),
+    NameEntry::new(1634, "CrabBanner", "Crab Banner"),
+    NameEntry::new(1635, "CrimeraBanner", "Crimera Banner"),
+    NameEntry::new(1636, "CrimsonAxeBanner", "Crimson Axe Banner"),
+    NameEntry::new(1637, "CursedHammerBanner", "Cursed Hammer Banner"),
+    NameEntry::new(1638, "DemonBanner", "Demon Banner"),
+    NameEntry::new(1639, "DemonEyeBanner", "Demon Eye Banner"),
+    NameEntry::new(1640, "DerplingBanner", "Derpling Banner"),
+    NameEntry::new(1641, "EaterofSoulsBanner", "Eater of Souls Banner"),
+    NameEntry::new(1642, "EnchantedSwordBanner", "Enchanted Sword Banner"),
+    NameEntry::new(1643, "ZombieEskimoBanner", "Frozen Zombie Banner"),
+    NameEntry::new(1644, "FaceMonsterBanner", "Face Monster Banner"),
+    NameEntry::new(1645, "FloatyGrossBanner", "Floaty Gross Banner"),
+    NameEntry::new(1646, "FlyingFishBanner", "Flying Fish Banner"),
+    NameEntry::new(1647, "FlyingSnakeBanner", "Flying Snake Banner"),
+    NameEntry::new(1648, "FrankensteinBanner", "Frankenstein Banner"),
+    NameEntry::new(1649, "FungiBulbBanner", "Fungi Bulb Banner"),
+    NameEntry::new(1650, "FungoFishBanner", "Fungo Fish Banner"),
+    NameEntry::new(1651, "GastropodBanner", "Gastropod Banner"),
+    NameEntry::new(1652, "GoblinThiefBanner", "Goblin Thief Banner"),
+    NameEntry::new(1653, "GoblinSorcererBanner", "Goblin Sorcerer Banner"),
+    NameEntry::new(1654, "GoblinPeonBanner", "Goblin Peon Banner"),
+    NameEntry::new(1655, "GoblinScoutBanner", "Goblin Scout Banner"),
+    NameEntry::new(1656, "GoblinWarriorBanner", "Goblin Warrior Banner"),
+    NameEntry::new(1657, "GoldfishBanner", "Goldfish Banner"),
+    NameEntry::new(1658, "HarpyBanner", "Harpy Banner"),
+    NameEntry::new(1659, "HellbatBanner", "Hellbat Banner"),
+    NameEntry::new(1660, "HerplingBanner", "Herpling Banner"),
+    NameEntry::new(1661, "HornetBanner", "Hornet Banner"),
+    NameEntry::new(1662, "IceElementalBanner", "Ice Elemental Banner"),
+    NameEntry::new(1663, "IcyMermanBanner", "Icy Merman Banner"),
+    NameEntry::new(1664, "FireImpBanner", "Fire Imp Banner"),
+    NameEntry::new(1665, "JellyfishBanner", "Blue Jellyfish Banner"),
+    NameEntry::new(1666, "JungleCreeperBanner", "Jungle Creeper Banner"),
+    NameEntry::new(1667, "LihzahrdBanner", "Lihzahrd Banner"),
+    NameEntry::new(1668, "ManEaterBanner", "Man Eater Banner"),
+    NameEntry::new(1669, "MeteorHeadBanner", "Meteor Head Banner"),
+    NameEntry::new(1670, "MothBanner", "Moth Banner"),
+    NameEntry::new(1671, "MummyBanner", "Mummy Banner"),
+    NameEntry::new(1672, "MushiLadybugBanner", "Mushi Ladybug Banner"),
+    NameEntry::new(1673, "ParrotBanner", "Parrot Banner"),
+    NameEntry::new(1674, "PigronBanner", "Pigron Banner"),
+    NameEntry::new(1675, "PiranhaBanner", "Piranha Banner"),
+    NameEntry::new(1676, "PirateBanner", "Pirate Deckhand Banner"),
+    NameEntry::new(1677, "PixieBanner", "Pixie Banner"),
+    NameEntry::new(1678, "RaincoatZombieBanner", "Raincoat Zombie Banner"),
+    NameEntry::new(1679, "ReaperBanner", "Reaper Banner"),
+    NameEntry::new(1680, "SharkBanner", "Shark Banner"),
+    NameEntry::new(1681, "SkeletonBanner", "Skeleton Banner"),
+    NameEntry::new(1682, "SkeletonMageBanner", "Dark Caster Banner"),
+    NameEntry::new(1683, "SlimeBanner", "Blue Slime Banner"),
+    NameEntry::new(1684, "SnowFlinxBanner", "Snow Flinx Banner"),
+    NameEntry::new(1685, "SpiderBanner", "Wall Creeper Banner"),
+    NameEntry::new(1686, "SporeZombieBanner", "Spore Zombie Banner"),
+    NameEntry::new(1687, "SwampThingBanner", "Swamp Thing Banner"),
+    NameEntry::new(1688, "TortoiseBanner", "Giant Tortoise Banner"),
+    NameEntry::new(1689, "ToxicSludgeBanner", "Toxic Sludge Banner"),
+    NameEntry::new(1690, "UmbrellaSlimeBanner", "Umbrella Slime Banner"),
+    NameEntry::new(1691, "UnicornBanner", "Unicorn Banner"),
+    NameEntry::new(1692, "VampireBanner", "Vampire Banner"),
+    NameEntry::new(1693, "VultureBanner", "Vulture Banner"),
+    NameEntry::new(1694, "NypmhBanner", "Nymph Banner"),
+    NameEntry::new(1695, "WerewolfBanner", "Werewolf Banner"),
+    NameEntry::new(1696, "WolfBanner", "Wolf Banner"),
+    NameEntry::new(1697, "WorldFeederBanner", "World Feeder Banner"),
+    NameEntry::new(1698, "WormBanner", "Worm Banner"),
+    NameEntry::new(1699, "WraithBanner", "Wraith Banner"),
+    NameEntry::new(1700, "WyvernBanner", "Wyvern Banner"),
+    NameEntry::new(1701, "ZombieBanner", "Zombie Banner"),
+    NameEntry::new(1702, "GlassPlatform", "Glass Platform"),
+    NameEntry::new(1703, "GlassChair", "Glass Chair"),
+    NameEntry::new(1704, "GoldenChair", "Golden Chair"),
+    NameEntry::new(1705, "GoldenToilet", "Golden Toilet"),
+    NameEntry::new(1706, "BarStool", "Bar Stool"),
+    NameEntry::new(1707, "HoneyChair", "Honey Chair"),
+    NameEntry::new(1708, "SteampunkChair", "Steampunk Chair"),
+    NameEntry::new(1709, "GlassDoor", "Glass Door"),
+    NameEntry::new(1710, "GoldenDoor", "Golden Door"),
+    NameEntry::new(1711, "HoneyDoor", "Honey Door"),
+    NameEntry::new(1712, "SteampunkDoor", "Steampunk Door"),
+    NameEntry::new(1713, "GlassTable", "Glass Table"),
+    NameEntry::new(1714, "BanquetTable", "Banquet Table"),
+    NameEntry::new(1715, "Bar", "Bar"),
+    NameEntry::new(1716, "GoldenTable", "Golden Table"),
+    NameEntry::new(1717, "HoneyTable", "Honey Table"),
+    NameEntry::new(1718, "SteampunkTable", "Steampunk Table"),
+    NameEntry::new(1719, "GlassBed", "Glass Bed"),
+    NameEntry::new(1720, "GoldenBed", "Golden Bed"),
+    NameEntry::new(1721, "HoneyBed", "Honey Bed"),
+    NameEntry::new(1722, "SteampunkBed", "Steampunk Bed"),
+    NameEntry::new(1723, "LivingWoodWall", "Living Wood Wall"),
+    NameEntry::new(1724, "FartinaJar", "Fart in a Jar"),
+    NameEntry::new(1725, "Pumpkin", "Pumpkin"),
+    NameEntry::new(1726, "PumpkinWall", "Pumpkin Wall"),
+    NameEntry::new(1727, "Hay", "Hay"),
+    NameEntry::new(1728, "HayWall", "Hay Wall"),
+    NameEntry::new(1729, "SpookyWood", "Spooky Wood"),
+    NameEntry::new(1730, "SpookyWoodWall", "Spooky Wood Wall"),
+    NameEntry::new(1731, "PumpkinHelmet", "Pumpkin Helmet"),
+    NameEntry::new(1732, "PumpkinBreastplate", "Pumpkin Breastplate"),
+    NameEntry::new(1733, "PumpkinLeggings", "Pumpkin Leggings"),
+    NameEntry::new(1734, "CandyApple", "Candy Apple"),
+    NameEntry::new(1735, "SoulCake", "Soul Cake"),
+    NameEntry::new(1736, "NurseHat", "Nurse Hat"),
+    NameEntry::new(1737, "NurseShirt", "Nurse Shirt"),
+    NameEntry::new(1738, "NursePants", "Nurse Pants"),
+    NameEntry::new(1739, "WizardsHat", "Wizard's Hat"),
+    NameEntry::new(1740, "GuyFawkesMask", "Guy Fawkes Mask"),
+    NameEntry::new(1741, "DyeTraderRobe", "Dye Trader Robe"),
+    NameEntry::new(1742, "SteampunkGoggles", "Steampunk Goggles"),
+    NameEntry::new(1743, "CyborgHelmet", "Cyborg Helmet"),
+    NameEntry::new(1744, "CyborgShirt", "Cyborg Shirt"),
+    NameEntry::new(1745, "CyborgPants", "Cyborg Pants"),
+    NameEntry::new(1746, "CreeperMask", "Creeper Mask"),
+    NameEntry::new(1747, "CreeperShirt", "Creeper Shirt"),
+    NameEntry::new(1748, "CreeperPants", "Creeper Pants"),
+    NameEntry::new(1749, "CatMask", "Cat Mask"),
+    NameEntry::new(1750, "CatShirt", "Cat Shirt"),
+    NameEntry::new(1751, "CatPants", "Cat Pants"),
+    NameEntry::new(1752, "GhostMask", "Ghost Mask"),
+    NameEntry::new(1753, "GhostShirt", "Ghost Shirt"),
+    NameEntry::new(1754, "PumpkinMask", "Pumpkin Mask"),
+    NameEntry::new(1755, "PumpkinShirt", "Pumpkin Shirt"),
+    NameEntry::new(1756, "PumpkinPants", "Pumpkin Pants"),
+    NameEntry::new(1757, "RobotMask", "Robot Mask"),
+    NameEntry::new(1758, "RobotShirt", "Robot Shirt"),
+    NameEntry::new(1759, "RobotPants", "Robot Pants"),
+    NameEntry::new(1760, "UnicornMask", "Unicorn Mask"),
+    NameEntry::new(1761, "UnicornShirt", "Unicorn Shirt"),
+    NameEntry::new(1762, "UnicornPants", "Unicorn Pants"),
+    NameEntry::new(1763, "VampireMask", "Vampire Mask"),
+    NameEntry::new(1764, "VampireShirt", "Vampire Shirt"),
+    NameEntry::new(1765, "VampirePants", "Vampire Pants"),
+    NameEntry::new(1766, "WitchHat", "Witch Hat"),
+    NameEntry::new(1767, "LeprechaunHat", "Leprechaun Hat"),
+    NameEntry::new(1768, "LeprechaunShirt", "Leprechaun Shirt"),
+    NameEntry::new(1769, "LeprechaunPants", "Leprechaun Pants"),
+    NameEntry::new(1770, "PixieShirt", "Pixie Shirt"),
+    NameEntry::new(1771, "PixiePants", "Pixie Pants"),
+    NameEntry::new(1772, "PrincessHat", "Princess Hat"),
+    NameEntry::new(1773, "PrincessDressNew", "Princess Dress"),
+    NameEntry::new(1774, "GoodieBag", "Goodie Bag"),
+    NameEntry::new(1775, "WitchDress", "Witch Dress"),
+    NameEntry::new(1776, "WitchBoots", "Witch Boots"),
+    NameEntry::new(1777, "BrideofFrankensteinMask", "Bride of Frankenstein Mask"),
+    NameEntry::new(1778, "BrideofFrankensteinDress", "Bride of Frankenstein Dress"),
+    NameEntry::new(1779, "KarateTortoiseMask", "Karate Tortoise Mask"),
+    NameEntry::new(1780, "KarateTortoiseShirt", "Karate Tortoise Shirt"),
+    NameEntry::new(1781, "KarateTortoisePants", "Karate Tortoise Pants"),
+    NameEntry::new(1782, "CandyCornRifle", "Candy Corn Rifle"),
+    NameEntry::new(1783, "CandyCorn", "Candy Corn"),
+    NameEntry::new(1784, "JackOLanternLauncher", "Jack 'O Lantern Launcher"),
+    NameEntry::new(1785, "ExplosiveJackOLantern", "Explosive Jack 'O Lantern"),
+    NameEntry::new(1786, "Sickle", "Sickle"),
+    NameEntry::new(1787, "PumpkinPie", "Pumpkin Pie"),
+    NameEntry::new(1788, "ScarecrowHat", "Scarecrow Hat"),
+    NameEntry::new(1789, "ScarecrowShirt", "Scarecrow Shirt"),
+    NameEntry::new(1790, "ScarecrowPants", "Scarecrow Pants"),
+    NameEntry::new(1791, "Cauldron", "Cauldron"),
+    NameEntry::new(1792, "PumpkinChair", "Pumpkin Chair"),
+    NameEntry::new(1793, "PumpkinDoor", "Pumpkin Door"),
+    NameEntry::new(1794, "PumpkinTable", "Pumpkin Table"),
+    NameEntry::new(1795, "PumpkinWorkBench", "Pumpkin Work Bench"),
+    NameEntry::new(1796, "PumpkinPlatform", "Pumpkin Platform"),
+    NameEntry::new(1797, "TatteredFairyWings", "Tattered Fairy Wings"),
+    NameEntry::new(1798, "SpiderEgg", "Spider Egg"),
+    NameEntry::new(1799, "MagicalPumpkinSeed", "Magical Pumpkin Seed"),
+    NameEntry::new(1800, "BatHook", "Bat Hook"),
+    NameEntry::new(1801, "BatScepter", "Bat Scepter"),
+    NameEntry::new(1802, "RavenStaff", "Raven Staff"),
+    NameEntry::new(1803, "JungleKeyMold", "Jungle Key Mold"),
+    NameEntry::new(1804, "CorruptionKeyMold", "Corruption Key Mold"),
+    NameEntry::new(1805, "CrimsonKeyMold", "Crimson Key Mold"),
+    NameEntry::new(1806, "HallowedKeyMold", "Hallowed Key Mold"),
+    NameEntry::new(1807, "FrozenKeyMold", "Frozen Key Mold"),
+    NameEntry::new(1808, "HangingJackOLantern", "Hanging Jack 'O Lantern"),
+    NameEntry::new(1809, "RottenEgg", "Rotten Egg"),
+    NameEntry::new(1810, "UnluckyYarn", "Unlucky Yarn"),
+    NameEntry::new(1811, "BlackFairyDust", "Black Fairy Dust"),
+    NameEntry::new(1812, "Jackelier", "Jackelier"),
+    NameEntry::new(1813, "JackOLantern", "Jack 'O Lantern"),
+    NameEntry::new(1814, "SpookyChair", "Spooky Chair"),
+    NameEntry::new(1815, "SpookyDoor", "Spooky Door"),
+    NameEntry::new(1816, "SpookyTable", "Spooky Table"),
+    NameEntry::new(1817, "SpookyWorkBench", "Spooky Work Bench"),
+    NameEntry::new(1818, "SpookyPlatform", "Spooky Wood Platform"),
+    NameEntry::new(1819, "ReaperHood", "Reaper Hood"),
+    NameEntry::new(1820, "ReaperRobe", "Reaper Robe"),
+    NameEntry::new(1821, "FoxMask", "Fox Mask"),
+    NameEntry::new(1822, "FoxShirt", "Fox Shirt"),
+    NameEntry::new(1823, "FoxPants", "Fox Pants"),
+    NameEntry::new(1824, "CatEars", "Cat Ears"),
+    NameEntry::new(1825, "BloodyMachete", "Bloody Machete"),
+    NameEntry::new(1826, "TheHorsemansBlade", "The Horseman's Blade"),
+    NameEntry::new(1827, "BladedGlove", "Bladed Glove"),
+    NameEntry::new(1828, "PumpkinSeed", "Pumpkin Seed"),
+    NameEntry::new(1829, "SpookyHook", "Spooky Hook"),
+    NameEntry::new(1830, "SpookyWings", "Spooky Wings"),
+    NameEntry::new(1831, "SpookyTwig", "Spooky Twig"),
+    NameEntry::new(1832, "SpookyHelmet", "Spooky Helmet"),
+    NameEntry::new(1833, "SpookyBreastplate", "Spooky Breastplate"),
+    NameEntry::new(1834, "SpookyLeggings", "Spooky Leggings"),
+    NameEntry::new(1835, "StakeLauncher", "Stake Launcher"),
+    NameEntry::new(1836, "Stake", "Stake"),
+    NameEntry::new(1837, "CursedSapling", "Cursed Sapling"),
+    NameEntry::new(1838, "SpaceCreatureMask", "Space Creature Mask"),
+    NameEntry::new(1839, "SpaceCreatureShirt", "Space Creature Shirt"),
+    NameEntry::new(1840, "SpaceCreaturePants", "Space Creature Pants"),
+    NameEntry::new(1841, "WolfMask", "Wolf Mask"),
+    NameEntry::new(1842, "WolfShirt", "Wolf Shirt"),
+    NameEntry::new(1843, "WolfPants", "Wolf Pants"),
+    NameEntry::new(1844, "PumpkinMoonMedallion", "Pumpkin Moon Medallion"),
+    NameEntry::new(1845, "NecromanticScroll", "Necromantic Scroll"),
+    NameEntry::new(1846, "JackingSkeletron", "Jacking Skeletron"),
+    NameEntry::new(1847, "BitterHarvest", "Bitter Harvest"),
+    NameEntry::new(1848, "BloodMoonCountess", "Blood Moon Countess"),
+    NameEntry::new(1849, "HallowsEve", "Hallow's Eve"),
+    NameEntry::new(1850, "MorbidCuriosity", "Morbid Curiosity"),
+    NameEntry::new(1851, "TreasureHunterShirt", "Treasure Hunter Shirt"),
+    NameEntry::new(1852, "TreasureHunterPants", "Treasure Hunter Pants"),
+    NameEntry::new(1853, "DryadCoverings", "Dryad Coverings"),
+    NameEntry::new(1854, "DryadLoincloth", "Dryad Loincloth"),
+    NameEntry::new(1855, "MourningWoodTrophy", "Mourning Wood Trophy"),
+    NameEntry::new(1856, "PumpkingTrophy", "Pumpking Trophy"),
+    NameEntry::new(1857, "JackOLanternMask", "Jack 'O Lantern Mask"),
+    NameEntry::new(1858, "SniperScope", "Sniper Scope"),
+    NameEntry::new(1859, "HeartLantern", "Heart Lantern"),
+    NameEntry::new(1860, "JellyfishDivingGear", "Jellyfish Diving Gear"),
+    NameEntry::new(1861, "ArcticDivingGear", "Arctic Diving Gear"),
+    NameEntry::new(1862, "FrostsparkBoots", "Frostspark Boots"),
+    NameEntry::new(1863, "FartInABalloon", "Fart in a Balloon"),
+    NameEntry::new(1864, "PapyrusScarab", "Papyrus Scarab"),
+    NameEntry::new(1865, "CelestialStone", "Celestial Stone"),
+    NameEntry::new(1866, "Hoverboard", "Hoverboard"),
+    NameEntry::new(1867, "CandyCane", "Candy Cane"),
+    NameEntry::new(1868, "SugarPlum", "Sugar Plum"),
+    NameEntry::new(1869, "Present", "Present"),
+    NameEntry::new(1870, "RedRyder", "Red Ryder"),
+    NameEntry::new(1871, "FestiveWings", "Festive Wings"),
+    NameEntry::new(1872, "PineTreeBlock", "Pine Tree Block"),
+    NameEntry::new(1873, "ChristmasTree", "Christmas Tree"),
+    NameEntry::new(1874, "StarTopper1", "Star Topper 1"),
+    NameEntry::new(1875, "StarTopper2", "Star Topper 2"),
+    NameEntry::new(1876, "StarTopper3", "Star Topper 3"),
+    NameEntry::new(1877, "BowTopper", "Bow Topper"),
+    NameEntry::new(1878, "WhiteGarland", "White Garland"),
+    NameEntry::new(1879, "WhiteAndRedGarland", "White and Red Garland"),
+    NameEntry::new(1880, "RedGardland", "Red Garland"),
+    NameEntry::new(1881, "RedAndGreenGardland", "Red and Green Garland"),
+    NameEntry::new(1882, "GreenGardland", "Green Garland"),
+    NameEntry::new(1883, "GreenAndWhiteGarland", "Green and White Garland"),
+    NameEntry::new(1884, "MulticoloredBulb", "Multicolored Bulb"),
+    NameEntry::new(1885, "RedBulb", "Red Bulb"),
+    NameEntry::new(1886, "YellowBulb", "Yellow Bulb"),
+    NameEntry::new(1887, "GreenBulb", "Green Bulb"),
+    NameEntry::new(1888, "RedAndGreenBulb", "Red and Green Bulb"),
+    NameEntry::new(1889, "YellowAndGreenBulb", "Yellow and Green Bulb"),
+    NameEntry::new(1890, "RedAndYellowBulb", "Red and Yellow Bulb"),
+    NameEntry::new(1891, "WhiteBulb", "White Bulb"),
+    NameEntry::new(1892, "WhiteAndRedBulb", "White and Red Bulb"),
+    NameEntry::new(1893, "WhiteAndYellowBulb", "White and Yellow Bulb"),
+    NameEntry::new(1894, "WhiteAndGreenBulb", "White and Green Bulb"),
+    NameEntry::new(1895, "MulticoloredLights", "Multicolored Lights"),
+    NameEntry::new(1896, "RedLights", "Red Lights"),
+    NameEntry::new(1897, "GreenLights", "Green Lights"),
+    NameEntry::new(1898, "BlueLights", "Blue Lights"),
+    NameEntry::new(1899, "YellowLights", "Yellow Lights"),
+    NameEntry::new(1900, "RedAndYellowLights", "Red and Yellow Lights"),
+    NameEntry::new(1901, "RedAndGreenLights", "Red and Green Lights"),
+    NameEntry::new(1902, "YellowAndGreenLights", "Yellow and Green Lights"),
+    NameEntry::new(1903, "BlueAndGreenLights", "Blue and Green Lights"),
+    NameEntry::new(1904, "RedAndBlueLights", "Red and Blue Lights"),
+    NameEntry::new(1905, "BlueAndYellowLights", "Blue and Yellow Lights"),
+    NameEntry::new(1906, "GiantBow", "Giant Bow"),
+    NameEntry::new(1907, "ReindeerAntlers", "Reindeer Antlers"),
+    NameEntry::new(1908, "Holly", "Holly"),
+    NameEntry::new(1909, "CandyCaneSword", "Candy Cane Sword"),
+    NameEntry::new(1910, "ElfMelter", "Elf Melter"),
+    NameEntry::new(1911, "ChristmasPudding", "Christmas Pudding"),
+    NameEntry::new(1912, "Eggnog", "Eggnog"),
+    NameEntry::new(1913, "StarAnise", "Star Anise"),
+    NameEntry::new(1914, "ReindeerBells", "Reindeer Bells"),
+    NameEntry::new(1915, "CandyCaneHook", "Candy Cane Hook"),
+    NameEntry::new(1916, "ChristmasHook", "Christmas Hook"),
+    NameEntry::new(1917, "CnadyCanePickaxe", "Candy Cane Pickaxe"),
+    NameEntry::new(1918, "FruitcakeChakram", "Fruitcake Chakram"),
+    NameEntry::new(1919, "SugarCookie", "Sugar Cookie"),
+    NameEntry::new(1920, "GingerbreadCookie", "Gingerbread Cookie"),
+    NameEntry::new(1921, "HandWarmer", "Hand Warmer"),
+    NameEntry::new(1922, "Coal", "Coal"),
+    NameEntry::new(1923, "Toolbox", "Toolbox"),
+    NameEntry::new(1924, "PineDoor", "Pine Door"),
+    NameEntry::new(1925, "PineChair", "Pine Chair"),
+    NameEntry::new(1926, "PineTable", "Pine Table"),
+    NameEntry::new(1927, "DogWhistle", "Dog Whistle"),
+    NameEntry::new(1928, "ChristmasTreeSword", "Christmas Tree Sword"),
+    NameEntry::new(1929, "ChainGun", "Chain Gun"),
+    NameEntry::new(1930, "Razorpine", "Razorpine"),
+    NameEntry::new(1931, "BlizzardStaff", "Blizzard Staff"),
+    NameEntry::new(1932, "MrsClauseHat", "Mrs. Claus Hat"),
+    NameEntry::new(1933, "MrsClauseShirt", "Mrs. Claus Shirt"),
+    NameEntry::new(1934, "MrsClauseHeels", "Mrs. Claus Heels"),
+    NameEntry::new(1935, "ParkaHood", "Parka Hood"),
+    NameEntry::new(1936, "ParkaCoat", "Parka Coat"),
+    NameEntry::new(1937, "ParkaPants", "Parka Pants"),
+    NameEntry::new(1938, "SnowHat", "Snow Hat"),
+    NameEntry::new(1939, "UglySweater", "Ugly Sweater"),
+    NameEntry::new(1940, "TreeMask", "Tree Mask"),
+    NameEntry::new(1941, "TreeShirt", "Tree Shirt"),
+    NameEntry::new(1942, "TreeTrunks", "Tree Trunks"),
+    NameEntry::new(1943, "ElfHat", "Elf Hat"),
+    NameEntry::new(1944, "ElfShirt", "Elf Shirt"),
+    NameEntry::new(1945, "ElfPants", "Elf Pants"),
+    NameEntry::new(1946, "SnowmanCannon", "Snowman Cannon"),
+    NameEntry::new(1947, "NorthPole", "North Pole"),
+    NameEntry::new(1948, "ChristmasTreeWallpaper", "Christmas Tree Wallpaper"),
+    NameEntry::new(1949, "OrnamentWallpaper", "Ornament Wallpaper"),
+    NameEntry::new(1950, "CandyCaneWallpaper", "Candy Cane Wallpaper"),
+    NameEntry::new(1951, "FestiveWallpaper", "Festive Wallpaper"),
+    NameEntry::new(1952, "StarsWallpaper", "Stars Wallpaper"),
+    NameEntry::new(1953, "SquigglesWallpaper", "Squiggles Wallpaper"),
+    NameEntry::new(1954, "SnowflakeWallpaper", "Snowflake Wallpaper"),
+    NameEntry::new(1955, "KrampusHornWallpaper", "Krampus Horn Wallpaper"),
+    NameEntry::new(1956, "BluegreenWallpaper", "Bluegreen Wallpaper"),
+    NameEntry::new(1957, "GrinchFingerWallpaper", "Grinch Finger Wallpaper"),
+    NameEntry::new(1958, "NaughtyPresent", "Naughty Present"),
+    NameEntry::new(1959, "BabyGrinchMischiefWhistle", "Baby Grinch's Mischief Whistle"),
+    NameEntry::new(1960, "IceQueenTrophy", "Ice Queen Trophy"),
+    NameEntry::new(1961, "SantaNK1Trophy", "Santa-NK1 Trophy"),
+    NameEntry::new(1962, "EverscreamTrophy", "Everscream Trophy"),
+    NameEntry::new(1963, "MusicBoxPumpkinMoon", "Music Box (Pumpkin Moon)"),
+    NameEntry::new(1964, "MusicBoxAltUnderground", "Music Box (Alt Underground)"),
+    NameEntry::new(1965, "MusicBoxFrostMoon", "Music Box (Frost Moon)"),
+    NameEntry::new(1966, "BrownPaint", "Brown Paint"),
+    NameEntry::new(1967, "ShadowPaint", "Shadow Paint"),
+    NameEntry::new(1968, "NegativePaint", "Negative Paint"),
+    NameEntry::new(1969, "TeamDye", "Team Dye"),
+    NameEntry::new(1970, "AmethystGemsparkBlock", "Amethyst Gemspark Block"),
+    NameEntry::new(1971, "TopazGemsparkBlock", "Topaz Gemspark Block"),
+    NameEntry::new(1972, "SapphireGemsparkBlock", "Sapphire Gemspark Block"),
+    NameEntry::new(1973, "EmeraldGemsparkBlock", "Emerald Gemspark Block"),
+    NameEntry::new(1974, "RubyGemsparkBlock", "Ruby Gemspark Block"),
+    NameEntry::new(1975, "DiamondGemsparkBlock", "Diamond Gemspark Block"),
+    NameEntry::new(1976, "AmberGemsparkBlock", "Amber Gemspark Block"),
+    NameEntry::new(1977, "LifeHairDye", "Life Hair Dye"),
+    NameEntry::new(1978, "ManaHairDye", "Mana Hair Dye"),
+    NameEntry::new(1979, "DepthHairDye", "Depth Hair Dye"),
+    NameEntry::new(1980, "MoneyHairDye", "Money Hair Dye"),
+    NameEntry::new(1981, "TimeHairDye", "Time Hair Dye"),
+    NameEntry::new(1982, "TeamHairDye", "Team Hair Dye"),
+    NameEntry::new(1983, "BiomeHairDye", "Biome Hair Dye"),
+    NameEntry::new(1984, "PartyHairDye", "Party Hair Dye"),
+    NameEntry::new(1985, "RainbowHairDye", "Rainbow Hair Dye"),
+    NameEntry::new(1986, "SpeedHairDye", "Speed Hair Dye"),
+    NameEntry::new(1987, "AngelHalo", "Angel Halo"),
+    NameEntry::new(1988, "Fez", "Fez"),
+    NameEntry::new(1989, "Womannquin", "Womannequin"),
+    NameEntry::new(1990, "HairDyeRemover", "Hair Dye Remover"),
+    NameEntry::new(1991, "BugNet", "Bug Net"),
+    NameEntry::new(1992, "Firefly", "Firefly"),
+    NameEntry::new(1993, "FireflyinaBottle", "Firefly in a Bottle"),
+    NameEntry::new(1994, "MonarchButterfly", "Monarch Butterfly"),
+    NameEntry::new(1995, "PurpleEmperorButterfly", "Purple Emperor Butterfly"),
+    NameEntry::new(1996, "RedAdmiralButterfly", "Red Admiral Butterfly"),
+    NameEntry::new(1997, "UlyssesButterfly", "Ulysses Butterfly"),
+    NameEntry::new(1998, "SulphurButterfly", "Sulphur Butterfly"),
+    NameEntry::new(1999, "TreeNymphButterfly", "Tree Nymph Butterfly"),
+    NameEntry::new(2000, "ZebraSwallowtailButterfly", "Zebra Swallowtail Butterfly"),
+    NameEntry::new(2001, "JuliaButterfly", "Julia Butterfly"),
+    NameEntry::new(2002, "Worm", "Worm"),
+    NameEntry::new(2003, "Mouse", "Mouse"),
+    NameEntry::new(2004, "LightningBug", "Lightning Bug"),
+    NameEntry::new(2005, "LightningBuginaBottle", "Lightning Bug in a Bottle"),
+    NameEntry::new(2006, "Snail", "Snail"),
+    NameEntry::new(2007, "GlowingSnail", "Glowing Snail"),
+    NameEntry::new(2008, "FancyGreyWallpaper", "Fancy Gray Wallpaper"),
+    NameEntry::new(2009, "IceFloeWallpaper", "Ice Floe Wallpaper"),
+    NameEntry::new(2010, "MusicWallpaper", "Music Wallpaper"),
+    NameEntry::new(2011, "PurpleRainWallpaper", "Purple Rain Wallpaper"),
+    NameEntry::new(2012, "RainbowWallpaper", "Rainbow Wallpaper"),
+    NameEntry::new(2013, "SparkleStoneWallpaper", "Sparkle Stone Wallpaper"),
+    NameEntry::new(2014, "StarlitHeavenWallpaper", "Starlit Heaven Wallpaper"),
+    NameEntry::new(2015, "Bird", "Bird"),
+    NameEntry::new(2016, "BlueJay", "Blue Jay"),
+    NameEntry::new(2017, "Cardinal", "Cardinal"),
+    NameEntry::new(2018, "Squirrel", "Squirrel"),
+    NameEntry::new(2019, "Bunny", "Bunny"),
+    NameEntry::new(2020, "CactusBookcase", "Cactus Bookcase"),
+    NameEntry::new(2021, "EbonwoodBookcase", "Ebonwood Bookcase"),
+    NameEntry::new(2022, "FleshBookcase", "Flesh Bookcase"),
+    NameEntry::new(2023, "HoneyBookcase", "Honey Bookcase"),
+    NameEntry::new(2024, "SteampunkBookcase", "Steampunk Bookcase"),
+    NameEntry::new(2025, "GlassBookcase", "Glass Bookcase"),
+    NameEntry::new(2026, "RichMahoganyBookcase", "Rich Mahogany Bookcase"),
+    NameEntry::new(2027, "PearlwoodBookcase", "Pearlwood Bookcase"),
+    NameEntry::new(2028, "SpookyBookcase", "Spooky Bookcase"),
+    NameEntry::new(2029, "SkywareBookcase", "Skyware Bookcase"),
+    NameEntry::new(2030, "LihzahrdBookcase", "Lihzahrd Bookcase"),
+    NameEntry::new(2031, "FrozenBookcase", "Frozen Bookcase"),
+    NameEntry::new(2032, "CactusLantern", "Cactus Lantern"),
+    NameEntry::new(2033, "EbonwoodLantern", "Ebonwood Lantern"),
+    NameEntry::new(2034, "FleshLantern", "Flesh Lantern"),
+    NameEntry::new(2035, "HoneyLantern", "Honey Lantern"),
+    NameEntry::new(2036, "SteampunkLantern", "Steampunk Lantern"),
+    NameEntry::new(2037, "GlassLantern", "Glass Lantern"),
+    NameEntry::new(2038, "RichMahoganyLantern", "Rich Mahogany Lantern"),
+    NameEntry::new(2039, "PearlwoodLantern", "Pearlwood Lantern"),
+    NameEntry::new(2040, "FrozenLantern", "Frozen Lantern"),
+    NameEntry::new(2041, "LihzahrdLantern", "Lihzahrd Lantern"),
+    NameEntry::new(2042, "SkywareLantern", "Skyware Lantern"),
+    NameEntry::new(2043, "SpookyLantern", "Spooky Lantern"),
+    NameEntry::new(2044, "FrozenDoor", "Frozen Door"),
+    NameEntry::new(2045, "CactusCandle", "Cactus Candle"),
+    NameEntry::new(2046, "EbonwoodCandle", "Ebonwood Candle"),
+    NameEntry::new(2047, "FleshCandle", "Flesh Candle"),
+    NameEntry::new(2048, "GlassCandle", "Glass Candle"),
+    NameEntry::new(2049, "FrozenCandle", "Frozen Candle"),
+    NameEntry::new(2050, "RichMahoganyCandle", "Rich Mahogany Candle"),
+    NameEntry::new(2051, "PearlwoodCandle", "Pearlwood Candle"),
+    NameEntry::new(2052, "LihzahrdCandle", "Lihzahrd Candle"),
+    NameEntry::new(2053, "SkywareCandle", "Skyware Candle"),
+    NameEntry::new(2054, "PumpkinCandle", "Pumpkin Candle"),
+    NameEntry::new(2055, "CactusChandelier", "Cactus Chandelier"),
+    NameEntry::new(2056, "EbonwoodChandelier", "Ebonwood Chandelier"),
+    NameEntry::new(2057, "FleshChandelier", "Flesh Chandelier"),
+    NameEntry::new(2058, "HoneyChandelier", "Honey Chandelier"),
+    NameEntry::new(2059, "FrozenChandelier", "Frozen Chandelier"),
+    NameEntry::new(2060, "RichMahoganyChandelier", "Rich Mahogany Chandelier"),
+    NameEntry::new(2061, "PearlwoodChandelier", "Pearlwood Chandelier"),
+    NameEntry::new(2062, "LihzahrdChandelier", "Lihzahrd Chandelier"),
+    NameEntry::new(2063, "SkywareChandelier", "Skyware Chandelier"),
+    NameEntry::new(2064, "SpookyChandelier", "Spooky Chandelier"),
+    NameEntry::new(2065, "GlassChandelier", "Glass Chandelier"),
+    NameEntry::new(2066, "CactusBed", "Cactus Bed"),
+    NameEntry::new(2067, "FleshBed", "Flesh Bed"),
+    NameEntry::new(2068, "FrozenBed", "Frozen Bed"),
+    NameEntry::new(2069, "LihzahrdBed", "Lihzahrd Bed"),
+    NameEntry::new(2070, "SkywareBed", "Skyware Bed"),
+    NameEntry::new(2071, "SpookyBed", "Spooky Bed"),
+    NameEntry::new(2072, "CactusBathtub", "Cactus Bathtub"),
+    NameEntry::new(2073, "EbonwoodBathtub", "Ebonwood Bathtub"),
+    NameEntry::new(2074, "FleshBathtub", "Flesh Bathtub"),
+    NameEntry::new(2075, "GlassBathtub", "Glass Bathtub"),
+    NameEntry::new(2076, "FrozenBathtub", "Frozen Bathtub"),
+    NameEntry::new(2077, "RichMahoganyBathtub", "Rich Mahogany Bathtub"),
+    NameEntry::new(2078, "PearlwoodBathtub", "Pearlwood Bathtub"),
+    NameEntry::new(2079, "LihzahrdBathtub", "Lihzahrd Bathtub"),
+    NameEntry::new(2080, "SkywareBathtub", "Skyware Bathtub"),
+    NameEntry::new(2081, "SpookyBathtub", "Spooky Bathtub"),
+    NameEntry::new(2082, "CactusLamp", "Cactus Lamp"),
+    NameEntry::new(2083, "EbonwoodLamp", "Ebonwood Lamp"),
+    NameEntry::new(2084, "FleshLamp", "Flesh Lamp"),
+    NameEntry::new(2085, "GlassLamp", "Glass Lamp"),
+    NameEntry::new(2086, "FrozenLamp", "Frozen Lamp"),
+    NameEntry::new(2087, "RichMahoganyLamp", "Rich Mahogany Lamp"),
+    NameEntry::new(2088, "PearlwoodLamp", "Pearlwood Lamp"),
+    NameEntry::new(2089, "LihzahrdLamp", "Lihzahrd Lamp"),
+    NameEntry::new(2090, "SkywareLamp", "Skyware Lamp"),
+    NameEntry::new(2091, "SpookyLamp", "Spooky Lamp"),
+    NameEntry::new(2092, "CactusCandelabra", "Cactus Candelabra"),
+    NameEntry::new(2093, "EbonwoodCandelabra", "Ebonwood Candelabra"),
+    NameEntry::new(2094, "FleshCandelabra", "Flesh Candelabra"),
+    NameEntry::new(2095, "HoneyCandelabra", "Honey Candelabra"),
+    NameEntry::new(2096, "SteampunkCandelabra", "Steampunk Candelabra"),
+    NameEntry::new(2097, "GlassCandelabra", "Glass Candelabra"),
+    NameEntry::new(2098, "RichMahoganyCandelabra", "Rich Mahogany Candelabra"),
+    NameEntry::new(2099, "PearlwoodCandelabra", "Pearlwood Candelabra"),
+    NameEntry::new(2100, "FrozenCandelabra", "Frozen Candelabra"),
+    NameEntry::new(2101, "LihzahrdCandelabra", "Lihzahrd Candelabra"),
+    NameEntry::new(2102, "SkywareCandelabra", "Skyware Candelabra"),
+    NameEntry::new(2103, "SpookyCandelabra", "Spooky Candelabra"),
+    NameEntry::new(2104, "BrainMask", "Brain of Cthulhu Mask"),
+    NameEntry::new(2105, "FleshMask", "Wall of Flesh Mask"),
+    NameEntry::new(2106, "TwinMask", "Twin Mask"),
+    NameEntry::new(2107, "SkeletronPrimeMask", "Skeletron Prime Mask"),
+    NameEntry::new(2108, "BeeMask", "Queen Bee Mask"),
+    NameEntry::new(2109, "PlanteraMask", "Plantera Mask"),
+    NameEntry::new(2110, "GolemMask", "Golem Mask"),
+    NameEntry::new(2111, "EaterMask", "Eater of Worlds Mask"),
+    NameEntry::new(2112, "EyeMask", "Eye of Cthulhu Mask"),
+    NameEntry::new(2113, "DestroyerMask", "Destroyer Mask"),
+    NameEntry::new(2114, "BlacksmithRack", "Blacksmith Rack"),
+    NameEntry::new(2115, "CarpentryRack", "Carpentry Rack"),
+    NameEntry::new(2116, "HelmetRack", "Helmet Rack"),
+    NameEntry::new(2117, "SpearRack", "Spear Rack"),
+    NameEntry::new(2118, "SwordRack", "Sword Rack"),
+    NameEntry::new(2119, "StoneSlab", "Stone Slab"),
+    NameEntry::new(2120, "SandstoneSlab", "Sandstone Slab"),
+    NameEntry::new(2121, "Frog", "Frog"),
+    NameEntry::new(2122, "MallardDuck", "Mallard Duck"),
+    NameEntry::new(2123, "Duck", "Duck"),
+    NameEntry::new(2124, "HoneyBathtub", "Honey Bathtub"),
+    NameEntry::new(2125, "SteampunkBathtub", "Steampunk Bathtub"),
+    NameEntry::new(2126, "LivingWoodBathtub", "Living Wood Bathtub"),
+    NameEntry::new(2127, "ShadewoodBathtub", "Shadewood Bathtub"),
+    NameEntry::new(2128, "BoneBathtub", "Bone Bathtub"),
+    NameEntry::new(2129, "HoneyLamp", "Honey Lamp"),
+    NameEntry::new(2130, "SteampunkLamp", "Steampunk Lamp"),
+    NameEntry::new(2131, "LivingWoodLamp", "Living Wood Lamp"),
+    NameEntry::new(2132, "ShadewoodLamp", "Shadewood Lamp"),
+    NameEntry::new(2133, "GoldenLamp", "Golden Lamp"),
+    NameEntry::new(2134, "BoneLamp", "Bone Lamp"),
+    NameEntry::new(2135, "LivingWoodBookcase", "Living Wood Bookcase"),
+    NameEntry::new(2136, "ShadewoodBookcase", "Shadewood Bookcase"),
+    NameEntry::new(2137, "GoldenBookcase", "Golden Bookcase"),
+    NameEntry::new(2138, "BoneBookcase", "Bone Bookcase"),
+    NameEntry::new(2139, "LivingWoodBed", "Living Wood Bed"),
+    NameEntry::new(2140, "BoneBed", "Bone Bed"),
+    NameEntry::new(2141, "LivingWoodChandelier", "Living Wood Chandelier"),
+    NameEntry::new(2142, "ShadewoodChandelier", "Shadewood Chandelier"),
+    NameEntry::new(2143, "GoldenChandelier", "Golden Chandelier"),
+    NameEntry::new(2144, "BoneChandelier", "Bone Chandelier"),
+    NameEntry::new(2145, "LivingWoodLantern", "Living Wood Lantern"),
+    NameEntry::new(2146, "ShadewoodLantern", "Shadewood Lantern"),
+    NameEntry::new(2147, "GoldenLantern", "Golden Lantern"),
+    NameEntry::new(2148, "BoneLantern", "Bone Lantern"),
+    NameEntry::new(2149, "LivingWoodCandelabra", "Living Wood Candelabra"),
+    NameEntry::new(2150, "ShadewoodCandelabra", "Shadewood Candelabra"),
+    NameEntry::new(2151, "GoldenCandelabra", "Golden Candelabra"),
+    NameEntry::new(2152, "BoneCandelabra", "Bone Candelabra"),
+    NameEntry::new(2153, "LivingWoodCandle", "Living Wood Candle"),
+    NameEntry::new(2154, "ShadewoodCandle", "Shadewood Candle"),
+    NameEntry::new(2155, "GoldenCandle", "Golden Candle"),
+    NameEntry::new(2156, "BlackScorpion", "Black Scorpion"),
+    NameEntry::new(2157, "Scorpion", "Scorpion"),
+    NameEntry::new(2158, "BubbleWallpaper", "Bubble Wallpaper"),
+    NameEntry::new(2159, "CopperPipeWallpaper", "Copper Pipe Wallpaper"),
+    NameEntry::new(2160, "DuckyWallpaper", "Ducky Wallpaper"),
+    NameEntry::new(2161, "FrostCore", "Frost Core"),
+    NameEntry::new(2162, "BunnyCage", "Bunny Cage"),
+    NameEntry::new(2163, "SquirrelCage", "Squirrel Cage"),
+    NameEntry::new(2164, "MallardDuckCage", "Mallard Duck Cage"),
+    NameEntry::new(2165, "DuckCage", "Duck Cage"),
+    NameEntry::new(2166, "BirdCage", "Bird Cage"),
+    NameEntry::new(2167, "BlueJayCage", "Blue Jay Cage"),
+    NameEntry::new(2168, "CardinalCage", "Cardinal Cage"),
+    NameEntry::new(2169, "WaterfallWall", "Waterfall Wall"),
+    NameEntry::new(2170, "LavafallWall", "Lavafall Wall"),
+    NameEntry::new(2171, "CrimsonSeeds", "Crimson Seeds"),
+    NameEntry::new(2172, "HeavyWorkBench", "Heavy Assembler"),
+    NameEntry::new(2173, "CopperPlating", "Copper Plating"),
+    NameEntry::new(2174, "SnailCage", "Snail Cage"),
+    NameEntry::new(2175, "GlowingSnailCage", "Glowing Snail Cage"),
+    NameEntry::new(2176, "ShroomiteDiggingClaw", "Shroomite Digging Claw"),
+    NameEntry::new(2177, "AmmoBox", "Ammo Box"),
+    NameEntry::new(2178, "MonarchButterflyJar", "Monarch Butterfly Jar"),
+    NameEntry::new(2179, "PurpleEmperorButterflyJar", "Purple Emperor Butterfly Jar"),
+    NameEntry::new(2180, "RedAdmiralButterflyJar", "Red Admiral Butterfly Jar"),
+    NameEntry::new(2181, "UlyssesButterflyJar", "Ulysses Butterfly Jar"),
+    NameEntry::new(2182, "SulphurButterflyJar", "Sulphur Butterfly Jar"),
+    NameEntry::new(2183, "TreeNymphButterflyJar", "Tree Nymph Butterfly Jar"),
+    NameEntry::new(2184, "ZebraSwallowtailButterflyJar", "Zebra Swallowtail Butterfly Jar"),
+    NameEntry::new(2185, "JuliaButterflyJar", "Julia Butterfly Jar"),
+    NameEntry::new(2186, "ScorpionCage", "Scorpion Cage"),
+    NameEntry::new(2187, "BlackScorpionCage", "Black Scorpion Cage"),
+    NameEntry::new(2188, "VenomStaff", "Venom Staff"),
+    NameEntry::new(2189, "SpectreMask", "Spectre Mask"),
+    NameEntry::new(2190, "FrogCage", "Frog Cage"),
+    NameEntry::new(2191, "MouseCage", "Mouse Cage"),
+    NameEntry::new(2192, "BoneWelder", "Bone Welder"),
+    NameEntry::new(2193, "FleshCloningVaat", "Flesh Cloning Vat"),
+    NameEntry::new(2194, "GlassKiln", "Glass Kiln"),
+    NameEntry::new(2195, "LihzahrdFurnace", "Lihzahrd Furnace"),
+    NameEntry::new(2196, "LivingLoom", "Living Loom"),
+    NameEntry::new(2197, "SkyMill", "Sky Mill"),
+    NameEntry::new(2198, "IceMachine", "Ice Machine"),
+    NameEntry::new(2199, "BeetleHelmet", "Beetle Helmet"),
+    NameEntry::new(2200, "BeetleScaleMail", "Beetle Scale Mail"),
+    NameEntry::new(2201, "BeetleShell", "Beetle Shell"),
+    NameEntry::new(2202, "BeetleLeggings", "Beetle Leggings"),
+    NameEntry::new(2203, "SteampunkBoiler", "Steampunk Boiler"),
+    NameEntry::new(2204, "HoneyDispenser", "Honey Dispenser"),
+    NameEntry::new(2205, "Penguin", "Penguin"),
+    NameEntry::new(2206, "PenguinCage", "Penguin Cage"),
+    NameEntry::new(2207, "WormCage", "Worm Cage"),
+    NameEntry::new(2208, "Terrarium", "Terrarium"),
+    NameEntry::new(2209, "SuperManaPotion", "Super Mana Potion"),
+    NameEntry::new(2210, "EbonwoodFence", "Ebonwood Fence"),
+    NameEntry::new(2211, "RichMahoganyFence", "Rich Mahogany Fence"),
+    NameEntry::new(2212, "PearlwoodFence", "Pearlwood Fence"),
+    NameEntry::new(2213, "ShadewoodFence", "Shadewood Fence"),
+    NameEntry::new(2214, "BrickLayer", "Brick Layer"),
+    NameEntry::new(2215, "ExtendoGrip", "Extendo Grip"),
+    NameEntry::new(2216, "PaintSprayer", "Paint Sprayer"),
+    NameEntry::new(2217, "PortableCementMixer", "Portable Cement Mixer"),
+    NameEntry::new(2218, "BeetleHusk", "Beetle Husk"),
+    NameEntry::new(2219, "CelestialMagnet", "Celestial Magnet"),
+    NameEntry::new(2220, "CelestialEmblem", "Celestial Emblem"),
+    NameEntry::new(2221, "CelestialCuffs", "Celestial Cuffs"),
+    NameEntry::new(2222, "PeddlersHat", "Peddler's Hat"),
+    NameEntry::new(2223, "PulseBow", "Pulse Bow"),
+    NameEntry::new(2224, "DynastyChandelier", "Large Dynasty Lantern"),
+    NameEntry::new(2225, "DynastyLamp", "Dynasty Lamp"),
+    NameEntry::new(2226, "DynastyLantern", "Dynasty Lantern"),
+    NameEntry::new(2227, "DynastyCandelabra", "Large Dynasty Candle"),
+    NameEntry::new(2228, "DynastyChair", "Dynasty Chair"),
+    NameEntry::new(2229, "DynastyWorkBench", "Dynasty Work Bench"),
+    NameEntry::new(2230, "DynastyChest", "Dynasty Chest"),
+    NameEntry::new(2231, "DynastyBed", "Dynasty Bed"),
+    NameEntry::new(2232, "DynastyBathtub", "Dynasty Bathtub"),
+    NameEntry::new(2233, "DynastyBookcase", "Dynasty Bookcase"),
+    NameEntry::new(2234, "DynastyCup", "Dynasty Cup"),
+    NameEntry::new(2235, "DynastyBowl", "Dynasty Bowl"),
+    NameEntry::new(2236, "DynastyCandle", "Dynasty Candle"),
+    NameEntry::new(2237, "DynastyClock", "Dynasty Clock"),
+    NameEntry::new(2238, "GoldenClock", "Golden Clock"),
+    NameEntry::new(2239, "GlassClock", "Glass Clock"),
+    NameEntry::new(2240, "HoneyClock", "Honey Clock"),
+    NameEntry::new(2241, "SteampunkClock", "Steampunk Clock"),
+    NameEntry::new(2242, "FancyDishes", "Fancy Dishes"),
+    NameEntry::new(2243, "GlassBowl", "Glass Bowl"),
+    NameEntry::new(2244, "WineGlass", "Wine Glass"),
+    NameEntry::new(2245, "LivingWoodPiano", "Living Wood Piano"),
+    NameEntry::new(2246, "FleshPiano", "Flesh Piano"),
+    NameEntry::new(2247, "FrozenPiano", "Frozen Piano"),
+    NameEntry::new(2248, "FrozenTable", "Frozen Table"),
+    NameEntry::new(2249, "HoneyChest", "Honey Chest"),
+    NameEntry::new(2250, "SteampunkChest", "Steampunk Chest"),
+    NameEntry::new(2251, "HoneyWorkBench", "Honey Work Bench"),
+    NameEntry::new(2252, "FrozenWorkBench", "Frozen Work Bench"),
+    NameEntry::new(2253, "SteampunkWorkBench", "Steampunk Work Bench"),
+    NameEntry::new(2254, "GlassPiano", "Glass Piano"),
+    NameEntry::new(2255, "HoneyPiano", "Honey Piano"),
+    NameEntry::new(2256, "SteampunkPiano", "Steampunk Piano"),
+    NameEntry::new(2257, "HoneyCup", "Honey Cup"),
+    NameEntry::new(2258, "SteampunkCup", "Chalice"),
+    NameEntry::new(2259, "DynastyTable", "Dynasty Table"),
+    NameEntry::new(2260, "DynastyWood", "Dynasty Wood"),
+    NameEntry::new(2261, "RedDynastyShingles", "Red Dynasty Shingles"),
+    NameEntry::new(2262, "BlueDynastyShingles", "Blue Dynasty Shingles"),
+    NameEntry::new(2263, "WhiteDynastyWall", "White Dynasty Wall"),
+    NameEntry::new(2264, "BlueDynastyWall", "Blue Dynasty Wall"),
+    NameEntry::new(2265, "DynastyDoor", "Dynasty Door"),
+    NameEntry::new(2266, "Sake", "Sake"),
+    NameEntry::new(2267, "PadThai", "Pad Thai"),
+    NameEntry::new(2268, "Pho", "Pho"),
+    NameEntry::new(2269, "Revolver", "Revolver"),
+    NameEntry::new(2270, "Gatligator", "Gatligator"),
+    NameEntry::new(2271, "ArcaneRuneWall", "Arcane Rune Wall"),
+    NameEntry::new(2272, "WaterGun", "Water Gun"),
+    NameEntry::new(2273, "Katana", "Katana"),
+    NameEntry::new(2274, "UltrabrightTorch", "Ultrabright Torch"),
+    NameEntry::new(2275, "MagicHat", "Magic Hat"),
+    NameEntry::new(2276, "DiamondRing", "Diamond Ring"),
+    NameEntry::new(2277, "Gi", "Gi"),
+    NameEntry::new(2278, "Kimono", "Kimono"),
+    NameEntry::new(2279, "GypsyRobe", "Mystic Robe"),
+    NameEntry::new(2280, "BeetleWings", "Beetle Wings"),
+    NameEntry::new(2281, "TigerSkin", "Tiger Skin"),
+    NameEntry::new(2282, "LeopardSkin", "Leopard Skin"),
+    NameEntry::new(2283, "ZebraSkin", "Zebra Skin"),
+    NameEntry::new(2284, "CrimsonCloak", "Crimson Cloak"),
+    NameEntry::new(2285, "MysteriousCape", "Mysterious Cape"),
+    NameEntry::new(2286, "RedCape", "Red Cape"),
+    NameEntry::new(2287, "WinterCape", "Winter Cape"),
+    NameEntry::new(2288, "FrozenChair", "Frozen Chair"),
+    NameEntry::new(2289, "WoodFishingPole", "Wood Fishing Pole"),
+    NameEntry::new(2290, "Bass", "Bass"),
+    NameEntry::new(2291, "ReinforcedFishingPole", "Reinforced Fishing Pole"),
+    NameEntry::new(2292, "FiberglassFishingPole", "Fiberglass Fishing Pole"),
+    NameEntry::new(2293, "FisherofSouls", "Fisher of Souls"),
+    NameEntry::new(2294, "GoldenFishingRod", "Golden Fishing Rod"),
+    NameEntry::new(2295, "MechanicsRod", "Mechanic's Rod"),
+    NameEntry::new(2296, "SittingDucksFishingRod", "Sitting Duck's Fishing Pole"),
+    NameEntry::new(2297, "Trout", "Trout"),
+    NameEntry::new(2298, "Salmon", "Salmon"),
+    NameEntry::new(2299, "AtlanticCod", "Atlantic Cod"),
+    NameEntry::new(2300, "Tuna", "Tuna"),
+    NameEntry::new(2301, "RedSnapper", "Red Snapper"),
+    NameEntry::new(2302, "NeonTetra", "Neon Tetra"),
+    NameEntry::new(2303, "ArmoredCavefish", "Armored Cavefish"),
+    NameEntry::new(2304, "Damselfish", "Damselfish"),
+    NameEntry::new(2305, "CrimsonTigerfish", "Crimson Tigerfish"),
+    NameEntry::new(2306, "FrostMinnow", "Frost Minnow"),
+    NameEntry::new(2307, "PrincessFish", "Princess Fish"),
+    NameEntry::new(2308, "GoldenCarp", "Golden Carp"),
+    NameEntry::new(2309, "SpecularFish", "Specular Fish"),
+    NameEntry::new(2310, "Prismite", "Prismite"),
+    NameEntry::new(2311, "VariegatedLardfish", "Variegated Lardfish"),
+    NameEntry::new(2312, "FlarefinKoi", "Flarefin Koi"),
+    NameEntry::new(2313, "DoubleCod", "Double Cod"),
+    NameEntry::new(2314, "Honeyfin", "Honeyfin"),
+    NameEntry::new(2315, "Obsidifish", "Obsidifish"),
+    NameEntry::new(2316, "Shrimp", "Shrimp"),
+    NameEntry::new(2317, "ChaosFish", "Chaos Fish"),
+    NameEntry::new(2318, "Ebonkoi", "Ebonkoi"),
+    NameEntry::new(2319, "Hemopiranha", "Hemopiranha"),
+    NameEntry::new(2320, "Rockfish", "Rockfish"),
+    NameEntry::new(2321, "Stinkfish", "Stinkfish"),
+    NameEntry::new(2322, "MiningPotion", "Mining Potion"),
+    NameEntry::new(2323, "HeartreachPotion", "Heartreach Potion"),
+    NameEntry::new(2324, "CalmingPotion", "Calming Potion"),
+    NameEntry::new(2325, "BuilderPotion", "Builder Potion"),
+    NameEntry::new(2326, "TitanPotion", "Titan Potion"),
+    NameEntry::new(2327, "FlipperPotion", "Flipper Potion"),
+    NameEntry::new(2328, "SummoningPotion", "Summoning Potion"),
+    NameEntry::new(2329, "TrapsightPotion", "Dangersense Potion"),
+    NameEntry::new(2330, "PurpleClubberfish", "Purple Clubberfish"),
+    NameEntry::new(2331, "ObsidianSwordfish", "Obsidian Swordfish"),
+    NameEntry::new(2332, "Swordfish", "Swordfish"),
+    NameEntry::new(2333, "IronFence", "Iron Fence"),
+    NameEntry::new(2334, "WoodenCrate", "Wooden Crate"),
+    NameEntry::new(2335, "IronCrate", "Iron Crate"),
+    NameEntry::new(2336, "GoldenCrate", "Golden Crate"),
+    NameEntry::new(2337, "OldShoe", "Old Shoe"),
+    NameEntry::new(2338, "FishingSeaweed", "Seaweed"),
+    NameEntry::new(2339, "TinCan", "Tin Can"),
+    NameEntry::new(2340, "MinecartTrack", "Minecart Track"),
+    NameEntry::new(2341, "ReaverShark", "Reaver Shark"),
+    NameEntry::new(2342, "SawtoothShark", "Sawtooth Shark"),
+    NameEntry::new(2343, "Minecart", "Minecart"),
+    NameEntry::new(2344, "AmmoReservationPotion", "Ammo Reservation Potion"),
+    NameEntry::new(2345, "LifeforcePotion", "Lifeforce Potion"),
+    NameEntry::new(2346, "EndurancePotion", "Endurance Potion"),
+    NameEntry::new(2347, "RagePotion", "Rage Potion"),
+    NameEntry::new(2348, "InfernoPotion", "Inferno Potion"),
+    NameEntry::new(2349, "WrathPotion", "Wrath Potion"),
+    NameEntry::new(2350, "RecallPotion", "Recall Potion"),
+    NameEntry::new(2351, "TeleportationPotion", "Teleportation Potion"),
+    NameEntry::new(2352, "LovePotion", "Love Potion"),
+    NameEntry::new(2353, "StinkPotion", "Stink Potion"),
+    NameEntry::new(2354, "FishingPotion", "Fishing Potion"),
+    NameEntry::new(2355, "SonarPotion", "Sonar Potion"),
+    NameEntry::new(2356, "CratePotion", "Crate Potion"),
+    NameEntry::new(2357, "ShiverthornSeeds", "Shiverthorn Seeds"),
+    NameEntry::new(2358, "Shiverthorn", "Shiverthorn"),
+    NameEntry::new(2359, "WarmthPotion", "Warmth Potion"),
+    NameEntry::new(2360, "FishHook", "Fish Hook"),
+    NameEntry::new(2361, "BeeHeadgear", "Bee Headgear"),
+    NameEntry::new(2362, "BeeBreastplate", "Bee Breastplate"),
+    NameEntry::new(2363, "BeeGreaves", "Bee Greaves"),
+    NameEntry::new(2364, "HornetStaff", "Hornet Staff"),
+    NameEntry::new(2365, "ImpStaff", "Imp Staff"),
+    NameEntry::new(2366, "QueenSpiderStaff", "Queen Spider Staff"),
+    NameEntry::new(2367, "AnglerHat", "Angler Hat"),
+    NameEntry::new(2368, "AnglerVest", "Angler Vest"),
+    NameEntry::new(2369, "AnglerPants", "Angler Pants"),
+    NameEntry::new(2370, "SpiderMask", "Spider Mask"),
+    NameEntry::new(2371, "SpiderBreastplate", "Spider Breastplate"),
+    NameEntry::new(2372, "SpiderGreaves", "Spider Greaves"),
+    NameEntry::new(2373, "HighTestFishingLine", "High Test Fishing Line"),
+    NameEntry::new(2374, "AnglerEarring", "Angler Earring"),
+    NameEntry::new(2375, "TackleBox", "Tackle Box"),
+    NameEntry::new(2376, "BlueDungeonPiano", "Blue Dungeon Piano"),
+    NameEntry::new(2377, "GreenDungeonPiano", "Green Dungeon Piano"),
+    NameEntry::new(2378, "PinkDungeonPiano", "Pink Dungeon Piano"),
+    NameEntry::new(2379, "GoldenPiano", "Golden Piano"),
+    NameEntry::new(2380, "ObsidianPiano", "Obsidian Piano"),
+    NameEntry::new(2381, "BonePiano", "Bone Piano"),
+    NameEntry::new(2382, "CactusPiano", "Cactus Piano"),
+    NameEntry::new(2383, "SpookyPiano", "Spooky Piano"),
+    NameEntry::new(2384, "SkywarePiano", "Skyware Piano"),
+    NameEntry::new(2385, "LihzahrdPiano", "Lihzahrd Piano"),
+    NameEntry::new(2386, "BlueDungeonDresser", "Blue Dungeon Dresser"),
+    NameEntry::new(2387, "GreenDungeonDresser", "Green Dungeon Dresser"),
+    NameEntry::new(2388, "PinkDungeonDresser", "Pink Dungeon Dresser"),
+    NameEntry::new(2389, "GoldenDresser", "Golden Dresser"),
+    NameEntry::new(2390, "ObsidianDresser", "Obsidian Dresser"),
+    NameEntry::new(2391, "BoneDresser", "Bone Dresser"),
+    NameEntry::new(2392, "CactusDresser", "Cactus Dresser"),
+    NameEntry::new(2393, "SpookyDresser", "Spooky Dresser"),
+    NameEntry::new(2394, "SkywareDresser", "Skyware Dresser"),
+    NameEntry::new(2395, "HoneyDresser", "Honey Dresser"),
+    NameEntry::new(2396, "LihzahrdDresser", "Lihzahrd Dresser"),
+    NameEntry::new(2397, "Sofa", "Sofa"),
+    NameEntry::new(2398, "EbonwoodSofa", "Ebonwood Sofa"),
+    NameEntry::new(2399, "RichMahoganySofa", "Rich Mahogany Sofa"),
+    NameEntry::new(2400, "PearlwoodSofa", "Pearlwood Sofa"),
+    NameEntry::new(2401, "ShadewoodSofa", "Shadewood Sofa"),
+    NameEntry::new(2402, "BlueDungeonSofa", "Blue Dungeon Sofa"),
+    NameEntry::new(2403, "GreenDungeonSofa", "Green Dungeon Sofa"),
+    NameEntry::new(2404, "PinkDungeonSofa", "Pink Dungeon Sofa"),
+    NameEntry::new(2405, "GoldenSofa", "Golden Sofa"),
+    NameEntry::new(2406, "ObsidianSofa", "Obsidian Sofa"),
+    NameEntry::new(2407, "BoneSofa", "Bone Sofa"),
+    NameEntry::new(2408, "CactusSofa", "Cactus Sofa"),
+    NameEntry::new(2409, "SpookySofa", "Spooky Sofa"),
+    NameEntry::new(2410, "SkywareSofa", "Skyware Sofa"),
+    NameEntry::new(2411, "HoneySofa", "Honey Sofa"),
+    NameEntry::new(2412, "SteampunkSofa", "Steampunk Sofa"),
+    NameEntry::new(2413, "MushroomSofa", "Mushroom Sofa"),
+    NameEntry::new(2414, "GlassSofa", "Glass Sofa"),
+    NameEntry::new(2415, "PumpkinSofa", "Pumpkin Sofa"),
+    NameEntry::new(2416, "LihzahrdSofa", "Lihzahrd Sofa"),
+    NameEntry::new(2417, "SeashellHairpin", "Seashell Hairpin"),
+    NameEntry::new(2418, "MermaidAdornment", "Mermaid Adornment"),
+    NameEntry::new(2419, "MermaidTail", "Mermaid Tail"),
+    NameEntry::new(2420, "ZephyrFish", "Zephyr Fish"),
+    NameEntry::new(2421, "Fleshcatcher", "Fleshcatcher"),
+    NameEntry::new(2422, "HotlineFishingHook", "Hotline Fishing Hook"),
+    NameEntry::new(2423, "FrogLeg", "Frog Leg"),
+    NameEntry::new(2424, "Anchor", "Anchor"),
+    NameEntry::new(2425, "CookedFish", "Cooked Fish"),
+    NameEntry::new(2426, "CookedShrimp", "Cooked Shrimp"),
+    NameEntry::new(2427, "Sashimi", "Sashimi"),
+    NameEntry::new(2428, "FuzzyCarrot", "Fuzzy Carrot"),
+    NameEntry::new(2429, "ScalyTruffle", "Scaly Truffle"),
+    NameEntry::new(2430, "SlimySaddle", "Slimy Saddle"),
+    NameEntry::new(2431, "BeeWax", "Bee Wax"),
+    NameEntry::new(2432, "CopperPlatingWall", "Copper Plating Wall"),
+    NameEntry::new(2433, "StoneSlabWall", "Stone Slab Wall"),
+    NameEntry::new(2434, "Sail", "Sail"),
+    NameEntry::new(2435, "CoralstoneBlock", "Coralstone Block"),
+    NameEntry::new(2436, "BlueJellyfish", "Blue Jellyfish"),
+    NameEntry::new(2437, "GreenJellyfish", "Green Jellyfish"),
+    NameEntry::new(2438, "PinkJellyfish", "Pink Jellyfish"),
+    NameEntry::new(2439, "BlueJellyfishJar", "Blue Jellyfish Jar"),
+    NameEntry::new(2440, "GreenJellyfishJar", "Green Jellyfish Jar"),
+    NameEntry::new(2441, "PinkJellyfishJar", "Pink Jellyfish Jar"),
+    NameEntry::new(2442, "LifePreserver", "Life Preserver"),
+    NameEntry::new(2443, "ShipsWheel", "Ship's Wheel"),
+    NameEntry::new(2444, "CompassRose", "Compass Rose"),
+    NameEntry::new(2445, "WallAnchor", "Wall Anchor"),
+    NameEntry::new(2446, "GoldfishTrophy", "Goldfish Trophy"),
+    NameEntry::new(2447, "BunnyfishTrophy", "Bunnyfish Trophy"),
+    NameEntry::new(2448, "SwordfishTrophy", "Swordfish Trophy"),
+    NameEntry::new(2449, "SharkteethTrophy", "Sharkteeth Trophy"),
+    NameEntry::new(2450, "Batfish", "Batfish"),
+    NameEntry::new(2451, "BumblebeeTuna", "Bumblebee Tuna"),
+    NameEntry::new(2452, "Catfish", "Catfish"),
+    NameEntry::new(2453, "Cloudfish", "Cloudfish"),
+    NameEntry::new(2454, "Cursedfish", "Cursedfish"),
+    NameEntry::new(2455, "Dirtfish", "Dirtfish"),
+    NameEntry::new(2456, "DynamiteFish", "Dynamite Fish"),
+    NameEntry::new(2457, "EaterofPlankton", "Eater of Plankton"),
+    NameEntry::new(2458, "FallenStarfish", "Fallen Starfish"),
+    NameEntry::new(2459, "TheFishofCthulu", "The Fish of Cthulhu"),
+    NameEntry::new(2460, "Fishotron", "Fishotron"),
+    NameEntry::new(2461, "Harpyfish", "Harpyfish"),
+    NameEntry::new(2462, "Hungerfish", "Hungerfish"),
+    NameEntry::new(2463, "Ichorfish", "Ichorfish"),
+    NameEntry::new(2464, "Jewelfish", "Jewelfish"),
+    NameEntry::new(2465, "MirageFish", "Mirage Fish"),
+    NameEntry::new(2466, "MutantFlinxfin", "Mutant Flinxfin"),
+    NameEntry::new(2467, "Pengfish", "Pengfish"),
+    NameEntry::new(2468, "Pixiefish", "Pixiefish"),
+    NameEntry::new(2469, "Spiderfish", "Spiderfish"),
+    NameEntry::new(2470, "TundraTrout", "Tundra Trout"),
+    NameEntry::new(2471, "UnicornFish", "Unicorn Fish"),
+    NameEntry::new(2472, "GuideVoodooFish", "Guide Voodoo Fish"),
+    NameEntry::new(2473, "Wyverntail", "Wyverntail"),
+    NameEntry::new(2474, "ZombieFish", "Zombie Fish"),
+    NameEntry::new(2475, "AmanitaFungifin", "Amanita Fungifin"),
+    NameEntry::new(2476, "Angelfish", "Angelfish"),
+    NameEntry::new(2477, "BloodyManowar", "Bloody Manowar"),
+    NameEntry::new(2478, "Bonefish", "Bonefish"),
+    NameEntry::new(2479, "Bunnyfish", "Bunnyfish"),
+    NameEntry::new(2480, "CapnTunabeard", "Cap'n Tunabeard"),
+    NameEntry::new(2481, "Clownfish", "Clownfish"),
+    NameEntry::new(2482, "DemonicHellfish", "Demonic Hellfish"),
+    NameEntry::new(2483, "Derpfish", "Derpfish"),
+    NameEntry::new(2484, "Fishron", "Fishron"),
+    NameEntry::new(2485, "InfectedScabbardfish", "Infected Scabbardfish"),
+    NameEntry::new(2486, "Mudfish", "Mudfish"),
+    NameEntry::new(2487, "Slimefish", "Slimefish"),
+    NameEntry::new(2488, "TropicalBarracuda", "Tropical Barracuda"),
+    NameEntry::new(2489, "KingSlimeTrophy", "King Slime Trophy"),
+    NameEntry::new(2490, "ShipInABottle", "Ship in a Bottle"),
+    NameEntry::new(2491, "HardySaddle", "Hardy Saddle"),
+    NameEntry::new(2492, "PressureTrack", "Pressure Plate Track"),
+    NameEntry::new(2493, "KingSlimeMask", "King Slime Mask"),
+    NameEntry::new(2494, "FinWings", "Fin Wings"),
+    NameEntry::new(2495, "TreasureMap", "Treasure Map"),
+    NameEntry::new(2496, "SeaweedPlanter", "Seaweed Planter"),
+    NameEntry::new(2497, "PillaginMePixels", "Pillagin' Me Pixels"),
+    NameEntry::new(2498, "FishCostumeMask", "Fish Costume Mask"),
+    NameEntry::new(2499, "FishCostumeShirt", "Fish Costume Shirt"),
+    NameEntry::new(2500, "FishCostumeFinskirt", "Fish Costume Finskirt"),
+    NameEntry::new(2501, "GingerBeard", "Ginger Beard"),
+    NameEntry::new(2502, "HoneyedGoggles", "Honeyed Goggles"),
+    NameEntry::new(2503, "BorealWood", "Boreal Wood"),
+    NameEntry::new(2504, "PalmWood", "Palm Wood"),
+    NameEntry::new(2505, "BorealWoodWall", "Boreal Wood Wall"),
+    NameEntry::new(2506, "PalmWoodWall", "Palm Wood Wall"),
+    NameEntry::new(2507, "BorealWoodFence", "Boreal Wood Fence"),
+    NameEntry::new(2508, "PalmWoodFence", "Palm Wood Fence"),
+    NameEntry::new(2509, "BorealWoodHelmet", "Boreal Wood Helmet"),
+    NameEntry::new(2510, "BorealWoodBreastplate", "Boreal Wood Breastplate"),
+    NameEntry::new(2511, "BorealWoodGreaves", "Boreal Wood Greaves"),
+    NameEntry::new(2512, "PalmWoodHelmet", "Palm Wood Helmet"),
+    NameEntry::new(2513, "PalmWoodBreastplate", "Palm Wood Breastplate"),
+    NameEntry::new(2514, "PalmWoodGreaves", "Palm Wood Greaves"),
+    NameEntry::new(2515, "PalmWoodBow", "Palm Wood Bow"),
+    NameEntry::new(2516, "PalmWoodHammer", "Palm Wood Hammer"),
+    NameEntry::new(2517, "PalmWoodSword", "Palm Wood Sword"),
+    NameEntry::new(2518, "PalmWoodPlatform", "Palm Wood Platform"),
+    NameEntry::new(2519, "PalmWoodBathtub", "Palm Wood Bathtub"),
+    NameEntry::new(2520, "PalmWoodBed", "Palm Wood Bed"),
+    NameEntry::new(2521, "PalmWoodBench", "Palm Wood Bench"),
+    NameEntry::new(2522, "PalmWoodCandelabra", "Palm Wood Candelabra"),
+    NameEntry::new(2523, "PalmWoodCandle", "Palm Wood Candle"),
+    NameEntry::new(2524, "PalmWoodChair", "Palm Wood Chair"),
+    NameEntry::new(2525, "PalmWoodChandelier", "Palm Wood Chandelier"),
+    NameEntry::new(2526, "PalmWoodChest", "Palm Wood Chest"),
+    NameEntry::new(2527, "PalmWoodSofa", "Palm Wood Sofa"),
+    NameEntry::new(2528, "PalmWoodDoor", "Palm Wood Door"),
+    NameEntry::new(2529, "PalmWoodDresser", "Palm Wood Dresser"),
+    NameEntry::new(2530, "PalmWoodLantern", "Palm Wood Lantern"),
+    NameEntry::new(2531, "PalmWoodPiano", "Palm Wood Piano"),
+    NameEntry::new(2532, "PalmWoodTable", "Palm Wood Table"),
+    NameEntry::new(2533, "PalmWoodLamp", "Palm Wood Lamp"),
+    NameEntry::new(2534, "PalmWoodWorkBench", "Palm Wood Work Bench"),
+    NameEntry::new(2535, "OpticStaff", "Optic Staff"),
+    NameEntry::new(2536, "PalmWoodBookcase", "Palm Wood Bookcase"),
+    NameEntry::new(2537, "MushroomBathtub", "Mushroom Bathtub"),
+    NameEntry::new(2538, "MushroomBed", "Mushroom Bed"),
+    NameEntry::new(2539, "MushroomBench", "Mushroom Bench"),
+    NameEntry::new(2540, "MushroomBookcase", "Mushroom Bookcase"),
+    NameEntry::new(2541, "MushroomCandelabra", "Mushroom Candelabra"),
+    NameEntry::new(2542, "MushroomCandle", "Mushroom Candle"),
+    NameEntry::new(2543, "MushroomChandelier", "Mushroom Chandelier"),
+    NameEntry::new(2544, "MushroomChest", "Mushroom Chest"),
+    NameEntry::new(2545, "MushroomDresser", "Mushroom Dresser"),
+    NameEntry::new(2546, "MushroomLantern", "Mushroom Lantern"),
+    NameEntry::new(2547, "MushroomLamp", "Mushroom Lamp"),
+    NameEntry::new(2548, "MushroomPiano", "Mushroom Piano"),
+    NameEntry::new(2549, "MushroomPlatform", "Mushroom Platform"),
+    NameEntry::new(2550, "MushroomTable", "Mushroom Table"),
+    NameEntry::new(2551, "SpiderStaff", "Spider Staff"),
+    NameEntry::new(2552, "BorealWoodBathtub", "Boreal Wood Bathtub"),
+    NameEntry::new(2553, "BorealWoodBed", "Boreal Wood Bed"),
+    NameEntry::new(2554, "BorealWoodBookcase", "Boreal Wood Bookcase"),
+    NameEntry::new(2555, "BorealWoodCandelabra", "Boreal Wood Candelabra"),
+    NameEntry::new(2556, "BorealWoodCandle", "Boreal Wood Candle"),
+    NameEntry::new(2557, "BorealWoodChair", "Boreal Wood Chair"),
+    NameEntry::new(2558, "BorealWoodChandelier", "Boreal Wood Chandelier"),
+    NameEntry::new(2559, "BorealWoodChest", "Boreal Wood Chest"),
+    NameEntry::new(2560, "BorealWoodClock", "Boreal Wood Clock"),
+    NameEntry::new(2561, "BorealWoodDoor", "Boreal Wood Door"),
+    NameEntry::new(2562, "BorealWoodDresser", "Boreal Wood Dresser"),
+    NameEntry::new(2563, "BorealWoodLamp", "Boreal Wood Lamp"),
+    NameEntry::new(2564, "BorealWoodLantern", "Boreal Wood Lantern"),
+    NameEntry::new(2565, "BorealWoodPiano", "Boreal Wood Piano"),
+    NameEntry::new(2566, "BorealWoodPlatform", "Boreal Wood Platform"),
+    NameEntry::new(2567, "SlimeBathtub", "Slime Bathtub"),
+    NameEntry::new(2568, "SlimeBed", "Slime Bed"),
+    NameEntry::new(2569, "SlimeBookcase", "Slime Bookcase"),
+    NameEntry::new(2570, "SlimeCandelabra", "Slime Candelabra"),
+    NameEntry::new(2571, "SlimeCandle", "Slime Candle"),
+    NameEntry::new(2572, "SlimeChair", "Slime Chair"),
+    NameEntry::new(2573, "SlimeChandelier", "Slime Chandelier"),
+    NameEntry::new(2574, "SlimeChest", "Slime Chest"),
+    NameEntry::new(2575, "SlimeClock", "Slime Clock"),
+    NameEntry::new(2576, "SlimeDoor", "Slime Door"),
+    NameEntry::new(2577, "SlimeDresser", "Slime Dresser"),
+    NameEntry::new(2578, "SlimeLamp", "Slime Lamp"),
+    NameEntry::new(2579, "SlimeLantern", "Slime Lantern"),
+    NameEntry::new(2580, "SlimePiano", "Slime Piano"),
+    NameEntry::new(2581, "SlimePlatform", "Slime Platform"),
+    NameEntry::new(2582, "SlimeSofa", "Slime Sofa"),
+    NameEntry::new(2583, "SlimeTable", "Slime Table"),
+    NameEntry::new(2584, "PirateStaff", "Pirate Staff"),
+    NameEntry::new(2585, "SlimeHook", "Slime Hook"),
+    NameEntry::new(2586, "StickyGrenade", "Sticky Grenade"),
+    NameEntry::new(2587, "TartarSauce", "Beguiling Lyre"),
+    NameEntry::new(2588, "DukeFishronMask", "Duke Fishron Mask"),
+    NameEntry::new(2589, "DukeFishronTrophy", "Duke Fishron Trophy"),
+    NameEntry::new(2590, "MolotovCocktail", "Molotov Cocktail"),
+    NameEntry::new(2591, "BoneClock", "Bone Clock"),
+    NameEntry::new(2592, "CactusClock", "Cactus Clock"),
+    NameEntry::new(2593, "EbonwoodClock", "Ebonwood Clock"),
+    NameEntry::new(2594, "FrozenClock", "Frozen Clock"),
+    NameEntry::new(2595, "LihzahrdClock", "Lihzahrd Clock"),
+    NameEntry::new(2596, "LivingWoodClock", "Living Wood Clock"),
+    NameEntry::new(2597, "RichMahoganyClock", "Rich Mahogany Clock"),
+    NameEntry::new(2598, "FleshClock", "Flesh Clock"),
+    NameEntry::new(2599, "MushroomClock", "Mushroom Clock"),
+    NameEntry::new(2600, "ObsidianClock", "Obsidian Clock"),
+    NameEntry::new(2601, "PalmWoodClock", "Palm Wood Clock"),
+    NameEntry::new(2602, "PearlwoodClock", "Pearlwood Clock"),
+    NameEntry::new(2603, "PumpkinClock", "Pumpkin Clock"),
+    NameEntry::new(2604, "ShadewoodClock", "Shadewood Clock"),
+    NameEntry::new(2605, "SpookyClock", "Spooky Clock"),
+    NameEntry::new(2606, "SkywareClock", "Skyware Clock"),
+    NameEntry::new(2607, "SpiderFang", "Spider Fang"),
+    NameEntry::new(2608, "FalconBlade", "Falcon Blade"),
+    NameEntry::new(2609, "FishronWings", "Fishron Wings"),
+    NameEntry::new(2610, "SlimeGun", "Slime Gun"),
+    NameEntry::new(2611, "Flairon", "Flairoon"),
+    NameEntry::new(2612, "GreenDungeonChest", "Green Dungeon Chest"),
+    NameEntry::new(2613, "PinkDungeonChest", "Pink Dungeon Chest"),
+    NameEntry::new(2614, "BlueDungeonChest", "Blue Dungeon Chest"),
+    NameEntry::new(2615, "BoneChest", "Bone Chest"),
+    NameEntry::new(2616, "CactusChest", "Cactus Chest"),
+    NameEntry::new(2617, "FleshChest", "Flesh Chest"),
+    NameEntry::new(2618, "ObsidianChest", "Obsidian Chest"),
+    NameEntry::new(2619, "PumpkinChest", "Pumpkin Chest"),
+    NameEntry::new(2620, "SpookyChest", "Spooky Chest"),
+    NameEntry::new(2621, "TempestStaff", "Tempest Staff"),
+    NameEntry::new(2622, "RazorbladeTyphoon", "Razorblade Typhoon"),
+    NameEntry::new(2623, "BubbleGun", "Bubble Gun"),
+    NameEntry::new(2624, "Tsunami", "Tsunami"),
+    NameEntry::new(2625, "Seashell", "Seashell"),
+    NameEntry::new(2626, "Starfish", "Starfish"),
+    NameEntry::new(2627, "SteampunkPlatform", "Steampunk Platform"),
+    NameEntry::new(2628, "SkywarePlatform", "Skyware Platform"),
+    NameEntry::new(2629, "LivingWoodPlatform", "Living Wood Platform"),
+    NameEntry::new(2630, "HoneyPlatform", "Honey Platform"),
+    NameEntry::new(2631, "SkywareWorkbench", "Skyware Work Bench"),
+    NameEntry::new(2632, "GlassWorkBench", "Glass Work Bench"),
+    NameEntry::new(2633, "LivingWoodWorkBench", "Living Wood Work Bench"),
+    NameEntry::new(2634, "FleshSofa", "Flesh Sofa"),
+    NameEntry::new(2635, "FrozenSofa", "Frozen Sofa"),
+    NameEntry::new(2636, "LivingWoodSofa", "Living Wood Sofa"),
+    NameEntry::new(2637, "PumpkinDresser", "Pumpkin Dresser"),
+    NameEntry::new(2638, "SteampunkDresser", "Steampunk Dresser"),
+    NameEntry::new(2639, "GlassDresser", "Glass Dresser"),
+    NameEntry::new(2640, "FleshDresser", "Flesh Dresser"),
+    NameEntry::new(2641, "PumpkinLantern", "Pumpkin Lantern"),
+    NameEntry::new(2642, "ObsidianLantern", "Obsidian Lantern"),
+    NameEntry::new(2643, "PumpkinLamp", "Pumpkin Lamp"),
+    NameEntry::new(2644, "ObsidianLamp", "Obsidian Lamp"),
+    NameEntry::new(2645, "BlueDungeonLamp", "Blue Dungeon Lamp"),
+    NameEntry::new(2646, "GreenDungeonLamp", "Green Dungeon Lamp"),
+    NameEntry::new(2647, "PinkDungeonLamp", "Pink Dungeon Lamp"),
+    NameEntry::new(2648, "HoneyCandle", "Honey Candle"),
+    NameEntry::new(2649, "SteampunkCandle", "Steampunk Candle"),
+    NameEntry::new(2650, "SpookyCandle", "Spooky Candle"),
+    NameEntry::new(2651, "ObsidianCandle", "Obsidian Candle"),
+    NameEntry::new(2652, "BlueDungeonChandelier", "Blue Dungeon Chandelier"),
+    NameEntry::new(2653, "GreenDungeonChandelier", "Green Dungeon Chandelier"),
+    NameEntry::new(2654, "PinkDungeonChandelier", "Pink Dungeon Chandelier"),
+    NameEntry::new(2655, "SteampunkChandelier", "Steampunk Chandelier"),
+    NameEntry::new(2656, "PumpkinChandelier", "Pumpkin Chandelier"),
+    NameEntry::new(2657, "ObsidianChandelier", "Obsidian Chandelier"),
+    NameEntry::new(2658, "BlueDungeonBathtub", "Blue Dungeon Bathtub"),
+    NameEntry::new(2659, "GreenDungeonBathtub", "Green Dungeon Bathtub"),
+    NameEntry::new(2660, "PinkDungeonBathtub", "Pink Dungeon Bathtub"),
+    NameEntry::new(2661, "PumpkinBathtub", "Pumpkin Bathtub"),
+    NameEntry::new(2662, "ObsidianBathtub", "Obsidian Bathtub"),
+    NameEntry::new(2663, "GoldenBathtub", "Golden Bathtub"),
+    NameEntry::new(2664, "BlueDungeonCandelabra", "Blue Dungeon Candelabra"),
+    NameEntry::new(2665, "GreenDungeonCandelabra", "Green Dungeon Candelabra"),
+    NameEntry::new(2666, "PinkDungeonCandelabra", "Pink Dungeon Candelabra"),
+    NameEntry::new(2667, "ObsidianCandelabra", "Obsidian Candelabra"),
+    NameEntry::new(2668, "PumpkinCandelabra", "Pumpkin Candelabra"),
+    NameEntry::new(2669, "PumpkinBed", "Pumpkin Bed"),
+    NameEntry::new(2670, "PumpkinBookcase", "Pumpkin Bookcase"),
+    NameEntry::new(2671, "PumpkinPiano", "Pumpkin Piano"),
+    NameEntry::new(2672, "SharkStatue", "Shark Statue"),
+    NameEntry::new(2673, "TruffleWorm", "Truffle Worm"),
+    NameEntry::new(2674, "ApprenticeBait", "Apprentice Bait"),
+    NameEntry::new(2675, "JourneymanBait", "Journeyman Bait"),
+    NameEntry::new(2676, "MasterBait", "Master Bait"),
+    NameEntry::new(2677, "AmberGemsparkWall", "Amber Gemspark Wall"),
+    NameEntry::new(2678, "AmberGemsparkWallOff", "Offline Amber Gemspark Wall"),
+    NameEntry::new(2679, "AmethystGemsparkWall", "Amethyst Gemspark Wall"),
+    NameEntry::new(2680, "AmethystGemsparkWallOff", "Offline Amethyst Gemspark Wall"),
+    NameEntry::new(2681, "DiamondGemsparkWall", "Diamond Gemspark Wall"),
+    NameEntry::new(2682, "DiamondGemsparkWallOff", "Offline Diamond Gemspark Wall"),
+    NameEntry::new(2683, "EmeraldGemsparkWall", "Emerald Gemspark Wall"),
+    NameEntry::new(2684, "EmeraldGemsparkWallOff", "Offline Emerald Gemspark Wall"),
+    NameEntry::new(2685, "RubyGemsparkWall", "Ruby Gemspark Wall"),
+    NameEntry::new(2686, "RubyGemsparkWallOff", "Offline Ruby Gemspark Wall"),
+    NameEntry::new(2687, "SapphireGemsparkWall", "Sapphire Gemspark Wall"),
+    NameEntry::new(2688, "SapphireGemsparkWallOff", "Offline Sapphire Gemspark Wall"),
+    NameEntry::new(2689, "TopazGemsparkWall", "Topaz Gemspark Wall"),
+    NameEntry::new(2690, "TopazGemsparkWallOff", "Offline Topaz Gemspark Wall"),
+    NameEntry::new(2691, "TinPlatingWall", "Tin Plating Wall"),
+    NameEntry::new(2692, "TinPlating", "Tin Plating"),
+    NameEntry::new(2693, "WaterfallBlock", "Waterfall Block"),
+    NameEntry::new(2694, "LavafallBlock", "Lavafall Block"),
+    NameEntry::new(2695, "ConfettiBlock", "Confetti Block"),
+    NameEntry::new(2696, "ConfettiWall", "Confetti Wall"),
+    NameEntry::new(2697, "ConfettiBlockBlack", "Midnight Confetti Block"),
+    NameEntry::new(2698, "ConfettiWallBlack", "Midnight Confetti Wall"),
+    NameEntry::new(2699, "WeaponRack", "Weapon Rack"),
+    NameEntry::new(2700, "FireworksBox", "Fireworks Box"),
+    NameEntry::new(2701, "LivingFireBlock", "Living Fire Block"),
+    NameEntry::new(2702, "AlphabetStatue0", "'0' Statue"),
+    NameEntry::new(2703, "AlphabetStatue1", "'1' Statue"),
+    NameEntry::new(2704, "AlphabetStatue2", "'2' Statue"),
+    NameEntry::new(2705, "AlphabetStatue3", "'3' Statue"),
+    NameEntry::new(2706, "AlphabetStatue4", "'4' Statue"),
+    NameEntry::new(2707, "AlphabetStatue5", "'5' Statue"),
+    NameEntry::new(2708, "AlphabetStatue6", "'6' Statue"),
+    NameEntry::new(2709, "AlphabetStatue7", "'7' Statue"),
+    NameEntry::new(2710, "AlphabetStatue8", "'8' Statue"),
+    NameEntry::new(2711, "AlphabetStatue9", "'9' Statue"),
+    NameEntry::new(2712, "AlphabetStatueA", "'A' Statue"),
+    NameEntry::new(2713, "AlphabetStatueB", "'B' Statue"),
+    NameEntry::new(2714, "AlphabetStatueC", "'C' Statue"),
+    NameEntry::new(2715, "AlphabetStatueD", "'D' Statue"),
+    NameEntry::new(2716, "AlphabetStatueE", "'E' Statue"),
+    NameEntry::new(2717, "AlphabetStatueF", "'F' Statue"),
+    NameEntry::new(2718, "AlphabetStatueG", "'G' Statue"),
+    NameEntry::new(2719, "AlphabetStatueH", "'H' Statue"),
+    NameEntry::new(2720, "AlphabetStatueI", "'I' Statue"),
+    NameEntry::new(2721, "AlphabetStatueJ", "'J' Statue"),
+    NameEntry::new(2722, "AlphabetStatueK", "'K' Statue"),
+    NameEntry::new(2723, "AlphabetStatueL", "'L' Statue"),
+    NameEntry::new(2724, "AlphabetStatueM", "'M' Statue"),
+    NameEntry::new(2725, "AlphabetStatueN", "'N' Statue"),
+    NameEntry::new(2726, "AlphabetStatueO", "'O' Statue"),
+    NameEntry::new(2727, "AlphabetStatueP", "'P' Statue"),
+    NameEntry::new(2728, "AlphabetStatueQ", "'Q' Statue"),
+    NameEntry::new(2729, "AlphabetStatueR", "'R' Statue"),
+    NameEntry::new(2730, "AlphabetStatueS", "'S' Statue"),
+    NameEntry::new(2731, "AlphabetStatueT", "'T' Statue"),
+    NameEntry::new(2732, "AlphabetStatueU", "'U' Statue"),
+    NameEntry::new(2733, "AlphabetStatueV", "'V' Statue"),
+    NameEntry::new(2734, "AlphabetStatueW", "'W' Statue"),
+    NameEntry::new(2735, "AlphabetStatueX", "'X' Statue"),
+    NameEntry::new(2736, "AlphabetStatueY", "'Y' Statue"),
+    NameEntry::new(2737, "AlphabetStatueZ", "'Z' Statue"),
+    NameEntry::new(2738, "FireworkFountain", "Firework Fountain"),
+    NameEntry::new(2739, "BoosterTrack", "Booster Track"),
+    NameEntry::new(2740, "Grasshopper", "Grasshopper"),
+    NameEntry::new(2741, "GrasshopperCage", "Grasshopper Cage"),
+    NameEntry::new(2742, "MusicBoxUndergroundCrimson", "Music Box (Underground Crimson)"),
+    NameEntry::new(2743, "CactusTable", "Cactus Table"),
+    NameEntry::new(2744, "CactusPlatform", "Cactus Platform"),
+    NameEntry::new(2745, "BorealWoodSword", "Boreal Wood Sword"),
+    NameEntry::new(2746, "BorealWoodHammer", "Boreal Wood Hammer"),
+    NameEntry::new(2747, "BorealWoodBow", "Boreal Wood Bow"),
+    NameEntry::new(2748, "GlassChest", "Glass Chest"),
+    NameEntry::new(2749, "XenoStaff", "Xeno Staff"),
+    NameEntry::new(2750, "MeteorStaff", "Meteor Staff"),
+    NameEntry::new(2751, "LivingCursedFireBlock", "Living Cursed Fire Block"),
+    NameEntry::new(2752, "LivingDemonFireBlock", "Living Demon Fire Block"),
+    NameEntry::new(2753, "LivingFrostFireBlock", "Living Frost Fire Block"),
+    NameEntry::new(2754, "LivingIchorBlock", "Living Ichor Block"),
+    NameEntry::new(2755, "LivingUltrabrightFireBlock", "Living Ultrabright Fire Block"),
+    NameEntry::new(2756, "GenderChangePotion", "Gender Change Potion"),
+    NameEntry::new(2757, "VortexHelmet", "Vortex Helmet"),
+    NameEntry::new(2758, "VortexBreastplate", "Vortex Breastplate"),
+    NameEntry::new(2759, "VortexLeggings", "Vortex Leggings"),
+    NameEntry::new(2760, "NebulaHelmet", "Nebula Helmet"),
+    NameEntry::new(2761, "NebulaBreastplate", "Nebula Breastplate"),
+    NameEntry::new(2762, "NebulaLeggings", "Nebula Leggings"),
+    NameEntry::new(2763, "SolarFlareHelmet", "Solar Flare Helmet"),
+    NameEntry::new(2764, "SolarFlareBreastplate", "Solar Flare Breastplate"),
+    NameEntry::new(2765, "SolarFlareLeggings", "Solar Flare Leggings"),
+    NameEntry::new(2766, "LunarTabletFragment", "Solar Tablet Fragment"),
+    NameEntry::new(2767, "SolarTablet", "Solar Tablet"),
+    NameEntry::new(2768, "DrillContainmentUnit", "Drill Containment Unit"),
+    NameEntry::new(2769, "CosmicCarKey", "Cosmic Car Key"),
+    NameEntry::new(2770, "MothronWings", "Mothron Wings"),
+    NameEntry::new(2771, "BrainScrambler", "Brain Scrambler"),
+    NameEntry::new(2772, "VortexAxe", "Vortex Axe"),
+    NameEntry::new(2773, "VortexChainsaw", "Vortex Chainsaw"),
+    NameEntry::new(2774, "VortexDrill", "Vortex Drill"),
+    NameEntry::new(2775, "VortexHammer", "Vortex Hammer"),
+    NameEntry::new(2776, "VortexPickaxe", "Vortex Pickaxe"),
+    NameEntry::new(2777, "NebulaAxe", "Nebula Axe"),
+    NameEntry::new(2778, "NebulaChainsaw", "Nebula Chainsaw"),
+    NameEntry::new(2779, "NebulaDrill", "Nebula Drill"),
+    NameEntry::new(2780, "NebulaHammer", "Nebula Hammer"),
+    NameEntry::new(2781, "NebulaPickaxe", "Nebula Pickaxe"),
+    NameEntry::new(2782, "SolarFlareAxe", "Solar Flare Axe"),
+    NameEntry::new(2783, "SolarFlareChainsaw", "Solar Flare Chainsaw"),
+    NameEntry::new(2784, "SolarFlareDrill", "Solar Flare Drill"),
+    NameEntry::new(2785, "SolarFlareHammer", "Solar Flare Hammer"),
+    NameEntry::new(2786, "SolarFlarePickaxe", "Solar Flare Pickaxe"),
+    NameEntry::new(2787, "HoneyfallBlock", "Honeyfall Block"),
+    NameEntry::new(2788, "HoneyfallWall", "Honeyfall Wall"),
+    NameEntry::new(2789, "ChlorophyteBrickWall", "Chlorophyte Brick Wall"),
+    NameEntry::new(2790, "CrimtaneBrickWall", "Crimtane Brick Wall"),
+    NameEntry::new(2791, "ShroomitePlatingWall", "Shroomite Plating Wall"),
+    NameEntry::new(2792, "ChlorophyteBrick", "Chlorophyte Brick"),
+    NameEntry::new(2793, "CrimtaneBrick", "Crimtane Brick"),
+    NameEntry::new(2794, "ShroomitePlating", "Shroomite Plating"),
+    NameEntry::new(2795, "LaserMachinegun", "Laser Machinegun"),
+    NameEntry::new(2796, "ElectrosphereLauncher", "Electrosphere Launcher"),
+    NameEntry::new(2797, "Xenopopper", "Xenopopper"),
+    NameEntry::new(2798, "LaserDrill", "Laser Drill"),
+    NameEntry::new(2799, "LaserRuler", "Mechanical Ruler"),
+    NameEntry::new(2800, "AntiGravityHook", "Anti-Gravity Hook"),
+    NameEntry::new(2801, "MoonMask", "Moon Mask"),
+    NameEntry::new(2802, "SunMask", "Sun Mask"),
+    NameEntry::new(2803, "MartianCostumeMask", "Martian Costume Mask"),
+    NameEntry::new(2804, "MartianCostumeShirt", "Martian Costume Shirt"),
+    NameEntry::new(2805, "MartianCostumePants", "Martian Costume Pants"),
+    NameEntry::new(2806, "MartianUniformHelmet", "Martian Uniform Helmet"),
+    NameEntry::new(2807, "MartianUniformTorso", "Martian Uniform Torso"),
+    NameEntry::new(2808, "MartianUniformPants", "Martian Uniform Pants"),
+    NameEntry::new(2809, "MartianAstroClock", "Martian Astro Clock"),
+    NameEntry::new(2810, "MartianBathtub", "Martian Bathtub"),
+    NameEntry::new(2811, "MartianBed", "Martian Bed"),
+    NameEntry::new(2812, "MartianHoverChair", "Martian Hover Chair"),
+    NameEntry::new(2813, "MartianChandelier", "Martian Chandelier"),
+    NameEntry::new(2814, "MartianChest", "Martian Chest"),
+    NameEntry::new(2815, "MartianDoor", "Martian Door"),
+    NameEntry::new(2816, "MartianDresser", "Martian Dresser"),
+    NameEntry::new(2817, "MartianHolobookcase", "Martian Holobookcase"),
+    NameEntry::new(2818, "MartianHoverCandle", "Martian Hover Candle"),
+    NameEntry::new(2819, "MartianLamppost", "Martian Lamppost"),
+    NameEntry::new(2820, "MartianLantern", "Martian Lantern"),
+    NameEntry::new(2821, "MartianPiano", "Martian Piano"),
+    NameEntry::new(2822, "MartianPlatform", "Martian Platform"),
+    NameEntry::new(2823, "MartianSofa", "Martian Sofa"),
+    NameEntry::new(2824, "MartianTable", "Martian Table"),
+    NameEntry::new(2825, "MartianTableLamp", "Martian Table Lamp"),
+    NameEntry::new(2826, "MartianWorkBench", "Martian Work Bench"),
+    NameEntry::new(2827, "WoodenSink", "Wooden Sink"),
+    NameEntry::new(2828, "EbonwoodSink", "Ebonwood Sink"),
+    NameEntry::new(2829, "RichMahoganySink", "Rich Mahogany Sink"),
+    NameEntry::new(2830, "PearlwoodSink", "Pearlwood Sink"),
+    NameEntry::new(2831, "BoneSink", "Bone Sink"),
+    NameEntry::new(2832, "FleshSink", "Flesh Sink"),
+    NameEntry::new(2833, "LivingWoodSink", "Living Wood Sink"),
+    NameEntry::new(2834, "SkywareSink", "Skyware Sink"),
+    NameEntry::new(2835, "ShadewoodSink", "Shadewood Sink"),
+    NameEntry::new(2836, "LihzahrdSink", "Lihzahrd Sink"),
+    NameEntry::new(2837, "BlueDungeonSink", "Blue Dungeon Sink"),
+    NameEntry::new(2838, "GreenDungeonSink", "Green Dungeon Sink"),
+    NameEntry::new(2839, "PinkDungeonSink", "Pink Dungeon Sink"),
+    NameEntry::new(2840, "ObsidianSink", "Obsidian Sink"),
+    NameEntry::new(2841, "MetalSink", "Metal Sink"),
+    NameEntry::new(2842, "GlassSink", "Glass Sink"),
+    NameEntry::new(2843, "GoldenSink", "Golden Sink"),
+    NameEntry::new(2844, "HoneySink", "Honey Sink"),
+    NameEntry::new(2845, "SteampunkSink", "Steampunk Sink"),
+    NameEntry::new(2846, "PumpkinSink", "Pumpkin Sink"),
+    NameEntry::new(2847, "SpookySink", "Spooky Sink"),
+    NameEntry::new(2848, "FrozenSink", "Frozen Sink"),
+    NameEntry::new(2849, "DynastySink", "Dynasty Sink"),
+    NameEntry::new(2850, "PalmWoodSink", "Palm Wood Sink"),
+    NameEntry::new(2851, "MushroomSink", "Mushroom Sink"),
+    NameEntry::new(2852, "BorealWoodSink", "Boreal Wood Sink"),
+    NameEntry::new(2853, "SlimeSink", "Slime Sink"),
+    NameEntry::new(2854, "CactusSink", "Cactus Sink"),
+    NameEntry::new(2855, "MartianSink", "Martian Sink"),
+    NameEntry::new(2856, "WhiteLunaticHood", "Solar Cultist Hood"),
+    NameEntry::new(2857, "BlueLunaticHood", "Lunar Cultist Hood"),
+    NameEntry::new(2858, "WhiteLunaticRobe", "Solar Cultist Robe"),
+    NameEntry::new(2859, "BlueLunaticRobe", "Lunar Cultist Robe"),
+    NameEntry::new(2860, "MartianConduitPlating", "Martian Conduit Plating"),
+    NameEntry::new(2861, "MartianConduitWall", "Martian Conduit Wall"),
+    NameEntry::new(2862, "HiTekSunglasses", "HiTek Sunglasses"),
+    NameEntry::new(2863, "MartianHairDye", "Martian Hair Dye"),
+    NameEntry::new(2864, "MartianArmorDye", "Martian Dye"),
+    NameEntry::new(2865, "PaintingCastleMarsberg", "Castle Marsberg"),
+    NameEntry::new(2866, "PaintingMartiaLisa", "Martia Lisa"),
+    NameEntry::new(2867, "PaintingTheTruthIsUpThere", "The Truth Is Up There"),
+    NameEntry::new(2868, "SmokeBlock", "Smoke Block"),
+    NameEntry::new(2869, "LivingFlameDye", "Living Flame Dye"),
+    NameEntry::new(2870, "LivingRainbowDye", "Living Rainbow Dye"),
+    NameEntry::new(2871, "ShadowDye", "Shadow Dye"),
+    NameEntry::new(2872, "NegativeDye", "Negative Dye"),
+    NameEntry::new(2873, "LivingOceanDye", "Living Ocean Dye"),
+    NameEntry::new(2874, "BrownDye", "Brown Dye"),
+    NameEntry::new(2875, "BrownAndBlackDye", "Brown and Black Dye"),
+    NameEntry::new(2876, "BrightBrownDye", "Bright Brown Dye"),
+    NameEntry::new(2877, "BrownAndSilverDye", "Brown and Silver Dye"),
+    NameEntry::new(2878, "WispDye", "Wisp Dye"),
+    NameEntry::new(2879, "PixieDye", "Pixie Dye"),
+    NameEntry::new(2880, "InfluxWaver", "Influx Waver"),
+    NameEntry::new(2881, "PhasicWarpEjector", "Phasic Warp Ejector"),
+    NameEntry::new(2882, "ChargedBlasterCannon", "Charged Blaster Cannon"),
+    NameEntry::new(2883, "ChlorophyteDye", "Chlorophyte Dye"),
+    NameEntry::new(2884, "UnicornWispDye", "Unicorn Wisp Dye"),
+    NameEntry::new(2885, "InfernalWispDye", "Infernal Wisp Dye"),
+    NameEntry::new(2886, "ViciousPowder", "Vicious Powder"),
+    NameEntry::new(2887, "ViciousMushroom", "Vicious Mushroom"),
+    NameEntry::new(2888, "BeesKnees", "The Bee's Knees"),
+    NameEntry::new(2889, "GoldBird", "Gold Bird"),
+    NameEntry::new(2890, "GoldBunny", "Gold Bunny"),
+    NameEntry::new(2891, "GoldButterfly", "Gold Butterfly"),
+    NameEntry::new(2892, "GoldFrog", "Gold Frog"),
+    NameEntry::new(2893, "GoldGrasshopper", "Gold Grasshopper"),
+    NameEntry::new(2894, "GoldMouse", "Gold Mouse"),
+    NameEntry::new(2895, "GoldWorm", "Gold Worm"),
+    NameEntry::new(2896, "StickyDynamite", "Sticky Dynamite"),
+    NameEntry::new(2897, "AngryTrapperBanner", "Angry Trapper Banner"),
+    NameEntry::new(2898, "ArmoredVikingBanner", "Armored Viking Banner"),
+    NameEntry::new(2899, "BlackSlimeBanner", "Black Slime Banner"),
+    NameEntry::new(2900, "BlueArmoredBonesBanner", "Blue Armored Bones Banner"),
+    NameEntry::new(2901, "BlueCultistArcherBanner", "Blue Cultist Archer Banner"),
+    NameEntry::new(2902, "BlueCultistCasterBanner", "Lunatic Devotee Banner"),
+    NameEntry::new(2903, "BlueCultistFighterBanner", "Blue Cultist Fighter Banner"),
+    NameEntry::new(2904, "BoneLeeBanner", "Bone Lee Banner"),
+    NameEntry::new(2905, "ClingerBanner", "Clinger Banner"),
+    NameEntry::new(2906, "CochinealBeetleBanner", "Cochineal Beetle Banner"),
+    NameEntry::new(2907, "CorruptPenguinBanner", "Corrupt Penguin Banner"),
+    NameEntry::new(2908, "CorruptSlimeBanner", "Corrupt Slime Banner"),
+    NameEntry::new(2909, "CorruptorBanner", "Corruptor Banner"),
+    NameEntry::new(2910, "CrimslimeBanner", "Crimslime Banner"),
+    NameEntry::new(2911, "CursedSkullBanner", "Cursed Skull Banner"),
+    NameEntry::new(2912, "CyanBeetleBanner", "Cyan Beetle Banner"),
+    NameEntry::new(2913, "DevourerBanner", "Devourer Banner"),
+    NameEntry::new(2914, "DiablolistBanner", "Diabolist Banner"),
+    NameEntry::new(2915, "DoctorBonesBanner", "Doctor Bones Banner"),
+    NameEntry::new(2916, "DungeonSlimeBanner", "Dungeon Slime Banner"),
+    NameEntry::new(2917, "DungeonSpiritBanner", "Dungeon Spirit Banner"),
+    NameEntry::new(2918, "ElfArcherBanner", "Elf Archer Banner"),
+    NameEntry::new(2919, "ElfCopterBanner", "Elf Copter Banner"),
+    NameEntry::new(2920, "EyezorBanner", "Eyezor Banner"),
+    NameEntry::new(2921, "FlockoBanner", "Flocko Banner"),
+    NameEntry::new(2922, "GhostBanner", "Ghost Banner"),
+    NameEntry::new(2923, "GiantBatBanner", "Giant Bat Banner"),
+    NameEntry::new(2924, "GiantCursedSkullBanner", "Giant Cursed Skull Banner"),
+    NameEntry::new(2925, "GiantFlyingFoxBanner", "Giant Flying Fox Banner"),
+    NameEntry::new(2926, "GingerbreadManBanner", "Gingerbread Man Banner"),
+    NameEntry::new(2927, "GoblinArcherBanner", "Goblin Archer Banner"),
+    NameEntry::new(2928, "GreenSlimeBanner", "Green Slime Banner"),
+    NameEntry::new(2929, "HeadlessHorsemanBanner", "Headless Horseman Banner"),
+    NameEntry::new(2930, "HellArmoredBonesBanner", "Hell Armored Bones Banner"),
+    NameEntry::new(2931, "HellhoundBanner", "Hellhound Banner"),
+    NameEntry::new(2932, "HoppinJackBanner", "Hoppin' Jack Banner"),
+    NameEntry::new(2933, "IceBatBanner", "Ice Bat Banner"),
+    NameEntry::new(2934, "IceGolemBanner", "Ice Golem Banner"),
+    NameEntry::new(2935, "IceSlimeBanner", "Ice Slime Banner"),
+    NameEntry::new(2936, "IchorStickerBanner", "Ichor Sticker Banner"),
+    NameEntry::new(2937, "IlluminantBatBanner", "Illuminant Bat Banner"),
+    NameEntry::new(2938, "IlluminantSlimeBanner", "Illuminant Slime Banner"),
+    NameEntry::new(2939, "JungleBatBanner", "Jungle Bat Banner"),
+    NameEntry::new(2940, "JungleSlimeBanner", "Jungle Slime Banner"),
+    NameEntry::new(2941, "KrampusBanner", "Krampus Banner"),
+    NameEntry::new(2942, "LacBeetleBanner", "Lac Beetle Banner"),
+    NameEntry::new(2943, "LavaBatBanner", "Lava Bat Banner"),
+    NameEntry::new(2944, "LavaSlimeBanner", "Lava Slime Banner"),
+    NameEntry::new(2945, "MartianBrainscramblerBanner", "Martian Brain Scrambler Banner"),
+    NameEntry::new(2946, "MartianDroneBanner", "Martian Drone Banner"),
+    NameEntry::new(2947, "MartianEngineerBanner", "Martian Engineer Banner"),
+    NameEntry::new(2948, "MartianGigazapperBanner", "Martian Gigazapper Banner"),
+    NameEntry::new(2949, "MartianGreyGruntBanner", "Martian Gray Grunt Banner"),
+    NameEntry::new(2950, "MartianOfficerBanner", "Martian Officer Banner"),
+    NameEntry::new(2951, "MartianRaygunnerBanner", "Martian Ray Gunner Banner"),
+    NameEntry::new(2952, "MartianScutlixGunnerBanner", "Martian Scutlix Gunner Banner"),
+    NameEntry::new(2953, "MartianTeslaTurretBanner", "Martian Tesla Turret Banner"),
+    NameEntry::new(2954, "MisterStabbyBanner", "Mister Stabby Banner"),
+    NameEntry::new(2955, "MotherSlimeBanner", "Mother Slime Banner"),
+    NameEntry::new(2956, "NecromancerBanner", "Necromancer Banner"),
+    NameEntry::new(2957, "NutcrackerBanner", "Nutcracker Banner"),
+    NameEntry::new(2958, "PaladinBanner", "Paladin Banner"),
+    NameEntry::new(2959, "PenguinBanner", "Penguin Banner"),
+    NameEntry::new(2960, "PinkyBanner", "Pinky Banner"),
+    NameEntry::new(2961, "PoltergeistBanner", "Poltergeist Banner"),
+    NameEntry::new(2962, "PossessedArmorBanner", "Possessed Armor Banner"),
+    NameEntry::new(2963, "PresentMimicBanner", "Present Mimic Banner"),
+    NameEntry::new(2964, "PurpleSlimeBanner", "Purple Slime Banner"),
+    NameEntry::new(2965, "RaggedCasterBanner", "Ragged Caster Banner"),
+    NameEntry::new(2966, "RainbowSlimeBanner", "Rainbow Slime Banner"),
+    NameEntry::new(2967, "RavenBanner", "Raven Banner"),
+    NameEntry::new(2968, "RedSlimeBanner", "Red Slime Banner"),
+    NameEntry::new(2969, "RuneWizardBanner", "Rune Wizard Banner"),
+    NameEntry::new(2970, "RustyArmoredBonesBanner", "Rusty Armored Bones Banner"),
+    NameEntry::new(2971, "ScarecrowBanner", "Scarecrow Banner"),
+    NameEntry::new(2972, "ScutlixBanner", "Scutlix Banner"),
+    NameEntry::new(2973, "SkeletonArcherBanner", "Skeleton Archer Banner"),
+    NameEntry::new(2974, "SkeletonCommandoBanner", "Skeleton Commando Banner"),
+    NameEntry::new(2975, "SkeletonSniperBanner", "Skeleton Sniper Banner"),
+    NameEntry::new(2976, "SlimerBanner", "Slimer Banner"),
+    NameEntry::new(2977, "SnatcherBanner", "Snatcher Banner"),
+    NameEntry::new(2978, "SnowBallaBanner", "Snow Balla Banner"),
+    NameEntry::new(2979, "SnowmanGangstaBanner", "Snowman Gangsta Banner"),
+    NameEntry::new(2980, "SpikedIceSlimeBanner", "Spiked Ice Slime Banner"),
+    NameEntry::new(2981, "SpikedJungleSlimeBanner", "Spiked Jungle Slime Banner"),
+    NameEntry::new(2982, "SplinterlingBanner", "Splinterling Banner"),
+    NameEntry::new(2983, "SquidBanner", "Squid Banner"),
+    NameEntry::new(2984, "TacticalSkeletonBanner", "Tactical Skeleton Banner"),
+    NameEntry::new(2985, "TheGroomBanner", "The Groom Banner"),
+    NameEntry::new(2986, "TimBanner", "Tim Banner"),
+    NameEntry::new(2987, "UndeadMinerBanner", "Undead Miner Banner"),
+    NameEntry::new(2988, "UndeadVikingBanner", "Undead Viking Banner"),
+    NameEntry::new(2989, "WhiteCultistArcherBanner", "White Cultist Archer Banner"),
+    NameEntry::new(2990, "WhiteCultistCasterBanner", "White Cultist Caster Banner"),
+    NameEntry::new(2991, "WhiteCultistFighterBanner", "White Cultist Fighter Banner"),
+    NameEntry::new(2992, "YellowSlimeBanner", "Yellow Slime Banner"),
+    NameEntry::new(2993, "YetiBanner", "Yeti Banner"),
+    NameEntry::new(2994, "ZombieElfBanner", "Zombie Elf Banner"),
+    NameEntry::new(2995, "SparkyPainting", "Sparky"),
+    NameEntry::new(2996, "VineRope", "Vine Rope"),
+    NameEntry::new(2997, "WormholePotion", "Wormhole Potion"),
+    NameEntry::new(2998, "SummonerEmblem", "Summoner Emblem"),
+    NameEntry::new(2999, "BewitchingTable", "Bewitching Table"),
+    NameEntry::new(3000, "AlchemyTable", "Alchemy Table"),
+    NameEntry::new(3001, "StrangeBrew", "Strange Brew"),
+    NameEntry::new(3002, "SpelunkerGlowstick", "Spelunker Glowstick"),
+    NameEntry::new(3003, "BoneArrow", "Bone Arrow"),
+    NameEntry::new(3004, "BoneTorch", "Bone Torch"),
+    NameEntry::new(3005, "VineRopeCoil", "Vine Rope Coil"),
+    NameEntry::new(3006, "SoulDrain", "Life Drain"),
+    NameEntry::new(3007, "DartPistol", "Dart Pistol"),
+    NameEntry::new(3008, "DartRifle", "Dart Rifle"),
+    NameEntry::new(3009, "CrystalDart", "Crystal Dart"),
+    NameEntry::new(3010, "CursedDart", "Cursed Dart"),
+    NameEntry::new(3011, "IchorDart", "Ichor Dart"),
+    NameEntry::new(3012, "ChainGuillotines", "Chain Guillotines"),
+    NameEntry::new(3013, "FetidBaghnakhs", "Fetid Baghnakhs"),
+    NameEntry::new(3014, "ClingerStaff", "Clinger Staff"),
+    NameEntry::new(3015, "PutridScent", "Putrid Scent"),
+    NameEntry::new(3016, "FleshKnuckles", "Flesh Knuckles"),
+    NameEntry::new(3017, "FlowerBoots", "Flower Boots"),
+    NameEntry::new(3018, "Seedler", "Seedler"),
+    NameEntry::new(3019, "HellwingBow", "Hellwing Bow"),
+    NameEntry::new(3020, "TendonHook", "Tendon Hook"),
+    NameEntry::new(3021, "ThornHook", "Thorn Hook"),
+    NameEntry::new(3022, "IlluminantHook", "Illuminant Hook"),
+    NameEntry::new(3023, "WormHook", "Worm Hook"),
+    NameEntry::new(3024, "DevDye", "Skiphs' Blood"),
+    NameEntry::new(3025, "PurpleOozeDye", "Purple Ooze Dye"),
+    NameEntry::new(3026, "ReflectiveSilverDye", "Reflective Silver Dye"),
+    NameEntry::new(3027, "ReflectiveGoldDye", "Reflective Gold Dye"),
+    NameEntry::new(3028, "BlueAcidDye", "Blue Acid Dye"),
+    NameEntry::new(3029, "DaedalusStormbow", "Daedalus Stormbow"),
+    NameEntry::new(3030, "FlyingKnife", "Flying Knife"),
+    NameEntry::new(3031, "BottomlessBucket", "Bottomless Water Bucket"),
+    NameEntry::new(3032, "SuperAbsorbantSponge", "Super Absorbant Sponge"),
+    NameEntry::new(3033, "GoldRing", "Gold Ring"),
+    NameEntry::new(3034, "CoinRing", "Coin Ring"),
+    NameEntry::new(3035, "GreedyRing", "Greedy Ring"),
+    NameEntry::new(3036, "FishFinder", "Fish Finder"),
+    NameEntry::new(3037, "WeatherRadio", "Weather Radio"),
+    NameEntry::new(3038, "HadesDye", "Hades Dye"),
+    NameEntry::new(3039, "TwilightDye", "Twilight Dye"),
+    NameEntry::new(3040, "AcidDye", "Acid Dye"),
+    NameEntry::new(3041, "MushroomDye", "Glowing Mushroom Dye"),
+    NameEntry::new(3042, "PhaseDye", "Phase Dye"),
+    NameEntry::new(3043, "MagicLantern", "Magic Lantern"),
+    NameEntry::new(3044, "MusicBoxLunarBoss", "Music Box (Lunar Boss)"),
+    NameEntry::new(3045, "RainbowTorch", "Rainbow Torch"),
+    NameEntry::new(3046, "CursedCampfire", "Cursed Campfire"),
+    NameEntry::new(3047, "DemonCampfire", "Demon Campfire"),
+    NameEntry::new(3048, "FrozenCampfire", "Frozen Campfire"),
+    NameEntry::new(3049, "IchorCampfire", "Ichor Campfire"),
+    NameEntry::new(3050, "RainbowCampfire", "Rainbow Campfire"),
+    NameEntry::new(3051, "CrystalVileShard", "Crystal Vile Shard"),
+    NameEntry::new(3052, "ShadowFlameBow", "Shadowflame Bow"),
+    NameEntry::new(3053, "ShadowFlameHexDoll", "Shadowflame Hex Doll"),
+    NameEntry::new(3054, "ShadowFlameKnife", "Shadowflame Knife"),
+    NameEntry::new(3055, "PaintingAcorns", "Acorns"),
+    NameEntry::new(3056, "PaintingColdSnap", "Cold Snap"),
+    NameEntry::new(3057, "PaintingCursedSaint", "Cursed Saint"),
+    NameEntry::new(3058, "PaintingSnowfellas", "Snowfellas"),
+    NameEntry::new(3059, "PaintingTheSeason", "The Season"),
+    NameEntry::new(3060, "BoneRattle", "Bone Rattle"),
+    NameEntry::new(3061, "ArchitectGizmoPack", "Architect Gizmo Pack"),
+    NameEntry::new(3062, "CrimsonHeart", "Crimson Heart"),
+    NameEntry::new(3063, "Meowmere", "Meowmere"),
+    NameEntry::new(3064, "Sundial", "Enchanted Sundial"),
+    NameEntry::new(3065, "StarWrath", "Star Wrath"),
+    NameEntry::new(3066, "MarbleBlock", "Smooth Marble Block"),
+    NameEntry::new(3067, "HellstoneBrickWall", "Hellstone Brick Wall"),
+    NameEntry::new(3068, "CordageGuide", "Guide to Plant Fiber Cordage"),
+    NameEntry::new(3069, "WandofSparking", "Wand of Sparking"),
+    NameEntry::new(3070, "GoldBirdCage", "Gold Bird Cage"),
+    NameEntry::new(3071, "GoldBunnyCage", "Gold Bunny Cage"),
+    NameEntry::new(3072, "GoldButterflyCage", "Gold Butterfly Jar"),
+    NameEntry::new(3073, "GoldFrogCage", "Gold Frog Cage"),
+    NameEntry::new(3074, "GoldGrasshopperCage", "Gold Grasshopper Cage"),
+    NameEntry::new(3075, "GoldMouseCage", "Gold Mouse Cage"),
+    NameEntry::new(3076, "GoldWormCage", "Gold Worm Cage"),
+    NameEntry::new(3077, "SilkRope", "Silk Rope"),
+    NameEntry::new(3078, "WebRope", "Web Rope"),
+    NameEntry::new(3079, "SilkRopeCoil", "Silk Rope Coil"),
+    NameEntry::new(3080, "WebRopeCoil", "Web Rope Coil"),
+    NameEntry::new(3081, "Marble", "Marble Block"),
+    NameEntry::new(3082, "MarbleWall", "Marble Wall"),
+    NameEntry::new(3083, "MarbleBlockWall", "Smooth Marble Wall"),
+    NameEntry::new(3084, "Radar", "Radar"),
+    NameEntry::new(3085, "LockBox", "Golden Lock Box"),
+    NameEntry::new(3086, "Granite", "Granite Block"),
+    NameEntry::new(3087, "GraniteBlock", "Smooth Granite Block"),
+    NameEntry::new(3088, "GraniteWall", "Granite Wall"),
+    NameEntry::new(3089, "GraniteBlockWall", "Smooth Granite Wall"),
+    NameEntry::new(3090, "RoyalGel", "Royal Gel"),
+    NameEntry::new(3091, "NightKey", "Key of Night"),
+    NameEntry::new(3092, "LightKey", "Key of Light"),
+    NameEntry::new(3093, "HerbBag", "Herb Bag"),
+    NameEntry::new(3094, "Javelin", "Javelin"),
+    NameEntry::new(3095, "TallyCounter", "Tally Counter"),
+    NameEntry::new(3096, "Sextant", "Sextant"),
+    NameEntry::new(3097, "EoCShield", "Shield of Cthulhu"),
+    NameEntry::new(3098, "ButchersChainsaw", "Butcher's Chainsaw"),
+    NameEntry::new(3099, "Stopwatch", "Stopwatch"),
+    NameEntry::new(3100, "MeteoriteBrick", "Meteorite Brick"),
+    NameEntry::new(3101, "MeteoriteBrickWall", "Meteorite Brick Wall"),
+    NameEntry::new(3102, "MetalDetector", "Metal Detector"),
+    NameEntry::new(3103, "EndlessQuiver", "Endless Quiver"),
+    NameEntry::new(3104, "EndlessMusketPouch", "Endless Musket Pouch"),
+    NameEntry::new(3105, "ToxicFlask", "Toxic Flask"),
+    NameEntry::new(3106, "PsychoKnife", "Psycho Knife"),
+    NameEntry::new(3107, "NailGun", "Nail Gun"),
+    NameEntry::new(3108, "Nail", "Nail"),
+    NameEntry::new(3109, "NightVisionHelmet", "Night Vision Helmet"),
+    NameEntry::new(3110, "CelestialShell", "Celestial Shell"),
+    NameEntry::new(3111, "PinkGel", "Pink Gel"),
+    NameEntry::new(3112, "BouncyGlowstick", "Bouncy Glowstick"),
+    NameEntry::new(3113, "PinkSlimeBlock", "Pink Slime Block"),
+    NameEntry::new(3114, "PinkTorch", "Pink Torch"),
+    NameEntry::new(3115, "BouncyBomb", "Bouncy Bomb"),
+    NameEntry::new(3116, "BouncyGrenade", "Bouncy Grenade"),
+    NameEntry::new(3117, "PeaceCandle", "Peace Candle"),
+    NameEntry::new(3118, "LifeformAnalyzer", "Lifeform Analyzer"),
+    NameEntry::new(3119, "DPSMeter", "DPS Meter"),
+    NameEntry::new(3120, "FishermansGuide", "Fisherman's Pocket Guide"),
+    NameEntry::new(3121, "GoblinTech", "Goblin Tech"),
+    NameEntry::new(3122, "REK", "R.E.K. 3000"),
+    NameEntry::new(3123, "PDA", "PDA"),
+    NameEntry::new(3124, "CellPhone", "Cell Phone"),
+    NameEntry::new(3125, "GraniteChest", "Granite Chest"),
+    NameEntry::new(3126, "MeteoriteClock", "Meteorite Clock"),
+    NameEntry::new(3127, "MarbleClock", "Marble Clock"),
+    NameEntry::new(3128, "GraniteClock", "Granite Clock"),
+    NameEntry::new(3129, "MeteoriteDoor", "Meteorite Door"),
+    NameEntry::new(3130, "MarbleDoor", "Marble Door"),
+    NameEntry::new(3131, "GraniteDoor", "Granite Door"),
+    NameEntry::new(3132, "MeteoriteDresser", "Meteorite Dresser"),
+    NameEntry::new(3133, "MarbleDresser", "Marble Dresser"),
+    NameEntry::new(3134, "GraniteDresser", "Granite Dresser"),
+    NameEntry::new(3135, "MeteoriteLamp", "Meteorite Lamp"),
+    NameEntry::new(3136, "MarbleLamp", "Marble Lamp"),
+    NameEntry::new(3137, "GraniteLamp", "Granite Lamp"),
+    NameEntry::new(3138, "MeteoriteLantern", "Meteorite Lantern"),
+    NameEntry::new(3139, "MarbleLantern", "Marble Lantern"),
+    NameEntry::new(3140, "GraniteLantern", "Granite Lantern"),
+    NameEntry::new(3141, "MeteoritePiano", "Meteorite Piano"),
+    NameEntry::new(3142, "MarblePiano", "Marble Piano"),
+    NameEntry::new(3143, "GranitePiano", "Granite Piano"),
+    NameEntry::new(3144, "MeteoritePlatform", "Meteorite Platform"),
+    NameEntry::new(3145, "MarblePlatform", "Marble Platform"),
+    NameEntry::new(3146, "GranitePlatform", "Granite Platform"),
+    NameEntry::new(3147, "MeteoriteSink", "Meteorite Sink"),
+    NameEntry::new(3148, "MarbleSink", "Marble Sink"),
+    NameEntry::new(3149, "GraniteSink", "Granite Sink"),
+    NameEntry::new(3150, "MeteoriteSofa", "Meteorite Sofa"),
+    NameEntry::new(3151, "MarbleSofa", "Marble Sofa"),
+    NameEntry::new(3152, "GraniteSofa", "Granite Sofa"),
+    NameEntry::new(3153, "MeteoriteTable", "Meteorite Table"),
+    NameEntry::new(3154, "MarbleTable", "Marble Table"),
+    NameEntry::new(3155, "GraniteTable", "Granite Table"),
+    NameEntry::new(3156, "MeteoriteWorkBench", "Meteorite Work Bench"),
+    NameEntry::new(3157, "MarbleWorkBench", "Marble Work Bench"),
+    NameEntry::new(3158, "GraniteWorkBench", "Granite Work Bench"),
+    NameEntry::new(3159, "MeteoriteBathtub", "Meteorite Bathtub"),
+    NameEntry::new(3160, "MarbleBathtub", "Marble Bathtub"),
+    NameEntry::new(3161, "GraniteBathtub", "Granite Bathtub"),
+    NameEntry::new(3162, "MeteoriteBed", "Meteorite Bed"),
+    NameEntry::new(3163, "MarbleBed", "Marble Bed"),
+    NameEntry::new(3164, "GraniteBed", "Granite Bed"),
+    NameEntry::new(3165, "MeteoriteBookcase", "Meteorite Bookcase"),
+    NameEntry::new(3166, "MarbleBookcase", "Marble Bookcase"),
+    NameEntry::new(3167, "GraniteBookcase", "Granite Bookcase"),
+    NameEntry::new(3168, "MeteoriteCandelabra", "Meteorite Candelabra"),
+    NameEntry::new(3169, "MarbleCandelabra", "Marble Candelabra"),
+    NameEntry::new(3170, "GraniteCandelabra", "Granite Candelabra"),
+    NameEntry::new(3171, "MeteoriteCandle", "Meteorite Candle"),
+    NameEntry::new(3172, "MarbleCandle", "Marble Candle"),
+    NameEntry::new(3173, "GraniteCandle", "Granite Candle"),
+    NameEntry::new(3174, "MeteoriteChair", "Meteorite Chair"),
+    NameEntry::new(3175, "MarbleChair", "Marble Chair"),
+    NameEntry::new(3176, "GraniteChair", "Granite Chair"),
+    NameEntry::new(3177, "MeteoriteChandelier", "Meteorite Chandelier"),
+    NameEntry::new(3178, "MarbleChandelier", "Marble Chandelier"),
+    NameEntry::new(3179, "GraniteChandelier", "Granite Chandelier"),
+    NameEntry::new(3180, "MeteoriteChest", "Meteorite Chest"),
+    NameEntry::new(3181, "MarbleChest", "Marble Chest"),
+    NameEntry::new(3182, "MagicWaterDropper", "Magic Water Dropper"),
+    NameEntry::new(3183, "GoldenBugNet", "Golden Bug Net"),
+    NameEntry::new(3184, "MagicLavaDropper", "Magic Lava Dropper"),
+    NameEntry::new(3185, "MagicHoneyDropper", "Magic Honey Dropper"),
+    NameEntry::new(3186, "EmptyDropper", "Empty Dropper"),
+    NameEntry::new(3187, "GladiatorHelmet", "Gladiator Helmet"),
+    NameEntry::new(3188, "GladiatorBreastplate", "Gladiator Breastplate"),
+    NameEntry::new(3189, "GladiatorLeggings", "Gladiator Leggings"),
+    NameEntry::new(3190, "ReflectiveDye", "Reflective Dye"),
+    NameEntry::new(3191, "EnchantedNightcrawler", "Enchanted Nightcrawler"),
+    NameEntry::new(3192, "Grubby", "Grubby"),
+    NameEntry::new(3193, "Sluggy", "Sluggy"),
+    NameEntry::new(3194, "Buggy", "Buggy"),
+    NameEntry::new(3195, "GrubSoup", "Grub Soup"),
+    NameEntry::new(3196, "BombFish", "Bomb Fish"),
+    NameEntry::new(3197, "FrostDaggerfish", "Frost Daggerfish"),
+    NameEntry::new(3198, "SharpeningStation", "Sharpening Station"),
+    NameEntry::new(3199, "IceMirror", "Ice Mirror"),
+    NameEntry::new(3200, "SailfishBoots", "Sailfish Boots"),
+    NameEntry::new(3201, "TsunamiInABottle", "Tsunami in a Bottle"),
+    NameEntry::new(3202, "TargetDummy", "Target Dummy"),
+    NameEntry::new(3203, "CorruptFishingCrate", "Corrupt Crate"),
+    NameEntry::new(3204, "CrimsonFishingCrate", "Crimson Crate"),
+    NameEntry::new(3205, "DungeonFishingCrate", "Dungeon Crate"),
+    NameEntry::new(3206, "FloatingIslandFishingCrate", "Sky Crate"),
+    NameEntry::new(3207, "HallowedFishingCrate", "Hallowed Crate"),
+    NameEntry::new(3208, "JungleFishingCrate", "Jungle Crate"),
+    NameEntry::new(3209, "CrystalSerpent", "Crystal Serpent"),
+    NameEntry::new(3210, "Toxikarp", "Toxikarp"),
+    NameEntry::new(3211, "Bladetongue", "Bladetongue"),
+    NameEntry::new(3212, "SharkToothNecklace", "Shark Tooth Necklace"),
+    NameEntry::new(3213, "MoneyTrough", "Money Trough"),
+    NameEntry::new(3214, "Bubble", "Bubble"),
+    NameEntry::new(3215, "DayBloomPlanterBox", "Daybloom Planter Box"),
+    NameEntry::new(3216, "MoonglowPlanterBox", "Moonglow Planter Box"),
+    NameEntry::new(3217, "CorruptPlanterBox", "Deathweed Planter Box"),
+    NameEntry::new(3218, "CrimsonPlanterBox", "Deathweed Planter Box"),
+    NameEntry::new(3219, "BlinkrootPlanterBox", "Blinkroot Planter Box"),
+    NameEntry::new(3220, "WaterleafPlanterBox", "Waterleaf Planter Box"),
+    NameEntry::new(3221, "ShiverthornPlanterBox", "Shiverthorn Planter Box"),
+    NameEntry::new(3222, "FireBlossomPlanterBox", "Fireblossom Planter Box"),
+    NameEntry::new(3223, "BrainOfConfusion", "Brain of Confusion"),
+    NameEntry::new(3224, "WormScarf", "Worm Scarf"),
+    NameEntry::new(3225, "BalloonPufferfish", "Balloon Pufferfish"),
+    NameEntry::new(3226, "BejeweledValkyrieHead", "Lazure's Valkyrie Circlet"),
+    NameEntry::new(3227, "BejeweledValkyrieBody", "Lazure's Valkyrie Cloak"),
+    NameEntry::new(3228, "BejeweledValkyrieWing", "Lazure's Barrier Platform"),
+    NameEntry::new(3229, "RichGravestone1", "Golden Cross Grave Marker"),
+    NameEntry::new(3230, "RichGravestone2", "Golden Tombstone"),
+    NameEntry::new(3231, "RichGravestone3", "Golden Grave Marker"),
+    NameEntry::new(3232, "RichGravestone4", "Golden Gravestone"),
+    NameEntry::new(3233, "RichGravestone5", "Golden Headstone"),
+    NameEntry::new(3234, "CrystalBlock", "Crystal Block"),
+    NameEntry::new(3235, "MusicBoxMartians", "Music Box (Martian Madness)"),
+    NameEntry::new(3236, "MusicBoxPirates", "Music Box (Pirate Invasion)"),
+    NameEntry::new(3237, "MusicBoxHell", "Music Box (Hell)"),
+    NameEntry::new(3238, "CrystalBlockWall", "Crystal Block Wall"),
+    NameEntry::new(3239, "Trapdoor", "Trap Door"),
+    NameEntry::new(3240, "TallGate", "Tall Gate"),
+    NameEntry::new(3241, "SharkronBalloon", "Sharkron Balloon"),
+    NameEntry::new(3242, "TaxCollectorHat", "Tax Collector's Hat"),
+    NameEntry::new(3243, "TaxCollectorSuit", "Tax Collector's Suit"),
+    NameEntry::new(3244, "TaxCollectorPants", "Tax Collector's Pants"),
+    NameEntry::new(3245, "BoneGlove", "Bone Glove"),
+    NameEntry::new(3246, "ClothierJacket", "Clothier's Jacket"),
+    NameEntry::new(3247, "ClothierPants", "Clothier's Pants"),
+    NameEntry::new(3248, "DyeTraderTurban", "Dye Trader's Turban"),
+    NameEntry::new(3249, "DeadlySphereStaff", "Deadly Sphere Staff"),
+    NameEntry::new(3250, "BalloonHorseshoeFart", "Green Horseshoe Balloon"),
+    NameEntry::new(3251, "BalloonHorseshoeHoney", "Amber Horseshoe Balloon"),
+    NameEntry::new(3252, "BalloonHorseshoeSharkron", "Pink Horseshoe Balloon"),
+    NameEntry::new(3253, "LavaLamp", "Lava Lamp"),
+    NameEntry::new(3254, "CageEnchantedNightcrawler", "Enchanted Nightcrawler Cage"),
+    NameEntry::new(3255, "CageBuggy", "Buggy Cage"),
+    NameEntry::new(3256, "CageGrubby", "Grubby Cage"),
+    NameEntry::new(3257, "CageSluggy", "Sluggy Cage"),
+    NameEntry::new(3258, "SlapHand", "Slap Hand"),
+    NameEntry::new(3259, "TwilightHairDye", "Twilight Hair Dye"),
+    NameEntry::new(3260, "BlessedApple", "Blessed Apple"),
+    NameEntry::new(3261, "SpectreBar", "Spectre Bar"),
+    NameEntry::new(3262, "Code1", "Code 1"),
+    NameEntry::new(3263, "BuccaneerBandana", "Buccaneer Bandana"),
+    NameEntry::new(3264, "BuccaneerShirt", "Buccaneer Tunic"),
+    NameEntry::new(3265, "BuccaneerPants", "Buccaneer Pantaloons"),
+    NameEntry::new(3266, "ObsidianHelm", "Obsidian Outlaw Hat"),
+    NameEntry::new(3267, "ObsidianShirt", "Obsidian Longcoat"),
+    NameEntry::new(3268, "ObsidianPants", "Obsidian Pants"),
+    NameEntry::new(3269, "MedusaHead", "Medusa Head"),
+    NameEntry::new(3270, "ItemFrame", "Item Frame"),
+    NameEntry::new(3271, "Sandstone", "Sandstone Block"),
+    NameEntry::new(3272, "HardenedSand", "Hardened Sand Block"),
+    NameEntry::new(3273, "SandstoneWall", "Sandstone Wall"),
+    NameEntry::new(3274, "CorruptHardenedSand", "Hardened Ebonsand Block"),
+    NameEntry::new(3275, "CrimsonHardenedSand", "Hardened Crimsand Block"),
+    NameEntry::new(3276, "CorruptSandstone", "Ebonsandstone Block"),
+    NameEntry::new(3277, "CrimsonSandstone", "Crimsandstone Block"),
+    NameEntry::new(3278, "WoodYoyo", "Wooden Yoyo"),
+    NameEntry::new(3279, "CorruptYoyo", "Malaise"),
+    NameEntry::new(3280, "CrimsonYoyo", "Artery"),
+    NameEntry::new(3281, "JungleYoyo", "Amazon"),
+    NameEntry::new(3282, "Cascade", "Cascade"),
+    NameEntry::new(3283, "Chik", "Chik"),
+    NameEntry::new(3284, "Code2", "Code 2"),
+    NameEntry::new(3285, "Rally", "Rally"),
+    NameEntry::new(3286, "Yelets", "Yelets"),
+    NameEntry::new(3287, "RedsYoyo", "Red's Throw"),
+    NameEntry::new(3288, "ValkyrieYoyo", "Valkyrie Yoyo"),
+    NameEntry::new(3289, "Amarok", "Amarok"),
+    NameEntry::new(3290, "HelFire", "Hel-Fire"),
+    NameEntry::new(3291, "Kraken", "Kraken"),
+    NameEntry::new(3292, "TheEyeOfCthulhu", "The Eye of Cthulhu"),
+    NameEntry::new(3293, "RedString", "Red String"),
+    NameEntry::new(3294, "OrangeString", "Orange String"),
+    NameEntry::new(3295, "YellowString", "Yellow String"),
+    NameEntry::new(3296, "LimeString", "Lime String"),
+    NameEntry::new(3297, "GreenString", "Green String"),
+    NameEntry::new(3298, "TealString", "Teal String"),
+    NameEntry::new(3299, "CyanString", "Cyan String"),
+    NameEntry::new(3300, "SkyBlueString", "Sky Blue String"),
+    NameEntry::new(3301, "BlueString", "Blue String"),
+    NameEntry::new(3302, "PurpleString", "Purple String"),
+    NameEntry::new(3303, "VioletString", "Violet String"),
+    NameEntry::new(3304, "PinkString", "Pink String"),
+    NameEntry::new(3305, "BrownString", "Brown String"),
+    NameEntry::new(3306, "WhiteString", "White String"),
+    NameEntry::new(3307, "RainbowString", "Rainbow String"),
+    NameEntry::new(3308, "BlackString", "Black String"),
+    NameEntry::new(3309, "BlackCounterweight", "Black Counterweight"),
+    NameEntry::new(3310, "BlueCounterweight", "Blue Counterweight"),
+    NameEntry::new(3311, "GreenCounterweight", "Green Counterweight"),
+    NameEntry::new(3312, "PurpleCounterweight", "Purple Counterweight"),
+    NameEntry::new(3313, "RedCounterweight", "Red Counterweight"),
+    NameEntry::new(3314, "YellowCounterweight", "Yellow Counterweight"),
+    NameEntry::new(3315, "FormatC", "Format:C"),
+    NameEntry::new(3316, "Gradient", "Gradient"),
+    NameEntry::new(3317, "Valor", "Valor"),
+    NameEntry::new(3318, "KingSlimeBossBag", "Treasure Bag (King Slime)"),
+    NameEntry::new(3319, "EyeOfCthulhuBossBag", "Treasure Bag (Eye of Cthulhu)"),
+    NameEntry::new(3320, "EaterOfWorldsBossBag", "Treasure Bag (Eater of Worlds)"),
+    NameEntry::new(3321, "BrainOfCthulhuBossBag", "Treasure Bag (Brain of Cthulhu)"),
+    NameEntry::new(3322, "QueenBeeBossBag", "Treasure Bag (Queen Bee)"),
+    NameEntry::new(3323, "SkeletronBossBag", "Treasure Bag (Skeletron)"),
+    NameEntry::new(3324, "WallOfFleshBossBag", "Treasure Bag (Wall of Flesh)"),
+    NameEntry::new(3325, "DestroyerBossBag", "Treasure Bag (The Destroyer)"),
+    NameEntry::new(3326, "TwinsBossBag", "Treasure Bag (The Twins)"),
+    NameEntry::new(3327, "SkeletronPrimeBossBag", "Treasure Bag (Skeletron Prime)"),
+    NameEntry::new(3328, "PlanteraBossBag", "Treasure Bag (Plantera)"),
+    NameEntry::new(3329, "GolemBossBag", "Treasure Bag (Golem)"),
+    NameEntry::new(3330, "FishronBossBag", "Treasure Bag (Duke Fishron)"),
+    NameEntry::new(3331, "CultistBossBag", "Treasure Bag (Lunatic Cultist)"),
+    NameEntry::new(3332, "MoonLordBossBag", "Treasure Bag (Moon Lord)"),
+    NameEntry::new(3333, "HiveBackpack", "Hive Pack"),
+    NameEntry::new(3334, "YoYoGlove", "Yoyo Glove"),
+    NameEntry::new(3335, "DemonHeart", "Demon Heart"),
+    NameEntry::new(3336, "SporeSac", "Spore Sac"),
+    NameEntry::new(3337, "ShinyStone", "Shiny Stone"),
+    NameEntry::new(3338, "HallowHardenedSand", "Hardened Pearlsand Block"),
+    NameEntry::new(3339, "HallowSandstone", "Pearlsandstone Block"),
+    NameEntry::new(3340, "HardenedSandWall", "Hardened Sand Wall"),
+    NameEntry::new(3341, "CorruptHardenedSandWall", "Hardened Ebonsand Wall"),
+    NameEntry::new(3342, "CrimsonHardenedSandWall", "Hardened Crimsand Wall"),
+    NameEntry::new(3343, "HallowHardenedSandWall", "Hardened Pearlsand Wall"),
+    NameEntry::new(3344, "CorruptSandstoneWall", "Ebonsandstone Wall"),
+    NameEntry::new(3345, "CrimsonSandstoneWall", "Crimsandstone Wall"),
+    NameEntry::new(3346, "HallowSandstoneWall", "Pearlsandstone Wall"),
+    NameEntry::new(3347, "DesertFossil", "Desert Fossil"),
+    NameEntry::new(3348, "DesertFossilWall", "Desert Fossil Wall"),
+    NameEntry::new(3349, "DyeTradersScimitar", "Exotic Scimitar"),
+    NameEntry::new(3350, "PainterPaintballGun", "Paintball Gun"),
+    NameEntry::new(3351, "TaxCollectorsStickOfDoom", "Classy Cane"),
+    NameEntry::new(3352, "StylistKilLaKillScissorsIWish", "Stylish Scissors"),
+    NameEntry::new(3353, "MinecartMech", "Mechanical Cart"),
+    NameEntry::new(3354, "MechanicalWheelPiece", "Mechanical Wheel Piece"),
+    NameEntry::new(3355, "MechanicalWagonPiece", "Mechanical Wagon Piece"),
+    NameEntry::new(3356, "MechanicalBatteryPiece", "Mechanical Battery Piece"),
+    NameEntry::new(3357, "AncientCultistTrophy", "Lunatic Cultist Trophy"),
+    NameEntry::new(3358, "MartianSaucerTrophy", "Martian Saucer Trophy"),
+    NameEntry::new(3359, "FlyingDutchmanTrophy", "Flying Dutchman Trophy"),
+    NameEntry::new(3360, "LivingMahoganyWand", "Living Mahogany Wand"),
+    NameEntry::new(3361, "LivingMahoganyLeafWand", "Rich Mahogany Leaf Wand"),
+    NameEntry::new(3362, "FallenTuxedoShirt", "Fallen Tuxedo Shirt"),
+    NameEntry::new(3363, "FallenTuxedoPants", "Fallen Tuxedo Pants"),
+    NameEntry::new(3364, "Fireplace", "Fireplace"),
+    NameEntry::new(3365, "Chimney", "Chimney"),
+    NameEntry::new(3366, "YoyoBag", "Yoyo Bag"),
+    NameEntry::new(3367, "ShrimpyTruffle", "Shrimpy Truffle"),
+    NameEntry::new(3368, "Arkhalis", "Arkhalis"),
+    NameEntry::new(3369, "ConfettiCannon", "Confetti Cannon"),
+    NameEntry::new(3370, "MusicBoxTowers", "Music Box (The Towers)"),
+    NameEntry::new(3371, "MusicBoxGoblins", "Music Box (Goblin Invasion)"),
+    NameEntry::new(3372, "BossMaskCultist", "Lunatic Cultist Mask"),
+    NameEntry::new(3373, "BossMaskMoonlord", "Moon Lord Mask"),
+    NameEntry::new(3374, "FossilHelm", "Fossil Helmet"),
+    NameEntry::new(3375, "FossilShirt", "Fossil Plate"),
+    NameEntry::new(3376, "FossilPants", "Fossil Greaves"),
+    NameEntry::new(3377, "AmberStaff", "Amber Staff"),
+    NameEntry::new(3378, "BoneJavelin", "Bone Javelin"),
+    NameEntry::new(3379, "BoneDagger", "Bone Throwing Knife"),
+    NameEntry::new(3380, "FossilOre", "Sturdy Fossil"),
+    NameEntry::new(3381, "StardustHelmet", "Stardust Helmet"),
+    NameEntry::new(3382, "StardustBreastplate", "Stardust Plate"),
+    NameEntry::new(3383, "StardustLeggings", "Stardust Leggings"),
+    NameEntry::new(3384, "PortalGun", "Portal Gun"),
+    NameEntry::new(3385, "StrangePlant1", "Strange Plant"),
+    NameEntry::new(3386, "StrangePlant2", "Strange Plant"),
+    NameEntry::new(3387, "StrangePlant3", "Strange Plant"),
+    NameEntry::new(3388, "StrangePlant4", "Strange Plant"),
+    NameEntry::new(3389, "Terrarian", "Terrarian"),
+    NameEntry::new(3390, "GoblinSummonerBanner", "Goblin Warlock Banner"),
+    NameEntry::new(3391, "SalamanderBanner", "Salamander Banner"),
+    NameEntry::new(3392, "GiantShellyBanner", "Giant Shelly Banner"),
+    NameEntry::new(3393, "CrawdadBanner", "Crawdad Banner"),
+    NameEntry::new(3394, "FritzBanner", "Fritz Banner"),
+    NameEntry::new(3395, "CreatureFromTheDeepBanner", "Creature From The Deep Banner"),
+    NameEntry::new(3396, "DrManFlyBanner", "Dr. Man Fly Banner"),
+    NameEntry::new(3397, "MothronBanner", "Mothron Banner"),
+    NameEntry::new(3398, "SeveredHandBanner", "Severed Hand Banner"),
+    NameEntry::new(3399, "ThePossessedBanner", "The Possessed Banner"),
+    NameEntry::new(3400, "ButcherBanner", "Butcher Banner"),
+    NameEntry::new(3401, "PsychoBanner", "Psycho Banner"),
+    NameEntry::new(3402, "DeadlySphereBanner", "Deadly Sphere Banner"),
+    NameEntry::new(3403, "NailheadBanner", "Nailhead Banner"),
+    NameEntry::new(3404, "PoisonousSporeBanner", "Poisonous Spore Banner"),
+    NameEntry::new(3405, "MedusaBanner", "Medusa Banner"),
+    NameEntry::new(3406, "GreekSkeletonBanner", "Hoplite Banner"),
+    NameEntry::new(3407, "GraniteFlyerBanner", "Granite Elemental Banner"),
+    NameEntry::new(3408, "GraniteGolemBanner", "Granite Golem Banner"),
+    NameEntry::new(3409, "BloodZombieBanner", "Blood Zombie Banner"),
+    NameEntry::new(3410, "DripplerBanner", "Drippler Banner"),
+    NameEntry::new(3411, "TombCrawlerBanner", "Tomb Crawler Banner"),
+    NameEntry::new(3412, "DuneSplicerBanner", "Dune Splicer Banner"),
+    NameEntry::new(3413, "FlyingAntlionBanner", "Antlion Swarmer Banner"),
+    NameEntry::new(3414, "WalkingAntlionBanner", "Antlion Charger Banner"),
+    NameEntry::new(3415, "DesertGhoulBanner", "Ghoul Banner"),
+    NameEntry::new(3416, "DesertLamiaBanner", "Lamia Banner"),
+    NameEntry::new(3417, "DesertDjinnBanner", "Desert Spirit Banner"),
+    NameEntry::new(3418, "DesertBasiliskBanner", "Basilisk Banner"),
+    NameEntry::new(3419, "RavagerScorpionBanner", "Sand Poacher Banner"),
+    NameEntry::new(3420, "StardustSoldierBanner", "Stargazer Banner"),
+    NameEntry::new(3421, "StardustWormBanner", "Milkyway Weaver Banner"),
+    NameEntry::new(3422, "StardustJellyfishBanner", "Flow Invader Banner"),
+    NameEntry::new(3423, "StardustSpiderBanner", "Twinkle Popper Banner"),
+    NameEntry::new(3424, "StardustSmallCellBanner", "Mini Star Cell Banner"),
+    NameEntry::new(3425, "StardustLargeCellBanner", "Star Cell Banner"),
+    NameEntry::new(3426, "SolarCoriteBanner", "Corite Banner"),
+    NameEntry::new(3427, "SolarSrollerBanner", "Sroller Banner"),
+    NameEntry::new(3428, "SolarCrawltipedeBanner", "Crawltipede Banner"),
+    NameEntry::new(3429, "SolarDrakomireRiderBanner", "Drakomire Rider Banner"),
+    NameEntry::new(3430, "SolarDrakomireBanner", "Drakomire Banner"),
+    NameEntry::new(3431, "SolarSolenianBanner", "Selenian Banner"),
+    NameEntry::new(3432, "NebulaSoldierBanner", "Predictor Banner"),
+    NameEntry::new(3433, "NebulaHeadcrabBanner", "Brain Suckler Banner"),
+    NameEntry::new(3434, "NebulaBrainBanner", "Nebula Floater Banner"),
+    NameEntry::new(3435, "NebulaBeastBanner", "Evolution Beast Banner"),
+    NameEntry::new(3436, "VortexLarvaBanner", "Alien Larva Banner"),
+    NameEntry::new(3437, "VortexHornetQueenBanner", "Alien Queen Banner"),
+    NameEntry::new(3438, "VortexHornetBanner", "Alien Hornet Banner"),
+    NameEntry::new(3439, "VortexSoldierBanner", "Vortexian Banner"),
+    NameEntry::new(3440, "VortexRiflemanBanner", "Storm Diver Banner"),
+    NameEntry::new(3441, "PirateCaptainBanner", "Pirate Captain Banner"),
+    NameEntry::new(3442, "PirateDeadeyeBanner", "Pirate Deadeye Banner"),
+    NameEntry::new(3443, "PirateCorsairBanner", "Pirate Corsair Banner"),
+    NameEntry::new(3444, "PirateCrossbowerBanner", "Pirate Crossbower Banner"),
+    NameEntry::new(3445, "MartianWalkerBanner", "Martian Walker Banner"),
+    NameEntry::new(3446, "RedDevilBanner", "Red Devil Banner"),
+    NameEntry::new(3447, "PinkJellyfishBanner", "Pink Jellyfish Banner"),
+    NameEntry::new(3448, "GreenJellyfishBanner", "Green Jellyfish Banner"),
+    NameEntry::new(3449, "DarkMummyBanner", "Dark Mummy Banner"),
+    NameEntry::new(3450, "LightMummyBanner", "Light Mummy Banner"),
+    NameEntry::new(3451, "AngryBonesBanner", "Angry Bones Banner"),
+    NameEntry::new(3452, "IceTortoiseBanner", "Ice Tortoise Banner"),
+    NameEntry::new(3453, "NebulaPickup1", "Damage Booster"),
+    NameEntry::new(3454, "NebulaPickup2", "Life Booster"),
+    NameEntry::new(3455, "NebulaPickup3", "Mana Booster"),
+    NameEntry::new(3456, "FragmentVortex", "Vortex Fragment"),
+    NameEntry::new(3457, "FragmentNebula", "Nebula Fragment"),
+    NameEntry::new(3458, "FragmentSolar", "Solar Fragment"),
+    NameEntry::new(3459, "FragmentStardust", "Stardust Fragment"),
+    NameEntry::new(3460, "LunarOre", "Luminite"),
+    NameEntry::new(3461, "LunarBrick", "Luminite Brick"),
+    NameEntry::new(3462, "StardustAxe", "Stardust Axe"),
+    NameEntry::new(3463, "StardustChainsaw", "Stardust Chainsaw"),
+    NameEntry::new(3464, "StardustDrill", "Stardust Drill"),
+    NameEntry::new(3465, "StardustHammer", "Stardust Hammer"),
+    NameEntry::new(3466, "StardustPickaxe", "Stardust Pickaxe"),
+    NameEntry::new(3467, "LunarBar", "Luminite Bar"),
+    NameEntry::new(3468, "WingsSolar", "Solar Wings"),
+    NameEntry::new(3469, "WingsVortex", "Vortex Booster"),
+    NameEntry::new(3470, "WingsNebula", "Nebula Mantle"),
+    NameEntry::new(3471, "WingsStardust", "Stardust Wings"),
+    NameEntry::new(3472, "LunarBrickWall", "Luminite Brick Wall"),
+    NameEntry::new(3473, "SolarEruption", "Solar Eruption"),
+    NameEntry::new(3474, "StardustCellStaff", "Stardust Cell Staff"),
+    NameEntry::new(3475, "VortexBeater", "Vortex Beater"),
+    NameEntry::new(3476, "NebulaArcanum", "Nebula Arcanum"),
+    NameEntry::new(3477, "BloodWater", "Blood Water"),
+    NameEntry::new(3478, "TheBrideHat", "Wedding Veil"),
+    NameEntry::new(3479, "TheBrideDress", "Wedding Dress"),
+    NameEntry::new(3480, "PlatinumBow", "Platinum Bow"),
+    NameEntry::new(3481, "PlatinumHammer", "Platinum Hammer"),
+    NameEntry::new(3482, "PlatinumAxe", "Platinum Axe"),
+    NameEntry::new(3483, "PlatinumShortsword", "Platinum Shortsword"),
+    NameEntry::new(3484, "PlatinumBroadsword", "Platinum Broadsword"),
+    NameEntry::new(3485, "PlatinumPickaxe", "Platinum Pickaxe"),
+    NameEntry::new(3486, "TungstenBow", "Tungsten Bow"),
+    NameEntry::new(3487, "TungstenHammer", "Tungsten Hammer"),
+    NameEntry::new(3488, "TungstenAxe", "Tungsten Axe"),
+    NameEntry::new(3489, "TungstenShortsword", "Tungsten Shortsword"),
+    NameEntry::new(3490, "TungstenBroadsword", "Tungsten Broadsword"),
+    NameEntry::new(3491, "TungstenPickaxe", "Tungsten Pickaxe"),
+    NameEntry::new(3492, "LeadBow", "Lead Bow"),
+    NameEntry::new(3493, "LeadHammer", "Lead Hammer"),
+    NameEntry::new(3494, "LeadAxe", "Lead Axe"),
+    NameEntry::new(3495, "LeadShortsword", "Lead Shortsword"),
+    NameEntry::new(3496, "LeadBroadsword", "Lead Broadsword"),
+    NameEntry::new(3497, "LeadPickaxe", "Lead Pickaxe"),
+    NameEntry::new(3498, "TinBow", "Tin Bow"),
+    NameEntry::new(3499, "TinHammer", "Tin Hammer"),
+    NameEntry::new(3500, "TinAxe", "Tin Axe"),
+    NameEntry::new(3501, "TinShortsword", "Tin Shortsword"),
+    NameEntry::new(3502, "TinBroadsword", "Tin Broadsword"),
+    NameEntry::new(3503, "TinPickaxe", "Tin Pickaxe"),
+    NameEntry::new(3504, "CopperBow", "Copper Bow"),
+    NameEntry::new(3505, "CopperHammer", "Copper Hammer"),
+    NameEntry::new(3506, "CopperAxe", "Copper Axe"),
+    NameEntry::new(3507, "CopperShortsword", "Copper Shortsword"),
+    NameEntry::new(3508, "CopperBroadsword", "Copper Broadsword"),
+    NameEntry::new(3509, "CopperPickaxe", "Copper Pickaxe"),
+    NameEntry::new(3510, "SilverBow", "Silver Bow"),
+    NameEntry::new(3511, "SilverHammer", "Silver Hammer"),
+    NameEntry::new(3512, "SilverAxe", "Silver Axe"),
+    NameEntry::new(3513, "SilverShortsword", "Silver Shortsword"),
+    NameEntry::new(3514, "SilverBroadsword", "Silver Broadsword"),
+    NameEntry::new(3515, "SilverPickaxe", "Silver Pickaxe"),
+    NameEntry::new(3516, "GoldBow", "Gold Bow"),
+    NameEntry::new(3517, "GoldHammer", "Gold Hammer"),
+    NameEntry::new(3518, "GoldAxe", "Gold Axe"),
+    NameEntry::new(3519, "GoldShortsword", "Gold Shortsword"),
+    NameEntry::new(3520, "GoldBroadsword", "Gold Broadsword"),
+    NameEntry::new(3521, "GoldPickaxe", "Gold Pickaxe"),
+    NameEntry::new(3522, "LunarHamaxeSolar", "Solar Flare Hamaxe"),
+    NameEntry::new(3523, "LunarHamaxeVortex", "Vortex Hamaxe"),
+    NameEntry::new(3524, "LunarHamaxeNebula", "Nebula Hamaxe"),
+    NameEntry::new(3525, "LunarHamaxeStardust", "Stardust Hamaxe"),
+    NameEntry::new(3526, "SolarDye", "Solar Dye"),
+    NameEntry::new(3527, "NebulaDye", "Nebula Dye"),
+    NameEntry::new(3528, "VortexDye", "Vortex Dye"),
+    NameEntry::new(3529, "StardustDye", "Stardust Dye"),
+    NameEntry::new(3530, "VoidDye", "Void Dye"),
+    NameEntry::new(3531, "StardustDragonStaff", "Stardust Dragon Staff"),
+    NameEntry::new(3532, "Bacon", "Bacon"),
+    NameEntry::new(3533, "ShiftingSandsDye", "Shifting Sands Dye"),
+    NameEntry::new(3534, "MirageDye", "Mirage Dye"),
+    NameEntry::new(3535, "ShiftingPearlSandsDye", "Shifting Pearlsands Dye"),
+    NameEntry::new(3536, "VortexMonolith", "Vortex Monolith"),
+    NameEntry::new(3537, "NebulaMonolith", "Nebula Monolith"),
+    NameEntry::new(3538, "StardustMonolith", "Stardust Monolith"),
+    NameEntry::new(3539, "SolarMonolith", "Solar Monolith"),
+    NameEntry::new(3540, "Phantasm", "Phantasm"),
+    NameEntry::new(3541, "LastPrism", "Last Prism"),
+    NameEntry::new(3542, "NebulaBlaze", "Nebula Blaze"),
+    NameEntry::new(3543, "DayBreak", "Daybreak"),
+    NameEntry::new(3544, "SuperHealingPotion", "Super Healing Potion"),
+    NameEntry::new(3545, "Detonator", "Detonator"),
+    NameEntry::new(3546, "FireworksLauncher", "Celebration"),
+    NameEntry::new(3547, "BouncyDynamite", "Bouncy Dynamite"),
+    NameEntry::new(3548, "PartyGirlGrenade", "Happy Grenade"),
+    NameEntry::new(3549, "LunarCraftingStation", "Ancient Manipulator"),
+    NameEntry::new(3550, "FlameAndSilverDye", "Flame and Silver Dye"),
+    NameEntry::new(3551, "GreenFlameAndSilverDye", "Green Flame and Silver Dye"),
+    NameEntry::new(3552, "BlueFlameAndSilverDye", "Blue Flame and Silver Dye"),
+    NameEntry::new(3553, "ReflectiveCopperDye", "Reflective Copper Dye"),
+    NameEntry::new(3554, "ReflectiveObsidianDye", "Reflective Obsidian Dye"),
+    NameEntry::new(3555, "ReflectiveMetalDye", "Reflective Metal Dye"),
+    NameEntry::new(3556, "MidnightRainbowDye", "Midnight Rainbow Dye"),
+    NameEntry::new(3557, "BlackAndWhiteDye", "Black and White Dye"),
+    NameEntry::new(3558, "BrightSilverDye", "Bright Silver Dye"),
+    NameEntry::new(3559, "SilverAndBlackDye", "Silver and Black Dye"),
+    NameEntry::new(3560, "RedAcidDye", "Red Acid Dye"),
+    NameEntry::new(3561, "GelDye", "Gel Dye"),
+    NameEntry::new(3562, "PinkGelDye", "Pink Gel Dye"),
+    NameEntry::new(3563, "SquirrelRed", "Red Squirrel"),
+    NameEntry::new(3564, "SquirrelGold", "Gold Squirrel"),
+    NameEntry::new(3565, "SquirrelOrangeCage", "Red Squirrel Cage"),
+    NameEntry::new(3566, "SquirrelGoldCage", "Gold Squirrel Cage"),
+    NameEntry::new(3567, "MoonlordBullet", "Luminite Bullet"),
+    NameEntry::new(3568, "MoonlordArrow", "Luminite Arrow"),
+    NameEntry::new(3569, "MoonlordTurretStaff", "Lunar Portal Staff"),
+    NameEntry::new(3570, "LunarFlareBook", "Lunar Flare"),
+    NameEntry::new(3571, "RainbowCrystalStaff", "Rainbow Crystal Staff"),
+    NameEntry::new(3572, "LunarHook", "Lunar Hook"),
+    NameEntry::new(3573, "LunarBlockSolar", "Solar Fragment Block"),
+    NameEntry::new(3574, "LunarBlockVortex", "Vortex Fragment Block"),
+    NameEntry::new(3575, "LunarBlockNebula", "Nebula Fragment Block"),
+    NameEntry::new(3576, "LunarBlockStardust", "Stardust Fragment Block"),
+    NameEntry::new(3577, "SuspiciousLookingTentacle", "Suspicious Looking Tentacle"),
+    NameEntry::new(3578, "Yoraiz0rShirt", "Yoraiz0r's Uniform"),
+    NameEntry::new(3579, "Yoraiz0rPants", "Yoraiz0r's Skirt"),
+    NameEntry::new(3580, "Yoraiz0rWings", "Yoraiz0r's Spell"),
+    NameEntry::new(3581, "Yoraiz0rDarkness", "Yoraiz0r's Scowl"),
+    NameEntry::new(3582, "JimsWings", "Jim's Wings"),
+    NameEntry::new(3583, "Yoraiz0rHead", "Yoraiz0r's Recolored Goggles"),
+    NameEntry::new(3584, "LivingLeafWall", "Living Leaf Wall"),
+    NameEntry::new(3585, "SkiphsHelm", "Skiphs' Mask"),
+    NameEntry::new(3586, "SkiphsShirt", "Skiphs' Skin"),
+    NameEntry::new(3587, "SkiphsPants", "Skiphs' Bear Butt"),
+    NameEntry::new(3588, "SkiphsWings", "Skiphs' Paws"),
+    NameEntry::new(3589, "LokisHelm", "Loki's Helmet"),
+    NameEntry::new(3590, "LokisShirt", "Loki's Breastplate"),
+    NameEntry::new(3591, "LokisPants", "Loki's Greaves"),
+    NameEntry::new(3592, "LokisWings", "Loki's Wings"),
+    NameEntry::new(3593, "SandSlimeBanner", "Sand Slime Banner"),
+    NameEntry::new(3594, "SeaSnailBanner", "Sea Snail Banner"),
+    NameEntry::new(3595, "MoonLordTrophy", "Moon Lord Trophy"),
+    NameEntry::new(3596, "MoonLordPainting", "Not a Kid, nor a Squid"),
+    NameEntry::new(3597, "BurningHadesDye", "Burning Hades Dye"),
+    NameEntry::new(3598, "GrimDye", "Grim Dye"),
+    NameEntry::new(3599, "LokisDye", "Loki's Dye"),
+    NameEntry::new(3600, "ShadowflameHadesDye", "Shadowflame Hades Dye"),
+    NameEntry::new(3601, "CelestialSigil", "Celestial Sigil"),
+    NameEntry::new(3602, "LogicGateLamp_Off", "Logic Gate Lamp (Off)"),
+    NameEntry::new(3603, "LogicGate_AND", "Logic Gate (AND)"),
+    NameEntry::new(3604, "LogicGate_OR", "Logic Gate (OR)"),
+    NameEntry::new(3605, "LogicGate_NAND", "Logic Gate (NAND)"),
+    NameEntry::new(3606, "LogicGate_NOR", "Logic Gate (NOR)"),
+    NameEntry::new(3607, "LogicGate_XOR", "Logic Gate (XOR)"),
+    NameEntry::new(3608, "LogicGate_NXOR", "Logic Gate (XNOR)"),
+    NameEntry::new(3609, "ConveyorBeltLeft", "Conveyor Belt (Clockwise)"),
+    NameEntry::new(3610, "ConveyorBeltRight", "Conveyor Belt (Counter Clockwise)"),
+    NameEntry::new(3611, "WireKite", "The Grand Design"),
+    NameEntry::new(3612, "YellowWrench", "Yellow Wrench"),
+    NameEntry::new(3613, "LogicSensor_Sun", "Logic Sensor (Day)"),
+    NameEntry::new(3614, "LogicSensor_Moon", "Logic Sensor (Night)"),
+    NameEntry::new(3615, "LogicSensor_Above", "Logic Sensor (Player Above)"),
+    NameEntry::new(3616, "WirePipe", "Junction Box"),
+    NameEntry::new(3617, "AnnouncementBox", "Announcement Box"),
+    NameEntry::new(3618, "LogicGateLamp_On", "Logic Gate Lamp (On)"),
+    NameEntry::new(3619, "MechanicalLens", "Mechanical Lens"),
+    NameEntry::new(3620, "ActuationRod", "Actuation Rod"),
+    NameEntry::new(3621, "TeamBlockRed", "Red Team Block"),
+    NameEntry::new(3622, "TeamBlockRedPlatform", "Red Team Platform"),
+    NameEntry::new(3623, "StaticHook", "Static Hook"),
+    NameEntry::new(3624, "ActuationAccessory", "Presserator"),
+    NameEntry::new(3625, "MulticolorWrench", "Multicolor Wrench"),
+    NameEntry::new(3626, "WeightedPressurePlatePink", "Pink Weighted Pressure Plate"),
+    NameEntry::new(3627, "EngineeringHelmet", "Engineering Helmet"),
+    NameEntry::new(3628, "CompanionCube", "Companion Cube"),
+    NameEntry::new(3629, "WireBulb", "Wire Bulb"),
+    NameEntry::new(3630, "WeightedPressurePlateOrange", "Orange Weighted Pressure Plate"),
+    NameEntry::new(3631, "WeightedPressurePlatePurple", "Purple Weighted Pressure Plate"),
+    NameEntry::new(3632, "WeightedPressurePlateCyan", "Cyan Weighted Pressure Plate"),
+    NameEntry::new(3633, "TeamBlockGreen", "Green Team Block"),
+    NameEntry::new(3634, "TeamBlockBlue", "Blue Team Block"),
+    NameEntry::new(3635, "TeamBlockYellow", "Yellow Team Block"),
+    NameEntry::new(3636, "TeamBlockPink", "Pink Team Block"),
+    NameEntry::new(3637, "TeamBlockWhite", "White Team Block"),
+    NameEntry::new(3638, "TeamBlockGreenPlatform", "Green Team Platform"),
+    NameEntry::new(3639, "TeamBlockBluePlatform", "Blue Team Platform"),
+    NameEntry::new(3640, "TeamBlockYellowPlatform", "Yellow Team Platform"),
+    NameEntry::new(3641, "TeamBlockPinkPlatform", "Pink Team Platform"),
+    NameEntry::new(3642, "TeamBlockWhitePlatform", "White Team Platform"),
+    NameEntry::new(3643, "LargeAmber", "Large Amber"),
+    NameEntry::new(3644, "GemLockRuby", "Ruby Gem Lock"),
+    NameEntry::new(3645, "GemLockSapphire", "Sapphire Gem Lock"),
+    NameEntry::new(3646, "GemLockEmerald", "Emerald Gem Lock"),
+    NameEntry::new(3647, "GemLockTopaz", "Topaz Gem Lock"),
+    NameEntry::new(3648, "GemLockAmethyst", "Amethyst Gem Lock"),
+    NameEntry::new(3649, "GemLockDiamond", "Diamond Gem Lock"),
+    NameEntry::new(3650, "GemLockAmber", "Amber Gem Lock"),
+    NameEntry::new(3651, "SquirrelStatue", "Squirrel Statue"),
+    NameEntry::new(3652, "ButterflyStatue", "Butterfly Statue"),
+    NameEntry::new(3653, "WormStatue", "Worm Statue"),
+    NameEntry::new(3654, "FireflyStatue", "Firefly Statue"),
+    NameEntry::new(3655, "ScorpionStatue", "Scorpion Statue"),
+    NameEntry::new(3656, "SnailStatue", "Snail Statue"),
+    NameEntry::new(3657, "GrasshopperStatue", "Grasshopper Statue"),
+    NameEntry::new(3658, "MouseStatue", "Mouse Statue"),
+    NameEntry::new(3659, "DuckStatue", "Duck Statue"),
+    NameEntry::new(3660, "PenguinStatue", "Penguin Statue"),
+    NameEntry::new(3661, "FrogStatue", "Frog Statue"),
+    NameEntry::new(3662, "BuggyStatue", "Buggy Statue"),
+    NameEntry::new(3663, "LogicGateLamp_Faulty", "Logic Gate Lamp (Faulty)"),
+    NameEntry::new(3664, "PortalGunStation", "Portal Gun Station"),
+    NameEntry::new(3665, "Fake_Chest", "Trapped Chest"),
+    NameEntry::new(3666, "Fake_GoldChest", "Trapped Gold Chest"),
+    NameEntry::new(3667, "Fake_ShadowChest", "Trapped Shadow Chest"),
+    NameEntry::new(3668, "Fake_EbonwoodChest", "Trapped Ebonwood Chest"),
+    NameEntry::new(3669, "Fake_RichMahoganyChest", "Trapped Rich Mahogany Chest"),
+    NameEntry::new(3670, "Fake_PearlwoodChest", "Trapped Pearlwood Chest"),
+    NameEntry::new(3671, "Fake_IvyChest", "Trapped Ivy Chest"),
+    NameEntry::new(3672, "Fake_IceChest", "Trapped Frozen Chest"),
+    NameEntry::new(3673, "Fake_LivingWoodChest", "Trapped Living Wood Chest"),
+    NameEntry::new(3674, "Fake_SkywareChest", "Trapped Skyware Chest"),
+    NameEntry::new(3675, "Fake_ShadewoodChest", "Trapped Shadewood Chest"),
+    NameEntry::new(3676, "Fake_WebCoveredChest", "Trapped Web Covered Chest"),
+    NameEntry::new(3677, "Fake_LihzahrdChest", "Trapped Lihzahrd Chest"),
+    NameEntry::new(3678, "Fake_WaterChest", "Trapped Water Chest"),
+    NameEntry::new(3679, "Fake_JungleChest", "Trapped Jungle Chest"),
+    NameEntry::new(3680, "Fake_CorruptionChest", "Trapped Corruption Chest"),
+    NameEntry::new(3681, "Fake_CrimsonChest", "Trapped Crimson Chest"),
+    NameEntry::new(3682, "Fake_HallowedChest", "Trapped Hallowed Chest"),
+    NameEntry::new(3683, "Fake_FrozenChest", "Trapped Ice Chest"),
+    NameEntry::new(3684, "Fake_DynastyChest", "Trapped Dynasty Chest"),
+    NameEntry::new(3685, "Fake_HoneyChest", "Trapped Honey Chest"),
+    NameEntry::new(3686, "Fake_SteampunkChest", "Trapped Steampunk Chest"),
+    NameEntry::new(3687, "Fake_PalmWoodChest", "Trapped Palm Wood Chest"),
+    NameEntry::new(3688, "Fake_MushroomChest", "Trapped Mushroom Chest"),
+    NameEntry::new(3689, "Fake_BorealWoodChest", "Trapped Boreal Wood Chest"),
+    NameEntry::new(3690, "Fake_SlimeChest", "Trapped Slime Chest"),
+    NameEntry::new(3691, "Fake_GreenDungeonChest", "Trapped Green Dungeon Chest"),
+    NameEntry::new(3692, "Fake_PinkDungeonChest", "Trapped Pink Dungeon Chest"),
+    NameEntry::new(3693, "Fake_BlueDungeonChest", "Trapped Blue Dungeon Chest"),
+    NameEntry::new(3694, "Fake_BoneChest", "Trapped Bone Chest"),
+    NameEntry::new(3695, "Fake_CactusChest", "Trapped Cactus Chest"),
+    NameEntry::new(3696, "Fake_FleshChest", "Trapped Flesh Chest"),
+    NameEntry::new(3697, "Fake_ObsidianChest", "Trapped Obsidian Chest"),
+    NameEntry::new(3698, "Fake_PumpkinChest", "Trapped Pumpkin Chest"),
+    NameEntry::new(3699, "Fake_SpookyChest", "Trapped Spooky Chest"),
+    NameEntry::new(3700, "Fake_GlassChest", "Trapped Glass Chest"),
+    NameEntry::new(3701, "Fake_MartianChest", "Trapped Martian Chest"),
+    NameEntry::new(3702, "Fake_MeteoriteChest", "Trapped Meteorite Chest"),
+    NameEntry::new(3703, "Fake_GraniteChest", "Trapped Granite Chest"),
+    NameEntry::new(3704, "Fake_MarbleChest", "Trapped Marble Chest"),
+    NameEntry::new(3705, "Fake_newchest1", "Trapped newchest1"),
+    NameEntry::new(3706, "Fake_newchest2", "Trapped newchest2"),
+    NameEntry::new(3707, "ProjectilePressurePad", "Teal Pressure Pad"),
+    NameEntry::new(3708, "WallCreeperStatue", "Wall Creeper Statue"),
+    NameEntry::new(3709, "UnicornStatue", "Unicorn Statue"),
+    NameEntry::new(3710, "DripplerStatue", "Drippler Statue"),
+    NameEntry::new(3711, "WraithStatue", "Wraith Statue"),
+    NameEntry::new(3712, "BoneSkeletonStatue", "Bone Skeleton Statue"),
+    NameEntry::new(3713, "UndeadVikingStatue", "Undead Viking Statue"),
+    NameEntry::new(3714, "MedusaStatue", "Medusa Statue"),
+    NameEntry::new(3715, "HarpyStatue", "Harpy Statue"),
+    NameEntry::new(3716, "PigronStatue", "Pigron Statue"),
+    NameEntry::new(3717, "HopliteStatue", "Hoplite Statue"),
+    NameEntry::new(3718, "GraniteGolemStatue", "Granite Golem Statue"),
+    NameEntry::new(3719, "ZombieArmStatue", "Armed Zombie Statue"),
+    NameEntry::new(3720, "BloodZombieStatue", "Blood Zombie Statue"),
+    NameEntry::new(3721, "AnglerTackleBag", "Angler Tackle Bag"),
+    NameEntry::new(3722, "GeyserTrap", "Geyser"),
+    NameEntry::new(3723, "UltraBrightCampfire", "Ultrabright Campfire"),
+    NameEntry::new(3724, "BoneCampfire", "Bone Campfire"),
+    NameEntry::new(3725, "PixelBox", "Pixel Box"),
+    NameEntry::new(3726, "LogicSensor_Water", "Liquid Sensor (Water)"),
+    NameEntry::new(3727, "LogicSensor_Lava", "Liquid Sensor (Lava)"),
+    NameEntry::new(3728, "LogicSensor_Honey", "Liquid Sensor (Honey)"),
+    NameEntry::new(3729, "LogicSensor_Liquid", "Liquid Sensor (Any)"),
+    NameEntry::new(3730, "PartyBundleOfBalloonsAccessory", "Bundled Party Balloons"),
+    NameEntry::new(3731, "PartyBalloonAnimal", "Balloon Animal"),
+    NameEntry::new(3732, "PartyHat", "Party Hat"),
+    NameEntry::new(3733, "FlowerBoyHat", "Silly Sunflower Petals"),
+    NameEntry::new(3734, "FlowerBoyShirt", "Silly Sunflower Tops"),
+    NameEntry::new(3735, "FlowerBoyPants", "Silly Sunflower Bottoms"),
+    NameEntry::new(3736, "SillyBalloonPink", "Silly Pink Balloon"),
+    NameEntry::new(3737, "SillyBalloonPurple", "Silly Purple Balloon"),
+    NameEntry::new(3738, "SillyBalloonGreen", "Silly Green Balloon"),
+    NameEntry::new(3739, "SillyStreamerBlue", "Blue Streamer"),
+    NameEntry::new(3740, "SillyStreamerGreen", "Green Streamer"),
+    NameEntry::new(3741, "SillyStreamerPink", "Pink Streamer"),
+    NameEntry::new(3742, "SillyBalloonMachine", "Silly Balloon Machine"),
+    NameEntry::new(3743, "SillyBalloonTiedPink", "Silly Tied Balloon (Pink)"),
+    NameEntry::new(3744, "SillyBalloonTiedPurple", "Silly Tied Balloon (Purple)"),
+    NameEntry::new(3745, "SillyBalloonTiedGreen", "Silly Tied Balloon (Green)"),
+    NameEntry::new(3746, "Pigronata", "Pigronata"),
+    NameEntry::new(3747, "PartyMonolith", "Party Center"),
+    NameEntry::new(3748, "PartyBundleOfBalloonTile", "Silly Tied Bundle of Balloons"),
+    NameEntry::new(3749, "PartyPresent", "Party Present"),
+    NameEntry::new(3750, "SliceOfCake", "Slice of Cake"),
+    NameEntry::new(3751, "CogWall", "Cog Wall"),
+    NameEntry::new(3752, "SandFallWall", "Sandfall Wall"),
+    NameEntry::new(3753, "SnowFallWall", "Snowfall Wall"),
+    NameEntry::new(3754, "SandFallBlock", "Sandfall Block"),
+    NameEntry::new(3755, "SnowFallBlock", "Snowfall Block"),
+    NameEntry::new(3756, "SnowCloudBlock", "Snow Cloud"),
+    NameEntry::new(3757, "PedguinHat", "Pedguin's Hood"),
+    NameEntry::new(3758, "PedguinShirt", "Pedguin's Jacket"),
+    NameEntry::new(3759, "PedguinPants", "Pedguin's Trousers"),
+    NameEntry::new(3760, "SillyBalloonPinkWall", "Silly Pink Balloon Wall"),
+    NameEntry::new(3761, "SillyBalloonPurpleWall", "Silly Purple Balloon Wall"),
+    NameEntry::new(3762, "SillyBalloonGreenWall", "Silly Green Balloon Wall"),
+    NameEntry::new(3763, "AviatorSunglasses", "0x33's Aviators"),
+    NameEntry::new(3764, "BluePhasesaber", "Blue Phasesaber"),
+    NameEntry::new(3765, "RedPhasesaber", "Red Phasesaber"),
+    NameEntry::new(3766, "GreenPhasesaber", "Green Phasesaber"),
+    NameEntry::new(3767, "PurplePhasesaber", "Purple Phasesaber"),
+    NameEntry::new(3768, "WhitePhasesaber", "White Phasesaber"),
+    NameEntry::new(3769, "YellowPhasesaber", "Yellow Phasesaber"),
+    NameEntry::new(3770, "DjinnsCurse", "Djinn's Curse"),
+    NameEntry::new(3771, "AncientHorn", "Ancient Horn"),
+    NameEntry::new(3772, "AntlionClaw", "Mandible Blade"),
+    NameEntry::new(3773, "AncientArmorHat", "Ancient Headdress"),
+    NameEntry::new(3774, "AncientArmorShirt", "Ancient Garments"),
+    NameEntry::new(3775, "AncientArmorPants", "Ancient Slacks"),
+    NameEntry::new(3776, "AncientBattleArmorHat", "Forbidden Mask"),
+    NameEntry::new(3777, "AncientBattleArmorShirt", "Forbidden Robes"),
+    NameEntry::new(3778, "AncientBattleArmorPants", "Forbidden Treads"),
+    NameEntry::new(3779, "SpiritFlame", "Spirit Flame"),
+    NameEntry::new(3780, "SandElementalBanner", "Sand Elemental Banner"),
+    NameEntry::new(3781, "PocketMirror", "Pocket Mirror"),
+    NameEntry::new(3782, "MagicSandDropper", "Magic Sand Dropper"),
+    NameEntry::new(3783, "AncientBattleArmorMaterial", "Forbidden Fragment"),
+    NameEntry::new(3784, "LamiaPants", "Lamia Tail"),
+    NameEntry::new(3785, "LamiaShirt", "Lamia Wraps"),
+    NameEntry::new(3786, "LamiaHat", "Lamia Mask"),
+    NameEntry::new(3787, "SkyFracture", "Sky Fracture"),
+    NameEntry::new(3788, "OnyxBlaster", "Onyx Blaster"),
+    NameEntry::new(3789, "SandsharkBanner", "Sand Shark Banner"),
+    NameEntry::new(3790, "SandsharkCorruptBanner", "Bone Biter Banner"),
+    NameEntry::new(3791, "SandsharkCrimsonBanner", "Flesh Reaver Banner"),
+    NameEntry::new(3792, "SandsharkHallowedBanner", "Crystal Thresher Banner"),
+    NameEntry::new(3793, "TumbleweedBanner", "Angry Tumbler Banner"),
+    NameEntry::new(3794, "AncientCloth", "Ancient Cloth"),
+    NameEntry::new(3795, "DjinnLamp", "Desert Spirit Lamp"),
+    NameEntry::new(3796, "MusicBoxSandstorm", "Music Box (Sandstorm)"),
+    NameEntry::new(3797, "ApprenticeHat", "Apprentice's Hat"),
+    NameEntry::new(3798, "ApprenticeRobe", "Apprentice's Robe"),
+    NameEntry::new(3799, "ApprenticeTrousers", "Apprentice's Trousers"),
+    NameEntry::new(3800, "SquireGreatHelm", "Squire's Great Helm"),
+    NameEntry::new(3801, "SquirePlating", "Squire's Plating"),
+    NameEntry::new(3802, "SquireGreaves", "Squire's Greaves"),
+    NameEntry::new(3803, "HuntressWig", "Huntress's Wig"),
+    NameEntry::new(3804, "HuntressJerkin", "Huntress's Jerkin"),
+    NameEntry::new(3805, "HuntressPants", "Huntress's Pants"),
+    NameEntry::new(3806, "MonkBrows", "Monk's Bushy Brow Bald Cap"),
+    NameEntry::new(3807, "MonkShirt", "Monk's Shirt"),
+    NameEntry::new(3808, "MonkPants", "Monk's Pants"),
+    NameEntry::new(3809, "ApprenticeScarf", "Apprentice's Scarf"),
+    NameEntry::new(3810, "SquireShield", "Squire's Shield"),
+    NameEntry::new(3811, "HuntressBuckler", "Huntress's Buckler"),
+    NameEntry::new(3812, "MonkBelt", "Monk's Belt"),
+    NameEntry::new(3813, "DefendersForge", "Defender's Forge"),
+    NameEntry::new(3814, "WarTable", "War Table"),
+    NameEntry::new(3815, "WarTableBanner", "War Table Banner"),
+    NameEntry::new(3816, "DD2ElderCrystalStand", "Eternia Crystal Stand"),
+    NameEntry::new(3817, "DefenderMedal", "Defender Medal"),
+    NameEntry::new(3818, "DD2FlameburstTowerT1Popper", "Flameburst Rod"),
+    NameEntry::new(3819, "DD2FlameburstTowerT2Popper", "Flameburst Cane"),
+    NameEntry::new(3820, "DD2FlameburstTowerT3Popper", "Flameburst Staff"),
+    NameEntry::new(3821, "AleThrowingGlove", "Ale Tosser"),
+    NameEntry::new(3822, "DD2EnergyCrystal", "Etherian Mana"),
+    NameEntry::new(3823, "DD2SquireDemonSword", "Brand of the Inferno"),
+    NameEntry::new(3824, "DD2BallistraTowerT1Popper", "Ballista Rod"),
+    NameEntry::new(3825, "DD2BallistraTowerT2Popper", "Ballista Cane"),
+    NameEntry::new(3826, "DD2BallistraTowerT3Popper", "Ballista Staff"),
+    NameEntry::new(3827, "DD2SquireBetsySword", "Flying Dragon"),
+    NameEntry::new(3828, "DD2ElderCrystal", "Eternia Crystal"),
+    NameEntry::new(3829, "DD2LightningAuraT1Popper", "Lightning Aura Rod"),
+    NameEntry::new(3830, "DD2LightningAuraT2Popper", "Lightning Aura Cane"),
+    NameEntry::new(3831, "DD2LightningAuraT3Popper", "Lightning Aura Staff"),
+    NameEntry::new(3832, "DD2ExplosiveTrapT1Popper", "Explosive Trap Rod"),
+    NameEntry::new(3833, "DD2ExplosiveTrapT2Popper", "Explosive Trap Cane"),
+    NameEntry::new(3834, "DD2ExplosiveTrapT3Popper", "Explosive Trap Staff"),
+    NameEntry::new(3835, "MonkStaffT1", "Sleepy Octopod"),
+    NameEntry::new(3836, "MonkStaffT2", "Ghastly Glaive"),
+    NameEntry::new(3837, "DD2GoblinBomberBanner", "Etherian Goblin Bomber Banner"),
+    NameEntry::new(3838, "DD2GoblinBanner", "Etherian Goblin Banner"),
+    NameEntry::new(3839, "DD2SkeletonBanner", "Old One's Skeleton Banner"),
+    NameEntry::new(3840, "DD2DrakinBanner", "Drakin Banner"),
+    NameEntry::new(3841, "DD2KoboldFlyerBanner", "Kobold Glider Banner"),
+    NameEntry::new(3842, "DD2KoboldBanner", "Kobold Banner"),
+    NameEntry::new(3843, "DD2WitherBeastBanner", "Wither Beast Banner"),
+    NameEntry::new(3844, "DD2WyvernBanner", "Etherian Wyvern Banner"),
+    NameEntry::new(3845, "DD2JavelinThrowerBanner", "Etherian Javelin Thrower Banner"),
+    NameEntry::new(3846, "DD2LightningBugBanner", "Etherian Lightning Bug Banner"),
+    NameEntry::new(3847, "OgreMask", "OgreMask"),
+    NameEntry::new(3848, "GoblinMask", "GoblinMask"),
+    NameEntry::new(3849, "GoblinBomberCap", "GoblinBomberCap"),
+    NameEntry::new(3850, "EtherianJavelin", "EtherianJavelin"),
+    NameEntry::new(3851, "KoboldDynamiteBackpack", "KoboldDynamiteBackpack"),
+    NameEntry::new(3852, "BookStaff", "Tome of Infinite Wisdom"),
+    NameEntry::new(3853, "BoringBow", "BoringBow"),
+    NameEntry::new(3854, "DD2PhoenixBow", "Phantom Phoenix"),
+    NameEntry::new(3855, "DD2PetGato", "Gato Egg"),
+    NameEntry::new(3856, "DD2PetGhost", "Creeper Egg"),
+    NameEntry::new(3857, "DD2PetDragon", "Dragon Egg"),
+    NameEntry::new(3858, "MonkStaffT3", "Sky Dragon's Fury"),
+    NameEntry::new(3859, "DD2BetsyBow", "Aerial Bane"),
+    NameEntry::new(3860, "BossBagBetsy", "Treasure Bag (Betsy)"),
+    NameEntry::new(3861, "BossBagOgre", "BossBagOgre"),
+    NameEntry::new(3862, "BossBagDarkMage", "BossBagDarkMage"),
+    NameEntry::new(3863, "BossMaskBetsy", "Betsy Mask"),
+    NameEntry::new(3864, "BossMaskDarkMage", "Dark Mage Mask"),
+    NameEntry::new(3865, "BossMaskOgre", "Ogre Mask"),
+    NameEntry::new(3866, "BossTrophyBetsy", "Betsy Trophy"),
+    NameEntry::new(3867, "BossTrophyDarkmage", "Dark Mage Trophy"),
+    NameEntry::new(3868, "BossTrophyOgre", "Ogre Trophy"),
+    NameEntry::new(3869, "MusicBoxDD2", "Music Box (Old One's Army)"),
+    NameEntry::new(3870, "ApprenticeStaffT3", "Betsy's Wrath"),
+    NameEntry::new(3871, "SquireAltHead", "Valhalla Knight's Helm"),
+    NameEntry::new(3872, "SquireAltShirt", "Valhalla Knight's Breastplate"),
+    NameEntry::new(3873, "SquireAltPants", "Valhalla Knight's Greaves"),
+    NameEntry::new(3874, "ApprenticeAltHead", "Dark Artist's Hat"),
+    NameEntry::new(3875, "ApprenticeAltShirt", "Dark Artist's Robes"),
+    NameEntry::new(3876, "ApprenticeAltPants", "Dark Artist's Leggings"),
+    NameEntry::new(3877, "HuntressAltHead", "Red Riding Hood"),
+    NameEntry::new(3878, "HuntressAltShirt", "Red Riding Dress"),
+    NameEntry::new(3879, "HuntressAltPants", "Red Riding Leggings"),
+    NameEntry::new(3880, "MonkAltHead", "Shinobi Infiltrator's Helmet"),
+    NameEntry::new(3881, "MonkAltShirt", "Shinobi Infiltrator's Torso"),
+    NameEntry::new(3882, "MonkAltPants", "Shinobi Infiltrator's Pants"),
+    NameEntry::new(3883, "BetsyWings", "Betsy's Wings"),
+    NameEntry::new(3884, "CrystalChest", "Crystal Chest"),
+    NameEntry::new(3885, "GoldenChest", "Golden Chest"),
+    NameEntry::new(3886, "Fake_CrystalChest", "Trapped Crystal Chest"),
+    NameEntry::new(3887, "Fake_GoldenChest", "Trapped Golden Chest"),
+    NameEntry::new(3888, "CrystalDoor", "Crystal Door"),
+    NameEntry::new(3889, "CrystalChair", "Crystal Chair"),
+    NameEntry::new(3890, "CrystalCandle", "Crystal Candle"),
+    NameEntry::new(3891, "CrystalLantern", "Crystal Lantern"),
+    NameEntry::new(3892, "CrystalLamp", "Crystal Lamp"),
+    NameEntry::new(3893, "CrystalCandelabra", "Crystal Candelabra"),
+    NameEntry::new(3894, "CrystalChandelier", "Crystal Chandelier"),
+    NameEntry::new(3895, "CrystalBathtub", "Crystal Bathtub"),
+    NameEntry::new(3896, "CrystalSink", "Crystal Sink"),
+    NameEntry::new(3897, "CrystalBed", "Crystal Bed"),
+    NameEntry::new(3898, "CrystalClock", "Crystal Clock"),
+    NameEntry::new(3899, "SkywareClock2", "Sunplate Clock"),
+    NameEntry::new(3900, "DungeonClockBlue", "Blue Dungeon Clock"),
+    NameEntry::new(3901, "DungeonClockGreen", "Green Dungeon Clock"),
+    NameEntry::new(3902, "DungeonClockPink", "Pink Dungeon Clock"),
+    NameEntry::new(3903, "CrystalPlatform", "Crystal Platform"),
+    NameEntry::new(3904, "GoldenPlatform", "Golden Platform"),
+    NameEntry::new(3905, "DynastyPlatform", "Dynasty Wood Platform"),
+    NameEntry::new(3906, "LihzahrdPlatform", "Lihzahrd Platform"),
+    NameEntry::new(3907, "FleshPlatform", "Flesh Platform"),
+    NameEntry::new(3908, "FrozenPlatform", "Frozen Platform"),
+    NameEntry::new(3909, "CrystalWorkbench", "Crystal Work Bench"),
+    NameEntry::new(3910, "GoldenWorkbench", "Golden Work Bench"),
+    NameEntry::new(3911, "CrystalDresser", "Crystal Dresser"),
+    NameEntry::new(3912, "DynastyDresser", "Dynasty Dresser"),
+    NameEntry::new(3913, "FrozenDresser", "Frozen Dresser"),
+    NameEntry::new(3914, "LivingWoodDresser", "Living Wood Dresser"),
+    NameEntry::new(3915, "CrystalPiano", "Crystal Piano"),
+    NameEntry::new(3916, "DynastyPiano", "Dynasty Piano"),
+    NameEntry::new(3917, "CrystalBookCase", "Crystal Bookcase"),
+    NameEntry::new(3918, "CrystalSofaHowDoesThatEvenWork", "Crystal Sofa"),
+    NameEntry::new(3919, "DynastySofa", "Dynasty Sofa"),
+    NameEntry::new(3920, "CrystalTable", "Crystal Table"),
+    NameEntry::new(3921, "ArkhalisHat", "Arkhalis' Hood"),
+    NameEntry::new(3922, "ArkhalisShirt", "Arkhalis' Bodice"),
+    NameEntry::new(3923, "ArkhalisPants", "Arkhalis' Tights"),
+    NameEntry::new(3924, "ArkhalisWings", "Arkhalis' Lightwings"),
+    NameEntry::new(3925, "LeinforsHat", "Leinfors' Hair Protector"),
+    NameEntry::new(3926, "LeinforsShirt", "Leinfors' Excessive Style"),
+    NameEntry::new(3927, "LeinforsPants", "Leinfors' Fancypants"),
+    NameEntry::new(3928, "LeinforsWings", "Leinfors' Prehensile Cloak"),
+    NameEntry::new(3929, "LeinforsAccessory", "Leinfors' Luxury Shampoo"),
+    NameEntry::new(3930, "Celeb2", "Celebration Mk2"),
+    NameEntry::new(3931, "SpiderBathtub", "Spider Bathtub"),
+    NameEntry::new(3932, "SpiderBed", "Spider Bed"),
+    NameEntry::new(3933, "SpiderBookcase", "Spider Bookcase"),
+    NameEntry::new(3934, "SpiderDresser", "Spider Dresser"),
+    NameEntry::new(3935, "SpiderCandelabra", "Spider Candelabra"),
+    NameEntry::new(3936, "SpiderCandle", "Spider Candle"),
+    NameEntry::new(3937, "SpiderChair", "Spider Chair"),
+    NameEntry::new(3938, "SpiderChandelier", "Spider Chandelier"),
+    NameEntry::new(3939, "SpiderChest", "Spider Chest"),
+    NameEntry::new(3940, "SpiderClock", "Spider Clock"),
+    NameEntry::new(3941, "SpiderDoor", "Spider Door"),
+    NameEntry::new(3942, "SpiderLamp", "Spider Lamp"),
+    NameEntry::new(3943, "SpiderLantern", "Spider Lantern"),
+    NameEntry::new(3944, "SpiderPiano", "Spider Piano"),
+    NameEntry::new(3945, "SpiderPlatform", "Spider Platform"),
+    NameEntry::new(3946, "SpiderSinkSpiderSinkDoesWhateverASpiderSinkDoes", "Spider Sink"),
+    NameEntry::new(3947, "SpiderSofa", "Spider Sofa"),
+    NameEntry::new(3948, "SpiderTable", "Spider Table"),
+    NameEntry::new(3949, "SpiderWorkbench", "Spider Work Bench"),
+    NameEntry::new(3950, "Fake_SpiderChest", "Trapped Spider Chest"),
+    NameEntry::new(3951, "IronBrick", "Iron Brick"),
+    NameEntry::new(3952, "IronBrickWall", "Iron Brick Wall"),
+    NameEntry::new(3953, "LeadBrick", "Lead Brick"),
+    NameEntry::new(3954, "LeadBrickWall", "Lead Brick Wall"),
+    NameEntry::new(3955, "LesionBlock", "Lesion Block"),
+    NameEntry::new(3956, "LesionBlockWall", "Lesion Block Wall"),
+    NameEntry::new(3957, "LesionPlatform", "Lesion Platform"),
+    NameEntry::new(3958, "LesionBathtub", "Lesion Bathtub"),
+    NameEntry::new(3959, "LesionBed", "Lesion Bed"),
+    NameEntry::new(3960, "LesionBookcase", "Lesion Bookcase"),
+    NameEntry::new(3961, "LesionCandelabra", "Lesion Candelabra"),
+    NameEntry::new(3962, "LesionCandle", "Lesion Candle"),
+    NameEntry::new(3963, "LesionChair", "Lesion Chair"),
+    NameEntry::new(3964, "LesionChandelier", "Lesion Chandelier"),
+    NameEntry::new(3965, "LesionChest", "Lesion Chest"),
+    NameEntry::new(3966, "LesionClock", "Lesion Clock"),
+    NameEntry::new(3967, "LesionDoor", "Lesion Door"),
+    NameEntry::new(3968, "LesionDresser", "Lesion Dresser"),
+    NameEntry::new(3969, "LesionLamp", "Lesion Lamp"),
+    NameEntry::new(3970, "LesionLantern", "Lesion Lantern"),
+    NameEntry::new(3971, "LesionPiano", "Lesion Piano"),
+    NameEntry::new(3972, "LesionSink", "Lesion Sink"),
+    NameEntry::new(3973, "LesionSofa", "Lesion Sofa"),
+    NameEntry::new(3974, "LesionTable", "Lesion Table"),
+    NameEntry::new(3975, "LesionWorkbench", "Lesion Work Bench"),
+    NameEntry::new(3976, "Fake_LesionChest", "Trapped Lesion Chest"),
+    NameEntry::new(3977, "HatRack", "Hat Rack"),
+    NameEntry::new(3978, "ColorOnlyDye", "ColorOnlyDye"),
+    NameEntry::new(3979, "WoodenCrateHard", "Pearlwood Crate"),
+    NameEntry::new(3980, "IronCrateHard", "Mythril Crate"),
+    NameEntry::new(3981, "GoldenCrateHard", "Titanium Crate"),
+    NameEntry::new(3982, "CorruptFishingCrateHard", "Defiled Crate"),
+    NameEntry::new(3983, "CrimsonFishingCrateHard", "Hematic Crate"),
+    NameEntry::new(3984, "DungeonFishingCrateHard", "Stockade Crate"),
+    NameEntry::new(3985, "FloatingIslandFishingCrateHard", "Azure Crate"),
+    NameEntry::new(3986, "HallowedFishingCrateHard", "Divine Crate"),
+    NameEntry::new(3987, "JungleFishingCrateHard", "Bramble Crate"),
+    NameEntry::new(3988, "DeadMansChest", "Dead Man's Chest"),
+    NameEntry::new(3989, "GolfBall", "Golf Ball"),
+    NameEntry::new(3990, "AmphibianBoots", "Amphibian Boots"),
+    NameEntry::new(3991, "ArcaneFlower", "Arcane Flower"),
+    NameEntry::new(3992, "BerserkerGlove", "Berserker's Glove"),
+    NameEntry::new(3993, "FairyBoots", "Fairy Boots"),
+    NameEntry::new(3994, "FrogFlipper", "Frog Flipper"),
+    NameEntry::new(3995, "FrogGear", "Frog Gear"),
+    NameEntry::new(3996, "FrogWebbing", "Frog Webbing"),
+    NameEntry::new(3997, "FrozenShield", "Frozen Shield"),
+    NameEntry::new(3998, "HeroShield", "Hero Shield"),
+    NameEntry::new(3999, "LavaSkull", "Magma Skull"),
+    NameEntry::new(4000, "MagnetFlower", "Magnet Flower"),
+    NameEntry::new(4001, "ManaCloak", "Mana Cloak"),
+    NameEntry::new(4002, "MoltenQuiver", "Molten Quiver"),
+    NameEntry::new(4003, "MoltenSkullRose", "Molten Skull Rose"),
+    NameEntry::new(4004, "ObsidianSkullRose", "Obsidian Skull Rose"),
+    NameEntry::new(4005, "ReconScope", "Recon Scope"),
+    NameEntry::new(4006, "StalkersQuiver", "Stalker's Quiver"),
+    NameEntry::new(4007, "StingerNecklace", "Stinger Necklace"),
+    NameEntry::new(4008, "UltrabrightHelmet", "Ultrabright Helmet"),
+    NameEntry::new(4009, "Apple", "Apple"),
+    NameEntry::new(4010, "ApplePieSlice", "Apple Pie Slice"),
+    NameEntry::new(4011, "ApplePie", "Apple Pie"),
+    NameEntry::new(4012, "BananaSplit", "Banana Split"),
+    NameEntry::new(4013, "BBQRibs", "BBQ Ribs"),
+    NameEntry::new(4014, "BunnyStew", "Bunny Stew"),
+    NameEntry::new(4015, "Burger", "Burger"),
+    NameEntry::new(4016, "ChickenNugget", "Chicken Nugget"),
+    NameEntry::new(4017, "ChocolateChipCookie", "Chocolate Chip Cookie"),
+    NameEntry::new(4018, "CreamSoda", "Cream Soda"),
+    NameEntry::new(4019, "Escargot", "Escargot"),
+    NameEntry::new(4020, "FriedEgg", "Fried Egg"),
+    NameEntry::new(4021, "Fries", "Fries"),
+    NameEntry::new(4022, "GoldenDelight", "Golden Delight"),
+    NameEntry::new(4023, "Grapes", "Grapes"),
+    NameEntry::new(4024, "GrilledSquirrel", "Grilled Squirrel"),
+    NameEntry::new(4025, "Hotdog", "Hotdog"),
+    NameEntry::new(4026, "IceCream", "Ice Cream"),
+    NameEntry::new(4027, "Milkshake", "Milkshake"),
+    NameEntry::new(4028, "Nachos", "Nachos"),
+    NameEntry::new(4029, "Pizza", "Pizza"),
+    NameEntry::new(4030, "PotatoChips", "Potato Chips"),
+    NameEntry::new(4031, "RoastedBird", "Roasted Bird"),
+    NameEntry::new(4032, "RoastedDuck", "Roasted Duck"),
+    NameEntry::new(4033, "SauteedFrogLegs", "Sauteed Frog Legs"),
+    NameEntry::new(4034, "SeafoodDinner", "Seafood Dinner"),
+    NameEntry::new(4035, "ShrimpPoBoy", "Shrimp Po' Boy"),
+    NameEntry::new(4036, "Spaghetti", "Spaghetti"),
+    NameEntry::new(4037, "Steak", "Steak"),
+    NameEntry::new(4038, "MoltenCharm", "Molten Charm"),
+    NameEntry::new(4039, "GolfClubIron", "Golf Club (Iron)"),
+    NameEntry::new(4040, "GolfCup", "Golf Cup"),
+    NameEntry::new(4041, "FlowerPacketBlue", "Blue Flower Seeds"),
+    NameEntry::new(4042, "FlowerPacketMagenta", "Magenta Flower Seeds"),
+    NameEntry::new(4043, "FlowerPacketPink", "Pink Flower Seeds"),
+    NameEntry::new(4044, "FlowerPacketRed", "Red Flower Seeds"),
+    NameEntry::new(4045, "FlowerPacketYellow", "Yellow Flower Seeds"),
+    NameEntry::new(4046, "FlowerPacketViolet", "Violet Flower Seeds"),
+    NameEntry::new(4047, "FlowerPacketWhite", "White Flower Seeds"),
+    NameEntry::new(4048, "FlowerPacketTallGrass", "Tall Grass Seeds"),
+    NameEntry::new(4049, "LawnMower", "Lawn Mower"),
+    NameEntry::new(4050, "CrimstoneBrick", "Crimstone Brick"),
+    NameEntry::new(4051, "SmoothSandstone", "Smooth Sandstone"),
+    NameEntry::new(4052, "CrimstoneBrickWall", "Crimstone Brick Wall"),
+    NameEntry::new(4053, "SmoothSandstoneWall", "Smooth Sandstone Wall"),
+    NameEntry::new(4054, "BloodMoonMonolith", "Blood Moon Monolith"),
+    NameEntry::new(4055, "SandBoots", "Dunerider Boots"),
+    NameEntry::new(4056, "AncientChisel", "Ancient Chisel"),
+    NameEntry::new(4057, "CarbonGuitar", "Rain Song"),
+    NameEntry::new(4058, "SkeletonBow", "Skull Bow"),
+    NameEntry::new(4059, "FossilPickaxe", "Fossil Pickaxe"),
+    NameEntry::new(4060, "SuperStarCannon", "Super Star Shooter"),
+    NameEntry::new(4061, "ThunderSpear", "Storm Spear"),
+    NameEntry::new(4062, "ThunderStaff", "Thunder Zapper"),
+    NameEntry::new(4063, "DrumSet", "Drum Set"),
+    NameEntry::new(4064, "PicnicTable", "Picnic Table"),
+    NameEntry::new(4065, "PicnicTableWithCloth", "Fancy Picnic Table"),
+    NameEntry::new(4066, "DesertMinecart", "Desert Minecart"),
+    NameEntry::new(4067, "FishMinecart", "Minecarp"),
+    NameEntry::new(4068, "FairyCritterPink", "Pink Fairy"),
+    NameEntry::new(4069, "FairyCritterGreen", "Green Fairy"),
+    NameEntry::new(4070, "FairyCritterBlue", "Blue Fairy"),
+    NameEntry::new(4071, "JunoniaShell", "Junonia Shell"),
+    NameEntry::new(4072, "LightningWhelkShell", "Lightning Whelk Shell"),
+    NameEntry::new(4073, "TulipShell", "Tulip Shell"),
+    NameEntry::new(4074, "PinWheel", "Pin Wheel"),
+    NameEntry::new(4075, "WeatherVane", "Weather Vane"),
+    NameEntry::new(4076, "VoidVault", "Void Vault"),
+    NameEntry::new(4077, "MusicBoxOceanAlt", "Music Box (Ocean Night)"),
+    NameEntry::new(4078, "MusicBoxSlimeRain", "Music Box (Slime Rain)"),
+    NameEntry::new(4079, "MusicBoxSpaceAlt", "Music Box (Space Day)"),
+    NameEntry::new(4080, "MusicBoxTownDay", "Music Box (Town Day)"),
+    NameEntry::new(4081, "MusicBoxTownNight", "Music Box (Town Night)"),
+    NameEntry::new(4082, "MusicBoxWindyDay", "Music Box (Windy Day)"),
+    NameEntry::new(4083, "GolfCupFlagWhite", "White Pin Flag"),
+    NameEntry::new(4084, "GolfCupFlagRed", "Red Pin Flag"),
+    NameEntry::new(4085, "GolfCupFlagGreen", "Green Pin Flag"),
+    NameEntry::new(4086, "GolfCupFlagBlue", "Blue Pin Flag"),
+    NameEntry::new(4087, "GolfCupFlagYellow", "Yellow Pin Flag"),
+    NameEntry::new(4088, "GolfCupFlagPurple", "Purple Pin Flag"),
+    NameEntry::new(4089, "GolfTee", "Golf Tee"),
+    NameEntry::new(4090, "ShellPileBlock", "Shell Pile"),
+    NameEntry::new(4091, "AntiPortalBlock", "Anti-Portal Block"),
+    NameEntry::new(4092, "GolfClubPutter", "Golf Club (Putter)"),
+    NameEntry::new(4093, "GolfClubWedge", "Golf Club (Wedge)"),
+    NameEntry::new(4094, "GolfClubDriver", "Golf Club (Driver)"),
+    NameEntry::new(4095, "GolfWhistle", "Golf Whistle"),
+    NameEntry::new(4096, "ToiletEbonyWood", "Ebonwood Toilet"),
+    NameEntry::new(4097, "ToiletRichMahogany", "Rich Mahogany Toilet"),
+    NameEntry::new(4098, "ToiletPearlwood", "Pearlwood Toilet"),
+    NameEntry::new(4099, "ToiletLivingWood", "Living Wood Toilet"),
+    NameEntry::new(4100, "ToiletCactus", "Cactus Toilet"),
+    NameEntry::new(4101, "ToiletBone", "Bone Toilet"),
+    NameEntry::new(4102, "ToiletFlesh", "Flesh Toilet"),
+    NameEntry::new(4103, "ToiletMushroom", "Mushroom Toilet"),
+    NameEntry::new(4104, "ToiletSunplate", "Skyware Toilet"),
+    NameEntry::new(4105, "ToiletShadewood", "Shadewood Toilet"),
+    NameEntry::new(4106, "ToiletLihzhard", "Lihzahrd Toilet"),
+    NameEntry::new(4107, "ToiletDungeonBlue", "Blue Dungeon Toilet"),
+    NameEntry::new(4108, "ToiletDungeonGreen", "Green Dungeon Toilet"),
+    NameEntry::new(4109, "ToiletDungeonPink", "Pink Dungeon Toilet"),
+    NameEntry::new(4110, "ToiletObsidian", "Obsidian Toilet"),
+    NameEntry::new(4111, "ToiletFrozen", "Frozen Toilet"),
+    NameEntry::new(4112, "ToiletGlass", "Glass Toilet"),
+    NameEntry::new(4113, "ToiletHoney", "Honey Toilet"),
+    NameEntry::new(4114, "ToiletSteampunk", "Steampunk Toilet"),
+    NameEntry::new(4115, "ToiletPumpkin", "Pumpkin Toilet"),
+    NameEntry::new(4116, "ToiletSpooky", "Spooky Toilet"),
+    NameEntry::new(4117, "ToiletDynasty", "Dynasty Toilet"),
+    NameEntry::new(4118, "ToiletPalm", "Palm Wood Toilet"),
+    NameEntry::new(4119, "ToiletBoreal", "Boreal Wood Toilet"),
+    NameEntry::new(4120, "ToiletSlime", "Slime Toilet"),
+    NameEntry::new(4121, "ToiletMartian", "Martian Toilet"),
+    NameEntry::new(4122, "ToiletGranite", "Granite Toilet"),
+    NameEntry::new(4123, "ToiletMarble", "Marble Toilet"),
+    NameEntry::new(4124, "ToiletCrystal", "Crystal Toilet"),
+    NameEntry::new(4125, "ToiletSpider", "Spider Toilet"),
+    NameEntry::new(4126, "ToiletLesion", "Lesion Toilet"),
+    NameEntry::new(4127, "ToiletDiamond", "Diamond Toilet"),
+    NameEntry::new(4128, "MaidHead", "Maid Bonnet"),
+    NameEntry::new(4129, "MaidShirt", "Maid Dress"),
+    NameEntry::new(4130, "MaidPants", "Maid Shoes"),
+    NameEntry::new(4131, "VoidLens", "Void Bag"),
+    NameEntry::new(4132, "MaidHead2", "Pink Maid Bonnet"),
+    NameEntry::new(4133, "MaidShirt2", "Pink Maid Dress"),
+    NameEntry::new(4134, "MaidPants2", "Pink Maid Shoes"),
+    NameEntry::new(4135, "GolfHat", "Country Club Cap"),
+    NameEntry::new(4136, "GolfShirt", "Country Club Vest"),
+    NameEntry::new(4137, "GolfPants", "Country Club Trousers"),
+    NameEntry::new(4138, "GolfVisor", "Country Club Visor"),
+    NameEntry::new(4139, "SpiderBlock", "Spider Nest Block"),
+    NameEntry::new(4140, "SpiderWall", "Spider Nest Wall"),
+    NameEntry::new(4141, "ToiletMeteor", "Meteorite Toilet"),
+    NameEntry::new(4142, "LesionStation", "Decay Chamber"),
+    NameEntry::new(4143, "ManaCloakStar", ""),
+    NameEntry::new(4144, "Terragrim", "Terragrim"),
+    NameEntry::new(4145, "SolarBathtub", "Solar Bathtub"),
+    NameEntry::new(4146, "SolarBed", "Solar Bed"),
+    NameEntry::new(4147, "SolarBookcase", "Solar Bookcase"),
+    NameEntry::new(4148, "SolarDresser", "Solar Dresser"),
+    NameEntry::new(4149, "SolarCandelabra", "Solar Candelabra"),
+    NameEntry::new(4150, "SolarCandle", "Solar Candle"),
+    NameEntry::new(4151, "SolarChair", "Solar Chair"),
+    NameEntry::new(4152, "SolarChandelier", "Solar Chandelier"),
+    NameEntry::new(4153, "SolarChest", "Solar Chest"),
+    NameEntry::new(4154, "SolarClock", "Solar Clock"),
+    NameEntry::new(4155, "SolarDoor", "Solar Door"),
+    NameEntry::new(4156, "SolarLamp", "Solar Lamp"),
+    NameEntry::new(4157, "SolarLantern", "Solar Lantern"),
+    NameEntry::new(4158, "SolarPiano", "Solar Piano"),
+    NameEntry::new(4159, "SolarPlatform", "Solar Platform"),
+    NameEntry::new(4160, "SolarSink", "Solar Sink"),
+    NameEntry::new(4161, "SolarSofa", "Solar Sofa"),
+    NameEntry::new(4162, "SolarTable", "Solar Table"),
+    NameEntry::new(4163, "SolarWorkbench", "Solar Work Bench"),
+    NameEntry::new(4164, "Fake_SolarChest", "Trapped Solar Chest"),
+    NameEntry::new(4165, "SolarToilet", "Solar Toilet"),
+    NameEntry::new(4166, "VortexBathtub", "Vortex Bathtub"),
+    NameEntry::new(4167, "VortexBed", "Vortex Bed"),
+    NameEntry::new(4168, "VortexBookcase", "Vortex Bookcase"),
+    NameEntry::new(4169, "VortexDresser", "Vortex Dresser"),
+    NameEntry::new(4170, "VortexCandelabra", "Vortex Candelabra"),
+    NameEntry::new(4171, "VortexCandle", "Vortex Candle"),
+    NameEntry::new(4172, "VortexChair", "Vortex Chair"),
+    NameEntry::new(4173, "VortexChandelier", "Vortex Chandelier"),
+    NameEntry::new(4174, "VortexChest", "Vortex Chest"),
+    NameEntry::new(4175, "VortexClock", "Vortex Clock"),
+    NameEntry::new(4176, "VortexDoor", "Vortex Door"),
+    NameEntry::new(4177, "VortexLamp", "Vortex Lamp"),
+    NameEntry::new(4178, "VortexLantern", "Vortex Lantern"),
+    NameEntry::new(4179, "VortexPiano", "Vortex Piano"),
+    NameEntry::new(4180, "VortexPlatform", "Vortex Platform"),
+    NameEntry::new(4181, "VortexSink", "Vortex Sink"),
+    NameEntry::new(4182, "VortexSofa", "Vortex Sofa"),
+    NameEntry::new(4183, "VortexTable", "Vortex Table"),
+    NameEntry::new(4184, "VortexWorkbench", "Vortex Work Bench"),
+    NameEntry::new(4185, "Fake_VortexChest", "Trapped Vortex Chest"),
+    NameEntry::new(4186, "VortexToilet", "Vortex Toilet"),
+    NameEntry::new(4187, "NebulaBathtub", "Nebula Bathtub"),
+    NameEntry::new(4188, "NebulaBed", "Nebula Bed"),
+    NameEntry::new(4189, "NebulaBookcase", "Nebula Bookcase"),
+    NameEntry::new(4190, "NebulaDresser", "Nebula Dresser"),
+    NameEntry::new(4191, "NebulaCandelabra", "Nebula Candelabra"),
+    NameEntry::new(4192, "NebulaCandle", "Nebula Candle"),
+    NameEntry::new(4193, "NebulaChair", "Nebula Chair"),
+    NameEntry::new(4194, "NebulaChandelier", "Nebula Chandelier"),
+    NameEntry::new(4195, "NebulaChest", "Nebula Chest"),
+    NameEntry::new(4196, "NebulaClock", "Nebula Clock"),
+    NameEntry::new(4197, "NebulaDoor", "Nebula Door"),
+    NameEntry::new(4198, "NebulaLamp", "Nebula Lamp"),
+    NameEntry::new(4199, "NebulaLantern", "Nebula Lantern"),
+    NameEntry::new(4200, "NebulaPiano", "Nebula Piano"),
+    NameEntry::new(4201, "NebulaPlatform", "Nebula Platform"),
+    NameEntry::new(4202, "NebulaSink", "Nebula Sink"),
+    NameEntry::new(4203, "NebulaSofa", "Nebula Sofa"),
+    NameEntry::new(4204, "NebulaTable", "Nebula Table"),
+    NameEntry::new(4205, "NebulaWorkbench", "Nebula Work Bench"),
+    NameEntry::new(4206, "Fake_NebulaChest", "Trapped Nebula Chest"),
+    NameEntry::new(4207, "NebulaToilet", "Nebula Toilet"),
+    NameEntry::new(4208, "StardustBathtub", "Stardust Bathtub"),
+    NameEntry::new(4209, "StardustBed", "Stardust Bed"),
+    NameEntry::new(4210, "StardustBookcase", "Stardust Bookcase"),
+    NameEntry::new(4211, "StardustDresser", "Stardust Dresser"),
+    NameEntry::new(4212, "StardustCandelabra", "Stardust Candelabra"),
+    NameEntry::new(4213, "StardustCandle", "Stardust Candle"),
+    NameEntry::new(4214, "StardustChair", "Stardust Chair"),
+    NameEntry::new(4215, "StardustChandelier", "Stardust Chandelier"),
+    NameEntry::new(4216, "StardustChest", "Stardust Chest"),
+    NameEntry::new(4217, "StardustClock", "Stardust Clock"),
+    NameEntry::new(4218, "StardustDoor", "Stardust Door"),
+    NameEntry::new(4219, "StardustLamp", "Stardust Lamp"),
+    NameEntry::new(4220, "StardustLantern", "Stardust Lantern"),
+    NameEntry::new(4221, "StardustPiano", "Stardust Piano"),
+    NameEntry::new(4222, "StardustPlatform", "Stardust Platform"),
+    NameEntry::new(4223, "StardustSink", "Stardust Sink"),
+    NameEntry::new(4224, "StardustSofa", "Stardust Sofa"),
+    NameEntry::new(4225, "StardustTable", "Stardust Table"),
+    NameEntry::new(4226, "StardustWorkbench", "Stardust Work Bench"),
+    NameEntry::new(4227, "Fake_StardustChest", "Trapped Stardust Chest"),
+    NameEntry::new(4228, "StardustToilet", "Stardust Toilet"),
+    NameEntry::new(4229, "SolarBrick", "Solar Brick"),
+    NameEntry::new(4230, "VortexBrick", "Vortex Brick"),
+    NameEntry::new(4231, "NebulaBrick", "Nebula Brick"),
+    NameEntry::new(4232, "StardustBrick", "Stardust Brick"),
+    NameEntry::new(4233, "SolarBrickWall", "Solar Brick Wall"),
+    NameEntry::new(4234, "VortexBrickWall", "Vortex Brick Wall"),
+    NameEntry::new(4235, "NebulaBrickWall", "Nebula Brick Wall"),
+    NameEntry::new(4236, "StardustBrickWall", "Stardust Brick Wall"),
+    NameEntry::new(4237, "MusicBoxDayRemix", "Music Box (Day Remix)"),
+    NameEntry::new(4238, "CrackedBlueBrick", "Cracked Blue Brick"),
+    NameEntry::new(4239, "CrackedGreenBrick", "Cracked Green Brick"),
+    NameEntry::new(4240, "CrackedPinkBrick", "Cracked Pink Brick"),
+    NameEntry::new(4241, "FlowerPacketWild", "Wild Flower Seeds"),
+    NameEntry::new(4242, "GolfBallDyedBlack", "Black Golf Ball"),
+    NameEntry::new(4243, "GolfBallDyedBlue", "Blue Golf Ball"),
+    NameEntry::new(4244, "GolfBallDyedBrown", "Brown Golf Ball"),
+    NameEntry::new(4245, "GolfBallDyedCyan", "Cyan Golf Ball"),
+    NameEntry::new(4246, "GolfBallDyedGreen", "Green Golf Ball"),
+    NameEntry::new(4247, "GolfBallDyedLimeGreen", "Lime Golf Ball"),
+    NameEntry::new(4248, "GolfBallDyedOrange", "Orange Golf Ball"),
+    NameEntry::new(4249, "GolfBallDyedPink", "Pink Golf Ball"),
+    NameEntry::new(4250, "GolfBallDyedPurple", "Purple Golf Ball"),
+    NameEntry::new(4251, "GolfBallDyedRed", "Red Golf Ball"),
+    NameEntry::new(4252, "GolfBallDyedSkyBlue", "Sky Blue Golf Ball"),
+    NameEntry::new(4253, "GolfBallDyedTeal", "Teal Golf Ball"),
+    NameEntry::new(4254, "GolfBallDyedViolet", "Violet Golf Ball"),
+    NameEntry::new(4255, "GolfBallDyedYellow", "Yellow Golf Ball"),
+    NameEntry::new(4256, "AmberRobe", "Amber Robe"),
+    NameEntry::new(4257, "AmberHook", "Amber Hook"),
+    NameEntry::new(4258, "OrangePhaseblade", "Orange Phaseblade"),
+    NameEntry::new(4259, "OrangePhasesaber", "Orange Phasesaber"),
+    NameEntry::new(4260, "OrangeStainedGlass", "Orange Stained Glass"),
+    NameEntry::new(4261, "OrangePressurePlate", "Orange Pressure Plate"),
+    NameEntry::new(4262, "MysticCoilSnake", "Snake Charmer's Flute"),
+    NameEntry::new(4263, "MagicConch", "Magic Conch"),
+    NameEntry::new(4264, "GolfCart", "Golf Cart Keys"),
+    NameEntry::new(4265, "GolfChest", "Golf Chest"),
+    NameEntry::new(4266, "Fake_GolfChest", "Trapped Golf Chest"),
+    NameEntry::new(4267, "DesertChest", "Sandstone Chest"),
+    NameEntry::new(4268, "Fake_DesertChest", "Trapped Sandstone Chest"),
+    NameEntry::new(4269, "SanguineStaff", "Sanguine Staff"),
+    NameEntry::new(4270, "SharpTears", "Blood Thorn"),
+    NameEntry::new(4271, "BloodMoonStarter", "Bloody Tear"),
+    NameEntry::new(4272, "DripplerFlail", "Drippler Crippler"),
+    NameEntry::new(4273, "VampireFrogStaff", "Vampire Frog Staff"),
+    NameEntry::new(4274, "GoldGoldfish", "Gold Goldfish"),
+    NameEntry::new(4275, "GoldGoldfishBowl", "Gold Fish Bowl"),
+    NameEntry::new(4276, "CatBast", "Bast Statue"),
+    NameEntry::new(4277, "GoldStarryGlassBlock", "Gold Starry Block"),
+    NameEntry::new(4278, "BlueStarryGlassBlock", "Blue Starry Block"),
+    NameEntry::new(4279, "GoldStarryGlassWall", "Gold Starry Wall"),
+    NameEntry::new(4280, "BlueStarryGlassWall", "Blue Starry Wall"),
+    NameEntry::new(4281, "BabyBirdStaff", "Finch Staff"),
+    NameEntry::new(4282, "Apricot", "Apricot"),
+    NameEntry::new(4283, "Banana", "Banana"),
+    NameEntry::new(4284, "BlackCurrant", "Blackcurrant"),
+    NameEntry::new(4285, "BloodOrange", "Blood Orange"),
+    NameEntry::new(4286, "Cherry", "Cherry"),
+    NameEntry::new(4287, "Coconut", "Coconut"),
+    NameEntry::new(4288, "Dragonfruit", "Dragon Fruit"),
+    NameEntry::new(4289, "Elderberry", "Elderberry"),
+    NameEntry::new(4290, "Grapefruit", "Grapefruit"),
+    NameEntry::new(4291, "Lemon", "Lemon"),
+    NameEntry::new(4292, "Mango", "Mango"),
+    NameEntry::new(4293, "Peach", "Peach"),
+    NameEntry::new(4294, "Pineapple", "Pineapple"),
+    NameEntry::new(4295, "Plum", "Plum"),
+    NameEntry::new(4296, "Rambutan", "Rambutan"),
+    NameEntry::new(4297, "Starfruit", "Star Fruit"),
+    NameEntry::new(4298, "SandstoneBathtub", "Sandstone Bathtub"),
+    NameEntry::new(4299, "SandstoneBed", "Sandstone Bed"),
+    NameEntry::new(4300, "SandstoneBookcase", "Sandstone Bookcase"),
+    NameEntry::new(4301, "SandstoneDresser", "Sandstone Dresser"),
+    NameEntry::new(4302, "SandstoneCandelabra", "Sandstone Candelabra"),
+    NameEntry::new(4303, "SandstoneCandle", "Sandstone Candle"),
+    NameEntry::new(4304, "SandstoneChair", "Sandstone Chair"),
+    NameEntry::new(4305, "SandstoneChandelier", "Sandstone Chandelier"),
+    NameEntry::new(4306, "SandstoneClock", "Sandstone Clock"),
+    NameEntry::new(4307, "SandstoneDoor", "Sandstone Door"),
+    NameEntry::new(4308, "SandstoneLamp", "Sandstone Lamp"),
+    NameEntry::new(4309, "SandstoneLantern", "Sandstone Lantern"),
+    NameEntry::new(4310, "SandstonePiano", "Sandstone Piano"),
+    NameEntry::new(4311, "SandstonePlatform", "Sandstone Platform"),
+    NameEntry::new(4312, "SandstoneSink", "Sandstone Sink"),
+    NameEntry::new(4313, "SandstoneSofa", "Sandstone Sofa"),
+    NameEntry::new(4314, "SandstoneTable", "Sandstone Table"),
+    NameEntry::new(4315, "SandstoneWorkbench", "Sandstone Work Bench"),
+    NameEntry::new(4316, "SandstoneToilet", "Sandstone Toilet"),
+    NameEntry::new(4317, "BloodHamaxe", "Haemorrhaxe"),
+    NameEntry::new(4318, "VoidMonolith", "Void Monolith"),
+    NameEntry::new(4319, "ArrowSign", "Arrow Sign"),
+    NameEntry::new(4320, "PaintedArrowSign", "Painted Arrow Sign"),
+    NameEntry::new(4321, "GameMasterShirt", "Master Gamer's Jacket"),
+    NameEntry::new(4322, "GameMasterPants", "Master Gamer's Pants"),
+    NameEntry::new(4323, "StarPrincessCrown", "Star Princess Crown"),
+    NameEntry::new(4324, "StarPrincessDress", "Star Princess Dress"),
+    NameEntry::new(4325, "BloodFishingRod", "Chum Caster"),
+    NameEntry::new(4326, "FoodPlatter", "Plate"),
+    NameEntry::new(4327, "BlackDragonflyJar", "Black Dragonfly Jar"),
+    NameEntry::new(4328, "BlueDragonflyJar", "Blue Dragonfly Jar"),
+    NameEntry::new(4329, "GreenDragonflyJar", "Green Dragonfly Jar"),
+    NameEntry::new(4330, "OrangeDragonflyJar", "Orange Dragonfly Jar"),
+    NameEntry::new(4331, "RedDragonflyJar", "Red Dragonfly Jar"),
+    NameEntry::new(4332, "YellowDragonflyJar", "Yellow Dragonfly Jar"),
+    NameEntry::new(4333, "GoldDragonflyJar", "Gold Dragonfly Jar"),
+    NameEntry::new(4334, "BlackDragonfly", "Black Dragonfly"),
+    NameEntry::new(4335, "BlueDragonfly", "Blue Dragonfly"),
+    NameEntry::new(4336, "GreenDragonfly", "Green Dragonfly"),
+    NameEntry::new(4337, "OrangeDragonfly", "Orange Dragonfly"),
+    NameEntry::new(4338, "RedDragonfly", "Red Dragonfly"),
+    NameEntry::new(4339, "YellowDragonfly", "Yellow Dragonfly"),
+    NameEntry::new(4340, "GoldDragonfly", "Gold Dragonfly"),
+    NameEntry::new(4341, "PortableStool", "Step Stool"),
+    NameEntry::new(4342, "DragonflyStatue", "Dragonfly Statue"),
+    NameEntry::new(4343, "PaperAirplaneA", "Paper Airplane"),
+    NameEntry::new(4344, "PaperAirplaneB", "White Paper Airplane"),
+    NameEntry::new(4345, "CanOfWorms", "Can Of Worms"),
+    NameEntry::new(4346, "EncumberingStone", "Encumbering Stone"),
+    NameEntry::new(4347, "ZapinatorGray", "Gray Zapinator"),
+    NameEntry::new(4348, "ZapinatorOrange", "Orange Zapinator"),
+    NameEntry::new(4349, "GreenMoss", "Green Moss"),
+    NameEntry::new(4350, "BrownMoss", "Brown Moss"),
+    NameEntry::new(4351, "RedMoss", "Red Moss"),
+    NameEntry::new(4352, "BlueMoss", "Blue Moss"),
+    NameEntry::new(4353, "PurpleMoss", "Purple Moss"),
+    NameEntry::new(4354, "LavaMoss", "Lava Moss"),
+    NameEntry::new(4355, "BoulderStatue", "Boulder Statue"),
+    NameEntry::new(4356, "MusicBoxTitleAlt", "Music Box (Journey's Beginning)"),
+    NameEntry::new(4357, "MusicBoxStorm", "Music Box (Storm)"),
+    NameEntry::new(4358, "MusicBoxGraveyard", "Music Box (Graveyard)"),
+    NameEntry::new(4359, "Seagull", "Seagull"),
+    NameEntry::new(4360, "SeagullStatue", "Seagull Statue"),
+    NameEntry::new(4361, "LadyBug", "Ladybug"),
+    NameEntry::new(4362, "GoldLadyBug", "Gold Ladybug"),
+    NameEntry::new(4363, "Maggot", "Maggot"),
+    NameEntry::new(4364, "MaggotCage", "Maggot Cage"),
+    NameEntry::new(4365, "CelestialWand", "Celestial Wand"),
+    NameEntry::new(4366, "EucaluptusSap", "Eucalyptus Sap"),
+    NameEntry::new(4367, "KiteBlue", "Blue Kite"),
+    NameEntry::new(4368, "KiteBlueAndYellow", "Blue and Yellow Kite"),
+    NameEntry::new(4369, "KiteRed", "Red Kite"),
+    NameEntry::new(4370, "KiteRedAndYellow", "Red and Yellow Kite"),
+    NameEntry::new(4371, "KiteYellow", "Yellow Kite"),
+    NameEntry::new(4372, "IvyGuitar", "Ivy"),
+    NameEntry::new(4373, "Pupfish", "Pupfish"),
+    NameEntry::new(4374, "Grebe", "Grebe"),
+    NameEntry::new(4375, "Rat", "Rat"),
+    NameEntry::new(4376, "RatCage", "Rat Cage"),
+    NameEntry::new(4377, "KryptonMoss", "Krypton Moss"),
+    NameEntry::new(4378, "XenonMoss", "Xenon Moss"),
+    NameEntry::new(4379, "KiteWyvern", "Wyvern Kite"),
+    NameEntry::new(4380, "LadybugCage", "Ladybug Cage"),
+    NameEntry::new(4381, "BloodRainBow", "Blood Rain Bow"),
+    NameEntry::new(4382, "CombatBook", "Advanced Combat Techniques"),
+    NameEntry::new(4383, "DesertTorch", "Desert Torch"),
+    NameEntry::new(4384, "CoralTorch", "Coral Torch"),
+    NameEntry::new(4385, "CorruptTorch", "Corrupt Torch"),
+    NameEntry::new(4386, "CrimsonTorch", "Crimson Torch"),
+    NameEntry::new(4387, "HallowedTorch", "Hallowed Torch"),
+    NameEntry::new(4388, "JungleTorch", "Jungle Torch"),
+    NameEntry::new(4389, "ArgonMoss", "Argon Moss"),
+    NameEntry::new(4390, "RollingCactus", "Rolling Cactus"),
+    NameEntry::new(4391, "ThinIce", "Thin Ice"),
+    NameEntry::new(4392, "EchoBlock", "Echo Block"),
+    NameEntry::new(4393, "ScarabFish", "Scarab Fish"),
+    NameEntry::new(4394, "ScorpioFish", "Scorpio Fish"),
+    NameEntry::new(4395, "Owl", "Owl"),
+    NameEntry::new(4396, "OwlCage", "Owl Cage"),
+    NameEntry::new(4397, "OwlStatue", "Owl Statue"),
+    NameEntry::new(4398, "PupfishBowl", "Pupfish Bowl"),
+    NameEntry::new(4399, "GoldLadybugCage", "Gold Ladybug Cage"),
+    NameEntry::new(4400, "Geode", "Geode"),
+    NameEntry::new(4401, "Flounder", "Flounder"),
+    NameEntry::new(4402, "RockLobster", "Rock Lobster"),
+    NameEntry::new(4403, "LobsterTail", "Lobster Tail"),
+    NameEntry::new(4404, "FloatingTube", "Inner Tube"),
+    NameEntry::new(4405, "FrozenCrate", "Frozen Crate"),
+    NameEntry::new(4406, "FrozenCrateHard", "Boreal Crate"),
+    NameEntry::new(4407, "OasisCrate", "Oasis Crate"),
+    NameEntry::new(4408, "OasisCrateHard", "Mirage Crate"),
+    NameEntry::new(4409, "SpectreGoggles", "Spectre Goggles"),
+    NameEntry::new(4410, "Oyster", "Oyster"),
+    NameEntry::new(4411, "ShuckedOyster", "Shucked Oyster"),
+    NameEntry::new(4412, "WhitePearl", "White Pearl"),
+    NameEntry::new(4413, "BlackPearl", "Black Pearl"),
+    NameEntry::new(4414, "PinkPearl", "Pink Pearl"),
+    NameEntry::new(4415, "StoneDoor", "Stone Door"),
+    NameEntry::new(4416, "StonePlatform", "Stone Platform"),
+    NameEntry::new(4417, "OasisFountain", "Oasis Water Fountain"),
+    NameEntry::new(4418, "WaterStrider", "Water Strider"),
+    NameEntry::new(4419, "GoldWaterStrider", "Gold Water Strider"),
+    NameEntry::new(4420, "LawnFlamingo", "Lawn Flamingo"),
+    NameEntry::new(4421, "MusicBoxUndergroundJungle", "Music Box (Underground Jungle)"),
+    NameEntry::new(4422, "Grate", "Grate"),
+    NameEntry::new(4423, "ScarabBomb", "Scarab Bomb"),
+    NameEntry::new(4424, "WroughtIronFence", "Wrought Iron Fence"),
+    NameEntry::new(4425, "SharkBait", "Shark Bait"),
+    NameEntry::new(4426, "BeeMinecart", "Bee Minecart"),
+    NameEntry::new(4427, "LadybugMinecart", "Ladybug Minecart"),
+    NameEntry::new(4428, "PigronMinecart", "Pigron Minecart"),
+    NameEntry::new(4429, "SunflowerMinecart", "Sunflower Minecart"),
+    NameEntry::new(4430, "PottedForestCedar", "Potted Forest Cedar"),
+    NameEntry::new(4431, "PottedJungleCedar", "Potted Jungle Cedar"),
+    NameEntry::new(4432, "PottedHallowCedar", "Potted Hallow Cedar"),
+    NameEntry::new(4433, "PottedForestTree", "Potted Forest Tree"),
+    NameEntry::new(4434, "PottedJungleTree", "Potted Jungle Tree"),
+    NameEntry::new(4435, "PottedHallowTree", "Potted Hallow Tree"),
+    NameEntry::new(4436, "PottedForestPalm", "Potted Forest Palm"),
+    NameEntry::new(4437, "PottedJunglePalm", "Potted Jungle Palm"),
+    NameEntry::new(4438, "PottedHallowPalm", "Potted Hallow Palm"),
+    NameEntry::new(4439, "PottedForestBamboo", "Potted Forest Bamboo"),
+    NameEntry::new(4440, "PottedJungleBamboo", "Potted Jungle Bamboo"),
+    NameEntry::new(4441, "PottedHallowBamboo", "Potted Hallow Bamboo"),
+    NameEntry::new(4442, "ScarabFishingRod", "Scarab Fishing Rod"),
+    NameEntry::new(4443, "HellMinecart", "Demonic Hellcart"),
+    NameEntry::new(4444, "WitchBroom", "Witch's Broom"),
+    NameEntry::new(4445, "ClusterRocketI", "Cluster Rocket I"),
+    NameEntry::new(4446, "ClusterRocketII", "Cluster Rocket II"),
+    NameEntry::new(4447, "WetRocket", "Wet Rocket"),
+    NameEntry::new(4448, "LavaRocket", "Lava Rocket"),
+    NameEntry::new(4449, "HoneyRocket", "Honey Rocket"),
+    NameEntry::new(4450, "ShroomMinecart", "Shroom Minecart"),
+    NameEntry::new(4451, "AmethystMinecart", "Amethyst Minecart"),
+    NameEntry::new(4452, "TopazMinecart", "Topaz Minecart"),
+    NameEntry::new(4453, "SapphireMinecart", "Sapphire Minecart"),
+    NameEntry::new(4454, "EmeraldMinecart", "Emerald Minecart"),
+    NameEntry::new(4455, "RubyMinecart", "Ruby Minecart"),
+    NameEntry::new(4456, "DiamondMinecart", "Diamond Minecart"),
+    NameEntry::new(4457, "MiniNukeI", "Mini Nuke I"),
+    NameEntry::new(4458, "MiniNukeII", "Mini Nuke II"),
+    NameEntry::new(4459, "DryRocket", "Dry Rocket"),
+    NameEntry::new(4460, "SandcastleBucket", "Sandcastle Bucket"),
+    NameEntry::new(4461, "TurtleCage", "Turtle Cage"),
+    NameEntry::new(4462, "TurtleJungleCage", "Jungle Turtle Cage"),
+    NameEntry::new(4463, "Gladius", "Gladius"),
+    NameEntry::new(4464, "Turtle", "Turtle"),
+    NameEntry::new(4465, "TurtleJungle", "Jungle Turtle"),
+    NameEntry::new(4466, "TurtleStatue", "Turtle Statue"),
+    NameEntry::new(4467, "AmberMinecart", "Amber Minecart"),
+    NameEntry::new(4468, "BeetleMinecart", "Beetle Minecart"),
+    NameEntry::new(4469, "MeowmereMinecart", "Meowmere Minecart"),
+    NameEntry::new(4470, "PartyMinecart", "Party Wagon"),
+    NameEntry::new(4471, "PirateMinecart", "The Dutchman"),
+    NameEntry::new(4472, "SteampunkMinecart", "Steampunk Minecart"),
+    NameEntry::new(4473, "GrebeCage", "Grebe Cage"),
+    NameEntry::new(4474, "SeagullCage", "Seagull Cage"),
+    NameEntry::new(4475, "WaterStriderCage", "Water Strider Cage"),
+    NameEntry::new(4476, "GoldWaterStriderCage", "Gold Water Strider Cage"),
+    NameEntry::new(4477, "LuckPotionLesser", "Lesser Luck Potion"),
+    NameEntry::new(4478, "LuckPotion", "Luck Potion"),
+    NameEntry::new(4479, "LuckPotionGreater", "Greater Luck Potion"),
+    NameEntry::new(4480, "Seahorse", "Seahorse"),
+    NameEntry::new(4481, "SeahorseCage", "Seahorse Cage"),
+    NameEntry::new(4482, "GoldSeahorse", "Gold Seahorse"),
+    NameEntry::new(4483, "GoldSeahorseCage", "Gold Seahorse Cage"),
+    NameEntry::new(4484, "TimerOneHalfSecond", "1/2 Second Timer"),
+    NameEntry::new(4485, "TimerOneFourthSecond", "1/4 Second Timer"),
+    NameEntry::new(4486, "EbonstoneEcho", "Ebonstone Wall"),
+    NameEntry::new(4487, "MudWallEcho", "Mud Wall"),
+    NameEntry::new(4488, "PearlstoneEcho", "Pearlstone Wall"),
+    NameEntry::new(4489, "SnowWallEcho", "Snow Wall"),
+    NameEntry::new(4490, "AmethystEcho", "Amethyst Stone Wall"),
+    NameEntry::new(4491, "TopazEcho", "Topaz Stone Wall"),
+    NameEntry::new(4492, "SapphireEcho", "Sapphire Stone Wall"),
+    NameEntry::new(4493, "EmeraldEcho", "Emerald Stone Wall"),
+    NameEntry::new(4494, "RubyEcho", "Ruby Stone Wall"),
+    NameEntry::new(4495, "DiamondEcho", "Diamond Stone Wall"),
+    NameEntry::new(4496, "Cave1Echo", "Green Mossy Wall"),
+    NameEntry::new(4497, "Cave2Echo", "Brown Mossy Wall"),
+    NameEntry::new(4498, "Cave3Echo", "Red Mossy Wall"),
+    NameEntry::new(4499, "Cave4Echo", "Blue Mossy Wall"),
+    NameEntry::new(4500, "Cave5Echo", "Purple Mossy Wall"),
+    NameEntry::new(4501, "Cave6Echo", "Rocky Dirt Wall"),
+    NameEntry::new(4502, "Cave7Echo", "Old Stone Wall"),
+    NameEntry::new(4503, "SpiderEcho", "Spider Wall"),
+    NameEntry::new(4504, "CorruptGrassEcho", "Corrupt Grass Wall"),
+    NameEntry::new(4505, "HallowedGrassEcho", "Hallowed Grass Wall"),
+    NameEntry::new(4506, "IceEcho", "Ice Wall"),
+    NameEntry::new(4507, "ObsidianBackEcho", "Obsidian Wall"),
+    NameEntry::new(4508, "CrimsonGrassEcho", "Crimson Grass Wall"),
+    NameEntry::new(4509, "CrimstoneEcho", "Crimstone Wall"),
+    NameEntry::new(4510, "CaveWall1Echo", "Cave Dirt Wall"),
+    NameEntry::new(4511, "CaveWall2Echo", "Rough Dirt Wall"),
+    NameEntry::new(4512, "Cave8Echo", "Craggy Stone Wall"),
+    NameEntry::new(4513, "Corruption1Echo", "Corrupt Growth Wall"),
+    NameEntry::new(4514, "Corruption2Echo", "Corrupt Mass Wall"),
+    NameEntry::new(4515, "Corruption3Echo", "Corrupt Pustule Wall"),
+    NameEntry::new(4516, "Corruption4Echo", "Corrupt Tendril Wall"),
+    NameEntry::new(4517, "Crimson1Echo", "Crimson Crust Wall"),
+    NameEntry::new(4518, "Crimson2Echo", "Crimson Scab Wall"),
+    NameEntry::new(4519, "Crimson3Echo", "Crimson Teeth Wall"),
+    NameEntry::new(4520, "Crimson4Echo", "Crimson Blister Wall"),
+    NameEntry::new(4521, "Dirt1Echo", "Layered Dirt Wall"),
+    NameEntry::new(4522, "Dirt2Echo", "Crumbling Dirt Wall"),
+    NameEntry::new(4523, "Dirt3Echo", "Cracked Dirt Wall"),
+    NameEntry::new(4524, "Dirt4Echo", "Wavy Dirt Wall"),
+    NameEntry::new(4525, "Hallow1Echo", "Hallowed Prism Wall"),
+    NameEntry::new(4526, "Hallow2Echo", "Hallowed Cavern Wall"),
+    NameEntry::new(4527, "Hallow3Echo", "Hallowed Shard Wall"),
+    NameEntry::new(4528, "Hallow4Echo", "Hallowed Crystalline Wall"),
+    NameEntry::new(4529, "Jungle1Echo", "Lichen Stone Wall"),
+    NameEntry::new(4530, "Jungle2Echo", "Leafy Jungle Wall"),
+    NameEntry::new(4531, "Jungle3Echo", "Ivy Stone Wall"),
+    NameEntry::new(4532, "Jungle4Echo", "Jungle Vine Wall"),
+    NameEntry::new(4533, "Lava1Echo", "Ember Wall"),
+    NameEntry::new(4534, "Lava2Echo", "Cinder Wall"),
+    NameEntry::new(4535, "Lava3Echo", "Magma Wall"),
+    NameEntry::new(4536, "Lava4Echo", "Smouldering Stone Wall"),
+    NameEntry::new(4537, "Rocks1Echo", "Worn Stone Wall"),
+    NameEntry::new(4538, "Rocks2Echo", "Stalactite Stone Wall"),
+    NameEntry::new(4539, "Rocks3Echo", "Mottled Stone Wall"),
+    NameEntry::new(4540, "Rocks4Echo", "Fractured Stone Wall"),
+    NameEntry::new(4541, "TheBrideBanner", "The Bride Banner"),
+    NameEntry::new(4542, "ZombieMermanBanner", "Zombie Merman Banner"),
+    NameEntry::new(4543, "EyeballFlyingFishBanner", "Wandering Eye Fish Banner"),
+    NameEntry::new(4544, "BloodSquidBanner", "Blood Squid Banner"),
+    NameEntry::new(4545, "BloodEelBanner", "Blood Eel Banner"),
+    NameEntry::new(4546, "GoblinSharkBanner", "Hemogoblin Shark Banner"),
+    NameEntry::new(4547, "LargeBambooBlock", "Large Bamboo"),
+    NameEntry::new(4548, "LargeBambooBlockWall", "Large Bamboo Wall"),
+    NameEntry::new(4549, "DemonHorns", "Demon Horns"),
+    NameEntry::new(4550, "BambooLeaf", "Bamboo Leaf"),
+    NameEntry::new(4551, "HellCake", "Slice of Hell Cake"),
+    NameEntry::new(4552, "FogMachine", "Fog Machine"),
+    NameEntry::new(4553, "PlasmaLamp", "Plasma Lamp"),
+    NameEntry::new(4554, "MarbleColumn", "Marble Column"),
+    NameEntry::new(4555, "ChefHat", "Chef Hat"),
+    NameEntry::new(4556, "ChefShirt", "Chef Uniform"),
+    NameEntry::new(4557, "ChefPants", "Chef Pants"),
+    NameEntry::new(4558, "StarHairpin", "Star Hairpin"),
+    NameEntry::new(4559, "HeartHairpin", "Heart Hairpin"),
+    NameEntry::new(4560, "BunnyEars", "Bunny Ears"),
+    NameEntry::new(4561, "DevilHorns", "Devil Horns"),
+    NameEntry::new(4562, "Fedora", "Fedora"),
+    NameEntry::new(4563, "UnicornHornHat", "Fake Unicorn Horn"),
+    NameEntry::new(4564, "BambooBlock", "Bamboo"),
+    NameEntry::new(4565, "BambooBlockWall", "Bamboo Wall"),
+    NameEntry::new(4566, "BambooBathtub", "Bamboo Bathtub"),
+    NameEntry::new(4567, "BambooBed", "Bamboo Bed"),
+    NameEntry::new(4568, "BambooBookcase", "Bamboo Bookcase"),
+    NameEntry::new(4569, "BambooDresser", "Bamboo Dresser"),
+    NameEntry::new(4570, "BambooCandelabra", "Bamboo Candelabra"),
+    NameEntry::new(4571, "BambooCandle", "Bamboo Candle"),
+    NameEntry::new(4572, "BambooChair", "Bamboo Chair"),
+    NameEntry::new(4573, "BambooChandelier", "Bamboo Chandelier"),
+    NameEntry::new(4574, "BambooChest", "Bamboo Chest"),
+    NameEntry::new(4575, "BambooClock", "Bamboo Clock"),
+    NameEntry::new(4576, "BambooDoor", "Bamboo Door"),
+    NameEntry::new(4577, "BambooLamp", "Bamboo Lamp"),
+    NameEntry::new(4578, "BambooLantern", "Bamboo Lantern"),
+    NameEntry::new(4579, "BambooPiano", "Bamboo Piano"),
+    NameEntry::new(4580, "BambooPlatform", "Bamboo Platform"),
+    NameEntry::new(4581, "BambooSink", "Bamboo Sink"),
+    NameEntry::new(4582, "BambooSofa", "Bamboo Sofa"),
+    NameEntry::new(4583, "BambooTable", "Bamboo Table"),
+    NameEntry::new(4584, "BambooWorkbench", "Bamboo Work Bench"),
+    NameEntry::new(4585, "Fake_BambooChest", "Trapped Bamboo Chest"),
+    NameEntry::new(4586, "BambooToilet", "Bamboo Toilet"),
+    NameEntry::new(4587, "GolfClubStoneIron", "Worn Golf Club (Iron)"),
+    NameEntry::new(4588, "GolfClubRustyPutter", "Worn Golf Club (Putter)"),
+    NameEntry::new(4589, "GolfClubBronzeWedge", "Worn Golf Club (Wedge)"),
+    NameEntry::new(4590, "GolfClubWoodDriver", "Worn Golf Club (Driver)"),
+    NameEntry::new(4591, "GolfClubMythrilIron", "Fancy Golf Club (Iron)"),
+    NameEntry::new(4592, "GolfClubLeadPutter", "Fancy Golf Club (Putter)"),
+    NameEntry::new(4593, "GolfClubGoldWedge", "Fancy Golf Club (Wedge)"),
+    NameEntry::new(4594, "GolfClubPearlwoodDriver", "Fancy Golf Club (Driver)"),
+    NameEntry::new(4595, "GolfClubTitaniumIron", "Premium Golf Club (Iron)"),
+    NameEntry::new(4596, "GolfClubShroomitePutter", "Premium Golf Club (Putter)"),
+    NameEntry::new(4597, "GolfClubDiamondWedge", "Premium Golf Club (Wedge)"),
+    NameEntry::new(4598, "GolfClubChlorophyteDriver", "Premium Golf Club (Driver)"),
+    NameEntry::new(4599, "GolfTrophyBronze", "Bronze Golf Trophy"),
+    NameEntry::new(4600, "GolfTrophySilver", "Silver Golf Trophy"),
+    NameEntry::new(4601, "GolfTrophyGold", "Gold Golf Trophy"),
+    NameEntry::new(4602, "BloodNautilusBanner", "Dreadnautilus Banner"),
+    NameEntry::new(4603, "BirdieRattle", "Birdie Rattle"),
+    NameEntry::new(4604, "ExoticEasternChewToy", "Exotic Chew Toy"),
+    NameEntry::new(4605, "BedazzledNectar", "Bedazzled Nectar"),
+    NameEntry::new(4606, "MusicBoxJungleNight", "Music Box (Jungle Night)"),
+    NameEntry::new(4607, "StormTigerStaff", "Desert Tiger Staff"),
+    NameEntry::new(4608, "ChumBucket", "Chum Bucket"),
+    NameEntry::new(4609, "GardenGnome", "Garden Gnome"),
+    NameEntry::new(4610, "KiteBoneSerpent", "Bone Serpent Kite"),
+    NameEntry::new(4611, "KiteWorldFeeder", "World Feeder Kite"),
+    NameEntry::new(4612, "KiteBunny", "Bunny Kite"),
+    NameEntry::new(4613, "KitePigron", "Pigron Kite"),
+    NameEntry::new(4614, "AppleJuice", "Apple Juice"),
+    NameEntry::new(4615, "GrapeJuice", "Grape Juice"),
+    NameEntry::new(4616, "Lemonade", "Lemonade"),
+    NameEntry::new(4617, "BananaDaiquiri", "Frozen Banana Daiquiri"),
+    NameEntry::new(4618, "PeachSangria", "Peach Sangria"),
+    NameEntry::new(4619, "PinaColada", "Piña Colada"),
+    NameEntry::new(4620, "TropicalSmoothie", "Tropical Smoothie"),
+    NameEntry::new(4621, "BloodyMoscato", "Bloody Moscato"),
+    NameEntry::new(4622, "SmoothieofDarkness", "Smoothie of Darkness"),
+    NameEntry::new(4623, "PrismaticPunch", "Prismatic Punch"),
+    NameEntry::new(4624, "FruitJuice", "Fruit Juice"),
+    NameEntry::new(4625, "FruitSalad", "Fruit Salad"),
+    NameEntry::new(4626, "AndrewSphinx", "Andrew Sphinx"),
+    NameEntry::new(4627, "WatchfulAntlion", "Watchful Antlion"),
+    NameEntry::new(4628, "BurningSpirit", "Burning Spirit"),
+    NameEntry::new(4629, "JawsOfDeath", "Jaws of Death"),
+    NameEntry::new(4630, "TheSandsOfSlime", "The Sands of Slime"),
+    NameEntry::new(4631, "SnakesIHateSnakes", "Snakes, I Hate Snakes"),
+    NameEntry::new(4632, "LifeAboveTheSand", "Life Above the Sand"),
+    NameEntry::new(4633, "Oasis", "Oasis"),
+    NameEntry::new(4634, "PrehistoryPreserved", "Prehistory Preserved"),
+    NameEntry::new(4635, "AncientTablet", "Ancient Tablet"),
+    NameEntry::new(4636, "Uluru", "Uluru"),
+    NameEntry::new(4637, "VisitingThePyramids", "Visiting the Pyramids"),
+    NameEntry::new(4638, "BandageBoy", "Bandage Boy"),
+    NameEntry::new(4639, "DivineEye", "Divine Eye"),
+    NameEntry::new(4640, "AmethystStoneBlock", "Amethyst Stone Block"),
+    NameEntry::new(4641, "TopazStoneBlock", "Topaz Stone Block"),
+    NameEntry::new(4642, "SapphireStoneBlock", "Sapphire Stone Block"),
+    NameEntry::new(4643, "EmeraldStoneBlock", "Emerald Stone Block"),
+    NameEntry::new(4644, "RubyStoneBlock", "Ruby Stone Block"),
+    NameEntry::new(4645, "DiamondStoneBlock", "Diamond Stone Block"),
+    NameEntry::new(4646, "AmberStoneBlock", "Amber Stone Block"),
+    NameEntry::new(4647, "AmberStoneWallEcho", "Amber Stone Wall"),
+    NameEntry::new(4648, "KiteManEater", "Man Eater Kite"),
+    NameEntry::new(4649, "KiteJellyfishBlue", "Blue Jellyfish Kite"),
+    NameEntry::new(4650, "KiteJellyfishPink", "Pink Jellyfish Kite"),
+    NameEntry::new(4651, "KiteShark", "Shark Kite"),
+    NameEntry::new(4652, "SuperHeroMask", "Superhero Mask"),
+    NameEntry::new(4653, "SuperHeroCostume", "Superhero Costume"),
+    NameEntry::new(4654, "SuperHeroTights", "Superhero Tights"),
+    NameEntry::new(4655, "PinkFairyJar", "Pink Fairy Jar"),
+    NameEntry::new(4656, "GreenFairyJar", "Green Fairy Jar"),
+    NameEntry::new(4657, "BlueFairyJar", "Blue Fairy Jar"),
+    NameEntry::new(4658, "GolfPainting1", "The Rolling Greens"),
+    NameEntry::new(4659, "GolfPainting2", "Study of a Ball at Rest"),
+    NameEntry::new(4660, "GolfPainting3", "Fore!"),
+    NameEntry::new(4661, "GolfPainting4", "The Duplicity of Reflections"),
+    NameEntry::new(4662, "FogboundDye", "Fogbound Dye"),
+    NameEntry::new(4663, "BloodbathDye", "Bloodbath Dye"),
+    NameEntry::new(4664, "PrettyPinkDressSkirt", "Pretty Pink Dress"),
+    NameEntry::new(4665, "PrettyPinkDressPants", "Pretty Pink Stockings"),
+    NameEntry::new(4666, "PrettyPinkRibbon", "Pretty Pink Ribbon"),
+    NameEntry::new(4667, "BambooFence", "Bamboo Fence"),
+    NameEntry::new(4668, "GlowPaint", "Illuminant Coating"),
+    NameEntry::new(4669, "KiteSandShark", "Sand Shark Kite"),
+    NameEntry::new(4670, "KiteBunnyCorrupt", "Corrupt Bunny Kite"),
+    NameEntry::new(4671, "KiteBunnyCrimson", "Vicious Bunny Kite"),
+    NameEntry::new(4672, "BlandWhip", "Leather Whip"),
+    NameEntry::new(4673, "DrumStick", "Drumstick"),
+    NameEntry::new(4674, "KiteGoldfish", "Goldfish Kite"),
+    NameEntry::new(4675, "KiteAngryTrapper", "Angry Trapper Kite"),
+    NameEntry::new(4676, "KiteKoi", "Koi Kite"),
+    NameEntry::new(4677, "KiteCrawltipede", "Crawltipede Kite"),
+    NameEntry::new(4678, "SwordWhip", "Durendal"),
+    NameEntry::new(4679, "MaceWhip", "Morning Star"),
+    NameEntry::new(4680, "ScytheWhip", "Dark Harvest"),
+    NameEntry::new(4681, "KiteSpectrum", "Spectrum Kite"),
+    NameEntry::new(4682, "ReleaseDoves", "Release Doves"),
+    NameEntry::new(4683, "KiteWanderingEye", "Wandering Eye Kite"),
+    NameEntry::new(4684, "KiteUnicorn", "Unicorn Kite"),
+    NameEntry::new(4685, "UndertakerHat", "Gravedigger Hat"),
+    NameEntry::new(4686, "UndertakerCoat", "Gravedigger Coat"),
+    NameEntry::new(4687, "DandelionBanner", "Angry Dandelion Banner"),
+    NameEntry::new(4688, "GnomeBanner", "Gnome Banner"),
+    NameEntry::new(4689, "DesertCampfire", "Desert Campfire"),
+    NameEntry::new(4690, "CoralCampfire", "Coral Campfire"),
+    NameEntry::new(4691, "CorruptCampfire", "Corrupt Campfire"),
+    NameEntry::new(4692, "CrimsonCampfire", "Crimson Campfire"),
+    NameEntry::new(4693, "HallowedCampfire", "Hallowed Campfire"),
+    NameEntry::new(4694, "JungleCampfire", "Jungle Campfire"),
+    NameEntry::new(4695, "SoulBottleLight", "Soul of Light in a Bottle"),
+    NameEntry::new(4696, "SoulBottleNight", "Soul of Night in a Bottle"),
+    NameEntry::new(4697, "SoulBottleFlight", "Soul of Flight in a Bottle"),
+    NameEntry::new(4698, "SoulBottleSight", "Soul of Sight in a Bottle"),
+    NameEntry::new(4699, "SoulBottleMight", "Soul of Might in a Bottle"),
+    NameEntry::new(4700, "SoulBottleFright", "Soul of Fright in a Bottle"),
+    NameEntry::new(4701, "MudBud", "Mud Bud"),
+    NameEntry::new(4702, "ReleaseLantern", "Release Lantern"),
+    NameEntry::new(4703, "QuadBarrelShotgun", "Quad-Barrel Shotgun"),
+    NameEntry::new(4704, "FuneralHat", "Funeral Hat"),
+    NameEntry::new(4705, "FuneralCoat", "Funeral Coat"),
+    NameEntry::new(4706, "FuneralPants", "Funeral Pants"),
+    NameEntry::new(4707, "TragicUmbrella", "Tragic Umbrella"),
+    NameEntry::new(4708, "VictorianGothHat", "Victorian Goth Hat"),
+    NameEntry::new(4709, "VictorianGothDress", "Victorian Goth Dress"),
+    NameEntry::new(4710, "TatteredWoodSign", "Tattered Wood Sign"),
+    NameEntry::new(4711, "GravediggerShovel", "Gravedigger's Shovel"),
+    NameEntry::new(4712, "DungeonDesertChest", "Desert Chest"),
+    NameEntry::new(4713, "Fake_DungeonDesertChest", "Trapped Desert Chest"),
+    NameEntry::new(4714, "DungeonDesertKey", "Desert Key"),
+    NameEntry::new(4715, "SparkleGuitar", "Stellar Tune"),
+    NameEntry::new(4716, "MolluskWhistle", "Mollusk Whistle"),
+    NameEntry::new(4717, "BorealBeam", "Boreal Beam"),
+    NameEntry::new(4718, "RichMahoganyBeam", "Rich Mahogany Beam"),
+    NameEntry::new(4719, "GraniteColumn", "Granite Column"),
+    NameEntry::new(4720, "SandstoneColumn", "Sandstone Column"),
+    NameEntry::new(4721, "MushroomBeam", "Mushroom Beam"),
+    NameEntry::new(4722, "FirstFractal", "FirstFractal"),
+    NameEntry::new(4723, "Nevermore", "Nevermore"),
+    NameEntry::new(4724, "Reborn", "Reborn"),
+    NameEntry::new(4725, "Graveyard", "Graveyard"),
+    NameEntry::new(4726, "GhostManifestation", "Ghost Manifestation"),
+    NameEntry::new(4727, "WickedUndead", "Wicked Undead"),
+    NameEntry::new(4728, "BloodyGoblet", "Bloody Goblet"),
+    NameEntry::new(4729, "StillLife", "Still Life"),
+    NameEntry::new(4730, "GhostarsWings", "Ghostar's Infinity Eight"),
+    NameEntry::new(4731, "TerraToilet", "Terra Toilet"),
+    NameEntry::new(4732, "GhostarSkullPin", "Ghostar's Soul Jar"),
+    NameEntry::new(4733, "GhostarShirt", "Ghostar's Garb"),
+    NameEntry::new(4734, "GhostarPants", "Ghostar's Tights"),
+    NameEntry::new(4735, "BallOfFuseWire", "Ball O' Fuse Wire"),
+    NameEntry::new(4736, "FullMoonSqueakyToy", "Full Moon Squeaky Toy"),
+    NameEntry::new(4737, "OrnateShadowKey", "Ornate Shadow Key"),
+    NameEntry::new(4738, "DrManFlyMask", "Dr. Man Fly Mask"),
+    NameEntry::new(4739, "DrManFlyLabCoat", "Dr. Man Fly's Lab Coat"),
+    NameEntry::new(4740, "ButcherMask", "Butcher Mask"),
+    NameEntry::new(4741, "ButcherApron", "Butcher's Bloodstained Apron"),
+    NameEntry::new(4742, "ButcherPants", "Butcher's Bloodstained Pants"),
+    NameEntry::new(4743, "Football", "Football"),
+    NameEntry::new(4744, "HunterCloak", "Hunter Cloak"),
+    NameEntry::new(4745, "CoffinMinecart", "Coffin Minecart"),
+    NameEntry::new(4746, "SafemanWings", "Safeman's Blanket Cape"),
+    NameEntry::new(4747, "SafemanSunHair", "Safeman's Sunny Day"),
+    NameEntry::new(4748, "SafemanSunDress", "Safeman's Sun Dress"),
+    NameEntry::new(4749, "SafemanDressLeggings", "Safeman's Pink Leggings"),
+    NameEntry::new(4750, "FoodBarbarianWings", "FoodBarbarian's Tattered Dragon Wings"),
+    NameEntry::new(4751, "FoodBarbarianHelm", "FoodBarbarian's Horned Helm"),
+    NameEntry::new(4752, "FoodBarbarianArmor", "FoodBarbarian's Wild Wolf Spaulders"),
+    NameEntry::new(4753, "FoodBarbarianGreaves", "FoodBarbarian's Savage Greaves"),
+    NameEntry::new(4754, "GroxTheGreatWings", "Grox The Great's Wings"),
+    NameEntry::new(4755, "GroxTheGreatHelm", "Grox The Great's Horned Cowl"),
+    NameEntry::new(4756, "GroxTheGreatArmor", "Grox The Great's Chestplate"),
+    NameEntry::new(4757, "GroxTheGreatGreaves", "Grox The Great's Greaves"),
+    NameEntry::new(4758, "Smolstar", "Blade Staff"),
+    NameEntry::new(4759, "SquirrelHook", "Squirrel Hook"),
+    NameEntry::new(4760, "BouncingShield", "Sergeant United Shield"),
+    NameEntry::new(4761, "RockGolemHead", "Rock Golem Head"),
+    NameEntry::new(4762, "CritterShampoo", "Critter Shampoo"),
+    NameEntry::new(4763, "DiggingMoleMinecart", "Digging Molecart"),
+    NameEntry::new(4764, "Shroomerang", "Shroomerang"),
+    NameEntry::new(4765, "TreeGlobe", "Tree Globe"),
+    NameEntry::new(4766, "WorldGlobe", "World Globe"),
+    NameEntry::new(4767, "DontHurtCrittersBook", "Guide to Critter Companionship"),
+    NameEntry::new(4768, "DogEars", "Dog Ears"),
+    NameEntry::new(4769, "DogTail", "Dog Tail"),
+    NameEntry::new(4770, "FoxEars", "Fox Ears"),
+    NameEntry::new(4771, "FoxTail", "Fox Tail"),
+    NameEntry::new(4772, "LizardEars", "Lizard Ears"),
+    NameEntry::new(4773, "LizardTail", "Lizard Tail"),
+    NameEntry::new(4774, "PandaEars", "Panda Ears"),
+    NameEntry::new(4775, "BunnyTail", "Bunny Tail"),
+    NameEntry::new(4776, "FairyGlowstick", "Fairy Glowstick"),
+    NameEntry::new(4777, "LightningCarrot", "Lightning Carrot"),
+    NameEntry::new(4778, "HallowBossDye", "Prismatic Dye"),
+    NameEntry::new(4779, "MushroomHat", "Mushroom Hat"),
+    NameEntry::new(4780, "MushroomVest", "Mushroom Vest"),
+    NameEntry::new(4781, "MushroomPants", "Mushroom Pants"),
+    NameEntry::new(4782, "FairyQueenBossBag", "Treasure Bag (Empress of Light)"),
+    NameEntry::new(4783, "FairyQueenTrophy", "Empress of Light Trophy"),
+    NameEntry::new(4784, "FairyQueenMask", "Empress of Light Mask"),
+    NameEntry::new(4785, "PaintedHorseSaddle", "Dusty Rawhide Saddle"),
+    NameEntry::new(4786, "MajesticHorseSaddle", "Royal Gilded Saddle"),
+    NameEntry::new(4787, "DarkHorseSaddle", "Black Studded Saddle"),
+    NameEntry::new(4788, "JoustingLance", "Jousting Lance"),
+    NameEntry::new(4789, "ShadowJoustingLance", "Shadow Jousting Lance"),
+    NameEntry::new(4790, "HallowJoustingLance", "Hallowed Jousting Lance"),
+    NameEntry::new(4791, "PogoStick", "Pogo Stick"),
+    NameEntry::new(4792, "PirateShipMountItem", "The Black Spot"),
+    NameEntry::new(4793, "SpookyWoodMountItem", "Hexxed Branch"),
+    NameEntry::new(4794, "SantankMountItem", "Toy Tank"),
+    NameEntry::new(4795, "WallOfFleshGoatMountItem", "Goat Skull"),
+    NameEntry::new(4796, "DarkMageBookMountItem", "Dark Mage's Tome"),
+    NameEntry::new(4797, "KingSlimePetItem", "Royal Delight"),
+    NameEntry::new(4798, "EyeOfCthulhuPetItem", "Suspicious Grinning Eye"),
+    NameEntry::new(4799, "EaterOfWorldsPetItem", "Writhing Remains"),
+    NameEntry::new(4800, "BrainOfCthulhuPetItem", "Brain in a Jar"),
+    NameEntry::new(4801, "SkeletronPetItem", "Possessed Skull"),
+    NameEntry::new(4802, "QueenBeePetItem", "Sparkling Honey"),
+    NameEntry::new(4803, "DestroyerPetItem", "Deactivated Probe"),
+    NameEntry::new(4804, "TwinsPetItem", "Pair of Eyeballs"),
+    NameEntry::new(4805, "SkeletronPrimePetItem", "Robotic Skull"),
+    NameEntry::new(4806, "PlanteraPetItem", "Plantera Seedling"),
+    NameEntry::new(4807, "GolemPetItem", "Guardian Golem"),
+    NameEntry::new(4808, "DukeFishronPetItem", "Pork of the Sea"),
+    NameEntry::new(4809, "LunaticCultistPetItem", "Tablet Fragment"),
+    NameEntry::new(4810, "MoonLordPetItem", "Piece of Moon Squid"),
+    NameEntry::new(4811, "FairyQueenPetItem", "Jewel of Light"),
+    NameEntry::new(4812, "PumpkingPetItem", "Pumpkin Scented Candle"),
+    NameEntry::new(4813, "EverscreamPetItem", "Shrub Star"),
+    NameEntry::new(4814, "IceQueenPetItem", "Frozen Crown"),
+    NameEntry::new(4815, "MartianPetItem", "Cosmic Skateboard"),
+    NameEntry::new(4816, "DD2OgrePetItem", "Ogre's Club"),
+    NameEntry::new(4817, "DD2BetsyPetItem", "Betsy's Egg"),
+    NameEntry::new(4818, "CombatWrench", "Combat Wrench"),
+    NameEntry::new(4819, "DemonConch", "Demon Conch"),
+    NameEntry::new(4820, "BottomlessLavaBucket", "Bottomless Lava Bucket"),
+    NameEntry::new(4821, "FireproofBugNet", "Lavaproof Bug Net"),
+    NameEntry::new(4822, "FlameWakerBoots", "Flame Waker Boots"),
+    NameEntry::new(4823, "RainbowWings", "Empress Wings"),
+    NameEntry::new(4824, "WetBomb", "Wet Bomb"),
+    NameEntry::new(4825, "LavaBomb", "Lava Bomb"),
+    NameEntry::new(4826, "HoneyBomb", "Honey Bomb"),
+    NameEntry::new(4827, "DryBomb", "Dry Bomb"),
+    NameEntry::new(4828, "SuperheatedBlood", "Superheated Blood"),
+    NameEntry::new(4829, "LicenseCat", "Cat License"),
+    NameEntry::new(4830, "LicenseDog", "Dog License"),
+    NameEntry::new(4831, "GemSquirrelAmethyst", "Amethyst Squirrel"),
+    NameEntry::new(4832, "GemSquirrelTopaz", "Topaz Squirrel"),
+    NameEntry::new(4833, "GemSquirrelSapphire", "Sapphire Squirrel"),
+    NameEntry::new(4834, "GemSquirrelEmerald", "Emerald Squirrel"),
+    NameEntry::new(4835, "GemSquirrelRuby", "Ruby Squirrel"),
+    NameEntry::new(4836, "GemSquirrelDiamond", "Diamond Squirrel"),
+    NameEntry::new(4837, "GemSquirrelAmber", "Amber Squirrel"),
+    NameEntry::new(4838, "GemBunnyAmethyst", "Amethyst Bunny"),
+    NameEntry::new(4839, "GemBunnyTopaz", "Topaz Bunny"),
+    NameEntry::new(4840, "GemBunnySapphire", "Sapphire Bunny"),
+    NameEntry::new(4841, "GemBunnyEmerald", "Emerald Bunny"),
+    NameEntry::new(4842, "GemBunnyRuby", "Ruby Bunny"),
+    NameEntry::new(4843, "GemBunnyDiamond", "Diamond Bunny"),
+    NameEntry::new(4844, "GemBunnyAmber", "Amber Bunny"),
+    NameEntry::new(4845, "HellButterfly", "Hell Butterfly"),
+    NameEntry::new(4846, "HellButterflyJar", "Hell Butterfly Jar"),
+    NameEntry::new(4847, "Lavafly", "Lavafly"),
+    NameEntry::new(4848, "LavaflyinaBottle", "Lavafly in a Bottle"),
+    NameEntry::new(4849, "MagmaSnail", "Magma Snail"),
+    NameEntry::new(4850, "MagmaSnailCage", "Magma Snail Cage"),
+    NameEntry::new(4851, "GemTreeTopazSeed", "Topaz Gemcorn"),
+    NameEntry::new(4852, "GemTreeAmethystSeed", "Amethyst Gemcorn"),
+    NameEntry::new(4853, "GemTreeSapphireSeed", "Sapphire Gemcorn"),
+    NameEntry::new(4854, "GemTreeEmeraldSeed", "Emerald Gemcorn"),
+    NameEntry::new(4855, "GemTreeRubySeed", "Ruby Gemcorn"),
+    NameEntry::new(4856, "GemTreeDiamondSeed", "Diamond Gemcorn"),
+    NameEntry::new(4857, "GemTreeAmberSeed", "Amber Gemcorn"),
+    NameEntry::new(4858, "PotSuspended", "Hanging Pot"),
+    NameEntry::new(4859, "PotSuspendedDaybloom", "Hanging Daybloom"),
+    NameEntry::new(4860, "PotSuspendedMoonglow", "Hanging Moonglow"),
+    NameEntry::new(4861, "PotSuspendedWaterleaf", "Hanging Waterleaf"),
+    NameEntry::new(4862, "PotSuspendedShiverthorn", "Hanging Shiverthorn"),
+    NameEntry::new(4863, "PotSuspendedBlinkroot", "Hanging Blinkroot"),
+    NameEntry::new(4864, "PotSuspendedDeathweedCorrupt", "Hanging Corrupt Deathweed"),
+    NameEntry::new(4865, "PotSuspendedDeathweedCrimson", "Hanging Crimson Deathweed"),
+    NameEntry::new(4866, "PotSuspendedFireblossom", "Hanging Fireblossom"),
+    NameEntry::new(4867, "BrazierSuspended", "Hanging Brazier"),
+    NameEntry::new(4868, "VolcanoSmall", "Mini Volcano"),
+    NameEntry::new(4869, "VolcanoLarge", "Large Volcano"),
+    NameEntry::new(4870, "PotionOfReturn", "Potion of Return"),
+    NameEntry::new(4871, "VanityTreeSakuraSeed", "Sakura Sapling"),
+    NameEntry::new(4872, "LavaAbsorbantSponge", "Lava Absorbant Sponge"),
+    NameEntry::new(4873, "HallowedHood", "Hallowed Hood"),
+    NameEntry::new(4874, "HellfireTreads", "Hellfire Treads"),
+    NameEntry::new(4875, "TeleportationPylonJungle", "Jungle Pylon"),
+    NameEntry::new(4876, "TeleportationPylonPurity", "Forest Pylon"),
+    NameEntry::new(4877, "LavaCrate", "Obsidian Crate"),
+    NameEntry::new(4878, "LavaCrateHard", "Hellstone Crate"),
+    NameEntry::new(4879, "ObsidianLockbox", "Obsidian Lock Box"),
+    NameEntry::new(4880, "LavaFishbowl", "Lava Serpent Bowl"),
+    NameEntry::new(4881, "LavaFishingHook", "Lavaproof Fishing Hook"),
+    NameEntry::new(4882, "AmethystBunnyCage", "Amethyst Bunny Cage"),
+    NameEntry::new(4883, "TopazBunnyCage", "Topaz Bunny Cage"),
+    NameEntry::new(4884, "SapphireBunnyCage", "Sapphire Bunny Cage"),
+    NameEntry::new(4885, "EmeraldBunnyCage", "Emerald Bunny Cage"),
+    NameEntry::new(4886, "RubyBunnyCage", "Ruby Bunny Cage"),
+    NameEntry::new(4887, "DiamondBunnyCage", "Diamond Bunny Cage"),
+    NameEntry::new(4888, "AmberBunnyCage", "Amber Bunny Cage"),
+    NameEntry::new(4889, "AmethystSquirrelCage", "Amethyst Squirrel Cage"),
+    NameEntry::new(4890, "TopazSquirrelCage", "Topaz Squirrel Cage"),
+    NameEntry::new(4891, "SapphireSquirrelCage", "Sapphire Squirrel Cage"),
+    NameEntry::new(4892, "EmeraldSquirrelCage", "Emerald Squirrel Cage"),
+    NameEntry::new(4893, "RubySquirrelCage", "Ruby Squirrel Cage"),
+    NameEntry::new(4894, "DiamondSquirrelCage", "Diamond Squirrel Cage"),
+    NameEntry::new(4895, "AmberSquirrelCage", "Amber Squirrel Cage"),
+    NameEntry::new(4896, "AncientHallowedMask", "Ancient Hallowed Mask"),
+    NameEntry::new(4897, "AncientHallowedHelmet", "Ancient Hallowed Helmet"),
+    NameEntry::new(4898, "AncientHallowedHeadgear", "Ancient Hallowed Headgear"),
+    NameEntry::new(4899, "AncientHallowedHood", "Ancient Hallowed Hood"),
+    NameEntry::new(4900, "AncientHallowedPlateMail", "Ancient Hallowed Plate Mail"),
+    NameEntry::new(4901, "AncientHallowedGreaves", "Ancient Hallowed Greaves"),
+    NameEntry::new(4902, "PottedLavaPlantPalm", "Potted Magma Palm"),
+    NameEntry::new(4903, "PottedLavaPlantBush", "Potted Brimstone Bush"),
+    NameEntry::new(4904, "PottedLavaPlantBramble", "Potted Fire Brambles"),
+    NameEntry::new(4905, "PottedLavaPlantBulb", "Potted Lava Bulb"),
+    NameEntry::new(4906, "PottedLavaPlantTendrils", "Potted Ember Tendrils"),
+    NameEntry::new(4907, "VanityTreeYellowWillowSeed", "Yellow Willow Sapling"),
+    NameEntry::new(4908, "DirtBomb", "Dirt Bomb"),
+    NameEntry::new(4909, "DirtStickyBomb", "Sticky Dirt Bomb"),
+    NameEntry::new(4910, "LicenseBunny", "Bunny License"),
+    NameEntry::new(4911, "CoolWhip", "Cool Whip"),
+    NameEntry::new(4912, "FireWhip", "Firecracker"),
+    NameEntry::new(4913, "ThornWhip", "Snapthorn"),
+    NameEntry::new(4914, "RainbowWhip", "Kaleidoscope"),
+    NameEntry::new(4915, "TungstenBullet", "Tungsten Bullet"),
+    NameEntry::new(4916, "TeleportationPylonHallow", "Hallow Pylon"),
+    NameEntry::new(4917, "TeleportationPylonUnderground", "Cavern Pylon"),
+    NameEntry::new(4918, "TeleportationPylonOcean", "Ocean Pylon"),
+    NameEntry::new(4919, "TeleportationPylonDesert", "Desert Pylon"),
+    NameEntry::new(4920, "TeleportationPylonSnow", "Snow Pylon"),
+    NameEntry::new(4921, "TeleportationPylonMushroom", "Mushroom Pylon"),
+    NameEntry::new(4922, "CavernFountain", "Cavern Water Fountain"),
+    NameEntry::new(4923, "PiercingStarlight", "Starlight"),
+    NameEntry::new(4924, "EyeofCthulhuMasterTrophy", "Eye of Cthulhu Relic"),
+    NameEntry::new(4925, "EaterofWorldsMasterTrophy", "Eater of Worlds Relic"),
+    NameEntry::new(4926, "BrainofCthulhuMasterTrophy", "Brain of Cthulhu Relic"),
+    NameEntry::new(4927, "SkeletronMasterTrophy", "Skeletron Relic"),
+    NameEntry::new(4928, "QueenBeeMasterTrophy", "Queen Bee Relic"),
+    NameEntry::new(4929, "KingSlimeMasterTrophy", "King Slime Relic"),
+    NameEntry::new(4930, "WallofFleshMasterTrophy", "Wall of Flesh Relic"),
+    NameEntry::new(4931, "TwinsMasterTrophy", "Twins Relic"),
+    NameEntry::new(4932, "DestroyerMasterTrophy", "Destroyer Relic"),
+    NameEntry::new(4933, "SkeletronPrimeMasterTrophy", "Skeletron Prime Relic"),
+    NameEntry::new(4934, "PlanteraMasterTrophy", "Plantera Relic"),
+    NameEntry::new(4935, "GolemMasterTrophy", "Golem Relic"),
+    NameEntry::new(4936, "DukeFishronMasterTrophy", "Duke Fishron Relic"),
+    NameEntry::new(4937, "LunaticCultistMasterTrophy", "Lunatic Cultist Relic"),
+    NameEntry::new(4938, "MoonLordMasterTrophy", "Moon Lord Relic"),
+    NameEntry::new(4939, "UFOMasterTrophy", "Martian Saucer Relic"),
+    NameEntry::new(4940, "FlyingDutchmanMasterTrophy", "Flying Dutchman Relic"),
+    NameEntry::new(4941, "MourningWoodMasterTrophy", "Mourning Wood Relic"),
+    NameEntry::new(4942, "PumpkingMasterTrophy", "Pumpking Relic"),
+    NameEntry::new(4943, "IceQueenMasterTrophy", "Ice Queen Relic"),
+    NameEntry::new(4944, "EverscreamMasterTrophy", "Everscream Relic"),
+    NameEntry::new(4945, "SantankMasterTrophy", "Santa-NK1 Relic"),
+    NameEntry::new(4946, "DarkMageMasterTrophy", "Dark Mage Relic"),
+    NameEntry::new(4947, "OgreMasterTrophy", "Ogre Relic"),
+    NameEntry::new(4948, "BetsyMasterTrophy", "Betsy Relic"),
+    NameEntry::new(4949, "FairyQueenMasterTrophy", "Empress of Light Relic"),
+    NameEntry::new(4950, "QueenSlimeMasterTrophy", "Queen Slime Relic"),
+    NameEntry::new(4951, "TeleportationPylonVictory", "Universal Pylon"),
+    NameEntry::new(4952, "FairyQueenMagicItem", "Nightglow"),
+    NameEntry::new(4953, "FairyQueenRangedItem", "Eventide"),
+    NameEntry::new(4954, "LongRainbowTrailWings", "Celestial Starboard"),
+    NameEntry::new(4955, "RabbitOrder", "Rabbit Perch"),
+    NameEntry::new(4956, "Zenith", "Zenith"),
+    NameEntry::new(4957, "QueenSlimeBossBag", "Treasure Bag (Queen Slime)"),
+    NameEntry::new(4958, "QueenSlimeTrophy", "Queen Slime Trophy"),
+    NameEntry::new(4959, "QueenSlimeMask", "Queen Slime Mask"),
+    NameEntry::new(4960, "QueenSlimePetItem", "Regal Delicacy"),
+    NameEntry::new(4961, "EmpressButterfly", "Prismatic Lacewing"),
+    NameEntry::new(4962, "AccentSlab", "Stone Accent Slab"),
+    NameEntry::new(4963, "TruffleWormCage", "Truffle Worm Cage"),
+    NameEntry::new(4964, "EmpressButterflyJar", "Prismatic Lacewing Jar"),
+    NameEntry::new(4965, "RockGolemBanner", "Rock Golem Banner"),
+    NameEntry::new(4966, "BloodMummyBanner", "Blood Mummy Banner"),
+    NameEntry::new(4967, "SporeSkeletonBanner", "Spore Skeleton Banner"),
+    NameEntry::new(4968, "SporeBatBanner", "Spore Bat Banner"),
+    NameEntry::new(4969, "LarvaeAntlionBanner", "Antlion Larva Banner"),
+    NameEntry::new(4970, "CrimsonBunnyBanner", "Vicious Bunny Banner"),
+    NameEntry::new(4971, "CrimsonGoldfishBanner", "Vicious Goldfish Banner"),
+    NameEntry::new(4972, "CrimsonPenguinBanner", "Vicious Penguin Banner"),
+    NameEntry::new(4973, "BigMimicCorruptionBanner", "Corrupt Mimic Banner"),
+    NameEntry::new(4974, "BigMimicCrimsonBanner", "Crimson Mimic Banner"),
+    NameEntry::new(4975, "BigMimicHallowBanner", "Hallowed Mimic Banner"),
+    NameEntry::new(4976, "MossHornetBanner", "Moss Hornet Banner"),
+    NameEntry::new(4977, "WanderingEyeBanner", "Wandering Eye Banner"),
+    NameEntry::new(4978, "CreativeWings", "Fledgling Wings"),
+    NameEntry::new(4979, "MusicBoxQueenSlime", "Music Box (Queen Slime)"),
+    NameEntry::new(4980, "QueenSlimeHook", "Hook of Dissonance"),
+    NameEntry::new(4981, "QueenSlimeMountSaddle", "Gelatinous Pillion"),
+    NameEntry::new(4982, "CrystalNinjaHelmet", "Crystal Assassin Hood"),
+    NameEntry::new(4983, "CrystalNinjaChestplate", "Crystal Assassin Shirt"),
+    NameEntry::new(4984, "CrystalNinjaLeggings", "Crystal Assassin Pants"),
+    NameEntry::new(4985, "MusicBoxEmpressOfLight", "Music Box (Empress Of Light)"),
+    NameEntry::new(4986, "GelBalloon", "Sparkle Slime Balloon"),
+    NameEntry::new(4987, "VolatileGelatin", "Volatile Gelatin"),
+    NameEntry::new(4988, "QueenSlimeCrystal", "Gelatin Crystal"),
+    NameEntry::new(4989, "EmpressFlightBooster", "Soaring Insignia"),
+    NameEntry::new(4990, "MusicBoxDukeFishron", "Music Box (Duke Fishron)"),
+    NameEntry::new(4991, "MusicBoxMorningRain", "Music Box (Morning Rain)"),
+    NameEntry::new(4992, "MusicBoxConsoleTitle", "Music Box (Alt Title)"),
+    NameEntry::new(4993, "ChippysCouch", "Chippy's Couch"),
+    NameEntry::new(4994, "GraduationCapBlue", "Blue Graduation Cap"),
+    NameEntry::new(4995, "GraduationCapMaroon", "Maroon Graduation Cap"),
+    NameEntry::new(4996, "GraduationCapBlack", "Black Graduation Cap"),
+    NameEntry::new(4997, "GraduationGownBlue", "Blue Graduation Gown"),
+    NameEntry::new(4998, "GraduationGownMaroon", "Maroon Graduation Gown"),
+    NameEntry::new(4999, "GraduationGownBlack", "Black Graduation Gown"),
+    NameEntry::new(5000, "TerrasparkBoots", "Terraspark Boots"),
+    NameEntry::new(5001, "MoonLordLegs", "Moon Lord Legs"),
+    NameEntry::new(5002, "OceanCrate", "Ocean Crate"),
+    NameEntry::new(5003, "OceanCrateHard", "Seaside Crate"),
+    NameEntry::new(5004, "BadgersHat", "Badger's Hat"),
+    NameEntry::new(5005, "EmpressBlade", "Terraprisma"),
+    NameEntry::new(5006, "MusicBoxUndergroundDesert", "Music Box (Underground Desert)"),
+    NameEntry::new(5007, "DeadMansSweater", "Dead Man's Sweater"),
+    NameEntry::new(5008, "TeaKettle", "Teapot"),
+    NameEntry::new(5009, "Teacup", "Teacup"),
+    NameEntry::new(5010, "TreasureMagnet", "Treasure Magnet"),
+    NameEntry::new(5011, "Mace", "Mace"),
+    NameEntry::new(5012, "FlamingMace", "Flaming Mace"),
+    NameEntry::new(5013, "SleepingIcon", "SleepingIcon"),
+    NameEntry::new(5014, "MusicBoxOWRain", "Otherworldly Music Box (Rain)"),
+    NameEntry::new(5015, "MusicBoxOWDay", "Otherworldly Music Box (Overworld Day)"),
+    NameEntry::new(5016, "MusicBoxOWNight", "Otherworldly Music Box (Night)"),
+    NameEntry::new(5017, "MusicBoxOWUnderground", "Otherworldly Music Box (Underground)"),
+    NameEntry::new(5018, "MusicBoxOWDesert", "Otherworldly Music Box (Desert)"),
+    NameEntry::new(5019, "MusicBoxOWOcean", "Otherworldly Music Box (Ocean)"),
+    NameEntry::new(5020, "MusicBoxOWMushroom", "Otherworldly Music Box (Mushrooms)"),
+    NameEntry::new(5021, "MusicBoxOWDungeon", "Otherworldly Music Box (Dungeon)"),
+    NameEntry::new(5022, "MusicBoxOWSpace", "Otherworldly Music Box (Space)"),
+    NameEntry::new(5023, "MusicBoxOWUnderworld", "Otherworldly Music Box (Underworld)"),
+    NameEntry::new(5024, "MusicBoxOWSnow", "Otherworldly Music Box (Snow)"),
+    NameEntry::new(5025, "MusicBoxOWCorruption", "Otherworldly Music Box (Corruption)"),
+    NameEntry::new(5026, "MusicBoxOWUndergroundCorruption", "Otherworldly Music Box (Underground Corruption)"),
+    NameEntry::new(5027, "MusicBoxOWCrimson", "Otherworldly Music Box (Crimson)"),
+    NameEntry::new(5028, "MusicBoxOWUndergroundCrimson", "Otherworldly Music Box (Underground Crimson)"),
+    NameEntry::new(5029, "MusicBoxOWUndergroundSnow", "Otherworldly Music Box (Ice)"),
+    NameEntry::new(5030, "MusicBoxOWUndergroundHallow", "Otherworldly Music Box (Underground Hallow)"),
+    NameEntry::new(5031, "MusicBoxOWBloodMoon", "Otherworldly Music Box (Eerie)"),
+    NameEntry::new(5032, "MusicBoxOWBoss2", "Otherworldly Music Box (Boss 2)"),
+    NameEntry::new(5033, "MusicBoxOWBoss1", "Otherworldly Music Box (Boss 1)"),
+    NameEntry::new(5034, "MusicBoxOWInvasion", "Otherworldly Music Box (Invasion)"),
+    NameEntry::new(5035, "MusicBoxOWTowers", "Otherworldly Music Box (The Towers)"),
+    NameEntry::new(5036, "MusicBoxOWMoonLord", "Otherworldly Music Box (Lunar Boss)"),
+    NameEntry::new(5037, "MusicBoxOWPlantera", "Otherworldly Music Box (Plantera)"),
+    NameEntry::new(5038, "MusicBoxOWJungle", "Otherworldly Music Box (Jungle)"),
+    NameEntry::new(5039, "MusicBoxOWWallOfFlesh", "Otherworldly Music Box (Wall of Flesh)"),
+    NameEntry::new(5040, "MusicBoxOWHallow", "Otherworldly Music Box (Hallow)"),
+    NameEntry::new(5041, "MilkCarton", "Carton of Milk"),
+    NameEntry::new(5042, "CoffeeCup", "Coffee"),
+    NameEntry::new(5043, "TorchGodsFavor", "Torch God's Favor"),
+    NameEntry::new(5044, "MusicBoxCredits", "Music Box (Journey's End)"),
+    NameEntry::new(5045, "PlaguebringerHelmet", "Plaguebringer's Skull"),
+    NameEntry::new(5046, "PlaguebringerChestplate", "Plaguebringer's Cloak"),
+    NameEntry::new(5047, "PlaguebringerGreaves", "Plaguebringer's Treads"),
+    NameEntry::new(5048, "RoninHat", "Wandering Jingasa"),
+    NameEntry::new(5049, "RoninShirt", "Wandering Yukata"),
+    NameEntry::new(5050, "RoninPants", "Wandering Geta"),
+    NameEntry::new(5051, "TimelessTravelerHood", "Timeless Traveler's Hood"),
+    NameEntry::new(5052, "TimelessTravelerRobe", "Timeless Traveler's Cloak"),
+    NameEntry::new(5053, "TimelessTravelerBottom", "Timeless Traveler's Footwear"),
+    NameEntry::new(5054, "FloretProtectorHelmet", "Floret Protector Helmet"),
+    NameEntry::new(5055, "FloretProtectorChestplate", "Floret Protector Shirt"),
+    NameEntry::new(5056, "FloretProtectorLegs", "Floret Protector Pants"),
+    NameEntry::new(5057, "CapricornMask", "Capricorn Helmet"),
+    NameEntry::new(5058, "CapricornChestplate", "Capricorn Chestplate"),
+    NameEntry::new(5059, "CapricornLegs", "Capricorn Hooves"),
+    NameEntry::new(5060, "CapricornTail", "Capricorn Tail"),
+    NameEntry::new(5061, "TVHeadMask", "Video Visage"),
+    NameEntry::new(5062, "TVHeadSuit", "Lazer Blazer"),
+    NameEntry::new(5063, "TVHeadPants", "Pinstripe Pants"),
+    NameEntry::new(5064, "LavaproofTackleBag", "Lavaproof Tackle Bag"),
+    NameEntry::new(5065, "PrincessWeapon", "Resonance Scepter"),
+    NameEntry::new(5066, "BeeHive", "Bee Hive"),
+    NameEntry::new(5067, "AntlionEggs", "Antlion Eggs"),
+    NameEntry::new(5068, "FlinxFurCoat", "Flinx Fur Coat"),
+    NameEntry::new(5069, "FlinxStaff", "Flinx Staff"),
+    NameEntry::new(5070, "FlinxFur", "Flinx Fur"),
+    NameEntry::new(5071, "RoyalTiara", "Royal Tiara"),
+    NameEntry::new(5072, "RoyalDressTop", "Royal Blouse"),
+    NameEntry::new(5073, "RoyalDressBottom", "Royal Dress"),
+    NameEntry::new(5074, "BoneWhip", "Spinal Tap"),
+    NameEntry::new(5075, "RainbowCursor", "Rainbow Cursor"),
+    NameEntry::new(5076, "RoyalScepter", "Royal Scepter"),
+    NameEntry::new(5077, "GlassSlipper", "Glass Slipper"),
+    NameEntry::new(5078, "PrinceUniform", "Prince Uniform"),
+    NameEntry::new(5079, "PrincePants", "Prince Pants"),
+    NameEntry::new(5080, "PrinceCape", "Prince Cape"),
+    NameEntry::new(5081, "PottedCrystalPlantFern", "Potted Crystal Fern"),
+    NameEntry::new(5082, "PottedCrystalPlantSpiral", "Potted Crystal Spiral"),
+    NameEntry::new(5083, "PottedCrystalPlantTeardrop", "Potted Crystal Teardrop"),
+    NameEntry::new(5084, "PottedCrystalPlantTree", "Potted Crystal Tree"),
+    NameEntry::new(5085, "Princess64", "Princess 64"),
+    NameEntry::new(5086, "PaintingOfALass", "Painting of a Lass"),
+    NameEntry::new(5087, "DarkSideHallow", "Dark Side of the Hallow"),
+    NameEntry::new(5088, "BerniePetItem", "Bernie's Button"),
+    NameEntry::new(5089, "GlommerPetItem", "Glommer's Flower"),
+    NameEntry::new(5090, "DeerclopsPetItem", "Deerclops Eyeball"),
+    NameEntry::new(5091, "PigPetItem", "Monster Meat"),
+    NameEntry::new(5092, "MonsterLasagna", "Monster Lasagna"),
+    NameEntry::new(5093, "FroggleBunwich", "Froggle Bunwich"),
+    NameEntry::new(5094, "TentacleSpike", "Tentacle Spike"),
+    NameEntry::new(5095, "LucyTheAxe", "Lucy the Axe"),
+    NameEntry::new(5096, "HamBat", "Ham Bat"),
+    NameEntry::new(5097, "BatBat", "Bat Bat"),
+    NameEntry::new(5098, "ChesterPetItem", "Eye Bone"),
+    NameEntry::new(5099, "GarlandHat", "Garland"),
+    NameEntry::new(5100, "BoneHelm", "Bone Helm"),
+    NameEntry::new(5101, "Eyebrella", "Eyebrella"),
+    NameEntry::new(5102, "WilsonShirt", "Gentleman's Vest"),
+    NameEntry::new(5103, "WilsonPants", "Gentleman's Trousers"),
+    NameEntry::new(5104, "WilsonBeardShort", "Gentleman's Beard"),
+    NameEntry::new(5105, "WilsonBeardLong", "Gentleman's Long Beard"),
+    NameEntry::new(5106, "WilsonBeardMagnificent", "Gentleman's Magnificent Beard"),
+    NameEntry::new(5107, "Magiluminescence", "Magiluminescence"),
+    NameEntry::new(5108, "DeerclopsTrophy", "Deerclops Trophy"),
+    NameEntry::new(5109, "DeerclopsMask", "Deerclops Mask"),
+    NameEntry::new(5110, "DeerclopsMasterTrophy", "Deerclops Relic"),
+    NameEntry::new(5111, "DeerclopsBossBag", "Treasure Bag (Deerclops)"),
+    NameEntry::new(5112, "MusicBoxDeerclops", "Music Box (Deerclops)"),
+    NameEntry::new(5113, "DontStarveShaderItem", "Radio Thing"),
+    NameEntry::new(5114, "AbigailsFlower", "Abigail's Flower"),
+    NameEntry::new(5115, "WillowShirt", "Firestarter's Sweater"),
+    NameEntry::new(5116, "WillowSkirt", "Firestarter's Skirt"),
+    NameEntry::new(5117, "PewMaticHorn", "Pew-matic Horn"),
+    NameEntry::new(5118, "WeatherPain", "Weather Pain"),
+    NameEntry::new(5119, "HoundiusShootius", "Houndius Shootius"),
+    NameEntry::new(5120, "DeerThing", "Deer Thing"),
+    NameEntry::new(5121, "PaintingWilson", "The Gentleman Scientist"),
+    NameEntry::new(5122, "PaintingWillow", "The Firestarter"),
+    NameEntry::new(5123, "PaintingWendy", "The Bereaved"),
+    NameEntry::new(5124, "PaintingWolfgang", "The Strongman"),
+    NameEntry::new(5125, "FartMinecart", "Fart Kart"),
+    NameEntry::new(5126, "HandOfCreation", "Hand Of Creation"),
+    NameEntry::new(5127, "VioletMoss", "Neon Moss"),
+    NameEntry::new(5128, "RainbowMoss", "Helium Moss"),
+    NameEntry::new(5129, "Flymeal", "Flymeal"),
+    NameEntry::new(5130, "WolfMountItem", "Lilith's Necklace"),
+    NameEntry::new(5131, "ResplendentDessert", "Resplendent Dessert"),
+    NameEntry::new(5132, "Stinkbug", "Stinkbug"),
+    NameEntry::new(5133, "StinkbugCage", "Stinkbug Cage"),
+    NameEntry::new(5134, "Clentaminator2", "Terraformer"),
+    NameEntry::new(5135, "VenomDartTrap", "Venom Dart Trap"),
+    NameEntry::new(5136, "VulkelfEar", "Vulkelf Ears"),
+    NameEntry::new(5137, "StinkbugHousingBlocker", "Stinkbug Blocker"),
+    NameEntry::new(5138, "StinkbugHousingBlockerEcho", "Ghostly Stinkbug Blocker"),
+    NameEntry::new(5139, "FishingBobber", "Fishing Bobber"),
+    NameEntry::new(5140, "FishingBobberGlowingStar", "Glowing Fishing Bobber"),
+    NameEntry::new(5141, "FishingBobberGlowingLava", "Lava Moss Fishing Bobber"),
+    NameEntry::new(5142, "FishingBobberGlowingKrypton", "Krypton Moss Fishing Bobber"),
+    NameEntry::new(5143, "FishingBobberGlowingXenon", "Xenon Moss Fishing Bobber"),
+    NameEntry::new(5144, "FishingBobberGlowingArgon", "Argon Moss Fishing Bobber"),
+    NameEntry::new(5145, "FishingBobberGlowingViolet", "Neon Moss Fishing Bobber"),
+    NameEntry::new(5146, "FishingBobberGlowingRainbow", "Helium Moss Fishing Bobber"),
+    NameEntry::new(5147, "WandofFrosting", "Wand of Frosting"),
+    NameEntry::new(5148, "CoralBathtub", "Reef Bathtub"),
+    NameEntry::new(5149, "CoralBed", "Reef Bed"),
+    NameEntry::new(5150, "CoralBookcase", "Reef Bookcase"),
+    NameEntry::new(5151, "CoralDresser", "Reef Dresser"),
+    NameEntry::new(5152, "CoralCandelabra", "Reef Candelabra"),
+    NameEntry::new(5153, "CoralCandle", "Reef Candle"),
+    NameEntry::new(5154, "CoralChair", "Reef Chair"),
+    NameEntry::new(5155, "CoralChandelier", "Reef Chandelier"),
+    NameEntry::new(5156, "CoralChest", "Reef Chest"),
+    NameEntry::new(5157, "CoralClock", "Reef Clock"),
+    NameEntry::new(5158, "CoralDoor", "Reef Door"),
+    NameEntry::new(5159, "CoralLamp", "Reef Lamp"),
+    NameEntry::new(5160, "CoralLantern", "Reef Lantern"),
+    NameEntry::new(5161, "CoralPiano", "Reef Piano"),
+    NameEntry::new(5162, "CoralPlatform", "Reef Platform"),
+    NameEntry::new(5163, "CoralSink", "Reef Sink"),
+    NameEntry::new(5164, "CoralSofa", "Reef Sofa"),
+    NameEntry::new(5165, "CoralTable", "Reef Table"),
+    NameEntry::new(5166, "CoralWorkbench", "Reef Work Bench"),
+    NameEntry::new(5167, "Fake_CoralChest", "Trapped Reef Chest"),
+    NameEntry::new(5168, "CoralToilet", "Reef Toilet"),
+    NameEntry::new(5169, "BalloonBathtub", "Balloon Bathtub"),
+    NameEntry::new(5170, "BalloonBed", "Balloon Bed"),
+    NameEntry::new(5171, "BalloonBookcase", "Balloon Bookcase"),
+    NameEntry::new(5172, "BalloonDresser", "Balloon Dresser"),
+    NameEntry::new(5173, "BalloonCandelabra", "Balloon Candelabra"),
+    NameEntry::new(5174, "BalloonCandle", "Balloon Candle"),
+    NameEntry::new(5175, "BalloonChair", "Balloon Chair"),
+    NameEntry::new(5176, "BalloonChandelier", "Balloon Chandelier"),
+    NameEntry::new(5177, "BalloonChest", "Balloon Chest"),
+    NameEntry::new(5178, "BalloonClock", "Balloon Clock"),
+    NameEntry::new(5179, "BalloonDoor", "Balloon Door"),
+    NameEntry::new(5180, "BalloonLamp", "Balloon Lamp"),
+    NameEntry::new(5181, "BalloonLantern", "Balloon Lantern"),
+    NameEntry::new(5182, "BalloonPiano", "Balloon Piano"),
+    NameEntry::new(5183, "BalloonPlatform", "Balloon Platform"),
+    NameEntry::new(5184, "BalloonSink", "Balloon Sink"),
+    NameEntry::new(5185, "BalloonSofa", "Balloon Sofa"),
+    NameEntry::new(5186, "BalloonTable", "Balloon Table"),
+    NameEntry::new(5187, "BalloonWorkbench", "Balloon Work Bench"),
+    NameEntry::new(5188, "Fake_BalloonChest", "Trapped Balloon Chest"),
+    NameEntry::new(5189, "BalloonToilet", "Balloon Toilet"),
+    NameEntry::new(5190, "AshWoodBathtub", "Ash Wood Bathtub"),
+    NameEntry::new(5191, "AshWoodBed", "Ash Wood Bed"),
+    NameEntry::new(5192, "AshWoodBookcase", "Ash Wood Bookcase"),
+    NameEntry::new(5193, "AshWoodDresser", "Ash Wood Dresser"),
+    NameEntry::new(5194, "AshWoodCandelabra", "Ash Wood Candelabra"),
+    NameEntry::new(5195, "AshWoodCandle", "Ash Wood Candle"),
+    NameEntry::new(5196, "AshWoodChair", "Ash Wood Chair"),
+    NameEntry::new(5197, "AshWoodChandelier", "Ash Wood Chandelier"),
+    NameEntry::new(5198, "AshWoodChest", "Ash Wood Chest"),
+    NameEntry::new(5199, "AshWoodClock", "Ash Wood Clock"),
+    NameEntry::new(5200, "AshWoodDoor", "Ash Wood Door"),
+    NameEntry::new(5201, "AshWoodLamp", "Ash Wood Lamp"),
+    NameEntry::new(5202, "AshWoodLantern", "Ash Wood Lantern"),
+    NameEntry::new(5203, "AshWoodPiano", "Ash Wood Piano"),
+    NameEntry::new(5204, "AshWoodPlatform", "Ash Wood Platform"),
+    NameEntry::new(5205, "AshWoodSink", "Ash Wood Sink"),
+    NameEntry::new(5206, "AshWoodSofa", "Ash Wood Sofa"),
+    NameEntry::new(5207, "AshWoodTable", "Ash Wood Table"),
+    NameEntry::new(5208, "AshWoodWorkbench", "Ash Wood Work Bench"),
+    NameEntry::new(5209, "Fake_AshWoodChest", "Trapped Ash Wood Chest"),
+    NameEntry::new(5210, "AshWoodToilet", "Ash Wood Toilet"),
+    NameEntry::new(5211, "BiomeSightPotion", "Biome Sight Potion"),
+    NameEntry::new(5212, "ScarletMacaw", "Scarlet Macaw"),
+    NameEntry::new(5213, "ScarletMacawCage", "Scarlet Macaw Cage"),
+    NameEntry::new(5214, "AshGrassSeeds", "Ash Grass Seeds"),
+    NameEntry::new(5215, "AshWood", "Ash Wood"),
+    NameEntry::new(5216, "AshWoodWall", "Ash Wood Wall"),
+    NameEntry::new(5217, "AshWoodFence", "Ash Wood Fence"),
+    NameEntry::new(5218, "Outcast", "Outcast"),
+    NameEntry::new(5219, "FairyGuides", "Fairy Guides"),
+    NameEntry::new(5220, "AHorribleNightforAlchemy", "A Horrible Night for Alchemy"),
+    NameEntry::new(5221, "MorningHunt", "Morning Hunt"),
+    NameEntry::new(5222, "SuspiciouslySparkly", "Suspiciously Sparkly"),
+    NameEntry::new(5223, "Requiem", "Requiem"),
+    NameEntry::new(5224, "CatSword", "Cat Sword"),
+    NameEntry::new(5225, "KargohsSummon", "Kargoh's Summon"),
+    NameEntry::new(5226, "HighPitch", "High Pitch"),
+    NameEntry::new(5227, "AMachineforTerrarians", "A Machine for Terrarians"),
+    NameEntry::new(5228, "TerraBladeChronicles", "Terra Blade Chronicles"),
+    NameEntry::new(5229, "BennyWarhol", "Benny Warhol"),
+    NameEntry::new(5230, "LizardKing", "Lizard King"),
+    NameEntry::new(5231, "MySon", "My Son"),
+    NameEntry::new(5232, "Duality", "Duality"),
+    NameEntry::new(5233, "ParsecPals", "Parsec Pals"),
+    NameEntry::new(5234, "RemnantsofDevotion", "Remnants of Devotion"),
+    NameEntry::new(5235, "NotSoLostInParadise", "Not So Lost In Paradise"),
+    NameEntry::new(5236, "OcularResonance", "Ocular Resonance"),
+    NameEntry::new(5237, "WingsofEvil", "Wings of Evil"),
+    NameEntry::new(5238, "Constellation", "Constellation"),
+    NameEntry::new(5239, "Eyezorhead", "Eyezorhead"),
+    NameEntry::new(5240, "DreadoftheRedSea", "Dread of the Red Sea"),
+    NameEntry::new(5241, "DoNotEattheVileMushroom", "Do Not Eat the Vile Mushroom!"),
+    NameEntry::new(5242, "YuumaTheBlueTiger", "Yuuma, The Blue Tiger"),
+    NameEntry::new(5243, "MoonmanandCompany", "Moonman & Company"),
+    NameEntry::new(5244, "SunshineofIsrapony", "Sunshine of Israpony"),
+    NameEntry::new(5245, "Purity", "Purity"),
+    NameEntry::new(5246, "SufficientlyAdvanced", "Sufficiently Advanced"),
+    NameEntry::new(5247, "StrangeGrowth", "Strange Growth"),
+    NameEntry::new(5248, "HappyLittleTree", "Happy Little Tree"),
+    NameEntry::new(5249, "StrangeDeadFellows", "Strange Dead Fellows"),
+    NameEntry::new(5250, "Secrets", "Secrets"),
+    NameEntry::new(5251, "Thunderbolt", "Thunderbolt"),
+    NameEntry::new(5252, "Crustography", "Crustography"),
+    NameEntry::new(5253, "TheWerewolf", "The Werewolf"),
+    NameEntry::new(5254, "BlessingfromTheHeavens", "Blessing from the Heavens"),
+    NameEntry::new(5255, "LoveisintheTrashSlot", "Love is in the Trash Slot"),
+    NameEntry::new(5256, "Fangs", "Fangs"),
+    NameEntry::new(5257, "HailtotheKing", "Hail to the King"),
+    NameEntry::new(5258, "SeeTheWorldForWhatItIs", "See The World For What It Is"),
+    NameEntry::new(5259, "WhatLurksBelow", "What Lurks Below"),
+    NameEntry::new(5260, "ThisIsGettingOutOfHand", "This Is Getting Out Of Hand"),
+    NameEntry::new(5261, "Buddies", "Buddies"),
+    NameEntry::new(5262, "MidnightSun", "Midnight Sun"),
+    NameEntry::new(5263, "CouchGag", "Couch Gag"),
+    NameEntry::new(5264, "SilentFish", "Silent Fish"),
+    NameEntry::new(5265, "TheDuke", "The Duke"),
+    NameEntry::new(5266, "RoyalRomance", "Royal Romance"),
+    NameEntry::new(5267, "Bioluminescence", "Bioluminescence"),
+    NameEntry::new(5268, "Wildflowers", "Wildflowers"),
+    NameEntry::new(5269, "VikingVoyage", "Viking Voyage"),
+    NameEntry::new(5270, "Bifrost", "Bifrost"),
+    NameEntry::new(5271, "Heartlands", "Heartlands"),
+    NameEntry::new(5272, "ForestTroll", "Forest Troll"),
+    NameEntry::new(5273, "AuroraBorealis", "Aurora Borealis"),
+    NameEntry::new(5274, "LadyOfTheLake", "Lady Of The Lake"),
+    NameEntry::new(5275, "JojaCola", "Joja Cola"),
+    NameEntry::new(5276, "JunimoPetItem", "Stardrop"),
+    NameEntry::new(5277, "SpicyPepper", "Spicy Pepper"),
+    NameEntry::new(5278, "Pomegranate", "Pomegranate"),
+    NameEntry::new(5279, "AshWoodHelmet", "Ash Wood Helmet"),
+    NameEntry::new(5280, "AshWoodBreastplate", "Ash Wood Breastplate"),
+    NameEntry::new(5281, "AshWoodGreaves", "Ash Wood Greaves"),
+    NameEntry::new(5282, "AshWoodBow", "Ash Wood Bow"),
+    NameEntry::new(5283, "AshWoodHammer", "Ash Wood Hammer"),
+    NameEntry::new(5284, "AshWoodSword", "Ash Wood Sword"),
+    NameEntry::new(5285, "MoonGlobe", "Moon Globe"),
+    NameEntry::new(5286, "RepairedLifeCrystal", "Repaired Life Crystal"),
+    NameEntry::new(5287, "RepairedManaCrystal", "Repaired Mana Crystal"),
+    NameEntry::new(5288, "TerraFartMinecart", "Terra Fart Kart"),
+    NameEntry::new(5289, "MinecartPowerup", "Minecart Upgrade Kit"),
+    NameEntry::new(5290, "JimsCap", "Jim's Cap"),
+    NameEntry::new(5291, "EchoWall", "Echo Wall"),
+    NameEntry::new(5292, "EchoPlatform", "Echo Platform"),
+    NameEntry::new(5293, "MushroomTorch", "Mushroom Torch"),
+    NameEntry::new(5294, "HiveFive", "Hive-Five"),
+    NameEntry::new(5295, "AcornAxe", "Axe of Regrowth"),
+    NameEntry::new(5296, "ChlorophyteExtractinator", "Chlorophyte Extractinator"),
+    NameEntry::new(5297, "BlueEgg", "Blue Chicken Egg"),
+    NameEntry::new(5298, "Trimarang", "Trimarang"),
+    NameEntry::new(5299, "MushroomCampfire", "Mushroom Campfire"),
+    NameEntry::new(5300, "BlueMacaw", "Blue Macaw"),
+    NameEntry::new(5301, "BlueMacawCage", "Blue Macaw Cage"),
+    NameEntry::new(5302, "BottomlessHoneyBucket", "Bottomless Honey Bucket"),
+    NameEntry::new(5303, "HoneyAbsorbantSponge", "Honey Absorbant Sponge"),
+    NameEntry::new(5304, "UltraAbsorbantSponge", "Ultra Absorbant Sponge"),
+    NameEntry::new(5305, "GoblorcEar", "Goblorc Ears"),
+    NameEntry::new(5306, "ReefBlock", "Reef Block"),
+    NameEntry::new(5307, "ReefWall", "Reef Wall"),
+    NameEntry::new(5308, "PlacePainting", "r/Terraria"),
+    NameEntry::new(5309, "DontHurtNatureBook", "Guide to Environmental Preservation"),
+    NameEntry::new(5310, "PrincessStyle", "Princess Style"),
+    NameEntry::new(5311, "Toucan", "Toucan"),
+    NameEntry::new(5312, "YellowCockatiel", "Yellow Cockatiel"),
+    NameEntry::new(5313, "GrayCockatiel", "Gray Cockatiel"),
+    NameEntry::new(5314, "ToucanCage", "Toucan Cage"),
+    NameEntry::new(5315, "YellowCockatielCage", "Yellow Cockatiel Cage"),
+    NameEntry::new(5316, "GrayCockatielCage", "Gray Cockatiel Cage"),
+    NameEntry::new(5317, "MacawStatue", "Macaw Statue"),
+    NameEntry::new(5318, "ToucanStatue", "Toucan Statue"),
+    NameEntry::new(5319, "CockatielStatue", "Cockatiel Statue"),
+    NameEntry::new(5320, "PlaceableHealingPotion", "Decorative Healing Potion"),
+    NameEntry::new(5321, "PlaceableManaPotion", "Decorative Mana Potion"),
+    NameEntry::new(5322, "ShadowCandle", "Shadow Candle"),
+    NameEntry::new(5323, "DontHurtComboBook", "Guide to Peaceful Coexistence"),
+    NameEntry::new(5324, "RubblemakerSmall", "Rubblemaker (Small)"),
+    NameEntry::new(5325, "ClosedVoidBag", "Closed Void Bag"),
+    NameEntry::new(5326, "ArtisanLoaf", "Artisan Loaf"),
+    NameEntry::new(5327, "TNTBarrel", "TNT Barrel"),
+    NameEntry::new(5328, "ChestLock", "Chest Lock"),
+    NameEntry::new(5329, "RubblemakerMedium", "Rubblemaker (Medium)"),
+    NameEntry::new(5330, "RubblemakerLarge", "Rubblemaker (Large)"),
+    NameEntry::new(5331, "HorseshoeBundle", "Bundle of Horseshoe Balloons"),
+    NameEntry::new(5332, "SpiffoPlush", "Spiffo Plush"),
+    NameEntry::new(5333, "GlowTulip", "Glow Tulip"),
+    NameEntry::new(5334, "MechdusaSummon", "Ocram's Razor"),
+    NameEntry::new(5335, "RodOfHarmony", "Rod of Harmony"),
+    NameEntry::new(5336, "CombatBookVolumeTwo", "Advanced Combat Techniques: Volume Two"),
+    NameEntry::new(5337, "AegisCrystal", "Vital Crystal"),
+    NameEntry::new(5338, "AegisFruit", "Aegis Fruit"),
+    NameEntry::new(5339, "ArcaneCrystal", "Arcane Crystal"),
+    NameEntry::new(5340, "GalaxyPearl", "Galaxy Pearl"),
+    NameEntry::new(5341, "GummyWorm", "Gummy Worm"),
+    NameEntry::new(5342, "Ambrosia", "Ambrosia"),
+    NameEntry::new(5343, "PeddlersSatchel", "Peddler's Satchel"),
+    NameEntry::new(5344, "EchoCoating", "Echo Coating"),
+    NameEntry::new(5345, "EchoMonolith", "Echo Chamber"),
+    NameEntry::new(5346, "GasTrap", "Gas Trap"),
+    NameEntry::new(5347, "ShimmerMonolith", "Aether Monolith"),
+    NameEntry::new(5348, "ShimmerArrow", "Shimmer Arrow"),
+    NameEntry::new(5349, "ShimmerBlock", "Aetherium Block"),
+    NameEntry::new(5350, "Shimmerfly", "Faeling"),
+    NameEntry::new(5351, "ShimmerflyinaBottle", "Faeling in a Bottle"),
+    NameEntry::new(5352, "ShimmerSlimeBanner", "Shimmer Slime Banner"),
+    NameEntry::new(5353, "ShimmerTorch", "Aether Torch"),
+    NameEntry::new(5354, "ReflectiveShades", "Reflective Shades"),
+    NameEntry::new(5355, "ShimmerCloak", "Chromatic Cloak"),
+    NameEntry::new(5356, "UsedGasTrap", "Used Gas Trap"),
+    NameEntry::new(5357, "ShimmerCampfire", "Aether Campfire"),
+    NameEntry::new(5358, "Shellphone", "Shellphone (Home)"),
+    NameEntry::new(5359, "ShellphoneSpawn", "Shellphone (Spawn)"),
+    NameEntry::new(5360, "ShellphoneOcean", "Shellphone (Ocean)"),
+    NameEntry::new(5361, "ShellphoneHell", "Shellphone (Underworld)"),
+    NameEntry::new(5362, "MusicBoxShimmer", "Music Box (Aether)"),
+    NameEntry::new(5363, "SpiderWallUnsafe", "Infested Spider Wall"),
+    NameEntry::new(5364, "BottomlessShimmerBucket", "Bottomless Shimmer Bucket"),
+    NameEntry::new(5365, "BlueBrickWallUnsafe", "Cursed Blue Brick Wall"),
+    NameEntry::new(5366, "BlueSlabWallUnsafe", "Cursed Blue Slab Wall"),
+    NameEntry::new(5367, "BlueTiledWallUnsafe", "Cursed Blue Tiled Wall"),
+    NameEntry::new(5368, "PinkBrickWallUnsafe", "Cursed Pink Brick Wall"),
+    NameEntry::new(5369, "PinkSlabWallUnsafe", "Cursed Pink Slab Wall"),
+    NameEntry::new(5370, "PinkTiledWallUnsafe", "Cursed Pink Tiled Wall"),
+    NameEntry::new(5371, "GreenBrickWallUnsafe", "Cursed Green Brick Wall"),
+    NameEntry::new(5372, "GreenSlabWallUnsafe", "Cursed Green Slab Wall"),
+    NameEntry::new(5373, "GreenTiledWallUnsafe", "Cursed Green Tiled Wall"),
+    NameEntry::new(5374, "SandstoneWallUnsafe", "Treacherous Sandstone Wall"),
+    NameEntry::new(5375, "HardenedSandWallUnsafe", "Treacherous Hardened Sand Wall"),
+    NameEntry::new(5376, "LihzahrdWallUnsafe", "Forbidden Lihzahrd Brick Wall"),
+    NameEntry::new(5377, "SpelunkerFlare", "Spelunker Flare"),
+    NameEntry::new(5378, "CursedFlare", "Cursed Flare"),
+    NameEntry::new(5379, "RainbowFlare", "Rainbow Flare"),
+    NameEntry::new(5380, "ShimmerFlare", "Shimmer Flare"),
+    NameEntry::new(5381, "Moondial", "Enchanted Moondial"),
+    NameEntry::new(5382, "WaffleIron", "Waffle's Iron"),
+    NameEntry::new(5383, "BouncyBoulder", "Bouncy Boulder"),
+    NameEntry::new(5384, "LifeCrystalBoulder", "Life Crystal Boulder"),
+    NameEntry::new(5385, "DizzyHat", "Dizzy's Rare Gecko Chester"),
+    NameEntry::new(5386, "LincolnsHoodie", "Raynbro's Hoodie"),
+    NameEntry::new(5387, "LincolnsPants", "Raynbro's Pants"),
+    NameEntry::new(5388, "SunOrnament", "Eye of the Sun"),
+    NameEntry::new(5389, "HoplitePizza", "Cheesy Pizza Poster"),
+    NameEntry::new(5390, "LincolnsHood", "Raynbro's Hood"),
+    NameEntry::new(5391, "UncumberingStone", "Uncumbering Stone"),
+    NameEntry::new(5392, "SandSolution", "Yellow Solution"),
+    NameEntry::new(5393, "SnowSolution", "White Solution"),
+    NameEntry::new(5394, "DirtSolution", "Brown Solution"),
+    NameEntry::new(5395, "PoopBlock", "Poo"),
+    NameEntry::new(5396, "PoopWall", "Poo Wall"),
+    NameEntry::new(5397, "ShimmerWall", "Aetherium Wall"),
+    NameEntry::new(5398, "ShimmerBrick", "Aetherium Brick"),
+    NameEntry::new(5399, "ShimmerBrickWall", "Aetherium Brick Wall"),
+    NameEntry::new(5400, "DirtiestBlock", "The Dirtiest Block"),
+    NameEntry::new(5401, "LunarRustBrick", "Lunar Rust Brick"),
+    NameEntry::new(5402, "DarkCelestialBrick", "Dark Celestial Brick"),
+    NameEntry::new(5403, "AstraBrick", "Astra Brick"),
+    NameEntry::new(5404, "CosmicEmberBrick", "Cosmic Ember Brick"),
+    NameEntry::new(5405, "CryocoreBrick", "Cryocore Brick"),
+    NameEntry::new(5406, "MercuryBrick", "Mercury Brick"),
+    NameEntry::new(5407, "StarRoyaleBrick", "Star Royale Brick"),
+    NameEntry::new(5408, "HeavenforgeBrick", "Heavenforge Brick"),
+    NameEntry::new(5409, "LunarRustBrickWall", "Lunar Rust Brick Wall"),
+    NameEntry::new(5410, "DarkCelestialBrickWall", "Dark Celestial Brick Wall"),
+    NameEntry::new(5411, "AstraBrickWall", "Astra Brick Wall"),
+    NameEntry::new(5412, "CosmicEmberBrickWall", "Cosmic Ember Brick Wall"),
+    NameEntry::new(5413, "CryocoreBrickWall", "Cryocore Brick Wall"),
+    NameEntry::new(5414, "MercuryBrickWall", "Mercury Brick Wall"),
+    NameEntry::new(5415, "StarRoyaleBrickWall", "Star Royale Brick Wall"),
+    NameEntry::new(5416, "HeavenforgeBrickWall", "Heavenforge Brick Wall"),
+    NameEntry::new(5417, "AncientBlueDungeonBrick", "Ancient Blue Brick"),
+    NameEntry::new(5418, "AncientBlueDungeonBrickWall", "Ancient Blue Brick Wall"),
+    NameEntry::new(5419, "AncientGreenDungeonBrick", "Ancient Green Brick"),
+    NameEntry::new(5420, "AncientGreenDungeonBrickWall", "Ancient Green Brick Wall"),
+    NameEntry::new(5421, "AncientPinkDungeonBrick", "Ancient Pink Brick"),
+    NameEntry::new(5422, "AncientPinkDungeonBrickWall", "Ancient Pink Brick Wall"),
+    NameEntry::new(5423, "AncientGoldBrick", "Ancient Gold Brick"),
+    NameEntry::new(5424, "AncientGoldBrickWall", "Ancient Gold Brick Wall"),
+    NameEntry::new(5425, "AncientSilverBrick", "Ancient Silver Brick"),
+    NameEntry::new(5426, "AncientSilverBrickWall", "Ancient Silver Brick Wall"),
+    NameEntry::new(5427, "AncientCopperBrick", "Ancient Copper Brick"),
+    NameEntry::new(5428, "AncientCopperBrickWall", "Ancient Copper Brick Wall"),
+    NameEntry::new(5429, "AncientCobaltBrick", "Ancient Cobalt Brick"),
+    NameEntry::new(5430, "AncientCobaltBrickWall", "Ancient Cobalt Brick Wall"),
+    NameEntry::new(5431, "AncientMythrilBrick", "Ancient Mythril Brick"),
+    NameEntry::new(5432, "AncientMythrilBrickWall", "Ancient Mythril Brick Wall"),
+    NameEntry::new(5433, "AncientObsidianBrick", "Ancient Obsidian Brick"),
+    NameEntry::new(5434, "AncientObsidianBrickWall", "Ancient Obsidian Brick Wall"),
+    NameEntry::new(5435, "AncientHellstoneBrick", "Ancient Hellstone Brick"),
+    NameEntry::new(5436, "AncientHellstoneBrickWall", "Ancient Hellstone Brick Wall"),
+    NameEntry::new(5437, "ShellphoneDummy", "Shellphone"),
+    NameEntry::new(5438, "Fertilizer", "Fertilizer"),
+    NameEntry::new(5439, "LavaMossBlock", "Lava Moss Brick"),
+    NameEntry::new(5440, "ArgonMossBlock", "Argon Moss Brick"),
+    NameEntry::new(5441, "KryptonMossBlock", "Krypton Moss Brick"),
+    NameEntry::new(5442, "XenonMossBlock", "Xenon Moss Brick"),
+    NameEntry::new(5443, "VioletMossBlock", "Neon Moss Brick"),
+    NameEntry::new(5444, "RainbowMossBlock", "Helium Moss Brick"),
+    NameEntry::new(5445, "LavaMossBlockWall", "Lava Moss Brick Wall"),
+    NameEntry::new(5446, "ArgonMossBlockWall", "Argon Moss Brick Wall"),
+    NameEntry::new(5447, "KryptonMossBlockWall", "Krypton Moss Brick Wall"),
+    NameEntry::new(5448, "XenonMossBlockWall", "Xenon Moss Brick Wall"),
+    NameEntry::new(5449, "VioletMossBlockWall", "Neon Moss Brick Wall"),
+    NameEntry::new(5450, "RainbowMossBlockWall", "Helium Moss Brick Wall"),
+    NameEntry::new(5451, "JimsDrone", "Kwad Racer Drone"),
+    NameEntry::new(5452, "JimsDroneVisor", "FPV Goggles"),
+    NameEntry::new(5453, "DontHurtCrittersBookInactive", "Guide to Critter Companionship (Inactive)"),
+    NameEntry::new(5454, "DontHurtNatureBookInactive", "Guide to Environmental Preservation (Inactive)"),
+    NameEntry::new(5455, "DontHurtComboBookInactive", "Guide to Peaceful Coexistence (Inactive)"),
+    NameEntry::new(5456, "DeadCellsMushroomBoiSummonItem", "Mushroom Staff"),
+    NameEntry::new(5457, "DeadCellsBeheadedHead", "The Beheaded's Head"),
+    NameEntry::new(5458, "DeadCellsBeheadedBody", "The Beheaded's Cuirass"),
+    NameEntry::new(5459, "DeadCellsBeheadedLegs", "The Beheaded's Trousers"),
+    NameEntry::new(5460, "DeadCellsBarrelLauncher", "Barrel Launcher"),
+    NameEntry::new(5461, "DeadCellsKillingDeck", "Killing Deck"),
+    NameEntry::new(5462, "DeadCellsFlint", "Flint"),
+    NameEntry::new(5463, "DeadCellsBarnacleSummonItem", "Barnacle Staff"),
+    NameEntry::new(5464, "MiteyTitey", "Mitey-Titey"),
+    NameEntry::new(5465, "DeadCellsRamRune", "Ram Rune"),
+    NameEntry::new(5466, "DeadCellsSwarmGrenade", "Swarm Grenade"),
+    NameEntry::new(5467, "DemonAltarReplica", "Replica Demon Altar"),
+    NameEntry::new(5468, "CrimsonAltarReplica", "Replica Crimson Altar"),
+    NameEntry::new(5469, "ShadowOrbReplica", "Replica Shadow Orb"),
+    NameEntry::new(5470, "CrimsonHeartReplica", "Replica Crimson Heart"),
+    NameEntry::new(5471, "CobwebReplica", "Decorative Cobweb"),
+    NameEntry::new(5472, "DeadCellsDisplayJar", "Item Flask"),
+    NameEntry::new(5473, "CobWhip", "Cobwhip"),
+    NameEntry::new(5474, "CorruptWhip", "Soulscourge"),
+    NameEntry::new(5475, "CrimsonWhip", "Vasculash"),
+    NameEntry::new(5476, "MeteorWhip", "Starcrash"),
+    NameEntry::new(5477, "FlowerWhip", "Vulgar Display of Flower"),
+    NameEntry::new(5478, "EelWhip", "Electric Eel"),
+    NameEntry::new(5479, "ConstellationWhip", "Constellation"),
+    NameEntry::new(5480, "MoonLordWhip", "Possession"),
+    NameEntry::new(5481, "PortableKiln", "Portable Kiln"),
+    NameEntry::new(5482, "DeadCellsPotionStation", "Alchemy Flask"),
+    NameEntry::new(5483, "QueenOfBees", "Queen of Bees"),
+    NameEntry::new(5484, "PlayerVoiceCowbellItem", "Cow Bell"),
+    NameEntry::new(5485, "PlayerVoiceChickenFeetItem", "Chicken Charm"),
+    NameEntry::new(5486, "TheSeaOfSilence", "The Sea of Silence"),
+    NameEntry::new(5487, "HeroesFromAnotherWorld", "Heroes From Another World"),
+    NameEntry::new(5488, "Crystallize", "Crystallize"),
+    NameEntry::new(5489, "EaterOfLife", "Eater Of Life"),
+    NameEntry::new(5490, "ThisIsCanonNow", "This Is Canon Now"),
+    NameEntry::new(5491, "WinterAtVaringskollen", "Winter At Varingskollen"),
+    NameEntry::new(5492, "MagicShimmerDropper", "Magic Shimmer Dropper"),
+    NameEntry::new(5493, "ShimmerFallBlock", "Shimmerfall Block"),
+    NameEntry::new(5494, "ShimmerFallWall", "Shimmerfall Wall"),
+    NameEntry::new(5495, "ShimmerGun", "Shimmer Gun"),
+    NameEntry::new(5496, "LifeFruitHealingPotion", "Jungle Juice"),
+    NameEntry::new(5497, "PinkBanner", "Pink Banner"),
+    NameEntry::new(5498, "WhiteBanner", "White Banner"),
+    NameEntry::new(5499, "PlayerVoiceFrogItem", "Froggy Neckband"),
+    NameEntry::new(5500, "PlayerVoiceGoatItem", "Goat's Tuft"),
+    NameEntry::new(5501, "PlayerVoiceRetroItem", "Old Companion Locket"),
+    NameEntry::new(5502, "PlayerVoiceCatItem", "Cat Chime"),
+    NameEntry::new(5503, "PlayerVoiceDogItem", "Dog Collar"),
+    NameEntry::new(5504, "PlayerVoiceTurkeyItem", "Turkey Wattle Necklace"),
+    NameEntry::new(5505, "PlayerVoiceGoblinItem", "Mean Goblin's Spikes"),
+    NameEntry::new(5506, "PlayerVoiceCrowItem", "Crow's Beak"),
+    NameEntry::new(5507, "PlayerVoiceBalloonItem", "Balloony Beads"),
+    NameEntry::new(5508, "PlayerVoiceUndeadItem", "Grim Old Barb"),
+    NameEntry::new(5509, "PlayerVoiceVampireItem", "Vampire Pendant"),
+    NameEntry::new(5510, "VelociraptorMountItem", "Slasher's Mysterious Skull"),
+    NameEntry::new(5511, "Pufferfish", "Pufferfish"),
+    NameEntry::new(5512, "PufferfishCage", "Pufferfish Cage"),
+    NameEntry::new(5513, "PufferfishPet", "Puffer Shrimp"),
+    NameEntry::new(5514, "RainbowBoulder", "Rainbow Boulder"),
+    NameEntry::new(5515, "MoonLordBody", "Moon Lord Torso"),
+    NameEntry::new(5516, "Poulder", "Poo Boulder"),
+    NameEntry::new(5517, "AxeFairyPetItem", "Faecorn"),
+    NameEntry::new(5518, "SuperFertilizer", "Infused Fertilizer"),
+    NameEntry::new(5519, "Axearang", "Axearang"),
+    NameEntry::new(5520, "LavaBoulder", "Lava Boulder"),
+    NameEntry::new(5521, "SpiderBoulder", "Spider Boulder"),
+    NameEntry::new(5522, "Ghoulder", "Ghoulder"),
+    NameEntry::new(5523, "BoulderPet", "Friendly Boulder"),
+    NameEntry::new(5524, "ChlorophyteVisor", "Chlorophyte Visor"),
+    NameEntry::new(5525, "RatMountItem", "Cursed Piper Flute"),
+    NameEntry::new(5526, "FlaironFlail", "Flairon"),
+    NameEntry::new(5527, "OfSeaAndDreams", "Of Sea and Dreams"),
+    NameEntry::new(5528, "TheRunicPixie", "The Runic Pixie"),
+    NameEntry::new(5529, "BannerOfTheBeast", "Banner of the Beast"),
+    NameEntry::new(5530, "StickmanVsTerrTerr", "Stickman vs Terr Terr"),
+    NameEntry::new(5531, "CozyWindow", "Cozy Window"),
+    NameEntry::new(5532, "DemonAltar", "Demon Altar"),
+    NameEntry::new(5533, "CrimsonAltar", "Crimson Altar"),
+    NameEntry::new(5534, "PlayerVoiceFairyItem", "Fairy Choker"),
+    NameEntry::new(5535, "PinkPhaseblade", "Pink Phaseblade"),
+    NameEntry::new(5536, "PinkPhasesaber", "Pink Phasesaber"),
+    NameEntry::new(5537, "BlackenedFish", "Blackened Fish"),
+    NameEntry::new(5538, "MusicBoxQueenBee", "Music Box (Queen Bee)"),
+    NameEntry::new(5539, "MusicBoxTwins", "Music Box (The Twins)"),
+    NameEntry::new(5540, "MagicString", "Magic String"),
+    NameEntry::new(5541, "MagicYoyoBag", "Magic Yoyo Bag"),
+    NameEntry::new(5542, "FreezeBomb", "Freeze Bomb"),
+    NameEntry::new(5543, "StressBall", "Stress Ball"),
+    NameEntry::new(5544, "CloudPlatform", "Cloud Platform"),
+    NameEntry::new(5545, "LivingWoodWallUnsafe", "Overgrown Living Wood Wall"),
+    NameEntry::new(5546, "DirtWallUnsafe", "Natural Dirt Wall"),
+    NameEntry::new(5547, "StrungCounterweight", "Strung Counterweight"),
+    NameEntry::new(5548, "AetheriumBathtub", "Aetherium Bathtub"),
+    NameEntry::new(5549, "AetheriumBed", "Aetherium Bed"),
+    NameEntry::new(5550, "AetheriumBookcase", "Aetherium Bookcase"),
+    NameEntry::new(5551, "AetheriumDresser", "Aetherium Dresser"),
+    NameEntry::new(5552, "AetheriumCandelabra", "Aetherium Candelabra"),
+    NameEntry::new(5553, "AetheriumCandle", "Aetherium Candle"),
+    NameEntry::new(5554, "AetheriumChair", "Aetherium Chair"),
+    NameEntry::new(5555, "AetheriumChandelier", "Aetherium Chandelier"),
+    NameEntry::new(5556, "AetheriumChest", "Aetherium Chest"),
+    NameEntry::new(5557, "AetheriumClock", "Aetherium Clock"),
+    NameEntry::new(5558, "AetheriumDoor", "Aetherium Door"),
+    NameEntry::new(5559, "AetheriumLamp", "Aetherium Lamp"),
+    NameEntry::new(5560, "AetheriumLantern", "Aetherium Lantern"),
+    NameEntry::new(5561, "AetheriumPiano", "Aetherium Piano"),
+    NameEntry::new(5562, "AetheriumPlatform", "Aetherium Platform"),
+    NameEntry::new(5563, "AetheriumSink", "Aetherium Sink"),
+    NameEntry::new(5564, "AetheriumSofa", "Aetherium Sofa"),
+    NameEntry::new(5565, "AetheriumTable", "Aetherium Table"),
+    NameEntry::new(5566, "AetheriumWorkbench", "Aetherium Work Bench"),
+    NameEntry::new(5567, "Fake_AetheriumChest", "Trapped Aetherium Chest"),
+    NameEntry::new(5568, "AetheriumToilet", "Aetherium Toilet"),
+    NameEntry::new(5569, "LavaCloud", "Lava Cloud"),
+    NameEntry::new(5570, "StarCloud", "Star Cloud"),
+    NameEntry::new(5571, "RainbowCloud", "Rainbow Cloud"),
+    NameEntry::new(5572, "MudBallPlayer", "Mud Ball"),
+    NameEntry::new(5573, "TorchGodPotion", "Torch God's Flavor"),
+    NameEntry::new(5574, "LuckyClover", "Lucky Clover"),
+    NameEntry::new(5575, "WiltedClover", "Wilted Clover"),
+    NameEntry::new(5576, "RavenFeather", "Raven Feather"),
+    NameEntry::new(5577, "PrettyMirror", "Pretty Mirror"),
+    NameEntry::new(5578, "MusicBoxKingSlime", "Music Box (King Slime)"),
+    NameEntry::new(5579, "MusicBoxQueenBeeAlt", "Music Box (Alt Queen Bee)"),
+    NameEntry::new(5580, "MusicBoxLunaticCultist", "Music Box (Lunatic Cultist)"),
+    NameEntry::new(5581, "MusicBoxSkeletronPrime", "Music Box (Skeletron Prime)"),
+    NameEntry::new(5582, "MusicBoxDestroyer", "Music Box (The Destroyer)"),
+    NameEntry::new(5583, "ChickenBonesHead", "Chicken Bones' Visor"),
+    NameEntry::new(5584, "ChickenBonesBody", "Chicken Bones' Vest"),
+    NameEntry::new(5585, "ChickenBonesLegs", "Chicken Bones' Pants"),
+    NameEntry::new(5586, "ChickenBonesWings", "Chicken Bones' Wings"),
+    NameEntry::new(5587, "ChickenBonesRobe", "Chicken Bones' Robe"),
+    NameEntry::new(5588, "UpgradedMiningHead", "Prospector Helmet"),
+    NameEntry::new(5589, "UpgradedMiningBody", "Prospector Shirt"),
+    NameEntry::new(5590, "UpgradedMiningLegs", "Prospector Pants"),
+    NameEntry::new(5591, "UpgradedFishingHead", "Captain Hat"),
+    NameEntry::new(5592, "UpgradedFishingBody", "Captain Vest"),
+    NameEntry::new(5593, "UpgradedFishingLegs", "Captain Pants"),
+    NameEntry::new(5594, "SuperBomb", "Power Bomb"),
+    NameEntry::new(5595, "SuperStickyBomb", "Sticky Power Bomb"),
+    NameEntry::new(5596, "WeldingMask", "Welding Mask"),
+    NameEntry::new(5597, "BatMountItem", "Amulet of the Night"),
+    NameEntry::new(5598, "CRTMonolith", "CRT Monolith"),
+    NameEntry::new(5599, "RetroMonolith", "Retro Monolith"),
+    NameEntry::new(5600, "RollerSkatesBlueMountItem", "Blue Roller Skates"),
+    NameEntry::new(5601, "FallenStarBathtub", "Fallen Star Bathtub"),
+    NameEntry::new(5602, "FallenStarBed", "Fallen Star Bed"),
+    NameEntry::new(5603, "FallenStarBookcase", "Fallen Star Bookcase"),
+    NameEntry::new(5604, "FallenStarDresser", "Fallen Star Dresser"),
+    NameEntry::new(5605, "FallenStarCandelabra", "Fallen Star Candelabra"),
+    NameEntry::new(5606, "FallenStarCandle", "Fallen Star Candle"),
+    NameEntry::new(5607, "FallenStarChair", "Fallen Star Chair"),
+    NameEntry::new(5608, "FallenStarChandelier", "Fallen Star Chandelier"),
+    NameEntry::new(5609, "FallenStarChest", "Fallen Star Chest"),
+    NameEntry::new(5610, "FallenStarClock", "Fallen Star Clock"),
+    NameEntry::new(5611, "FallenStarDoor", "Fallen Star Door"),
+    NameEntry::new(5612, "FallenStarLamp", "Fallen Star Lamp"),
+    NameEntry::new(5613, "FallenStarLantern", "Fallen Star Lantern"),
+    NameEntry::new(5614, "FallenStarPiano", "Fallen Star Piano"),
+    NameEntry::new(5615, "FallenStarPlatform", "Fallen Star Platform"),
+    NameEntry::new(5616, "FallenStarSink", "Fallen Star Sink"),
+    NameEntry::new(5617, "FallenStarSofa", "Fallen Star Sofa"),
+    NameEntry::new(5618, "FallenStarTable", "Fallen Star Table"),
+    NameEntry::new(5619, "FallenStarWorkbench", "Fallen Star Work Bench"),
+    NameEntry::new(5620, "Fake_FallenStarChest", "Trapped Fallen Star Chest"),
+    NameEntry::new(5621, "FallenStarToilet", "Fallen Star Toilet"),
+    NameEntry::new(5622, "FallenStarBlock", "Fallen Star Block"),
+    NameEntry::new(5623, "FallenStarWall", "Fallen Star Wall"),
+    NameEntry::new(5624, "ChippysHead", "Chippy's Helmet"),
+    NameEntry::new(5625, "ChippysBody", "Chippy's Chestplate"),
+    NameEntry::new(5626, "ChippysLegs", "Chippy's Greaves"),
+    NameEntry::new(5627, "ChippysWings", "Chippy's Cloak"),
+    NameEntry::new(5628, "ChippysHeadband", "Chippy's Headband"),
+    NameEntry::new(5629, "AcornSlingshot", "Acorn Slingshot"),
+    NameEntry::new(5630, "PaintingRPlace2023", "r/Terraria 2023"),
+    NameEntry::new(5631, "PaintingBouldChoices", "Bould and Bash"),
+    NameEntry::new(5632, "PaintingDarkForebodings", "Dark Forebodings"),
+    NameEntry::new(5633, "PaintingGermanZenith", "Oktober"),
+    NameEntry::new(5634, "PaintingItsScragglinTime", "It's Scragglin' Time"),
+    NameEntry::new(5635, "PaintingKaguya", "Kaguya"),
+    NameEntry::new(5636, "PaintingGermanBeer", "Prost"),
+    NameEntry::new(5637, "MusicBoxEaterOfWorlds", "Music Box (Eater of Worlds)"),
+    NameEntry::new(5638, "MusicBoxTorchGod", "Music Box (Torch God)"),
+    NameEntry::new(5639, "MusicBoxTorchGodAlt", "Music Box (Alt Torch God)"),
+    NameEntry::new(5640, "RollerSkatesGreenMountItem", "Green Roller Skates"),
+    NameEntry::new(5641, "RollerSkatesClassicMountItem", "Classic Roller Skates"),
+    NameEntry::new(5642, "RollerSkatesPartyMountItem", "Party Roller Skates"),
+    NameEntry::new(5643, "RainbowGlowstick", "Rainbow Glowstick"),
+    NameEntry::new(5644, "ScryingOrb", "Scrying Orb"),
+    NameEntry::new(5645, "RockCandy", "Rock Candy"),
+    NameEntry::new(5646, "BlueBikiniBody", "Blue Bikini Top"),
+    NameEntry::new(5647, "BlueBikiniLegs", "Blue Bikini Bottom"),
+    NameEntry::new(5648, "RedSwimsuit", "Red Swimsuit"),
+    NameEntry::new(5649, "GreenSwimshorts", "Green Swimshorts"),
+    NameEntry::new(5650, "GraySwimshorts", "Gray Swimshorts"),
+    NameEntry::new(5651, "OrcaBanner", "Orca Banner"),
+    NameEntry::new(5652, "TeleportationPylonUnderworld", "Underworld Pylon"),
+    NameEntry::new(5653, "TeleportationPylonShimmer", "Aether Pylon"),
+    NameEntry::new(5654, "RainbowBoulderPet", "Friendly Rainbow Boulder"),
+    NameEntry::new(5655, "NoirMonolith", "Film Projector"),
+    NameEntry::new(5656, "HeroicisHead", "Heroicis' Hat"),
+    NameEntry::new(5657, "HeroicisBody", "Heroicis' Coat"),
+    NameEntry::new(5658, "HeroicisLegs", "Heroicis' Pants"),
+    NameEntry::new(5659, "HeroicisWings", "Heroicis' Wings"),
+    NameEntry::new(5660, "HallowedCrown", "Hallowed Crown"),
+    NameEntry::new(5661, "HeroicisWingsInactive", "Heroicis' Wings (Inactive)"),
+    NameEntry::new(5662, "EnchantedPixieDust", "Enchanted Pixie Dust"),
+    NameEntry::new(5663, "PalworldMinionCattiva", "Cattiva"),
+    NameEntry::new(5664, "PalworldMinionFoxsparks", "Foxparks"),
+    NameEntry::new(5665, "PalworldPetChillet", "Chillet"),
+    NameEntry::new(5666, "PalworldPetChilletIgnis", "Chillet Ignis"),
+    NameEntry::new(5667, "PalworldDigtoise", "Digtoise"),
+    NameEntry::new(5668, "SoundGun", "The Imploder"),
+    NameEntry::new(5669, "TrueCopperShortsword", "True Copper Shortsword"),
+    NameEntry::new(5670, "RainbowPhaseblade", "Rainbow Phaseblade"),
+    NameEntry::new(5671, "RainbowPhasesaber", "Rainbow Phasesaber"),
+    NameEntry::new(5672, "LibrarianSkeletonBanner", "Librarian Skeleton Banner"),
+    NameEntry::new(5673, "WaterBoltMimicBanner", "Water Bolt Mimic Banner"),
+    NameEntry::new(5674, "TeamBlockRedVariant", "Dull Red Team Block"),
+    NameEntry::new(5675, "TeamBlockGreenVariant", "Dull Green Team Block"),
+    NameEntry::new(5676, "TeamBlockBlueVariant", "Dull Blue Team Block"),
+    NameEntry::new(5677, "TeamBlockYellowVariant", "Dull Yellow Team Block"),
+    NameEntry::new(5678, "TeamBlockPinkVariant", "Dull Pink Team Block"),
+    NameEntry::new(5679, "TeamBlockWhiteVariant", "Dull White Team Block"),
+    NameEntry::new(5680, "LilacDuskHead", "Lilac Dusk Hairclip"),
+    NameEntry::new(5681, "LilacDuskBody", "Lilac Dusk Dress"),
+    NameEntry::new(5682, "LilacDuskLegs", "Lilac Dusk Skirt"),
+    NameEntry::new(5683, "KazzymodusHood", "Kazzymodus' Hood"),
+    NameEntry::new(5684, "KazzymodusChestpiece", "Kazzymodus' Chestpiece"),
+    NameEntry::new(5685, "KazzymodusLeggings", "Kazzymodus' Leggings"),
+    NameEntry::new(5686, "KazzymodusWings", "Kazzymodus' Wings"),
+    NameEntry::new(5687, "SlimeSpear", "Slime Spear"),
+    NameEntry::new(5688, "SlimeWhip", "Slime Whip"),
+    NameEntry::new(5689, "FeywoodBathtub", "Feywood Bathtub"),
+    NameEntry::new(5690, "FeywoodBed", "Feywood Bed"),
+    NameEntry::new(5691, "FeywoodBookcase", "Feywood Bookcase"),
+    NameEntry::new(5692, "FeywoodDresser", "Feywood Dresser"),
+    NameEntry::new(5693, "FeywoodCandelabra", "Feywood Candelabra"),
+    NameEntry::new(5694, "FeywoodCandle", "Feywood Candle"),
+    NameEntry::new(5695, "FeywoodChair", "Feywood Chair"),
+    NameEntry::new(5696, "FeywoodChandelier", "Feywood Chandelier"),
+    NameEntry::new(5697, "FeywoodChest", "Feywood Chest"),
+    NameEntry::new(5698, "FeywoodClock", "Feywood Clock"),
+    NameEntry::new(5699, "FeywoodDoor", "Feywood Door"),
+    NameEntry::new(5700, "FeywoodLamp", "Feywood Lamp"),
+    NameEntry::new(5701, "FeywoodLantern", "Feywood Lantern"),
+    NameEntry::new(5702, "FeywoodPiano", "Feywood Piano"),
+    NameEntry::new(5703, "FeywoodPlatform", "Feywood Platform"),
+    NameEntry::new(5704, "FeywoodSink", "Feywood Sink"),
+    NameEntry::new(5705, "FeywoodSofa", "Feywood Sofa"),
+    NameEntry::new(5706, "FeywoodTable", "Feywood Table"),
+    NameEntry::new(5707, "FeywoodWorkbench", "Feywood Work Bench"),
+    NameEntry::new(5708, "Fake_FeywoodChest", "Trapped Feywood Chest"),
+    NameEntry::new(5709, "FeywoodToilet", "Feywood Toilet"),
+    NameEntry::new(5710, "Feywood", "Feywood"),
+    NameEntry::new(5711, "FeywoodWall", "Feywood Wall"),
+    NameEntry::new(5712, "HallowedBathtub", "Hallowed Bathtub"),
+    NameEntry::new(5713, "HallowedBed", "Hallowed Bed"),
+    NameEntry::new(5714, "HallowedBookcase", "Hallowed Bookcase"),
+    NameEntry::new(5715, "HallowedDresser", "Hallowed Dresser"),
+    NameEntry::new(5716, "HallowedCandelabra", "Hallowed Candelabra"),
+    NameEntry::new(5717, "HallowedCandle", "Hallowed Candle"),
+    NameEntry::new(5718, "HallowedChair", "Hallowed Chair"),
+    NameEntry::new(5719, "HallowedChandelier", "Hallowed Chandelier"),
+    NameEntry::new(5720, "HallowedFurnitureChest", "Fancy Hallowed Chest"),
+    NameEntry::new(5721, "HallowedClock", "Hallowed Clock"),
+    NameEntry::new(5722, "HallowedDoor", "Hallowed Door"),
+    NameEntry::new(5723, "HallowedLamp", "Hallowed Lamp"),
+    NameEntry::new(5724, "HallowedLantern", "Hallowed Lantern"),
+    NameEntry::new(5725, "HallowedPiano", "Hallowed Piano"),
+    NameEntry::new(5726, "HallowedPlatform", "Hallowed Platform"),
+    NameEntry::new(5727, "HallowedSink", "Hallowed Sink"),
+    NameEntry::new(5728, "HallowedSofa", "Hallowed Sofa"),
+    NameEntry::new(5729, "HallowedTable", "Hallowed Table"),
+    NameEntry::new(5730, "HallowedWorkbench", "Hallowed Work Bench"),
+    NameEntry::new(5731, "Fake_HallowedFurnitureChest", "Trapped Fancy Hallowed Chest"),
+    NameEntry::new(5732, "HallowedToilet", "Hallowed Toilet"),
+    NameEntry::new(5733, "HallowedBrick", "Hallowed Brick"),
+    NameEntry::new(5734, "HallowedBrickWall", "Hallowed Brick Wall"),
+    NameEntry::new(5735, "PalworldPalMetalArmorBody", "Pal Metal Chestplate"),
+    NameEntry::new(5736, "PalworldPalMetalArmorLegs", "Pal Metal Leggings"),
+    NameEntry::new(5737, "ChippysWingsInactive", "Chippy's Cloak (Inactive)"),
+    NameEntry::new(5738, "RemoteControlCar", "Wall Racer Car"),
+    NameEntry::new(5739, "GothicBathtub", "Gothic Bathtub"),
+    NameEntry::new(5740, "GothicBed", "Gothic Bed"),
+    NameEntry::new(5741, "GothicDresser", "Gothic Dresser"),
+    NameEntry::new(5742, "GothicCandelabra", "Gothic Candelabra"),
+    NameEntry::new(5743, "GothicCandle", "Gothic Candle"),
+    NameEntry::new(5744, "GothicChandelier", "Gothic Chandelier"),
+    NameEntry::new(5745, "GothicChest", "Gothic Chest"),
+    NameEntry::new(5746, "GothicClock", "Gothic Clock"),
+    NameEntry::new(5747, "GothicDoor", "Gothic Door"),
+    NameEntry::new(5748, "GothicLamp", "Gothic Lamp"),
+    NameEntry::new(5749, "GothicLantern", "Gothic Lantern"),
+    NameEntry::new(5750, "GothicPiano", "Gothic Piano"),
+    NameEntry::new(5751, "GothicPlatform", "Gothic Platform"),
+    NameEntry::new(5752, "GothicSink", "Gothic Sink"),
+    NameEntry::new(5753, "GothicSofa", "Gothic Sofa"),
+    NameEntry::new(5754, "Fake_GothicChest", "Trapped Gothic Chest"),
+    NameEntry::new(5755, "GothicToilet", "Gothic Toilet"),
+    NameEntry::new(5756, "DemoniteBathtub", "Demonite Bathtub"),
+    NameEntry::new(5757, "DemoniteBed", "Demonite Bed"),
+    NameEntry::new(5758, "DemoniteBookcase", "Demonite Bookcase"),
+    NameEntry::new(5759, "DemoniteCandelabra", "Demonite Candelabra"),
+    NameEntry::new(5760, "DemoniteCandle", "Demonite Candle"),
+    NameEntry::new(5761, "DemoniteChair", "Demonite Chair"),
+    NameEntry::new(5762, "DemoniteChandelier", "Demonite Chandelier"),
+    NameEntry::new(5763, "DemoniteChest", "Demonite Chest"),
+    NameEntry::new(5764, "DemoniteClock", "Demonite Clock"),
+    NameEntry::new(5765, "DemoniteDoor", "Demonite Door"),
+    NameEntry::new(5766, "DemoniteDresser", "Demonite Dresser"),
+    NameEntry::new(5767, "DemoniteLamp", "Demonite Lamp"),
+    NameEntry::new(5768, "DemoniteLantern", "Demonite Lantern"),
+    NameEntry::new(5769, "DemonitePiano", "Demonite Piano"),
+    NameEntry::new(5770, "DemonitePlatform", "Demonite Platform"),
+    NameEntry::new(5771, "DemoniteSink", "Demonite Sink"),
+    NameEntry::new(5772, "DemoniteSofa", "Demonite Sofa"),
+    NameEntry::new(5773, "DemoniteTable", "Demonite Table"),
+    NameEntry::new(5774, "DemoniteToilet", "Demonite Toilet"),
+    NameEntry::new(5775, "DemoniteWorkbench", "Demonite Work Bench"),
+    NameEntry::new(5776, "Fake_DemoniteChest", "Trapped Demonite Chest"),
+    NameEntry::new(5777, "CrimtaneBathtub", "Crimtane Bathtub"),
+    NameEntry::new(5778, "CrimtaneBed", "Crimtane Bed"),
+    NameEntry::new(5779, "CrimtaneBookcase", "Crimtane Bookcase"),
+    NameEntry::new(5780, "CrimtaneCandelabra", "Crimtane Candelabra"),
+    NameEntry::new(5781, "CrimtaneCandle", "Crimtane Candle"),
+    NameEntry::new(5782, "CrimtaneChair", "Crimtane Chair"),
+    NameEntry::new(5783, "CrimtaneChandelier", "Crimtane Chandelier"),
+    NameEntry::new(5784, "CrimtaneChest", "Crimtane Chest"),
+    NameEntry::new(5785, "CrimtaneClock", "Crimtane Clock"),
+    NameEntry::new(5786, "CrimtaneDoor", "Crimtane Door"),
+    NameEntry::new(5787, "CrimtaneDresser", "Crimtane Dresser"),
+    NameEntry::new(5788, "CrimtaneLamp", "Crimtane Lamp"),
+    NameEntry::new(5789, "CrimtaneLantern", "Crimtane Lantern"),
+    NameEntry::new(5790, "CrimtanePiano", "Crimtane Piano"),
+    NameEntry::new(5791, "CrimtanePlatform", "Crimtane Platform"),
+    NameEntry::new(5792, "CrimtaneSink", "Crimtane Sink"),
+    NameEntry::new(5793, "CrimtaneSofa", "Crimtane Sofa"),
+    NameEntry::new(5794, "CrimtaneTable", "Crimtane Table"),
+    NameEntry::new(5795, "CrimtaneToilet", "Crimtane Toilet"),
+    NameEntry::new(5796, "CrimtaneWorkbench", "Crimtane Work Bench"),
+    NameEntry::new(5797, "Fake_CrimtaneChest", "Trapped Crimtane Chest"),
+    NameEntry::new(5798, "SnowBathtub", "Snow Bathtub"),
+    NameEntry::new(5799, "SnowBed", "Snow Bed"),
+    NameEntry::new(5800, "SnowBookcase", "Snow Bookcase"),
+    NameEntry::new(5801, "SnowCandelabra", "Snow Candelabra"),
+    NameEntry::new(5802, "SnowCandle", "Snow Candle"),
+    NameEntry::new(5803, "SnowChair", "Snow Chair"),
+    NameEntry::new(5804, "SnowChandelier", "Snow Chandelier"),
+    NameEntry::new(5805, "SnowChest", "Snow Chest"),
+    NameEntry::new(5806, "SnowClock", "Snow Clock"),
+    NameEntry::new(5807, "SnowDoor", "Snow Door"),
+    NameEntry::new(5808, "SnowDresser", "Snow Dresser"),
+    NameEntry::new(5809, "SnowLamp", "Snow Lamp"),
+    NameEntry::new(5810, "SnowLantern", "Snow Lantern"),
+    NameEntry::new(5811, "SnowPiano", "Snow Piano"),
+    NameEntry::new(5812, "SnowPlatform", "Snow Platform"),
+    NameEntry::new(5813, "SnowSink", "Snow Sink"),
+    NameEntry::new(5814, "SnowSofa", "Snow Sofa"),
+    NameEntry::new(5815, "SnowTable", "Snow Table"),
+    NameEntry::new(5816, "SnowToilet", "Snow Toilet"),
+    NameEntry::new(5817, "SnowWorkbench", "Snow Work Bench"),
+    NameEntry::new(5818, "Fake_SnowChest", "Trapped Snow Chest"),
+    NameEntry::new(5819, "FlinxFurBathtub", "Flinx Fur Bathtub"),
+    NameEntry::new(5820, "FlinxFurBed", "Flinx Fur Bed"),
+    NameEntry::new(5821, "FlinxFurBookcase", "Flinx Fur Bookcase"),
+    NameEntry::new(5822, "FlinxFurCandelabra", "Flinx Fur Candelabra"),
+    NameEntry::new(5823, "FlinxFurCandle", "Flinx Fur Candle"),
+    NameEntry::new(5824, "FlinxFurChair", "Flinx Fur Chair"),
+    NameEntry::new(5825, "FlinxFurChandelier", "Flinx Fur Chandelier"),
+    NameEntry::new(5826, "FlinxFurChest", "Flinx Fur Chest"),
+    NameEntry::new(5827, "FlinxFurClock", "Flinx Fur Clock"),
+    NameEntry::new(5828, "FlinxFurDoor", "Flinx Fur Door"),
+    NameEntry::new(5829, "FlinxFurDresser", "Flinx Fur Dresser"),
+    NameEntry::new(5830, "FlinxFurLamp", "Flinx Fur Lamp"),
+    NameEntry::new(5831, "FlinxFurLantern", "Flinx Fur Lantern"),
+    NameEntry::new(5832, "FlinxFurPiano", "Flinx Fur Piano"),
+    NameEntry::new(5833, "FlinxFurPlatform", "Flinx Fur Platform"),
+    NameEntry::new(5834, "FlinxFurSink", "Flinx Fur Sink"),
+    NameEntry::new(5835, "FlinxFurSofa", "Flinx Fur Sofa"),
+    NameEntry::new(5836, "FlinxFurTable", "Flinx Fur Table"),
+    NameEntry::new(5837, "FlinxFurToilet", "Flinx Fur Toilet"),
+    NameEntry::new(5838, "FlinxFurWorkbench", "Flinx Fur Work Bench"),
+    NameEntry::new(5839, "Fake_FlinxFurChest", "Trapped Flinx Fur Chest"),
+    NameEntry::new(5840, "PineBathtub", "Pine Bathtub"),
+    NameEntry::new(5841, "PineBed", "Pine Bed"),
+    NameEntry::new(5842, "PineBookcase", "Pine Bookcase"),
+    NameEntry::new(5843, "PineCandelabra", "Pine Candelabra"),
+    NameEntry::new(5844, "PineCandle", "Pine Candle"),
+    NameEntry::new(5845, "PineChandelier", "Pine Chandelier"),
+    NameEntry::new(5846, "PineChest", "Pine Chest"),
+    NameEntry::new(5847, "PineClock", "Pine Clock"),
+    NameEntry::new(5848, "PineDresser", "Pine Dresser"),
+    NameEntry::new(5849, "PineLamp", "Pine Lamp"),
+    NameEntry::new(5850, "PineLantern", "Pine Lantern"),
+    NameEntry::new(5851, "PinePiano", "Pine Piano"),
+    NameEntry::new(5852, "PinePlatform", "Pine Platform"),
+    NameEntry::new(5853, "PineSink", "Pine Sink"),
+    NameEntry::new(5854, "PineSofa", "Pine Sofa"),
+    NameEntry::new(5855, "PineToilet", "Pine Toilet"),
+    NameEntry::new(5856, "PineWorkbench", "Pine Work Bench"),
+    NameEntry::new(5857, "Fake_PineChest", "Trapped Pine Chest"),
+    NameEntry::new(5858, "EasterBathtub", "Easter Bathtub"),
+    NameEntry::new(5859, "EasterBed", "Easter Bed"),
+    NameEntry::new(5860, "EasterBookcase", "Easter Bookcase"),
+    NameEntry::new(5861, "EasterCandelabra", "Easter Candelabra"),
+    NameEntry::new(5862, "EasterCandle", "Easter Candle"),
+    NameEntry::new(5863, "EasterChair", "Easter Chair"),
+    NameEntry::new(5864, "EasterChandelier", "Easter Chandelier"),
+    NameEntry::new(5865, "EasterChest", "Easter Chest"),
+    NameEntry::new(5866, "EasterClock", "Easter Clock"),
+    NameEntry::new(5867, "EasterDoor", "Easter Door"),
+    NameEntry::new(5868, "EasterDresser", "Easter Dresser"),
+    NameEntry::new(5869, "EasterLamp", "Easter Lamp"),
+    NameEntry::new(5870, "EasterLantern", "Easter Lantern"),
+    NameEntry::new(5871, "EasterPiano", "Easter Piano"),
+    NameEntry::new(5872, "EasterPlatform", "Easter Platform"),
+    NameEntry::new(5873, "EasterSink", "Easter Sink"),
+    NameEntry::new(5874, "EasterSofa", "Easter Sofa"),
+    NameEntry::new(5875, "EasterTable", "Easter Table"),
+    NameEntry::new(5876, "EasterToilet", "Easter Toilet"),
+    NameEntry::new(5877, "EasterWorkbench", "Easter Work Bench"),
+    NameEntry::new(5878, "Fake_EasterChest", "Trapped Easter Chest"),
+    NameEntry::new(5879, "StoneBathtub", "Stone Bathtub"),
+    NameEntry::new(5880, "StoneBed", "Stone Bed"),
+    NameEntry::new(5881, "StoneBookcase", "Stone Bookcase"),
+    NameEntry::new(5882, "StoneCandelabra", "Stone Candelabra"),
+    NameEntry::new(5883, "StoneCandle", "Stone Candle"),
+    NameEntry::new(5884, "StoneChair", "Stone Chair"),
+    NameEntry::new(5885, "StoneChandelier", "Stone Chandelier"),
+    NameEntry::new(5886, "StoneChest", "Stone Chest"),
+    NameEntry::new(5887, "StoneClock", "Stone Clock"),
+    NameEntry::new(5888, "StoneDresser", "Stone Dresser"),
+    NameEntry::new(5889, "StoneLamp", "Stone Lamp"),
+    NameEntry::new(5890, "StoneLantern", "Stone Lantern"),
+    NameEntry::new(5891, "StonePiano", "Stone Piano"),
+    NameEntry::new(5892, "StoneSink", "Stone Sink"),
+    NameEntry::new(5893, "StoneSofa", "Stone Sofa"),
+    NameEntry::new(5894, "StoneTable", "Stone Table"),
+    NameEntry::new(5895, "StoneToilet", "Stone Toilet"),
+    NameEntry::new(5896, "StoneWorkbench", "Stone Work Bench"),
+    NameEntry::new(5897, "Fake_StoneChest", "Trapped Stone Chest"),
+    NameEntry::new(5898, "JellyfishBathtub", "Jellyfish Bathtub"),
+    NameEntry::new(5899, "JellyfishBed", "Jellyfish Bed"),
+    NameEntry::new(5900, "JellyfishBookcase", "Jellyfish Bookcase"),
+    NameEntry::new(5901, "JellyfishCandelabra", "Jellyfish Candelabra"),
+    NameEntry::new(5902, "JellyfishCandle", "Jellyfish Candle"),
+    NameEntry::new(5903, "JellyfishChair", "Jellyfish Chair"),
+    NameEntry::new(5904, "JellyfishChandelier", "Jellyfish Chandelier"),
+    NameEntry::new(5905, "JellyfishChest", "Jellyfish Chest"),
+    NameEntry::new(5906, "JellyfishClock", "Jellyfish Clock"),
+    NameEntry::new(5907, "JellyfishDoor", "Jellyfish Door"),
+    NameEntry::new(5908, "JellyfishDresser", "Jellyfish Dresser"),
+    NameEntry::new(5909, "JellyfishLamp", "Jellyfish Lamp"),
+    NameEntry::new(5910, "JellyfishLantern", "Jellyfish Lantern"),
+    NameEntry::new(5911, "JellyfishPiano", "Jellyfish Piano"),
+    NameEntry::new(5912, "JellyfishPlatform", "Jellyfish Platform"),
+    NameEntry::new(5913, "JellyfishSink", "Jellyfish Sink"),
+    NameEntry::new(5914, "JellyfishSofa", "Jellyfish Sofa"),
+    NameEntry::new(5915, "JellyfishTable", "Jellyfish Table"),
+    NameEntry::new(5916, "JellyfishToilet", "Jellyfish Toilet"),
+    NameEntry::new(5917, "JellyfishWorkbench", "Jellyfish Work Bench"),
+    NameEntry::new(5918, "Fake_JellyfishChest", "Trapped Jellyfish Chest"),
+    NameEntry::new(5919, "PineTreeBlockWall", "Pine Tree Wall"),
+    NameEntry::new(5920, "EasterBlock", "Easter Block"),
+    NameEntry::new(5921, "EasterBlockWall", "Easter Wall"),
+    NameEntry::new(5922, "GothicBrick", "Gothic Brick"),
+    NameEntry::new(5923, "GothicBrickWall", "Gothic Brick Wall"),
+    NameEntry::new(5924, "FlinxFurBlock", "Flinx Fur Block"),
+    NameEntry::new(5925, "FlinxFurBlockWall", "Flinx Fur Wall"),
+    NameEntry::new(5926, "JellyfishBlock", "Jellyfish Block"),
+    NameEntry::new(5927, "JellyfishBlockWall", "Jellyfish Wall"),
+    NameEntry::new(5928, "ToyBreakerBlock", "Toybreaker Brick"),
+    NameEntry::new(5929, "PaintingRemix", "Remix"),
+    NameEntry::new(5930, "PineWoodBlock", "Pine Wood"),
+    NameEntry::new(5931, "PineWoodBlockWall", "Pine Wood Wall"),
+    NameEntry::new(5932, "HarpyBathtub", "Harpy Bathtub"),
+    NameEntry::new(5933, "HarpyBed", "Harpy Bed"),
+    NameEntry::new(5934, "HarpyBookcase", "Harpy Bookcase"),
+    NameEntry::new(5935, "HarpyCandelabra", "Harpy Candelabra"),
+    NameEntry::new(5936, "HarpyCandle", "Harpy Candle"),
+    NameEntry::new(5937, "HarpyChair", "Harpy Chair"),
+    NameEntry::new(5938, "HarpyChandelier", "Harpy Chandelier"),
+    NameEntry::new(5939, "HarpyChest", "Harpy Chest"),
+    NameEntry::new(5940, "HarpyClock", "Harpy Clock"),
+    NameEntry::new(5941, "HarpyDoor", "Harpy Door"),
+    NameEntry::new(5942, "HarpyDresser", "Harpy Dresser"),
+    NameEntry::new(5943, "HarpyLamp", "Harpy Lamp"),
+    NameEntry::new(5944, "HarpyLantern", "Harpy Lantern"),
+    NameEntry::new(5945, "HarpyPiano", "Harpy Piano"),
+    NameEntry::new(5946, "HarpyPlatform", "Harpy Platform"),
+    NameEntry::new(5947, "HarpySink", "Harpy Sink"),
+    NameEntry::new(5948, "HarpySofa", "Harpy Sofa"),
+    NameEntry::new(5949, "HarpyTable", "Harpy Table"),
+    NameEntry::new(5950, "HarpyToilet", "Harpy Toilet"),
+    NameEntry::new(5951, "HarpyWorkbench", "Harpy Work Bench"),
+    NameEntry::new(5952, "Fake_HarpyChest", "Trapped Harpy Chest"),
+    NameEntry::new(5953, "HarpyBlock", "Harpy Block"),
+    NameEntry::new(5954, "HarpyBlockWall", "Harpy Wall"),
+    NameEntry::new(5955, "CloudBathtub", "Cloud Bathtub"),
+    NameEntry::new(5956, "CloudBed", "Cloud Bed"),
+    NameEntry::new(5957, "CloudBookcase", "Cloud Bookcase"),
+    NameEntry::new(5958, "CloudCandelabra", "Cloud Candelabra"),
+    NameEntry::new(5959, "CloudCandle", "Cloud Candle"),
+    NameEntry::new(5960, "CloudChair", "Cloud Chair"),
+    NameEntry::new(5961, "CloudChandelier", "Cloud Chandelier"),
+    NameEntry::new(5962, "CloudChest", "Cloud Chest"),
+    NameEntry::new(5963, "CloudClock", "Cloud Clock"),
+    NameEntry::new(5964, "CloudDoor", "Cloud Door"),
+    NameEntry::new(5965, "CloudDresser", "Cloud Dresser"),
+    NameEntry::new(5966, "CloudLamp", "Cloud Lamp"),
+    NameEntry::new(5967, "CloudLantern", "Cloud Lantern"),
+    NameEntry::new(5968, "CloudPiano", "Cloud Piano"),
+    NameEntry::new(5969, "CloudSink", "Cloud Sink"),
+    NameEntry::new(5970, "CloudSofa", "Cloud Sofa"),
+    NameEntry::new(5971, "CloudTable", "Cloud Table"),
+    NameEntry::new(5972, "CloudToilet", "Cloud Toilet"),
+    NameEntry::new(5973, "CloudWorkbench", "Cloud Work Bench"),
+    NameEntry::new(5974, "Fake_CloudChest", "Trapped Cloud Chest"),
+    NameEntry::new(5975, "MoonplateBathtub", "Duskware Bathtub"),
+    NameEntry::new(5976, "MoonplateBed", "Duskware Bed"),
+    NameEntry::new(5977, "MoonplateBookcase", "Duskware Bookcase"),
+    NameEntry::new(5978, "MoonplateCandelabra", "Duskware Candelabra"),
+    NameEntry::new(5979, "MoonplateCandle", "Duskware Candle"),
+    NameEntry::new(5980, "MoonplateChair", "Duskware Chair"),
+    NameEntry::new(5981, "MoonplateChandelier", "Duskware Chandelier"),
+    NameEntry::new(5982, "MoonplateChest", "Duskware Chest"),
+    NameEntry::new(5983, "MoonplateClock", "Duskware Clock"),
+    NameEntry::new(5984, "MoonplateDoor", "Duskware Door"),
+    NameEntry::new(5985, "MoonplateDresser", "Duskware Dresser"),
+    NameEntry::new(5986, "MoonplateLamp", "Duskware Lamp"),
+    NameEntry::new(5987, "MoonplateLantern", "Duskware Lantern"),
+    NameEntry::new(5988, "MoonplatePiano", "Duskware Piano"),
+    NameEntry::new(5989, "MoonplatePlatform", "Duskware Platform"),
+    NameEntry::new(5990, "MoonplateSink", "Duskware Sink"),
+    NameEntry::new(5991, "MoonplateSofa", "Duskware Sofa"),
+    NameEntry::new(5992, "MoonplateTable", "Duskware Table"),
+    NameEntry::new(5993, "MoonplateToilet", "Duskware Toilet"),
+    NameEntry::new(5994, "MoonplateWorkbench", "Duskware Work Bench"),
+    NameEntry::new(5995, "Fake_MoonplateChest", "Trapped Duskware Chest"),
+    NameEntry::new(5996, "MoonplateBlock", "Moonplate Block"),
+    NameEntry::new(5997, "MoonplateBlockWall", "Crescent Wall"),
+    NameEntry::new(5998, "LibrarianBathtub", "Librarian Bathtub"),
+    NameEntry::new(5999, "LibrarianBed", "Librarian Bed"),
+    NameEntry::new(6000, "LibrarianBookcase", "Librarian Bookcase"),
+    NameEntry::new(6001, "LibrarianCandelabra", "Librarian Candelabra"),
+    NameEntry::new(6002, "LibrarianCandle", "Librarian Candle"),
+    NameEntry::new(6003, "LibrarianChair", "Librarian Chair"),
+    NameEntry::new(6004, "LibrarianChandelier", "Librarian Chandelier"),
+    NameEntry::new(6005, "LibrarianChest", "Librarian Chest"),
+    NameEntry::new(6006, "LibrarianClock", "Librarian Clock"),
+    NameEntry::new(6007, "LibrarianDoor", "Librarian Door"),
+    NameEntry::new(6008, "LibrarianDresser", "Librarian Dresser"),
+    NameEntry::new(6009, "LibrarianLamp", "Librarian Lamp"),
+    NameEntry::new(6010, "LibrarianLantern", "Librarian Lantern"),
+    NameEntry::new(6011, "LibrarianPiano", "Librarian Piano"),
+    NameEntry::new(6012, "LibrarianPlatform", "Librarian Platform"),
+    NameEntry::new(6013, "LibrarianSink", "Librarian Sink"),
+    NameEntry::new(6014, "LibrarianSofa", "Librarian Sofa"),
+    NameEntry::new(6015, "LibrarianTable", "Librarian Table"),
+    NameEntry::new(6016, "LibrarianToilet", "Librarian Toilet"),
+    NameEntry::new(6017, "LibrarianWorkbench", "Librarian Work Bench"),
+    NameEntry::new(6018, "Fake_LibrarianChest", "Trapped Librarian Chest"),
+    NameEntry::new(6019, "LibrarianBlock", "Librarian Block"),
+    NameEntry::new(6020, "LibrarianBlockWall", "Librarian Wall"),
+    NameEntry::new(6021, "SpikeBathtub", "Spike Bathtub"),
+    NameEntry::new(6022, "SpikeBed", "Spike Bed"),
+    NameEntry::new(6023, "SpikeBookcase", "Spike Bookcase"),
+    NameEntry::new(6024, "SpikeCandelabra", "Spike Candelabra"),
+    NameEntry::new(6025, "SpikeCandle", "Spike Candle"),
+    NameEntry::new(6026, "SpikeChair", "Spike Chair"),
+    NameEntry::new(6027, "SpikeChandelier", "Spike Chandelier"),
+    NameEntry::new(6028, "SpikeChest", "Spike Chest"),
+    NameEntry::new(6029, "SpikeClock", "Spike Clock"),
+    NameEntry::new(6030, "SpikeDoor", "Spike Door"),
+    NameEntry::new(6031, "SpikeDresser", "Spike Dresser"),
+    NameEntry::new(6032, "SpikeLamp", "Spike Lamp"),
+    NameEntry::new(6033, "SpikeLantern", "Spike Lantern"),
+    NameEntry::new(6034, "SpikePiano", "Spike Piano"),
+    NameEntry::new(6035, "SpikePlatform", "Spike Platform"),
+    NameEntry::new(6036, "SpikeSink", "Spike Sink"),
+    NameEntry::new(6037, "SpikeSofa", "Spike Sofa"),
+    NameEntry::new(6038, "SpikeTable", "Spike Table"),
+    NameEntry::new(6039, "SpikeToilet", "Spike Toilet"),
+    NameEntry::new(6040, "SpikeWorkbench", "Spike Work Bench"),
+    NameEntry::new(6041, "Fake_SpikeChest", "Trapped Spike Chest"),
+    NameEntry::new(6042, "SpikeBlock", "Spike Block"),
+    NameEntry::new(6043, "SpikeBlockWall", "Spike Wall"),
+    NameEntry::new(6044, "OfficeBathtub", "Office Bathtub"),
+    NameEntry::new(6045, "OfficeBed", "Office Bed"),
+    NameEntry::new(6046, "OfficeBookcase", "Office Bookcase"),
+    NameEntry::new(6047, "OfficeCandelabra", "Office Candelabra"),
+    NameEntry::new(6048, "OfficeCandle", "Office Candle"),
+    NameEntry::new(6049, "OfficeChair", "Office Chair"),
+    NameEntry::new(6050, "OfficeChandelier", "Office Chandelier"),
+    NameEntry::new(6051, "OfficeChest", "Office Chest"),
+    NameEntry::new(6052, "OfficeClock", "Office Clock"),
+    NameEntry::new(6053, "OfficeDoor", "Office Door"),
+    NameEntry::new(6054, "OfficeDresser", "Office Dresser"),
+    NameEntry::new(6055, "OfficeLamp", "Office Lamp"),
+    NameEntry::new(6056, "OfficeLantern", "Office Lantern"),
+    NameEntry::new(6057, "OfficePiano", "Office Piano"),
+    NameEntry::new(6058, "OfficePlatform", "Office Platform"),
+    NameEntry::new(6059, "OfficeSink", "Office Sink"),
+    NameEntry::new(6060, "OfficeSofa", "Office Sofa"),
+    NameEntry::new(6061, "OfficeTable", "Office Table"),
+    NameEntry::new(6062, "OfficeToilet", "Office Toilet"),
+    NameEntry::new(6063, "OfficeWorkbench", "Office Work Bench"),
+    NameEntry::new(6064, "Fake_OfficeChest", "Trapped Office Chest"),
+    NameEntry::new(6065, "OfficeBlock", "Office Block"),
+    NameEntry::new(6066, "OfficeBlockWall", "Office Wall"),
+    NameEntry::new(6067, "ForbiddenBathtub", "Forbidden Bathtub"),
+    NameEntry::new(6068, "ForbiddenBed", "Forbidden Bed"),
+    NameEntry::new(6069, "ForbiddenBookcase", "Forbidden Bookcase"),
+    NameEntry::new(6070, "ForbiddenCandelabra", "Forbidden Candelabra"),
+    NameEntry::new(6071, "ForbiddenCandle", "Forbidden Candle"),
+    NameEntry::new(6072, "ForbiddenChair", "Forbidden Chair"),
+    NameEntry::new(6073, "ForbiddenChandelier", "Forbidden Chandelier"),
+    NameEntry::new(6074, "ForbiddenChest", "Forbidden Chest"),
+    NameEntry::new(6075, "ForbiddenClock", "Forbidden Clock"),
+    NameEntry::new(6076, "ForbiddenDoor", "Forbidden Door"),
+    NameEntry::new(6077, "ForbiddenDresser", "Forbidden Dresser"),
+    NameEntry::new(6078, "ForbiddenLamp", "Forbidden Lamp"),
+    NameEntry::new(6079, "ForbiddenLantern", "Forbidden Lantern"),
+    NameEntry::new(6080, "ForbiddenPiano", "Forbidden Piano"),
+    NameEntry::new(6081, "ForbiddenPlatform", "Forbidden Platform"),
+    NameEntry::new(6082, "ForbiddenSink", "Forbidden Sink"),
+    NameEntry::new(6083, "ForbiddenSofa", "Forbidden Sofa"),
+    NameEntry::new(6084, "ForbiddenTable", "Forbidden Table"),
+    NameEntry::new(6085, "ForbiddenToilet", "Forbidden Toilet"),
+    NameEntry::new(6086, "ForbiddenWorkbench", "Forbidden Work Bench"),
+    NameEntry::new(6087, "Fake_ForbiddenChest", "Trapped Forbidden Chest"),
+    NameEntry::new(6088, "ForbiddenBlock", "Forbidden Block"),
+    NameEntry::new(6089, "ForbiddenBlockWall", "Forbidden Wall"),
+    NameEntry::new(6090, "WaterBathtub", "Aquarium Bathtub"),
+    NameEntry::new(6091, "WaterBed", "Aquarium Bed"),
+    NameEntry::new(6092, "WaterBookcase", "Aquarium Bookcase"),
+    NameEntry::new(6093, "WaterCandelabra", "Aquarium Candelabra"),
+    NameEntry::new(6094, "WaterFurnitureCandle", "Aquarium Candle"),
+    NameEntry::new(6095, "WaterChair", "Aquarium Chair"),
+    NameEntry::new(6096, "WaterChandelier", "Aquarium Chandelier"),
+    NameEntry::new(6097, "WaterClock", "Aquarium Clock"),
+    NameEntry::new(6098, "WaterDoor", "Aquarium Door"),
+    NameEntry::new(6099, "WaterDresser", "Aquarium Dresser"),
+    NameEntry::new(6100, "WaterLamp", "Aquarium Lamp"),
+    NameEntry::new(6101, "WaterLantern", "Aquarium Lantern"),
+    NameEntry::new(6102, "WaterPiano", "Aquarium Piano"),
+    NameEntry::new(6103, "WaterPlatform", "Aquarium Platform"),
+    NameEntry::new(6104, "WaterSink", "Aquarium Sink"),
+    NameEntry::new(6105, "WaterSofa", "Aquarium Sofa"),
+    NameEntry::new(6106, "WaterTable", "Aquarium Table"),
+    NameEntry::new(6107, "WaterToilet", "Aquarium Toilet"),
+    NameEntry::new(6108, "WaterWorkbench", "Aquarium Work Bench"),
+    NameEntry::new(6109, "WaterBlock", "Aquarium Block"),
+    NameEntry::new(6110, "WaterBlockWall", "Aquarium Wall"),
+    NameEntry::new(6111, "BoulderBathtub", "Boulder Bathtub"),
+    NameEntry::new(6112, "BoulderBed", "Boulder Bed"),
+    NameEntry::new(6113, "BoulderBookcase", "Boulder Bookcase"),
+    NameEntry::new(6114, "BoulderCandelabra", "Boulder Candelabra"),
+    NameEntry::new(6115, "BoulderCandle", "Boulder Candle"),
+    NameEntry::new(6116, "BoulderChair", "Boulder Chair"),
+    NameEntry::new(6117, "BoulderChandelier", "Boulder Chandelier"),
+    NameEntry::new(6118, "BoulderChest", "Boulder Chest"),
+    NameEntry::new(6119, "BoulderClock", "Boulder Clock"),
+    NameEntry::new(6120, "BoulderDoor", "Boulder Door"),
+    NameEntry::new(6121, "BoulderDresser", "Boulder Dresser"),
+    NameEntry::new(6122, "BoulderLamp", "Boulder Lamp"),
+    NameEntry::new(6123, "BoulderLantern", "Boulder Lantern"),
+    NameEntry::new(6124, "BoulderPiano", "Boulder Piano"),
+    NameEntry::new(6125, "BoulderPlatform", "Boulder Platform"),
+    NameEntry::new(6126, "BoulderSink", "Boulder Sink"),
+    NameEntry::new(6127, "BoulderSofa", "Boulder Sofa"),
+    NameEntry::new(6128, "BoulderTable", "Boulder Table"),
+    NameEntry::new(6129, "BoulderToilet", "Boulder Toilet"),
+    NameEntry::new(6130, "BoulderWorkbench", "Boulder Work Bench"),
+    NameEntry::new(6131, "Fake_BoulderChest", "Trapped Boulder Chest"),
+    NameEntry::new(6132, "BoulderBlock", "Boulder Block"),
+    NameEntry::new(6133, "BoulderBlockWall", "Boulder Wall"),
+    NameEntry::new(6134, "DamagingSpikeBlock", "Sharp Spike Block"),
+    NameEntry::new(6135, "DemonAltarIcon", "Demon Altar"),
+    NameEntry::new(6136, "CrimsonAltarIcon", "Crimson Altar"),
+    NameEntry::new(6137, "LunasHead", "Luna's Runic Pixie Hood"),
+    NameEntry::new(6138, "LunasBody", "Luna's Runic Pixie Shirt"),
+    NameEntry::new(6139, "LunasLegs", "Luna's Runic Pixie Pants"),
+    NameEntry::new(6140, "LunasWings", "Luna's Runic Pixie Wings"),
+    NameEntry::new(6141, "LunasCloak", "Luna's Runic Pixie Cloak"),
+    NameEntry::new(6142, "PalworldChilletEgg", "Huge Dragon Egg"),
+    NameEntry::new(6143, "FoxparksTagEffect", "FoxparksTagEffect"),
+    NameEntry::new(6144, "MusicBoxSkeletron", "Music Box (Skeletron)"),
+];
+
+pub const TILE_NAMES: &[NameEntry] = &[
+    NameEntry::new(0, "Dirt", "Dirt Block"),
+    NameEntry::new(1, "Stone", "Stone Block"),
+    NameEntry::new(2, "Grass", "Grass Block"),
+    NameEntry::new(3, "Plants", "Grass Flowers"),
+    NameEntry::new(4, "Torches", "Torch"),
+    NameEntry::new(5, "Trees", "Tree"),
+    NameEntry::new(6, "Iron", "Iron Ore"),
+    NameEntry::new(7, "Copper", "Copper Ore"),
+    NameEntry::new(8, "Gold", "Gold Ore"),
+    NameEntry::new(9, "Silver", "Silver Ore"),
+    NameEntry::new(10, "ClosedDoor", "Door Closed"),
+    NameEntry::new(11, "OpenDoor", "Door Open"),
+    NameEntry::new(12, "Heart", "Crystal Heart"),
+    NameEntry::new(13, "Bottles", "Bottle"),
+    NameEntry::new(14, "Tables", "Table"),
+    NameEntry::new(15, "Chairs", "Chair"),
+    NameEntry::new(16, "Anvils", "Iron Anvil"),
+    NameEntry::new(17, "Furnaces", "Furnace"),
+    NameEntry::new(18, "WorkBenches", "Work Bench"),
+    NameEntry::new(19, "Platforms", "Platform"),
+    NameEntry::new(20, "Saplings", "Acorn"),
+    NameEntry::new(21, "Containers", "Chest"),
+    NameEntry::new(22, "Demonite", "Demonite Ore"),
+    NameEntry::new(23, "CorruptGrass", "Corrupt Grass Block"),
+    NameEntry::new(24, "CorruptPlants", "Corruption Plants"),
+    NameEntry::new(25, "Ebonstone", "Ebonstone Block"),
+    NameEntry::new(26, "DemonAltar", "Altar"),
+    NameEntry::new(27, "Sunflower", "Sunflower"),
+    NameEntry::new(28, "Pots", "Pot"),
+    NameEntry::new(29, "PiggyBank", "Piggy Bank"),
+    NameEntry::new(30, "WoodBlock", "Wood"),
+    NameEntry::new(31, "ShadowOrbs", "Orb Heart"),
+    NameEntry::new(32, "CorruptThorns", "Corruption Thorns"),
+    NameEntry::new(33, "Candles", "Candle"),
+    NameEntry::new(34, "Chandeliers", "Chandelier"),
+    NameEntry::new(35, "Jackolanterns", "Jack 'O Lantern"),
+    NameEntry::new(36, "Presents", "Present"),
+    NameEntry::new(37, "Meteorite", "Meteorite"),
+    NameEntry::new(38, "GrayBrick", "Gray Brick"),
+    NameEntry::new(39, "RedBrick", "Red Brick"),
+    NameEntry::new(40, "ClayBlock", "Clay Block"),
+    NameEntry::new(41, "BlueDungeonBrick", "Blue Brick"),
+    NameEntry::new(42, "HangingLanterns", "Chain Lantern"),
+    NameEntry::new(43, "GreenDungeonBrick", "Green Brick"),
+    NameEntry::new(44, "PinkDungeonBrick", "Pink Brick"),
+    NameEntry::new(45, "GoldBrick", "Gold Brick"),
+    NameEntry::new(46, "SilverBrick", "Silver Brick"),
+    NameEntry::new(47, "CopperBrick", "Copper Brick"),
+    NameEntry::new(48, "Spikes", "Spike"),
+    NameEntry::new(49, "WaterCandle", "Water Candle"),
+    NameEntry::new(50, "Books", "Book"),
+    NameEntry::new(51, "Cobweb", "Cobweb"),
+    NameEntry::new(52, "Vines", "Vines"),
+    NameEntry::new(53, "Sand", "Sand Block"),
+    NameEntry::new(54, "Glass", "Glass"),
+    NameEntry::new(55, "Signs", "Sign"),
+    NameEntry::new(56, "Obsidian", "Obsidian"),
+    NameEntry::new(57, "Ash", "Ash Block"),
+    NameEntry::new(58, "Hellstone", "Hellstone"),
+    NameEntry::new(59, "Mud", "Mud Block"),
+    NameEntry::new(60, "JungleGrass", "Jungle Grass Block"),
+    NameEntry::new(61, "JunglePlants", "Jungle Plants"),
+    NameEntry::new(62, "JungleVines", "Jungle Vines"),
+    NameEntry::new(63, "Sapphire", "Gem Sapphire"),
+    NameEntry::new(64, "Ruby", "Gem Ruby"),
+    NameEntry::new(65, "Emerald", "Gem Emerald"),
+    NameEntry::new(66, "Topaz", "Gem Topaz"),
+    NameEntry::new(67, "Amethyst", "Gem Amethyst"),
+    NameEntry::new(68, "Diamond", "Gem Diamond"),
+    NameEntry::new(69, "JungleThorns", "Jungle Thorns"),
+    NameEntry::new(70, "MushroomGrass", "Mushroom Grass Block"),
+    NameEntry::new(71, "MushroomPlants", "Mushroom Plant"),
+    NameEntry::new(72, "MushroomTrees", "Mushroom Tree"),
+    NameEntry::new(73, "Plants2", "Tall Grass"),
+    NameEntry::new(74, "JunglePlants2", "Tall Jungle Grass"),
+    NameEntry::new(75, "ObsidianBrick", "Obsidian Brick"),
+    NameEntry::new(76, "HellstoneBrick", "Hellstone Brick"),
+    NameEntry::new(77, "Hellforge", "Hellforge"),
+    NameEntry::new(78, "ClayPot", "Clay Pot"),
+    NameEntry::new(79, "Beds", "Bed"),
+    NameEntry::new(80, "Cactus", "Cactus"),
+    NameEntry::new(81, "Coral", "Coral"),
+    NameEntry::new(82, "ImmatureHerbs", "Daybloom Seeds"),
+    NameEntry::new(83, "MatureHerbs", "Herb Mature"),
+    NameEntry::new(84, "BloomingHerbs", "Herb Bloom"),
+    NameEntry::new(85, "Tombstones", "Grave Marker"),
+    NameEntry::new(86, "Loom", "Loom"),
+    NameEntry::new(87, "Pianos", "Piano"),
+    NameEntry::new(88, "Dressers", "Dresser"),
+    NameEntry::new(89, "Benches", "Bench"),
+    NameEntry::new(90, "Bathtubs", "Bathtub"),
+    NameEntry::new(91, "Banners", "Banner"),
+    NameEntry::new(92, "Lampposts", "Lamp Post"),
+    NameEntry::new(93, "Lamps", "Lamp"),
+    NameEntry::new(94, "Kegs", "Keg"),
+    NameEntry::new(95, "ChineseLanterns", "Chinese Lantern"),
+    NameEntry::new(96, "CookingPots", "Cooking Pot"),
+    NameEntry::new(97, "Safes", "Safe"),
+    NameEntry::new(98, "SkullLanterns", "Skull Lantern"),
+    NameEntry::new(99, "TrashCan", "Trash Can"),
+    NameEntry::new(100, "Candelabras", "Candelabra"),
+    NameEntry::new(101, "Bookcases", "Bookcase"),
+    NameEntry::new(102, "Thrones", "Throne"),
+    NameEntry::new(103, "Bowls", "Bowl"),
+    NameEntry::new(104, "GrandfatherClocks", "Grandfather Clock"),
+    NameEntry::new(105, "Statues", "Statue"),
+    NameEntry::new(106, "Sawmill", "Sawmill"),
+    NameEntry::new(107, "Cobalt", "Cobalt Ore"),
+    NameEntry::new(108, "Mythril", "Mythril Ore"),
+    NameEntry::new(109, "HallowedGrass", "Hallowed Grass Block"),
+    NameEntry::new(110, "HallowedPlants", "Hallowed Plants"),
+    NameEntry::new(111, "Adamantite", "Adamantite Ore"),
+    NameEntry::new(112, "Ebonsand", "Ebonsand Block"),
+    NameEntry::new(113, "HallowedPlants2", "Hallowed Tall Plants"),
+    NameEntry::new(114, "TinkerersWorkbench", "Tinkerer's Workshop"),
+    NameEntry::new(115, "HallowedVines", "Hallowed Vines"),
+    NameEntry::new(116, "Pearlsand", "Pearlsand Block"),
+    NameEntry::new(117, "Pearlstone", "Pearlstone Block"),
+    NameEntry::new(118, "PearlstoneBrick", "Pearlstone Brick"),
+    NameEntry::new(119, "IridescentBrick", "Iridescent Brick"),
+    NameEntry::new(120, "Mudstone", "Mudstone Block"),
+    NameEntry::new(121, "CobaltBrick", "Cobalt Brick"),
+    NameEntry::new(122, "MythrilBrick", "Mythril Brick"),
+    NameEntry::new(123, "Silt", "Silt Block"),
+    NameEntry::new(124, "WoodenBeam", "Wooden Beam"),
+    NameEntry::new(125, "CrystalBall", "Crystal Ball"),
+    NameEntry::new(126, "DiscoBall", "Disco Ball"),
+    NameEntry::new(127, "MagicalIceBlock", "Ice (Ice Rod)"),
+    NameEntry::new(128, "Mannequin", "Mannequin"),
+    NameEntry::new(129, "Crystals", "Crystal Shard"),
+    NameEntry::new(130, "ActiveStoneBlock", "Active Stone Block"),
+    NameEntry::new(131, "InactiveStoneBlock", "Inactive Stone Block"),
+    NameEntry::new(132, "Lever", "Lever"),
+    NameEntry::new(133, "AdamantiteForge", "Adamantite Forge"),
+    NameEntry::new(134, "MythrilAnvil", "Mythril Anvil"),
+    NameEntry::new(135, "PressurePlates", "Pressure Plate"),
+    NameEntry::new(136, "Switches", "Switch"),
+    NameEntry::new(137, "Traps", "Dart Trap"),
+    NameEntry::new(138, "Boulder", "Boulder"),
+    NameEntry::new(139, "MusicBoxes", "Music Box (Overworld Day)"),
+    NameEntry::new(140, "DemoniteBrick", "Demonite Brick"),
+    NameEntry::new(141, "Explosives", "Explosives"),
+    NameEntry::new(142, "InletPump", "Inlet Pump"),
+    NameEntry::new(143, "OutletPump", "Outlet Pump"),
+    NameEntry::new(144, "Timers", "1 Second Timer"),
+    NameEntry::new(145, "CandyCaneBlock", "Candy Cane Block"),
+    NameEntry::new(146, "GreenCandyCaneBlock", "Green Candy Cane Block"),
+    NameEntry::new(147, "SnowBlock", "Snow Block"),
+    NameEntry::new(148, "SnowBrick", "Snow Brick"),
+    NameEntry::new(149, "HolidayLights", "Blue Light"),
+    NameEntry::new(150, "AdamantiteBeam", "Adamantite Beam"),
+    NameEntry::new(151, "SandstoneBrick", "Sandstone Brick"),
+    NameEntry::new(152, "EbonstoneBrick", "Ebonstone Brick"),
+    NameEntry::new(153, "RedStucco", "Red Stucco"),
+    NameEntry::new(154, "YellowStucco", "Yellow Stucco"),
+    NameEntry::new(155, "GreenStucco", "Green Stucco"),
+    NameEntry::new(156, "GrayStucco", "Gray Stucco"),
+    NameEntry::new(157, "Ebonwood", "Ebonwood"),
+    NameEntry::new(158, "RichMahogany", "Rich Mahogany"),
+    NameEntry::new(159, "Pearlwood", "Pearlwood"),
+    NameEntry::new(160, "RainbowBrick", "Rainbow Brick"),
+    NameEntry::new(161, "IceBlock", "Ice Block"),
+    NameEntry::new(162, "BreakableIce", "Thin Ice"),
+    NameEntry::new(163, "CorruptIce", "Purple Ice Block"),
+    NameEntry::new(164, "HallowedIce", "Pink Ice Block"),
+    NameEntry::new(165, "Stalactite", "Large Deco"),
+    NameEntry::new(166, "Tin", "Tin Ore"),
+    NameEntry::new(167, "Lead", "Lead Ore"),
+    NameEntry::new(168, "Tungsten", "Tungsten Ore"),
+    NameEntry::new(169, "Platinum", "Platinum Ore"),
+    NameEntry::new(170, "PineTree", "Pine Tree Block"),
+    NameEntry::new(171, "ChristmasTree", "Christmas Tree"),
+    NameEntry::new(172, "Sinks", "Sink"),
+    NameEntry::new(173, "PlatinumCandelabra", "Platinum Candelabra"),
+    NameEntry::new(174, "PlatinumCandle", "Platinum Candle"),
+    NameEntry::new(175, "TinBrick", "Tin Brick"),
+    NameEntry::new(176, "TungstenBrick", "Tungsten Brick"),
+    NameEntry::new(177, "PlatinumBrick", "Platinum Brick"),
+    NameEntry::new(178, "ExposedGems", "Sapphire"),
+    NameEntry::new(179, "GreenMoss", "Green Moss"),
+    NameEntry::new(180, "BrownMoss", "Yellow Moss"),
+    NameEntry::new(181, "RedMoss", "Red Moss"),
+    NameEntry::new(182, "BlueMoss", "Blue Moss"),
+    NameEntry::new(183, "PurpleMoss", "Purple Moss"),
+    NameEntry::new(184, "LongMoss", "Moss Plants"),
+    NameEntry::new(185, "SmallPiles", "Small Decos"),
+    NameEntry::new(186, "LargePiles", "3x2 Decos"),
+    NameEntry::new(187, "LargePiles2", "3x2 Decos"),
+    NameEntry::new(188, "CactusBlock", "Cactus"),
+    NameEntry::new(189, "Cloud", "Cloud"),
+    NameEntry::new(190, "MushroomBlock", "Glowing Mushroom"),
+    NameEntry::new(191, "LivingWood", "Living Wood Wand"),
+    NameEntry::new(192, "LeafBlock", "Leaf Wand"),
+    NameEntry::new(193, "SlimeBlock", "Slime Block"),
+    NameEntry::new(194, "BoneBlock", "Bone Block"),
+    NameEntry::new(195, "FleshBlock", "Flesh Block"),
+    NameEntry::new(196, "RainCloud", "Rain Cloud"),
+    NameEntry::new(197, "FrozenSlimeBlock", "Frozen Slime Block"),
+    NameEntry::new(198, "Asphalt", "Asphalt Block"),
+    NameEntry::new(199, "CrimsonGrass", "Crimson Grass Block"),
+    NameEntry::new(200, "FleshIce", "Red Ice Block"),
+    NameEntry::new(201, "CrimsonPlants", "Crimson Grass"),
+    NameEntry::new(202, "Sunplate", "Sunplate Block"),
+    NameEntry::new(203, "Crimstone", "Crimstone Block"),
+    NameEntry::new(204, "Crimtane", "Crimtane Ore"),
+    NameEntry::new(205, "CrimsonVines", "Crimson Vine"),
+    NameEntry::new(206, "IceBrick", "Ice Brick"),
+    NameEntry::new(207, "WaterFountain", "Water Fountain"),
+    NameEntry::new(208, "Shadewood", "Shadewood"),
+    NameEntry::new(209, "Cannon", "Cannon"),
+    NameEntry::new(210, "LandMine", "Land Mine"),
+    NameEntry::new(211, "Chlorophyte", "Chlorophyte Ore"),
+    NameEntry::new(212, "SnowballLauncher", "Snowball Launcher"),
+    NameEntry::new(213, "Rope", "Rope"),
+    NameEntry::new(214, "Chain", "Chain"),
+    NameEntry::new(215, "Campfire", "Campfire"),
+    NameEntry::new(216, "Firework", "Rocket"),
+    NameEntry::new(217, "Blendomatic", "Blend-O-Matic"),
+    NameEntry::new(218, "MeatGrinder", "Meat Grinder"),
+    NameEntry::new(219, "Extractinator", "Extractinator"),
+    NameEntry::new(220, "Solidifier", "Solidifier"),
+    NameEntry::new(221, "Palladium", "Palladium Ore"),
+    NameEntry::new(222, "Orichalcum", "Orichalcum Ore"),
+    NameEntry::new(223, "Titanium", "Titanium Ore"),
+    NameEntry::new(224, "Slush", "Slush Block"),
+    NameEntry::new(225, "Hive", "Hive Wand"),
+    NameEntry::new(226, "LihzahrdBrick", "Lihzahrd Brick"),
+    NameEntry::new(227, "DyePlants", "Dye Plant"),
+    NameEntry::new(228, "DyeVat", "Dye Vat"),
+    NameEntry::new(229, "HoneyBlock", "Honey Block"),
+    NameEntry::new(230, "CrispyHoneyBlock", "Crispy Honey Block"),
+    NameEntry::new(231, "Larva", "Larva"),
+    NameEntry::new(232, "WoodenSpikes", "Wooden Spike"),
+    NameEntry::new(233, "PlantDetritus", "Jungle Vegetation"),
+    NameEntry::new(234, "Crimsand", "Crimsand Block"),
+    NameEntry::new(235, "Teleporter", "Teleporter"),
+    NameEntry::new(236, "LifeFruit", "Life Fruit Plant"),
+    NameEntry::new(237, "LihzahrdAltar", "Lihzahrd Altar"),
+    NameEntry::new(238, "PlanteraBulb", "Plantera's Bulb"),
+    NameEntry::new(239, "MetalBars", "Gold Bar"),
+    NameEntry::new(240, "Painting3X3", "3x3 Wall Hangings"),
+    NameEntry::new(241, "Painting4X3", "Catacomb"),
+    NameEntry::new(242, "Painting6X4", "6x4 Painting"),
+    NameEntry::new(243, "ImbuingStation", "Imbuing Station"),
+    NameEntry::new(244, "BubbleMachine", "Bubble Machine"),
+    NameEntry::new(245, "Painting2X3", "2x3 Painting"),
+    NameEntry::new(246, "Painting3X2", "3x2 Painting"),
+    NameEntry::new(247, "Autohammer", "Autohammer"),
+    NameEntry::new(248, "PalladiumColumn", "Palladium Column"),
+    NameEntry::new(249, "BubblegumBlock", "Bubblegum Block"),
+    NameEntry::new(250, "Titanstone", "Titanstone Block"),
+    NameEntry::new(251, "PumpkinBlock", "Pumpkin"),
+    NameEntry::new(252, "HayBlock", "Hay"),
+    NameEntry::new(253, "SpookyWood", "Spooky Wood"),
+    NameEntry::new(254, "Pumpkins", "Pumpkin Plant"),
+    NameEntry::new(255, "AmethystGemsparkOff", "Amethyst Gemspark Block (Offline)"),
+    NameEntry::new(256, "TopazGemsparkOff", "Topaz Gemspark Block (Offline)"),
+    NameEntry::new(257, "SapphireGemsparkOff", "Sapphire Gemspark Block (Offline)"),
+    NameEntry::new(258, "EmeraldGemsparkOff", "Emerald Gemspark Block (Offline)"),
+    NameEntry::new(259, "RubyGemsparkOff", "Ruby Gemspark Block (Offline)"),
+    NameEntry::new(260, "DiamondGemsparkOff", "Diamond Gemspark Block (Offline)"),
+    NameEntry::new(261, "AmberGemsparkOff", "Amber Gemspark Block (Offline)"),
+    NameEntry::new(262, "AmethystGemspark", "Amethyst Gemspark Block"),
+    NameEntry::new(263, "TopazGemspark", "Topaz Gemspark Block"),
+    NameEntry::new(264, "SapphireGemspark", "Sapphire Gemspark Block"),
+    NameEntry::new(265, "EmeraldGemspark", "Emerald Gemspark Block"),
+    NameEntry::new(266, "RubyGemspark", "Ruby Gemspark Block"),
+    NameEntry::new(267, "DiamondGemspark", "Diamond Gemspark Block"),
+    NameEntry::new(268, "AmberGemspark", "Amber Gemspark Block"),
+    NameEntry::new(269, "Womannequin", "Womannequin"),
+    NameEntry::new(270, "FireflyinaBottle", "Firefly in a Bottle"),
+    NameEntry::new(271, "LightningBuginaBottle", "Lightning Bug in a Bottle"),
+    NameEntry::new(272, "Cog", "Cog"),
+    NameEntry::new(273, "StoneSlab", "Stone Slab"),
+    NameEntry::new(274, "SandStoneSlab", "Sandstone Slab"),
+    NameEntry::new(275, "BunnyCage", "Bunny Cage"),
+    NameEntry::new(276, "SquirrelCage", "Squirrel Cage"),
+    NameEntry::new(277, "MallardDuckCage", "Mallard Duck Cage"),
+    NameEntry::new(278, "DuckCage", "Duck Cage"),
+    NameEntry::new(279, "BirdCage", "Bird Cage"),
+    NameEntry::new(280, "BlueJay", "Blue Jay Cage"),
+    NameEntry::new(281, "CardinalCage", "Cardinal Cage"),
+    NameEntry::new(282, "FishBowl", "Fish Bowl"),
+    NameEntry::new(283, "HeavyWorkBench", "Heavy Work Bench"),
+    NameEntry::new(284, "CopperPlating", "Copper Plating"),
+    NameEntry::new(285, "SnailCage", "Snail Cage"),
+    NameEntry::new(286, "GlowingSnailCage", "Glowing Snail Cage"),
+    NameEntry::new(287, "AmmoBox", "Ammo Box"),
+    NameEntry::new(288, "MonarchButterflyJar", "Monarch Butterfly Jar"),
+    NameEntry::new(289, "PurpleEmperorButterflyJar", "Purple Emperor Butterfly Jar"),
+    NameEntry::new(290, "RedAdmiralButterflyJar", "Red Admiral Butterfly Jar"),
+    NameEntry::new(291, "UlyssesButterflyJar", "Ulysses Butterfly Jar"),
+    NameEntry::new(292, "SulphurButterflyJar", "Sulphur Butterfly Jar"),
+    NameEntry::new(293, "TreeNymphButterflyJar", "Tree Nymph Butterfly Jar"),
+    NameEntry::new(294, "ZebraSwallowtailButterflyJar", "Zebra Swallowtail Butterfly Jar"),
+    NameEntry::new(295, "JuliaButterflyJar", "Julia Butterfly Jar"),
+    NameEntry::new(296, "ScorpionCage", "Scorpion Cage"),
+    NameEntry::new(297, "BlackScorpionCage", "Black Scorpion Cage"),
+    NameEntry::new(298, "FrogCage", "Frog Cage"),
+    NameEntry::new(299, "MouseCage", "Mouse Cage"),
+    NameEntry::new(300, "BoneWelder", "Bone Welder"),
+    NameEntry::new(301, "FleshCloningVat", "Flesh Cloning Vat"),
+    NameEntry::new(302, "GlassKiln", "Glass Kiln"),
+    NameEntry::new(303, "LihzahrdFurnace", "Lihzahrd Furnace"),
+    NameEntry::new(304, "LivingLoom", "Living Loom"),
+    NameEntry::new(305, "SkyMill", "Sky Mill"),
+    NameEntry::new(306, "IceMachine", "Ice Machine"),
+    NameEntry::new(307, "SteampunkBoiler", "Steampunk Boiler"),
+    NameEntry::new(308, "HoneyDispenser", "Honey Dispenser"),
+    NameEntry::new(309, "PenguinCage", "Penguin Cage"),
+    NameEntry::new(310, "WormCage", "Worm Cage"),
+    NameEntry::new(311, "DynastyWood", "Dynasty Wood"),
+    NameEntry::new(312, "RedDynastyShingles", "Red Dynasty Shingles"),
+    NameEntry::new(313, "BlueDynastyShingles", "Blue Dynasty Shingles"),
+    NameEntry::new(314, "MinecartTrack", "Minecart Track"),
+    NameEntry::new(315, "Coralstone", "Coralstone Block"),
+    NameEntry::new(316, "BlueJellyfishBowl", "Blue Jellyfish Jar"),
+    NameEntry::new(317, "GreenJellyfishBowl", "Green Jellyfish Jar"),
+    NameEntry::new(318, "PinkJellyfishBowl", "Pink Jellyfish Jar"),
+    NameEntry::new(319, "ShipInABottle", "Ship in a Bottle"),
+    NameEntry::new(320, "SeaweedPlanter", "Seaweed Planter"),
+    NameEntry::new(321, "BorealWood", "Boreal Wood"),
+    NameEntry::new(322, "PalmWood", "Palm Wood"),
+    NameEntry::new(323, "PalmTree", "Palm Tree"),
+    NameEntry::new(324, "BeachPiles", "Seashell"),
+    NameEntry::new(325, "TinPlating", "Tin Plating"),
+    NameEntry::new(326, "Waterfall", "Waterfall Block"),
+    NameEntry::new(327, "Lavafall", "Lavafall Block"),
+    NameEntry::new(328, "Confetti", "Confetti Block"),
+    NameEntry::new(329, "ConfettiBlack", "Midnight Confetti Block"),
+    NameEntry::new(330, "CopperCoinPile", "Copper Coin Pile"),
+    NameEntry::new(331, "SilverCoinPile", "Silver Coin Pile"),
+    NameEntry::new(332, "GoldCoinPile", "Gold Coin Pile"),
+    NameEntry::new(333, "PlatinumCoinPile", "Platinum Coin Pile"),
+    NameEntry::new(334, "WeaponsRack", "Weapon Rack"),
+    NameEntry::new(335, "FireworksBox", "Fireworks Box"),
+    NameEntry::new(336, "LivingFire", "Living Fire Block"),
+    NameEntry::new(337, "AlphabetStatues", "Text Statue"),
+    NameEntry::new(338, "FireworkFountain", "Firework Fountain"),
+    NameEntry::new(339, "GrasshopperCage", "Grasshopper Cage"),
+    NameEntry::new(340, "LivingCursedFire", "Living Cursed Fire"),
+    NameEntry::new(341, "LivingDemonFire", "Living Demon Fire"),
+    NameEntry::new(342, "LivingFrostFire", "Living Frost Fire"),
+    NameEntry::new(343, "LivingIchor", "Living Ichor"),
+    NameEntry::new(344, "LivingUltrabrightFire", "Living Ultrabright Fire"),
+    NameEntry::new(345, "Honeyfall", "Honeyfall Block"),
+    NameEntry::new(346, "ChlorophyteBrick", "Chlorophyte Brick"),
+    NameEntry::new(347, "CrimtaneBrick", "Crimtane Brick"),
+    NameEntry::new(348, "ShroomitePlating", "Shroomite Plating"),
+    NameEntry::new(349, "MushroomStatue", "Mushroom Statue"),
+    NameEntry::new(350, "MartianConduitPlating", "Martian Conduit Plating"),
+    NameEntry::new(351, "ChimneySmoke", "Chimney Smoke"),
+    NameEntry::new(352, "CrimsonThorns", "Crimtane Thorns"),
+    NameEntry::new(353, "VineRope", "Vine Rope"),
+    NameEntry::new(354, "BewitchingTable", "Bewitching Table"),
+    NameEntry::new(355, "AlchemyTable", "Alchemy Table"),
+    NameEntry::new(356, "Sundial", "Enchanted Sundial"),
+    NameEntry::new(357, "MarbleBlock", "Marble Block"),
+    NameEntry::new(358, "GoldBirdCage", "Gold Bird Cage"),
+    NameEntry::new(359, "GoldBunnyCage", "Gold Bunny Cage"),
+    NameEntry::new(360, "GoldButterflyCage", "Gold Butterfly Cage"),
+    NameEntry::new(361, "GoldFrogCage", "Gold Frog Cage"),
+    NameEntry::new(362, "GoldGrasshopperCage", "Gold Grasshopper Cage"),
+    NameEntry::new(363, "GoldMouseCage", "Gold Mouse Cage"),
+    NameEntry::new(364, "GoldWormCage", "Gold Worm Cage"),
+    NameEntry::new(365, "SilkRope", "Silk Rope"),
+    NameEntry::new(366, "WebRope", "Web Rope"),
+    NameEntry::new(367, "Marble", "Marble"),
+    NameEntry::new(368, "Granite", "Granite"),
+    NameEntry::new(369, "GraniteBlock", "Granite Block"),
+    NameEntry::new(370, "MeteoriteBrick", "Meteorite Brick"),
+    NameEntry::new(371, "PinkSlimeBlock", "Pink Slime Block"),
+    NameEntry::new(372, "PeaceCandle", "Peace Candle"),
+    NameEntry::new(373, "WaterDrip", "Water Drip"),
+    NameEntry::new(374, "LavaDrip", "Lava Drip"),
+    NameEntry::new(375, "HoneyDrip", "Honey Drip"),
+    NameEntry::new(376, "FishingCrate", "Fishing Crate"),
+    NameEntry::new(377, "SharpeningStation", "Sharpening Station"),
+    NameEntry::new(378, "TargetDummy", "Target Dummy"),
+    NameEntry::new(379, "Bubble", "Bubble"),
+    NameEntry::new(380, "PlanterBox", "Planter Box"),
+    NameEntry::new(381, "LavaMoss", "Lava Moss"),
+    NameEntry::new(382, "VineFlowers", "Vine Flowers"),
+    NameEntry::new(383, "LivingMahogany", "Living Mahogany"),
+    NameEntry::new(384, "LivingMahoganyLeaves", "Living MahoganyLeaves"),
+    NameEntry::new(385, "CrystalBlock", "Crystal Block"),
+    NameEntry::new(386, "TrapdoorOpen", "Trapdoor Open"),
+    NameEntry::new(387, "TrapdoorClosed", "Trapdoor Closed"),
+    NameEntry::new(388, "TallGateClosed", "Tall Gate Closed"),
+    NameEntry::new(389, "TallGateOpen", "Tall Gate Open"),
+    NameEntry::new(390, "LavaLamp", "Lava Lamp"),
+    NameEntry::new(391, "CageEnchantedNightcrawler", "Cage Enchanted Nightcrawler"),
+    NameEntry::new(392, "CageBuggy", "Buggy Cage"),
+    NameEntry::new(393, "CageGrubby", "Grubby Cage"),
+    NameEntry::new(394, "CageSluggy", "Sluggy Cage"),
+    NameEntry::new(395, "ItemFrame", "Item Frame"),
+    NameEntry::new(396, "Sandstone", "Sandstone"),
+    NameEntry::new(397, "HardenedSand", "Hardened Sand"),
+    NameEntry::new(398, "CorruptHardenedSand", "Corrupt Hardened Sand"),
+    NameEntry::new(399, "CrimsonHardenedSand", "Crimson Hardened Sand"),
+    NameEntry::new(400, "CorruptSandstone", "Corrupt Sandstone"),
+    NameEntry::new(401, "CrimsonSandstone", "Crimson Sandstone"),
+    NameEntry::new(402, "HallowHardenedSand", "Hallow Hardened Sand"),
+    NameEntry::new(403, "HallowSandstone", "Hallow Sandstone"),
+    NameEntry::new(404, "DesertFossil", "Desert Fossil"),
+    NameEntry::new(405, "Fireplace", "Fireplace"),
+    NameEntry::new(406, "Chimney", "Chimney"),
+    NameEntry::new(407, "FossilOre", "Fossil Ore"),
+    NameEntry::new(408, "LunarOre", "Lunar Ore"),
+    NameEntry::new(409, "LunarBrick", "Lunar Brick"),
+    NameEntry::new(410, "LunarMonolith", "Lunar Monolith"),
+    NameEntry::new(411, "Detonator", "Detonator"),
+    NameEntry::new(412, "LunarCraftingStation", "Lunar Crafting Station"),
+    NameEntry::new(413, "SquirrelOrangeCage", "Red Squirrel Cage"),
+    NameEntry::new(414, "SquirrelGoldCage", "Gold Squirrel Cage"),
+    NameEntry::new(415, "LunarBlockSolar", "Lunar Block Solar"),
+    NameEntry::new(416, "LunarBlockVortex", "Lunar Block Vortex"),
+    NameEntry::new(417, "LunarBlockNebula", "Lunar Block Nebula"),
+    NameEntry::new(418, "LunarBlockStardust", "Lunar Block Stardust"),
+    NameEntry::new(419, "LogicGateLamp", "Logic Gate Lamp"),
+    NameEntry::new(420, "LogicGate", "Logic Gate"),
+    NameEntry::new(421, "ConveyorBeltLeft", "Conveyor Belt Left"),
+    NameEntry::new(422, "ConveyorBeltRight", "Conveyor Belt Right"),
+    NameEntry::new(423, "LogicSensor", "Logic Sensor"),
+    NameEntry::new(424, "WirePipe", "Wire Pipe"),
+    NameEntry::new(425, "AnnouncementBox", "Announcement Box"),
+    NameEntry::new(426, "TeamBlockRed", "Team Block Red"),
+    NameEntry::new(427, "TeamBlockRedPlatform", "Team Block Red Platform"),
+    NameEntry::new(428, "WeightedPressurePlate", "Weighted Pressure Plate"),
+    NameEntry::new(429, "WireBulb", "Wire Bulb"),
+    NameEntry::new(430, "TeamBlockGreen", "Team Block Green"),
+    NameEntry::new(431, "TeamBlockBlue", "Team Block Blue"),
+    NameEntry::new(432, "TeamBlockYellow", "Team Block Yellow"),
+    NameEntry::new(433, "TeamBlockPink", "Team Block Pink"),
+    NameEntry::new(434, "TeamBlockWhite", "Team Block White"),
+    NameEntry::new(435, "TeamBlockGreenPlatform", "Team Block Green Platform"),
+    NameEntry::new(436, "TeamBlockBluePlatform", "Team Block Blue Platform"),
+    NameEntry::new(437, "TeamBlockYellowPlatform", "Team Block Yellow Platform"),
+    NameEntry::new(438, "TeamBlockPinkPlatform", "Team Block Pink Platform"),
+    NameEntry::new(439, "TeamBlockWhitePlatform", "Team Block White Platform"),
+    NameEntry::new(440, "GemLocks", "Gem Locks"),
+    NameEntry::new(441, "FakeContainers", "Fake Containers"),
+    NameEntry::new(442, "ProjectilePressurePad", "Projectile Pressure Pad"),
+    NameEntry::new(443, "GeyserTrap", "Geyser Trap"),
+    NameEntry::new(444, "BeeHive", "Bee Hive"),
+    NameEntry::new(445, "PixelBox", "Pixel Box"),
+    NameEntry::new(446, "SillyBalloonPink", "Silly Balloon Pink"),
+    NameEntry::new(447, "SillyBalloonPurple", "Silly Balloon Purple"),
+    NameEntry::new(448, "SillyBalloonGreen", "Silly Balloon Green"),
+    NameEntry::new(449, "SillyStreamerBlue", "Silly Streamer Blue"),
+    NameEntry::new(450, "SillyStreamerGreen", "Silly Streamer Green"),
+    NameEntry::new(451, "SillyStreamerPink", "Silly Streamer Pink"),
+    NameEntry::new(452, "SillyBalloonMachine", "Silly Balloon Machine"),
+    NameEntry::new(453, "SillyBalloonTile", "Silly Balloon Tile"),
+    NameEntry::new(454, "Pigronata", "Pigronata"),
+    NameEntry::new(455, "PartyMonolith", "Party Monolith"),
+    NameEntry::new(456, "PartyBundleOfBalloonTile", "Party Bundle Of Balloon Tile"),
+    NameEntry::new(457, "PartyPresent", "Party Present"),
+    NameEntry::new(458, "SandFallBlock", "Sand Fall Block"),
+    NameEntry::new(459, "SnowFallBlock", "Snow Fall Block"),
+    NameEntry::new(460, "SnowCloud", "Snow Cloud"),
+    NameEntry::new(461, "SandDrip", "Sand Drip"),
+    NameEntry::new(462, "DjinnLamp", "Desert Spirit Lamp"),
+    NameEntry::new(463, "DefendersForge", "Defenders Forge"),
+    NameEntry::new(464, "WarTable", "War Table"),
+    NameEntry::new(465, "WarTableBanner", "War Table Banner"),
+    NameEntry::new(466, "ElderCrystalStand", "Elder Crystal Stand"),
+    NameEntry::new(467, "Containers2", "Chest (Group 2)"),
+    NameEntry::new(468, "FakeContainers2", "Trapped Chests (Group 2)"),
+    NameEntry::new(469, "Tables2", "Crystal Table"),
+    NameEntry::new(470, "DisplayDoll", "Display Doll"),
+    NameEntry::new(471, "WeaponsRack2", "Weapons Rack 2"),
+    NameEntry::new(472, "IronBrick", "Iron Brick"),
+    NameEntry::new(473, "LeadBrick", "Lead Brick"),
+    NameEntry::new(474, "LesionBlock", "Lesion Block"),
+    NameEntry::new(475, "HatRack", "Hat Rack"),
+    NameEntry::new(476, "GolfHole", "Golf Cup"),
+    NameEntry::new(477, "GolfGrass", "Mowed Golf Grass"),
+    NameEntry::new(478, "CrimstoneBrick", "Crimstone Brick"),
+    NameEntry::new(479, "SmoothSandstone", "Smooth Sandstone"),
+    NameEntry::new(480, "BloodMoonMonolith", "Blood Moon Monolith"),
+    NameEntry::new(481, "CrackedBlueDungeonBrick", "Cracked Blue Dungeon Brick"),
+    NameEntry::new(482, "CrackedGreenDungeonBrick", "Cracked Green Dungeon Brick"),
+    NameEntry::new(483, "CrackedPinkDungeonBrick", "Cracked Pink Dungeon Brick"),
+    NameEntry::new(484, "RollingCactus", "Rolling Cactus"),
+    NameEntry::new(485, "AntlionLarva", "Antlion Larva"),
+    NameEntry::new(486, "DrumSet", "Drum Set"),
+    NameEntry::new(487, "PicnicTable", "Picnic Table"),
+    NameEntry::new(488, "FallenLog", "Fallen Log"),
+    NameEntry::new(489, "PinWheel", "Pin Wheel"),
+    NameEntry::new(490, "WeatherVane", "Weather Vane"),
+    NameEntry::new(491, "VoidVault", "Void Vault"),
+    NameEntry::new(492, "GolfGrassHallowed", "Golf Grass Hallowed"),
+    NameEntry::new(493, "GolfCupFlag", "Golf Cup Flag"),
+    NameEntry::new(494, "GolfTee", "Golf Tee"),
+    NameEntry::new(495, "ShellPile", "Shell Pile"),
+    NameEntry::new(496, "AntiPortalBlock", "Anti-Portal Block"),
+    NameEntry::new(497, "Toilets", "Toilets"),
+    NameEntry::new(498, "Spider", "Spider"),
+    NameEntry::new(499, "LesionStation", "Lesion Station"),
+    NameEntry::new(500, "SolarBrick", "Solar Brick"),
+    NameEntry::new(501, "VortexBrick", "Vortex Brick"),
+    NameEntry::new(502, "NebulaBrick", "Nebula Brick"),
+    NameEntry::new(503, "StardustBrick", "Stardust Brick"),
+    NameEntry::new(504, "MysticSnakeRope", "Mystic Snake Rope"),
+    NameEntry::new(505, "GoldGoldfishBowl", "Gold Goldfish Bowl"),
+    NameEntry::new(506, "CatBast", "Cat Bast"),
+    NameEntry::new(507, "GoldStarryGlassBlock", "Gold Starry Glass Block"),
+    NameEntry::new(508, "BlueStarryGlassBlock", "Blue Starry Glass Block"),
+    NameEntry::new(509, "VoidMonolith", "Void Monolith"),
+    NameEntry::new(510, "ArrowSign", "Arrow Sign"),
+    NameEntry::new(511, "PaintedArrowSign", "Painted Arrow Sign"),
+    NameEntry::new(512, "GreenMossBrick", "Green Moss Brick"),
+    NameEntry::new(513, "BrownMossBrick", "Brown Moss Brick"),
+    NameEntry::new(514, "RedMossBrick", "Red Moss Brick"),
+    NameEntry::new(515, "BlueMossBrick", "Blue Moss Brick"),
+    NameEntry::new(516, "PurpleMossBrick", "Purple Moss Brick"),
+    NameEntry::new(517, "LavaMossBrick", "Lava Moss Brick"),
+    NameEntry::new(518, "LilyPad", "Lily Pad"),
+    NameEntry::new(519, "Cattail", "Cattail"),
+    NameEntry::new(520, "FoodPlatter", "Food Platter"),
+    NameEntry::new(521, "BlackDragonflyJar", "Black Dragonfly Jar"),
+    NameEntry::new(522, "BlueDragonflyJar", "Blue Dragonfly Jar"),
+    NameEntry::new(523, "GreenDragonflyJar", "Green Dragonfly Jar"),
+    NameEntry::new(524, "OrangeDragonflyJar", "Orange Dragonfly Jar"),
+    NameEntry::new(525, "RedDragonflyJar", "Red Dragonfly Jar"),
+    NameEntry::new(526, "YellowDragonflyJar", "Yellow Dragonfly Jar"),
+    NameEntry::new(527, "GoldDragonflyJar", "Gold Dragonfly Jar"),
+    NameEntry::new(528, "MushroomVines", "Mushroom Vines"),
+    NameEntry::new(529, "SeaOats", "Sea Oats"),
+    NameEntry::new(530, "OasisPlants", "Oasis Plants"),
+    NameEntry::new(531, "BoulderStatue", "Boulder Statue"),
+    NameEntry::new(532, "MaggotCage", "Maggot Cage"),
+    NameEntry::new(533, "RatCage", "Rat Cage"),
+    NameEntry::new(534, "KryptonMoss", "Krypton Moss"),
+    NameEntry::new(535, "KryptonMossBrick", "Krypton Moss Brick"),
+    NameEntry::new(536, "XenonMoss", "Xenon Moss"),
+    NameEntry::new(537, "XenonMossBrick", "Xenon Moss Brick"),
+    NameEntry::new(538, "LadybugCage", "Ladybug Cage"),
+    NameEntry::new(539, "ArgonMoss", "Argon Moss"),
+    NameEntry::new(540, "ArgonMossBrick", "Argon Moss Brick"),
+    NameEntry::new(541, "EchoBlock", "Echo Block"),
+    NameEntry::new(542, "OwlCage", "Owl Cage"),
+    NameEntry::new(543, "PupfishBowl", "Pupfish Bowl"),
+    NameEntry::new(544, "GoldLadybugCage", "Gold Ladybug Cage"),
+    NameEntry::new(545, "LawnFlamingo", "Lawn Flamingo"),
+    NameEntry::new(546, "Grate", "Grate"),
+    NameEntry::new(547, "PottedPlants1", "Potted Plants 1"),
+    NameEntry::new(548, "PottedPlants2", "Potted Plants 2"),
+    NameEntry::new(549, "Seaweed", "Seaweed"),
+    NameEntry::new(550, "TurtleCage", "Turtle Cage"),
+    NameEntry::new(551, "TurtleJungleCage", "Turtle Jungle Cage"),
+    NameEntry::new(552, "Sandcastles", "Sandcastles"),
+    NameEntry::new(553, "GrebeCage", "Grebe Cage"),
+    NameEntry::new(554, "SeagullCage", "Seagull Cage"),
+    NameEntry::new(555, "WaterStriderCage", "Water Strider Cage"),
+    NameEntry::new(556, "GoldWaterStriderCage", "Gold Water Strider Cage"),
+    NameEntry::new(557, "GrateClosed", "Grate Closed"),
+    NameEntry::new(558, "SeahorseCage", "Seahorse Cage"),
+    NameEntry::new(559, "GoldSeahorseCage", "Gold Seahorse Cage"),
+    NameEntry::new(560, "GolfTrophies", "Golf Trophies"),
+    NameEntry::new(561, "MarbleColumn", "Marble Column"),
+    NameEntry::new(562, "BambooBlock", "Bamboo Block"),
+    NameEntry::new(563, "LargeBambooBlock", "Large Bamboo Block"),
+    NameEntry::new(564, "PlasmaLamp", "Plasma Lamp"),
+    NameEntry::new(565, "FogMachine", "Fog Machine"),
+    NameEntry::new(566, "AmberStoneBlock", "Amber Stone Block"),
+    NameEntry::new(567, "GardenGnome", "Garden Gnome"),
+    NameEntry::new(568, "PinkFairyJar", "Pink Fairy Jar"),
+    NameEntry::new(569, "GreenFairyJar", "Green Fairy Jar"),
+    NameEntry::new(570, "BlueFairyJar", "Blue Fairy Jar"),
+    NameEntry::new(571, "Bamboo", "Bamboo"),
+    NameEntry::new(572, "SoulBottles", "Soul Bottles"),
+    NameEntry::new(573, "TatteredWoodSign", "Tattered Wood Sign"),
+    NameEntry::new(574, "BorealBeam", "Boreal Beam"),
+    NameEntry::new(575, "RichMahoganyBeam", "Rich Mahogany Beam"),
+    NameEntry::new(576, "GraniteColumn", "Granite Column"),
+    NameEntry::new(577, "SandstoneColumn", "Sandstone Column"),
+    NameEntry::new(578, "MushroomBeam", "Mushroom Beam"),
+    NameEntry::new(579, "RockGolemHead", "Rock Golem Head"),
+    NameEntry::new(580, "HellButterflyJar", "Hell Butterfly Jar"),
+    NameEntry::new(581, "LavaflyinaBottle", "Lavafly in a Bottle"),
+    NameEntry::new(582, "MagmaSnailCage", "Magma Snail Cage"),
+    NameEntry::new(583, "TreeTopaz", "Tree Topaz"),
+    NameEntry::new(584, "TreeAmethyst", "Tree Amethyst"),
+    NameEntry::new(585, "TreeSapphire", "Tree Sapphire"),
+    NameEntry::new(586, "TreeEmerald", "Tree Emerald"),
+    NameEntry::new(587, "TreeRuby", "Tree Ruby"),
+    NameEntry::new(588, "TreeDiamond", "Tree Diamond"),
+    NameEntry::new(589, "TreeAmber", "Tree Amber"),
+    NameEntry::new(590, "GemSaplings", "Gem Saplings"),
+    NameEntry::new(591, "PotsSuspended", "Pots Suspended"),
+    NameEntry::new(592, "BrazierSuspended", "Brazier Suspended"),
+    NameEntry::new(593, "VolcanoSmall", "Volcano Small"),
+    NameEntry::new(594, "VolcanoLarge", "Volcano Large"),
+    NameEntry::new(595, "VanityTreeSakuraSaplings", "Vanity Tree Sakura Saplings"),
+    NameEntry::new(596, "VanityTreeSakura", "Vanity Tree Sakura"),
+    NameEntry::new(597, "TeleportationPylon", "Teleportation Pylon"),
+    NameEntry::new(598, "LavafishBowl", "Lavafish Bowl"),
+    NameEntry::new(599, "AmethystBunnyCage", "Amethyst Bunny Cage"),
+    NameEntry::new(600, "TopazBunnyCage", "Topaz Bunny Cage"),
+    NameEntry::new(601, "SapphireBunnyCage", "Sapphire Bunny Cage"),
+    NameEntry::new(602, "EmeraldBunnyCage", "Emerald Bunny Cage"),
+    NameEntry::new(603, "RubyBunnyCage", "Ruby Bunny Cage"),
+    NameEntry::new(604, "DiamondBunnyCage", "Diamond Bunny Cage"),
+    NameEntry::new(605, "AmberBunnyCage", "Amber Bunny Cage"),
+    NameEntry::new(606, "AmethystSquirrelCage", "Amethyst Squirrel Cage"),
+    NameEntry::new(607, "TopazSquirrelCage", "Topaz Squirrel Cage"),
+    NameEntry::new(608, "SapphireSquirrelCage", "Sapphire Squirrel Cage"),
+    NameEntry::new(609, "EmeraldSquirrelCage", "Emerald Squirrel Cage"),
+    NameEntry::new(610, "RubySquirrelCage", "Ruby Squirrel Cage"),
+    NameEntry::new(611, "DiamondSquirrelCage", "Diamond Squirrel Cage"),
+    NameEntry::new(612, "AmberSquirrelCage", "Amber Squirrel Cage"),
+    NameEntry::new(613, "PottedLavaPlants", "Potted Lava Plants"),
+    NameEntry::new(614, "PottedLavaPlantTendrils", "Potted Lava Plant Tendrils"),
+    NameEntry::new(615, "VanityTreeWillowSaplings", "Vanity Tree Willow Saplings"),
+    NameEntry::new(616, "VanityTreeYellowWillow", "Vanity Tree Yellow Willow"),
+    NameEntry::new(617, "MasterTrophyBase", "Master Trophy Base"),
+    NameEntry::new(618, "AccentSlab", "Accent Slab"),
+    NameEntry::new(619, "TruffleWormCage", "Truffle Worm Cage"),
+    NameEntry::new(620, "EmpressButterflyJar", "Empress Butterfly Jar"),
+    NameEntry::new(621, "SliceOfCake", "Slice Of Cake"),
+    NameEntry::new(622, "TeaKettle", "Tea Kettle"),
+    NameEntry::new(623, "PottedCrystalPlants", "Potted Crystal Plants"),
+    NameEntry::new(624, "AbigailsFlower", "Abigail's Flower"),
+    NameEntry::new(625, "VioletMoss", "VioletMoss"),
+    NameEntry::new(626, "VioletMossBrick", "VioletMossBrick"),
+    NameEntry::new(627, "RainbowMoss", "RainbowMoss"),
+    NameEntry::new(628, "RainbowMossBrick", "RainbowMossBrick"),
+    NameEntry::new(629, "StinkbugCage", "StinkbugCage"),
+    NameEntry::new(630, "StinkbugHousingBlocker", "StinkbugHousingBlocker"),
+    NameEntry::new(631, "StinkbugHousingBlockerEcho", "StinkbugHousingBlockerEcho"),
+    NameEntry::new(632, "ScarletMacawCage", "ScarletMacawCage"),
+    NameEntry::new(633, "AshGrass", "AshGrass"),
+    NameEntry::new(634, "TreeAsh", "TreeAsh"),
+    NameEntry::new(635, "AshWood", "AshWood"),
+    NameEntry::new(636, "CorruptVines", "CorruptVines"),
+    NameEntry::new(637, "AshPlants", "AshPlants"),
+    NameEntry::new(638, "AshVines", "AshVines"),
+    NameEntry::new(639, "ManaCrystal", "ManaCrystal"),
+    NameEntry::new(640, "BlueMacawCage", "BlueMacawCage"),
+    NameEntry::new(641, "ReefBlock", "ReefBlock"),
+    NameEntry::new(642, "ChlorophyteExtractinator", "ChlorophyteExtractinator"),
+    NameEntry::new(643, "ToucanCage", "ToucanCage"),
+    NameEntry::new(644, "YellowCockatielCage", "YellowCockatielCage"),
+    NameEntry::new(645, "GrayCockatielCage", "GrayCockatielCage"),
+    NameEntry::new(646, "ShadowCandle", "ShadowCandle"),
+    NameEntry::new(647, "LargePilesEcho", "LargePilesEcho"),
+    NameEntry::new(648, "LargePiles2Echo", "LargePiles2Echo"),
+    NameEntry::new(649, "SmallPiles2x1Echo", "SmallPiles2x1Echo"),
+    NameEntry::new(650, "SmallPiles1x1Echo", "SmallPiles1x1Echo"),
+    NameEntry::new(651, "PlantDetritus3x2Echo", "PlantDetritus3x2Echo"),
+    NameEntry::new(652, "PlantDetritus2x2Echo", "PlantDetritus2x2Echo"),
+    NameEntry::new(653, "PotsEcho", "PotsEcho"),
+    NameEntry::new(654, "TNTBarrel", "TNTBarrel"),
+    NameEntry::new(655, "PlanteraThorns", "PlanteraThorns"),
+    NameEntry::new(656, "GlowTulip", "GlowTulip"),
+    NameEntry::new(657, "EchoMonolith", "EchoMonolith"),
+    NameEntry::new(658, "ShimmerMonolith", "ShimmerMonolith"),
+    NameEntry::new(659, "ShimmerBlock", "ShimmerBlock"),
+    NameEntry::new(660, "ShimmerflyinaBottle", "ShimmerflyinaBottle"),
+    NameEntry::new(661, "CorruptJungleGrass", "CorruptJungleGrass"),
+    NameEntry::new(662, "CrimsonJungleGrass", "CrimsonJungleGrass"),
+    NameEntry::new(663, "Moondial", "Moondial"),
+    NameEntry::new(664, "BouncyBoulder", "BouncyBoulder"),
+    NameEntry::new(665, "LifeCrystalBoulder", "LifeCrystalBoulder"),
+    NameEntry::new(666, "PoopBlock", "PoopBlock"),
+    NameEntry::new(667, "ShimmerBrick", "ShimmerBrick"),
+    NameEntry::new(668, "DirtiestBlock", "DirtiestBlock"),
+    NameEntry::new(669, "LunarRustBrick", "LunarRustBrick"),
+    NameEntry::new(670, "DarkCelestialBrick", "DarkCelestialBrick"),
+    NameEntry::new(671, "AstraBrick", "AstraBrick"),
+    NameEntry::new(672, "CosmicEmberBrick", "CosmicEmberBrick"),
+    NameEntry::new(673, "CryocoreBrick", "CryocoreBrick"),
+    NameEntry::new(674, "MercuryBrick", "MercuryBrick"),
+    NameEntry::new(675, "StarRoyaleBrick", "StarRoyaleBrick"),
+    NameEntry::new(676, "HeavenforgeBrick", "HeavenforgeBrick"),
+    NameEntry::new(677, "AncientBlueBrick", "AncientBlueBrick"),
+    NameEntry::new(678, "AncientGreenBrick", "AncientGreenBrick"),
+    NameEntry::new(679, "AncientPinkBrick", "AncientPinkBrick"),
+    NameEntry::new(680, "AncientGoldBrick", "AncientGoldBrick"),
+    NameEntry::new(681, "AncientSilverBrick", "AncientSilverBrick"),
+    NameEntry::new(682, "AncientCopperBrick", "AncientCopperBrick"),
+    NameEntry::new(683, "AncientObsidianBrick", "AncientObsidianBrick"),
+    NameEntry::new(684, "AncientHellstoneBrick", "AncientHellstoneBrick"),
+    NameEntry::new(685, "AncientCobaltBrick", "AncientCobaltBrick"),
+    NameEntry::new(686, "AncientMythrilBrick", "AncientMythrilBrick"),
+    NameEntry::new(687, "LavaMossBlock", "LavaMossBlock"),
+    NameEntry::new(688, "ArgonMossBlock", "ArgonMossBlock"),
+    NameEntry::new(689, "KryptonMossBlock", "KryptonMossBlock"),
+    NameEntry::new(690, "XenonMossBlock", "XenonMossBlock"),
+    NameEntry::new(691, "VioletMossBlock", "VioletMossBlock"),
+    NameEntry::new(692, "RainbowMossBlock", "RainbowMossBlock"),
+    NameEntry::new(693, "Stalactite1x1Echo", "Stalactite1x1Echo"),
+    NameEntry::new(694, "Stalactite1x2Echo", "Stalactite1x2Echo"),
+    NameEntry::new(695, "DemonAltarReplica", "DemonAltarReplica"),
+    NameEntry::new(696, "ShadowOrbsReplica", "ShadowOrbsReplica"),
+    NameEntry::new(697, "CobwebReplica", "CobwebReplica"),
+    NameEntry::new(698, "DeadCellsDisplayJar", "DeadCellsDisplayJar"),
+    NameEntry::new(699, "DeadCellsPotionStation", "DeadCellsPotionStation"),
+    NameEntry::new(700, "AbigailsFlowerReplica", "AbigailsFlowerReplica"),
+    NameEntry::new(701, "GlowTulipReplica", "GlowTulipReplica"),
+    NameEntry::new(702, "LifeFruitReplica", "LifeFruitReplica"),
+    NameEntry::new(703, "JunglePlantsEcho", "JunglePlantsEcho"),
+    NameEntry::new(704, "FallenLogEcho", "FallenLogEcho"),
+    NameEntry::new(705, "OasisPlantsEcho", "OasisPlantsEcho"),
+    NameEntry::new(706, "TerragrimShrineEcho", "TerragrimShrineEcho"),
+    NameEntry::new(707, "BooksEcho", "BooksEcho"),
+    NameEntry::new(708, "Shimmerfall", "Shimmerfall"),
+    NameEntry::new(709, "ShimmerDrip", "ShimmerDrip"),
+    NameEntry::new(710, "PufferfishCage", "PufferfishCage"),
+    NameEntry::new(711, "RainbowBoulder", "RainbowBoulder"),
+    NameEntry::new(712, "Poulder", "Poulder"),
+    NameEntry::new(713, "LavaBoulder", "LavaBoulder"),
+    NameEntry::new(714, "SpiderBoulder", "SpiderBoulder"),
+    NameEntry::new(715, "Ghoulder", "Ghoulder"),
+    NameEntry::new(716, "BoulderThatSpawnsPet", "BoulderThatSpawnsPet"),
+    NameEntry::new(717, "LavaCloud", "LavaCloud"),
+    NameEntry::new(718, "StarCloud", "StarCloud"),
+    NameEntry::new(719, "RainbowCloud", "RainbowCloud"),
+    NameEntry::new(720, "CRTMonolith", "CRTMonolith"),
+    NameEntry::new(721, "RetroMonolith", "RetroMonolith"),
+    NameEntry::new(722, "FallenStarBlock", "FallenStarBlock"),
+    NameEntry::new(723, "KiteAnchor", "KiteAnchor"),
+    NameEntry::new(724, "CritterAnchor", "CritterAnchor"),
+    NameEntry::new(725, "NoirMonolith", "NoirMonolith"),
+    NameEntry::new(726, "CannonBall", "CannonBall"),
+    NameEntry::new(727, "TeamBlockRedVariant", "TeamBlockRedVariant"),
+    NameEntry::new(728, "TeamBlockGreenVariant", "TeamBlockGreenVariant"),
+    NameEntry::new(729, "TeamBlockBlueVariant", "TeamBlockBlueVariant"),
+    NameEntry::new(730, "TeamBlockYellowVariant", "TeamBlockYellowVariant"),
+    NameEntry::new(731, "TeamBlockPinkVariant", "TeamBlockPinkVariant"),
+    NameEntry::new(732, "TeamBlockWhiteVariant", "TeamBlockWhiteVariant"),
+    NameEntry::new(733, "RadioThingMonolith", "RadioThingMonolith"),
+    NameEntry::new(734, "Feywood", "Feywood"),
+    NameEntry::new(735, "HallowedBrick", "HallowedBrick"),
+    NameEntry::new(736, "EasterBlock", "EasterBlock"),
+    NameEntry::new(737, "GothicBrick", "GothicBrick"),
+    NameEntry::new(738, "FlinxFurBlock", "FlinxFurBlock"),
+    NameEntry::new(739, "JellyfishBlock", "JellyfishBlock"),
+    NameEntry::new(740, "ToyBreakerBlock", "ToyBreakerBlock"),
+    NameEntry::new(741, "PineWoodBlock", "PineWoodBlock"),
+    NameEntry::new(742, "HarpyBlock", "HarpyBlock"),
+    NameEntry::new(743, "MoonplateBlock", "MoonplateBlock"),
+    NameEntry::new(744, "LibrarianBlock", "LibrarianBlock"),
+    NameEntry::new(745, "SpikeBlock", "SpikeBlock"),
+    NameEntry::new(746, "OfficeBlock", "OfficeBlock"),
+    NameEntry::new(747, "ForbiddenBlock", "ForbiddenBlock"),
+    NameEntry::new(748, "WaterBlock", "WaterBlock"),
+    NameEntry::new(749, "BoulderBlock", "BoulderBlock"),
+    NameEntry::new(750, "DamagingSpikeBlock", "DamagingSpikeBlock"),
+    NameEntry::new(751, "PalworldDigtoiseSleeping", "PalworldDigtoiseSleeping"),
+    NameEntry::new(752, "PalworldChilletEgg", "PalworldChilletEgg"),
+];
+
+pub const WALL_NAMES: &[NameEntry] = &[
+    NameEntry::new(1, "Stone", "Stone Wall"),
+    NameEntry::new(2, "DirtUnsafe", "Dirt Wall"),
+    NameEntry::new(3, "EbonstoneUnsafe", "Ebonstone Wall"),
+    NameEntry::new(4, "Wood", "Wood Wall"),
+    NameEntry::new(5, "GrayBrick", "Gray Brick Wall"),
+    NameEntry::new(6, "RedBrick", "Red Brick Wall"),
+    NameEntry::new(7, "BlueDungeonUnsafe", "Dungeon Blue Brick Wall"),
+    NameEntry::new(8, "GreenDungeonUnsafe", "Dungeon Green Brick Wall"),
+    NameEntry::new(9, "PinkDungeonUnsafe", "Dungeon Pink Brick Wall"),
+    NameEntry::new(10, "GoldBrick", "Gold Brick Wall"),
+    NameEntry::new(11, "SilverBrick", "Silver Brick Wall"),
+    NameEntry::new(12, "CopperBrick", "Copper Brick Wall"),
+    NameEntry::new(13, "HellstoneBrickUnsafe", "Hellstone Wall"),
+    NameEntry::new(14, "ObsidianBrickUnsafe", "Obsidian Wall"),
+    NameEntry::new(15, "MudUnsafe", "Mud Wall"),
+    NameEntry::new(16, "Dirt", "Player Dirt Wall"),
+    NameEntry::new(17, "BlueDungeon", "Player Blue Brick Wall"),
+    NameEntry::new(18, "GreenDungeon", "Player Green Brick Wall"),
+    NameEntry::new(19, "PinkDungeon", "Player Pink Brick Wall"),
+    NameEntry::new(20, "ObsidianBrick", "Player Obsidian Wall"),
+    NameEntry::new(21, "Glass", "Glass Wall"),
+    NameEntry::new(22, "PearlstoneBrick", "Pearlstone Brick"),
+    NameEntry::new(23, "IridescentBrick", "Iridescent Wall"),
+    NameEntry::new(24, "MudstoneBrick", "Mudstone Wall"),
+    NameEntry::new(25, "CobaltBrick", "Cobalt Brick Wall"),
+    NameEntry::new(26, "MythrilBrick", "Mythril Brick Wall"),
+    NameEntry::new(27, "Planked", "Planked Wall"),
+    NameEntry::new(28, "PearlstoneBrickUnsafe", "Pearlstone Wall"),
+    NameEntry::new(29, "CandyCane", "Red Candy Cane Wall"),
+    NameEntry::new(30, "GreenCandyCane", "Green Candy Cane Wall"),
+    NameEntry::new(31, "SnowBrick", "Snow Brick Wall"),
+    NameEntry::new(32, "AdamantiteBeam", "Adamantite Beam Wall"),
+    NameEntry::new(33, "DemoniteBrick", "Demonite Brick Wall"),
+    NameEntry::new(34, "SandstoneBrick", "Sandstone Brick Wall"),
+    NameEntry::new(35, "EbonstoneBrick", "Ebonstone Brick Wall"),
+    NameEntry::new(36, "RedStucco", "Red Stucco Wall"),
+    NameEntry::new(37, "YellowStucco", "Yellow Stucco Wall"),
+    NameEntry::new(38, "GreenStucco", "Green Stucco Wall"),
+    NameEntry::new(39, "Gray", "Gray Stucco Wall"),
+    NameEntry::new(40, "SnowWallUnsafe", "Snow Wall"),
+    NameEntry::new(41, "Ebonwood", "Ebonwood Wall"),
+    NameEntry::new(42, "RichMaogany", "Rich Mahogany Wall"),
+    NameEntry::new(43, "Pearlwood", "Pearlwood Wall"),
+    NameEntry::new(44, "RainbowBrick", "Rainbow Brick Wall"),
+    NameEntry::new(45, "TinBrick", "Tin Brick Wall"),
+    NameEntry::new(46, "TungstenBrick", "Tungsten Brick Wall"),
+    NameEntry::new(47, "PlatinumBrick", "Platinum Brick Wall"),
+    NameEntry::new(48, "AmethystUnsafe", "Amethyst Gemstone Wall"),
+    NameEntry::new(49, "TopazUnsafe", "Topaz Gemstone Wall"),
+    NameEntry::new(50, "SapphireUnsafe", "Sapphire Gemstone Wall"),
+    NameEntry::new(51, "EmeraldUnsafe", "Emerald Gemstone Wall"),
+    NameEntry::new(52, "RubyUnsafe", "Ruby Gemstone Wall"),
+    NameEntry::new(53, "DiamondUnsafe", "Diamond Gemstone Wall"),
+    NameEntry::new(54, "CaveUnsafe", "Green Moss Cave Wall"),
+    NameEntry::new(55, "Cave2Unsafe", "Yellow Moss Cave Wall"),
+    NameEntry::new(56, "Cave3Unsafe", "Red Moss Cave Wall"),
+    NameEntry::new(57, "Cave4Unsafe", "Blue Moss Cave Wall"),
+    NameEntry::new(58, "Cave5Unsafe", "Purple Moss Cave Wall"),
+    NameEntry::new(59, "Cave6Unsafe", "Cave Wall"),
+    NameEntry::new(60, "LivingLeaf", "Moss Wall"),
+    NameEntry::new(61, "Cave7Unsafe", "Iron Cave Wall"),
+    NameEntry::new(62, "SpiderUnsafe", "Spider Wall"),
+    NameEntry::new(63, "GrassUnsafe", "Grass Wall"),
+    NameEntry::new(64, "JungleUnsafe", "Jungle Wall"),
+    NameEntry::new(65, "FlowerUnsafe", "Flower Wall"),
+    NameEntry::new(66, "Grass", "Player Grass Wall"),
+    NameEntry::new(67, "Jungle", "Player Jungle Wall"),
+    NameEntry::new(68, "Flower", "Player Flower Wall"),
+    NameEntry::new(69, "CorruptGrassUnsafe", "Corruption Jungle Wall"),
+    NameEntry::new(70, "HallowedGrassUnsafe", "Hallowed Jungle Wall"),
+    NameEntry::new(71, "IceUnsafe", "Ice Wall"),
+    NameEntry::new(72, "Cactus", "Cactus Wall"),
+    NameEntry::new(73, "Cloud", "Cloud Wall"),
+    NameEntry::new(74, "Mushroom", "Mushroom Wall"),
+    NameEntry::new(75, "Bone", "Bone Block Wall"),
+    NameEntry::new(76, "Slime", "Slime Block Wall"),
+    NameEntry::new(77, "Flesh", "Flesh Block Wall"),
+    NameEntry::new(78, "LivingWood", "Livingwood Wall"),
+    NameEntry::new(79, "ObsidianBackUnsafe", "Obsidian Cave Wall"),
+    NameEntry::new(80, "MushroomUnsafe", "Mushroom Cave Wall"),
+    NameEntry::new(81, "CrimsonGrassUnsafe", "Crimson Jungle Wall"),
+    NameEntry::new(82, "DiscWall", "Disc Wall"),
+    NameEntry::new(83, "CrimstoneUnsafe", "Crimstone Wall"),
+    NameEntry::new(84, "IceBrick", "Ice Brick Wall"),
+    NameEntry::new(85, "Shadewood", "Shadewood Wall"),
+    NameEntry::new(86, "HiveUnsafe", "Hive Wall"),
+    NameEntry::new(87, "LihzahrdBrickUnsafe", "Lihzahrd Brick Wall"),
+    NameEntry::new(88, "PurpleStainedGlass", "Purple Stained Glass"),
+    NameEntry::new(89, "YellowStainedGlass", "Yellow Stained Glass"),
+    NameEntry::new(90, "BlueStainedGlass", "Blue Stained Glass"),
+    NameEntry::new(91, "GreenStainedGlass", "Green Stained Glass"),
+    NameEntry::new(92, "RedStainedGlass", "Red Stained Glass"),
+    NameEntry::new(93, "RainbowStainedGlass", "Multicolored Stained Glass"),
+    NameEntry::new(94, "BlueDungeonSlabUnsafe", "Dungeon Blue Slab Wall"),
+    NameEntry::new(95, "BlueDungeonTileUnsafe", "Dungeon Blue Tiled Wall"),
+    NameEntry::new(96, "PinkDungeonSlabUnsafe", "Dungeon Pink Slab Wall"),
+    NameEntry::new(97, "PinkDungeonTileUnsafe", "Dungeon Pink Tiled Wall"),
+    NameEntry::new(98, "GreenDungeonSlabUnsafe", "Dungeon Green Slab Wall"),
+    NameEntry::new(99, "GreenDungeonTileUnsafe", "Dungeon Green Tiled Wall"),
+    NameEntry::new(100, "BlueDungeonSlab", "Player Blue Slab Wall"),
+    NameEntry::new(101, "BlueDungeonTile", "Player Blue Tiled Wall"),
+    NameEntry::new(102, "PinkDungeonSlab", "Player Pink Slab Wall"),
+    NameEntry::new(103, "PinkDungeonTile", "Player Pink Tiled Wall"),
+    NameEntry::new(104, "GreenDungeonSlab", "Player Green Slab Wall"),
+    NameEntry::new(105, "GreenDungeonTile", "Player Green Tiled Wall"),
+    NameEntry::new(106, "WoodenFence", "Wooden Fence"),
+    NameEntry::new(107, "MetalFence", "Metal Fence"),
+    NameEntry::new(108, "Hive", "Hive Wall"),
+    NameEntry::new(109, "PalladiumColumn", "Palladium Column Wall"),
+    NameEntry::new(110, "BubblegumBlock", "Bubblegum Block Wall"),
+    NameEntry::new(111, "TitanstoneBlock", "Titanstone Block Wall"),
+    NameEntry::new(112, "LihzahrdBrick", "Player Lihzahrd Brick Wall"),
+    NameEntry::new(113, "Pumpkin", "Pumpkin Wall"),
+    NameEntry::new(114, "Hay", "Hay Wall"),
+    NameEntry::new(115, "SpookyWood", "Spooky Wood Wall"),
+    NameEntry::new(116, "ChristmasTreeWallpaper", "Christmas Tree Wallpaper"),
+    NameEntry::new(117, "OrnamentWallpaper", "Ornament Wallpaper"),
+    NameEntry::new(118, "CandyCaneWallpaper", "Candy Cane Wallpaper"),
+    NameEntry::new(119, "FestiveWallpaper", "Festive Wallpaper"),
+    NameEntry::new(120, "StarsWallpaper", "Stars Wallpaper"),
+    NameEntry::new(121, "SquigglesWallpaper", "Squiggles Wallpaper"),
+    NameEntry::new(122, "SnowflakeWallpaper", "Snowflake Wallpaper"),
+    NameEntry::new(123, "KrampusHornWallpaper", "Krampus Horn Wallpaper"),
+    NameEntry::new(124, "BluegreenWallpaper", "Bluegreen Wallpaper"),
+    NameEntry::new(125, "GrinchFingerWallpaper", "Grinch Finger Wallpaper"),
+    NameEntry::new(126, "FancyGrayWallpaper", "Fancy Grey Wallpaper"),
+    NameEntry::new(127, "IceFloeWallpaper", "Ice Floe Wallpaper"),
+    NameEntry::new(128, "MusicWallpaper", "Music Wallpaper"),
+    NameEntry::new(129, "PurpleRainWallpaper", "Purple Rain Wallpaper"),
+    NameEntry::new(130, "RainbowWallpaper", "Rainbow Wallpaper"),
+    NameEntry::new(131, "SparkleStoneWallpaper", "Sparkle Stone Wallpaper"),
+    NameEntry::new(132, "StarlitHeavenWallpaper", "Starlit Heaven Wallpaper"),
+    NameEntry::new(133, "BubbleWallpaper", "Bubble Wallpaper"),
+    NameEntry::new(134, "CopperPipeWallpaper", "Copper Pipe Wallpaper"),
+    NameEntry::new(135, "DuckyWallpaper", "Ducky Wallpaper"),
+    NameEntry::new(136, "Waterfall", "Waterfall Wall"),
+    NameEntry::new(137, "Lavafall", "Lavafall Wall"),
+    NameEntry::new(138, "EbonwoodFence", "Ebonwood Fence"),
+    NameEntry::new(139, "RichMahoganyFence", "Rich Mahogany Fence"),
+    NameEntry::new(140, "PearlwoodFence", "Pearlwood Fence"),
+    NameEntry::new(141, "ShadewoodFence", "Shadewood Fence"),
+    NameEntry::new(142, "WhiteDynasty", "White Dynasty Wall"),
+    NameEntry::new(143, "BlueDynasty", "Blue Dynasty Wall"),
+    NameEntry::new(144, "ArcaneRunes", "Mystic Rune Wallpaper"),
+    NameEntry::new(145, "IronFence", "Iron Fence"),
+    NameEntry::new(146, "CopperPlating", "Copper Plating"),
+    NameEntry::new(147, "StoneSlab", "Stone Slab Wall"),
+    NameEntry::new(148, "Sail", "Sail"),
+    NameEntry::new(149, "BorealWood", "Boreal Wood Wall"),
+    NameEntry::new(150, "BorealWoodFence", "Boreal Wood Fence"),
+    NameEntry::new(151, "PalmWood", "Palm Wood Wall"),
+    NameEntry::new(152, "PalmWoodFence", "Palm Wood Fence"),
+    NameEntry::new(153, "AmberGemspark", "Amber Gemspark Wall"),
+    NameEntry::new(154, "AmethystGemspark", "Amethyst Gemspark Wall"),
+    NameEntry::new(155, "DiamondGemspark", "Diamond Gemspark Wall"),
+    NameEntry::new(156, "EmeraldGemspark", "Emerald Gemspark Wall"),
+    NameEntry::new(157, "AmberGemsparkOff", "Offline Amber Gemspark Wall"),
+    NameEntry::new(158, "AmethystGemsparkOff", "Offline Amethyst Gemspark Wall"),
+    NameEntry::new(159, "DiamondGemsparkOff", "Offline Diamond Gemspark Wall"),
+    NameEntry::new(160, "EmeraldGemsparkOff", "Offline Emerald Gemspark Wall"),
+    NameEntry::new(161, "RubyGemsparkOff", "Offline Ruby Gemspark Wall"),
+    NameEntry::new(162, "SapphireGemsparkOff", "Offline Sapphire Gemspark Wall"),
+    NameEntry::new(163, "TopazGemsparkOff", "Offline Topaz Gemspark Wall"),
+    NameEntry::new(164, "RubyGemspark", "Ruby Gemspark Wall"),
+    NameEntry::new(165, "SapphireGemspark", "Sapphire Gemspark Wall"),
+    NameEntry::new(166, "TopazGemspark", "Topaz Gemspark Wall"),
+    NameEntry::new(167, "TinPlating", "Tin Plating Wall"),
+    NameEntry::new(168, "Confetti", "Confetti Wall"),
+    NameEntry::new(169, "ConfettiBlack", "Midnight Confetti Wall"),
+    NameEntry::new(170, "CaveWall", "Unique Cave Wall 7 (natural)"),
+    NameEntry::new(171, "CaveWall2", "Unique Cave Wall 8 (natural)"),
+    NameEntry::new(172, "Honeyfall", "Honeyfall Wall"),
+    NameEntry::new(173, "ChlorophyteBrick", "Chlorophyte Brick Wall"),
+    NameEntry::new(174, "CrimtaneBrick", "Crimtane Brick Wall"),
+    NameEntry::new(175, "ShroomitePlating", "Shroomite Plating Wall"),
+    NameEntry::new(176, "MartianConduit", "Martian Conduit Wall"),
+    NameEntry::new(177, "HellstoneBrick", "Hellstone Brick Wall"),
+    NameEntry::new(178, "MarbleUnsafe", "Marble (natural)"),
+    NameEntry::new(179, "MarbleBlock", "Marble Block Wall"),
+    NameEntry::new(180, "GraniteUnsafe", "Granite (natural)"),
+    NameEntry::new(181, "GraniteBlock", "Granite Block Wall"),
+    NameEntry::new(182, "MeteoriteBrick", "Meteorite Brick Wall"),
+    NameEntry::new(183, "Marble", "Smooth Marble Wall"),
+    NameEntry::new(184, "Granite", "Smooth Granite Wall"),
+    NameEntry::new(185, "Cave8Unsafe", "Cave 8 (natural)"),
+    NameEntry::new(186, "Crystal", "Crystal Block Wall"),
+    NameEntry::new(187, "Sandstone", "Sandstone Wall"),
+    NameEntry::new(188, "CorruptionUnsafe1", "Corruption 1 (natural)"),
+    NameEntry::new(189, "CorruptionUnsafe2", "Corruption 2 (natural)"),
+    NameEntry::new(190, "CorruptionUnsafe3", "Corruption 3 (natural)"),
+    NameEntry::new(191, "CorruptionUnsafe4", "Corruption 4 (natural)"),
+    NameEntry::new(192, "CrimsonUnsafe1", "Crimson 1 (natural)"),
+    NameEntry::new(193, "CrimsonUnsafe2", "Crimson 2 (natural)"),
+    NameEntry::new(194, "CrimsonUnsafe3", "Crimson 3 (natural)"),
+    NameEntry::new(195, "CrimsonUnsafe4", "Crimson 4 (natural)"),
+    NameEntry::new(196, "DirtUnsafe1", "Dirt 1 (natural)"),
+    NameEntry::new(197, "DirtUnsafe2", "Dirt 2 (natural)"),
+    NameEntry::new(198, "DirtUnsafe3", "Dirt 3 (natural)"),
+    NameEntry::new(199, "DirtUnsafe4", "Dirt 4 (natural)"),
+    NameEntry::new(200, "HallowUnsafe1", "Hallow 1 (natural)"),
+    NameEntry::new(201, "HallowUnsafe2", "Hallow 2 (natural)"),
+    NameEntry::new(202, "HallowUnsafe3", "Hallow 3 (natural)"),
+    NameEntry::new(203, "HallowUnsafe4", "Hallow 4 (natural)"),
+    NameEntry::new(204, "JungleUnsafe1", "Jungle 1 (natural)"),
+    NameEntry::new(205, "JungleUnsafe2", "Jungle 2 (natural)"),
+    NameEntry::new(206, "JungleUnsafe3", "Jungle 3 (natural)"),
+    NameEntry::new(207, "JungleUnsafe4", "Jungle 4 (natural)"),
+    NameEntry::new(208, "LavaUnsafe1", "Lava 1 (natural)"),
+    NameEntry::new(209, "LavaUnsafe2", "Lava 2 (natural)"),
+    NameEntry::new(210, "LavaUnsafe3", "Lava 3 (natural)"),
+    NameEntry::new(211, "LavaUnsafe4", "Lava 4 (natural)"),
+    NameEntry::new(212, "RocksUnsafe1", "Rocks 1 (natural)"),
+    NameEntry::new(213, "RocksUnsafe2", "Rocks 2 (natural)"),
+    NameEntry::new(214, "RocksUnsafe3", "Rocks 3 (natural)"),
+    NameEntry::new(215, "RocksUnsafe4", "Rocks 4 (natural)"),
+    NameEntry::new(216, "HardenedSand", "Hardened Sand Wall"),
+    NameEntry::new(217, "CorruptHardenedSand", "Hardened Ebonsand Wall"),
+    NameEntry::new(218, "CrimsonHardenedSand", "Hardened Crimsand Wall"),
+    NameEntry::new(219, "HallowHardenedSand", "Hardened Pearlsand Wall"),
+    NameEntry::new(220, "CorruptSandstone", "Ebonsandstone Wall"),
+    NameEntry::new(221, "CrimsonSandstone", "Crimsandstone Wall"),
+    NameEntry::new(222, "HallowSandstone", "Pearlsandstone Wall"),
+    NameEntry::new(223, "DesertFossil", "Desert Fossil Wall"),
+    NameEntry::new(224, "LunarBrickWall", "Lunar Brick Wall"),
+    NameEntry::new(225, "CogWall", "CogWall"),
+    NameEntry::new(226, "SandFall", "SandFall"),
+    NameEntry::new(227, "SnowFall", "SnowFall"),
+    NameEntry::new(228, "SillyBalloonPinkWall", "SillyBalloonPinkWall"),
+    NameEntry::new(229, "SillyBalloonPurpleWall", "SillyBalloonPurpleWall"),
+    NameEntry::new(230, "SillyBalloonGreenWall", "SillyBalloonGreenWall"),
+    NameEntry::new(231, "IronBrick", "IronBrick"),
+    NameEntry::new(232, "LeadBrick", "LeadBrick"),
+    NameEntry::new(233, "LesionBlock", "LesionBlock"),
+    NameEntry::new(234, "CrimstoneBrick", "CrimstoneBrick"),
+    NameEntry::new(235, "SmoothSandstone", "SmoothSandstone"),
+    NameEntry::new(236, "Spider", "Spider"),
+    NameEntry::new(237, "SolarBrick", "SolarBrick"),
+    NameEntry::new(238, "VortexBrick", "VortexBrick"),
+    NameEntry::new(239, "NebulaBrick", "NebulaBrick"),
+    NameEntry::new(240, "StardustBrick", "StardustBrick"),
+    NameEntry::new(241, "OrangeStainedGlass", "OrangeStainedGlass"),
+    NameEntry::new(242, "GoldStarryGlassWall", "GoldStarryGlassWall"),
+    NameEntry::new(243, "BlueStarryGlassWall", "BlueStarryGlassWall"),
+    NameEntry::new(244, "LivingWoodUnsafe", "LivingWoodUnsafe"),
+    NameEntry::new(245, "WroughtIronFence", "WroughtIronFence"),
+    NameEntry::new(246, "EbonstoneEcho", "EbonstoneEcho"),
+    NameEntry::new(247, "MudWallEcho", "MudWallEcho"),
+    NameEntry::new(248, "PearlstoneEcho", "PearlstoneEcho"),
+    NameEntry::new(249, "SnowWallEcho", "SnowWallEcho"),
+    NameEntry::new(250, "AmethystEcho", "AmethystEcho"),
+    NameEntry::new(251, "TopazEcho", "TopazEcho"),
+    NameEntry::new(252, "SapphireEcho", "SapphireEcho"),
+    NameEntry::new(253, "EmeraldEcho", "EmeraldEcho"),
+    NameEntry::new(254, "RubyEcho", "RubyEcho"),
+    NameEntry::new(255, "DiamondEcho", "DiamondEcho"),
+    NameEntry::new(256, "Cave1Echo", "Cave1Echo"),
+    NameEntry::new(257, "Cave2Echo", "Cave2Echo"),
+    NameEntry::new(258, "Cave3Echo", "Cave3Echo"),
+    NameEntry::new(259, "Cave4Echo", "Cave4Echo"),
+    NameEntry::new(260, "Cave5Echo", "Cave5Echo"),
+    NameEntry::new(261, "Cave6Echo", "Cave6Echo"),
+    NameEntry::new(262, "Cave7Echo", "Cave7Echo"),
+    NameEntry::new(263, "SpiderEcho", "SpiderEcho"),
+    NameEntry::new(264, "CorruptGrassEcho", "CorruptGrassEcho"),
+    NameEntry::new(265, "HallowedGrassEcho", "HallowedGrassEcho"),
+    NameEntry::new(266, "IceEcho", "IceEcho"),
+    NameEntry::new(267, "ObsidianBackEcho", "ObsidianBackEcho"),
+    NameEntry::new(268, "CrimsonGrassEcho", "CrimsonGrassEcho"),
+    NameEntry::new(269, "CrimstoneEcho", "CrimstoneEcho"),
+    NameEntry::new(270, "CaveWall1Echo", "CaveWall1Echo"),
+    NameEntry::new(271, "CaveWall2Echo", "CaveWall2Echo"),
+    NameEntry::new(272, "MarbleEchoUnused", "MarbleEchoUnused"),
+    NameEntry::new(273, "GraniteEchoUnused", "GraniteEchoUnused"),
+    NameEntry::new(274, "Cave8Echo", "Cave8Echo"),
+    NameEntry::new(275, "SandstoneEcho", "SandstoneEcho"),
+    NameEntry::new(276, "Corruption1Echo", "Corruption1Echo"),
+    NameEntry::new(277, "Corruption2Echo", "Corruption2Echo"),
+    NameEntry::new(278, "Corruption3Echo", "Corruption3Echo"),
+    NameEntry::new(279, "Corruption4Echo", "Corruption4Echo"),
+    NameEntry::new(280, "Crimson1Echo", "Crimson1Echo"),
+    NameEntry::new(281, "Crimson2Echo", "Crimson2Echo"),
+    NameEntry::new(282, "Crimson3Echo", "Crimson3Echo"),
+    NameEntry::new(283, "Crimson4Echo", "Crimson4Echo"),
+    NameEntry::new(284, "Dirt1Echo", "Dirt1Echo"),
+    NameEntry::new(285, "Dirt2Echo", "Dirt2Echo"),
+    NameEntry::new(286, "Dirt3Echo", "Dirt3Echo"),
+    NameEntry::new(287, "Dirt4Echo", "Dirt4Echo"),
+    NameEntry::new(288, "Hallow1Echo", "Hallow1Echo"),
+    NameEntry::new(289, "Hallow2Echo", "Hallow2Echo"),
+    NameEntry::new(290, "Hallow3Echo", "Hallow3Echo"),
+    NameEntry::new(291, "Hallow4Echo", "Hallow4Echo"),
+    NameEntry::new(292, "Jungle1Echo", "Jungle1Echo"),
+    NameEntry::new(293, "Jungle2Echo", "Jungle2Echo"),
+    NameEntry::new(294, "Jungle3Echo", "Jungle3Echo"),
+    NameEntry::new(295, "Jungle4Echo", "Jungle4Echo"),
+    NameEntry::new(296, "Lava1Echo", "Lava1Echo"),
+    NameEntry::new(297, "Lava2Echo", "Lava2Echo"),
+    NameEntry::new(298, "Lava3Echo", "Lava3Echo"),
+    NameEntry::new(299, "Lava4Echo", "Lava4Echo"),
+    NameEntry::new(300, "Rocks1Echo", "Rocks1Echo"),
+    NameEntry::new(301, "Rocks2Echo", "Rocks2Echo"),
+    NameEntry::new(302, "Rocks3Echo", "Rocks3Echo"),
+    NameEntry::new(303, "Rocks4Echo", "Rocks4Echo"),
+    NameEntry::new(304, "HardenedSandEcho", "HardenedSandEcho"),
+    NameEntry::new(305, "CorruptHardenedSandEcho", "CorruptHardenedSandEcho"),
+    NameEntry::new(306, "CrimsonHardenedSandEcho", "CrimsonHardenedSandEcho"),
+    NameEntry::new(307, "HallowHardenedSandEcho", "HallowHardenedSandEcho"),
+    NameEntry::new(308, "CorruptSandstoneEcho", "CorruptSandstoneEcho"),
+    NameEntry::new(309, "CrimsonSandstoneEcho", "CrimsonSandstoneEcho"),
+    NameEntry::new(310, "HallowSandstoneEcho", "HallowSandstoneEcho"),
+    NameEntry::new(311, "DesertFossilEcho", "DesertFossilEcho"),
+    NameEntry::new(312, "BambooBlockWall", "BambooBlockWall"),
+    NameEntry::new(313, "LargeBambooBlockWall", "LargeBambooBlockWall"),
+    NameEntry::new(314, "AmberStoneWallEcho", "AmberStoneWallEcho"),
+    NameEntry::new(315, "BambooFence", "BambooFence"),
+    NameEntry::new(316, "AshWood", "AshWood"),
+    NameEntry::new(317, "AshWoodFence", "AshWoodFence"),
+    NameEntry::new(318, "EchoWall", "EchoWall"),
+    NameEntry::new(319, "ReefWall", "ReefWall"),
+    NameEntry::new(320, "PoopWall", "PoopWall"),
+    NameEntry::new(321, "ShimmerBlockWall", "ShimmerBlockWall"),
+    NameEntry::new(322, "ShimmerBrickWall", "ShimmerBrickWall"),
+    NameEntry::new(323, "LunarRustBrickWall", "LunarRustBrickWall"),
+    NameEntry::new(324, "DarkCelestialBrickWall", "DarkCelestialBrickWall"),
+    NameEntry::new(325, "AstraBrickWall", "AstraBrickWall"),
+    NameEntry::new(326, "CosmicEmberBrickWall", "CosmicEmberBrickWall"),
+    NameEntry::new(327, "CryocoreBrickWall", "CryocoreBrickWall"),
+    NameEntry::new(328, "MercuryBrickWall", "MercuryBrickWall"),
+    NameEntry::new(329, "StarRoyaleBrickWall", "StarRoyaleBrickWall"),
+    NameEntry::new(330, "HeavenforgeBrickWall", "HeavenforgeBrickWall"),
+    NameEntry::new(331, "AncientBlueBrickWall", "AncientBlueBrickWall"),
+    NameEntry::new(332, "AncientGreenBrickWall", "AncientGreenBrickWall"),
+    NameEntry::new(333, "AncientPinkBrickWall", "AncientPinkBrickWall"),
+    NameEntry::new(334, "AncientGoldBrickWall", "AncientGoldBrickWall"),
+    NameEntry::new(335, "AncientSilverBrickWall", "AncientSilverBrickWall"),
+    NameEntry::new(336, "AncientCopperBrickWall", "AncientCopperBrickWall"),
+    NameEntry::new(337, "AncientObsidianBrickWall", "AncientObsidianBrickWall"),
+    NameEntry::new(338, "AncientHellstoneBrickWall", "AncientHellstoneBrickWall"),
+    NameEntry::new(339, "AncientCobaltBrickWall", "AncientCobaltBrickWall"),
+    NameEntry::new(340, "AncientMythrilBrickWall", "AncientMythrilBrickWall"),
+    NameEntry::new(341, "LavaMossBlockWall", "LavaMossBlockWall"),
+    NameEntry::new(342, "ArgonMossBlockWall", "ArgonMossBlockWall"),
+    NameEntry::new(343, "KryptonMossBlockWall", "KryptonMossBlockWall"),
+    NameEntry::new(344, "XenonMossBlockWall", "XenonMossBlockWall"),
+    NameEntry::new(345, "VioletMossBlockWall", "VioletMossBlockWall"),
+    NameEntry::new(346, "RainbowMossBlockWall", "RainbowMossBlockWall"),
+    NameEntry::new(347, "Shimmerfall", "Shimmerfall"),
+    NameEntry::new(348, "FallenStarWall", "Fallen Star Wall"),
+    NameEntry::new(349, "StoneUnsafe", "StoneUnsafe"),
+    NameEntry::new(350, "UnbreakableBlockWall", "UnbreakableBlockWall"),
+    NameEntry::new(351, "FeywoodWall", "Feywood Wall"),
+    NameEntry::new(352, "HallowedBrickWall", "Hallowed Brick Wall"),
+    NameEntry::new(353, "PineTreeBlockWall", "Pine Tree Wall"),
+    NameEntry::new(354, "EasterBlockWall", "Easter Wall"),
+    NameEntry::new(355, "GothicBrickWall", "Gothic Brick Wall"),
+    NameEntry::new(356, "FlinxFurBlockWall", "Flinx Fur Wall"),
+    NameEntry::new(357, "JellyfishBlockWall", "Jellyfish Wall"),
+    NameEntry::new(358, "PineWoodBlockWall", "Pine Wood Wall"),
+    NameEntry::new(359, "HarpyBlockWall", "Harpy Wall"),
+    NameEntry::new(360, "MoonplateBlockWall", "Crescent Wall"),
+    NameEntry::new(361, "LibrarianBlockWall", "Librarian Wall"),
+    NameEntry::new(362, "SpikeBlockWall", "Spike Wall"),
+    NameEntry::new(363, "OfficeBlockWall", "Office Wall"),
+    NameEntry::new(364, "ForbiddenBlockWall", "Forbidden Wall"),
+    NameEntry::new(365, "WaterBlockWall", "Aquarium Wall"),
+    NameEntry::new(366, "BoulderBlockWall", "Boulder Wall"),
+];
+
+pub const NPC_NAMES: &[NameEntry] = &[
+    NameEntry::new(17, "Merchant", "Merchant"),
+    NameEntry::new(18, "Nurse", "Nurse"),
+    NameEntry::new(19, "ArmsDealer", "Arms Dealer"),
+    NameEntry::new(20, "Dryad", "Dryad"),
+    NameEntry::new(22, "Guide", "Guide"),
+    NameEntry::new(37, "OldMan", "Old Man"),
+    NameEntry::new(38, "Demolitionist", "Demolitionist"),
+    NameEntry::new(54, "Clothier", "Clothier"),
+    NameEntry::new(107, "GoblinTinkerer", "Goblin Tinkerer"),
+    NameEntry::new(108, "Wizard", "Wizard"),
+    NameEntry::new(124, "Mechanic", "Mechanic"),
+    NameEntry::new(142, "SantaClaus", "Santa Claus"),
+    NameEntry::new(160, "Truffle", "Truffle"),
+    NameEntry::new(178, "Steampunker", "Steampunker"),
+    NameEntry::new(207, "DyeTrader", "Dye Trader"),
+    NameEntry::new(208, "PartyGirl", "Party Girl"),
+    NameEntry::new(209, "Cyborg", "Cyborg"),
+    NameEntry::new(227, "Painter", "Painter"),
+    NameEntry::new(228, "WitchDoctor", "Witch Doctor"),
+    NameEntry::new(229, "Pirate", "Pirate"),
+    NameEntry::new(353, "Stylist", "Stylist"),
+    NameEntry::new(369, "Angler", "Angler"),
+    NameEntry::new(441, "TaxCollector", "Tax Collector"),
+    NameEntry::new(550, "Tavernkeep", "Tavernkeep"),
+    NameEntry::new(588, "Golfer", "Golfer"),
+    NameEntry::new(633, "Zoologist", "Zoologist"),
+    NameEntry::new(637, "TownCat", "Town Cat"),
+    NameEntry::new(638, "TownDog", "Town Dog"),
+    NameEntry::new(656, "TownBunny", "Town Bunny"),
+    NameEntry::new(663, "Princess", "Princess"),
+];
+
+pub const TILE_FRAMES: &[FrameEntry] = &[
+    FrameEntry::new(3, 0, 0, "Grass", "Single Blade"),
+    FrameEntry::new(3, 18, 0, "Grass", "Double Blade"),
+    FrameEntry::new(3, 36, 0, "Grass", "Small Y"),
+    FrameEntry::new(3, 54, 0, "Grass", "Three Small"),
+    FrameEntry::new(3, 72, 0, "Grass", "Single Blade"),
+    FrameEntry::new(3, 90, 0, "Grass", "Three Large"),
+    FrameEntry::new(3, 108, 0, "Flowers", "Purple, Immature"),
+    FrameEntry::new(3, 126, 0, "Flowers", "Pink, Immature"),
+    FrameEntry::new(3, 144, 0, "Mushroom", ""),
+    FrameEntry::new(4, 0, 0, "Torch", "On"),
+    FrameEntry::new(4, 22, 0, "Torch", "On"),
+    FrameEntry::new(4, 44, 0, "Torch", "On"),
+    FrameEntry::new(4, 66, 0, "Torch", "Off"),
+    FrameEntry::new(4, 88, 0, "Torch", "Off"),
+    FrameEntry::new(4, 110, 0, "Torch", "Off"),
+    FrameEntry::new(4, 0, 22, "Blue", "On"),
+    FrameEntry::new(4, 22, 22, "Blue", "On"),
+    FrameEntry::new(4, 44, 22, "Blue", "On"),
+    FrameEntry::new(4, 66, 22, "Blue", "Off"),
+    FrameEntry::new(4, 88, 22, "Blue", "Off"),
+    FrameEntry::new(4, 110, 22, "Blue", "Off"),
+    FrameEntry::new(4, 0, 44, "Red", "On"),
+    FrameEntry::new(4, 22, 44, "Red", "On"),
+    FrameEntry::new(4, 44, 44, "Red", "On"),
+    FrameEntry::new(4, 66, 44, "Red", "Off"),
+    FrameEntry::new(4, 88, 44, "Red", "Off"),
+    FrameEntry::new(4, 110, 44, "Red", "Off"),
+    FrameEntry::new(4, 0, 66, "Green", "On"),
+    FrameEntry::new(4, 22, 66, "Green", "On"),
+    FrameEntry::new(4, 44, 66, "Green", "On"),
+    FrameEntry::new(4, 66, 66, "Green", "Off"),
+    FrameEntry::new(4, 88, 66, "Green", "Off"),
+    FrameEntry::new(4, 110, 66, "Green", "Off"),
+    FrameEntry::new(4, 0, 88, "Purple", "On"),
+    FrameEntry::new(4, 22, 88, "Purple", "On"),
+    FrameEntry::new(4, 44, 88, "Purple", "On"),
+    FrameEntry::new(4, 66, 88, "Purple", "Off"),
+    FrameEntry::new(4, 88, 88, "Purple", "Off"),
+    FrameEntry::new(4, 110, 88, "Purple", "Off"),
+    FrameEntry::new(4, 0, 110, "White", "On"),
+    FrameEntry::new(4, 22, 110, "White", "On"),
+    FrameEntry::new(4, 44, 110, "White", "On"),
+    FrameEntry::new(4, 66, 110, "White", "Off"),
+    FrameEntry::new(4, 88, 110, "White", "Off"),
+    FrameEntry::new(4, 110, 110, "White", "Off"),
+    FrameEntry::new(4, 0, 132, "Yellow", "On"),
+    FrameEntry::new(4, 22, 132, "Yellow", "On"),
+    FrameEntry::new(4, 44, 132, "Yellow", "On"),
+    FrameEntry::new(4, 66, 132, "Yellow", "Off"),
+    FrameEntry::new(4, 88, 132, "Yellow", "Off"),
+    FrameEntry::new(4, 110, 132, "Yellow", "Off"),
+    FrameEntry::new(4, 0, 154, "Demon", "On"),
+    FrameEntry::new(4, 22, 154, "Demon", "On"),
+    FrameEntry::new(4, 44, 154, "Demon", "On"),
+    FrameEntry::new(4, 66, 154, "Demon", "Off"),
+    FrameEntry::new(4, 88, 154, "Demon", "Off"),
+    FrameEntry::new(4, 110, 154, "Demon", "Off"),
+    FrameEntry::new(4, 0, 176, "Cursed", "On"),
+    FrameEntry::new(4, 22, 176, "Cursed", "On"),
+    FrameEntry::new(4, 44, 176, "Cursed", "On"),
+    FrameEntry::new(4, 66, 176, "Cursed", "Off"),
+    FrameEntry::new(4, 88, 176, "Cursed", "Off"),
+    FrameEntry::new(4, 110, 176, "Cursed", "Off"),
+    FrameEntry::new(4, 0, 198, "Ice", "On"),
+    FrameEntry::new(4, 22, 198, "Ice", "On"),
+    FrameEntry::new(4, 44, 198, "Ice", "On"),
+    FrameEntry::new(4, 66, 198, "Ice", "Off"),
+    FrameEntry::new(4, 88, 198, "Ice", "Off"),
+    FrameEntry::new(4, 110, 198, "Ice", "Off"),
+    FrameEntry::new(4, 0, 220, "Orange", "On"),
+    FrameEntry::new(4, 22, 220, "Orange", "On"),
+    FrameEntry::new(4, 44, 220, "Orange", "On"),
+    FrameEntry::new(4, 66, 220, "Orange", "Off"),
+    FrameEntry::new(4, 88, 220, "Orange", "Off"),
+    FrameEntry::new(4, 110, 220, "Orange", "Off"),
+    FrameEntry::new(4, 0, 242, "Ichor", "On"),
+    FrameEntry::new(4, 22, 242, "Ichor", "On"),
+    FrameEntry::new(4, 44, 242, "Ichor", "On"),
+    FrameEntry::new(4, 66, 242, "Ichor", "Off"),
+    FrameEntry::new(4, 88, 242, "Ichor", "Off"),
+    FrameEntry::new(4, 110, 242, "Ichor", "Off"),
+    FrameEntry::new(4, 0, 264, "Ultrabright", "On"),
+    FrameEntry::new(4, 22, 264, "Ultrabright", "On"),
+    FrameEntry::new(4, 44, 264, "Ultrabright", "On"),
+    FrameEntry::new(4, 66, 264, "Ultrabright", "Off"),
+    FrameEntry::new(4, 88, 264, "Ultrabright", "Off"),
+    FrameEntry::new(4, 110, 264, "Ultrabright", "Off"),
+    FrameEntry::new(4, 0, 286, "Bone", "On"),
+    FrameEntry::new(4, 22, 286, "Bone", "On"),
+    FrameEntry::new(4, 44, 286, "Bone", "On"),
+    FrameEntry::new(4, 66, 286, "Bone", "Off"),
+    FrameEntry::new(4, 88, 286, "Bone", "Off"),
+    FrameEntry::new(4, 110, 286, "Bone", "Off"),
+    FrameEntry::new(4, 0, 308, "Rainbow", "On"),
+    FrameEntry::new(4, 22, 308, "Rainbow", "On"),
+    FrameEntry::new(4, 44, 308, "Rainbow", "On"),
+    FrameEntry::new(4, 66, 308, "Rainbow", "Off"),
+    FrameEntry::new(4, 88, 308, "Rainbow", "Off"),
+    FrameEntry::new(4, 110, 308, "Rainbow", "Off"),
+    FrameEntry::new(4, 0, 330, "Pink", "On"),
+    FrameEntry::new(4, 22, 330, "Pink", "On"),
+    FrameEntry::new(4, 44, 330, "Pink", "On"),
+    FrameEntry::new(4, 66, 330, "Pink", "Off"),
+    FrameEntry::new(4, 88, 330, "Pink", "Off"),
+    FrameEntry::new(4, 110, 330, "Pink", "Off"),
+    FrameEntry::new(5, 22, 198, "Tree Top Leafy", "A"),
+    FrameEntry::new(5, 22, 220, "Tree Top Leafy", "B"),
+    FrameEntry::new(5, 22, 242, "Tree Top Leafy", "C"),
+    FrameEntry::new(5, 0, 0, "Tree Trunk", "Plain A"),
+    FrameEntry::new(5, 22, 0, "Tree Trunk", "Right Indent A"),
+    FrameEntry::new(5, 44, 0, "Tree Trunk", "Right Slight Bulge A"),
+    FrameEntry::new(5, 66, 0, "Tree Branch", "Plain A"),
+    FrameEntry::new(5, 88, 0, "Tree Trunk", "Left Slight Bulge A"),
+    FrameEntry::new(5, 110, 0, "Tree Top", "Medium A"),
+    FrameEntry::new(5, 132, 0, "Tree Top", "Small A"),
+    FrameEntry::new(5, 154, 0, "Tree Top", "Large A"),
+    FrameEntry::new(5, 0, 22, "Tree Trunk", "Plain B"),
+    FrameEntry::new(5, 22, 22, "Tree Trunk", "Right Indent B"),
+    FrameEntry::new(5, 44, 22, "Tree Trunk", "Right Slight Bulge B"),
+    FrameEntry::new(5, 66, 22, "Tree Branch", "Plain B"),
+    FrameEntry::new(5, 88, 22, "Tree Trunk", "Left Slight Bulge B"),
+    FrameEntry::new(5, 110, 22, "Tree Top", "Medium B"),
+    FrameEntry::new(5, 132, 22, "Tree Top", "Small B"),
+    FrameEntry::new(5, 154, 22, "Tree Top", "Large B"),
+    FrameEntry::new(5, 0, 44, "Tree Trunk", "Plain C"),
+    FrameEntry::new(5, 22, 44, "Tree Trunk", "Right Indent C"),
+    FrameEntry::new(5, 44, 44, "Tree Trunk", "Right Slight Bulge C"),
+    FrameEntry::new(5, 66, 44, "Tree Branch", "Plain C"),
+    FrameEntry::new(5, 88, 44, "Tree Trunk", "Left Slight Bulge C"),
+    FrameEntry::new(5, 110, 44, "Tree Top", "Medium C"),
+    FrameEntry::new(5, 132, 44, "Tree Top", "Small C"),
+    FrameEntry::new(5, 154, 44, "Tree Top", "Large C"),
+    FrameEntry::new(5, 0, 66, "Tree Trunk", "Left Indent A"),
+    FrameEntry::new(5, 22, 66, "Tree Trunk", "Right Bulge A"),
+    FrameEntry::new(5, 44, 66, "Tree Trunk", "Left Bulge A"),
+    FrameEntry::new(5, 66, 66, "Tree Trunk", "Right Slight Bulge D"),
+    FrameEntry::new(5, 88, 66, "Tree Branch", "Plain A"),
+    FrameEntry::new(5, 110, 66, "Tree Trunk", "Double Slight Bulge A"),
+    FrameEntry::new(5, 132, 66, "Tree Top", "Small Right Slight Bulge A"),
+    FrameEntry::new(5, 154, 66, "Tree Top", "Large A"),
+    FrameEntry::new(5, 0, 88, "Tree Trunk", "Left Indent B"),
+    FrameEntry::new(5, 22, 88, "Tree Trunk", "Right Bulge B"),
+    FrameEntry::new(5, 44, 88, "Tree Trunk", "Left Bulge B"),
+    FrameEntry::new(5, 66, 88, "Tree Trunk", "Right Slight Bulge E"),
+    FrameEntry::new(5, 88, 88, "Tree Branch", "Plain B"),
+    FrameEntry::new(5, 110, 88, "Tree Trunk", "Double Slight Bulge B"),
+    FrameEntry::new(5, 132, 88, "Tree Top", "Small Right Slight Bulge B"),
+    FrameEntry::new(5, 154, 88, "Tree Top", "Large B"),
+    FrameEntry::new(5, 0, 110, "Tree Trunk", "Left Indent C"),
+    FrameEntry::new(5, 22, 110, "Tree Trunk", "Right Bulge C"),
+    FrameEntry::new(5, 44, 110, "Tree Trunk", "Left Bulge C"),
+    FrameEntry::new(5, 66, 110, "Tree Trunk", "Right Slight Bulge F"),
+    FrameEntry::new(5, 88, 110, "Tree Branch", "Plain C"),
+    FrameEntry::new(5, 110, 110, "Tree Trunk", "Double Slight Bulge C"),
+    FrameEntry::new(5, 132, 110, "Tree Top", "Small Right Slight Bulge C"),
+    FrameEntry::new(5, 154, 110, "Tree Top", "Large C"),
+    FrameEntry::new(5, 0, 132, "Tree Trunk", "Large A"),
+    FrameEntry::new(5, 22, 132, "Tree Base", "Plain A"),
+    FrameEntry::new(5, 44, 132, "Tree Base", "Plain A"),
+    FrameEntry::new(5, 66, 132, "Tree Trunk", "Large A"),
+    FrameEntry::new(5, 88, 132, "Tree Trunk", "Large A"),
+    FrameEntry::new(5, 132, 132, "Tree Top", "Small Double Slight Bulge A"),
+    FrameEntry::new(5, 154, 132, "Tree Top", "Huge"),
+    FrameEntry::new(5, 0, 154, "Tree Trunk", "Large B"),
+    FrameEntry::new(5, 22, 154, "Tree Base", "Plain B"),
+    FrameEntry::new(5, 44, 154, "Tree Base", "Plain B"),
+    FrameEntry::new(5, 66, 154, "Tree Trunk", "Large B"),
+    FrameEntry::new(5, 88, 154, "Tree Trunk", "Large B"),
+    FrameEntry::new(5, 132, 154, "Tree Top", "Small Double Slight Bulge B"),
+    FrameEntry::new(5, 154, 154, "Tree Top", "Huge"),
+    FrameEntry::new(5, 0, 176, "Tree Trunk", "Large C"),
+    FrameEntry::new(5, 22, 176, "Tree Base", "Plain C"),
+    FrameEntry::new(5, 44, 176, "Tree Base", "Plain C"),
+    FrameEntry::new(5, 66, 176, "Tree Trunk", "Large C"),
+    FrameEntry::new(5, 88, 176, "Tree Trunk", "Large C"),
+    FrameEntry::new(5, 132, 176, "Tree Top", "Small Double Slight Bulge C"),
+    FrameEntry::new(5, 154, 176, "Tree Top", "Huge"),
+    FrameEntry::new(5, 0, 198, "Tree Top", "Jagged"),
+    FrameEntry::new(5, 0, 220, "Tree Trunk", "Jagged"),
+    FrameEntry::new(5, 0, 242, "Tree Trunk", "Jagged"),
+    FrameEntry::new(10, 0, 0, "Door Closed", "Wooden Door A"),
+    FrameEntry::new(10, 18, 0, "Door Closed", "Wooden Door B"),
+    FrameEntry::new(10, 36, 0, "Door Closed", "Wooden Door C"),
+    FrameEntry::new(10, 0, 54, "Door Closed", "Ebonwood Door A"),
+    FrameEntry::new(10, 18, 54, "Door Closed", "Ebonwood Door B"),
+    FrameEntry::new(10, 36, 54, "Door Closed", "Ebonwood Door C"),
+    FrameEntry::new(10, 0, 108, "Door Closed", "Rich Mahogany Door A"),
+    FrameEntry::new(10, 18, 108, "Door Closed", "Rich Mahogany Door B"),
+    FrameEntry::new(10, 36, 108, "Door Closed", "Rich Mahogany Door C"),
+    FrameEntry::new(10, 0, 162, "Door Closed", "Pearlwood Door A"),
+    FrameEntry::new(10, 18, 162, "Door Closed", "Pearlwood Door B"),
+    FrameEntry::new(10, 36, 162, "Door Closed", "Pearlwood Door C"),
+    FrameEntry::new(10, 0, 216, "Door Closed", "Cactus Door A"),
+    FrameEntry::new(10, 18, 216, "Door Closed", "Cactus Door B"),
+    FrameEntry::new(10, 36, 216, "Door Closed", "Cactus Door C"),
+    FrameEntry::new(10, 0, 270, "Door Closed", "Flesh Door A"),
+    FrameEntry::new(10, 18, 270, "Door Closed", "Flesh Door B"),
+    FrameEntry::new(10, 36, 270, "Door Closed", "Flesh Door C"),
+    FrameEntry::new(10, 0, 324, "Door Closed", "Mushroom Door A"),
+    FrameEntry::new(10, 18, 324, "Door Closed", "Mushroom Door B"),
+    FrameEntry::new(10, 36, 324, "Door Closed", "Mushroom Door C"),
+    FrameEntry::new(10, 0, 378, "Door Closed", "Living Wood Door A"),
+    FrameEntry::new(10, 18, 378, "Door Closed", "Living Wood Door B"),
+    FrameEntry::new(10, 36, 378, "Door Closed", "Living Wood Door C"),
+    FrameEntry::new(10, 0, 432, "Door Closed", "Bone Door A"),
+    FrameEntry::new(10, 18, 432, "Door Closed", "Bone Door B"),
+    FrameEntry::new(10, 36, 432, "Door Closed", "Bone Door C"),
+    FrameEntry::new(10, 0, 486, "Door Closed", "Skyware Door A"),
+    FrameEntry::new(10, 18, 486, "Door Closed", "Skyware Door B"),
+    FrameEntry::new(10, 36, 486, "Door Closed", "Skyware Door C"),
+    FrameEntry::new(10, 0, 540, "Door Closed", "Shadewood Door A"),
+    FrameEntry::new(10, 18, 540, "Door Closed", "Shadewood Door B"),
+    FrameEntry::new(10, 36, 540, "Door Closed", "Shadewood Door C"),
+    FrameEntry::new(10, 0, 594, "Door Closed", "Lihzahrd Locked Door A"),
+    FrameEntry::new(10, 18, 594, "Door Closed", "Lihzahrd Locked Door B"),
+    FrameEntry::new(10, 36, 594, "Door Closed", "Lihzahrd Locked Door C"),
+    FrameEntry::new(10, 0, 648, "Door Closed", "Lihzahrd Door A"),
+    FrameEntry::new(10, 18, 648, "Door Closed", "Lihzahrd Door B"),
+    FrameEntry::new(10, 36, 648, "Door Closed", "Lihzahrd Door C"),
+    FrameEntry::new(10, 0, 702, "Door Closed", "Dungeon Door A"),
+    FrameEntry::new(10, 18, 702, "Door Closed", "Dungeon Door B"),
+    FrameEntry::new(10, 36, 702, "Door Closed", "Dungeon Door C"),
+    FrameEntry::new(10, 0, 756, "Door Closed", "Lead Door A"),
+    FrameEntry::new(10, 18, 756, "Door Closed", "Lead Door B"),
+    FrameEntry::new(10, 36, 756, "Door Closed", "Lead Door C"),
+    FrameEntry::new(10, 0, 810, "Door Closed", "Iron Door A"),
+    FrameEntry::new(10, 18, 810, "Door Closed", "Iron Door B"),
+    FrameEntry::new(10, 36, 810, "Door Closed", "Iron Door C"),
+    FrameEntry::new(10, 0, 864, "Door Closed", "Blue Dungeon Door A"),
+    FrameEntry::new(10, 18, 864, "Door Closed", "Blue Dungeon Door B"),
+    FrameEntry::new(10, 36, 864, "Door Closed", "Blue Dungeon Door C"),
+    FrameEntry::new(10, 0, 918, "Door Closed", "Green Dungeon Door A"),
+    FrameEntry::new(10, 18, 918, "Door Closed", "Green Dungeon Door B"),
+    FrameEntry::new(10, 36, 918, "Door Closed", "Green Dungeon Door C"),
+    FrameEntry::new(10, 0, 972, "Door Closed", "Pink Dungeon Door A"),
+    FrameEntry::new(10, 18, 972, "Door Closed", "Pink Dungeon Door B"),
+    FrameEntry::new(10, 36, 972, "Door Closed", "Pink Dungeon Door C"),
+    FrameEntry::new(10, 0, 1026, "Door Closed", "Obsidian Door A"),
+    FrameEntry::new(10, 18, 1026, "Door Closed", "Obsidian Door B"),
+    FrameEntry::new(10, 36, 1026, "Door Closed", "Obsidian Door C"),
+    FrameEntry::new(10, 0, 1080, "Door Closed", "Glass Door A"),
+    FrameEntry::new(10, 18, 1080, "Door Closed", "Glass Door B"),
+    FrameEntry::new(10, 36, 1080, "Door Closed", "Glass Door C"),
+    FrameEntry::new(10, 0, 1134, "Door Closed", "Golden Door A"),
+    FrameEntry::new(10, 18, 1134, "Door Closed", "Golden Door B"),
+    FrameEntry::new(10, 36, 1134, "Door Closed", "Golden Door C"),
+    FrameEntry::new(10, 0, 1188, "Door Closed", "Honey Door A"),
+    FrameEntry::new(10, 18, 1188, "Door Closed", "Honey Door B"),
+    FrameEntry::new(10, 36, 1188, "Door Closed", "Honey Door C"),
+    FrameEntry::new(10, 0, 1242, "Door Closed", "Steam Punk Door A"),
+    FrameEntry::new(10, 18, 1242, "Door Closed", "Steam Punk Door B"),
+    FrameEntry::new(10, 36, 1242, "Door Closed", "Steam Punk Door C"),
+    FrameEntry::new(10, 0, 1296, "Door Closed", "Pumpkin Door A"),
+    FrameEntry::new(10, 18, 1296, "Door Closed", "Pumpkin Door A"),
+    FrameEntry::new(10, 36, 1296, "Door Closed", "Pumpkin Door A"),
+    FrameEntry::new(10, 0, 1350, "Door Closed", "Spooky Door A"),
+    FrameEntry::new(10, 18, 1350, "Door Closed", "Spooky Door B"),
+    FrameEntry::new(10, 36, 1350, "Door Closed", "Spooky Door C"),
+    FrameEntry::new(10, 0, 1404, "Door Closed", "Pine Door A"),
+    FrameEntry::new(10, 18, 1404, "Door Closed", "Pine Door B"),
+    FrameEntry::new(10, 36, 1404, "Door Closed", "Pine Door C"),
+    FrameEntry::new(10, 0, 1458, "Door Closed", "Frozen Door A"),
+    FrameEntry::new(10, 18, 1458, "Door Closed", "Frozen Door B"),
+    FrameEntry::new(10, 36, 1458, "Door Closed", "Frozen Door C"),
+    FrameEntry::new(10, 0, 1512, "Door Closed", "Dynasty Door A"),
+    FrameEntry::new(10, 18, 1512, "Door Closed", "Dynasty Door B"),
+    FrameEntry::new(10, 36, 1512, "Door Closed", "Dynasty Door C"),
+    FrameEntry::new(10, 0, 1566, "Door Closed", "Palm Wood Door A"),
+    FrameEntry::new(10, 18, 1566, "Door Closed", "Palm Wood Door B"),
+    FrameEntry::new(10, 36, 1566, "Door Closed", "Palm Wood Door C"),
+    FrameEntry::new(10, 0, 1620, "Door Closed", "Boreal Wood Door A"),
+    FrameEntry::new(10, 18, 1620, "Door Closed", "Boreal Wood Door B"),
+    FrameEntry::new(10, 36, 1620, "Door Closed", "Boreal Wood Door C"),
+    FrameEntry::new(10, 0, 1674, "Door Closed", "Slime Door A"),
+    FrameEntry::new(10, 18, 1674, "Door Closed", "Slime Door B"),
+    FrameEntry::new(10, 36, 1674, "Door Closed", "Slime Door C"),
+    FrameEntry::new(10, 0, 1728, "Door Closed", "Martian Door A"),
+    FrameEntry::new(10, 18, 1728, "Door Closed", "Martian Door B"),
+    FrameEntry::new(10, 36, 1728, "Door Closed", "Martian Door C"),
+    FrameEntry::new(10, 0, 1782, "Door Closed", "Meteorite Door A"),
+    FrameEntry::new(10, 18, 1782, "Door Closed", "Meteorite Door B"),
+    FrameEntry::new(10, 36, 1782, "Door Closed", "Meteorite Door C"),
+    FrameEntry::new(10, 0, 1836, "Door Closed", "Granite Door A"),
+    FrameEntry::new(10, 18, 1836, "Door Closed", "Granite Door B"),
+    FrameEntry::new(10, 36, 1836, "Door Closed", "Granite Door C"),
+    FrameEntry::new(10, 0, 1890, "Door Closed", "Marble Door A"),
+    FrameEntry::new(10, 18, 1890, "Door Closed", "Marble Door B"),
+    FrameEntry::new(10, 36, 1890, "Door Closed", "Marble Door C"),
+    FrameEntry::new(10, 54, 0, "Door Closed", "Crystal Door A"),
+    FrameEntry::new(10, 72, 0, "Door Closed", "Crystal Door B"),
+    FrameEntry::new(10, 90, 0, "Door Closed", "Crystal Door C"),
+    FrameEntry::new(11, 0, 0, "Door Open", "Wooden Door"),
+    FrameEntry::new(11, 36, 0, "Door Open", "Wooden Door"),
+    FrameEntry::new(11, 0, 54, "Door Open", "Ebonwood Door"),
+    FrameEntry::new(11, 36, 54, "Door Open", "Ebonwood Door"),
+    FrameEntry::new(11, 0, 108, "Door Open", "Rich Mahogany Door"),
+    FrameEntry::new(11, 36, 108, "Door Open", "Rich Mahogany Door"),
+    FrameEntry::new(11, 0, 162, "Door Open", "Pearlwood Door"),
+    FrameEntry::new(11, 36, 162, "Door Open", "Pearlwood Door"),
+    FrameEntry::new(11, 0, 216, "Door Open", "Cactus Door"),
+    FrameEntry::new(11, 36, 216, "Door Open", "Cactus Door"),
+    FrameEntry::new(11, 0, 270, "Door Open", "Flesh Door"),
+    FrameEntry::new(11, 36, 270, "Door Open", "Flesh Door"),
+    FrameEntry::new(11, 0, 324, "Door Open", "Mushroom Door"),
+    FrameEntry::new(11, 36, 324, "Door Open", "Mushroom Door"),
+    FrameEntry::new(11, 0, 378, "Door Open", "Living Wood Door"),
+    FrameEntry::new(11, 36, 378, "Door Open", "Living Wood Door"),
+    FrameEntry::new(11, 0, 432, "Door Open", "Bone Door"),
+    FrameEntry::new(11, 36, 432, "Door Open", "Bone Door"),
+    FrameEntry::new(11, 0, 486, "Door Open", "Skyware Door"),
+    FrameEntry::new(11, 36, 486, "Door Open", "Skyware Door"),
+    FrameEntry::new(11, 0, 540, "Door Open", "Shadewood Door"),
+    FrameEntry::new(11, 36, 540, "Door Open", "Shadewood Door"),
+    FrameEntry::new(11, 0, 648, "Door Open", "Lihzahrd Door"),
+    FrameEntry::new(11, 36, 648, "Door Open", "Lihzahrd Door"),
+    FrameEntry::new(11, 0, 702, "Door Open", "Dungeon Door"),
+    FrameEntry::new(11, 36, 702, "Door Open", "Dungeon Door"),
+    FrameEntry::new(11, 0, 756, "Door Open", "Lead Door"),
+    FrameEntry::new(11, 36, 756, "Door Open", "Lead Door"),
+    FrameEntry::new(11, 0, 810, "Door Open", "Iron Door"),
+    FrameEntry::new(11, 36, 810, "Door Open", "Iron Door"),
+    FrameEntry::new(11, 0, 864, "Door Open", "Blue Dungeon Door"),
+    FrameEntry::new(11, 36, 864, "Door Open", "Blue Dungeon Door"),
+    FrameEntry::new(11, 0, 918, "Door Open", "Green Dungeon Door"),
+    FrameEntry::new(11, 36, 918, "Door Open", "Green Dungeon Door"),
+    FrameEntry::new(11, 0, 972, "Door Open", "Pink Dungeon Door"),
+    FrameEntry::new(11, 36, 972, "Door Open", "Pink Dungeon Door"),
+    FrameEntry::new(11, 0, 1026, "Door Open", "Obsidian Door"),
+    FrameEntry::new(11, 36, 1026, "Door Open", "Obsidian Door"),
+    FrameEntry::new(11, 0, 1080, "Door Open", "Glass Door"),
+    FrameEntry::new(11, 36, 1080, "Door Open", "Glass Door"),
+    FrameEntry::new(11, 0, 1134, "Door Open", "Golden Door"),
+    FrameEntry::new(11, 36, 1134, "Door Open", "Golden Door"),
+    FrameEntry::new(11, 0, 1188, "Door Open", "Honey Door"),
+    FrameEntry::new(11, 36, 1188, "Door Open", "Honey Door"),
+    FrameEntry::new(11, 0, 1242, "Door Open", "Steam Punk Door"),
+    FrameEntry::new(11, 36, 1242, "Door Open", "Steam Punk Door"),
+    FrameEntry::new(11, 0, 1296, "Door Open", "Pumpkin Door"),
+    FrameEntry::new(11, 36, 1296, "Door Open", "Pumpkin Door"),
+    FrameEntry::new(11, 0, 1350, "Door Open", "Spooky Door"),
+    FrameEntry::new(11, 36, 1350, "Door Open", "Spooky Door"),
+    FrameEntry::new(11, 0, 1404, "Door Open", "Pine Door"),
+    FrameEntry::new(11, 36, 1404, "Door Open", "Pine Door"),
+    FrameEntry::new(11, 0, 1458, "Door Open", "Frozen Door"),
+    FrameEntry::new(11, 36, 1458, "Door Open", "Frozen Door"),
+    FrameEntry::new(11, 0, 1512, "Door Open", "Dynasty Door"),
+    FrameEntry::new(11, 36, 1512, "Door Open", "Dynasty Door"),
+    FrameEntry::new(11, 0, 1566, "Door Open", "Palm Wood Door"),
+    FrameEntry::new(11, 36, 1566, "Door Open", "Palm Wood Door"),
+    FrameEntry::new(11, 0, 1620, "Door Open", "Boreal Wood Door"),
+    FrameEntry::new(11, 36, 1620, "Door Open", "Boreal Wood Door"),
+    FrameEntry::new(11, 0, 1674, "Door Open", "Slime Door"),
+    FrameEntry::new(11, 36, 1674, "Door Open", "Slime Door"),
+    FrameEntry::new(11, 0, 1728, "Door Open", "Martian Door"),
+    FrameEntry::new(11, 36, 1728, "Door Open", "Martian Door"),
+    FrameEntry::new(11, 0, 1782, "Door Open", "Meteorite Door"),
+    FrameEntry::new(11, 36, 1782, "Door Open", "Meteorite Door"),
+    FrameEntry::new(11, 0, 1836, "Door Open", "Granite Door"),
+    FrameEntry::new(11, 36, 1836, "Door Open", "Granite Door"),
+    FrameEntry::new(11, 0, 1890, "Door Open", "Marble Door"),
+    FrameEntry::new(11, 36, 1890, "Door Open", "Marble Door"),
+    FrameEntry::new(11, 72, 0, "Door Open", "Crystal Door"),
+    FrameEntry::new(11, 108, 0, "Door Open", "Crystal Door"),
+    FrameEntry::new(12, 0, 0, "Default", "Default"),
+    FrameEntry::new(13, 0, 0, "Bottle", ""),
+    FrameEntry::new(13, 18, 0, "Healing Potion", ""),
+    FrameEntry::new(13, 36, 0, "Mana Potion", ""),
+    FrameEntry::new(13, 54, 0, "Pink Vase", ""),
+    FrameEntry::new(13, 72, 0, "Mug", ""),
+    FrameEntry::new(13, 90, 0, "Dynasty Cup", ""),
+    FrameEntry::new(13, 108, 0, "Wine Glass", ""),
+    FrameEntry::new(13, 126, 0, "Honey Cup", ""),
+    FrameEntry::new(13, 144, 0, "Chalice", ""),
+    FrameEntry::new(14, 0, 0, "Table", "Wooden Table"),
+    FrameEntry::new(14, 54, 0, "Table", "Ebonwood Table"),
+    FrameEntry::new(14, 108, 0, "Table", "Rich Mahogany Table"),
+    FrameEntry::new(14, 162, 0, "Table", "Pearlwood Table"),
+    FrameEntry::new(14, 216, 0, "Table", "Bone Table"),
+    FrameEntry::new(14, 270, 0, "Table", "Flesh Table"),
+    FrameEntry::new(14, 324, 0, "Table", "Living Wood Table"),
+    FrameEntry::new(14, 378, 0, "Table", "Skyware Table"),
+    FrameEntry::new(14, 432, 0, "Table", "Shadewood Table"),
+    FrameEntry::new(14, 486, 0, "Table", "Lihzahrd Table"),
+    FrameEntry::new(14, 540, 0, "Table", "Blue Dungeon Table"),
+    FrameEntry::new(14, 594, 0, "Table", "Green Dungeon Table"),
+    FrameEntry::new(14, 648, 0, "Table", "Pink Dungeon Table"),
+    FrameEntry::new(14, 702, 0, "Table", "Obsidian Table"),
+    FrameEntry::new(14, 756, 0, "Table", "Gothic Table"),
+    FrameEntry::new(14, 810, 0, "Table", "Glass Table"),
+    FrameEntry::new(14, 864, 0, "Banquet Table", "Wooden Banquet"),
+    FrameEntry::new(14, 918, 0, "Bar Table", "Wooden Bar"),
+    FrameEntry::new(14, 972, 0, "Banquet Table", "Golden Banquet"),
+    FrameEntry::new(14, 1026, 0, "Table", "Honey Table"),
+    FrameEntry::new(14, 1080, 0, "Table", "Steam Punk Table"),
+    FrameEntry::new(14, 1134, 0, "Table", "Pumpkin Table"),
+    FrameEntry::new(14, 1188, 0, "Table", "Spooky Table"),
+    FrameEntry::new(14, 1242, 0, "Table", "Pine Table"),
+    FrameEntry::new(14, 1296, 0, "Table", "Frozen Table"),
+    FrameEntry::new(14, 1350, 0, "Table", "Dynasty Table"),
+    FrameEntry::new(14, 1404, 0, "Table", "Palm Wood Table"),
+    FrameEntry::new(14, 1458, 0, "Table", "Mushroom Table"),
+    FrameEntry::new(14, 1512, 0, "Table", "Boreal Wood Table"),
+    FrameEntry::new(14, 1566, 0, "Table", "Slime Table"),
+    FrameEntry::new(14, 1620, 0, "Table", "Cactus Table"),
+    FrameEntry::new(14, 1674, 0, "Table", "Martian Table"),
+    FrameEntry::new(14, 1728, 0, "Table", "Meteorite Table"),
+    FrameEntry::new(14, 1782, 0, "Table", "Granite Table"),
+    FrameEntry::new(14, 1836, 0, "Table", "Marble Table"),
+    FrameEntry::new(15, 0, 0, "Wooden Chair", ""),
+    FrameEntry::new(15, 18, 0, "Wooden Chair", ""),
+    FrameEntry::new(15, 0, 40, "Toilet", ""),
+    FrameEntry::new(15, 18, 40, "Toilet", ""),
+    FrameEntry::new(15, 0, 80, "Ebonwood Chair", ""),
+    FrameEntry::new(15, 18, 80, "Ebonwood Chair", ""),
+    FrameEntry::new(15, 0, 120, "Rich Mahogany Chair", ""),
+    FrameEntry::new(15, 18, 120, "Rich Mahogany Chair", ""),
+    FrameEntry::new(15, 0, 160, "Pearlwood Chair", ""),
+    FrameEntry::new(15, 18, 160, "Pearlwood Chair", ""),
+    FrameEntry::new(15, 0, 200, "Living Wood Chair", ""),
+    FrameEntry::new(15, 18, 200, "Living Wood Chair", ""),
+    FrameEntry::new(15, 0, 240, "Cactus Chair", ""),
+    FrameEntry::new(15, 18, 240, "Cactus Chair", ""),
+    FrameEntry::new(15, 0, 280, "Bone Chair", ""),
+    FrameEntry::new(15, 18, 280, "Bone Chair", ""),
+    FrameEntry::new(15, 0, 320, "Flesh Chair", ""),
+    FrameEntry::new(15, 18, 320, "Flesh Chair", ""),
+    FrameEntry::new(15, 0, 360, "Mushroom Chair", ""),
+    FrameEntry::new(15, 18, 360, "Mushroom Chair", ""),
+    FrameEntry::new(15, 0, 400, "Skyware Chair", ""),
+    FrameEntry::new(15, 18, 400, "Skyware Chair", ""),
+    FrameEntry::new(15, 0, 440, "Shadewood Chair", ""),
+    FrameEntry::new(15, 18, 440, "Shadewood Chair", ""),
+    FrameEntry::new(15, 0, 480, "Lihzahrd Chair", ""),
+    FrameEntry::new(15, 18, 480, "Lihzahrd Chair", ""),
+    FrameEntry::new(15, 0, 520, "Blue Dungeon Chair", ""),
+    FrameEntry::new(15, 18, 520, "Blue Dungeon Chair", ""),
+    FrameEntry::new(15, 0, 560, "Green Dungeon Chair", ""),
+    FrameEntry::new(15, 18, 560, "Green Dungeon Chair", ""),
+    FrameEntry::new(15, 0, 600, "Pink Dungeon Chair", ""),
+    FrameEntry::new(15, 18, 600, "Pink Dungeon Chair", ""),
+    FrameEntry::new(15, 0, 640, "Obsidian Chair", ""),
+    FrameEntry::new(15, 18, 640, "Obsidian Chair", ""),
+    FrameEntry::new(15, 0, 680, "Gothic Chair", ""),
+    FrameEntry::new(15, 18, 680, "Gothic Chair", ""),
+    FrameEntry::new(15, 0, 720, "Glass Chair", ""),
+    FrameEntry::new(15, 18, 720, "Glass Chair", ""),
+    FrameEntry::new(15, 0, 760, "Golden Chair", ""),
+    FrameEntry::new(15, 18, 760, "Golden Chair", ""),
+    FrameEntry::new(15, 0, 800, "Golden Toilet", ""),
+    FrameEntry::new(15, 18, 800, "Golden Toilet", ""),
+    FrameEntry::new(15, 0, 840, "Barstool", ""),
+    FrameEntry::new(15, 18, 840, "Barstool", ""),
+    FrameEntry::new(15, 0, 880, "Honey Chair", ""),
+    FrameEntry::new(15, 18, 880, "Honey Chair", ""),
+    FrameEntry::new(15, 0, 920, "Steam Punk Chair", ""),
+    FrameEntry::new(15, 18, 920, "Steam Punk Chair", ""),
+    FrameEntry::new(15, 0, 960, "Pumpkin Chair", ""),
+    FrameEntry::new(15, 18, 960, "Pumpkin Chair", ""),
+    FrameEntry::new(15, 0, 1000, "Spooky Chair", ""),
+    FrameEntry::new(15, 18, 1000, "Spooky Chair", ""),
+    FrameEntry::new(15, 0, 1040, "Pine Chair", ""),
+    FrameEntry::new(15, 18, 1040, "Pine Chair", ""),
+    FrameEntry::new(15, 0, 1080, "Dynasty Chair", ""),
+    FrameEntry::new(15, 18, 1080, "Dynasty Chair", ""),
+    FrameEntry::new(15, 0, 1120, "Frozen Chair", ""),
+    FrameEntry::new(15, 18, 1120, "Frozen Chair", ""),
+    FrameEntry::new(15, 0, 1160, "Palm Wood Chair", ""),
+    FrameEntry::new(15, 18, 1160, "Palm Wood Chair", ""),
+    FrameEntry::new(15, 0, 1200, "Boreal Wood Chair", ""),
+    FrameEntry::new(15, 18, 1200, "Boreal Wood Chair", ""),
+    FrameEntry::new(15, 0, 1240, "Slime Chair", ""),
+    FrameEntry::new(15, 18, 1240, "Slime Chair", ""),
+    FrameEntry::new(15, 0, 1280, "Martian Hover Chair", ""),
+    FrameEntry::new(15, 18, 1280, "Martian Hover Chair", ""),
+    FrameEntry::new(15, 0, 1320, "Meteorite Chair", ""),
+    FrameEntry::new(15, 18, 1320, "Meteorite Chair", ""),
+    FrameEntry::new(15, 0, 1360, "Granite Chair", ""),
+    FrameEntry::new(15, 18, 1360, "Granite Chair", ""),
+    FrameEntry::new(15, 0, 1400, "Marble Chair", ""),
+    FrameEntry::new(15, 18, 1400, "Marble Chair", ""),
+    FrameEntry::new(15, 0, 1440, "Crystal Chair", ""),
+    FrameEntry::new(15, 18, 1440, "Crystal Chair", ""),
+    FrameEntry::new(16, 0, 0, "Iron Anvil", ""),
+    FrameEntry::new(16, 36, 0, "Lead Anvil", ""),
+    FrameEntry::new(18, 0, 0, "Work Bench", "Wood"),
+    FrameEntry::new(18, 36, 0, "Work Bench", "Ebonwood"),
+    FrameEntry::new(18, 72, 0, "Work Bench", "Rich Mahogany"),
+    FrameEntry::new(18, 108, 0, "Work Bench", "Pearlwood"),
+    FrameEntry::new(18, 144, 0, "Work Bench", "Bone"),
+    FrameEntry::new(18, 180, 0, "Work Bench", "Cactus"),
+    FrameEntry::new(18, 216, 0, "Work Bench", "Flesh"),
+    FrameEntry::new(18, 252, 0, "Work Bench", "Mushroom"),
+    FrameEntry::new(18, 288, 0, "Work Bench", "Slime"),
+    FrameEntry::new(18, 324, 0, "Work Bench", "Shadewood"),
+    FrameEntry::new(18, 360, 0, "Work Bench", "Lihzahrd"),
+    FrameEntry::new(18, 396, 0, "Work Bench", "Blue Dungeon"),
+    FrameEntry::new(18, 432, 0, "Work Bench", "Green Dungeon"),
+    FrameEntry::new(18, 468, 0, "Work Bench", "Pink Dungeon"),
+    FrameEntry::new(18, 504, 0, "Work Bench", "Obsidian"),
+    FrameEntry::new(18, 540, 0, "Work Bench", "Gothic"),
+    FrameEntry::new(18, 576, 0, "Work Bench", "Pumpkin"),
+    FrameEntry::new(18, 612, 0, "Work Bench", "Spooky"),
+    FrameEntry::new(18, 648, 0, "Work Bench", "Dynasty"),
+    FrameEntry::new(18, 684, 0, "Work Bench", "Honey"),
+    FrameEntry::new(18, 720, 0, "Work Bench", "Frozen"),
+    FrameEntry::new(18, 756, 0, "Work Bench", "Steampunk"),
+    FrameEntry::new(18, 792, 0, "Work Bench", "Palm Wood"),
+    FrameEntry::new(18, 828, 0, "Work Bench", "Boreal"),
+    FrameEntry::new(18, 864, 0, "Work Bench", "Skyware"),
+    FrameEntry::new(18, 900, 0, "Work Bench", "Glass"),
+    FrameEntry::new(18, 936, 0, "Work Bench", "Living Wood"),
+    FrameEntry::new(18, 972, 0, "Work Bench", "Martian"),
+    FrameEntry::new(18, 1008, 0, "Work Bench", "Meteorite"),
+    FrameEntry::new(18, 1044, 0, "Work Bench", "Granite"),
+    FrameEntry::new(18, 1080, 0, "Work Bench", "Marble"),
+    FrameEntry::new(18, 1116, 0, "Work Bench", "Crystal"),
+    FrameEntry::new(18, 1152, 0, "Work Bench", "Golden"),
+    FrameEntry::new(19, 0, 0, "Platform", "Wood Flat"),
+    FrameEntry::new(19, 18, 0, "Platform", "Wood Endcap Right"),
+    FrameEntry::new(19, 36, 0, "Platform", "Wood Endcap Left"),
+    FrameEntry::new(19, 54, 0, "Platform", "Wood Attach Left"),
+    FrameEntry::new(19, 72, 0, "Platform", "Wood Attach Right"),
+    FrameEntry::new(19, 90, 0, "Platform", "Wood Single"),
+    FrameEntry::new(19, 108, 0, "Platform", "Wood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 0, "Platform", "Wood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 0, "Stair", "Wood Up-Right Riser"),
+    FrameEntry::new(19, 162, 0, "Stair", "Wood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 0, "Stair", "Wood Up-Left Riser"),
+    FrameEntry::new(19, 198, 0, "Stair", "Wood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 0, "Stair", "Wood Top Landing R"),
+    FrameEntry::new(19, 234, 0, "Stair", "Wood Top Landing L"),
+    FrameEntry::new(19, 252, 0, "Stair", "Wood Top Landing L-R"),
+    FrameEntry::new(19, 270, 0, "Stair", "Wood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 0, "Stair", "Wood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 0, "Stair", "Wood Bottom Landing R"),
+    FrameEntry::new(19, 324, 0, "Stair", "Wood Bottom Landing L"),
+    FrameEntry::new(19, 342, 0, "Stair", "Wood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 0, "Stair", "Wood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 0, "Stair", "Wood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 0, "Stair", "Wood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 0, "Stair", "Wood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 0, "Stair", "Wood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 0, "Stair", "Wood Inverted Right"),
+    FrameEntry::new(19, 468, 0, "Stair", "Wood Inverted Left"),
+    FrameEntry::new(19, 0, 18, "Platform", "Ebonwood Flat"),
+    FrameEntry::new(19, 18, 18, "Platform", "Ebonwood Endcap Right"),
+    FrameEntry::new(19, 36, 18, "Platform", "Ebonwood Endcap Left"),
+    FrameEntry::new(19, 54, 18, "Platform", "Ebonwood Attach Left"),
+    FrameEntry::new(19, 72, 18, "Platform", "Ebonwood Attach Right"),
+    FrameEntry::new(19, 90, 18, "Platform", "Ebonwood Single"),
+    FrameEntry::new(19, 108, 18, "Platform", "Ebonwood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 18, "Platform", "Ebonwood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 18, "Stair", "Ebonwood Up-Right Riser"),
+    FrameEntry::new(19, 162, 18, "Stair", "Ebonwood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 18, "Stair", "Ebonwood Up-Left Riser"),
+    FrameEntry::new(19, 198, 18, "Stair", "Ebonwood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 18, "Stair", "Ebonwood Top Landing R"),
+    FrameEntry::new(19, 234, 18, "Stair", "Ebonwood Top Landing L"),
+    FrameEntry::new(19, 252, 18, "Stair", "Ebonwood Top Landing L-R"),
+    FrameEntry::new(19, 270, 18, "Stair", "Ebonwood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 18, "Stair", "Ebonwood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 18, "Stair", "Ebonwood Bottom Landing R"),
+    FrameEntry::new(19, 324, 18, "Stair", "Ebonwood Bottom Landing L"),
+    FrameEntry::new(19, 342, 18, "Stair", "Ebonwood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 18, "Stair", "Ebonwood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 18, "Stair", "Ebonwood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 18, "Stair", "Ebonwood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 18, "Stair", "Ebonwood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 18, "Stair", "Ebonwood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 18, "Stair", "Ebonwood Inverted Right"),
+    FrameEntry::new(19, 468, 18, "Stair", "Ebonwood Inverted Left"),
+    FrameEntry::new(19, 0, 36, "Platform", "Rich Mahogany Flat"),
+    FrameEntry::new(19, 18, 36, "Platform", "Rich Mahogany Endcap Right"),
+    FrameEntry::new(19, 36, 36, "Platform", "Rich Mahogany Endcap Left"),
+    FrameEntry::new(19, 54, 36, "Platform", "Rich Mahogany Attach Left"),
+    FrameEntry::new(19, 72, 36, "Platform", "Rich Mahogany Attach Right"),
+    FrameEntry::new(19, 90, 36, "Platform", "Rich Mahogany Single"),
+    FrameEntry::new(19, 108, 36, "Platform", "Rich Mahogany Attach Left Endcap"),
+    FrameEntry::new(19, 126, 36, "Platform", "Rich Mahogany Attach Right Endcap"),
+    FrameEntry::new(19, 144, 36, "Stair", "Rich Mahogany Up-Right Riser"),
+    FrameEntry::new(19, 162, 36, "Stair", "Rich Mahogany Up-Right Stringer"),
+    FrameEntry::new(19, 180, 36, "Stair", "Rich Mahogany Up-Left Riser"),
+    FrameEntry::new(19, 198, 36, "Stair", "Rich Mahogany Up-Left Stringer"),
+    FrameEntry::new(19, 216, 36, "Stair", "Rich Mahogany Top Landing R"),
+    FrameEntry::new(19, 234, 36, "Stair", "Rich Mahogany Top Landing L"),
+    FrameEntry::new(19, 252, 36, "Stair", "Rich Mahogany Top Landing L-R"),
+    FrameEntry::new(19, 270, 36, "Stair", "Rich Mahogany Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 36, "Stair", "Rich Mahogany Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 36, "Stair", "Rich Mahogany Bottom Landing R"),
+    FrameEntry::new(19, 324, 36, "Stair", "Rich Mahogany Bottom Landing L"),
+    FrameEntry::new(19, 342, 36, "Stair", "Rich Mahogany Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 36, "Stair", "Rich Mahogany Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 36, "Stair", "Rich Mahogany Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 36, "Stair", "Rich Mahogany Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 36, "Stair", "Rich Mahogany Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 36, "Stair", "Rich Mahogany Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 36, "Stair", "Rich Mahogany Inverted Right"),
+    FrameEntry::new(19, 468, 36, "Stair", "Rich Mahogany Inverted Left"),
+    FrameEntry::new(19, 0, 54, "Platform", "Pearlwood Flat"),
+    FrameEntry::new(19, 18, 54, "Platform", "Pearlwood Endcap Right"),
+    FrameEntry::new(19, 36, 54, "Platform", "Pearlwood Endcap Left"),
+    FrameEntry::new(19, 54, 54, "Platform", "Pearlwood Attach Left"),
+    FrameEntry::new(19, 72, 54, "Platform", "Pearlwood Attach Right"),
+    FrameEntry::new(19, 90, 54, "Platform", "Pearlwood Single"),
+    FrameEntry::new(19, 108, 54, "Platform", "Pearlwood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 54, "Platform", "Pearlwood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 54, "Stair", "Pearlwood Up-Right Riser"),
+    FrameEntry::new(19, 162, 54, "Stair", "Pearlwood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 54, "Stair", "Pearlwood Up-Left Riser"),
+    FrameEntry::new(19, 198, 54, "Stair", "Pearlwood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 54, "Stair", "Pearlwood Top Landing R"),
+    FrameEntry::new(19, 234, 54, "Stair", "Pearlwood Top Landing L"),
+    FrameEntry::new(19, 252, 54, "Stair", "Pearlwood Top Landing L-R"),
+    FrameEntry::new(19, 270, 54, "Stair", "Pearlwood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 54, "Stair", "Pearlwood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 54, "Stair", "Pearlwood Bottom Landing R"),
+    FrameEntry::new(19, 324, 54, "Stair", "Pearlwood Bottom Landing L"),
+    FrameEntry::new(19, 342, 54, "Stair", "Pearlwood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 54, "Stair", "Pearlwood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 54, "Stair", "Pearlwood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 54, "Stair", "Pearlwood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 54, "Stair", "Pearlwood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 54, "Stair", "Pearlwood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 54, "Stair", "Pearlwood Inverted Right"),
+    FrameEntry::new(19, 468, 54, "Stair", "Pearlwood Inverted Left"),
+    FrameEntry::new(19, 0, 72, "Platform", "Bone Flat"),
+    FrameEntry::new(19, 18, 72, "Platform", "Bone Endcap Right"),
+    FrameEntry::new(19, 36, 72, "Platform", "Bone Endcap Left"),
+    FrameEntry::new(19, 54, 72, "Platform", "Bone Attach Left"),
+    FrameEntry::new(19, 72, 72, "Platform", "Bone Attach Right"),
+    FrameEntry::new(19, 90, 72, "Platform", "Bone Single"),
+    FrameEntry::new(19, 108, 72, "Platform", "Bone Attach Left Endcap"),
+    FrameEntry::new(19, 126, 72, "Platform", "Bone Attach Right Endcap"),
+    FrameEntry::new(19, 144, 72, "Stair", "Bone Up-Right Riser"),
+    FrameEntry::new(19, 162, 72, "Stair", "Bone Up-Right Stringer"),
+    FrameEntry::new(19, 180, 72, "Stair", "Bone Up-Left Riser"),
+    FrameEntry::new(19, 198, 72, "Stair", "Bone Up-Left Stringer"),
+    FrameEntry::new(19, 216, 72, "Stair", "Bone Top Landing R"),
+    FrameEntry::new(19, 234, 72, "Stair", "Bone Top Landing L"),
+    FrameEntry::new(19, 252, 72, "Stair", "Bone Top Landing L-R"),
+    FrameEntry::new(19, 270, 72, "Stair", "Bone Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 72, "Stair", "Bone Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 72, "Stair", "Bone Bottom Landing R"),
+    FrameEntry::new(19, 324, 72, "Stair", "Bone Bottom Landing L"),
+    FrameEntry::new(19, 342, 72, "Stair", "Bone Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 72, "Stair", "Bone Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 72, "Stair", "Bone Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 72, "Stair", "Bone Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 72, "Stair", "Bone Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 72, "Stair", "Bone Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 72, "Stair", "Bone Inverted Right"),
+    FrameEntry::new(19, 468, 72, "Stair", "Bone Inverted Left"),
+    FrameEntry::new(19, 0, 90, "Platform", "Shadewood Flat"),
+    FrameEntry::new(19, 18, 90, "Platform", "Shadewood Endcap Right"),
+    FrameEntry::new(19, 36, 90, "Platform", "Shadewood Endcap Left"),
+    FrameEntry::new(19, 54, 90, "Platform", "Shadewood Attach Left"),
+    FrameEntry::new(19, 72, 90, "Platform", "Shadewood Attach Right"),
+    FrameEntry::new(19, 90, 90, "Platform", "Shadewood Single"),
+    FrameEntry::new(19, 108, 90, "Platform", "Shadewood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 90, "Platform", "Shadewood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 90, "Stair", "Shadewood Up-Right Riser"),
+    FrameEntry::new(19, 162, 90, "Stair", "Shadewood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 90, "Stair", "Shadewood Up-Left Riser"),
+    FrameEntry::new(19, 198, 90, "Stair", "Shadewood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 90, "Stair", "Shadewood Top Landing R"),
+    FrameEntry::new(19, 234, 90, "Stair", "Shadewood Top Landing L"),
+    FrameEntry::new(19, 252, 90, "Stair", "Shadewood Top Landing L-R"),
+    FrameEntry::new(19, 270, 90, "Stair", "Shadewood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 90, "Stair", "Shadewood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 90, "Stair", "Shadewood Bottom Landing R"),
+    FrameEntry::new(19, 324, 90, "Stair", "Shadewood Bottom Landing L"),
+    FrameEntry::new(19, 342, 90, "Stair", "Shadewood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 90, "Stair", "Shadewood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 90, "Stair", "Shadewood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 90, "Stair", "Shadewood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 90, "Stair", "Shadewood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 90, "Stair", "Shadewood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 90, "Stair", "Shadewood Inverted Right"),
+    FrameEntry::new(19, 468, 90, "Stair", "Shadewood Inverted Left"),
+    FrameEntry::new(19, 0, 108, "Platform", "Blue Brick Flat"),
+    FrameEntry::new(19, 18, 108, "Platform", "Blue Brick Endcap Right"),
+    FrameEntry::new(19, 36, 108, "Platform", "Blue Brick Endcap Left"),
+    FrameEntry::new(19, 54, 108, "Platform", "Blue Brick Attach Left"),
+    FrameEntry::new(19, 72, 108, "Platform", "Blue Brick Attach Right"),
+    FrameEntry::new(19, 90, 108, "Platform", "Blue Brick Single"),
+    FrameEntry::new(19, 108, 108, "Platform", "Blue Brick Attach Left Endcap"),
+    FrameEntry::new(19, 126, 108, "Platform", "Blue Brick Attach Right Endcap"),
+    FrameEntry::new(19, 144, 108, "Stair", "Blue Brick Up-Right Riser"),
+    FrameEntry::new(19, 162, 108, "Stair", "Blue Brick Up-Right Stringer"),
+    FrameEntry::new(19, 180, 108, "Stair", "Blue Brick Up-Left Riser"),
+    FrameEntry::new(19, 198, 108, "Stair", "Blue Brick Up-Left Stringer"),
+    FrameEntry::new(19, 216, 108, "Stair", "Blue Brick Top Landing R"),
+    FrameEntry::new(19, 234, 108, "Stair", "Blue Brick Top Landing L"),
+    FrameEntry::new(19, 252, 108, "Stair", "Blue Brick Top Landing L-R"),
+    FrameEntry::new(19, 270, 108, "Stair", "Blue Brick Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 108, "Stair", "Blue Brick Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 108, "Stair", "Blue Brick Bottom Landing R"),
+    FrameEntry::new(19, 324, 108, "Stair", "Blue Brick Bottom Landing L"),
+    FrameEntry::new(19, 342, 108, "Stair", "Blue Brick Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 108, "Stair", "Blue Brick Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 108, "Stair", "Blue Brick Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 108, "Stair", "Blue Brick Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 108, "Stair", "Blue Brick Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 108, "Stair", "Blue Brick Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 108, "Stair", "Blue Brick Inverted Right"),
+    FrameEntry::new(19, 468, 108, "Stair", "Blue Brick Inverted Left"),
+    FrameEntry::new(19, 0, 126, "Platform", "Pink Brick Flat"),
+    FrameEntry::new(19, 18, 126, "Platform", "Pink Brick Endcap Right"),
+    FrameEntry::new(19, 36, 126, "Platform", "Pink Brick Endcap Left"),
+    FrameEntry::new(19, 54, 126, "Platform", "Pink Brick Attach Left"),
+    FrameEntry::new(19, 72, 126, "Platform", "Pink Brick Attach Right"),
+    FrameEntry::new(19, 90, 126, "Platform", "Pink Brick Single"),
+    FrameEntry::new(19, 108, 126, "Platform", "Pink Brick Attach Left Endcap"),
+    FrameEntry::new(19, 126, 126, "Platform", "Pink Brick Attach Right Endcap"),
+    FrameEntry::new(19, 144, 126, "Stair", "Pink Brick Up-Right Riser"),
+    FrameEntry::new(19, 162, 126, "Stair", "Pink Brick Up-Right Stringer"),
+    FrameEntry::new(19, 180, 126, "Stair", "Pink Brick Up-Left Riser"),
+    FrameEntry::new(19, 198, 126, "Stair", "Pink Brick Up-Left Stringer"),
+    FrameEntry::new(19, 216, 126, "Stair", "Pink Brick Top Landing R"),
+    FrameEntry::new(19, 234, 126, "Stair", "Pink Brick Top Landing L"),
+    FrameEntry::new(19, 252, 126, "Stair", "Pink Brick Top Landing L-R"),
+    FrameEntry::new(19, 270, 126, "Stair", "Pink Brick Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 126, "Stair", "Pink Brick Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 126, "Stair", "Pink Brick Bottom Landing R"),
+    FrameEntry::new(19, 324, 126, "Stair", "Pink Brick Bottom Landing L"),
+    FrameEntry::new(19, 342, 126, "Stair", "Pink Brick Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 126, "Stair", "Pink Brick Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 126, "Stair", "Pink Brick Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 126, "Stair", "Pink Brick Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 126, "Stair", "Pink Brick Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 126, "Stair", "Pink Brick Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 126, "Stair", "Pink Brick Inverted Right"),
+    FrameEntry::new(19, 468, 126, "Stair", "Pink Brick Inverted Left"),
+    FrameEntry::new(19, 0, 144, "Platform", "Green Brick Flat"),
+    FrameEntry::new(19, 18, 144, "Platform", "Green Brick Endcap Right"),
+    FrameEntry::new(19, 36, 144, "Platform", "Green Brick Endcap Left"),
+    FrameEntry::new(19, 54, 144, "Platform", "Green Brick Attach Left"),
+    FrameEntry::new(19, 72, 144, "Platform", "Green Brick Attach Right"),
+    FrameEntry::new(19, 90, 144, "Platform", "Green Brick Single"),
+    FrameEntry::new(19, 108, 144, "Platform", "Green Brick Attach Left Endcap"),
+    FrameEntry::new(19, 126, 144, "Platform", "Green Brick Attach Right Endcap"),
+    FrameEntry::new(19, 144, 144, "Stair", "Green Brick Up-Right Riser"),
+    FrameEntry::new(19, 162, 144, "Stair", "Green Brick Up-Right Stringer"),
+    FrameEntry::new(19, 180, 144, "Stair", "Green Brick Up-Left Riser"),
+    FrameEntry::new(19, 198, 144, "Stair", "Green Brick Up-Left Stringer"),
+    FrameEntry::new(19, 216, 144, "Stair", "Green Brick Top Landing R"),
+    FrameEntry::new(19, 234, 144, "Stair", "Green Brick Top Landing L"),
+    FrameEntry::new(19, 252, 144, "Stair", "Green Brick Top Landing L-R"),
+    FrameEntry::new(19, 270, 144, "Stair", "Green Brick Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 144, "Stair", "Green Brick Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 144, "Stair", "Green Brick Bottom Landing R"),
+    FrameEntry::new(19, 324, 144, "Stair", "Green Brick Bottom Landing L"),
+    FrameEntry::new(19, 342, 144, "Stair", "Green Brick Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 144, "Stair", "Green Brick Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 144, "Stair", "Green Brick Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 144, "Stair", "Green Brick Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 144, "Stair", "Green Brick Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 144, "Stair", "Green Brick Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 144, "Stair", "Green Brick Inverted Right"),
+    FrameEntry::new(19, 468, 144, "Stair", "Green Brick Inverted Left"),
+    FrameEntry::new(19, 0, 162, "Platform", "Metal Shelf Flat"),
+    FrameEntry::new(19, 18, 162, "Platform", "Metal Shelf Endcap Right"),
+    FrameEntry::new(19, 36, 162, "Platform", "Metal Shelf Endcap Left"),
+    FrameEntry::new(19, 54, 162, "Platform", "Metal Shelf Attach Left"),
+    FrameEntry::new(19, 72, 162, "Platform", "Metal Shelf Attach Right"),
+    FrameEntry::new(19, 90, 162, "Platform", "Metal Shelf Single"),
+    FrameEntry::new(19, 108, 162, "Platform", "Metal Shelf Attach Left Endcap"),
+    FrameEntry::new(19, 126, 162, "Platform", "Metal Shelf Attach Right Endcap"),
+    FrameEntry::new(19, 144, 162, "Stair", "Metal Shelf Up-Right Riser"),
+    FrameEntry::new(19, 162, 162, "Stair", "Metal Shelf Up-Right Stringer"),
+    FrameEntry::new(19, 180, 162, "Stair", "Metal Shelf Up-Left Riser"),
+    FrameEntry::new(19, 198, 162, "Stair", "Metal Shelf Up-Left Stringer"),
+    FrameEntry::new(19, 216, 162, "Stair", "Metal Shelf Top Landing R"),
+    FrameEntry::new(19, 234, 162, "Stair", "Metal Shelf Top Landing L"),
+    FrameEntry::new(19, 252, 162, "Stair", "Metal Shelf Top Landing L-R"),
+    FrameEntry::new(19, 270, 162, "Stair", "Metal Shelf Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 162, "Stair", "Metal Shelf Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 162, "Stair", "Metal Shelf Bottom Landing R"),
+    FrameEntry::new(19, 324, 162, "Stair", "Metal Shelf Bottom Landing L"),
+    FrameEntry::new(19, 342, 162, "Stair", "Metal Shelf Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 162, "Stair", "Metal Shelf Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 162, "Stair", "Metal Shelf Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 162, "Stair", "Metal Shelf Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 162, "Stair", "Metal Shelf Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 162, "Stair", "Metal Shelf Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 162, "Stair", "Metal Shelf Inverted Right"),
+    FrameEntry::new(19, 468, 162, "Stair", "Metal Shelf Inverted Left"),
+    FrameEntry::new(19, 0, 180, "Platform", "Brass Shelf Flat"),
+    FrameEntry::new(19, 18, 180, "Platform", "Brass Shelf Endcap Right"),
+    FrameEntry::new(19, 36, 180, "Platform", "Brass Shelf Endcap Left"),
+    FrameEntry::new(19, 54, 180, "Platform", "Brass Shelf Attach Left"),
+    FrameEntry::new(19, 72, 180, "Platform", "Brass Shelf Attach Right"),
+    FrameEntry::new(19, 90, 180, "Platform", "Brass Shelf Single"),
+    FrameEntry::new(19, 108, 180, "Platform", "Brass Shelf Attach Left Endcap"),
+    FrameEntry::new(19, 126, 180, "Platform", "Brass Shelf Attach Right Endcap"),
+    FrameEntry::new(19, 144, 180, "Stair", "Brass Shelf Up-Right Riser"),
+    FrameEntry::new(19, 162, 180, "Stair", "Brass Shelf Up-Right Stringer"),
+    FrameEntry::new(19, 180, 180, "Stair", "Brass Shelf Up-Left Riser"),
+    FrameEntry::new(19, 198, 180, "Stair", "Brass Shelf Up-Left Stringer"),
+    FrameEntry::new(19, 216, 180, "Stair", "Brass Shelf Top Landing R"),
+    FrameEntry::new(19, 234, 180, "Stair", "Brass Shelf Top Landing L"),
+    FrameEntry::new(19, 252, 180, "Stair", "Brass Shelf Top Landing L-R"),
+    FrameEntry::new(19, 270, 180, "Stair", "Brass Shelf Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 180, "Stair", "Brass Shelf Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 180, "Stair", "Brass Shelf Bottom Landing R"),
+    FrameEntry::new(19, 324, 180, "Stair", "Brass Shelf Bottom Landing L"),
+    FrameEntry::new(19, 342, 180, "Stair", "Brass Shelf Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 180, "Stair", "Brass Shelf Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 180, "Stair", "Brass Shelf Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 180, "Stair", "Brass Shelf Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 180, "Stair", "Brass Shelf Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 180, "Stair", "Brass Shelf Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 180, "Stair", "Brass Shelf Inverted Right"),
+    FrameEntry::new(19, 468, 180, "Stair", "Brass Shelf Inverted Left"),
+    FrameEntry::new(19, 0, 198, "Platform", "Wood Shelf Flat"),
+    FrameEntry::new(19, 18, 198, "Platform", "Wood Shelf Endcap Right"),
+    FrameEntry::new(19, 36, 198, "Platform", "Wood Shelf Endcap Left"),
+    FrameEntry::new(19, 54, 198, "Platform", "Wood Shelf Attach Left"),
+    FrameEntry::new(19, 72, 198, "Platform", "Wood Shelf Attach Right"),
+    FrameEntry::new(19, 90, 198, "Platform", "Wood Shelf Single"),
+    FrameEntry::new(19, 108, 198, "Platform", "Wood Shelf Attach Left Endcap"),
+    FrameEntry::new(19, 126, 198, "Platform", "Wood Shelf Attach Right Endcap"),
+    FrameEntry::new(19, 144, 198, "Stair", "Wood Shelf Up-Right Riser"),
+    FrameEntry::new(19, 162, 198, "Stair", "Wood Shelf Up-Right Stringer"),
+    FrameEntry::new(19, 180, 198, "Stair", "Wood Shelf Up-Left Riser"),
+    FrameEntry::new(19, 198, 198, "Stair", "Wood Shelf Up-Left Stringer"),
+    FrameEntry::new(19, 216, 198, "Stair", "Wood Shelf Top Landing R"),
+    FrameEntry::new(19, 234, 198, "Stair", "Wood Shelf Top Landing L"),
+    FrameEntry::new(19, 252, 198, "Stair", "Wood Shelf Top Landing L-R"),
+    FrameEntry::new(19, 270, 198, "Stair", "Wood Shelf Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 198, "Stair", "Wood Shelf Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 198, "Stair", "Wood Shelf Bottom Landing R"),
+    FrameEntry::new(19, 324, 198, "Stair", "Wood Shelf Bottom Landing L"),
+    FrameEntry::new(19, 342, 198, "Stair", "Wood Shelf Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 198, "Stair", "Wood Shelf Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 198, "Stair", "Wood Shelf Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 198, "Stair", "Wood Shelf Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 198, "Stair", "Wood Shelf Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 198, "Stair", "Wood Shelf Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 198, "Stair", "Wood Shelf Inverted Right"),
+    FrameEntry::new(19, 468, 198, "Stair", "Wood Shelf Inverted Left"),
+    FrameEntry::new(19, 0, 216, "Platform", "Dungeon Shelf Flat"),
+    FrameEntry::new(19, 18, 216, "Platform", "Dungeon Shelf Endcap Right"),
+    FrameEntry::new(19, 36, 216, "Platform", "Dungeon Shelf Endcap Left"),
+    FrameEntry::new(19, 54, 216, "Platform", "Dungeon Shelf Attach Left"),
+    FrameEntry::new(19, 72, 216, "Platform", "Dungeon Shelf Attach Right"),
+    FrameEntry::new(19, 90, 216, "Platform", "Dungeon Shelf Single"),
+    FrameEntry::new(19, 108, 216, "Platform", "Dungeon Shelf Attach Left Endcap"),
+    FrameEntry::new(19, 126, 216, "Platform", "Dungeon Shelf Attach Right Endcap"),
+    FrameEntry::new(19, 144, 216, "Stair", "Dungeon Shelf Up-Right Riser"),
+    FrameEntry::new(19, 162, 216, "Stair", "Dungeon Shelf Up-Right Stringer"),
+    FrameEntry::new(19, 180, 216, "Stair", "Dungeon Shelf Up-Left Riser"),
+    FrameEntry::new(19, 198, 216, "Stair", "Dungeon Shelf Up-Left Stringer"),
+    FrameEntry::new(19, 216, 216, "Stair", "Dungeon Shelf Top Landing R"),
+    FrameEntry::new(19, 234, 216, "Stair", "Dungeon Shelf Top Landing L"),
+    FrameEntry::new(19, 252, 216, "Stair", "Dungeon Shelf Top Landing L-R"),
+    FrameEntry::new(19, 270, 216, "Stair", "Dungeon Shelf Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 216, "Stair", "Dungeon Shelf Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 216, "Stair", "Dungeon Shelf Bottom Landing R"),
+    FrameEntry::new(19, 324, 216, "Stair", "Dungeon Shelf Bottom Landing L"),
+    FrameEntry::new(19, 342, 216, "Stair", "Dungeon Shelf Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 216, "Stair", "Dungeon Shelf Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 216, "Stair", "Dungeon Shelf Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 216, "Stair", "Dungeon Shelf Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 216, "Stair", "Dungeon Shelf Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 216, "Stair", "Dungeon Shelf Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 216, "Stair", "Dungeon Shelf Inverted Right"),
+    FrameEntry::new(19, 468, 216, "Stair", "Dungeon Shelf Inverted Left"),
+    FrameEntry::new(19, 0, 234, "Platform", "Obsidian Flat"),
+    FrameEntry::new(19, 18, 234, "Platform", "Obsidian Endcap Right"),
+    FrameEntry::new(19, 36, 234, "Platform", "Obsidian Endcap Left"),
+    FrameEntry::new(19, 54, 234, "Platform", "Obsidian Attach Left"),
+    FrameEntry::new(19, 72, 234, "Platform", "Obsidian Attach Right"),
+    FrameEntry::new(19, 90, 234, "Platform", "Obsidian Single"),
+    FrameEntry::new(19, 108, 234, "Platform", "Obsidian Attach Left Endcap"),
+    FrameEntry::new(19, 126, 234, "Platform", "Obsidian Attach Right Endcap"),
+    FrameEntry::new(19, 144, 234, "Stair", "Obsidian Up-Right Riser"),
+    FrameEntry::new(19, 162, 234, "Stair", "Obsidian Up-Right Stringer"),
+    FrameEntry::new(19, 180, 234, "Stair", "Obsidian Up-Left Riser"),
+    FrameEntry::new(19, 198, 234, "Stair", "Obsidian Up-Left Stringer"),
+    FrameEntry::new(19, 216, 234, "Stair", "Obsidian Top Landing R"),
+    FrameEntry::new(19, 234, 234, "Stair", "Obsidian Top Landing L"),
+    FrameEntry::new(19, 252, 234, "Stair", "Obsidian Top Landing L-R"),
+    FrameEntry::new(19, 270, 234, "Stair", "Obsidian Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 234, "Stair", "Obsidian Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 234, "Stair", "Obsidian Bottom Landing R"),
+    FrameEntry::new(19, 324, 234, "Stair", "Obsidian Bottom Landing L"),
+    FrameEntry::new(19, 342, 234, "Stair", "Obsidian Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 234, "Stair", "Obsidian Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 234, "Stair", "Obsidian Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 234, "Stair", "Obsidian Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 234, "Stair", "Obsidian Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 234, "Stair", "Obsidian Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 234, "Stair", "Obsidian Inverted Right"),
+    FrameEntry::new(19, 468, 234, "Stair", "Obsidian Inverted Left"),
+    FrameEntry::new(19, 0, 252, "Platform", "Glass Flat"),
+    FrameEntry::new(19, 18, 252, "Platform", "Glass Endcap Right"),
+    FrameEntry::new(19, 36, 252, "Platform", "Glass Endcap Left"),
+    FrameEntry::new(19, 54, 252, "Platform", "Glass Attach Left"),
+    FrameEntry::new(19, 72, 252, "Platform", "Glass Attach Right"),
+    FrameEntry::new(19, 90, 252, "Platform", "Glass Single"),
+    FrameEntry::new(19, 108, 252, "Platform", "Glass Attach Left Endcap"),
+    FrameEntry::new(19, 126, 252, "Platform", "Glass Attach Right Endcap"),
+    FrameEntry::new(19, 144, 252, "Stair", "Glass Up-Right Riser"),
+    FrameEntry::new(19, 162, 252, "Stair", "Glass Up-Right Stringer"),
+    FrameEntry::new(19, 180, 252, "Stair", "Glass Up-Left Riser"),
+    FrameEntry::new(19, 198, 252, "Stair", "Glass Up-Left Stringer"),
+    FrameEntry::new(19, 216, 252, "Stair", "Glass Top Landing R"),
+    FrameEntry::new(19, 234, 252, "Stair", "Glass Top Landing L"),
+    FrameEntry::new(19, 252, 252, "Stair", "Glass Top Landing L-R"),
+    FrameEntry::new(19, 270, 252, "Stair", "Glass Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 252, "Stair", "Glass Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 252, "Stair", "Glass Bottom Landing R"),
+    FrameEntry::new(19, 324, 252, "Stair", "Glass Bottom Landing L"),
+    FrameEntry::new(19, 342, 252, "Stair", "Glass Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 252, "Stair", "Glass Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 252, "Stair", "Glass Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 252, "Stair", "Glass Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 252, "Stair", "Glass Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 252, "Stair", "Glass Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 252, "Stair", "Glass Inverted Right"),
+    FrameEntry::new(19, 468, 252, "Stair", "Glass Inverted Left"),
+    FrameEntry::new(19, 0, 270, "Platform", "Pumpkin Flat"),
+    FrameEntry::new(19, 18, 270, "Platform", "Pumpkin Endcap Right"),
+    FrameEntry::new(19, 36, 270, "Platform", "Pumpkin Endcap Left"),
+    FrameEntry::new(19, 54, 270, "Platform", "Pumpkin Attach Left"),
+    FrameEntry::new(19, 72, 270, "Platform", "Pumpkin Attach Right"),
+    FrameEntry::new(19, 90, 270, "Platform", "Pumpkin Single"),
+    FrameEntry::new(19, 108, 270, "Platform", "Pumpkin Attach Left Endcap"),
+    FrameEntry::new(19, 126, 270, "Platform", "Pumpkin Attach Right Endcap"),
+    FrameEntry::new(19, 144, 270, "Stair", "Pumpkin Up-Right Riser"),
+    FrameEntry::new(19, 162, 270, "Stair", "Pumpkin Up-Right Stringer"),
+    FrameEntry::new(19, 180, 270, "Stair", "Pumpkin Up-Left Riser"),
+    FrameEntry::new(19, 198, 270, "Stair", "Pumpkin Up-Left Stringer"),
+    FrameEntry::new(19, 216, 270, "Stair", "Pumpkin Top Landing R"),
+    FrameEntry::new(19, 234, 270, "Stair", "Pumpkin Top Landing L"),
+    FrameEntry::new(19, 252, 270, "Stair", "Pumpkin Top Landing L-R"),
+    FrameEntry::new(19, 270, 270, "Stair", "Pumpkin Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 270, "Stair", "Pumpkin Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 270, "Stair", "Pumpkin Bottom Landing R"),
+    FrameEntry::new(19, 324, 270, "Stair", "Pumpkin Bottom Landing L"),
+    FrameEntry::new(19, 342, 270, "Stair", "Pumpkin Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 270, "Stair", "Pumpkin Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 270, "Stair", "Pumpkin Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 270, "Stair", "Pumpkin Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 270, "Stair", "Pumpkin Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 270, "Stair", "Pumpkin Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 270, "Stair", "Pumpkin Inverted Right"),
+    FrameEntry::new(19, 468, 270, "Stair", "Pumpkin Inverted Left"),
+    FrameEntry::new(19, 0, 288, "Platform", "Spooky Flat"),
+    FrameEntry::new(19, 18, 288, "Platform", "Spooky Endcap Right"),
+    FrameEntry::new(19, 36, 288, "Platform", "Spooky Endcap Left"),
+    FrameEntry::new(19, 54, 288, "Platform", "Spooky Attach Left"),
+    FrameEntry::new(19, 72, 288, "Platform", "Spooky Attach Right"),
+    FrameEntry::new(19, 90, 288, "Platform", "Spooky Single"),
+    FrameEntry::new(19, 108, 288, "Platform", "Spooky Attach Left Endcap"),
+    FrameEntry::new(19, 126, 288, "Platform", "Spooky Attach Right Endcap"),
+    FrameEntry::new(19, 144, 288, "Stair", "Spooky Up-Right Riser"),
+    FrameEntry::new(19, 162, 288, "Stair", "Spooky Up-Right Stringer"),
+    FrameEntry::new(19, 180, 288, "Stair", "Spooky Up-Left Riser"),
+    FrameEntry::new(19, 198, 288, "Stair", "Spooky Up-Left Stringer"),
+    FrameEntry::new(19, 216, 288, "Stair", "Spooky Top Landing R"),
+    FrameEntry::new(19, 234, 288, "Stair", "Spooky Top Landing L"),
+    FrameEntry::new(19, 252, 288, "Stair", "Spooky Top Landing L-R"),
+    FrameEntry::new(19, 270, 288, "Stair", "Spooky Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 288, "Stair", "Spooky Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 288, "Stair", "Spooky Bottom Landing R"),
+    FrameEntry::new(19, 324, 288, "Stair", "Spooky Bottom Landing L"),
+    FrameEntry::new(19, 342, 288, "Stair", "Spooky Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 288, "Stair", "Spooky Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 288, "Stair", "Spooky Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 288, "Stair", "Spooky Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 288, "Stair", "Spooky Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 288, "Stair", "Spooky Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 288, "Stair", "Spooky Inverted Right"),
+    FrameEntry::new(19, 468, 288, "Stair", "Spooky Inverted Left"),
+    FrameEntry::new(19, 0, 306, "Platform", "Palm Wood Flat"),
+    FrameEntry::new(19, 18, 306, "Platform", "Palm Wood Endcap Right"),
+    FrameEntry::new(19, 36, 306, "Platform", "Palm Wood Endcap Left"),
+    FrameEntry::new(19, 54, 306, "Platform", "Palm Wood Attach Left"),
+    FrameEntry::new(19, 72, 306, "Platform", "Palm Wood Attach Right"),
+    FrameEntry::new(19, 90, 306, "Platform", "Palm Wood Single"),
+    FrameEntry::new(19, 108, 306, "Platform", "Palm Wood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 306, "Platform", "Palm Wood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 306, "Stair", "Palm Wood Up-Right Riser"),
+    FrameEntry::new(19, 162, 306, "Stair", "Palm Wood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 306, "Stair", "Palm Wood Up-Left Riser"),
+    FrameEntry::new(19, 198, 306, "Stair", "Palm Wood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 306, "Stair", "Palm Wood Top Landing R"),
+    FrameEntry::new(19, 234, 306, "Stair", "Palm Wood Top Landing L"),
+    FrameEntry::new(19, 252, 306, "Stair", "Palm Wood Top Landing L-R"),
+    FrameEntry::new(19, 270, 306, "Stair", "Palm Wood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 306, "Stair", "Palm Wood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 306, "Stair", "Palm Wood Bottom Landing R"),
+    FrameEntry::new(19, 324, 306, "Stair", "Palm Wood Bottom Landing L"),
+    FrameEntry::new(19, 342, 306, "Stair", "Palm Wood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 306, "Stair", "Palm Wood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 306, "Stair", "Palm Wood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 306, "Stair", "Palm Wood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 306, "Stair", "Palm Wood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 306, "Stair", "Palm Wood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 306, "Stair", "Palm Wood Inverted Right"),
+    FrameEntry::new(19, 468, 306, "Stair", "Palm Wood Inverted Left"),
+    FrameEntry::new(19, 0, 324, "Platform", "Mushroom Flat"),
+    FrameEntry::new(19, 18, 324, "Platform", "Mushroom Endcap Right"),
+    FrameEntry::new(19, 36, 324, "Platform", "Mushroom Endcap Left"),
+    FrameEntry::new(19, 54, 324, "Platform", "Mushroom Attach Left"),
+    FrameEntry::new(19, 72, 324, "Platform", "Mushroom Attach Right"),
+    FrameEntry::new(19, 90, 324, "Platform", "Mushroom Single"),
+    FrameEntry::new(19, 108, 324, "Platform", "Mushroom Attach Left Endcap"),
+    FrameEntry::new(19, 126, 324, "Platform", "Mushroom Attach Right Endcap"),
+    FrameEntry::new(19, 144, 324, "Stair", "Mushroom Up-Right Riser"),
+    FrameEntry::new(19, 162, 324, "Stair", "Mushroom Up-Right Stringer"),
+    FrameEntry::new(19, 180, 324, "Stair", "Mushroom Up-Left Riser"),
+    FrameEntry::new(19, 198, 324, "Stair", "Mushroom Up-Left Stringer"),
+    FrameEntry::new(19, 216, 324, "Stair", "Mushroom Top Landing R"),
+    FrameEntry::new(19, 234, 324, "Stair", "Mushroom Top Landing L"),
+    FrameEntry::new(19, 252, 324, "Stair", "Mushroom Top Landing L-R"),
+    FrameEntry::new(19, 270, 324, "Stair", "Mushroom Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 324, "Stair", "Mushroom Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 324, "Stair", "Mushroom Bottom Landing R"),
+    FrameEntry::new(19, 324, 324, "Stair", "Mushroom Bottom Landing L"),
+    FrameEntry::new(19, 342, 324, "Stair", "Mushroom Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 324, "Stair", "Mushroom Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 324, "Stair", "Mushroom Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 324, "Stair", "Mushroom Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 324, "Stair", "Mushroom Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 324, "Stair", "Mushroom Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 324, "Stair", "Mushroom Inverted Right"),
+    FrameEntry::new(19, 468, 324, "Stair", "Mushroom Inverted Left"),
+    FrameEntry::new(19, 0, 342, "Platform", "Boreal Wood Flat"),
+    FrameEntry::new(19, 18, 342, "Platform", "Boreal Wood Endcap Right"),
+    FrameEntry::new(19, 36, 342, "Platform", "Boreal Wood Endcap Left"),
+    FrameEntry::new(19, 54, 342, "Platform", "Boreal Wood Attach Left"),
+    FrameEntry::new(19, 72, 342, "Platform", "Boreal Wood Attach Right"),
+    FrameEntry::new(19, 90, 342, "Platform", "Boreal Wood Single"),
+    FrameEntry::new(19, 108, 342, "Platform", "Boreal Wood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 342, "Platform", "Boreal Wood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 342, "Stair", "Boreal Wood Up-Right Riser"),
+    FrameEntry::new(19, 162, 342, "Stair", "Boreal Wood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 342, "Stair", "Boreal Wood Up-Left Riser"),
+    FrameEntry::new(19, 198, 342, "Stair", "Boreal Wood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 342, "Stair", "Boreal Wood Top Landing R"),
+    FrameEntry::new(19, 234, 342, "Stair", "Boreal Wood Top Landing L"),
+    FrameEntry::new(19, 252, 342, "Stair", "Boreal Wood Top Landing L-R"),
+    FrameEntry::new(19, 270, 342, "Stair", "Boreal Wood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 342, "Stair", "Boreal Wood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 342, "Stair", "Boreal Wood Bottom Landing R"),
+    FrameEntry::new(19, 324, 342, "Stair", "Boreal Wood Bottom Landing L"),
+    FrameEntry::new(19, 342, 342, "Stair", "Boreal Wood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 342, "Stair", "Boreal Wood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 342, "Stair", "Boreal Wood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 342, "Stair", "Boreal Wood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 342, "Stair", "Boreal Wood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 342, "Stair", "Boreal Wood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 342, "Stair", "Boreal Wood Inverted Right"),
+    FrameEntry::new(19, 468, 342, "Stair", "Boreal Wood Inverted Left"),
+    FrameEntry::new(19, 0, 360, "Platform", "Slime Platform Flat"),
+    FrameEntry::new(19, 18, 360, "Platform", "Slime Platform Endcap Right"),
+    FrameEntry::new(19, 36, 360, "Platform", "Slime Platform Endcap Left"),
+    FrameEntry::new(19, 54, 360, "Platform", "Slime Platform Attach Left"),
+    FrameEntry::new(19, 72, 360, "Platform", "Slime Platform Attach Right"),
+    FrameEntry::new(19, 90, 360, "Platform", "Slime Platform Single"),
+    FrameEntry::new(19, 108, 360, "Platform", "Slime Platform Attach Left Endcap"),
+    FrameEntry::new(19, 126, 360, "Platform", "Slime Platform Attach Right Endcap"),
+    FrameEntry::new(19, 144, 360, "Stair", "Slime Platform Up-Right Riser"),
+    FrameEntry::new(19, 162, 360, "Stair", "Slime Platform Up-Right Stringer"),
+    FrameEntry::new(19, 180, 360, "Stair", "Slime Platform Up-Left Riser"),
+    FrameEntry::new(19, 198, 360, "Stair", "Slime Platform Up-Left Stringer"),
+    FrameEntry::new(19, 216, 360, "Stair", "Slime Platform Top Landing R"),
+    FrameEntry::new(19, 234, 360, "Stair", "Slime Platform Top Landing L"),
+    FrameEntry::new(19, 252, 360, "Stair", "Slime Platform Top Landing L-R"),
+    FrameEntry::new(19, 270, 360, "Stair", "Slime Platform Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 360, "Stair", "Slime Platform Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 360, "Stair", "Slime Platform Bottom Landing R"),
+    FrameEntry::new(19, 324, 360, "Stair", "Slime Platform Bottom Landing L"),
+    FrameEntry::new(19, 342, 360, "Stair", "Slime Platform Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 360, "Stair", "Slime Platform Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 360, "Stair", "Slime Platform Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 360, "Stair", "Slime Platform Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 360, "Stair", "Slime Platform Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 360, "Stair", "Slime Platform Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 360, "Stair", "Slime Platform Inverted Right"),
+    FrameEntry::new(19, 468, 360, "Stair", "Slime Platform Inverted Left"),
+    FrameEntry::new(19, 0, 378, "Platform", "Steampunk Flat"),
+    FrameEntry::new(19, 18, 378, "Platform", "Steampunk Endcap Right"),
+    FrameEntry::new(19, 36, 378, "Platform", "Steampunk Endcap Left"),
+    FrameEntry::new(19, 54, 378, "Platform", "Steampunk Attach Left"),
+    FrameEntry::new(19, 72, 378, "Platform", "Steampunk Attach Right"),
+    FrameEntry::new(19, 90, 378, "Platform", "Steampunk Single"),
+    FrameEntry::new(19, 108, 378, "Platform", "Steampunk Attach Left Endcap"),
+    FrameEntry::new(19, 126, 378, "Platform", "Steampunk Attach Right Endcap"),
+    FrameEntry::new(19, 144, 378, "Stair", "Steampunk Up-Right Riser"),
+    FrameEntry::new(19, 162, 378, "Stair", "Steampunk Up-Right Stringer"),
+    FrameEntry::new(19, 180, 378, "Stair", "Steampunk Up-Left Riser"),
+    FrameEntry::new(19, 198, 378, "Stair", "Steampunk Up-Left Stringer"),
+    FrameEntry::new(19, 216, 378, "Stair", "Steampunk Top Landing R"),
+    FrameEntry::new(19, 234, 378, "Stair", "Steampunk Top Landing L"),
+    FrameEntry::new(19, 252, 378, "Stair", "Steampunk Top Landing L-R"),
+    FrameEntry::new(19, 270, 378, "Stair", "Steampunk Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 378, "Stair", "Steampunk Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 378, "Stair", "Steampunk Bottom Landing R"),
+    FrameEntry::new(19, 324, 378, "Stair", "Steampunk Bottom Landing L"),
+    FrameEntry::new(19, 342, 378, "Stair", "Steampunk Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 378, "Stair", "Steampunk Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 378, "Stair", "Steampunk Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 378, "Stair", "Steampunk Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 378, "Stair", "Steampunk Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 378, "Stair", "Steampunk Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 378, "Stair", "Steampunk Inverted Right"),
+    FrameEntry::new(19, 468, 378, "Stair", "Steampunk Inverted Left"),
+    FrameEntry::new(19, 0, 396, "Platform", "Skyware Flat"),
+    FrameEntry::new(19, 18, 396, "Platform", "Skyware Endcap Right"),
+    FrameEntry::new(19, 36, 396, "Platform", "Skyware Endcap Left"),
+    FrameEntry::new(19, 54, 396, "Platform", "Skyware Attach Left"),
+    FrameEntry::new(19, 72, 396, "Platform", "Skyware Attach Right"),
+    FrameEntry::new(19, 90, 396, "Platform", "Skyware Single"),
+    FrameEntry::new(19, 108, 396, "Platform", "Skyware Attach Left Endcap"),
+    FrameEntry::new(19, 126, 396, "Platform", "Skyware Attach Right Endcap"),
+    FrameEntry::new(19, 144, 396, "Stair", "Skyware Up-Right Riser"),
+    FrameEntry::new(19, 162, 396, "Stair", "Skyware Up-Right Stringer"),
+    FrameEntry::new(19, 180, 396, "Stair", "Skyware Up-Left Riser"),
+    FrameEntry::new(19, 198, 396, "Stair", "Skyware Up-Left Stringer"),
+    FrameEntry::new(19, 216, 396, "Stair", "Skyware Top Landing R"),
+    FrameEntry::new(19, 234, 396, "Stair", "Skyware Top Landing L"),
+    FrameEntry::new(19, 252, 396, "Stair", "Skyware Top Landing L-R"),
+    FrameEntry::new(19, 270, 396, "Stair", "Skyware Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 396, "Stair", "Skyware Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 396, "Stair", "Skyware Bottom Landing R"),
+    FrameEntry::new(19, 324, 396, "Stair", "Skyware Bottom Landing L"),
+    FrameEntry::new(19, 342, 396, "Stair", "Skyware Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 396, "Stair", "Skyware Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 396, "Stair", "Skyware Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 396, "Stair", "Skyware Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 396, "Stair", "Skyware Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 396, "Stair", "Skyware Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 396, "Stair", "Skyware Inverted Right"),
+    FrameEntry::new(19, 468, 396, "Stair", "Skyware Inverted Left"),
+    FrameEntry::new(19, 0, 414, "Platform", "Living Wood Flat"),
+    FrameEntry::new(19, 18, 414, "Platform", "Living Wood Endcap Right"),
+    FrameEntry::new(19, 36, 414, "Platform", "Living Wood Endcap Left"),
+    FrameEntry::new(19, 54, 414, "Platform", "Living Wood Attach Left"),
+    FrameEntry::new(19, 72, 414, "Platform", "Living Wood Attach Right"),
+    FrameEntry::new(19, 90, 414, "Platform", "Living Wood Single"),
+    FrameEntry::new(19, 108, 414, "Platform", "Living Wood Attach Left Endcap"),
+    FrameEntry::new(19, 126, 414, "Platform", "Living Wood Attach Right Endcap"),
+    FrameEntry::new(19, 144, 414, "Stair", "Living Wood Up-Right Riser"),
+    FrameEntry::new(19, 162, 414, "Stair", "Living Wood Up-Right Stringer"),
+    FrameEntry::new(19, 180, 414, "Stair", "Living Wood Up-Left Riser"),
+    FrameEntry::new(19, 198, 414, "Stair", "Living Wood Up-Left Stringer"),
+    FrameEntry::new(19, 216, 414, "Stair", "Living Wood Top Landing R"),
+    FrameEntry::new(19, 234, 414, "Stair", "Living Wood Top Landing L"),
+    FrameEntry::new(19, 252, 414, "Stair", "Living Wood Top Landing L-R"),
+    FrameEntry::new(19, 270, 414, "Stair", "Living Wood Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 414, "Stair", "Living Wood Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 414, "Stair", "Living Wood Bottom Landing R"),
+    FrameEntry::new(19, 324, 414, "Stair", "Living Wood Bottom Landing L"),
+    FrameEntry::new(19, 342, 414, "Stair", "Living Wood Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 414, "Stair", "Living Wood Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 414, "Stair", "Living Wood Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 414, "Stair", "Living Wood Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 414, "Stair", "Living Wood Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 414, "Stair", "Living Wood Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 414, "Stair", "Living Wood Inverted Right"),
+    FrameEntry::new(19, 468, 414, "Stair", "Living Wood Inverted Left"),
+    FrameEntry::new(19, 0, 432, "Platform", "Honey Flat"),
+    FrameEntry::new(19, 18, 432, "Platform", "Honey Endcap Right"),
+    FrameEntry::new(19, 36, 432, "Platform", "Honey Endcap Left"),
+    FrameEntry::new(19, 54, 432, "Platform", "Honey Attach Left"),
+    FrameEntry::new(19, 72, 432, "Platform", "Honey Attach Right"),
+    FrameEntry::new(19, 90, 432, "Platform", "Honey Single"),
+    FrameEntry::new(19, 108, 432, "Platform", "Honey Attach Left Endcap"),
+    FrameEntry::new(19, 126, 432, "Platform", "Honey Attach Right Endcap"),
+    FrameEntry::new(19, 144, 432, "Stair", "Honey Up-Right Riser"),
+    FrameEntry::new(19, 162, 432, "Stair", "Honey Up-Right Stringer"),
+    FrameEntry::new(19, 180, 432, "Stair", "Honey Up-Left Riser"),
+    FrameEntry::new(19, 198, 432, "Stair", "Honey Up-Left Stringer"),
+    FrameEntry::new(19, 216, 432, "Stair", "Honey Top Landing R"),
+    FrameEntry::new(19, 234, 432, "Stair", "Honey Top Landing L"),
+    FrameEntry::new(19, 252, 432, "Stair", "Honey Top Landing L-R"),
+    FrameEntry::new(19, 270, 432, "Stair", "Honey Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 432, "Stair", "Honey Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 432, "Stair", "Honey Bottom Landing R"),
+    FrameEntry::new(19, 324, 432, "Stair", "Honey Bottom Landing L"),
+    FrameEntry::new(19, 342, 432, "Stair", "Honey Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 432, "Stair", "Honey Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 432, "Stair", "Honey Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 432, "Stair", "Honey Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 432, "Stair", "Honey Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 432, "Stair", "Honey Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 432, "Stair", "Honey Inverted Right"),
+    FrameEntry::new(19, 468, 432, "Stair", "Honey Inverted Left"),
+    FrameEntry::new(19, 0, 450, "Platform", "Cactus Flat"),
+    FrameEntry::new(19, 18, 450, "Platform", "Cactus Endcap Right"),
+    FrameEntry::new(19, 36, 450, "Platform", "Cactus Endcap Left"),
+    FrameEntry::new(19, 54, 450, "Platform", "Cactus Attach Left"),
+    FrameEntry::new(19, 72, 450, "Platform", "Cactus Attach Right"),
+    FrameEntry::new(19, 90, 450, "Platform", "Cactus Single"),
+    FrameEntry::new(19, 108, 450, "Platform", "Cactus Attach Left Endcap"),
+    FrameEntry::new(19, 126, 450, "Platform", "Cactus Attach Right Endcap"),
+    FrameEntry::new(19, 144, 450, "Stair", "Cactus Up-Right Riser"),
+    FrameEntry::new(19, 162, 450, "Stair", "Cactus Up-Right Stringer"),
+    FrameEntry::new(19, 180, 450, "Stair", "Cactus Up-Left Riser"),
+    FrameEntry::new(19, 198, 450, "Stair", "Cactus Up-Left Stringer"),
+    FrameEntry::new(19, 216, 450, "Stair", "Cactus Top Landing R"),
+    FrameEntry::new(19, 234, 450, "Stair", "Cactus Top Landing L"),
+    FrameEntry::new(19, 252, 450, "Stair", "Cactus Top Landing L-R"),
+    FrameEntry::new(19, 270, 450, "Stair", "Cactus Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 450, "Stair", "Cactus Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 450, "Stair", "Cactus Bottom Landing R"),
+    FrameEntry::new(19, 324, 450, "Stair", "Cactus Bottom Landing L"),
+    FrameEntry::new(19, 342, 450, "Stair", "Cactus Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 450, "Stair", "Cactus Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 450, "Stair", "Cactus Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 450, "Stair", "Cactus Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 450, "Stair", "Cactus Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 450, "Stair", "Cactus Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 450, "Stair", "Cactus Inverted Right"),
+    FrameEntry::new(19, 468, 450, "Stair", "Cactus Inverted Left"),
+    FrameEntry::new(19, 0, 468, "Platform", "Martian Flat"),
+    FrameEntry::new(19, 18, 468, "Platform", "Martian Endcap Right"),
+    FrameEntry::new(19, 36, 468, "Platform", "Martian Endcap Left"),
+    FrameEntry::new(19, 54, 468, "Platform", "Martian Attach Left"),
+    FrameEntry::new(19, 72, 468, "Platform", "Martian Attach Right"),
+    FrameEntry::new(19, 90, 468, "Platform", "Martian Single"),
+    FrameEntry::new(19, 108, 468, "Platform", "Martian Attach Left Endcap"),
+    FrameEntry::new(19, 126, 468, "Platform", "Martian Attach Right Endcap"),
+    FrameEntry::new(19, 144, 468, "Stair", "Martian Up-Right Riser"),
+    FrameEntry::new(19, 162, 468, "Stair", "Martian Up-Right Stringer"),
+    FrameEntry::new(19, 180, 468, "Stair", "Martian Up-Left Riser"),
+    FrameEntry::new(19, 198, 468, "Stair", "Martian Up-Left Stringer"),
+    FrameEntry::new(19, 216, 468, "Stair", "Martian Top Landing R"),
+    FrameEntry::new(19, 234, 468, "Stair", "Martian Top Landing L"),
+    FrameEntry::new(19, 252, 468, "Stair", "Martian Top Landing L-R"),
+    FrameEntry::new(19, 270, 468, "Stair", "Martian Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 468, "Stair", "Martian Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 468, "Stair", "Martian Bottom Landing R"),
+    FrameEntry::new(19, 324, 468, "Stair", "Martian Bottom Landing L"),
+    FrameEntry::new(19, 342, 468, "Stair", "Martian Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 468, "Stair", "Martian Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 468, "Stair", "Martian Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 468, "Stair", "Martian Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 468, "Stair", "Martian Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 468, "Stair", "Martian Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 468, "Stair", "Martian Inverted Right"),
+    FrameEntry::new(19, 468, 468, "Stair", "Martian Inverted Left"),
+    FrameEntry::new(19, 0, 486, "Platform", "Meteorite Flat"),
+    FrameEntry::new(19, 18, 486, "Platform", "Meteorite Endcap Right"),
+    FrameEntry::new(19, 36, 486, "Platform", "Meteorite Endcap Left"),
+    FrameEntry::new(19, 54, 486, "Platform", "Meteorite Attach Left"),
+    FrameEntry::new(19, 72, 486, "Platform", "Meteorite Attach Right"),
+    FrameEntry::new(19, 90, 486, "Platform", "Meteorite Single"),
+    FrameEntry::new(19, 108, 486, "Platform", "Meteorite Attach Left Endcap"),
+    FrameEntry::new(19, 126, 486, "Platform", "Meteorite Attach Right Endcap"),
+    FrameEntry::new(19, 144, 486, "Stair", "Meteorite Up-Right Riser"),
+    FrameEntry::new(19, 162, 486, "Stair", "Meteorite Up-Right Stringer"),
+    FrameEntry::new(19, 180, 486, "Stair", "Meteorite Up-Left Riser"),
+    FrameEntry::new(19, 198, 486, "Stair", "Meteorite Up-Left Stringer"),
+    FrameEntry::new(19, 216, 486, "Stair", "Meteorite Top Landing R"),
+    FrameEntry::new(19, 234, 486, "Stair", "Meteorite Top Landing L"),
+    FrameEntry::new(19, 252, 486, "Stair", "Meteorite Top Landing L-R"),
+    FrameEntry::new(19, 270, 486, "Stair", "Meteorite Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 486, "Stair", "Meteorite Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 486, "Stair", "Meteorite Bottom Landing R"),
+    FrameEntry::new(19, 324, 486, "Stair", "Meteorite Bottom Landing L"),
+    FrameEntry::new(19, 342, 486, "Stair", "Meteorite Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 486, "Stair", "Meteorite Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 486, "Stair", "Meteorite Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 486, "Stair", "Meteorite Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 486, "Stair", "Meteorite Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 486, "Stair", "Meteorite Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 486, "Stair", "Meteorite Inverted Right"),
+    FrameEntry::new(19, 468, 486, "Stair", "Meteorite Inverted Left"),
+    FrameEntry::new(19, 0, 504, "Platform", "Granite Flat"),
+    FrameEntry::new(19, 18, 504, "Platform", "Granite Endcap Right"),
+    FrameEntry::new(19, 36, 504, "Platform", "Granite Endcap Left"),
+    FrameEntry::new(19, 54, 504, "Platform", "Granite Attach Left"),
+    FrameEntry::new(19, 72, 504, "Platform", "Granite Attach Right"),
+    FrameEntry::new(19, 90, 504, "Platform", "Granite Single"),
+    FrameEntry::new(19, 108, 504, "Platform", "Granite Attach Left Endcap"),
+    FrameEntry::new(19, 126, 504, "Platform", "Granite Attach Right Endcap"),
+    FrameEntry::new(19, 144, 504, "Stair", "Granite Up-Right Riser"),
+    FrameEntry::new(19, 162, 504, "Stair", "Granite Up-Right Stringer"),
+    FrameEntry::new(19, 180, 504, "Stair", "Granite Up-Left Riser"),
+    FrameEntry::new(19, 198, 504, "Stair", "Granite Up-Left Stringer"),
+    FrameEntry::new(19, 216, 504, "Stair", "Granite Top Landing R"),
+    FrameEntry::new(19, 234, 504, "Stair", "Granite Top Landing L"),
+    FrameEntry::new(19, 252, 504, "Stair", "Granite Top Landing L-R"),
+    FrameEntry::new(19, 270, 504, "Stair", "Granite Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 504, "Stair", "Granite Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 504, "Stair", "Granite Bottom Landing R"),
+    FrameEntry::new(19, 324, 504, "Stair", "Granite Bottom Landing L"),
+    FrameEntry::new(19, 342, 504, "Stair", "Granite Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 504, "Stair", "Granite Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 504, "Stair", "Granite Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 504, "Stair", "Granite Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 504, "Stair", "Granite Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 504, "Stair", "Granite Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 504, "Stair", "Granite Inverted Right"),
+    FrameEntry::new(19, 468, 504, "Stair", "Granite Inverted Left"),
+    FrameEntry::new(19, 0, 522, "Platform", "Marble Flat"),
+    FrameEntry::new(19, 18, 522, "Platform", "Marble Endcap Right"),
+    FrameEntry::new(19, 36, 522, "Platform", "Marble Endcap Left"),
+    FrameEntry::new(19, 54, 522, "Platform", "Marble Attach Left"),
+    FrameEntry::new(19, 72, 522, "Platform", "Marble Attach Right"),
+    FrameEntry::new(19, 90, 522, "Platform", "Marble Single"),
+    FrameEntry::new(19, 108, 522, "Platform", "Marble Attach Left Endcap"),
+    FrameEntry::new(19, 126, 522, "Platform", "Marble Attach Right Endcap"),
+    FrameEntry::new(19, 144, 522, "Stair", "Marble Up-Right Riser"),
+    FrameEntry::new(19, 162, 522, "Stair", "Marble Up-Right Stringer"),
+    FrameEntry::new(19, 180, 522, "Stair", "Marble Up-Left Riser"),
+    FrameEntry::new(19, 198, 522, "Stair", "Marble Up-Left Stringer"),
+    FrameEntry::new(19, 216, 522, "Stair", "Marble Top Landing R"),
+    FrameEntry::new(19, 234, 522, "Stair", "Marble Top Landing L"),
+    FrameEntry::new(19, 252, 522, "Stair", "Marble Top Landing L-R"),
+    FrameEntry::new(19, 270, 522, "Stair", "Marble Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 522, "Stair", "Marble Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 522, "Stair", "Marble Bottom Landing R"),
+    FrameEntry::new(19, 324, 522, "Stair", "Marble Bottom Landing L"),
+    FrameEntry::new(19, 342, 522, "Stair", "Marble Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 522, "Stair", "Marble Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 522, "Stair", "Marble Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 522, "Stair", "Marble Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 522, "Stair", "Marble Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 522, "Stair", "Marble Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 522, "Stair", "Marble Inverted Right"),
+    FrameEntry::new(19, 468, 522, "Stair", "Marble Inverted Left"),
+    FrameEntry::new(19, 0, 540, "Platform", "Crystal Flat"),
+    FrameEntry::new(19, 18, 540, "Platform", "Crystal Endcap Right"),
+    FrameEntry::new(19, 36, 540, "Platform", "Crystal Endcap Left"),
+    FrameEntry::new(19, 54, 540, "Platform", "Crystal Attach Left"),
+    FrameEntry::new(19, 72, 540, "Platform", "Crystal Attach Right"),
+    FrameEntry::new(19, 90, 540, "Platform", "Crystal Single"),
+    FrameEntry::new(19, 108, 540, "Platform", "Crystal Attach Left Endcap"),
+    FrameEntry::new(19, 126, 540, "Platform", "Crystal Attach Right Endcap"),
+    FrameEntry::new(19, 144, 540, "Stair", "Crystal Up-Right Riser"),
+    FrameEntry::new(19, 162, 540, "Stair", "Crystal Up-Right Stringer"),
+    FrameEntry::new(19, 180, 540, "Stair", "Crystal Up-Left Riser"),
+    FrameEntry::new(19, 198, 540, "Stair", "Crystal Up-Left Stringer"),
+    FrameEntry::new(19, 216, 540, "Stair", "Crystal Top Landing R"),
+    FrameEntry::new(19, 234, 540, "Stair", "Crystal Top Landing L"),
+    FrameEntry::new(19, 252, 540, "Stair", "Crystal Top Landing L-R"),
+    FrameEntry::new(19, 270, 540, "Stair", "Crystal Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 540, "Stair", "Crystal Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 540, "Stair", "Crystal Bottom Landing R"),
+    FrameEntry::new(19, 324, 540, "Stair", "Crystal Bottom Landing L"),
+    FrameEntry::new(19, 342, 540, "Stair", "Crystal Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 540, "Stair", "Crystal Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 540, "Stair", "Crystal Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 540, "Stair", "Crystal Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 540, "Stair", "Crystal Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 540, "Stair", "Crystal Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 540, "Stair", "Crystal Inverted Right"),
+    FrameEntry::new(19, 468, 540, "Stair", "Crystal Inverted Left"),
+    FrameEntry::new(19, 0, 558, "Platform", "Golden Flat"),
+    FrameEntry::new(19, 18, 558, "Platform", "Golden Endcap Right"),
+    FrameEntry::new(19, 36, 558, "Platform", "Golden Endcap Left"),
+    FrameEntry::new(19, 54, 558, "Platform", "Golden Attach Left"),
+    FrameEntry::new(19, 72, 558, "Platform", "Golden Attach Right"),
+    FrameEntry::new(19, 90, 558, "Platform", "Golden Single"),
+    FrameEntry::new(19, 108, 558, "Platform", "Golden Attach Left Endcap"),
+    FrameEntry::new(19, 126, 558, "Platform", "Golden Attach Right Endcap"),
+    FrameEntry::new(19, 144, 558, "Stair", "Golden Up-Right Riser"),
+    FrameEntry::new(19, 162, 558, "Stair", "Golden Up-Right Stringer"),
+    FrameEntry::new(19, 180, 558, "Stair", "Golden Up-Left Riser"),
+    FrameEntry::new(19, 198, 558, "Stair", "Golden Up-Left Stringer"),
+    FrameEntry::new(19, 216, 558, "Stair", "Golden Top Landing R"),
+    FrameEntry::new(19, 234, 558, "Stair", "Golden Top Landing L"),
+    FrameEntry::new(19, 252, 558, "Stair", "Golden Top Landing L-R"),
+    FrameEntry::new(19, 270, 558, "Stair", "Golden Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 558, "Stair", "Golden Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 558, "Stair", "Golden Bottom Landing R"),
+    FrameEntry::new(19, 324, 558, "Stair", "Golden Bottom Landing L"),
+    FrameEntry::new(19, 342, 558, "Stair", "Golden Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 558, "Stair", "Golden Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 558, "Stair", "Golden Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 558, "Stair", "Golden Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 558, "Stair", "Golden Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 558, "Stair", "Golden Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 558, "Stair", "Golden Inverted Right"),
+    FrameEntry::new(19, 468, 558, "Stair", "Golden Inverted Left"),
+    FrameEntry::new(19, 0, 576, "Platform", "Dynasty Flat"),
+    FrameEntry::new(19, 18, 576, "Platform", "Dynasty Endcap Right"),
+    FrameEntry::new(19, 36, 576, "Platform", "Dynasty Endcap Left"),
+    FrameEntry::new(19, 54, 576, "Platform", "Dynasty Attach Left"),
+    FrameEntry::new(19, 72, 576, "Platform", "Dynasty Attach Right"),
+    FrameEntry::new(19, 90, 576, "Platform", "Dynasty Single"),
+    FrameEntry::new(19, 108, 576, "Platform", "Dynasty Attach Left Endcap"),
+    FrameEntry::new(19, 126, 576, "Platform", "Dynasty Attach Right Endcap"),
+    FrameEntry::new(19, 144, 576, "Stair", "Dynasty Up-Right Riser"),
+    FrameEntry::new(19, 162, 576, "Stair", "Dynasty Up-Right Stringer"),
+    FrameEntry::new(19, 180, 576, "Stair", "Dynasty Up-Left Riser"),
+    FrameEntry::new(19, 198, 576, "Stair", "Dynasty Up-Left Stringer"),
+    FrameEntry::new(19, 216, 576, "Stair", "Dynasty Top Landing R"),
+    FrameEntry::new(19, 234, 576, "Stair", "Dynasty Top Landing L"),
+    FrameEntry::new(19, 252, 576, "Stair", "Dynasty Top Landing L-R"),
+    FrameEntry::new(19, 270, 576, "Stair", "Dynasty Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 576, "Stair", "Dynasty Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 576, "Stair", "Dynasty Bottom Landing R"),
+    FrameEntry::new(19, 324, 576, "Stair", "Dynasty Bottom Landing L"),
+    FrameEntry::new(19, 342, 576, "Stair", "Dynasty Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 576, "Stair", "Dynasty Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 576, "Stair", "Dynasty Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 576, "Stair", "Dynasty Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 576, "Stair", "Dynasty Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 576, "Stair", "Dynasty Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 576, "Stair", "Dynasty Inverted Right"),
+    FrameEntry::new(19, 468, 576, "Stair", "Dynasty Inverted Left"),
+    FrameEntry::new(19, 0, 592, "Platform", "Lihzahrd Flat"),
+    FrameEntry::new(19, 18, 592, "Platform", "Lihzahrd Endcap Right"),
+    FrameEntry::new(19, 36, 592, "Platform", "Lihzahrd Endcap Left"),
+    FrameEntry::new(19, 54, 592, "Platform", "Lihzahrd Attach Left"),
+    FrameEntry::new(19, 72, 592, "Platform", "Lihzahrd Attach Right"),
+    FrameEntry::new(19, 90, 592, "Platform", "Lihzahrd Single"),
+    FrameEntry::new(19, 108, 592, "Platform", "Lihzahrd Attach Left Endcap"),
+    FrameEntry::new(19, 126, 592, "Platform", "Lihzahrd Attach Right Endcap"),
+    FrameEntry::new(19, 144, 592, "Stair", "Lihzahrd Up-Right Riser"),
+    FrameEntry::new(19, 162, 592, "Stair", "Lihzahrd Up-Right Stringer"),
+    FrameEntry::new(19, 180, 592, "Stair", "Lihzahrd Up-Left Riser"),
+    FrameEntry::new(19, 198, 592, "Stair", "Lihzahrd Up-Left Stringer"),
+    FrameEntry::new(19, 216, 592, "Stair", "Lihzahrd Top Landing R"),
+    FrameEntry::new(19, 234, 592, "Stair", "Lihzahrd Top Landing L"),
+    FrameEntry::new(19, 252, 592, "Stair", "Lihzahrd Top Landing L-R"),
+    FrameEntry::new(19, 270, 592, "Stair", "Lihzahrd Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 592, "Stair", "Lihzahrd Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 592, "Stair", "Lihzahrd Bottom Landing R"),
+    FrameEntry::new(19, 324, 592, "Stair", "Lihzahrd Bottom Landing L"),
+    FrameEntry::new(19, 342, 592, "Stair", "Lihzahrd Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 592, "Stair", "Lihzahrd Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 592, "Stair", "Lihzahrd Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 592, "Stair", "Lihzahrd Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 592, "Stair", "Lihzahrd Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 592, "Stair", "Lihzahrd Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 592, "Stair", "Lihzahrd Inverted Right"),
+    FrameEntry::new(19, 468, 592, "Stair", "Lihzahrd Inverted Left"),
+    FrameEntry::new(19, 0, 610, "Platform", "Flesh Flat"),
+    FrameEntry::new(19, 18, 610, "Platform", "Flesh Endcap Right"),
+    FrameEntry::new(19, 36, 610, "Platform", "Flesh Endcap Left"),
+    FrameEntry::new(19, 54, 610, "Platform", "Flesh Attach Left"),
+    FrameEntry::new(19, 72, 610, "Platform", "Flesh Attach Right"),
+    FrameEntry::new(19, 90, 610, "Platform", "Flesh Single"),
+    FrameEntry::new(19, 108, 610, "Platform", "Flesh Attach Left Endcap"),
+    FrameEntry::new(19, 126, 610, "Platform", "Flesh Attach Right Endcap"),
+    FrameEntry::new(19, 144, 610, "Stair", "Flesh Up-Right Riser"),
+    FrameEntry::new(19, 162, 610, "Stair", "Flesh Up-Right Stringer"),
+    FrameEntry::new(19, 180, 610, "Stair", "Flesh Up-Left Riser"),
+    FrameEntry::new(19, 198, 610, "Stair", "Flesh Up-Left Stringer"),
+    FrameEntry::new(19, 216, 610, "Stair", "Flesh Top Landing R"),
+    FrameEntry::new(19, 234, 610, "Stair", "Flesh Top Landing L"),
+    FrameEntry::new(19, 252, 610, "Stair", "Flesh Top Landing L-R"),
+    FrameEntry::new(19, 270, 610, "Stair", "Flesh Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 610, "Stair", "Flesh Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 610, "Stair", "Flesh Bottom Landing R"),
+    FrameEntry::new(19, 324, 610, "Stair", "Flesh Bottom Landing L"),
+    FrameEntry::new(19, 342, 610, "Stair", "Flesh Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 610, "Stair", "Flesh Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 610, "Stair", "Flesh Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 610, "Stair", "Flesh Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 610, "Stair", "Flesh Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 610, "Stair", "Flesh Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 610, "Stair", "Flesh Inverted Right"),
+    FrameEntry::new(19, 468, 610, "Stair", "Flesh Inverted Left"),
+    FrameEntry::new(19, 0, 628, "Platform", "Frozen Flat"),
+    FrameEntry::new(19, 18, 628, "Platform", "Frozen Endcap Right"),
+    FrameEntry::new(19, 36, 628, "Platform", "Frozen Endcap Left"),
+    FrameEntry::new(19, 54, 628, "Platform", "Frozen Attach Left"),
+    FrameEntry::new(19, 72, 628, "Platform", "Frozen Attach Right"),
+    FrameEntry::new(19, 90, 628, "Platform", "Frozen Single"),
+    FrameEntry::new(19, 108, 628, "Platform", "Frozen Attach Left Endcap"),
+    FrameEntry::new(19, 126, 628, "Platform", "Frozen Attach Right Endcap"),
+    FrameEntry::new(19, 144, 628, "Stair", "Frozen Up-Right Riser"),
+    FrameEntry::new(19, 162, 628, "Stair", "Frozen Up-Right Stringer"),
+    FrameEntry::new(19, 180, 628, "Stair", "Frozen Up-Left Riser"),
+    FrameEntry::new(19, 198, 628, "Stair", "Frozen Up-Left Stringer"),
+    FrameEntry::new(19, 216, 628, "Stair", "Frozen Top Landing R"),
+    FrameEntry::new(19, 234, 628, "Stair", "Frozen Top Landing L"),
+    FrameEntry::new(19, 252, 628, "Stair", "Frozen Top Landing L-R"),
+    FrameEntry::new(19, 270, 628, "Stair", "Frozen Top Landing R Endcap"),
+    FrameEntry::new(19, 288, 628, "Stair", "Frozen Top Landing L Endcap"),
+    FrameEntry::new(19, 306, 628, "Stair", "Frozen Bottom Landing R"),
+    FrameEntry::new(19, 324, 628, "Stair", "Frozen Bottom Landing L"),
+    FrameEntry::new(19, 342, 628, "Stair", "Frozen Inset Up-Right 1"),
+    FrameEntry::new(19, 360, 628, "Stair", "Frozen Inset Up-Left 1"),
+    FrameEntry::new(19, 378, 628, "Stair", "Frozen Inset Up-Right 2"),
+    FrameEntry::new(19, 396, 628, "Stair", "Frozen Inset Up-Left 2"),
+    FrameEntry::new(19, 414, 628, "Stair", "Frozen Inset Up-Right 3"),
+    FrameEntry::new(19, 432, 628, "Stair", "Frozen Inset Up-Left 3"),
+    FrameEntry::new(19, 450, 628, "Stair", "Frozen Inverted Right"),
+    FrameEntry::new(19, 468, 628, "Stair", "Frozen Inverted Left"),
+    FrameEntry::new(20, 0, 0, "Acorn", ""),
+    FrameEntry::new(20, 18, 0, "Acorn", ""),
+    FrameEntry::new(20, 36, 0, "Acorn", ""),
+    FrameEntry::new(21, 0, 0, "Chest", ""),
+    FrameEntry::new(21, 36, 0, "Gold Chest", ""),
+    FrameEntry::new(21, 72, 0, "Gold Chest", "Locked"),
+    FrameEntry::new(21, 108, 0, "Shadow Chest", ""),
+    FrameEntry::new(21, 144, 0, "Shadow Chest", "Locked"),
+    FrameEntry::new(21, 180, 0, "Barrel", ""),
+    FrameEntry::new(21, 216, 0, "Trash Can", ""),
+    FrameEntry::new(21, 252, 0, "Ebonwood Chest", ""),
+    FrameEntry::new(21, 288, 0, "Rich Mahogany Chest", ""),
+    FrameEntry::new(21, 324, 0, "Pearlwood Chest", ""),
+    FrameEntry::new(21, 360, 0, "Ivy Chest", ""),
+    FrameEntry::new(21, 396, 0, "Ice Chest", ""),
+    FrameEntry::new(21, 432, 0, "Living Wood Chest", ""),
+    FrameEntry::new(21, 468, 0, "Skyware Chest", ""),
+    FrameEntry::new(21, 504, 0, "Shadewood Chest", ""),
+    FrameEntry::new(21, 540, 0, "Web Covered Chest", ""),
+    FrameEntry::new(21, 576, 0, "Lihzahrd Chest", ""),
+    FrameEntry::new(21, 612, 0, "Water Chest", ""),
+    FrameEntry::new(21, 648, 0, "Jungle Chest", ""),
+    FrameEntry::new(21, 684, 0, "Corruption Chest", ""),
+    FrameEntry::new(21, 720, 0, "Crimson Chest", ""),
+    FrameEntry::new(21, 756, 0, "Hallowed Chest", ""),
+    FrameEntry::new(21, 792, 0, "Frozen Chest", ""),
+    FrameEntry::new(21, 828, 0, "Jungle Chest", "Locked"),
+    FrameEntry::new(21, 864, 0, "Corruption Chest", "Locked"),
+    FrameEntry::new(21, 900, 0, "Crimson Chest", "Locked"),
+    FrameEntry::new(21, 936, 0, "Hallowed Chest", "Locked"),
+    FrameEntry::new(21, 972, 0, "Frozen Chest", "Locked"),
+    FrameEntry::new(21, 1008, 0, "Dynasty Chest", ""),
+    FrameEntry::new(21, 1044, 0, "Honey Chest", ""),
+    FrameEntry::new(21, 1080, 0, "Steampunk Chest", ""),
+    FrameEntry::new(21, 1116, 0, "Palm Wood Chest", ""),
+    FrameEntry::new(21, 1152, 0, "Mushroom Chest", ""),
+    FrameEntry::new(21, 1188, 0, "Boreal Wood Chest", ""),
+    FrameEntry::new(21, 1224, 0, "Slime Chest", ""),
+    FrameEntry::new(21, 1260, 0, "Green Dungeon Chest", ""),
+    FrameEntry::new(21, 1296, 0, "Green Dungeon Chest", "Locked"),
+    FrameEntry::new(21, 1332, 0, "Pink Dungeon Chest", ""),
+    FrameEntry::new(21, 1368, 0, "Pink Dungeon Chest", "Locked"),
+    FrameEntry::new(21, 1404, 0, "Blue Dungeon Chest", ""),
+    FrameEntry::new(21, 1440, 0, "Blue Dungeon Chest", "Locked"),
+    FrameEntry::new(21, 1476, 0, "Bone Chest", ""),
+    FrameEntry::new(21, 1512, 0, "Cactus Chest", ""),
+    FrameEntry::new(21, 1548, 0, "Flesh Chest", ""),
+    FrameEntry::new(21, 1584, 0, "Obsidian Chest", ""),
+    FrameEntry::new(21, 1620, 0, "Pumpkin Chest", ""),
+    FrameEntry::new(21, 1656, 0, "Spooky Chest", ""),
+    FrameEntry::new(21, 1692, 0, "Glass Chest", ""),
+    FrameEntry::new(21, 1728, 0, "Martian Chest", ""),
+    FrameEntry::new(21, 1764, 0, "Meteorite Chest", ""),
+    FrameEntry::new(21, 1800, 0, "Granite Chest", ""),
+    FrameEntry::new(21, 1836, 0, "Marble Chest", ""),
+    FrameEntry::new(24, 0, 0, "Vile Grass", "Single Blade"),
+    FrameEntry::new(24, 18, 0, "Vile Grass", "Double Blade"),
+    FrameEntry::new(24, 36, 0, "Vile Grass", "Small Y"),
+    FrameEntry::new(24, 54, 0, "Vile Grass", "Three Small"),
+    FrameEntry::new(24, 72, 0, "Vile Grass", "Single Blade"),
+    FrameEntry::new(24, 90, 0, "Vile Grass", "Three Large"),
+    FrameEntry::new(24, 108, 0, "Vile Flower", "Yellow"),
+    FrameEntry::new(24, 126, 0, "Vile Flower", "Red"),
+    FrameEntry::new(24, 144, 0, "Vile Mushroom", ""),
+    FrameEntry::new(26, 0, 0, "Altar", "Demon Altar"),
+    FrameEntry::new(26, 54, 0, "Altar", "Crimson Altar"),
+    FrameEntry::new(27, 0, 0, "Sunflower", "A"),
+    FrameEntry::new(27, 36, 0, "Sunflower", "B"),
+    FrameEntry::new(27, 72, 0, "Sunflower", "C"),
+    FrameEntry::new(28, 0, 0, "Pot", "Clay Pot A1"),
+    FrameEntry::new(28, 36, 0, "Pot", "Clay Pot A2"),
+    FrameEntry::new(28, 72, 0, "Pot", "Clay Pot A3"),
+    FrameEntry::new(28, 0, 36, "Pot", "Clay Pot B1"),
+    FrameEntry::new(28, 36, 36, "Pot", "Clay Pot B2"),
+    FrameEntry::new(28, 72, 36, "Pot", "Clay Pot B3"),
+    FrameEntry::new(28, 0, 72, "Pot", "Clay Pot C1"),
+    FrameEntry::new(28, 36, 72, "Pot", "Clay Pot C2"),
+    FrameEntry::new(28, 72, 72, "Pot", "Clay Pot C3"),
+    FrameEntry::new(28, 0, 108, "Pot", "Clay Pot D1"),
+    FrameEntry::new(28, 36, 108, "Pot", "Clay Pot D2"),
+    FrameEntry::new(28, 72, 108, "Pot", "Clay Pot D3"),
+    FrameEntry::new(28, 0, 144, "Pot", "Frozen Pot A1"),
+    FrameEntry::new(28, 36, 144, "Pot", "Frozen Pot A2"),
+    FrameEntry::new(28, 72, 144, "Pot", "Frozen Pot A3"),
+    FrameEntry::new(28, 0, 180, "Pot", "Frozen Pot B1"),
+    FrameEntry::new(28, 36, 180, "Pot", "Frozen Pot B2"),
+    FrameEntry::new(28, 72, 180, "Pot", "Frozen Pot B3"),
+    FrameEntry::new(28, 0, 216, "Pot", "Frozen Pot C1"),
+    FrameEntry::new(28, 36, 216, "Pot", "Frozen Pot C2"),
+    FrameEntry::new(28, 72, 216, "Pot", "Frozen Pot C3"),
+    FrameEntry::new(28, 0, 252, "Pot", "Jungle Pot A1"),
+    FrameEntry::new(28, 36, 252, "Pot", "Jungle Pot A2"),
+    FrameEntry::new(28, 72, 252, "Pot", "Jungle Pot A3"),
+    FrameEntry::new(28, 0, 288, "Pot", "Jungle Pot B1"),
+    FrameEntry::new(28, 36, 288, "Pot", "Jungle Pot B2"),
+    FrameEntry::new(28, 72, 288, "Pot", "Jungle Pot B3"),
+    FrameEntry::new(28, 0, 324, "Pot", "Jungle Pot C1"),
+    FrameEntry::new(28, 36, 324, "Pot", "Jungle Pot C2"),
+    FrameEntry::new(28, 72, 324, "Pot", "Jungle Pot C3"),
+    FrameEntry::new(28, 0, 360, "Pot", "Skull Pot A1"),
+    FrameEntry::new(28, 36, 360, "Pot", "Skull Pot A2"),
+    FrameEntry::new(28, 72, 360, "Pot", "Skull Pot A3"),
+    FrameEntry::new(28, 0, 396, "Pot", "Skull Pot B1"),
+    FrameEntry::new(28, 36, 396, "Pot", "Skull Pot B2"),
+    FrameEntry::new(28, 72, 396, "Pot", "Skull Pot B3"),
+    FrameEntry::new(28, 0, 432, "Pot", "Skull Pot C1"),
+    FrameEntry::new(28, 36, 432, "Pot", "Skull Pot C2"),
+    FrameEntry::new(28, 72, 432, "Pot", "Skull Pot C3"),
+    FrameEntry::new(28, 0, 468, "Pot", "Obsidian Pot A1"),
+    FrameEntry::new(28, 36, 468, "Pot", "Obsidian Pot A2"),
+    FrameEntry::new(28, 72, 468, "Pot", "Obsidian Pot A3"),
+    FrameEntry::new(28, 0, 504, "Pot", "Obsidian Pot B1"),
+    FrameEntry::new(28, 36, 504, "Pot", "Obsidian Pot B2"),
+    FrameEntry::new(28, 72, 504, "Pot", "Obsidian Pot B3"),
+    FrameEntry::new(28, 0, 540, "Pot", "Obsidian Pot C1"),
+    FrameEntry::new(28, 36, 540, "Pot", "Obsidian Pot C2"),
+    FrameEntry::new(28, 72, 540, "Pot", "Obsidian Pot C3"),
+    FrameEntry::new(28, 0, 576, "Pot", "Corrupt Pot A1"),
+    FrameEntry::new(28, 36, 576, "Pot", "Corrupt Pot A2"),
+    FrameEntry::new(28, 72, 576, "Pot", "Corrupt Pot A3"),
+    FrameEntry::new(28, 0, 612, "Pot", "Corrupt Pot B1"),
+    FrameEntry::new(28, 36, 612, "Pot", "Corrupt Pot B2"),
+    FrameEntry::new(28, 72, 612, "Pot", "Corrupt Pot B3"),
+    FrameEntry::new(28, 0, 648, "Pot", "Corrupt Pot C1"),
+    FrameEntry::new(28, 36, 648, "Pot", "Corrupt Pot C2"),
+    FrameEntry::new(28, 36, 648, "Pot", "Corrupt Pot C2"),
+    FrameEntry::new(28, 72, 648, "Pot", "Corrupt Pot C3"),
+    FrameEntry::new(28, 0, 684, "Pot", "Webbed Pot A1"),
+    FrameEntry::new(28, 36, 684, "Pot", "Webbed Pot A2"),
+    FrameEntry::new(28, 72, 684, "Pot", "Webbed Pot A3"),
+    FrameEntry::new(28, 0, 720, "Pot", "Webbed Pot B1"),
+    FrameEntry::new(28, 36, 720, "Pot", "Webbed Pot B2"),
+    FrameEntry::new(28, 72, 720, "Pot", "Webbed Pot B3"),
+    FrameEntry::new(28, 0, 756, "Pot", "Webbed Pot C1"),
+    FrameEntry::new(28, 36, 756, "Pot", "Webbed Pot C2"),
+    FrameEntry::new(28, 72, 756, "Pot", "Webbed Pot C3"),
+    FrameEntry::new(28, 0, 792, "Pot", "Crimson Pot A1"),
+    FrameEntry::new(28, 36, 792, "Pot", "Crimson Pot A2"),
+    FrameEntry::new(28, 72, 792, "Pot", "Crimson Pot A3"),
+    FrameEntry::new(28, 0, 828, "Pot", "Crimson Pot B1"),
+    FrameEntry::new(28, 36, 828, "Pot", "Crimson Pot B2"),
+    FrameEntry::new(28, 72, 828, "Pot", "Crimson Pot B3"),
+    FrameEntry::new(28, 0, 864, "Pot", "Crimson Pot C1"),
+    FrameEntry::new(28, 36, 864, "Pot", "Crimson Pot C2"),
+    FrameEntry::new(28, 72, 864, "Pot", "Crimson Pot C3"),
+    FrameEntry::new(28, 0, 900, "Pot", "Elegant Pot A1"),
+    FrameEntry::new(28, 36, 900, "Pot", "Elegant Pot A2"),
+    FrameEntry::new(28, 72, 900, "Pot", "Elegant Pot A3"),
+    FrameEntry::new(28, 0, 936, "Pot", "Elegant Pot B1"),
+    FrameEntry::new(28, 36, 936, "Pot", "Elegant Pot B2"),
+    FrameEntry::new(28, 72, 936, "Pot", "Elegant Pot B3"),
+    FrameEntry::new(28, 0, 972, "Pot", "Elegant Pot C1"),
+    FrameEntry::new(28, 36, 972, "Pot", "Elegant Pot C2"),
+    FrameEntry::new(28, 72, 972, "Pot", "Elegant Pot C3"),
+    FrameEntry::new(28, 0, 1008, "Pot", "Lihzahrd Pot A1"),
+    FrameEntry::new(28, 36, 1008, "Pot", "Lihzahrd Pot A2"),
+    FrameEntry::new(28, 72, 1008, "Pot", "Lihzahrd Pot A3"),
+    FrameEntry::new(28, 0, 1044, "Pot", "Lihzahrd Pot B1"),
+    FrameEntry::new(28, 36, 1044, "Pot", "Lihzahrd Pot B2"),
+    FrameEntry::new(28, 72, 1044, "Pot", "Lihzahrd Pot B3"),
+    FrameEntry::new(28, 0, 1080, "Pot", "Lihzahrd Pot C1"),
+    FrameEntry::new(28, 36, 1080, "Pot", "Lihzahrd Pot C2"),
+    FrameEntry::new(28, 72, 1080, "Pot", "Lihzahrd Pot C3"),
+    FrameEntry::new(28, 0, 1116, "Pot", "Marble Pot A1"),
+    FrameEntry::new(28, 36, 1116, "Pot", "Marble Pot A2"),
+    FrameEntry::new(28, 72, 1116, "Pot", "Marble Pot A3"),
+    FrameEntry::new(28, 0, 1152, "Pot", "Marble Pot B1"),
+    FrameEntry::new(28, 36, 1152, "Pot", "Marble Pot B2"),
+    FrameEntry::new(28, 72, 1152, "Pot", "Marble Pot B3"),
+    FrameEntry::new(28, 0, 1188, "Pot", "Marble Pot C1"),
+    FrameEntry::new(28, 36, 1188, "Pot", "Marble Pot C2"),
+    FrameEntry::new(28, 72, 1188, "Pot", "Marble Pot C3"),
+    FrameEntry::new(29, 0, 0, "Piggy Bank", "Default"),
+    FrameEntry::new(31, 0, 0, "Shadow Orb", ""),
+    FrameEntry::new(31, 36, 0, "Demon Heart", ""),
+    FrameEntry::new(33, 0, 0, "Candle", "On"),
+    FrameEntry::new(33, 18, 0, "Candle", "Off"),
+    FrameEntry::new(33, 0, 22, "Blue Dungeon Candle", "On"),
+    FrameEntry::new(33, 18, 22, "Blue Dungeon Candle", "Off"),
+    FrameEntry::new(33, 0, 44, "Green Dungeon Candle", "On"),
+    FrameEntry::new(33, 18, 44, "Green Dungeon Candle", "Off"),
+    FrameEntry::new(33, 0, 66, "Pink Dungeon Candle", "On"),
+    FrameEntry::new(33, 18, 66, "Pink Dungeon Candle", "Off"),
+    FrameEntry::new(33, 0, 88, "Cactus Candle", "On"),
+    FrameEntry::new(33, 18, 88, "Cactus Candle", "Off"),
+    FrameEntry::new(33, 0, 110, "Ebonwood Candle", "On"),
+    FrameEntry::new(33, 18, 110, "Ebonwood Candle", "Off"),
+    FrameEntry::new(33, 0, 132, "Flesh Candle", "On"),
+    FrameEntry::new(33, 18, 132, "Flesh Candle", "Off"),
+    FrameEntry::new(33, 0, 154, "Glass Candle", "On"),
+    FrameEntry::new(33, 18, 154, "Glass Candle", "Off"),
+    FrameEntry::new(33, 0, 176, "Frozen Candle", "On"),
+    FrameEntry::new(33, 18, 176, "Frozen Candle", "Off"),
+    FrameEntry::new(33, 0, 198, "Rich Mahogany Candle", "On"),
+    FrameEntry::new(33, 18, 198, "Rich Mahogany Candle", "Off"),
+    FrameEntry::new(33, 0, 220, "Pearlwood Candle", "On"),
+    FrameEntry::new(33, 18, 220, "Pearlwood Candle", "Off"),
+    FrameEntry::new(33, 0, 242, "Lihzahrd Candle", "On"),
+    FrameEntry::new(33, 18, 242, "Lihzahrd Candle", "Off"),
+    FrameEntry::new(33, 0, 264, "Skyware Candle", "On"),
+    FrameEntry::new(33, 18, 264, "Skyware Candle", "Off"),
+    FrameEntry::new(33, 0, 286, "Pumpkin Candle", "On"),
+    FrameEntry::new(33, 18, 286, "Pumpkin Candle", "Off"),
+    FrameEntry::new(33, 0, 308, "Living Wood Candle", "On"),
+    FrameEntry::new(33, 18, 308, "Living Wood Candle", "Off"),
+    FrameEntry::new(33, 0, 330, "Shadewood Candle", "On"),
+    FrameEntry::new(33, 18, 330, "Shadewood Candle", "Off"),
+    FrameEntry::new(33, 0, 352, "Golden Candle", "On"),
+    FrameEntry::new(33, 18, 352, "Golden Candle", "Off"),
+    FrameEntry::new(33, 0, 374, "Dynasty Candle", "On"),
+    FrameEntry::new(33, 18, 374, "Dynasty Candle", "Off"),
+    FrameEntry::new(33, 0, 396, "Palm Wood Candle", "On"),
+    FrameEntry::new(33, 18, 396, "Palm Wood Candle", "Off"),
+    FrameEntry::new(33, 0, 418, "Mushroom Candle", "On"),
+    FrameEntry::new(33, 18, 418, "Mushroom Candle", "Off"),
+    FrameEntry::new(33, 0, 440, "Boreal Wood Candle", "On"),
+    FrameEntry::new(33, 18, 440, "Boreal Wood Candle", "Off"),
+    FrameEntry::new(33, 0, 462, "Slime Candle", "On"),
+    FrameEntry::new(33, 18, 462, "Slime Candle", "Off"),
+    FrameEntry::new(33, 0, 484, "Honey Candle", "On"),
+    FrameEntry::new(33, 18, 484, "Honey Candle", "Off"),
+    FrameEntry::new(33, 0, 506, "Steampunk Candle", "On"),
+    FrameEntry::new(33, 18, 506, "Steampunk Candle", "Off"),
+    FrameEntry::new(33, 0, 528, "Spooky Candle", "On"),
+    FrameEntry::new(33, 18, 528, "Spooky Candle", "Off"),
+    FrameEntry::new(33, 0, 550, "Obsidian Candle", "On"),
+    FrameEntry::new(33, 18, 550, "Obsidian Candle", "Off"),
+    FrameEntry::new(33, 0, 572, "Martian Hover Candle", "On"),
+    FrameEntry::new(33, 18, 572, "Martian Hover Candle", "Off"),
+    FrameEntry::new(33, 0, 594, "Meteorite Candle", "On"),
+    FrameEntry::new(33, 18, 594, "Meteorite Candle", "Off"),
+    FrameEntry::new(33, 0, 616, "Granite Candle", "On"),
+    FrameEntry::new(33, 18, 616, "Granite Candle", "Off"),
+    FrameEntry::new(33, 0, 638, "Marble Candle", "On"),
+    FrameEntry::new(33, 18, 638, "Marble Candle", "Off"),
+    FrameEntry::new(33, 0, 654, "Crystal Candle", "On"),
+    FrameEntry::new(33, 18, 654, "Crystal Candle", "Off"),
+    FrameEntry::new(34, 0, 0, "Copper Chandelier", "On"),
+    FrameEntry::new(34, 54, 0, "Copper Chandelier", "Off"),
+    FrameEntry::new(34, 0, 54, "Silver Chandelier", "On"),
+    FrameEntry::new(34, 54, 54, "Silver Chandelier", "Off"),
+    FrameEntry::new(34, 0, 108, "Gold Chandelier", "On"),
+    FrameEntry::new(34, 54, 108, "Gold Chandelier", "Off"),
+    FrameEntry::new(34, 0, 162, "Tin Chandelier", "On"),
+    FrameEntry::new(34, 54, 162, "Tin Chandelier", "Off"),
+    FrameEntry::new(34, 0, 216, "Tungsten Chandelier", "On"),
+    FrameEntry::new(34, 54, 216, "Tungsten Chandelier", "Off"),
+    FrameEntry::new(34, 0, 270, "Platinum Chandelier", "On"),
+    FrameEntry::new(34, 54, 270, "Platinum Chandelier", "Off"),
+    FrameEntry::new(34, 0, 324, "Jackelier", "On"),
+    FrameEntry::new(34, 54, 324, "Jackelier", "Off"),
+    FrameEntry::new(34, 0, 378, "Cactus Chandelier", "On"),
+    FrameEntry::new(34, 54, 378, "Cactus Chandelier", "Off"),
+    FrameEntry::new(34, 0, 432, "Ebonwood Chandelier", "On"),
+    FrameEntry::new(34, 54, 432, "Ebonwood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 486, "Flesh Chandelier", "On"),
+    FrameEntry::new(34, 54, 486, "Flesh Chandelier", "Off"),
+    FrameEntry::new(34, 0, 540, "Honey Chandelier", "On"),
+    FrameEntry::new(34, 54, 540, "Honey Chandelier", "Off"),
+    FrameEntry::new(34, 0, 594, "Frozen Chandelier", "On"),
+    FrameEntry::new(34, 54, 594, "Frozen Chandelier", "Off"),
+    FrameEntry::new(34, 0, 648, "Rich Mahogany Chandelier", "On"),
+    FrameEntry::new(34, 54, 648, "Rich Mahogany Chandelier", "Off"),
+    FrameEntry::new(34, 0, 702, "Pearlwood Chandelier", "On"),
+    FrameEntry::new(34, 54, 702, "Pearlwood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 756, "Lihzahrd Chandelier", "On"),
+    FrameEntry::new(34, 54, 756, "Lihzahrd Chandelier", "Off"),
+    FrameEntry::new(34, 0, 810, "Skyware Chandelier", "On"),
+    FrameEntry::new(34, 54, 810, "Skyware Chandelier", "Off"),
+    FrameEntry::new(34, 0, 864, "Spooky Chandelier", "On"),
+    FrameEntry::new(34, 54, 864, "Spooky Chandelier", "Off"),
+    FrameEntry::new(34, 0, 918, "Glass Chandelier", "On"),
+    FrameEntry::new(34, 54, 918, "Glass Chandelier", "Off"),
+    FrameEntry::new(34, 0, 972, "Living Wood Chandelier", "On"),
+    FrameEntry::new(34, 54, 972, "Living Wood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1026, "Shadewood Chandelier", "On"),
+    FrameEntry::new(34, 54, 1026, "Shadewood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1080, "Golden Chandelier", "On"),
+    FrameEntry::new(34, 54, 1080, "Golden Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1134, "Bone Chandelier", "On"),
+    FrameEntry::new(34, 54, 1134, "Bone Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1188, "Large Dynasty Lantern", "On"),
+    FrameEntry::new(34, 54, 1188, "Large Dynasty Lantern", "Off"),
+    FrameEntry::new(34, 0, 1242, "Palm Wood Chandelier", "On"),
+    FrameEntry::new(34, 54, 1242, "Palm Wood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1296, "Mushroom Chandelier", "On"),
+    FrameEntry::new(34, 54, 1296, "Mushroom Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1350, "Boreal Wood Chandelier", "On"),
+    FrameEntry::new(34, 54, 1350, "Boreal Wood Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1404, "Slime Chandelier", "On"),
+    FrameEntry::new(34, 54, 1404, "Slime Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1458, "Blue Dungeon Chandelier", "On"),
+    FrameEntry::new(34, 54, 1458, "Blue Dungeon Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1512, "Green Dungeon Chandelier", "On"),
+    FrameEntry::new(34, 54, 1512, "Green Dungeon Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1566, "Pink Dungeon Chandelier", "On"),
+    FrameEntry::new(34, 54, 1566, "Pink Dungeon Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1620, "Steampunk Chandelier", "On"),
+    FrameEntry::new(34, 54, 1620, "Steampunk Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1674, "Pumpkin Chandelier", "On"),
+    FrameEntry::new(34, 54, 1674, "Pumpkin Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1728, "Obsidian Chandelier", "On"),
+    FrameEntry::new(34, 54, 1728, "Obsidian Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1782, "Martian Chandelier", "On"),
+    FrameEntry::new(34, 54, 1782, "Martian Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1836, "Meteorite Chandelier", "On"),
+    FrameEntry::new(34, 54, 1836, "Meteorite Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1890, "Granite Chandelier", "On"),
+    FrameEntry::new(34, 54, 1890, "Granite Chandelier", "Off"),
+    FrameEntry::new(34, 0, 1944, "Marble Chandelier", "On"),
+    FrameEntry::new(34, 54, 1944, "Marble Chandelier", "Off"),
+    FrameEntry::new(34, 108, 0, "Crystal Chandelier", "On"),
+    FrameEntry::new(34, 162, 0, "Crystal Chandelier", "Off"),
+    FrameEntry::new(35, 0, 0, "Jack 'O Lantern 1", "On"),
+    FrameEntry::new(35, 36, 0, "Jack 'O Lantern 1", "Off"),
+    FrameEntry::new(35, 0, 36, "Jack 'O Lantern 2", "On"),
+    FrameEntry::new(35, 36, 36, "Jack 'O Lantern 2", "Off"),
+    FrameEntry::new(35, 0, 72, "Jack 'O Lantern 3", "On"),
+    FrameEntry::new(35, 36, 72, "Jack 'O Lantern 3", "Off"),
+    FrameEntry::new(35, 0, 108, "Jack 'O Lantern 4", "On"),
+    FrameEntry::new(35, 36, 108, "Jack 'O Lantern 4", "Off"),
+    FrameEntry::new(35, 0, 144, "Jack 'O Lantern 5", "On"),
+    FrameEntry::new(35, 36, 144, "Jack 'O Lantern 5", "Off"),
+    FrameEntry::new(35, 0, 180, "Jack 'O Lantern 6", "On"),
+    FrameEntry::new(35, 36, 180, "Jack 'O Lantern 6", "Off"),
+    FrameEntry::new(35, 0, 216, "Jack 'O Lantern 7", "On"),
+    FrameEntry::new(35, 36, 216, "Jack 'O Lantern 7", "Off"),
+    FrameEntry::new(35, 0, 252, "Jack 'O Lantern 8", "On"),
+    FrameEntry::new(35, 36, 252, "Jack 'O Lantern 8", "Off"),
+    FrameEntry::new(35, 0, 288, "Jack 'O Lantern 9", "On"),
+    FrameEntry::new(35, 36, 288, "Jack 'O Lantern 9", "Off"),
+    FrameEntry::new(35, 0, 1332, "Crystal Lantern", "On"),
+    FrameEntry::new(35, 18, 1332, "Crystal Lantern", "Off"),
+    FrameEntry::new(36, 0, 0, "Present", "Red with White Ribbon"),
+    FrameEntry::new(36, 18, 0, "Present", "Red with Blue Ribbon"),
+    FrameEntry::new(36, 36, 0, "Present", "Green with White Ribbon"),
+    FrameEntry::new(36, 54, 0, "Present", "Green with Red Ribbon"),
+    FrameEntry::new(36, 72, 0, "Present", "Yellow with White Ribbon"),
+    FrameEntry::new(36, 90, 0, "Present", "Blue with White Ribbon"),
+    FrameEntry::new(36, 108, 0, "Present", "Blue with Yellow Ribbon"),
+    FrameEntry::new(42, 0, 0, "Chain Lantern", "On"),
+    FrameEntry::new(42, 18, 0, "Chain Lantern", "Off"),
+    FrameEntry::new(42, 0, 36, "Brass Lantern", "On"),
+    FrameEntry::new(42, 18, 36, "Brass Lantern", "Off"),
+    FrameEntry::new(42, 0, 72, "Caged Lantern", "On"),
+    FrameEntry::new(42, 18, 72, "Caged Lantern", "Off"),
+    FrameEntry::new(42, 0, 108, "Carriage Lantern", "On"),
+    FrameEntry::new(42, 18, 108, "Carriage Lantern", "Off"),
+    FrameEntry::new(42, 0, 144, "Alchemy Lantern", "On"),
+    FrameEntry::new(42, 18, 144, "Alchemy Lantern", "Off"),
+    FrameEntry::new(42, 0, 180, "Diablost Lamp", "On"),
+    FrameEntry::new(42, 18, 180, "Diablost Lamp", "Off"),
+    FrameEntry::new(42, 0, 216, "Oil Rag Sconse", "On"),
+    FrameEntry::new(42, 18, 216, "Oil Rag Sconse", "Off"),
+    FrameEntry::new(42, 0, 252, "Star in a Bottle", "On"),
+    FrameEntry::new(42, 18, 252, "Star in a Bottle", "Off"),
+    FrameEntry::new(42, 0, 288, "Hanging Jack 'O Lantern", "On"),
+    FrameEntry::new(42, 18, 288, "Hanging Jack 'O Lantern", "Off"),
+    FrameEntry::new(42, 0, 324, "Heart Lantern", "On"),
+    FrameEntry::new(42, 18, 324, "Heart Lantern", "Off"),
+    FrameEntry::new(42, 0, 360, "Cactus Lantern", "On"),
+    FrameEntry::new(42, 18, 360, "Cactus Lantern", "Off"),
+    FrameEntry::new(42, 0, 396, "Ebonwood Lantern", "On"),
+    FrameEntry::new(42, 18, 396, "Ebonwood Lantern", "Off"),
+    FrameEntry::new(42, 0, 432, "Flesh Lantern", "On"),
+    FrameEntry::new(42, 18, 432, "Flesh Lantern", "Off"),
+    FrameEntry::new(42, 0, 468, "Honey Lantern", "On"),
+    FrameEntry::new(42, 18, 468, "Honey Lantern", "Off"),
+    FrameEntry::new(42, 0, 504, "Steampunk Lantern", "On"),
+    FrameEntry::new(42, 18, 504, "Steampunk Lantern", "Off"),
+    FrameEntry::new(42, 0, 540, "Glass Lantern", "On"),
+    FrameEntry::new(42, 18, 540, "Glass Lantern", "Off"),
+    FrameEntry::new(42, 0, 576, "Rich Mahogany Lantern", "On"),
+    FrameEntry::new(42, 18, 576, "Rich Mahogany Lantern", "Off"),
+    FrameEntry::new(42, 0, 612, "Pearlwood Lantern", "On"),
+    FrameEntry::new(42, 18, 612, "Pearlwood Lantern", "Off"),
+    FrameEntry::new(42, 0, 648, "Frozen Lantern", "On"),
+    FrameEntry::new(42, 18, 648, "Frozen Lantern", "Off"),
+    FrameEntry::new(42, 0, 684, "Lihzahrd Lantern", "On"),
+    FrameEntry::new(42, 18, 684, "Lihzahrd Lantern", "Off"),
+    FrameEntry::new(42, 0, 720, "Skyware Lantern", "On"),
+    FrameEntry::new(42, 18, 720, "Skyware Lantern", "Off"),
+    FrameEntry::new(42, 0, 756, "Spooky Lantern", "On"),
+    FrameEntry::new(42, 18, 756, "Spooky Lantern", "Off"),
+    FrameEntry::new(42, 0, 792, "Living Wood Lantern", "On"),
+    FrameEntry::new(42, 18, 792, "Living Wood Lantern", "Off"),
+    FrameEntry::new(42, 0, 828, "Shadewood Lantern", "On"),
+    FrameEntry::new(42, 18, 828, "Shadewood Lantern", "Off"),
+    FrameEntry::new(42, 0, 864, "Golden Lantern", "On"),
+    FrameEntry::new(42, 18, 864, "Golden Lantern", "Off"),
+    FrameEntry::new(42, 0, 900, "Bone Lantern", "On"),
+    FrameEntry::new(42, 18, 900, "Bone Lantern", "Off"),
+    FrameEntry::new(42, 0, 936, "Dynasty Lantern", "On"),
+    FrameEntry::new(42, 18, 936, "Dynasty Lantern", "Off"),
+    FrameEntry::new(42, 0, 972, "Palm Wood Lantern", "On"),
+    FrameEntry::new(42, 18, 972, "Palm Wood Lantern", "Off"),
+    FrameEntry::new(42, 0, 1008, "Mushroom Lantern", "On"),
+    FrameEntry::new(42, 18, 1008, "Mushroom Lantern", "Off"),
+    FrameEntry::new(42, 0, 1044, "Boreal Wood Lantern", "On"),
+    FrameEntry::new(42, 18, 1044, "Boreal Wood Lantern", "Off"),
+    FrameEntry::new(42, 0, 1080, "Slime Lantern", "On"),
+    FrameEntry::new(42, 18, 1080, "Slime Lantern", "Off"),
+    FrameEntry::new(42, 0, 1116, "Pumpkin Lantern", "On"),
+    FrameEntry::new(42, 18, 1116, "Pumpkin Lantern", "Off"),
+    FrameEntry::new(42, 0, 1152, "Obsidian Lantern", "On"),
+    FrameEntry::new(42, 18, 1152, "Obsidian Lantern", "Off"),
+    FrameEntry::new(42, 0, 1188, "Martian Lantern", "On"),
+    FrameEntry::new(42, 18, 1188, "Martian Lantern", "Off"),
+    FrameEntry::new(42, 0, 1224, "Meteorite Lantern", "On"),
+    FrameEntry::new(42, 18, 1224, "Meteorite Lantern", "Off"),
+    FrameEntry::new(42, 0, 1260, "Granite Lantern", "On"),
+    FrameEntry::new(42, 18, 1260, "Granite Lantern", "Off"),
+    FrameEntry::new(42, 0, 1296, "Marble Lantern", "On"),
+    FrameEntry::new(42, 18, 1296, "Marble Lantern", "Off"),
+    FrameEntry::new(50, 0, 0, "Book", "Blue Red Brown"),
+    FrameEntry::new(50, 18, 0, "Book", "Yellow Pink"),
+    FrameEntry::new(50, 36, 0, "Book", "Teal Brown"),
+    FrameEntry::new(50, 54, 0, "Book", "Purple Blue"),
+    FrameEntry::new(50, 72, 0, "Book", "Green Orange"),
+    FrameEntry::new(50, 90, 0, "Book", "Brown Water Bolt Gray"),
+    FrameEntry::new(55, 0, 0, "Sign", "Ground"),
+    FrameEntry::new(55, 36, 0, "Sign", "Hanging"),
+    FrameEntry::new(55, 72, 0, "Sign", "Left Side"),
+    FrameEntry::new(55, 108, 0, "Sign", "Right Side"),
+    FrameEntry::new(61, 0, 0, "Jungle Grass", "Single Blade"),
+    FrameEntry::new(61, 18, 0, "Jungle Grass", "Double Blade"),
+    FrameEntry::new(61, 36, 0, "Jungle Grass", "Small Y"),
+    FrameEntry::new(61, 54, 0, "Jungle Grass", "Three Small"),
+    FrameEntry::new(61, 72, 0, "Jungle Grass", "Single Blade"),
+    FrameEntry::new(61, 90, 0, "Jungle Grass", "Three Large"),
+    FrameEntry::new(61, 108, 0, "Jungle Rose", "Immature"),
+    FrameEntry::new(61, 126, 0, "Jungle Rose", "Immature"),
+    FrameEntry::new(61, 144, 0, "Puff Light", ""),
+    FrameEntry::new(61, 162, 0, "Nature's Gift", "Immature"),
+    FrameEntry::new(71, 0, 0, "Mushroom Plant", "Double Small"),
+    FrameEntry::new(71, 18, 0, "Mushroom Plant", "Small"),
+    FrameEntry::new(71, 36, 0, "Mushroom Plant", "Malformed"),
+    FrameEntry::new(71, 54, 0, "Mushroom Plant", "Thin"),
+    FrameEntry::new(71, 72, 0, "Mushroom Plant", "Standard"),
+    FrameEntry::new(72, 0, 0, "Mushroom Tree", "Trunk A"),
+    FrameEntry::new(72, 18, 0, "Mushroom Tree", "Tree Top"),
+    FrameEntry::new(72, 0, 18, "Mushroom Tree", "Trunk B"),
+    FrameEntry::new(72, 18, 18, "Mushroom Tree", "Tree Top"),
+    FrameEntry::new(72, 0, 36, "Mushroom Tree", "Trunk C"),
+    FrameEntry::new(72, 18, 36, "Mushroom Tree", "Tree Top"),
+    FrameEntry::new(73, 0, 0, "Grass", "G"),
+    FrameEntry::new(73, 18, 0, "Grass", "C"),
+    FrameEntry::new(73, 36, 0, "Grass", "?"),
+    FrameEntry::new(73, 54, 0, "Grass", "u"),
+    FrameEntry::new(73, 72, 0, "Grass", "w"),
+    FrameEntry::new(73, 90, 0, "Grass", "W"),
+    FrameEntry::new(73, 108, 0, "Rose", "A"),
+    FrameEntry::new(73, 126, 0, "Rose", "B"),
+    FrameEntry::new(74, 0, 0, "Jungle Grass", "G"),
+    FrameEntry::new(74, 18, 0, "Jungle Grass", "C"),
+    FrameEntry::new(74, 36, 0, "Jungle Grass", "?"),
+    FrameEntry::new(74, 54, 0, "Jungle Grass", "u"),
+    FrameEntry::new(74, 72, 0, "Jungle Grass", "w"),
+    FrameEntry::new(74, 90, 0, "Jungle Grass", "W"),
+    FrameEntry::new(74, 108, 0, "Jungle Rose", "A"),
+    FrameEntry::new(74, 126, 0, "Jungle Rose", "B"),
+    FrameEntry::new(79, 0, 0, "Wooden Bed", ""),
+    FrameEntry::new(79, 72, 0, "Wooden Bed", "Right"),
+    FrameEntry::new(79, 0, 36, "Ebonwood Bed", ""),
+    FrameEntry::new(79, 72, 36, "Ebonwood Bed", "Right"),
+    FrameEntry::new(79, 0, 72, "Rich Mahogany Bed", ""),
+    FrameEntry::new(79, 72, 72, "Rich Mahogany Bed", "Right"),
+    FrameEntry::new(79, 0, 108, "Pearlwood Bed", ""),
+    FrameEntry::new(79, 72, 108, "Pearlwood Bed", "Right"),
+    FrameEntry::new(79, 0, 144, "Shadewood Bed", ""),
+    FrameEntry::new(79, 72, 144, "Shadewood Bed", "Right"),
+    FrameEntry::new(79, 0, 180, "Blue Dungeon Bed", ""),
+    FrameEntry::new(79, 72, 180, "Blue Dungeon Bed", "Right"),
+    FrameEntry::new(79, 0, 216, "Green Dungeon Bed", ""),
+    FrameEntry::new(79, 72, 216, "Green Dungeon Bed", "Right"),
+    FrameEntry::new(79, 0, 252, "Pink Dungeon Bed", ""),
+    FrameEntry::new(79, 72, 252, "Pink Dungeon Bed", "Right"),
+    FrameEntry::new(79, 0, 288, "Obsidian Bed", ""),
+    FrameEntry::new(79, 72, 288, "Obsidian Bed", "Right"),
+    FrameEntry::new(79, 0, 324, "Glass Bed", ""),
+    FrameEntry::new(79, 72, 324, "Glass Bed", "Right"),
+    FrameEntry::new(79, 0, 360, "Golden Bed", ""),
+    FrameEntry::new(79, 72, 360, "Golden Bed", "Right"),
+    FrameEntry::new(79, 0, 396, "Honey Bed", ""),
+    FrameEntry::new(79, 72, 396, "Honey Bed", "Right"),
+    FrameEntry::new(79, 0, 432, "Steam Punk Bed", ""),
+    FrameEntry::new(79, 72, 432, "Steam Punk Bed", "Right"),
+    FrameEntry::new(79, 0, 468, "Cactus Bed", ""),
+    FrameEntry::new(79, 72, 468, "Cactus Bed", "Right"),
+    FrameEntry::new(79, 0, 504, "Flesh Bed", ""),
+    FrameEntry::new(79, 72, 504, "Flesh Bed", "Right"),
+    FrameEntry::new(79, 0, 540, "Frozen Bed", ""),
+    FrameEntry::new(79, 72, 540, "Frozen Bed", "Right"),
+    FrameEntry::new(79, 0, 576, "Lihzahrd Bed", ""),
+    FrameEntry::new(79, 72, 576, "Lihzahrd Bed", "Right"),
+    FrameEntry::new(79, 0, 612, "Skyware Bed", ""),
+    FrameEntry::new(79, 72, 612, "Skyware Bed", "Right"),
+    FrameEntry::new(79, 0, 648, "Spooky Bed", ""),
+    FrameEntry::new(79, 72, 648, "Spooky Bed", "Right"),
+    FrameEntry::new(79, 0, 684, "Living Wood Bed", ""),
+    FrameEntry::new(79, 72, 684, "Living Wood Bed", "Right"),
+    FrameEntry::new(79, 0, 720, "Bone Bed", ""),
+    FrameEntry::new(79, 72, 720, "Bone Bed", "Right"),
+    FrameEntry::new(79, 0, 756, "Dynasty Bed", ""),
+    FrameEntry::new(79, 72, 756, "Dynasty Bed", "Right"),
+    FrameEntry::new(79, 0, 792, "Palm Wood Bed", ""),
+    FrameEntry::new(79, 72, 792, "Palm Wood Bed", "Right"),
+    FrameEntry::new(79, 0, 828, "Mushroom Bed", ""),
+    FrameEntry::new(79, 72, 828, "Mushroom Bed", "Right"),
+    FrameEntry::new(79, 0, 864, "Boreal Wood Bed", ""),
+    FrameEntry::new(79, 72, 864, "Boreal Wood Bed", "Right"),
+    FrameEntry::new(79, 0, 900, "Slime Bed", ""),
+    FrameEntry::new(79, 72, 900, "Slime Bed", "Right"),
+    FrameEntry::new(79, 0, 936, "Pumpkin Bed", ""),
+    FrameEntry::new(79, 72, 936, "Pumpkin Bed", "Right"),
+    FrameEntry::new(79, 0, 972, "Martian Bed", ""),
+    FrameEntry::new(79, 72, 972, "Martian Bed", "Right"),
+    FrameEntry::new(79, 0, 1008, "Meteorite Bed", ""),
+    FrameEntry::new(79, 72, 1008, "Meteorite Bed", "Right"),
+    FrameEntry::new(79, 0, 1044, "Granite Bed", ""),
+    FrameEntry::new(79, 72, 1044, "Granite Bed", "Right"),
+    FrameEntry::new(79, 0, 1080, "Marble Bed", ""),
+    FrameEntry::new(79, 72, 1080, "Marble Bed", "Right"),
+    FrameEntry::new(79, 0, 1116, "Crystal Bed", "Left"),
+    FrameEntry::new(79, 72, 1116, "Crystal Bed", "Right"),
+    FrameEntry::new(80, 0, 0, "Cactus", "Default"),
+    FrameEntry::new(81, 0, 0, "Coral", "Red"),
+    FrameEntry::new(81, 26, 0, "Coral", "Pink"),
+    FrameEntry::new(81, 52, 0, "Coral", "Yellow"),
+    FrameEntry::new(81, 78, 0, "Coral", "Green"),
+    FrameEntry::new(81, 104, 0, "Coral", "Blue"),
+    FrameEntry::new(81, 130, 0, "Coral", "Sponge"),
+    FrameEntry::new(82, 0, 0, "Daybloom", ""),
+    FrameEntry::new(82, 18, 0, "Moonglow", ""),
+    FrameEntry::new(82, 36, 0, "Blinkroot", ""),
+    FrameEntry::new(82, 54, 0, "Deathweed", ""),
+    FrameEntry::new(82, 72, 0, "Waterleaf", ""),
+    FrameEntry::new(82, 90, 0, "Fireblossom", ""),
+    FrameEntry::new(82, 108, 0, "Shiverthorn", ""),
+    FrameEntry::new(83, 0, 0, "Daybloom", ""),
+    FrameEntry::new(83, 18, 0, "Moonglow", ""),
+    FrameEntry::new(83, 36, 0, "Blinkroot", ""),
+    FrameEntry::new(83, 54, 0, "Deathweed", ""),
+    FrameEntry::new(83, 72, 0, "Waterleaf", ""),
+    FrameEntry::new(83, 90, 0, "Fireblossom", ""),
+    FrameEntry::new(83, 108, 0, "Shiverthorn", ""),
+    FrameEntry::new(84, 0, 0, "Daybloom", ""),
+    FrameEntry::new(84, 18, 0, "Moonglow", ""),
+    FrameEntry::new(84, 36, 0, "Blinkroot", ""),
+    FrameEntry::new(84, 54, 0, "Deathweed", ""),
+    FrameEntry::new(84, 72, 0, "Waterleaf", ""),
+    FrameEntry::new(84, 90, 0, "Fireblossom", ""),
+    FrameEntry::new(84, 108, 0, "Shiverthorn", ""),
+    FrameEntry::new(85, 0, 0, "Grave Marker", "Tombstone"),
+    FrameEntry::new(85, 36, 0, "Grave Marker", "Grave Marker"),
+    FrameEntry::new(85, 72, 0, "Grave Marker", "Cross Grave Marker"),
+    FrameEntry::new(85, 108, 0, "Grave Marker", "Headstone"),
+    FrameEntry::new(85, 144, 0, "Grave Marker", "Gravestone"),
+    FrameEntry::new(85, 180, 0, "Grave Marker", "Obelisk"),
+    FrameEntry::new(85, 216, 0, "Grave Marker", "Golden Cross Grave Marker"),
+    FrameEntry::new(85, 252, 0, "Grave Marker", "Golden Tombstone"),
+    FrameEntry::new(85, 288, 0, "Grave Marker", "Golden Grave Marker"),
+    FrameEntry::new(85, 324, 0, "Grave Marker", "Golden Gravestone"),
+    FrameEntry::new(85, 360, 0, "Grave Marker", "Golden Headstone"),
+    FrameEntry::new(87, 0, 0, "Piano", "Wooden"),
+    FrameEntry::new(87, 54, 0, "Piano", "Ebonwood"),
+    FrameEntry::new(87, 108, 0, "Piano", "Rich Mahogany"),
+    FrameEntry::new(87, 162, 0, "Piano", "Pearlwood"),
+    FrameEntry::new(87, 216, 0, "Piano", "Shadewood"),
+    FrameEntry::new(87, 270, 0, "Piano", "Living Wood"),
+    FrameEntry::new(87, 324, 0, "Piano", "Flesh"),
+    FrameEntry::new(87, 378, 0, "Piano", "Frozen"),
+    FrameEntry::new(87, 432, 0, "Piano", "Glass"),
+    FrameEntry::new(87, 486, 0, "Piano", "Honey"),
+    FrameEntry::new(87, 540, 0, "Piano", "Steampunk"),
+    FrameEntry::new(87, 594, 0, "Piano", "Blue Dungeon"),
+    FrameEntry::new(87, 648, 0, "Piano", "Green Dungeon"),
+    FrameEntry::new(87, 702, 0, "Piano", "Pink Dungeon"),
+    FrameEntry::new(87, 756, 0, "Piano", "Golden"),
+    FrameEntry::new(87, 810, 0, "Piano", "Obsidian"),
+    FrameEntry::new(87, 864, 0, "Piano", "Bone"),
+    FrameEntry::new(87, 918, 0, "Piano", "Cactus"),
+    FrameEntry::new(87, 972, 0, "Piano", "Spooky"),
+    FrameEntry::new(87, 1026, 0, "Piano", "Skyware"),
+    FrameEntry::new(87, 1080, 0, "Piano", "Lihzahrd"),
+    FrameEntry::new(87, 1134, 0, "Piano", "Palm Wood"),
+    FrameEntry::new(87, 1188, 0, "Piano", "Mushroom"),
+    FrameEntry::new(87, 1242, 0, "Piano", "Boreal Wood"),
+    FrameEntry::new(87, 1296, 0, "Piano", "Slime"),
+    FrameEntry::new(87, 1350, 0, "Piano", "Pumpkin"),
+    FrameEntry::new(87, 1404, 0, "Piano", "Martian"),
+    FrameEntry::new(87, 1458, 0, "Piano", "Meteorite"),
+    FrameEntry::new(87, 1512, 0, "Piano", "Granite"),
+    FrameEntry::new(87, 1566, 0, "Piano", "Marble"),
+    FrameEntry::new(87, 1620, 0, "Piano", "Crystal"),
+    FrameEntry::new(87, 1674, 0, "Piano", "Dynasty"),
+    FrameEntry::new(88, 0, 0, "Dresser", "Wooden"),
+    FrameEntry::new(88, 54, 0, "Dresser", "Ebonwood"),
+    FrameEntry::new(88, 108, 0, "Dresser", "Rich Mahogany"),
+    FrameEntry::new(88, 162, 0, "Dresser", "Pearlwood"),
+    FrameEntry::new(88, 216, 0, "Dresser", "Shadewood"),
+    FrameEntry::new(88, 270, 0, "Dresser", "Blue Dungeon"),
+    FrameEntry::new(88, 324, 0, "Dresser", "Green Dungeon"),
+    FrameEntry::new(88, 378, 0, "Dresser", "Pink Dungeon"),
+    FrameEntry::new(88, 432, 0, "Dresser", "Golden"),
+    FrameEntry::new(88, 486, 0, "Dresser", "Obsidian"),
+    FrameEntry::new(88, 540, 0, "Dresser", "Bone"),
+    FrameEntry::new(88, 594, 0, "Dresser", "Cactus"),
+    FrameEntry::new(88, 648, 0, "Dresser", "Spooky"),
+    FrameEntry::new(88, 702, 0, "Dresser", "Skyware"),
+    FrameEntry::new(88, 756, 0, "Dresser", "Honey"),
+    FrameEntry::new(88, 810, 0, "Dresser", "Lihzahrd"),
+    FrameEntry::new(88, 864, 0, "Dresser", "Palm Wood"),
+    FrameEntry::new(88, 918, 0, "Dresser", "Mushroom"),
+    FrameEntry::new(88, 972, 0, "Dresser", "Boreal Wood"),
+    FrameEntry::new(88, 1026, 0, "Dresser", "Slime"),
+    FrameEntry::new(88, 1080, 0, "Dresser", "Pumpkin"),
+    FrameEntry::new(88, 1134, 0, "Dresser", "Steampunk"),
+    FrameEntry::new(88, 1188, 0, "Dresser", "Glass"),
+    FrameEntry::new(88, 1242, 0, "Dresser", "Flesh"),
+    FrameEntry::new(88, 1296, 0, "Dresser", "Martian"),
+    FrameEntry::new(88, 1350, 0, "Dresser", "Meteorite"),
+    FrameEntry::new(88, 1404, 0, "Dresser", "Granite"),
+    FrameEntry::new(88, 1458, 0, "Dresser", "Marble"),
+    FrameEntry::new(88, 1512, 0, "Dresser", "Crystal"),
+    FrameEntry::new(88, 1566, 0, "Dresser", "Dynasty"),
+    FrameEntry::new(88, 1620, 0, "Dresser", "Frozen"),
+    FrameEntry::new(88, 1674, 0, "Dresser", "Living Wood"),
+    FrameEntry::new(89, 0, 0, "Bench", "Wood Bench"),
+    FrameEntry::new(89, 54, 0, "Bench", "Sofa"),
+    FrameEntry::new(89, 108, 0, "Bench", "Ebonwood Sofa"),
+    FrameEntry::new(89, 162, 0, "Bench", "Rich Mahogany Sofa"),
+    FrameEntry::new(89, 216, 0, "Bench", "Pearlwood Sofa"),
+    FrameEntry::new(89, 270, 0, "Bench", "Shadewood Sofa"),
+    FrameEntry::new(89, 324, 0, "Bench", "Blue Dungeon Sofa"),
+    FrameEntry::new(89, 378, 0, "Bench", "Green Dungeon Sofa"),
+    FrameEntry::new(89, 432, 0, "Bench", "Pink Dungeon Sofa"),
+    FrameEntry::new(89, 486, 0, "Bench", "Golden Sofa"),
+    FrameEntry::new(89, 540, 0, "Bench", "Obsidian Sofa"),
+    FrameEntry::new(89, 594, 0, "Bench", "Bone Sofa"),
+    FrameEntry::new(89, 648, 0, "Bench", "Cactus Sofa"),
+    FrameEntry::new(89, 702, 0, "Bench", "Spooky Sofa"),
+    FrameEntry::new(89, 756, 0, "Bench", "Skyware Sofa"),
+    FrameEntry::new(89, 810, 0, "Bench", "Honey Sofa"),
+    FrameEntry::new(89, 864, 0, "Bench", "Steampunk Sofa"),
+    FrameEntry::new(89, 918, 0, "Bench", "Mushroom Sofa"),
+    FrameEntry::new(89, 972, 0, "Bench", "Glass Sofa"),
+    FrameEntry::new(89, 1026, 0, "Bench", "Pumpkin Sofa"),
+    FrameEntry::new(89, 1080, 0, "Bench", "Lihzahrd Sofa"),
+    FrameEntry::new(89, 1134, 0, "Bench", "Palm Wood Bench"),
+    FrameEntry::new(89, 1188, 0, "Bench", "Palm Wood Sofa"),
+    FrameEntry::new(89, 1242, 0, "Bench", "Mushroom Bench"),
+    FrameEntry::new(89, 1296, 0, "Bench", "Boreal Wood Sofa"),
+    FrameEntry::new(89, 1350, 0, "Bench", "Slime Sofa"),
+    FrameEntry::new(89, 1404, 0, "Bench", "Flesh Sofa"),
+    FrameEntry::new(89, 1458, 0, "Bench", "Frozen Sofa"),
+    FrameEntry::new(89, 1512, 0, "Bench", "Living Wood Sofa"),
+    FrameEntry::new(89, 1566, 0, "Bench", "Martian Sofa"),
+    FrameEntry::new(89, 1620, 0, "Bench", "Meteorite Sofa"),
+    FrameEntry::new(89, 1674, 0, "Bench", "Granite Sofa"),
+    FrameEntry::new(89, 1728, 0, "Bench", "Marble Sofa"),
+    FrameEntry::new(89, 1782, 0, "Bench", "Crystal Sofa"),
+    FrameEntry::new(89, 1826, 0, "Bench", "Dynasty Sofa"),
+    FrameEntry::new(90, 0, 0, "Bathtub", "Metal Bathtub"),
+    FrameEntry::new(90, 72, 0, "Bathtub", "Metal Bathtub"),
+    FrameEntry::new(90, 0, 36, "Bathtub", "Cactus Bathtub"),
+    FrameEntry::new(90, 72, 36, "Bathtub", "Cactus Bathtub"),
+    FrameEntry::new(90, 0, 72, "Bathtub", "Ebonwood Bathtub"),
+    FrameEntry::new(90, 72, 72, "Bathtub", "Ebonwood Bathtub"),
+    FrameEntry::new(90, 0, 108, "Bathtub", "Flesh Bathtub"),
+    FrameEntry::new(90, 72, 108, "Bathtub", "Flesh Bathtub"),
+    FrameEntry::new(90, 0, 144, "Bathtub", "Glass Bathtub"),
+    FrameEntry::new(90, 72, 144, "Bathtub", "Glass Bathtub"),
+    FrameEntry::new(90, 0, 180, "Bathtub", "Frozen Bathtub"),
+    FrameEntry::new(90, 72, 180, "Bathtub", "Frozen Bathtub"),
+    FrameEntry::new(90, 0, 216, "Bathtub", "Rich Mahogany Bathtub"),
+    FrameEntry::new(90, 72, 216, "Bathtub", "Rich Mahogany Bathtub"),
+    FrameEntry::new(90, 0, 252, "Bathtub", "Pearlwood Bathtub"),
+    FrameEntry::new(90, 72, 252, "Bathtub", "Pearlwood Bathtub"),
+    FrameEntry::new(90, 0, 288, "Bathtub", "Lihzahrd Bathtub"),
+    FrameEntry::new(90, 72, 288, "Bathtub", "Lihzahrd Bathtub"),
+    FrameEntry::new(90, 0, 324, "Bathtub", "Skyware Bathtub"),
+    FrameEntry::new(90, 72, 324, "Bathtub", "Skyware Bathtub"),
+    FrameEntry::new(90, 0, 360, "Bathtub", "Spooky Bathtub"),
+    FrameEntry::new(90, 72, 360, "Bathtub", "Spooky Bathtub"),
+    FrameEntry::new(90, 0, 396, "Bathtub", "Honey Bathtub"),
+    FrameEntry::new(90, 72, 396, "Bathtub", "Honey Bathtub"),
+    FrameEntry::new(90, 0, 432, "Bathtub", "Steampunk Bathtub"),
+    FrameEntry::new(90, 72, 432, "Bathtub", "Steampunk Bathtub"),
+    FrameEntry::new(90, 0, 468, "Bathtub", "Living Wood Bathtub"),
+    FrameEntry::new(90, 72, 468, "Bathtub", "Living Wood Bathtub"),
+    FrameEntry::new(90, 0, 504, "Bathtub", "Shadewood Bathtub"),
+    FrameEntry::new(90, 72, 504, "Bathtub", "Shadewood Bathtub"),
+    FrameEntry::new(90, 0, 540, "Bathtub", "Bone Bathtub"),
+    FrameEntry::new(90, 72, 540, "Bathtub", "Bone Bathtub"),
+    FrameEntry::new(90, 0, 576, "Bathtub", "Dynasty Bathtub"),
+    FrameEntry::new(90, 72, 576, "Bathtub", "Dynasty Bathtub"),
+    FrameEntry::new(90, 0, 612, "Bathtub", "Palm Wood Bathtub"),
+    FrameEntry::new(90, 72, 612, "Bathtub", "Palm Wood Bathtub"),
+    FrameEntry::new(90, 0, 648, "Bathtub", "Mushroom Bathtub"),
+    FrameEntry::new(90, 72, 648, "Bathtub", "Mushroom Bathtub"),
+    FrameEntry::new(90, 0, 684, "Bathtub", "Boreal Wood Bathtub"),
+    FrameEntry::new(90, 72, 684, "Bathtub", "Boreal Wood Bathtub"),
+    FrameEntry::new(90, 0, 720, "Bathtub", "Slime Bathtub"),
+    FrameEntry::new(90, 72, 720, "Bathtub", "Slime Bathtub"),
+    FrameEntry::new(90, 0, 756, "Bathtub", "Blue Dungeon Bathtub"),
+    FrameEntry::new(90, 72, 756, "Bathtub", "Blue Dungeon Bathtub"),
+    FrameEntry::new(90, 0, 792, "Bathtub", "Green Dungeon Bathtub"),
+    FrameEntry::new(90, 72, 792, "Bathtub", "Green Dungeon Bathtub"),
+    FrameEntry::new(90, 0, 828, "Bathtub", "Pink Dungeon Bathtub"),
+    FrameEntry::new(90, 72, 828, "Bathtub", "Pink Dungeon Bathtub"),
+    FrameEntry::new(90, 0, 864, "Bathtub", "Pumpkin Bathtub"),
+    FrameEntry::new(90, 72, 864, "Bathtub", "Pumpkin Bathtub"),
+    FrameEntry::new(90, 0, 900, "Bathtub", "Obsidian Bathtub"),
+    FrameEntry::new(90, 72, 900, "Bathtub", "Obsidian Bathtub"),
+    FrameEntry::new(90, 72, 936, "Bathtub", "Golden Bathtub"),
+    FrameEntry::new(90, 0, 936, "Bathtub", "Golden Bathtub"),
+    FrameEntry::new(90, 72, 972, "Bathtub", "Martian Bathtub"),
+    FrameEntry::new(90, 0, 972, "Bathtub", "Martian Bathtub"),
+    FrameEntry::new(90, 72, 1008, "Bathtub", "Meteorite Bathtub"),
+    FrameEntry::new(90, 0, 1008, "Bathtub", "Meteorite Bathtub"),
+    FrameEntry::new(90, 72, 1044, "Bathtub", "Granite Bathtub"),
+    FrameEntry::new(90, 0, 1044, "Bathtub", "Granite Bathtub"),
+    FrameEntry::new(90, 72, 1080, "Bathtub", "Marble Bathtub"),
+    FrameEntry::new(90, 0, 1080, "Bathtub", "Marble Bathtub"),
+    FrameEntry::new(90, 72, 1116, "Crystal Bathtub", "Left"),
+    FrameEntry::new(90, 0, 1116, "Crystal Bathtub", "Right"),
+    FrameEntry::new(91, 0, 0, "Craftable Banner", "Red Banner"),
+    FrameEntry::new(91, 18, 0, "Craftable Banner", "Green Banner"),
+    FrameEntry::new(91, 36, 0, "Craftable Banner", "Blue Banner"),
+    FrameEntry::new(91, 54, 0, "Craftable Banner", "Yellow Banner"),
+    FrameEntry::new(91, 72, 0, "Pyramid Banner", "Ankh Banner"),
+    FrameEntry::new(91, 90, 0, "Pyramid Banner", "Snake Banner"),
+    FrameEntry::new(91, 108, 0, "Pyramid Banner", "Omega Banner"),
+    FrameEntry::new(91, 126, 0, "Sky Banner", "World Banner"),
+    FrameEntry::new(91, 144, 0, "Sky Banner", "Sun Banner"),
+    FrameEntry::new(91, 162, 0, "Sky Banner", "Gravity Banner"),
+    FrameEntry::new(91, 180, 0, "Faction Banner", "Marching Bones Banner"),
+    FrameEntry::new(91, 198, 0, "Faction Banner", "Necromantic Sign"),
+    FrameEntry::new(91, 216, 0, "Faction Banner", "Rusted Company Standard"),
+    FrameEntry::new(91, 234, 0, "Faction Banner", "Ragged Brotherhood Sigil"),
+    FrameEntry::new(91, 252, 0, "Faction Banner", "Molten Legion Flag"),
+    FrameEntry::new(91, 270, 0, "Faction Banner", "Diabolic Sigil"),
+    FrameEntry::new(91, 288, 0, "Underworld Banner", "Hellbound Banner"),
+    FrameEntry::new(91, 306, 0, "Underworld Banner", "Hell Hammer Banner"),
+    FrameEntry::new(91, 324, 0, "Underworld Banner", "Helltower Banner"),
+    FrameEntry::new(91, 342, 0, "Underworld Banner", "Lost Hopes of Man Banner"),
+    FrameEntry::new(91, 360, 0, "Underworld Banner", "Obsidian Watcher Banner"),
+    FrameEntry::new(91, 378, 0, "Underworld Banner", "Lava Erupts Banner"),
+    FrameEntry::new(91, 396, 0, "Monster Banner", "Angler Fish Banner"),
+    FrameEntry::new(91, 414, 0, "Monster Banner", "Angry Nimbus Banner"),
+    FrameEntry::new(91, 432, 0, "Monster Banner", "Anomura Fungus Banner"),
+    FrameEntry::new(91, 450, 0, "Monster Banner", "Antlion Banner"),
+    FrameEntry::new(91, 468, 0, "Monster Banner", "Arapaima Banner"),
+    FrameEntry::new(91, 486, 0, "Monster Banner", "Armored Skeleton Banner"),
+    FrameEntry::new(91, 504, 0, "Monster Banner", "Bat Banner"),
+    FrameEntry::new(91, 522, 0, "Monster Banner", "Bird Banner"),
+    FrameEntry::new(91, 540, 0, "Monster Banner", "Black Recluse Banner"),
+    FrameEntry::new(91, 558, 0, "Monster Banner", "Blood Feeder Banner"),
+    FrameEntry::new(91, 576, 0, "Monster Banner", "Blood Jelly Banner"),
+    FrameEntry::new(91, 594, 0, "Monster Banner", "Blood Crawler Banner"),
+    FrameEntry::new(91, 612, 0, "Monster Banner", "Bone Serpent Banner"),
+    FrameEntry::new(91, 630, 0, "Monster Banner", "Bunny Banner"),
+    FrameEntry::new(91, 648, 0, "Monster Banner", "Chaos Elemental Banner"),
+    FrameEntry::new(91, 666, 0, "Monster Banner", "Mimic Banner"),
+    FrameEntry::new(91, 684, 0, "Monster Banner", "Clown Banner"),
+    FrameEntry::new(91, 702, 0, "Monster Banner", "Corrupt Bunny Banner"),
+    FrameEntry::new(91, 720, 0, "Monster Banner", "Corrupt Goldfish Banner"),
+    FrameEntry::new(91, 738, 0, "Monster Banner", "Crab Banner"),
+    FrameEntry::new(91, 756, 0, "Monster Banner", "Crimera Banner"),
+    FrameEntry::new(91, 774, 0, "Monster Banner", "Crimson Axe Banner"),
+    FrameEntry::new(91, 792, 0, "Monster Banner", "Cursed Hammer Banner"),
+    FrameEntry::new(91, 810, 0, "Monster Banner", "Demon Banner"),
+    FrameEntry::new(91, 828, 0, "Monster Banner", "Demon Eye Banner"),
+    FrameEntry::new(91, 846, 0, "Monster Banner", "Derpling Banner"),
+    FrameEntry::new(91, 864, 0, "Monster Banner", "Eater of Souls Banner"),
+    FrameEntry::new(91, 882, 0, "Monster Banner", "Enchanted Sword Banner"),
+    FrameEntry::new(91, 900, 0, "Monster Banner", "Zombie Eskimo Banner"),
+    FrameEntry::new(91, 918, 0, "Monster Banner", "Face Monster Banner"),
+    FrameEntry::new(91, 936, 0, "Monster Banner", "Floaty Gross Banner"),
+    FrameEntry::new(91, 954, 0, "Monster Banner", "Flying Fish Banner"),
+    FrameEntry::new(91, 972, 0, "Monster Banner", "Flying Snake Banner"),
+    FrameEntry::new(91, 990, 0, "Monster Banner", "Frankenstein Banner"),
+    FrameEntry::new(91, 1008, 0, "Monster Banner", "Fungi Bulb Banner"),
+    FrameEntry::new(91, 1026, 0, "Monster Banner", "Fungo Fish Banner"),
+    FrameEntry::new(91, 1044, 0, "Monster Banner", "Gastropod Banner"),
+    FrameEntry::new(91, 1062, 0, "Monster Banner", "Goblin Archer Banner"),
+    FrameEntry::new(91, 1080, 0, "Monster Banner", "Goblin Sorcerer Banner"),
+    FrameEntry::new(91, 1098, 0, "Monster Banner", "Goblin Scout Banner"),
+    FrameEntry::new(91, 1116, 0, "Monster Banner", "Goblin Thief Banner"),
+    FrameEntry::new(91, 1134, 0, "Monster Banner", "Goblin Warrior Banner"),
+    FrameEntry::new(91, 1152, 0, "Monster Banner", "Goldfish Banner"),
+    FrameEntry::new(91, 1170, 0, "Monster Banner", "Harpy Banner"),
+    FrameEntry::new(91, 1188, 0, "Monster Banner", "Hellbat Banner"),
+    FrameEntry::new(91, 1206, 0, "Monster Banner", "Herpling Banner"),
+    FrameEntry::new(91, 1224, 0, "Monster Banner", "Hornet Banner"),
+    FrameEntry::new(91, 1242, 0, "Monster Banner", "Ice Elemental Banner"),
+    FrameEntry::new(91, 1260, 0, "Monster Banner", "Icy Merman Banner"),
+    FrameEntry::new(91, 1278, 0, "Monster Banner", "Fire Imp Banner"),
+    FrameEntry::new(91, 1296, 0, "Monster Banner", "Blue Jellyfish Banner"),
+    FrameEntry::new(91, 1314, 0, "Monster Banner", "Jungle Creeper Banner"),
+    FrameEntry::new(91, 1332, 0, "Monster Banner", "Lihzahrd Banner"),
+    FrameEntry::new(91, 1350, 0, "Monster Banner", "Man Eater Banner"),
+    FrameEntry::new(91, 1368, 0, "Monster Banner", "Meteor Head Banner"),
+    FrameEntry::new(91, 1386, 0, "Monster Banner", "Moth Banner"),
+    FrameEntry::new(91, 1404, 0, "Monster Banner", "Mummy Banner"),
+    FrameEntry::new(91, 1422, 0, "Monster Banner", "Mushi Ladybug Banner"),
+    FrameEntry::new(91, 1440, 0, "Monster Banner", "Parrot Banner"),
+    FrameEntry::new(91, 1458, 0, "Monster Banner", "Pigron Banner"),
+    FrameEntry::new(91, 1476, 0, "Monster Banner", "Piranha Banner"),
+    FrameEntry::new(91, 1494, 0, "Monster Banner", "Pirate Deckhand Banner"),
+    FrameEntry::new(91, 1512, 0, "Monster Banner", "Pixie Banner"),
+    FrameEntry::new(91, 1530, 0, "Monster Banner", "Raincoat Zombie Banner"),
+    FrameEntry::new(91, 1548, 0, "Monster Banner", "Reaper Banner"),
+    FrameEntry::new(91, 1566, 0, "Monster Banner", "Shark Banner"),
+    FrameEntry::new(91, 1584, 0, "Monster Banner", "Skeleton Banner"),
+    FrameEntry::new(91, 1602, 0, "Monster Banner", "Skeleton Mage Banner"),
+    FrameEntry::new(91, 1620, 0, "Monster Banner", "Blue Slime Banner"),
+    FrameEntry::new(91, 1638, 0, "Monster Banner", "Snow Flinx Banner"),
+    FrameEntry::new(91, 1656, 0, "Monster Banner", "Spider Banner"),
+    FrameEntry::new(91, 1674, 0, "Monster Banner", "Spore Zombie Banner"),
+    FrameEntry::new(91, 1692, 0, "Monster Banner", "Swamp Thing Banner"),
+    FrameEntry::new(91, 1710, 0, "Monster Banner", "Tortoise Banner"),
+    FrameEntry::new(91, 1728, 0, "Monster Banner", "Toxic Sludge Banner"),
+    FrameEntry::new(91, 1746, 0, "Monster Banner", "Umbrella Slime Banner"),
+    FrameEntry::new(91, 1764, 0, "Monster Banner", "Unicorn Banner"),
+    FrameEntry::new(91, 1782, 0, "Monster Banner", "Vampire Banner"),
+    FrameEntry::new(91, 1800, 0, "Monster Banner", "Vulture Banner"),
+    FrameEntry::new(91, 1818, 0, "Monster Banner", "Nypmh Banner"),
+    FrameEntry::new(91, 1836, 0, "Monster Banner", "Werewolf Banner"),
+    FrameEntry::new(91, 1854, 0, "Monster Banner", "Wolf Banner"),
+    FrameEntry::new(91, 1872, 0, "Monster Banner", "World Feeder Banner"),
+    FrameEntry::new(91, 1890, 0, "Monster Banner", "Worm Banner"),
+    FrameEntry::new(91, 1908, 0, "Monster Banner", "Wraith Banner"),
+    FrameEntry::new(91, 1926, 0, "Monster Banner", "Wyvern Banner"),
+    FrameEntry::new(91, 1944, 0, "Monster Banner", "Zombie Banner"),
+    FrameEntry::new(91, 1962, 0, "Monster Banner", ""),
+    FrameEntry::new(91, 1980, 0, "Monster Banner", ""),
+    FrameEntry::new(91, 0, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 18, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 36, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 54, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 72, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 90, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 108, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 126, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 144, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 162, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 180, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 198, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 216, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 234, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 252, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 270, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 288, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 306, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 324, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 342, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 360, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 378, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 396, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 414, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 432, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 450, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 468, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 486, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 504, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 522, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 540, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 558, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 576, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 594, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 612, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 630, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 648, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 666, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 684, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 702, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 720, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 738, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 756, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 774, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 792, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 810, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 828, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 846, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 864, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 882, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 900, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 918, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 936, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 954, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 972, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 990, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1008, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1026, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1044, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1062, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1080, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1098, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1116, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1134, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1152, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1170, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1188, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1206, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1224, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1242, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1260, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1278, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1296, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1314, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1332, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1350, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1368, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1386, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1404, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1422, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1440, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1458, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1476, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1494, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1512, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1530, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1548, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1566, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1584, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1602, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1620, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1638, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1656, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1674, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1692, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1710, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1728, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1746, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1764, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1782, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1800, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1818, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1836, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1854, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1872, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1890, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1908, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1926, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1944, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1962, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 1980, 54, "Monster Banner", ""),
+    FrameEntry::new(91, 0, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 18, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 36, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 54, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 72, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 90, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 108, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 126, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 144, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 162, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 180, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 198, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 216, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 234, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 252, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 270, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 288, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 306, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 324, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 342, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 360, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 378, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 396, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 414, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 432, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 450, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 468, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 486, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 504, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 522, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 540, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 558, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 576, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 594, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 612, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 630, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 648, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 666, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 684, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 702, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 720, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 738, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 756, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 774, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 792, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 810, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 828, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 846, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 864, 108, "Monster Banner", ""),
+    FrameEntry::new(91, 882, 108, "Monster Banner", ""),
+    FrameEntry::new(92, 0, 0, "Lamp Post", "On"),
+    FrameEntry::new(92, 18, 0, "Lamp Post", "Off"),
+    FrameEntry::new(93, 0, 0, "Tiki Torch", "On"),
+    FrameEntry::new(93, 18, 0, "Tiki Torch", "Off"),
+    FrameEntry::new(93, 0, 54, "Cactus Lamp", "On"),
+    FrameEntry::new(93, 18, 54, "Cactus Lamp", "Off"),
+    FrameEntry::new(93, 0, 108, "Ebonwood Lamp", "On"),
+    FrameEntry::new(93, 18, 108, "Ebonwood Lamp", "Off"),
+    FrameEntry::new(93, 0, 162, "Flesh Lamp", "On"),
+    FrameEntry::new(93, 18, 162, "Flesh Lamp", "Off"),
+    FrameEntry::new(93, 0, 216, "Glass Lamp", "On"),
+    FrameEntry::new(93, 18, 216, "Glass Lamp", "Off"),
+    FrameEntry::new(93, 0, 270, "Frozen Lamp", "On"),
+    FrameEntry::new(93, 18, 270, "Frozen Lamp", "Off"),
+    FrameEntry::new(93, 0, 324, "Rich Mahogany Lamp", "On"),
+    FrameEntry::new(93, 18, 324, "Rich Mahogany Lamp", "Off"),
+    FrameEntry::new(93, 0, 378, "Pearlwood Lamp", "On"),
+    FrameEntry::new(93, 18, 378, "Pearlwood Lamp", "Off"),
+    FrameEntry::new(93, 0, 432, "Lihzahrd Lamp", "On"),
+    FrameEntry::new(93, 18, 432, "Lihzahrd Lamp", "Off"),
+    FrameEntry::new(93, 0, 486, "Skyware Lamp", "On"),
+    FrameEntry::new(93, 18, 486, "Skyware Lamp", "Off"),
+    FrameEntry::new(93, 0, 540, "Spooky Lamp", "On"),
+    FrameEntry::new(93, 18, 540, "Spooky Lamp", "Off"),
+    FrameEntry::new(93, 0, 594, "Honey Lamp", "On"),
+    FrameEntry::new(93, 18, 594, "Honey Lamp", "Off"),
+    FrameEntry::new(93, 0, 648, "Steampunk Lamp", "On"),
+    FrameEntry::new(93, 18, 648, "Steampunk Lamp", "Off"),
+    FrameEntry::new(93, 0, 702, "Living Wood Lamp", "On"),
+    FrameEntry::new(93, 18, 702, "Living Wood Lamp", "Off"),
+    FrameEntry::new(93, 0, 756, "Shadewood Lamp", "On"),
+    FrameEntry::new(93, 18, 756, "Shadewood Lamp", "Off"),
+    FrameEntry::new(93, 0, 810, "Golden Lamp", "On"),
+    FrameEntry::new(93, 18, 810, "Golden Lamp", "Off"),
+    FrameEntry::new(93, 0, 864, "Bone Lamp", "On"),
+    FrameEntry::new(93, 18, 864, "Bone Lamp", "Off"),
+    FrameEntry::new(93, 0, 918, "Dynasty Lamp", "On"),
+    FrameEntry::new(93, 18, 918, "Dynasty Lamp", "Off"),
+    FrameEntry::new(93, 0, 972, "Palm Wood Lamp", "On"),
+    FrameEntry::new(93, 18, 972, "Palm Wood Lamp", "Off"),
+    FrameEntry::new(93, 0, 1026, "Mushroom Lamp", "On"),
+    FrameEntry::new(93, 18, 1026, "Mushroom Lamp", "Off"),
+    FrameEntry::new(93, 0, 1080, "Boreal Wood Lamp", "On"),
+    FrameEntry::new(93, 18, 1080, "Boreal Wood Lamp", "Off"),
+    FrameEntry::new(93, 0, 1134, "Slime Lamp", "On"),
+    FrameEntry::new(93, 18, 1134, "Slime Lamp", "Off"),
+    FrameEntry::new(93, 0, 1188, "Pumpkin Lamp", "On"),
+    FrameEntry::new(93, 18, 1188, "Pumpkin Lamp", "Off"),
+    FrameEntry::new(93, 0, 1242, "Obsidian Lamp", "On"),
+    FrameEntry::new(93, 18, 1242, "Obsidian Lamp", "Off"),
+    FrameEntry::new(93, 0, 1296, "Blue Dungeon Lamp", "On"),
+    FrameEntry::new(93, 18, 1296, "Blue Dungeon Lamp", "Off"),
+    FrameEntry::new(93, 0, 1350, "Green Dungeon Lamp", "On"),
+    FrameEntry::new(93, 18, 1350, "Green Dungeon Lamp", "Off"),
+    FrameEntry::new(93, 0, 1404, "Pink Dungeon Lamp", "On"),
+    FrameEntry::new(93, 18, 1404, "Pink Dungeon Lamp", "Off"),
+    FrameEntry::new(93, 0, 1458, "Martian Lamppost", "On"),
+    FrameEntry::new(93, 18, 1458, "Martian Lamppost", "Off"),
+    FrameEntry::new(93, 0, 1512, "Meteorite Lamp", "On"),
+    FrameEntry::new(93, 18, 1512, "Meteorite Lamp", "Off"),
+    FrameEntry::new(93, 0, 1566, "Granite Lamp", "On"),
+    FrameEntry::new(93, 18, 1566, "Granite Lamp", "Off"),
+    FrameEntry::new(93, 0, 1620, "Marble Lamp", "On"),
+    FrameEntry::new(93, 18, 1620, "Marble Lamp", "Off"),
+    FrameEntry::new(93, 0, 1674, "Crystal Lamp", "On"),
+    FrameEntry::new(93, 18, 1674, "Crystal Lamp", "Off"),
+    FrameEntry::new(95, 0, 0, "Chinese Lantern", "On"),
+    FrameEntry::new(95, 36, 0, "Chinese Lantern", "Off"),
+    FrameEntry::new(96, 0, 0, "Cooking Pot", "Cooking Pot"),
+    FrameEntry::new(96, 36, 0, "Cooking Pot", "Cauldron"),
+    FrameEntry::new(100, 0, 0, "Candelabra", "On"),
+    FrameEntry::new(100, 36, 0, "Candelabra", "Off"),
+    FrameEntry::new(100, 0, 36, "Cactus Candelabra", "On"),
+    FrameEntry::new(100, 36, 36, "Cactus Candelabra", "Off"),
+    FrameEntry::new(100, 0, 72, "Ebonwood Candelabra", "On"),
+    FrameEntry::new(100, 36, 72, "Ebonwood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 108, "Flesh Candelabra", "On"),
+    FrameEntry::new(100, 36, 108, "Flesh Candelabra", "Off"),
+    FrameEntry::new(100, 0, 144, "Honey Candelabra", "On"),
+    FrameEntry::new(100, 36, 144, "Honey Candelabra", "Off"),
+    FrameEntry::new(100, 0, 180, "Steampunk Candelabra", "On"),
+    FrameEntry::new(100, 36, 180, "Steampunk Candelabra", "Off"),
+    FrameEntry::new(100, 0, 216, "Glass Candelabra", "On"),
+    FrameEntry::new(100, 36, 216, "Glass Candelabra", "Off"),
+    FrameEntry::new(100, 0, 252, "Rich Mahogany Candelabra", "On"),
+    FrameEntry::new(100, 36, 252, "Rich Mahogany Candelabra", "Off"),
+    FrameEntry::new(100, 0, 288, "Pearlwood Candelabra", "On"),
+    FrameEntry::new(100, 36, 288, "Pearlwood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 324, "Frozen Candelabra", "On"),
+    FrameEntry::new(100, 36, 324, "Frozen Candelabra", "Off"),
+    FrameEntry::new(100, 0, 360, "Lihzahrd Candelabra", "On"),
+    FrameEntry::new(100, 36, 360, "Lihzahrd Candelabra", "Off"),
+    FrameEntry::new(100, 0, 396, "Skyware Candelabra", "On"),
+    FrameEntry::new(100, 36, 396, "Skyware Candelabra", "Off"),
+    FrameEntry::new(100, 0, 432, "Spooky Candelabra", "On"),
+    FrameEntry::new(100, 36, 432, "Spooky Candelabra", "Off"),
+    FrameEntry::new(100, 0, 468, "Living Wood Candelabra", "On"),
+    FrameEntry::new(100, 36, 468, "Living Wood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 504, "Shadewood Candelabra", "On"),
+    FrameEntry::new(100, 36, 504, "Shadewood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 540, "Golden Candelabra", "On"),
+    FrameEntry::new(100, 36, 540, "Golden Candelabra", "Off"),
+    FrameEntry::new(100, 0, 576, "Bone Candelabra", "On"),
+    FrameEntry::new(100, 36, 576, "Bone Candelabra", "Off"),
+    FrameEntry::new(100, 0, 612, "Large Dynasty Candelabra", "On"),
+    FrameEntry::new(100, 36, 612, "Large Dynasty Candelabra", "Off"),
+    FrameEntry::new(100, 0, 648, "Palm Wood Candelabra", "On"),
+    FrameEntry::new(100, 36, 648, "Palm Wood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 684, "Mushroom Candelabra", "On"),
+    FrameEntry::new(100, 36, 684, "Mushroom Candelabra", "Off"),
+    FrameEntry::new(100, 0, 720, "Boreal Wood Candelabra", "On"),
+    FrameEntry::new(100, 36, 720, "Boreal Wood Candelabra", "Off"),
+    FrameEntry::new(100, 0, 756, "Slime Candelabra", "On"),
+    FrameEntry::new(100, 36, 756, "Slime Candelabra", "Off"),
+    FrameEntry::new(100, 0, 792, "Blue Dungeon Candelabra", "On"),
+    FrameEntry::new(100, 36, 792, "Blue Dungeon Candelabra", "Off"),
+    FrameEntry::new(100, 0, 828, "Green Dungeon Candelabra", "On"),
+    FrameEntry::new(100, 36, 828, "Green Dungeon Candelabra", "Off"),
+    FrameEntry::new(100, 0, 864, "Pink Dungeon Candelabra", "On"),
+    FrameEntry::new(100, 36, 864, "Pink Dungeon Candelabra", "Off"),
+    FrameEntry::new(100, 0, 900, "Obsidian Candelabra", "On"),
+    FrameEntry::new(100, 36, 900, "Obsidian Candelabra", "Off"),
+    FrameEntry::new(100, 0, 936, "Pumpkin Candelabra", "On"),
+    FrameEntry::new(100, 36, 936, "Pumpkin Candelabra", "Off"),
+    FrameEntry::new(100, 0, 972, "Martian Candelabra", "On"),
+    FrameEntry::new(100, 36, 972, "Martian Candelabra", "Off"),
+    FrameEntry::new(100, 0, 1008, "Meteorite Candelabra", "On"),
+    FrameEntry::new(100, 36, 1008, "Meteorite Candelabra", "Off"),
+    FrameEntry::new(100, 0, 1044, "Granite Candelabra", "On"),
+    FrameEntry::new(100, 36, 1044, "Granite Candelabra", "Off"),
+    FrameEntry::new(100, 0, 1080, "Marble Candelabra", "On"),
+    FrameEntry::new(100, 36, 1080, "Marble Candelabra", "Off"),
+    FrameEntry::new(100, 0, 1116, "Crystal Candelabra", "On"),
+    FrameEntry::new(100, 36, 1116, "Crystal Candelabra", "Off"),
+    FrameEntry::new(101, 0, 0, "Bookcase", "Wooden"),
+    FrameEntry::new(101, 54, 0, "Bookcase", "Blue Dungeon Bookcase"),
+    FrameEntry::new(101, 108, 0, "Bookcase", "Green Dungeon Bookcase"),
+    FrameEntry::new(101, 162, 0, "Bookcase", "Pink Dungeon Bookcase"),
+    FrameEntry::new(101, 216, 0, "Bookcase", "Obsidian Bookcase"),
+    FrameEntry::new(101, 270, 0, "Bookcase", "Gothic Bookcase"),
+    FrameEntry::new(101, 324, 0, "Bookcase", "Cactus Bookcase"),
+    FrameEntry::new(101, 378, 0, "Bookcase", "Ebonwood Bookcase"),
+    FrameEntry::new(101, 432, 0, "Bookcase", "Flesh Bookcase"),
+    FrameEntry::new(101, 486, 0, "Bookcase", "Honey Bookcase"),
+    FrameEntry::new(101, 540, 0, "Bookcase", "Steampunk Bookcase"),
+    FrameEntry::new(101, 594, 0, "Bookcase", "Glass Bookcase"),
+    FrameEntry::new(101, 648, 0, "Bookcase", "Rich Mahogany Bookcase"),
+    FrameEntry::new(101, 702, 0, "Bookcase", "Pearlwood Bookcase"),
+    FrameEntry::new(101, 756, 0, "Bookcase", "Spooky Bookcase"),
+    FrameEntry::new(101, 810, 0, "Bookcase", "Skyware Bookcase"),
+    FrameEntry::new(101, 864, 0, "Bookcase", "Lihzahrd Bookcase"),
+    FrameEntry::new(101, 918, 0, "Bookcase", "Frozen Bookcase"),
+    FrameEntry::new(101, 972, 0, "Bookcase", "Living Wood Bookcase"),
+    FrameEntry::new(101, 1026, 0, "Bookcase", "Shadewood Bookcase"),
+    FrameEntry::new(101, 1080, 0, "Bookcase", "Golden Bookcase"),
+    FrameEntry::new(101, 1134, 0, "Bookcase", "Bone Bookcase"),
+    FrameEntry::new(101, 1188, 0, "Bookcase", "Dynasty Bookcase"),
+    FrameEntry::new(101, 1242, 0, "Bookcase", "Palm Wood Bookcase"),
+    FrameEntry::new(101, 1296, 0, "Bookcase", "Mushroom Bookcase"),
+    FrameEntry::new(101, 1350, 0, "Bookcase", "Boreal Wood Bookcase"),
+    FrameEntry::new(101, 1404, 0, "Bookcase", "Slime Bookcase"),
+    FrameEntry::new(101, 1458, 0, "Bookcase", "Pumpkin Bookcase"),
+    FrameEntry::new(101, 1512, 0, "Bookcase", "Martian Holobookcase"),
+    FrameEntry::new(101, 1566, 0, "Bookcase", "Meteorite Bookcase"),
+    FrameEntry::new(101, 1620, 0, "Bookcase", "Granite Bookcase"),
+    FrameEntry::new(101, 1674, 0, "Bookcase", "Marble Bookcase"),
+    FrameEntry::new(101, 1728, 0, "Bookcase", "Crystal Bookcase"),
+    FrameEntry::new(103, 0, 0, "Bowl", "Default"),
+    FrameEntry::new(103, 36, 0, "Bowl", "Dynasty Bowl"),
+    FrameEntry::new(103, 72, 0, "Bowl", "Fancy Dishes"),
+    FrameEntry::new(103, 108, 0, "Bowl", "Glass Bowl"),
+    FrameEntry::new(104, 0, 0, "Grandfather Clock", ""),
+    FrameEntry::new(104, 36, 0, "Dynasty Clock", ""),
+    FrameEntry::new(104, 72, 0, "Golden Clock", ""),
+    FrameEntry::new(104, 108, 0, "Glass Clock", ""),
+    FrameEntry::new(104, 144, 0, "Honey Clock", ""),
+    FrameEntry::new(104, 180, 0, "Steampunk Clock", ""),
+    FrameEntry::new(104, 216, 0, "Boreal Wood Clock", ""),
+    FrameEntry::new(104, 252, 0, "Slime Clock", ""),
+    FrameEntry::new(104, 288, 0, "Bone Clock", ""),
+    FrameEntry::new(104, 324, 0, "Cactus Clock", ""),
+    FrameEntry::new(104, 360, 0, "Ebonwood Clock", ""),
+    FrameEntry::new(104, 396, 0, "Frozen Clock", ""),
+    FrameEntry::new(104, 432, 0, "Lihzahrd Clock", ""),
+    FrameEntry::new(104, 468, 0, "Living Wood Clock", ""),
+    FrameEntry::new(104, 504, 0, "Rich Mahogany Clock", ""),
+    FrameEntry::new(104, 540, 0, "Flesh Clock", ""),
+    FrameEntry::new(104, 576, 0, "Mushroom Clock", ""),
+    FrameEntry::new(104, 612, 0, "Obsidian Clock", ""),
+    FrameEntry::new(104, 648, 0, "Palm Wood Clock", ""),
+    FrameEntry::new(104, 684, 0, "Pearlwood Clock", ""),
+    FrameEntry::new(104, 720, 0, "Pumpkin Clock", ""),
+    FrameEntry::new(104, 756, 0, "Shadewood Clock", ""),
+    FrameEntry::new(104, 792, 0, "Spooky Clock", ""),
+    FrameEntry::new(104, 828, 0, "Sunplate Clock", ""),
+    FrameEntry::new(104, 864, 0, "Martian Astro Clock", ""),
+    FrameEntry::new(104, 900, 0, "Meteorite Clock", ""),
+    FrameEntry::new(104, 936, 0, "Granite Clock", ""),
+    FrameEntry::new(104, 972, 0, "Marble Clock", ""),
+    FrameEntry::new(104, 1008, 0, "Crystal Clock", ""),
+    FrameEntry::new(104, 1044, 0, "Sunplate Clock", ""),
+    FrameEntry::new(104, 1080, 0, "Blue Dungeon Clock", ""),
+    FrameEntry::new(104, 1116, 0, "Green Dungeon Clock", ""),
+    FrameEntry::new(104, 1152, 0, "Pink Dungeon Clock", ""),
+    FrameEntry::new(105, 0, 0, "Armor Statue", "Left"),
+    FrameEntry::new(105, 36, 0, "Angel Statue", "Left"),
+    FrameEntry::new(105, 72, 0, "Star Statue", "Left"),
+    FrameEntry::new(105, 108, 0, "Sword Statue", "Left"),
+    FrameEntry::new(105, 144, 0, "Slime Statue", "Left"),
+    FrameEntry::new(105, 180, 0, "Goblin Statue", "Left"),
+    FrameEntry::new(105, 216, 0, "Shield Statue", "Left"),
+    FrameEntry::new(105, 252, 0, "Bat Statue", "Left"),
+    FrameEntry::new(105, 288, 0, "Fish Statue", "Left"),
+    FrameEntry::new(105, 324, 0, "Bunny Statue", "Left"),
+    FrameEntry::new(105, 360, 0, "Skeleton Statue", "Left"),
+    FrameEntry::new(105, 396, 0, "Reaper Statue", "Left"),
+    FrameEntry::new(105, 432, 0, "Woman Statue", "Left"),
+    FrameEntry::new(105, 468, 0, "Imp Statue", "Left"),
+    FrameEntry::new(105, 504, 0, "Gargoyle Statue", "Left"),
+    FrameEntry::new(105, 540, 0, "Gloom Statue", "Left"),
+    FrameEntry::new(105, 576, 0, "Hornet Statue", "Left"),
+    FrameEntry::new(105, 612, 0, "Bomb Statue", "Left"),
+    FrameEntry::new(105, 648, 0, "Crab Statue", "Left"),
+    FrameEntry::new(105, 684, 0, "Hammer Statue", "Left"),
+    FrameEntry::new(105, 720, 0, "Potion Statue", "Left"),
+    FrameEntry::new(105, 756, 0, "Spear Statue", "Left"),
+    FrameEntry::new(105, 792, 0, "Cross Statue", "Left"),
+    FrameEntry::new(105, 828, 0, "Jellyfish Statue", "Left"),
+    FrameEntry::new(105, 864, 0, "Bow Statue", "Left"),
+    FrameEntry::new(105, 900, 0, "Boomerang Statue", "Left"),
+    FrameEntry::new(105, 936, 0, "Boot Statue", "Left"),
+    FrameEntry::new(105, 972, 0, "Chest Statue", "Left"),
+    FrameEntry::new(105, 1008, 0, "Bird Statue", "Left"),
+    FrameEntry::new(105, 1044, 0, "Axe Statue", "Left"),
+    FrameEntry::new(105, 1080, 0, "Corrupt Statue", "Left"),
+    FrameEntry::new(105, 1116, 0, "Tree Statue", "Left"),
+    FrameEntry::new(105, 1152, 0, "Anvil Statue", "Left"),
+    FrameEntry::new(105, 1188, 0, "Pickaxe Statue", "Left"),
+    FrameEntry::new(105, 1224, 0, "Mushroom Statue", "Left"),
+    FrameEntry::new(105, 1260, 0, "Eyeball Statue", "Left"),
+    FrameEntry::new(105, 1296, 0, "Pillar Statue", "Left"),
+    FrameEntry::new(105, 1332, 0, "Heart Statue", "Left"),
+    FrameEntry::new(105, 1368, 0, "Pot Statue", "Left"),
+    FrameEntry::new(105, 1404, 0, "Sunflower Statue", "Left"),
+    FrameEntry::new(105, 1440, 0, "King Statue", "Left"),
+    FrameEntry::new(105, 1476, 0, "Queen Statue", "Left"),
+    FrameEntry::new(105, 1512, 0, "Piranha Statue", "Left"),
+    FrameEntry::new(105, 1548, 0, "Lihzahrd Statue", "Left"),
+    FrameEntry::new(105, 1584, 0, "Lihzahrd Watcher Statue", "Left"),
+    FrameEntry::new(105, 1620, 0, "Lihzahrd Guardian Statue", "Left"),
+    FrameEntry::new(105, 1656, 0, "Blue Dungeon Vase", "Left"),
+    FrameEntry::new(105, 1692, 0, "Green Dungeon Vase", "Left"),
+    FrameEntry::new(105, 1728, 0, "Pink Dungeon Vase", "Left"),
+    FrameEntry::new(105, 1764, 0, "Obsidian Vase", "Left"),
+    FrameEntry::new(105, 1800, 0, "Shark Statue", "Left"),
+    FrameEntry::new(105, 1836, 0, "Squirrel Statue", "Left"),
+    FrameEntry::new(105, 1872, 0, "Butterfly Statue", "Left"),
+    FrameEntry::new(105, 1908, 0, "Worm Statue", "Left"),
+    FrameEntry::new(105, 1944, 0, "Firefly Statue", "Left"),
+    FrameEntry::new(105, 0, 54, "Scorpion Statue", "Left"),
+    FrameEntry::new(105, 36, 54, "Snail Statue", "Left"),
+    FrameEntry::new(105, 72, 54, "Grasshopper Statue", "Left"),
+    FrameEntry::new(105, 108, 54, "Mouse Statue", "Left"),
+    FrameEntry::new(105, 144, 54, "Duck Statue", "Left"),
+    FrameEntry::new(105, 180, 54, "Penguin Statue", "Left"),
+    FrameEntry::new(105, 216, 54, "Frog Statue", "Left"),
+    FrameEntry::new(105, 252, 54, "Buggy Statue", "Left"),
+    FrameEntry::new(105, 288, 54, "Wall Creeper Statue", "Left"),
+    FrameEntry::new(105, 324, 54, "Unicorn Statue", "Left"),
+    FrameEntry::new(105, 360, 54, "Drippler Statue", "Left"),
+    FrameEntry::new(105, 396, 54, "Wraith Statue", "Left"),
+    FrameEntry::new(105, 432, 54, "Bone Skeleton Statue", "Left"),
+    FrameEntry::new(105, 468, 54, "Undead Viking Statue", "Left"),
+    FrameEntry::new(105, 504, 54, "Medusa Statue", "Left"),
+    FrameEntry::new(105, 540, 54, "Harpy Statue", "Left"),
+    FrameEntry::new(105, 576, 54, "Pigron Statue", "Left"),
+    FrameEntry::new(105, 612, 54, "Hoplite Statue", "Left"),
+    FrameEntry::new(105, 648, 54, "Granite Golem Statue", "Left"),
+    FrameEntry::new(105, 684, 54, "Armed Zombie Statue", "Left"),
+    FrameEntry::new(105, 720, 54, "Blood Zombie Statue", "Left"),
+    FrameEntry::new(105, 756, 54, "Owl Statue", "Left"),
+    FrameEntry::new(105, 792, 54, "Seagull Statue", "Left"),
+    FrameEntry::new(105, 828, 54, "Dragonfly Statue", "Left"),
+    FrameEntry::new(105, 864, 54, "Turtle Statue", "Left"),
+    FrameEntry::new(105, 900, 54, "Macaw Statue", "Left"),
+    FrameEntry::new(105, 936, 54, "Toucan Statue", "Left"),
+    FrameEntry::new(105, 972, 54, "Cockatiel Statue", "Left"),
+    FrameEntry::new(105, 0, 162, "Armor Statue", "Right"),
+    FrameEntry::new(105, 36, 162, "Angel Statue", "Right"),
+    FrameEntry::new(105, 72, 162, "Star Statue", "Right"),
+    FrameEntry::new(105, 108, 162, "Sword Statue", "Right"),
+    FrameEntry::new(105, 144, 162, "Slime Statue", "Right"),
+    FrameEntry::new(105, 180, 162, "Goblin Statue", "Right"),
+    FrameEntry::new(105, 216, 162, "Shield Statue", "Right"),
+    FrameEntry::new(105, 252, 162, "Bat Statue", "Right"),
+    FrameEntry::new(105, 288, 162, "Fish Statue", "Right"),
+    FrameEntry::new(105, 324, 162, "Bunny Statue", "Right"),
+    FrameEntry::new(105, 360, 162, "Skeleton Statue", "Right"),
+    FrameEntry::new(105, 396, 162, "Reaper Statue", "Right"),
+    FrameEntry::new(105, 432, 162, "Woman Statue", "Right"),
+    FrameEntry::new(105, 468, 162, "Imp Statue", "Right"),
+    FrameEntry::new(105, 504, 162, "Gargoyle Statue", "Right"),
+    FrameEntry::new(105, 540, 162, "Gloom Statue", "Right"),
+    FrameEntry::new(105, 576, 162, "Hornet Statue", "Right"),
+    FrameEntry::new(105, 612, 162, "Bomb Statue", "Right"),
+    FrameEntry::new(105, 648, 162, "Crab Statue", "Right"),
+    FrameEntry::new(105, 684, 162, "Hammer Statue", "Right"),
+    FrameEntry::new(105, 720, 162, "Potion Statue", "Right"),
+    FrameEntry::new(105, 756, 162, "Spear Statue", "Right"),
+    FrameEntry::new(105, 792, 162, "Cross Statue", "Right"),
+    FrameEntry::new(105, 828, 162, "Jellyfish Statue", "Right"),
+    FrameEntry::new(105, 864, 162, "Bow Statue", "Right"),
+    FrameEntry::new(105, 900, 162, "Boomerang Statue", "Right"),
+    FrameEntry::new(105, 936, 162, "Boot Statue", "Right"),
+    FrameEntry::new(105, 972, 162, "Chest Statue", "Right"),
+    FrameEntry::new(105, 1008, 162, "Bird Statue", "Right"),
+    FrameEntry::new(105, 1044, 162, "Axe Statue", "Right"),
+    FrameEntry::new(105, 1080, 162, "Corrupt Statue", "Right"),
+    FrameEntry::new(105, 1116, 162, "Tree Statue", "Right"),
+    FrameEntry::new(105, 1152, 162, "Anvil Statue", "Right"),
+    FrameEntry::new(105, 1188, 162, "Pickaxe Statue", "Right"),
+    FrameEntry::new(105, 1224, 162, "Mushroom Statue", "Right"),
+    FrameEntry::new(105, 1260, 162, "Eyeball Statue", "Right"),
+    FrameEntry::new(105, 1296, 162, "Pillar Statue", "Right"),
+    FrameEntry::new(105, 1332, 162, "Heart Statue", "Right"),
+    FrameEntry::new(105, 1368, 162, "Pot Statue", "Right"),
+    FrameEntry::new(105, 1404, 162, "Sunflower Statue", "Right"),
+    FrameEntry::new(105, 1440, 162, "King Statue", "Right"),
+    FrameEntry::new(105, 1476, 162, "Queen Statue", "Right"),
+    FrameEntry::new(105, 1512, 162, "Piranha Statue", "Right"),
+    FrameEntry::new(105, 1548, 162, "Lihzahrd Statue", "Right"),
+    FrameEntry::new(105, 1584, 162, "Lihzahrd Watcher Statue", "Right"),
+    FrameEntry::new(105, 1620, 162, "Lihzahrd Guardian Statue", "Right"),
+    FrameEntry::new(105, 1656, 162, "Blue Dungeon Vase", "Right"),
+    FrameEntry::new(105, 1692, 162, "Green Dungeon Vase", "Right"),
+    FrameEntry::new(105, 1728, 162, "Pink Dungeon Vase", "Right"),
+    FrameEntry::new(105, 1764, 162, "Obsidian Vase", "Right"),
+    FrameEntry::new(105, 1800, 162, "Shark Statue", "Right"),
+    FrameEntry::new(105, 1836, 162, "Squirrel Statue", "Right"),
+    FrameEntry::new(105, 1872, 162, "Butterfly Statue", "Right"),
+    FrameEntry::new(105, 1908, 162, "Worm Statue", "Right"),
+    FrameEntry::new(105, 1944, 162, "Firefly Statue", "Right"),
+    FrameEntry::new(105, 0, 216, "Scorpion Statue", "Right"),
+    FrameEntry::new(105, 36, 216, "Snail Statue", "Right"),
+    FrameEntry::new(105, 72, 216, "Grasshopper Statue", "Right"),
+    FrameEntry::new(105, 108, 216, "Mouse Statue", "Right"),
+    FrameEntry::new(105, 144, 216, "Duck Statue", "Right"),
+    FrameEntry::new(105, 180, 216, "Penguin Statue", "Right"),
+    FrameEntry::new(105, 216, 216, "Frog Statue", "Right"),
+    FrameEntry::new(105, 252, 216, "Buggy Statue", "Right"),
+    FrameEntry::new(105, 288, 216, "Wall Creeper Statue", "Right"),
+    FrameEntry::new(105, 324, 216, "Unicorn Statue", "Right"),
+    FrameEntry::new(105, 360, 216, "Drippler Statue", "Right"),
+    FrameEntry::new(105, 396, 216, "Wraith Statue", "Right"),
+    FrameEntry::new(105, 432, 216, "Bone Skeleton Statue", "Right"),
+    FrameEntry::new(105, 468, 216, "Undead Viking Statue", "Right"),
+    FrameEntry::new(105, 504, 216, "Medusa Statue", "Right"),
+    FrameEntry::new(105, 540, 216, "Harpy Statue", "Right"),
+    FrameEntry::new(105, 576, 216, "Pigron Statue", "Right"),
+    FrameEntry::new(105, 612, 216, "Hoplite Statue", "Right"),
+    FrameEntry::new(105, 648, 216, "Granite Golem Statue", "Right"),
+    FrameEntry::new(105, 684, 216, "Armed Zombie Statue", "Right"),
+    FrameEntry::new(105, 720, 216, "Blood Zombie Statue", "Right"),
+    FrameEntry::new(105, 756, 216, "Owl Statue", "Right"),
+    FrameEntry::new(105, 792, 216, "Seagull Statue", "Right"),
+    FrameEntry::new(105, 828, 216, "Dragonfly Statue", "Right"),
+    FrameEntry::new(105, 864, 216, "Turtle Statue", "Right"),
+    FrameEntry::new(105, 900, 216, "Macaw Statue", "Right"),
+    FrameEntry::new(105, 936, 216, "Toucan Statue", "Right"),
+    FrameEntry::new(105, 972, 216, "Cockatiel Statue", "Right"),
+    FrameEntry::new(110, 0, 0, "Grass", "Single Blade"),
+    FrameEntry::new(110, 18, 0, "Grass", "Double Blade"),
+    FrameEntry::new(110, 36, 0, "Grass", "Single Blade"),
+    FrameEntry::new(110, 54, 0, "Grass", "Three Small"),
+    FrameEntry::new(110, 72, 0, "Grass", "Gold Flower"),
+    FrameEntry::new(110, 90, 0, "Grass", "Three Medium"),
+    FrameEntry::new(110, 108, 0, "Flowers", "Purple, Immature"),
+    FrameEntry::new(110, 126, 0, "Flowers", "Pink, Immature"),
+    FrameEntry::new(110, 144, 0, "Mushroom", ""),
+    FrameEntry::new(110, 162, 0, "Small Y", ""),
+    FrameEntry::new(113, 0, 0, "Grass", "G"),
+    FrameEntry::new(113, 18, 0, "Grass", "C"),
+    FrameEntry::new(113, 36, 0, "Grass", "?"),
+    FrameEntry::new(113, 54, 0, "Grass", "u"),
+    FrameEntry::new(113, 72, 0, "Grass", "w"),
+    FrameEntry::new(113, 90, 0, "Grass", "W"),
+    FrameEntry::new(113, 108, 0, "Rose", "A"),
+    FrameEntry::new(113, 126, 0, "Rose", "B"),
+    FrameEntry::new(126, 0, 0, "Disco Ball", "On"),
+    FrameEntry::new(126, 36, 0, "Disco Ball", "Off"),
+    FrameEntry::new(128, 0, 0, "Mannequin", ""),
+    FrameEntry::new(128, 36, 0, "Mannequin", ""),
+    FrameEntry::new(129, 0, 0, "Crystal Shard", "Large Blue"),
+    FrameEntry::new(129, 0, 18, "Crystal Shard", "Large Blue"),
+    FrameEntry::new(129, 0, 36, "Crystal Shard", "Large Blue"),
+    FrameEntry::new(129, 0, 54, "Crystal Shard", "Large Blue"),
+    FrameEntry::new(129, 108, 0, "Crystal Shard", "Medium Blue"),
+    FrameEntry::new(129, 108, 18, "Crystal Shard", "Medium Blue"),
+    FrameEntry::new(129, 108, 36, "Crystal Shard", "Medium Blue"),
+    FrameEntry::new(129, 108, 54, "Crystal Shard", "Medium Blue"),
+    FrameEntry::new(129, 54, 0, "Crystal Shard", "Small Blue"),
+    FrameEntry::new(129, 54, 18, "Crystal Shard", "Small Blue"),
+    FrameEntry::new(129, 54, 36, "Crystal Shard", "Small Blue"),
+    FrameEntry::new(129, 54, 54, "Crystal Shard", "Small Blue"),
+    FrameEntry::new(129, 18, 0, "Crystal Shard", "Large Pink"),
+    FrameEntry::new(129, 18, 18, "Crystal Shard", "Large Pink"),
+    FrameEntry::new(129, 18, 36, "Crystal Shard", "Large Pink"),
+    FrameEntry::new(129, 18, 54, "Crystal Shard", "Large Pink"),
+    FrameEntry::new(129, 126, 0, "Crystal Shard", "Medium Pink"),
+    FrameEntry::new(129, 126, 18, "Crystal Shard", "Medium Pink"),
+    FrameEntry::new(129, 126, 36, "Crystal Shard", "Medium Pink"),
+    FrameEntry::new(129, 126, 54, "Crystal Shard", "Medium Pink"),
+    FrameEntry::new(129, 72, 0, "Crystal Shard", "Small Pink"),
+    FrameEntry::new(129, 72, 18, "Crystal Shard", "Small Pink"),
+    FrameEntry::new(129, 72, 36, "Crystal Shard", "Small Pink"),
+    FrameEntry::new(129, 72, 54, "Crystal Shard", "Small Pink"),
+    FrameEntry::new(129, 36, 0, "Crystal Shard", "Large Purple"),
+    FrameEntry::new(129, 36, 18, "Crystal Shard", "Large Purple"),
+    FrameEntry::new(129, 36, 36, "Crystal Shard", "Large Purple"),
+    FrameEntry::new(129, 36, 54, "Crystal Shard", "Large Purple"),
+    FrameEntry::new(129, 144, 0, "Crystal Shard", "Medium Purple"),
+    FrameEntry::new(129, 144, 18, "Crystal Shard", "Medium Purple"),
+    FrameEntry::new(129, 144, 36, "Crystal Shard", "Medium Purple"),
+    FrameEntry::new(129, 144, 54, "Crystal Shard", "Medium Purple"),
+    FrameEntry::new(129, 90, 0, "Crystal Shard", "Small Purple"),
+    FrameEntry::new(129, 90, 18, "Crystal Shard", "Small Purple"),
+    FrameEntry::new(129, 90, 36, "Crystal Shard", "Small Purple"),
+    FrameEntry::new(129, 90, 54, "Crystal Shard", "Small Purple"),
+    FrameEntry::new(132, 0, 0, "Lever", "Right"),
+    FrameEntry::new(132, 36, 0, "Lever", "Left"),
+    FrameEntry::new(132, 72, 0, "Lever", "Up"),
+    FrameEntry::new(132, 108, 0, "Lever", "Down"),
+    FrameEntry::new(133, 0, 0, "Adamantite Forge", ""),
+    FrameEntry::new(133, 54, 0, "Titanium Forge", ""),
+    FrameEntry::new(134, 0, 0, "Mythril Anvil", ""),
+    FrameEntry::new(134, 36, 0, "Orichalcum Anvil", ""),
+    FrameEntry::new(135, 0, 0, "Pressure Plate", "Red"),
+    FrameEntry::new(135, 0, 18, "Pressure Plate", "Green"),
+    FrameEntry::new(135, 0, 36, "Pressure Plate", "Gray"),
+    FrameEntry::new(135, 0, 54, "Pressure Plate", "Brown"),
+    FrameEntry::new(135, 0, 72, "Pressure Plate", "Blue"),
+    FrameEntry::new(135, 0, 90, "Pressure Plate", "Yellow"),
+    FrameEntry::new(135, 0, 108, "Pressure Plate", "Lihzahrd"),
+    FrameEntry::new(136, 0, 0, "Switch", "Setting One"),
+    FrameEntry::new(136, 18, 18, "Switch", "Setting One"),
+    FrameEntry::new(136, 36, 0, "Switch", "Setting One"),
+    FrameEntry::new(136, 54, 0, "Switch", "Setting One"),
+    FrameEntry::new(136, 0, 18, "Switch", "Setting Two"),
+    FrameEntry::new(136, 18, 0, "Switch", "Setting Two"),
+    FrameEntry::new(136, 36, 18, "Switch", "Setting Two"),
+    FrameEntry::new(136, 54, 18, "Switch", "Setting Two"),
+    FrameEntry::new(137, 0, 0, "Dart Trap", ""),
+    FrameEntry::new(137, 18, 0, "Dart Trap", "Right"),
+    FrameEntry::new(137, 0, 18, "Super Dart Trap", ""),
+    FrameEntry::new(137, 18, 18, "Super Dart Trap", "Right"),
+    FrameEntry::new(137, 0, 36, "Flame  Trap", ""),
+    FrameEntry::new(137, 18, 36, "Flame  Trap", "Right"),
+    FrameEntry::new(137, 0, 54, "Spiky Ball Trap", ""),
+    FrameEntry::new(137, 0, 72, "Spear Trap", ""),
+    FrameEntry::new(139, 0, 0, "Music Box (Overworld Day)", "Off"),
+    FrameEntry::new(139, 36, 0, "Music Box (Overworld Day)", "On"),
+    FrameEntry::new(139, 0, 36, "Music Box (Eerie)", "Off"),
+    FrameEntry::new(139, 36, 36, "Music Box (Eerie)", "On"),
+    FrameEntry::new(139, 0, 72, "Music Box (Night)", "Off"),
+    FrameEntry::new(139, 36, 72, "Music Box (Night)", "On"),
+    FrameEntry::new(139, 0, 108, "Music Box (Title)", "Off"),
+    FrameEntry::new(139, 36, 108, "Music Box (Title)", "On"),
+    FrameEntry::new(139, 0, 144, "Music Box (Underground)", "Off"),
+    FrameEntry::new(139, 36, 144, "Music Box (Underground)", "On"),
+    FrameEntry::new(139, 0, 180, "Music Box (Boss 1)", "Off"),
+    FrameEntry::new(139, 36, 180, "Music Box (Boss 1)", "On"),
+    FrameEntry::new(139, 0, 216, "Music Box (Jungle)", "Off"),
+    FrameEntry::new(139, 36, 216, "Music Box (Jungle)", "On"),
+    FrameEntry::new(139, 0, 252, "Music Box (Corruption)", "Off"),
+    FrameEntry::new(139, 36, 252, "Music Box (Corruption)", "On"),
+    FrameEntry::new(139, 0, 288, "Music Box (Underground Corruption)", "Off"),
+    FrameEntry::new(139, 36, 288, "Music Box (Underground Corruption)", "On"),
+    FrameEntry::new(139, 0, 324, "Music Box (The Hallow)", "Off"),
+    FrameEntry::new(139, 36, 324, "Music Box (The Hallow)", "On"),
+    FrameEntry::new(139, 0, 360, "Music Box (Boss 2)", "Off"),
+    FrameEntry::new(139, 36, 360, "Music Box (Boss 2)", "On"),
+    FrameEntry::new(139, 0, 396, "Music Box (Underground Hallow)", "Off"),
+    FrameEntry::new(139, 36, 396, "Music Box (Underground Hallow)", "On"),
+    FrameEntry::new(139, 0, 432, "Music Box (Boss 3)", "Off"),
+    FrameEntry::new(139, 36, 432, "Music Box (Boss 3)", "On"),
+    FrameEntry::new(139, 0, 468, "Music Box (Snow)", "Off"),
+    FrameEntry::new(139, 36, 468, "Music Box (Snow)", "On"),
+    FrameEntry::new(139, 0, 504, "Music Box (Space)", "Off"),
+    FrameEntry::new(139, 36, 504, "Music Box (Space)", "On"),
+    FrameEntry::new(139, 0, 540, "Music Box (Crimson)", "Off"),
+    FrameEntry::new(139, 36, 540, "Music Box (Crimson)", "On"),
+    FrameEntry::new(139, 0, 576, "Music Box (Boss 4)", "Off"),
+    FrameEntry::new(139, 36, 576, "Music Box (Boss 4)", "On"),
+    FrameEntry::new(139, 0, 612, "Music Box (Alt Overworld Day)", "Off"),
+    FrameEntry::new(139, 36, 612, "Music Box (Alt Overworld Day)", "On"),
+    FrameEntry::new(139, 0, 648, "Music Box (Rain)", "Off"),
+    FrameEntry::new(139, 36, 648, "Music Box (Rain)", "On"),
+    FrameEntry::new(139, 0, 684, "Music Box (Ice)", "Off"),
+    FrameEntry::new(139, 36, 684, "Music Box (Ice)", "On"),
+    FrameEntry::new(139, 0, 720, "Music Box (Desert)", "Off"),
+    FrameEntry::new(139, 36, 720, "Music Box (Desert)", "On"),
+    FrameEntry::new(139, 0, 756, "Music Box (Ocean)", "Off"),
+    FrameEntry::new(139, 36, 756, "Music Box (Ocean)", "On"),
+    FrameEntry::new(139, 0, 792, "Music Box (Dungeon)", "Off"),
+    FrameEntry::new(139, 36, 792, "Music Box (Dungeon)", "On"),
+    FrameEntry::new(139, 0, 828, "Music Box (Plantera)", "Off"),
+    FrameEntry::new(139, 36, 828, "Music Box (Plantera)", "On"),
+    FrameEntry::new(139, 0, 864, "Music Box (Boss 5)", "Off"),
+    FrameEntry::new(139, 36, 864, "Music Box (Boss 5)", "On"),
+    FrameEntry::new(139, 0, 900, "Music Box (Temple)", "Off"),
+    FrameEntry::new(139, 36, 900, "Music Box (Temple)", "On"),
+    FrameEntry::new(139, 0, 936, "Music Box (Eclipse)", "Off"),
+    FrameEntry::new(139, 36, 936, "Music Box (Eclipse)", "On"),
+    FrameEntry::new(139, 0, 972, "Music Box (Mushrooms)", "Off"),
+    FrameEntry::new(139, 36, 972, "Music Box (Mushrooms)", "On"),
+    FrameEntry::new(139, 0, 1008, "Music Box (Pumpkin Moon)", "Off"),
+    FrameEntry::new(139, 36, 1008, "Music Box (Pumpkin Moon)", "On"),
+    FrameEntry::new(139, 0, 1044, "Music Box (Alt Underground)", "Off"),
+    FrameEntry::new(139, 36, 1044, "Music Box (Alt Underground)", "On"),
+    FrameEntry::new(139, 0, 1080, "Music Box (Frost Moon)", "Off"),
+    FrameEntry::new(139, 36, 1080, "Music Box (Frost Moon)", "On"),
+    FrameEntry::new(139, 0, 1116, "Music Box (Underground Crimson)", "Off"),
+    FrameEntry::new(139, 36, 1116, "Music Box (Underground Crimson)", "On"),
+    FrameEntry::new(139, 0, 1152, "Music Box (Lunar Boss)", "Off"),
+    FrameEntry::new(139, 36, 1152, "Music Box (Lunar Boss)", "On"),
+    FrameEntry::new(139, 0, 1188, "Music Box (Martian Madness)", "Off"),
+    FrameEntry::new(139, 36, 1188, "Music Box (Martian Madness)", "On"),
+    FrameEntry::new(139, 0, 1224, "Music Box (Pirate Invasion)", "Off"),
+    FrameEntry::new(139, 36, 1224, "Music Box (Pirate Invasion)", "On"),
+    FrameEntry::new(139, 0, 1260, "Music Box (Hell)", "Off"),
+    FrameEntry::new(139, 36, 1260, "Music Box (Hell)", "On"),
+    FrameEntry::new(139, 0, 1296, "Music Box (The Towers)", "Off"),
+    FrameEntry::new(139, 36, 1296, "Music Box (The Towers)", "On"),
+    FrameEntry::new(139, 0, 1332, "Music Box (Goblin Invasion)", "Off"),
+    FrameEntry::new(139, 36, 1332, "Music Box (Goblin Invasion)", "On"),
+    FrameEntry::new(141, 0, 0, "Explosives", "Single"),
+    FrameEntry::new(141, 0, 18, "Explosives", "Multi"),
+    FrameEntry::new(144, 0, 0, "1 Second Timer", "Inactive 1 Second"),
+    FrameEntry::new(144, 0, 18, "1 Second Timer", "Active 1 Second"),
+    FrameEntry::new(144, 18, 0, "1 Second Timer", "Inactive 3 Second"),
+    FrameEntry::new(144, 18, 18, "1 Second Timer", "Active 3 Second"),
+    FrameEntry::new(144, 36, 0, "1 Second Timer", "Inactive 5 Second"),
+    FrameEntry::new(144, 36, 18, "1 Second Timer", "Active 5 Second"),
+    FrameEntry::new(149, 0, 0, "Blue Light", "On"),
+    FrameEntry::new(149, 0, 18, "Blue Light", "On"),
+    FrameEntry::new(149, 0, 36, "Blue Light", "On"),
+    FrameEntry::new(149, 0, 54, "Blue Light", "On"),
+    FrameEntry::new(149, 54, 0, "Blue Light", "Off"),
+    FrameEntry::new(149, 54, 18, "Blue Light", "Off"),
+    FrameEntry::new(149, 54, 36, "Blue Light", "Off"),
+    FrameEntry::new(149, 54, 54, "Blue Light", "Off"),
+    FrameEntry::new(149, 18, 0, "Red Light", "On"),
+    FrameEntry::new(149, 18, 18, "Red Light", "On"),
+    FrameEntry::new(149, 18, 36, "Red Light", "On"),
+    FrameEntry::new(149, 18, 54, "Red Light", "On"),
+    FrameEntry::new(149, 72, 0, "Red Light", "Off"),
+    FrameEntry::new(149, 72, 18, "Red Light", "Off"),
+    FrameEntry::new(149, 72, 36, "Red Light", "Off"),
+    FrameEntry::new(149, 72, 54, "Red Light", "Off"),
+    FrameEntry::new(149, 36, 0, "Green Light", "On"),
+    FrameEntry::new(149, 36, 18, "Green Light", "On"),
+    FrameEntry::new(149, 36, 36, "Green Light", "On"),
+    FrameEntry::new(149, 36, 54, "Green Light", "On"),
+    FrameEntry::new(149, 90, 0, "Green Light", "Off"),
+    FrameEntry::new(149, 90, 18, "Green Light", "Off"),
+    FrameEntry::new(149, 90, 36, "Green Light", "Off"),
+    FrameEntry::new(149, 90, 54, "Green Light", "Off"),
+    FrameEntry::new(165, 0, 0, "Default", "Default"),
+    FrameEntry::new(165, 18, 0, "Large Icicle 1", ""),
+    FrameEntry::new(165, 36, 0, "Large Icicle 2", ""),
+    FrameEntry::new(165, 54, 0, "Large Icicle 3", ""),
+    FrameEntry::new(165, 18, 72, "Small Icicle 1", ""),
+    FrameEntry::new(165, 36, 72, "Small Icicle 2", ""),
+    FrameEntry::new(165, 54, 72, "Small Icicle 3", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite 1", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite 2", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite 3", ""),
+    FrameEntry::new(165, 0, 0, "Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite/Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite/Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Stalactite/Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Honey Stalactite/Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Honey Stalactite/Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Honey Stalactite/Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite 1", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite 2", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite 3", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite/Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite/Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Hallow Stalactite/Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite 1", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite 2", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite 3", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite/Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite/Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Corrupt Stalactite/Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite 1", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite 2", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite 3", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalagmite 3", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite/Stalagmite 1", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite/Stalagmite 2", ""),
+    FrameEntry::new(165, 0, 0, "Crimson Stalactite/Stalagmite 3", ""),
+    FrameEntry::new(170, 0, 0, "Pine Tree Block", "On"),
+    FrameEntry::new(170, 54, 0, "Pine Tree Block", "Off"),
+    FrameEntry::new(172, 0, 0, "Wooden Sink", ""),
+    FrameEntry::new(172, 0, 38, "Ebonwood Sink", ""),
+    FrameEntry::new(172, 0, 76, "Rich Mahogany Sink", ""),
+    FrameEntry::new(172, 0, 114, "Pearlwood Sink", ""),
+    FrameEntry::new(172, 0, 152, "Bone Sink", ""),
+    FrameEntry::new(172, 0, 190, "Flesh Sink", ""),
+    FrameEntry::new(172, 0, 228, "Living Wood Sink", ""),
+    FrameEntry::new(172, 0, 266, "Skyware Sink", ""),
+    FrameEntry::new(172, 0, 304, "Shadewood Sink", ""),
+    FrameEntry::new(172, 0, 342, "Lihzahrd Sink", ""),
+    FrameEntry::new(172, 0, 380, "Blue Dungeon Sink", ""),
+    FrameEntry::new(172, 0, 418, "Green Dungeon Sink", ""),
+    FrameEntry::new(172, 0, 456, "Pink Dungeon Sink", ""),
+    FrameEntry::new(172, 0, 494, "Obsidian Sink", ""),
+    FrameEntry::new(172, 0, 532, "Metal Sink", ""),
+    FrameEntry::new(172, 0, 570, "Glass Sink", ""),
+    FrameEntry::new(172, 0, 608, "Golden Sink", ""),
+    FrameEntry::new(172, 0, 646, "Honey Sink", ""),
+    FrameEntry::new(172, 0, 684, "Steampunk Sink", ""),
+    FrameEntry::new(172, 0, 722, "Pumpkin Sink", ""),
+    FrameEntry::new(172, 0, 760, "Spooky Sink", ""),
+    FrameEntry::new(172, 0, 798, "Frozen Sink", ""),
+    FrameEntry::new(172, 0, 836, "Dynasty Sink", ""),
+    FrameEntry::new(172, 0, 874, "Palm Wood Sink", ""),
+    FrameEntry::new(172, 0, 912, "Mushroom Sink", ""),
+    FrameEntry::new(172, 0, 950, "Boreal Wood Sink", ""),
+    FrameEntry::new(172, 0, 988, "Slime Sink", ""),
+    FrameEntry::new(172, 0, 1026, "Cactus Sink", ""),
+    FrameEntry::new(172, 0, 1064, "Martian Sink", ""),
+    FrameEntry::new(172, 0, 1102, "Meteorite Sink", ""),
+    FrameEntry::new(172, 0, 1140, "Granite Sink", ""),
+    FrameEntry::new(172, 0, 1178, "Marble Sink", ""),
+    FrameEntry::new(172, 0, 1216, "Crystal Sink", ""),
+    FrameEntry::new(173, 0, 0, "Platinum Candelabra", "On"),
+    FrameEntry::new(173, 36, 0, "Platinum Candelabra", "Off"),
+    FrameEntry::new(174, 0, 0, "Platinum Candle", "On"),
+    FrameEntry::new(174, 18, 0, "Platinum Candle", "Off"),
+    FrameEntry::new(178, 0, 0, "Amethyst", "Default"),
+    FrameEntry::new(178, 18, 0, "Topaz", "Default"),
+    FrameEntry::new(178, 36, 0, "Sapphire", "Default"),
+    FrameEntry::new(178, 54, 0, "Emerald", "Default"),
+    FrameEntry::new(178, 72, 0, "Ruby", "Default"),
+    FrameEntry::new(178, 90, 0, "Diamond", "Default"),
+    FrameEntry::new(178, 108, 0, "Amber", "Default"),
+    FrameEntry::new(184, 0, 0, "Default", "Default"),
+    FrameEntry::new(185, 0, 0, "Small Decos", "Small Stone 1"),
+    FrameEntry::new(185, 18, 0, "Small Decos", "Small Stone 2"),
+    FrameEntry::new(185, 36, 0, "Small Decos", "Small Stone 3"),
+    FrameEntry::new(185, 54, 0, "Small Decos", "Small Stone 4"),
+    FrameEntry::new(185, 72, 0, "Small Decos", "Small Stone 5"),
+    FrameEntry::new(185, 90, 0, "Small Decos", "Small Stone 6"),
+    FrameEntry::new(185, 108, 0, "Small Decos", "Small Dirt 1"),
+    FrameEntry::new(185, 126, 0, "Small Decos", "Small Dirt 2"),
+    FrameEntry::new(185, 144, 0, "Small Decos", "Small Dirt 3"),
+    FrameEntry::new(185, 162, 0, "Small Decos", "Small Dirt 4"),
+    FrameEntry::new(185, 180, 0, "Small Decos", "Small Dirt 5"),
+    FrameEntry::new(185, 198, 0, "Small Decos", "Small Dirt 6"),
+    FrameEntry::new(185, 216, 0, "Small Decos", "Skull Right"),
+    FrameEntry::new(185, 234, 0, "Small Decos", "Skull Down"),
+    FrameEntry::new(185, 252, 0, "Small Decos", "Skull Up"),
+    FrameEntry::new(185, 270, 0, "Small Decos", "Skull Crushed"),
+    FrameEntry::new(185, 288, 0, "Small Decos", "Bone 1"),
+    FrameEntry::new(185, 306, 0, "Small Decos", "Bone 2"),
+    FrameEntry::new(185, 324, 0, "Small Decos", "Bone 3"),
+    FrameEntry::new(185, 342, 0, "Small Decos", "Bone 4"),
+    FrameEntry::new(185, 360, 0, "Small Decos", "Gore Skull Up"),
+    FrameEntry::new(185, 378, 0, "Small Decos", "Gore Skull Down"),
+    FrameEntry::new(185, 396, 0, "Small Decos", "Gore Skull left"),
+    FrameEntry::new(185, 414, 0, "Small Decos", "Gore Skull Crushed"),
+    FrameEntry::new(185, 432, 0, "Small Decos", "Gore 1"),
+    FrameEntry::new(185, 450, 0, "Small Decos", "Gore 2"),
+    FrameEntry::new(185, 468, 0, "Small Decos", "Gore 3"),
+    FrameEntry::new(185, 486, 0, "Small Decos", "Gore 4"),
+    FrameEntry::new(185, 504, 0, "Small Decos", "Broken Tool 1"),
+    FrameEntry::new(185, 522, 0, "Small Decos", "Broken Tool 2"),
+    FrameEntry::new(185, 540, 0, "Small Decos", "Broken Tool 3"),
+    FrameEntry::new(185, 558, 0, "Small Decos", "Broken Tool 4"),
+    FrameEntry::new(185, 576, 0, "Small Decos", "Broken Tool 5"),
+    FrameEntry::new(185, 594, 0, "Small Decos", "Broken Tool 6"),
+    FrameEntry::new(185, 612, 0, "Small Decos", "Broken Tool 7"),
+    FrameEntry::new(185, 630, 0, "Small Decos", "Broken Tool 8"),
+    FrameEntry::new(185, 648, 0, "Small Decos", "Snowy 1"),
+    FrameEntry::new(185, 666, 0, "Small Decos", "Snowy 2"),
+    FrameEntry::new(185, 684, 0, "Small Decos", "Snowy 3"),
+    FrameEntry::new(185, 702, 0, "Small Decos", "Snowy 4"),
+    FrameEntry::new(185, 720, 0, "Small Decos", "Snowy 5"),
+    FrameEntry::new(185, 738, 0, "Small Decos", "Snowy 6"),
+    FrameEntry::new(185, 756, 0, "Small Decos", "Icy 1"),
+    FrameEntry::new(185, 774, 0, "Small Decos", "Icy 2"),
+    FrameEntry::new(185, 792, 0, "Small Decos", "Icy 3"),
+    FrameEntry::new(185, 810, 0, "Small Decos", "Icy 4"),
+    FrameEntry::new(185, 828, 0, "Small Decos", "Icy 5"),
+    FrameEntry::new(185, 846, 0, "Small Decos", "Icy 6"),
+    FrameEntry::new(185, 864, 0, "Small Decos", "Small Debris 1"),
+    FrameEntry::new(185, 882, 0, "Small Decos", "Small Debris 2"),
+    FrameEntry::new(185, 900, 0, "Small Decos", "Small Debris 3"),
+    FrameEntry::new(185, 918, 0, "Small Decos", "White 1"),
+    FrameEntry::new(185, 936, 0, "Small Decos", "White 2"),
+    FrameEntry::new(185, 954, 0, "Small Decos", "White 3"),
+    FrameEntry::new(185, 972, 0, "Small Decos", "Sandstone 1"),
+    FrameEntry::new(185, 990, 0, "Small Decos", "Sandstone 2"),
+    FrameEntry::new(185, 1008, 0, "Small Decos", "Sandstone 3"),
+    FrameEntry::new(185, 1026, 0, "Small Decos", "Sandstone 4"),
+    FrameEntry::new(185, 1044, 0, "Small Decos", "Sandstone 5"),
+    FrameEntry::new(185, 1062, 0, "Small Decos", "Sandstone 6"),
+    FrameEntry::new(185, 1080, 0, "Small Decos", "Granite 1"),
+    FrameEntry::new(185, 1098, 0, "Small Decos", "Granite 2"),
+    FrameEntry::new(185, 1116, 0, "Small Decos", "Granite 3"),
+    FrameEntry::new(185, 1134, 0, "Small Decos", "Granite 4"),
+    FrameEntry::new(185, 1152, 0, "Small Decos", "Granite 5"),
+    FrameEntry::new(185, 1170, 0, "Small Decos", "Granite 6"),
+    FrameEntry::new(185, 1188, 0, "Small Decos", "Marble 1"),
+    FrameEntry::new(185, 1206, 0, "Small Decos", "Marble 2"),
+    FrameEntry::new(185, 1224, 0, "Small Decos", "Marble 3"),
+    FrameEntry::new(185, 1242, 0, "Small Decos", "Marble 4"),
+    FrameEntry::new(185, 1260, 0, "Small Decos", "Marble 5"),
+    FrameEntry::new(185, 1278, 0, "Small Decos", "Marble 6"),
+    FrameEntry::new(185, 0, 18, "Small Decos", "Stone 1"),
+    FrameEntry::new(185, 18, 18, "Small Decos", "Stone 2"),
+    FrameEntry::new(185, 36, 18, "Small Decos", "Stone 3"),
+    FrameEntry::new(185, 54, 18, "Small Decos", "Stone 4"),
+    FrameEntry::new(185, 72, 18, "Small Decos", "Stone 5"),
+    FrameEntry::new(185, 90, 18, "Small Decos", "Stone 6"),
+    FrameEntry::new(185, 108, 18, "Small Decos", "Stone 7"),
+    FrameEntry::new(185, 126, 18, "Small Decos", "Stone 8"),
+    FrameEntry::new(185, 144, 18, "Small Decos", "Stone 9"),
+    FrameEntry::new(185, 162, 18, "Small Decos", "Stone 10"),
+    FrameEntry::new(185, 180, 18, "Small Decos", "Stone 11"),
+    FrameEntry::new(185, 198, 18, "Small Decos", "Stone 12"),
+    FrameEntry::new(185, 216, 18, "Small Decos", "Skull Up Reverse"),
+    FrameEntry::new(185, 234, 18, "Small Decos", "Bone 5"),
+    FrameEntry::new(185, 252, 18, "Small Decos", "Bone 6"),
+    FrameEntry::new(185, 270, 18, "Small Decos", "Bone 7"),
+    FrameEntry::new(185, 288, 18, "Small Decos", "Bone 8"),
+    FrameEntry::new(185, 306, 18, "Small Decos", "Bone 9"),
+    FrameEntry::new(185, 324, 18, "Small Decos", "Bone 10"),
+    FrameEntry::new(185, 342, 18, "Small Decos", "Bone 11"),
+    FrameEntry::new(185, 360, 18, "Small Decos", "Bone 12"),
+    FrameEntry::new(185, 378, 18, "Small Decos", "Bone 13"),
+    FrameEntry::new(185, 396, 18, "Small Decos", "Gore 5"),
+    FrameEntry::new(185, 414, 18, "Small Decos", "Gore Skull Up Reverse"),
+    FrameEntry::new(185, 432, 18, "Small Decos", "Gore 6"),
+    FrameEntry::new(185, 450, 18, "Small Decos", "Gore 7"),
+    FrameEntry::new(185, 468, 18, "Small Decos", "Gore 8"),
+    FrameEntry::new(185, 486, 18, "Small Decos", "Gore 9"),
+    FrameEntry::new(185, 504, 18, "Small Decos", "Gore 10"),
+    FrameEntry::new(185, 522, 18, "Small Decos", "Gore 11"),
+    FrameEntry::new(185, 540, 18, "Small Decos", "Gore 12"),
+    FrameEntry::new(185, 558, 18, "Small Decos", "Gore 13"),
+    FrameEntry::new(185, 576, 18, "Small Decos", "Copper Stash Left"),
+    FrameEntry::new(185, 594, 18, "Small Decos", "Copper Stash Right"),
+    FrameEntry::new(185, 612, 18, "Small Decos", "Sliver Stash Left"),
+    FrameEntry::new(185, 630, 18, "Small Decos", "Silver Stash Right"),
+    FrameEntry::new(185, 648, 18, "Small Decos", "Gold Stash Left"),
+    FrameEntry::new(185, 666, 18, "Small Decos", "Gold Stash Right"),
+    FrameEntry::new(185, 684, 18, "Small Decos", "Amethyst Stash Left"),
+    FrameEntry::new(185, 702, 18, "Small Decos", "Amethyst Stash Right"),
+    FrameEntry::new(185, 720, 18, "Small Decos", "Topaz Stash Left"),
+    FrameEntry::new(185, 738, 18, "Small Decos", "Topaz Stash Right"),
+    FrameEntry::new(185, 756, 18, "Small Decos", "Sapphire Stash Left"),
+    FrameEntry::new(185, 774, 18, "Small Decos", "Sapphire Stash Right"),
+    FrameEntry::new(185, 792, 18, "Small Decos", "Emerald Stash Left"),
+    FrameEntry::new(185, 810, 18, "Small Decos", "Emerald Stash Right"),
+    FrameEntry::new(185, 828, 18, "Small Decos", "Ruby Stash Left"),
+    FrameEntry::new(185, 846, 18, "Small Decos", "Ruby Stash Right"),
+    FrameEntry::new(185, 864, 18, "Small Decos", "Diamond Stash Left"),
+    FrameEntry::new(185, 882, 18, "Small Decos", "Diamond Stash Right"),
+    FrameEntry::new(185, 900, 18, "Small Decos", "Ice Junk 1"),
+    FrameEntry::new(185, 918, 18, "Small Decos", "Ice Junk 2"),
+    FrameEntry::new(185, 936, 18, "Small Decos", "Ice Junk 3"),
+    FrameEntry::new(185, 954, 18, "Small Decos", "Ice Junk 4"),
+    FrameEntry::new(185, 972, 18, "Small Decos", "Ice Junk 5"),
+    FrameEntry::new(185, 990, 18, "Small Decos", "Ice Junk 6"),
+    FrameEntry::new(185, 1008, 18, "Small Decos", "Ice Junk 7"),
+    FrameEntry::new(185, 1026, 18, "Small Decos", "Ice Junk 8"),
+    FrameEntry::new(185, 1044, 18, "Small Decos", "Ice Junk 9"),
+    FrameEntry::new(185, 1062, 18, "Small Decos", "Ice Junk 10"),
+    FrameEntry::new(185, 1080, 18, "Small Decos", "Ice Junk 11"),
+    FrameEntry::new(185, 1098, 18, "Small Decos", "Ice Junk 12"),
+    FrameEntry::new(185, 1116, 18, "Small Decos", "Debris 1 Left"),
+    FrameEntry::new(185, 1134, 18, "Small Decos", "Debris 1 Right"),
+    FrameEntry::new(185, 1152, 18, "Small Decos", "Debris 2 Left"),
+    FrameEntry::new(185, 1170, 18, "Small Decos", "Debris 2 Right"),
+    FrameEntry::new(185, 1188, 18, "Small Decos", "Debris 3 Left"),
+    FrameEntry::new(185, 1206, 18, "Small Decos", "Debris 3 Right"),
+    FrameEntry::new(185, 1224, 18, "Small Decos", "Dark 1 Left"),
+    FrameEntry::new(185, 1242, 18, "Small Decos", "Dark 1 Right"),
+    FrameEntry::new(185, 1260, 18, "Small Decos", "Dark 2 Left"),
+    FrameEntry::new(185, 1278, 18, "Small Decos", "Dark 2 Right"),
+    FrameEntry::new(185, 1296, 18, "Small Decos", "Dark 3 Left"),
+    FrameEntry::new(185, 1314, 18, "Small Decos", "Dark 3 Right"),
+    FrameEntry::new(185, 1332, 18, "Small Decos", "Dark 4 Left"),
+    FrameEntry::new(185, 1350, 18, "Small Decos", "Dark 4 Right"),
+    FrameEntry::new(185, 1368, 18, "Small Decos", "Stony Moss 1"),
+    FrameEntry::new(185, 1386, 18, "Small Decos", "Stony Moss 2"),
+    FrameEntry::new(185, 1404, 18, "Small Decos", "Stony Moss 3"),
+    FrameEntry::new(185, 1422, 18, "Small Decos", "Stony Moss 4"),
+    FrameEntry::new(185, 1440, 18, "Small Decos", "Stony Moss 5"),
+    FrameEntry::new(185, 1458, 18, "Small Decos", "Stony Moss 6"),
+    FrameEntry::new(185, 1476, 18, "Small Decos", "Sandstone 1"),
+    FrameEntry::new(185, 1512, 18, "Small Decos", "Sandstone 2"),
+    FrameEntry::new(185, 1548, 18, "Small Decos", "Sandstone 3"),
+    FrameEntry::new(185, 1584, 18, "Small Decos", "Sandstone 4"),
+    FrameEntry::new(185, 1620, 18, "Small Decos", "Sandstone 5"),
+    FrameEntry::new(185, 1656, 18, "Small Decos", "Sandstone 6"),
+    FrameEntry::new(185, 1692, 18, "Small Decos", "Granite 1"),
+    FrameEntry::new(185, 1728, 18, "Small Decos", "Granite 2"),
+    FrameEntry::new(185, 1764, 18, "Small Decos", "Granite 3"),
+    FrameEntry::new(185, 1800, 18, "Small Decos", "Granite 4"),
+    FrameEntry::new(185, 1836, 18, "Small Decos", "Granite 5"),
+    FrameEntry::new(185, 1872, 18, "Small Decos", "Granite 6"),
+    FrameEntry::new(185, 0, 54, "Small Decos", "Marble 1"),
+    FrameEntry::new(185, 36, 54, "Small Decos", "Marble 2"),
+    FrameEntry::new(185, 72, 54, "Small Decos", "Marble 3"),
+    FrameEntry::new(185, 108, 54, "Small Decos", "Marble 4"),
+    FrameEntry::new(185, 144, 54, "Small Decos", "Marble 5"),
+    FrameEntry::new(185, 180, 54, "Small Decos", "Marble 6"),
+    FrameEntry::new(186, 0, 0, "Bones 1", ""),
+    FrameEntry::new(186, 54, 0, "Bones 2", ""),
+    FrameEntry::new(186, 108, 0, "Bones 3", ""),
+    FrameEntry::new(186, 162, 0, "Bones 4", ""),
+    FrameEntry::new(186, 216, 0, "Bones 5", ""),
+    FrameEntry::new(186, 270, 0, "Bones 6", ""),
+    FrameEntry::new(186, 324, 0, "Sword Bones", ""),
+    FrameEntry::new(186, 378, 0, "Rocks 1", ""),
+    FrameEntry::new(186, 432, 0, "Rocks 2", ""),
+    FrameEntry::new(186, 486, 0, "Rocks 3", ""),
+    FrameEntry::new(186, 540, 0, "Rocks 4", ""),
+    FrameEntry::new(186, 594, 0, "Rocks 5", ""),
+    FrameEntry::new(186, 648, 0, "Rocks 6", ""),
+    FrameEntry::new(186, 702, 0, "Rocks 7", ""),
+    FrameEntry::new(186, 756, 0, "Rocks Pick", ""),
+    FrameEntry::new(186, 810, 0, "Rocks Sword", ""),
+    FrameEntry::new(186, 864, 0, "Copper Pile 1", ""),
+    FrameEntry::new(186, 918, 0, "Copper Pile 2", ""),
+    FrameEntry::new(186, 972, 0, "Silver Pile 1", ""),
+    FrameEntry::new(186, 1026, 0, "Silver Pile 2", ""),
+    FrameEntry::new(186, 1080, 0, "Gold Pile 1", ""),
+    FrameEntry::new(186, 1134, 0, "Gold Pile 2", ""),
+    FrameEntry::new(186, 1188, 0, "Broken Machine 1", ""),
+    FrameEntry::new(186, 1242, 0, "Broken Machine 2", ""),
+    FrameEntry::new(186, 1296, 0, "Broken Chest", ""),
+    FrameEntry::new(186, 1350, 0, "Broken Candelabra", ""),
+    FrameEntry::new(186, 1404, 0, "Frozen 1", ""),
+    FrameEntry::new(186, 1458, 0, "Frozen 2", ""),
+    FrameEntry::new(186, 1512, 0, "Frozen 3", ""),
+    FrameEntry::new(186, 1566, 0, "Frozen 4", ""),
+    FrameEntry::new(186, 1620, 0, "Frozen 5", ""),
+    FrameEntry::new(186, 1674, 0, "Frozen 6", ""),
+    FrameEntry::new(186, 1728, 0, "Mushroom 1", ""),
+    FrameEntry::new(186, 1782, 0, "Mushroom 2", ""),
+    FrameEntry::new(186, 1836, 0, "Mushroom 3", ""),
+    FrameEntry::new(187, 0, 0, "Jungle Stone 1", ""),
+    FrameEntry::new(187, 54, 0, "Jungle Stone 2", ""),
+    FrameEntry::new(187, 108, 0, "Jungle Stone 3", ""),
+    FrameEntry::new(187, 162, 0, "Jungle Stone 4", ""),
+    FrameEntry::new(187, 216, 0, "Jungle Stone 5", ""),
+    FrameEntry::new(187, 270, 0, "Jungle Stone 6", ""),
+    FrameEntry::new(187, 324, 0, "Corrupt Stone 1", ""),
+    FrameEntry::new(187, 378, 0, "Corrupt Stone 2", ""),
+    FrameEntry::new(187, 432, 0, "Corrupt Stone 3", ""),
+    FrameEntry::new(187, 486, 0, "Webbed Stone 1", ""),
+    FrameEntry::new(187, 540, 0, "Webbed Stone 2", ""),
+    FrameEntry::new(187, 594, 0, "Webbed Stone 3", ""),
+    FrameEntry::new(187, 648, 0, "Cocoon 1", ""),
+    FrameEntry::new(187, 702, 0, "Cocoon 2", ""),
+    FrameEntry::new(187, 756, 0, "Mossy Stone 1", ""),
+    FrameEntry::new(187, 810, 0, "Mossy Stone 2", ""),
+    FrameEntry::new(187, 864, 0, "Mossy Stone 3", ""),
+    FrameEntry::new(187, 918, 0, "Enchanted Sword", ""),
+    FrameEntry::new(187, 972, 0, "Lihzahrd 1", ""),
+    FrameEntry::new(187, 1026, 0, "Lihzahrd 2", ""),
+    FrameEntry::new(187, 1080, 0, "Lihzahrd 3", ""),
+    FrameEntry::new(187, 1134, 0, "Cage 1", ""),
+    FrameEntry::new(187, 1188, 0, "Cage 2", ""),
+    FrameEntry::new(187, 1242, 0, "Minecart", ""),
+    FrameEntry::new(187, 1296, 0, "Cave In", ""),
+    FrameEntry::new(187, 1350, 0, "Dirt Pile", ""),
+    FrameEntry::new(187, 1404, 0, "Abandoned Tent", ""),
+    FrameEntry::new(187, 1458, 0, "Wheelbarrow", ""),
+    FrameEntry::new(187, 1512, 0, "Peg", ""),
+    FrameEntry::new(187, 1566, 0, "Sandstone 1", ""),
+    FrameEntry::new(187, 1620, 0, "Sandstone 2", ""),
+    FrameEntry::new(187, 1674, 0, "Sandstone 3", ""),
+    FrameEntry::new(187, 1728, 0, "Sandstone 4", ""),
+    FrameEntry::new(187, 1782, 0, "Sandstone 5", ""),
+    FrameEntry::new(187, 1836, 0, "Sandstone 6", ""),
+    FrameEntry::new(187, 0, 36, "Granite 1", ""),
+    FrameEntry::new(187, 54, 36, "Granite 2", ""),
+    FrameEntry::new(187, 108, 36, "Granite 3", ""),
+    FrameEntry::new(187, 162, 36, "Granite 4", ""),
+    FrameEntry::new(187, 216, 36, "Granite 5", ""),
+    FrameEntry::new(187, 270, 36, "Granite 6", ""),
+    FrameEntry::new(187, 324, 36, "Marble 1", ""),
+    FrameEntry::new(187, 378, 36, "Marble 2", ""),
+    FrameEntry::new(187, 432, 36, "Marble 3", ""),
+    FrameEntry::new(187, 486, 36, "Marble 4", ""),
+    FrameEntry::new(187, 540, 36, "Marble 5", ""),
+    FrameEntry::new(187, 594, 36, "Marble 6", ""),
+    FrameEntry::new(201, 0, 0, "Crimson Grass", "Three Blades"),
+    FrameEntry::new(201, 18, 0, "Crimson Grass", "Two Blades"),
+    FrameEntry::new(201, 36, 0, "Crimson Grass", "Hooked"),
+    FrameEntry::new(201, 54, 0, "Crimson Grass", "Short"),
+    FrameEntry::new(201, 72, 0, "Crimson Grass", "Thick 1"),
+    FrameEntry::new(201, 90, 0, "Crimson Grass", "Fleshy 1"),
+    FrameEntry::new(201, 108, 0, "Crimson Grass", "Sparse"),
+    FrameEntry::new(201, 126, 0, "Crimson Grass", "Fleshy 2"),
+    FrameEntry::new(201, 144, 0, "Crimson Grass", "Thick 2"),
+    FrameEntry::new(201, 162, 0, "Crimson Grass", "Thick 3"),
+    FrameEntry::new(201, 180, 0, "Crimson Grass", "Fleshy 3"),
+    FrameEntry::new(201, 198, 0, "Crimson Grass", "Thick 4"),
+    FrameEntry::new(201, 216, 0, "Crimson Grass", "Thick 5"),
+    FrameEntry::new(201, 234, 0, "Crimson Grass", "Thick 6"),
+    FrameEntry::new(201, 252, 0, "Crimson Grass", "Thick 7"),
+    FrameEntry::new(207, 0, 0, "Pure Water Fountain", "Off"),
+    FrameEntry::new(207, 0, 72, "Pure Water Fountain", "On"),
+    FrameEntry::new(207, 36, 0, "Desert Water Fountain", "Off"),
+    FrameEntry::new(207, 36, 72, "Desert Water Fountain", "On"),
+    FrameEntry::new(207, 72, 0, "Jungle Water Fountain", "Off"),
+    FrameEntry::new(207, 72, 72, "Jungle Water Fountain", "On"),
+    FrameEntry::new(207, 108, 0, "Icy Water Fountain", "Off"),
+    FrameEntry::new(207, 108, 72, "Icy Water Fountain", "On"),
+    FrameEntry::new(207, 144, 0, "Corrupt Water Fountain", "Off"),
+    FrameEntry::new(207, 144, 72, "Corrupt Water Fountain", "On"),
+    FrameEntry::new(207, 180, 0, "Crimson Water Fountain", "Off"),
+    FrameEntry::new(207, 180, 72, "Crimson Water Fountain", "On"),
+    FrameEntry::new(207, 216, 0, "Hallowed Water Fountain", "Off"),
+    FrameEntry::new(207, 216, 72, "Hallowed Water Fountain", "On"),
+    FrameEntry::new(207, 252, 0, "Blood Water Fountain", "Off"),
+    FrameEntry::new(207, 252, 72, "Blood Water Fountain", "On"),
+    FrameEntry::new(209, 0, 0, "Cannon", "0 deg Elevation Right"),
+    FrameEntry::new(209, 72, 0, "Bunny Cannon", "0 deg Elevation Right"),
+    FrameEntry::new(209, 144, 0, "Confetti Cannon", "0 deg Elevation Right"),
+    FrameEntry::new(209, 0, 54, "Cannon", "22.5 deg Elevation Right"),
+    FrameEntry::new(209, 72, 54, "Bunny Cannon", "22.5 deg Elevation Right"),
+    FrameEntry::new(209, 144, 54, "Confetti Cannon", "22.5 deg Elevation Right"),
+    FrameEntry::new(209, 0, 108, "Cannon", "45 deg Elevation Right"),
+    FrameEntry::new(209, 72, 108, "Bunny Cannon", "45 deg Elevation Right"),
+    FrameEntry::new(209, 144, 108, "Confetti Cannon", "45 deg Elevation Right"),
+    FrameEntry::new(209, 0, 162, "Cannon", "67.5 deg Elevation Right"),
+    FrameEntry::new(209, 72, 162, "Bunny Cannon", "67.5 deg Elevation Right"),
+    FrameEntry::new(209, 144, 162, "Confetti Cannon", "67.5 deg Elevation Right"),
+    FrameEntry::new(209, 0, 216, "Cannon", "90 deg Elevation"),
+    FrameEntry::new(209, 72, 216, "Bunny Cannon", "90 deg Elevation"),
+    FrameEntry::new(209, 144, 216, "Confetti Cannon", "90 deg Elevation"),
+    FrameEntry::new(209, 0, 270, "Cannon", "67.5 deg Elevation Left"),
+    FrameEntry::new(209, 72, 270, "Bunny Cannon", "67.5 deg Elevation Left"),
+    FrameEntry::new(209, 144, 270, "Confetti Cannon", "67.5 deg Elevation Left"),
+    FrameEntry::new(209, 0, 324, "Cannon", "45 deg Elevation Left"),
+    FrameEntry::new(209, 72, 324, "Bunny Cannon", "45 deg Elevation Left"),
+    FrameEntry::new(209, 144, 324, "Confetti Cannon", "45 deg Elevation Left"),
+    FrameEntry::new(209, 0, 378, "Cannon", "22.5 deg Elevation Left"),
+    FrameEntry::new(209, 72, 378, "Bunny Cannon", "22.5 deg Elevation Left"),
+    FrameEntry::new(209, 144, 378, "Confetti Cannon", "22.5 deg Elevation Left"),
+    FrameEntry::new(209, 0, 432, "Cannon", "0 deg Elevation Left"),
+    FrameEntry::new(209, 72, 432, "Bunny Cannon", "0 deg Elevation Left"),
+    FrameEntry::new(209, 144, 432, "Confetti Cannon", "0 deg Elevation Left"),
+    FrameEntry::new(212, 0, 0, "Snowball Launcher", ""),
+    FrameEntry::new(212, 54, 0, "Snowball Launcher", ""),
+    FrameEntry::new(215, 0, 0, "Campfire", "Lit"),
+    FrameEntry::new(215, 0, 288, "Campfire", "Unlit"),
+    FrameEntry::new(215, 54, 0, "Cursed Campfire", "Lit"),
+    FrameEntry::new(215, 54, 288, "Cursed Campfire", "Unlit"),
+    FrameEntry::new(215, 108, 0, "Demon Campfire", "Lit"),
+    FrameEntry::new(215, 108, 288, "Demon Campfire", "Unlit"),
+    FrameEntry::new(215, 162, 0, "Frozen Campfire", "Lit"),
+    FrameEntry::new(215, 162, 288, "Frozen Campfire", "Unlit"),
+    FrameEntry::new(215, 216, 0, "Ichor Campfire", "Lit"),
+    FrameEntry::new(215, 216, 288, "Ichor Campfire", "Unlit"),
+    FrameEntry::new(215, 270, 0, "Rainbow Campfire", "Lit"),
+    FrameEntry::new(215, 270, 288, "Rainbow Campfire", "Unlit"),
+    FrameEntry::new(216, 0, 0, "Rocket", "Red Rocket"),
+    FrameEntry::new(216, 0, 40, "Rocket", "Green Rocket"),
+    FrameEntry::new(216, 0, 80, "Rocket", "Blue Rocket"),
+    FrameEntry::new(216, 0, 120, "Rocket", "Yellow Rocket"),
+    FrameEntry::new(227, 0, 0, "Dye Plant", "Teal Mushroom"),
+    FrameEntry::new(227, 34, 0, "Dye Plant", "Green Mushroom"),
+    FrameEntry::new(227, 68, 0, "Dye Plant", "Sky Blue Flower"),
+    FrameEntry::new(227, 102, 0, "Dye Plant", "Yellow Marigold"),
+    FrameEntry::new(227, 136, 0, "Dye Plant", "Blue Berries"),
+    FrameEntry::new(227, 170, 0, "Dye Plant", "Lime Kelp"),
+    FrameEntry::new(227, 204, 0, "Dye Plant", "Pink Prickly Pear"),
+    FrameEntry::new(227, 238, 0, "Dye Plant", "Orange Bloodroot"),
+    FrameEntry::new(227, 272, 0, "Dye Plant", "Strange Plant 1"),
+    FrameEntry::new(227, 306, 0, "Dye Plant", "Strange Plant 2"),
+    FrameEntry::new(227, 340, 0, "Dye Plant", "Strange Plant 3"),
+    FrameEntry::new(227, 374, 0, "Dye Plant", "Strange Plant 4"),
+    FrameEntry::new(233, 0, 0, "Default", "Default"),
+    FrameEntry::new(236, 0, 0, "Default", "Default"),
+    FrameEntry::new(236, 36, 0, "Life Fruit", "Default"),
+    FrameEntry::new(239, 0, 0, "Metal Bar", "Copper Bar"),
+    FrameEntry::new(239, 18, 0, "Metal Bar", "Tin Bar"),
+    FrameEntry::new(239, 36, 0, "Metal Bar", "Iron Bar"),
+    FrameEntry::new(239, 54, 0, "Metal Bar", "Lead Bar"),
+    FrameEntry::new(239, 72, 0, "Metal Bar", "Silver Bar"),
+    FrameEntry::new(239, 90, 0, "Metal Bar", "Tungsten Bar"),
+    FrameEntry::new(239, 108, 0, "Metal Bar", "Gold Bar"),
+    FrameEntry::new(239, 126, 0, "Metal Bar", "Platinum Bar"),
+    FrameEntry::new(239, 144, 0, "Metal Bar", "Demonite Bar"),
+    FrameEntry::new(239, 162, 0, "Metal Bar", "Meteorite Bar"),
+    FrameEntry::new(239, 180, 0, "Metal Bar", "Hellstone Bar"),
+    FrameEntry::new(239, 198, 0, "Metal Bar", "Cobalt Bar"),
+    FrameEntry::new(239, 216, 0, "Metal Bar", "Palladium Bar"),
+    FrameEntry::new(239, 234, 0, "Metal Bar", "Mythril Bar"),
+    FrameEntry::new(239, 252, 0, "Metal Bar", "Orichalcum Bar"),
+    FrameEntry::new(239, 270, 0, "Metal Bar", "Adamantite Bar"),
+    FrameEntry::new(239, 288, 0, "Metal Bar", "Titanium Bar"),
+    FrameEntry::new(239, 306, 0, "Metal Bar", "Chlorophyte Bar"),
+    FrameEntry::new(239, 324, 0, "Metal Bar", "Hallowed Bar"),
+    FrameEntry::new(239, 342, 0, "Metal Bar", "Crimtane Bar"),
+    FrameEntry::new(239, 360, 0, "Metal Bar", "Shroomite Bar"),
+    FrameEntry::new(239, 378, 0, "Metal Bar", "Spectre Bar"),
+    FrameEntry::new(239, 396, 0, "Metal Bar", "Luminite Bar"),
+    FrameEntry::new(240, 0, 0, "Boss Trophy", "Eye of Cthulhu"),
+    FrameEntry::new(240, 54, 0, "Boss Trophy", "Eater of Worlds"),
+    FrameEntry::new(240, 108, 0, "Boss Trophy", "Brain of Cthulhu"),
+    FrameEntry::new(240, 162, 0, "Boss Trophy", "Skeletron"),
+    FrameEntry::new(240, 216, 0, "Boss Trophy", "Queen Bee"),
+    FrameEntry::new(240, 270, 0, "Boss Trophy", "Wall of Flesh"),
+    FrameEntry::new(240, 324, 0, "Boss Trophy", "Destroyer"),
+    FrameEntry::new(240, 378, 0, "Boss Trophy", "Skeletron Prime"),
+    FrameEntry::new(240, 432, 0, "Boss Trophy", "Retinazer"),
+    FrameEntry::new(240, 486, 0, "Boss Trophy", "Spazmatism"),
+    FrameEntry::new(240, 540, 0, "Boss Trophy", "Plantera"),
+    FrameEntry::new(240, 594, 0, "Boss Trophy", "Golem"),
+    FrameEntry::new(240, 648, 0, "Painting", "Blood Moon Rising"),
+    FrameEntry::new(240, 702, 0, "Painting", "The Hanged Man"),
+    FrameEntry::new(240, 756, 0, "Painting", "Glory of the Fire"),
+    FrameEntry::new(240, 810, 0, "Painting", "Bone Warp"),
+    FrameEntry::new(240, 864, 0, "Hanging", "Wall Skeleton"),
+    FrameEntry::new(240, 918, 0, "Hanging", "Hanging Skeleton"),
+    FrameEntry::new(240, 972, 0, "Painting", "Skellington J Skellingsworth"),
+    FrameEntry::new(240, 1026, 0, "Painting", "The Cursed Man"),
+    FrameEntry::new(240, 1080, 0, "Painting", "Sunflowers"),
+    FrameEntry::new(240, 1134, 0, "Painting", "Terrarian Gothic"),
+    FrameEntry::new(240, 1188, 0, "Painting", "Guide Picasso"),
+    FrameEntry::new(240, 1242, 0, "Painting", "The Guardian's Gaze"),
+    FrameEntry::new(240, 1296, 0, "Painting", "Father of Someone"),
+    FrameEntry::new(240, 1350, 0, "Painting", "Nurse Lisa"),
+    FrameEntry::new(240, 1404, 0, "Painting", "Discover"),
+    FrameEntry::new(240, 1458, 0, "Painting", "Hand Earth"),
+    FrameEntry::new(240, 1512, 0, "Painting", "Old Miner"),
+    FrameEntry::new(240, 1566, 0, "Painting", "Skelehead"),
+    FrameEntry::new(240, 1620, 0, "Painting", "Imp Face"),
+    FrameEntry::new(240, 1674, 0, "Painting", "Ominous Presence"),
+    FrameEntry::new(240, 1728, 0, "Painting", "Shining Moon"),
+    FrameEntry::new(240, 1782, 0, "Painting", "The Merchant"),
+    FrameEntry::new(240, 1836, 0, "Painting", "Crowno Devours His Lunch"),
+    FrameEntry::new(240, 1890, 0, "Painting", "Rare Enchantment"),
+    FrameEntry::new(240, 0, 54, "Boss Trophy", "Mourning Wood Trophy"),
+    FrameEntry::new(240, 54, 54, "Boss Trophy", "Pumpking Trophy"),
+    FrameEntry::new(240, 108, 54, "Boss Trophy", "Ice Queen Trophy"),
+    FrameEntry::new(240, 162, 54, "Boss Trophy", "Santa-NK1 Trophy"),
+    FrameEntry::new(240, 216, 54, "Boss Trophy", "Everscream Trophy"),
+    FrameEntry::new(240, 270, 54, "Rack", "Blacksmith Rack"),
+    FrameEntry::new(240, 324, 54, "Rack", "Carpentry Rack"),
+    FrameEntry::new(240, 378, 54, "Rack", "Helmet Rack"),
+    FrameEntry::new(240, 432, 54, "Rack", "Spear Rack"),
+    FrameEntry::new(240, 486, 54, "Rack", "Sword Rack"),
+    FrameEntry::new(240, 540, 54, "Hanging", "Life Preserver"),
+    FrameEntry::new(240, 594, 54, "Hanging", "Ship's Wheel"),
+    FrameEntry::new(240, 648, 54, "Hanging", "Compass Rose"),
+    FrameEntry::new(240, 702, 54, "Hanging", "Wall Anchor"),
+    FrameEntry::new(240, 756, 54, "Fishing Trophy", "Goldfish Trophy"),
+    FrameEntry::new(240, 810, 54, "Fishing Trophy", "Bunnyfish Trophy"),
+    FrameEntry::new(240, 864, 54, "Fishing Trophy", "Swordfish Trophy"),
+    FrameEntry::new(240, 918, 54, "Fishing Trophy", "Sharkteeth Trophy"),
+    FrameEntry::new(240, 972, 54, "Boss Trophy", "King Slime Trophy"),
+    FrameEntry::new(240, 1026, 54, "Boss Trophy", "Duke Fishron Trophy"),
+    FrameEntry::new(240, 1080, 54, "Boss Trophy", "Ancient Cultist Trophy"),
+    FrameEntry::new(240, 1134, 54, "Boss Trophy", "Martian Saucer Trophy"),
+    FrameEntry::new(240, 1188, 54, "Boss Trophy", "Flying Dutchman Trophy"),
+    FrameEntry::new(240, 1242, 54, "Boss Trophy", "Moon Lord Trophy"),
+    FrameEntry::new(241, 0, 0, "Catacomb", "A"),
+    FrameEntry::new(241, 0, 54, "Catacomb", "B"),
+    FrameEntry::new(241, 0, 108, "Catacomb", "C"),
+    FrameEntry::new(241, 0, 162, "Catacomb", "D"),
+    FrameEntry::new(241, 0, 216, "Catacomb", "E"),
+    FrameEntry::new(241, 0, 270, "Catacomb", "F"),
+    FrameEntry::new(241, 0, 324, "Catacomb", "G"),
+    FrameEntry::new(241, 0, 378, "Catacomb", "H"),
+    FrameEntry::new(241, 0, 432, "Catacomb", "I"),
+    FrameEntry::new(242, 0, 0, "Painting", "The Eye Sees the End"),
+    FrameEntry::new(242, 0, 72, "Painting", "Something Evil is Watching You"),
+    FrameEntry::new(242, 0, 144, "Painting", "The Twins Have Awoken"),
+    FrameEntry::new(242, 0, 216, "Painting", "The Screamer"),
+    FrameEntry::new(242, 0, 288, "Painting", "Goblins Playing Poker"),
+    FrameEntry::new(242, 0, 360, "Painting", "Dryadisque"),
+    FrameEntry::new(242, 0, 432, "Painting", "Impact"),
+    FrameEntry::new(242, 0, 504, "Painting", "Powered by Birds"),
+    FrameEntry::new(242, 0, 576, "Painting", "The Destroyer"),
+    FrameEntry::new(242, 0, 648, "Painting", "The Persistency of Eyes"),
+    FrameEntry::new(242, 0, 720, "Painting", "Unicorn Crossing the Hallows"),
+    FrameEntry::new(242, 0, 792, "Painting", "Great Wave"),
+    FrameEntry::new(242, 0, 864, "Painting", "Starry Night"),
+    FrameEntry::new(242, 0, 936, "Painting", "Facing the Cerebral Mastermind"),
+    FrameEntry::new(242, 0, 1008, "Painting", "Lake of Fire"),
+    FrameEntry::new(242, 0, 1080, "Painting", "Trio Super Heroes"),
+    FrameEntry::new(242, 0, 1152, "Painting", "The Creation of the Guide"),
+    FrameEntry::new(242, 0, 1224, "Painting", "Jacking Skeletron"),
+    FrameEntry::new(242, 0, 1296, "Painting", "Bitter Harvest"),
+    FrameEntry::new(242, 0, 1368, "Painting", "Blood Moon Countess"),
+    FrameEntry::new(242, 0, 1440, "Painting", "Hallow's Eve"),
+    FrameEntry::new(242, 0, 1512, "Painting", "Morbid Curiosity"),
+    FrameEntry::new(242, 0, 1584, "Hanging", "Tiger Skin"),
+    FrameEntry::new(242, 0, 1656, "Hanging", "Leopard Skin"),
+    FrameEntry::new(242, 0, 1728, "Hanging", "Zebra Skin"),
+    FrameEntry::new(242, 0, 1800, "Hanging", "Treasure Map"),
+    FrameEntry::new(242, 0, 1872, "Painting", "Pillagin Me Pixels"),
+    FrameEntry::new(242, 108, 0, "Painting", "Castle Marsberg"),
+    FrameEntry::new(242, 108, 72, "Painting", "Martia Lisa"),
+    FrameEntry::new(242, 108, 144, "Painting", "The Truth Is Up There"),
+    FrameEntry::new(242, 108, 216, "Painting", "Sparky"),
+    FrameEntry::new(242, 108, 288, "Painting", "Acorns"),
+    FrameEntry::new(242, 108, 360, "Painting", "Cold Snap"),
+    FrameEntry::new(242, 108, 432, "Painting", "Cursed Saint"),
+    FrameEntry::new(242, 108, 504, "Painting", "Snowfellas"),
+    FrameEntry::new(242, 108, 576, "Painting", "The Season"),
+    FrameEntry::new(242, 108, 648, "Painting", "Not a Kid, nor a Squid"),
+    FrameEntry::new(245, 0, 0, "Painting", "Waldo"),
+    FrameEntry::new(245, 36, 0, "Painting", "Darkness"),
+    FrameEntry::new(245, 72, 0, "Painting", "Dark Soul Reaper"),
+    FrameEntry::new(245, 108, 0, "Painting", "Land"),
+    FrameEntry::new(245, 144, 0, "Painting", "Trapped Ghost"),
+    FrameEntry::new(245, 180, 0, "Painting", "American Explosive"),
+    FrameEntry::new(245, 216, 0, "Painting", "Glorious Night"),
+    FrameEntry::new(246, 0, 0, "Painting", "Demon's Eye"),
+    FrameEntry::new(246, 0, 36, "Painting", "Finding Gold"),
+    FrameEntry::new(246, 0, 72, "Painting", "First Encounter"),
+    FrameEntry::new(246, 0, 108, "Painting", "Good Morning"),
+    FrameEntry::new(246, 0, 144, "Painting", "Underground Reward"),
+    FrameEntry::new(246, 0, 180, "Painting", "Through the Window"),
+    FrameEntry::new(246, 0, 216, "Painting", "Place Above the Clouds"),
+    FrameEntry::new(246, 0, 252, "Painting", "Do Not Step on the Grass"),
+    FrameEntry::new(246, 0, 288, "Painting", "Cold Waters in the White Land"),
+    FrameEntry::new(246, 0, 324, "Painting", "Lightless Chasms"),
+    FrameEntry::new(246, 0, 360, "Painting", "The Land of Deceiving Looks"),
+    FrameEntry::new(246, 0, 396, "Painting", "Daylight"),
+    FrameEntry::new(246, 0, 432, "Painting", "Secret of the Sands"),
+    FrameEntry::new(246, 0, 468, "Painting", "Deadland Comes Alive"),
+    FrameEntry::new(246, 0, 504, "Painting", "Evil Presence"),
+    FrameEntry::new(246, 0, 540, "Painting", "Sky Guardian"),
+    FrameEntry::new(246, 0, 576, "Painting", "Living Gore"),
+    FrameEntry::new(246, 0, 612, "Painting", "Flowing Magma"),
+    FrameEntry::new(246, 0, 684, "Hanging", "Holly"),
+    FrameEntry::new(254, 0, 0, "Pumpkin Plant", "Sprout 1A"),
+    FrameEntry::new(254, 0, 36, "Pumpkin Plant", "Sprout 2A"),
+    FrameEntry::new(254, 0, 72, "Pumpkin Plant", "Sprout 3A"),
+    FrameEntry::new(254, 0, 108, "Pumpkin Plant", "Sprout 4A"),
+    FrameEntry::new(254, 0, 144, "Pumpkin Plant", "Sprout 5A"),
+    FrameEntry::new(254, 0, 180, "Pumpkin Plant", "Sprout 6A"),
+    FrameEntry::new(254, 36, 0, "Pumpkin Plant", "Sprout 1B"),
+    FrameEntry::new(254, 36, 36, "Pumpkin Plant", "Sprout 2B"),
+    FrameEntry::new(254, 36, 72, "Pumpkin Plant", "Sprout 3B"),
+    FrameEntry::new(254, 36, 108, "Pumpkin Plant", "Sprout 4B"),
+    FrameEntry::new(254, 36, 144, "Pumpkin Plant", "Sprout 5B"),
+    FrameEntry::new(254, 36, 180, "Pumpkin Plant", "Sprout 6B"),
+    FrameEntry::new(254, 72, 0, "Pumpkin Plant", "Immature 1A"),
+    FrameEntry::new(254, 72, 36, "Pumpkin Plant", "Immature 2A"),
+    FrameEntry::new(254, 72, 72, "Pumpkin Plant", "Immature 3A"),
+    FrameEntry::new(254, 72, 108, "Pumpkin Plant", "Immature 4A"),
+    FrameEntry::new(254, 72, 144, "Pumpkin Plant", "Immature 5A"),
+    FrameEntry::new(254, 72, 180, "Pumpkin Plant", "Immature 6A"),
+    FrameEntry::new(254, 108, 0, "Pumpkin Plant", "Immature 1B"),
+    FrameEntry::new(254, 108, 36, "Pumpkin Plant", "Immature 2B"),
+    FrameEntry::new(254, 108, 72, "Pumpkin Plant", "Immature 3B"),
+    FrameEntry::new(254, 108, 108, "Pumpkin Plant", "Immature 4B"),
+    FrameEntry::new(254, 108, 144, "Pumpkin Plant", "Immature 5B"),
+    FrameEntry::new(254, 108, 180, "Pumpkin Plant", "Immature 6B"),
+    FrameEntry::new(254, 144, 0, "Pumpkin Plant", "Mature 1"),
+    FrameEntry::new(254, 144, 36, "Pumpkin Plant", "Mature 2"),
+    FrameEntry::new(254, 144, 72, "Pumpkin Plant", "Mature 3"),
+    FrameEntry::new(254, 144, 108, "Pumpkin Plant", "Mature 4"),
+    FrameEntry::new(254, 144, 144, "Pumpkin Plant", "Mature 5"),
+    FrameEntry::new(254, 144, 180, "Pumpkin Plant", "Mature 6"),
+    FrameEntry::new(269, 0, 0, "Womannequin", ""),
+    FrameEntry::new(269, 36, 0, "Womannequin", ""),
+    FrameEntry::new(314, 0, 0, "Minecart Track", "Rail A"),
+    FrameEntry::new(314, 18, 0, "Minecart Track", "Rail B"),
+    FrameEntry::new(314, 36, 0, "Minecart Track", "Rail C"),
+    FrameEntry::new(314, 54, 0, "Minecart Track", "Rail D"),
+    FrameEntry::new(314, 72, 0, "Minecart Track", "Diagonal A"),
+    FrameEntry::new(314, 90, 0, "Minecart Track", "Diagonal B"),
+    FrameEntry::new(314, 108, 0, "Minecart Track", "Diagonal C"),
+    FrameEntry::new(314, 126, 0, "Minecart Track", "Diagonal D"),
+    FrameEntry::new(314, 18, 18, "Minecart Track", "Slope Transition Up-Right"),
+    FrameEntry::new(314, 0, 18, "Minecart Track", "Slope Transition Up-Left"),
+    FrameEntry::new(314, 0, 36, "Minecart Track", "Half Height Slope A"),
+    FrameEntry::new(314, 18, 36, "Minecart Track", "Half Height Slope B"),
+    FrameEntry::new(314, 0, 54, "Minecart Track", "Slope A"),
+    FrameEntry::new(314, 18, 54, "Minecart Track", "Slope B"),
+    FrameEntry::new(314, 0, 108, "Minecart Track", "Tie Upper Left"),
+    FrameEntry::new(314, 18, 108, "Minecart Track", "Tie Upper Right"),
+    FrameEntry::new(314, 0, 126, "Minecart Track", "End Cap"),
+    FrameEntry::new(314, 36, 18, "Minecart Track", "End Cap Level A"),
+    FrameEntry::new(314, 54, 18, "Minecart Track", "End Cap Level B"),
+    FrameEntry::new(314, 72, 18, "Minecart Track", "End Cap Diagonal A"),
+    FrameEntry::new(314, 90, 18, "Minecart Track", "End Cap Diagonal B"),
+    FrameEntry::new(314, 108, 18, "Minecart Track", "End Cap Diagonal C"),
+    FrameEntry::new(314, 126, 18, "Minecart Track", "End Cap Diagonal D"),
+    FrameEntry::new(314, 18, 126, "Minecart Track", "Bumper"),
+    FrameEntry::new(314, 36, 36, "Minecart Track", "Bumper Level A"),
+    FrameEntry::new(314, 54, 36, "Minecart Track", "Bumper Level B"),
+    FrameEntry::new(314, 72, 36, "Minecart Track", "Bumper Diagonal A"),
+    FrameEntry::new(314, 90, 36, "Minecart Track", "Bumper Diagonal B"),
+    FrameEntry::new(314, 108, 36, "Minecart Track", "Bumper Diagonal C"),
+    FrameEntry::new(314, 126, 36, "Minecart Track", "Bumper Diagonal D"),
+    FrameEntry::new(314, 36, 54, "Minecart Track", "Booster Right Level"),
+    FrameEntry::new(314, 54, 54, "Minecart Track", "Booster Left Level"),
+    FrameEntry::new(314, 72, 54, "Minecart Track", "Booster Down-Right"),
+    FrameEntry::new(314, 90, 54, "Minecart Track", "Booster Down-Left"),
+    FrameEntry::new(314, 108, 54, "Minecart Track", "Booster Up-Left"),
+    FrameEntry::new(314, 126, 54, "Minecart Track", "Booster Up-Right"),
+    FrameEntry::new(314, 0, 72, "Minecart Track", "Pressure Plate Track A"),
+    FrameEntry::new(314, 18, 72, "Minecart Track", "Pressure Plate Track B"),
+    FrameEntry::new(314, 0, 90, "Minecart Track", "Pressure Plate Track C"),
+    FrameEntry::new(314, 18, 90, "Minecart Track", "Pressure Plate Track D"),
+    FrameEntry::new(324, 0, 0, "Seashell", "Seashell A"),
+    FrameEntry::new(324, 22, 0, "Seashell", "Seashell B"),
+    FrameEntry::new(324, 44, 0, "Seashell", "Seashell C"),
+    FrameEntry::new(324, 0, 22, "Seashell", "Starfish A"),
+    FrameEntry::new(324, 22, 22, "Seashell", "Starfish B"),
+    FrameEntry::new(324, 44, 22, "Seashell", "Starfish C"),
+    FrameEntry::new(337, 0, 0, "Text Statue", "0 Statue"),
+    FrameEntry::new(337, 36, 0, "Text Statue", "1 Statue"),
+    FrameEntry::new(337, 72, 0, "Text Statue", "2 Statue"),
+    FrameEntry::new(337, 108, 0, "Text Statue", "3 Statue"),
+    FrameEntry::new(337, 144, 0, "Text Statue", "4 Statue"),
+    FrameEntry::new(337, 180, 0, "Text Statue", "5 Statue"),
+    FrameEntry::new(337, 216, 0, "Text Statue", "6 Statue"),
+    FrameEntry::new(337, 252, 0, "Text Statue", "7 Statue"),
+    FrameEntry::new(337, 288, 0, "Text Statue", "8 Statue"),
+    FrameEntry::new(337, 324, 0, "Text Statue", "9 Statue"),
+    FrameEntry::new(337, 360, 0, "Text Statue", "A Statue"),
+    FrameEntry::new(337, 396, 0, "Text Statue", "B Statue"),
+    FrameEntry::new(337, 432, 0, "Text Statue", "C Statue"),
+    FrameEntry::new(337, 468, 0, "Text Statue", "D Statue"),
+    FrameEntry::new(337, 504, 0, "Text Statue", "E Statue"),
+    FrameEntry::new(337, 540, 0, "Text Statue", "F Statue"),
+    FrameEntry::new(337, 576, 0, "Text Statue", "G Statue"),
+    FrameEntry::new(337, 612, 0, "Text Statue", "H Statue"),
+    FrameEntry::new(337, 648, 0, "Text Statue", "I Statue"),
+    FrameEntry::new(337, 684, 0, "Text Statue", "J Statue"),
+    FrameEntry::new(337, 720, 0, "Text Statue", "K Statue"),
+    FrameEntry::new(337, 756, 0, "Text Statue", "L Statue"),
+    FrameEntry::new(337, 792, 0, "Text Statue", "M Statue"),
+    FrameEntry::new(337, 828, 0, "Text Statue", "N Statue"),
+    FrameEntry::new(337, 864, 0, "Text Statue", "O Statue"),
+    FrameEntry::new(337, 900, 0, "Text Statue", "P Statue"),
+    FrameEntry::new(337, 936, 0, "Text Statue", "Q Statue"),
+    FrameEntry::new(337, 972, 0, "Text Statue", "R Statue"),
+    FrameEntry::new(337, 1008, 0, "Text Statue", "S Statue"),
+    FrameEntry::new(337, 1044, 0, "Text Statue", "T Statue"),
+    FrameEntry::new(337, 1080, 0, "Text Statue", "U Statue"),
+    FrameEntry::new(337, 1116, 0, "Text Statue", "V Statue"),
+    FrameEntry::new(337, 1152, 0, "Text Statue", "W Statue"),
+    FrameEntry::new(337, 1188, 0, "Text Statue", "X Statue"),
+    FrameEntry::new(337, 1224, 0, "Text Statue", "Y Statue"),
+    FrameEntry::new(337, 1260, 0, "Text Statue", "Z Statue"),
+    FrameEntry::new(376, 0, 0, "Fishing Crate", "Wooden Crate"),
+    FrameEntry::new(376, 36, 0, "Fishing Crate", "Iron Crate"),
+    FrameEntry::new(376, 72, 0, "Fishing Crate", "Gold Crate"),
+    FrameEntry::new(376, 108, 0, "Fishing Crate", "Corrupt Crate"),
+    FrameEntry::new(376, 144, 0, "Fishing Crate", "Crimson Crate"),
+    FrameEntry::new(376, 180, 0, "Fishing Crate", "Dungeon Crate"),
+    FrameEntry::new(376, 216, 0, "Fishing Crate", "Sky Crate"),
+    FrameEntry::new(376, 252, 0, "Fishing Crate", "Hallow Crate"),
+    FrameEntry::new(376, 288, 0, "Fishing Crate", "Jungle Crate"),
+    FrameEntry::new(377, 0, 0, "Sharpening Station", "Left"),
+    FrameEntry::new(377, 54, 0, "Sharpening Station", "Right"),
+    FrameEntry::new(378, 0, 0, "Target Dummy", "Left"),
+    FrameEntry::new(378, 36, 0, "Target Dummy", "Right"),
+    FrameEntry::new(380, 0, 0, "Daybloom Planter", "Left"),
+    FrameEntry::new(380, 18, 0, "Daybloom Planter", "Middle"),
+    FrameEntry::new(380, 36, 0, "Daybloom Planter", "Right"),
+    FrameEntry::new(380, 54, 0, "Daybloom Planter", "Single"),
+    FrameEntry::new(380, 0, 18, "Moonglow Planter", "Left"),
+    FrameEntry::new(380, 18, 18, "Moonglow Planter", "Middle"),
+    FrameEntry::new(380, 36, 18, "Moonglow Planter", "Right"),
+    FrameEntry::new(380, 54, 18, "Moonglow Planter", "Single"),
+    FrameEntry::new(380, 0, 36, "Deathweed Planter", "Left"),
+    FrameEntry::new(380, 18, 36, "Deathweed Planter", "Middle"),
+    FrameEntry::new(380, 36, 36, "Deathweed Planter", "Right"),
+    FrameEntry::new(380, 54, 36, "Deathweed Planter", "Single"),
+    FrameEntry::new(380, 0, 54, "Crimsonweed Planter", "Left"),
+    FrameEntry::new(380, 18, 54, "Crimsonweed Planter", "Middle"),
+    FrameEntry::new(380, 36, 54, "Crimsonweed Planter", "Right"),
+    FrameEntry::new(380, 54, 54, "Crimsonweed Planter", "Single"),
+    FrameEntry::new(380, 0, 72, "Blinkroot Planter", "Left"),
+    FrameEntry::new(380, 18, 72, "Blinkroot Planter", "Middle"),
+    FrameEntry::new(380, 36, 72, "Blinkroot Planter", "Right"),
+    FrameEntry::new(380, 54, 72, "Blinkroot Planter", "Single"),
+    FrameEntry::new(380, 0, 90, "Waterleaf Planter", "Left"),
+    FrameEntry::new(380, 18, 90, "Waterleaf Planter", "Middle"),
+    FrameEntry::new(380, 36, 90, "Waterleaf Planter", "Right"),
+    FrameEntry::new(380, 54, 90, "Waterleaf Planter", "Single"),
+    FrameEntry::new(380, 0, 108, "Shiverthorn Planter", "Left"),
+    FrameEntry::new(380, 18, 108, "Shiverthorn Planter", "Middle"),
+    FrameEntry::new(380, 36, 108, "Shiverthorn Planter", "Right"),
+    FrameEntry::new(380, 54, 108, "Shiverthorn Planter", "Single"),
+    FrameEntry::new(380, 0, 126, "Fireblossom Planter", "Left"),
+    FrameEntry::new(380, 18, 126, "Fireblossom Planter", "Middle"),
+    FrameEntry::new(380, 36, 126, "Fireblossom Planter", "Right"),
+    FrameEntry::new(380, 54, 126, "Fireblossom Planter", "Single"),
+    FrameEntry::new(386, 0, 0, "Trapdoor Open", "Up"),
+    FrameEntry::new(386, 36, 0, "Trapdoor Open", "Down"),
+    FrameEntry::new(388, 0, 0, "Closed", "Style 1"),
+    FrameEntry::new(388, 0, 94, "Closed", "Style 2"),
+    FrameEntry::new(389, 0, 0, "Open", "Style 1"),
+    FrameEntry::new(389, 0, 94, "Open", "Style 2"),
+    FrameEntry::new(405, 0, 0, "Fireplace", "Lit"),
+    FrameEntry::new(405, 54, 0, "Fireplace", "Unlit"),
+    FrameEntry::new(410, 0, 0, "Lunar Monolith", "Vortex"),
+    FrameEntry::new(410, 36, 0, "Lunar Monolith", "Nebula"),
+    FrameEntry::new(410, 72, 0, "Lunar Monolith", "Stardust"),
+    FrameEntry::new(410, 108, 0, "Lunar Monolith", "Solar"),
+    FrameEntry::new(411, 0, 0, "Detonator", "Trigger Up"),
+    FrameEntry::new(411, 36, 0, "Detonator", "Trigger Down"),
+    FrameEntry::new(467, 0, 0, "Crystal Chest", ""),
+    FrameEntry::new(467, 36, 0, "Golden Chest", ""),
+    FrameEntry::new(467, 72, 0, "Spider Chest", ""),
+    FrameEntry::new(467, 108, 0, "Lesion Chest", ""),
+    FrameEntry::new(467, 144, 0, "Dead Man's Chest", ""),
+    FrameEntry::new(467, 180, 0, "Solar Chest", ""),
+    FrameEntry::new(467, 216, 0, "Vortex Chest", ""),
+    FrameEntry::new(467, 252, 0, "Nebula Chest", ""),
+    FrameEntry::new(467, 288, 0, "Stardust Chest", ""),
+    FrameEntry::new(467, 324, 0, "Golf Chest", ""),
+    FrameEntry::new(467, 360, 0, "Sandstone Chest", ""),
+    FrameEntry::new(467, 396, 0, "Bamboo Chest", ""),
+    FrameEntry::new(467, 432, 0, "Desert Chest", "Locked"),
+    FrameEntry::new(467, 468, 0, "Desert Chest", ""),
+    FrameEntry::new(467, 486, 0, "Desert Chest", ""),
+    FrameEntry::new(468, 0, 0, "Trapped Crystal", ""),
+    FrameEntry::new(468, 36, 0, "Trapped Golden", ""),
+    FrameEntry::new(468, 72, 0, "Trapped Spider", ""),
+    FrameEntry::new(468, 108, 0, "Trapped Lesion", ""),
+    FrameEntry::new(468, 144, 0, "Trapped Dead Man's", ""),
+    FrameEntry::new(468, 180, 0, "Trapped Solar", ""),
+    FrameEntry::new(468, 216, 0, "Trapped Vortex", ""),
+    FrameEntry::new(468, 252, 0, "Trapped Nebula", ""),
+    FrameEntry::new(468, 288, 0, "Trapped Stardust", ""),
+    FrameEntry::new(468, 324, 0, "Trapped Golf", ""),
+    FrameEntry::new(468, 360, 0, "Trapped Sandstone", ""),
+    FrameEntry::new(468, 396, 0, "Trapped Bamboo", ""),
+    FrameEntry::new(468, 432, 0, "Trapped Desert", ""),
+    FrameEntry::new(468, 468, 0, "Trapped Desert", "Locked"),
+    FrameEntry::new(597, 0, 0, "Forest Pylon", ""),
+    FrameEntry::new(597, 54, 0, "Jungle Pylon", ""),
+    FrameEntry::new(597, 108, 0, "Hallow Pylon", ""),
+    FrameEntry::new(597, 162, 0, "Cavern Pylon", ""),
+    FrameEntry::new(597, 216, 0, "Ocean Pylon", ""),
+    FrameEntry::new(597, 270, 0, "Desert Pylon", ""),
+    FrameEntry::new(597, 324, 0, "Snow Pylon", ""),
+    FrameEntry::new(597, 378, 0, "Mushroom Pylon", ""),
+    FrameEntry::new(597, 432, 0, "Universal Pylon", ""),
+];
+
+pub const TILE_SIZES: &[TileSize] = &[
+    TileSize::new(10, 1, 3, 16, 16),
+    TileSize::new(11, 2, 3, 16, 16),
+    TileSize::new(12, 2, 2, 16, 16),
+    TileSize::new(14, 3, 2, 16, 16),
+    TileSize::new(15, 1, 2, 16, 16),
+    TileSize::new(16, 2, 1, 16, 18),
+    TileSize::new(17, 3, 2, 16, 16),
+    TileSize::new(18, 2, 1, 16, 18),
+    TileSize::new(20, 1, 2, 16, 16),
+    TileSize::new(21, 2, 2, 16, 16),
+    TileSize::new(26, 3, 2, 16, 16),
+    TileSize::new(27, 2, 4, 16, 16),
+    TileSize::new(28, 2, 2, 16, 16),
+    TileSize::new(29, 2, 1, 16, 16),
+    TileSize::new(31, 2, 2, 16, 16),
+    TileSize::new(34, 3, 3, 16, 16),
+    TileSize::new(35, 2, 2, 16, 16),
+    TileSize::new(42, 1, 2, 16, 16),
+    TileSize::new(55, 2, 2, 16, 16),
+    TileSize::new(77, 3, 2, 16, 16),
+    TileSize::new(79, 4, 2, 16, 16),
+    TileSize::new(85, 2, 2, 16, 16),
+    TileSize::new(86, 3, 2, 16, 16),
+    TileSize::new(87, 3, 2, 16, 16),
+    TileSize::new(88, 3, 2, 16, 16),
+    TileSize::new(89, 3, 2, 16, 16),
+    TileSize::new(90, 4, 2, 16, 16),
+    TileSize::new(91, 1, 3, 16, 16),
+    TileSize::new(92, 1, 6, 16, 16),
+    TileSize::new(93, 1, 3, 16, 16),
+    TileSize::new(94, 2, 2, 16, 16),
+    TileSize::new(95, 2, 2, 16, 16),
+    TileSize::new(96, 2, 2, 16, 16),
+    TileSize::new(97, 2, 2, 16, 16),
+    TileSize::new(98, 2, 2, 16, 16),
+    TileSize::new(99, 2, 2, 16, 16),
+    TileSize::new(100, 2, 2, 16, 16),
+    TileSize::new(101, 3, 4, 16, 16),
+    TileSize::new(102, 3, 4, 16, 16),
+    TileSize::new(103, 2, 1, 16, 16),
+    TileSize::new(104, 2, 5, 16, 16),
+    TileSize::new(105, 2, 3, 16, 16),
+    TileSize::new(106, 3, 3, 16, 16),
+    TileSize::new(114, 3, 2, 16, 16),
+    TileSize::new(125, 2, 2, 16, 16),
+    TileSize::new(126, 2, 2, 16, 16),
+    TileSize::new(128, 2, 3, 16, 16),
+    TileSize::new(132, 2, 2, 16, 16),
+    TileSize::new(133, 3, 2, 16, 16),
+    TileSize::new(134, 2, 1, 16, 16),
+    TileSize::new(138, 2, 2, 16, 16),
+    TileSize::new(139, 2, 2, 16, 16),
+    TileSize::new(142, 2, 2, 16, 16),
+    TileSize::new(143, 2, 2, 16, 16),
+    TileSize::new(165, 1, 2, 16, 16),
+    TileSize::new(170, 3, 3, 16, 16),
+    TileSize::new(172, 2, 2, 16, 16),
+    TileSize::new(173, 2, 2, 16, 16),
+    TileSize::new(186, 3, 2, 16, 16),
+    TileSize::new(187, 3, 2, 16, 16),
+    TileSize::new(207, 2, 4, 16, 16),
+    TileSize::new(209, 4, 3, 16, 16),
+    TileSize::new(212, 3, 3, 16, 16),
+    TileSize::new(215, 3, 2, 16, 16),
+    TileSize::new(216, 1, 2, 16, 16),
+    TileSize::new(217, 3, 2, 16, 16),
+    TileSize::new(218, 3, 2, 16, 16),
+    TileSize::new(219, 3, 3, 16, 16),
+    TileSize::new(220, 3, 3, 16, 16),
+    TileSize::new(228, 3, 3, 16, 16),
+    TileSize::new(231, 3, 3, 16, 16),
+    TileSize::new(233, 3, 2, 16, 16),
+    TileSize::new(235, 3, 1, 16, 16),
+    TileSize::new(236, 2, 2, 16, 16),
+    TileSize::new(237, 3, 2, 16, 16),
+    TileSize::new(238, 2, 2, 16, 16),
+    TileSize::new(240, 3, 3, 16, 16),
+    TileSize::new(241, 4, 3, 16, 16),
+    TileSize::new(242, 6, 4, 16, 16),
+    TileSize::new(243, 3, 3, 16, 16),
+    TileSize::new(244, 3, 2, 16, 16),
+    TileSize::new(245, 2, 3, 16, 16),
+    TileSize::new(246, 3, 2, 16, 16),
+    TileSize::new(247, 3, 3, 16, 16),
+    TileSize::new(254, 2, 2, 16, 16),
+    TileSize::new(269, 2, 3, 16, 16),
+    TileSize::new(270, 1, 2, 16, 16),
+    TileSize::new(271, 1, 2, 16, 16),
+    TileSize::new(275, 6, 3, 16, 16),
+    TileSize::new(276, 6, 3, 16, 16),
+    TileSize::new(277, 6, 3, 16, 16),
+    TileSize::new(278, 6, 3, 16, 16),
+    TileSize::new(279, 6, 3, 16, 16),
+    TileSize::new(280, 6, 3, 16, 16),
+    TileSize::new(281, 6, 3, 16, 16),
+    TileSize::new(282, 2, 2, 16, 16),
+    TileSize::new(283, 3, 3, 16, 16),
+    TileSize::new(285, 3, 2, 16, 16),
+    TileSize::new(286, 3, 2, 16, 16),
+    TileSize::new(287, 2, 2, 16, 16),
+    TileSize::new(288, 2, 2, 16, 16),
+    TileSize::new(289, 2, 2, 16, 16),
+    TileSize::new(290, 2, 2, 16, 16),
+    TileSize::new(291, 2, 2, 16, 16),
+    TileSize::new(292, 2, 2, 16, 16),
+    TileSize::new(293, 2, 2, 16, 16),
+    TileSize::new(294, 2, 2, 16, 16),
+    TileSize::new(295, 2, 2, 16, 16),
+    TileSize::new(296, 6, 3, 16, 16),
+    TileSize::new(297, 6, 3, 16, 16),
+    TileSize::new(298, 3, 2, 16, 16),
+    TileSize::new(299, 3, 2, 16, 16),
+    TileSize::new(300, 3, 3, 16, 16),
+    TileSize::new(301, 3, 3, 16, 16),
+    TileSize::new(302, 3, 3, 16, 16),
+    TileSize::new(303, 3, 3, 16, 16),
+    TileSize::new(304, 3, 3, 16, 16),
+    TileSize::new(305, 3, 3, 16, 16),
+    TileSize::new(306, 3, 3, 16, 16),
+    TileSize::new(307, 3, 3, 16, 16),
+    TileSize::new(308, 3, 3, 16, 16),
+    TileSize::new(309, 6, 3, 16, 16),
+    TileSize::new(310, 3, 2, 16, 16),
+    TileSize::new(316, 2, 2, 16, 16),
+    TileSize::new(317, 2, 2, 16, 16),
+    TileSize::new(318, 2, 2, 16, 16),
+    TileSize::new(319, 2, 2, 16, 16),
+    TileSize::new(320, 2, 3, 16, 16),
+    TileSize::new(334, 3, 3, 16, 16),
+    TileSize::new(335, 2, 2, 16, 16),
+    TileSize::new(337, 2, 3, 16, 16),
+    TileSize::new(338, 1, 2, 16, 16),
+    TileSize::new(339, 3, 2, 16, 16),
+    TileSize::new(349, 2, 3, 16, 16),
+    TileSize::new(354, 3, 3, 16, 16),
+    TileSize::new(355, 3, 3, 16, 16),
+    TileSize::new(356, 2, 3, 16, 16),
+    TileSize::new(358, 6, 3, 16, 16),
+    TileSize::new(359, 6, 3, 16, 16),
+    TileSize::new(360, 2, 2, 16, 16),
+    TileSize::new(361, 3, 2, 16, 16),
+    TileSize::new(362, 3, 2, 16, 16),
+    TileSize::new(363, 3, 2, 16, 16),
+    TileSize::new(364, 3, 2, 16, 16),
+    TileSize::new(376, 2, 2, 16, 16),
+    TileSize::new(377, 3, 2, 16, 16),
+    TileSize::new(378, 2, 3, 16, 16),
+    TileSize::new(386, 2, 2, 16, 16),
+    TileSize::new(387, 2, 1, 16, 16),
+    TileSize::new(388, 1, 5, 16, 16),
+    TileSize::new(389, 1, 5, 16, 16),
+    TileSize::new(390, 1, 2, 16, 16),
+    TileSize::new(391, 3, 2, 16, 16),
+    TileSize::new(392, 3, 2, 16, 16),
+    TileSize::new(393, 3, 2, 16, 16),
+    TileSize::new(394, 3, 2, 16, 16),
+    TileSize::new(395, 2, 2, 16, 16),
+    TileSize::new(405, 3, 2, 16, 16),
+    TileSize::new(406, 3, 3, 16, 16),
+    TileSize::new(410, 2, 3, 16, 16),
+    TileSize::new(411, 2, 2, 16, 16),
+    TileSize::new(412, 3, 3, 16, 16),
+    TileSize::new(413, 6, 3, 16, 16),
+    TileSize::new(414, 6, 3, 16, 16),
+    TileSize::new(470, 2, 3, 16, 16),
+    TileSize::new(471, 3, 3, 16, 16),
+    TileSize::new(475, 3, 4, 16, 16),
+];
@@ -0,0 +1,167 @@
+// 名称匹配
+// 不区分大小写的完全、前缀、子串与容错（编辑距离）匹配，用于搜索框的自动补全
+
+use serde::{Deserialize, Serialize};
+use crate::name_registry::NameKind;
+
+// 匹配程度，越靠前越好
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MatchQuality {
+    Exact,
+    Prefix,
+    WordPrefix, // 某个单词以查询开头，如 "ore" 匹配 "Adamantite Ore"
+    Substring,
+    Fuzzy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameMatch {
+    pub kind: NameKind,
+    pub id: i32,
+    pub name: String, // 当前语言的显示名称
+    pub key: String,
+    pub quality: MatchQuality,
+    pub distance: usize, // 容错匹配的编辑距离，其余为 0
+}
+
+impl NameMatch {
+    // 排序键：匹配程度、编辑距离、名称长度、类别、id
+    pub fn rank(&self) -> (MatchQuality, usize, usize, NameKind, i32) {
+        (self.quality, self.distance, self.name.chars().count(), self.kind, self.id)
+    }
+}
+
+// 允许的编辑距离随查询长度增加，过短的查询不做容错匹配
+fn max_distance(query_len: usize) -> usize {
+    match query_len {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    }
+}
+
+/// query 须已转为小写；不匹配时返回 None
+pub fn match_name(query: &str, candidate: &str) -> Option<(MatchQuality, usize)> {
+    if query.is_empty() {
+        return None;
+    }
+    let candidate = candidate.to_lowercase();
+    if candidate == query {
+        return Some((MatchQuality::Exact, 0));
+    }
+    if candidate.starts_with(query) {
+        return Some((MatchQuality::Prefix, 0));
+    }
+    let words: Vec<(usize, &str)> = word_starts(&candidate);
+    if words.iter().any(|(_, word)| word.starts_with(query)) {
+        return Some((MatchQuality::WordPrefix, 0));
+    }
+    if candidate.contains(query) {
+        return Some((MatchQuality::Substring, 0));
+    }
+
+    // 容错匹配：与整个名称比较，或与从某个单词开头截取的等长片段比较（输入尚未打完时）
+    let query_chars: Vec<char> = query.chars().collect();
+    let limit = max_distance(query_chars.len());
+    if limit == 0 {
+        return None;
+    }
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let mut best = edit_distance_within(&query_chars, &candidate_chars, limit);
+    for (start, _) in &words {
+        let start = candidate[..*start].chars().count();
+        for len in query_chars.len().saturating_sub(1)..=query_chars.len() + 1 {
+            let end = (start + len).min(candidate_chars.len());
+            let distance = edit_distance_within(&query_chars, &candidate_chars[start..end], limit);
+            best = best.into_iter().chain(distance).min();
+        }
+    }
+    best.map(|distance| (MatchQuality::Fuzzy, distance))
+}
+
+// 每个单词的字节起点与内容
+fn word_starts(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (pos, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(pos);
+        } else if let Some(begin) = start.take() {
+            words.push((begin, &text[begin..pos]));
+        }
+    }
+    if let Some(begin) = start {
+        words.push((begin, &text[begin..]));
+    }
+    words
+}
+
+// 不超过 limit 的 Levenshtein 距离，单行滚动数组；整行都超过 limit 时提前结束
+fn edit_distance_within(a: &[char], b: &[char], limit: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > limit {
+        return None;
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+        if row.iter().all(|&distance| distance > limit) {
+            return None;
+        }
+    }
+    (row[b.len()] <= limit).then_some(row[b.len()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quality(query: &str, candidate: &str) -> Option<MatchQuality> {
+        match_name(query, candidate).map(|(quality, _)| quality)
+    }
+
+    #[test]
+    fn orders_exact_prefix_word_and_substring() {
+        assert_eq!(quality("iron ore", "Iron Ore"), Some(MatchQuality::Exact));
+        assert_eq!(quality("iron", "Iron Ore"), Some(MatchQuality::Prefix));
+        assert_eq!(quality("ore", "Adamantite Ore"), Some(MatchQuality::WordPrefix));
+        assert_eq!(quality("mantite", "Adamantite Ore"), Some(MatchQuality::Substring));
+        assert_eq!(quality("", "Iron Ore"), None);
+        assert!(MatchQuality::Exact < MatchQuality::Substring && MatchQuality::Substring < MatchQuality::Fuzzy);
+    }
+
+    #[test]
+    fn fuzzy_distance_grows_with_query_length() {
+        // 3 个字符以内不容错
+        assert_eq!(match_name("irn", "Iron"), None);
+        // 4 到 6 个字符允许 1 处差异；"irno" 与整个名称差 2，但与片段 "iro" 只差 1
+        assert_eq!(match_name("irom", "Iron"), Some((MatchQuality::Fuzzy, 1)));
+        assert_eq!(match_name("irno", "Iron"), Some((MatchQuality::Fuzzy, 1)));
+        assert_eq!(match_name("ixom", "Iron"), None);
+        assert_eq!(match_name("adamantit", "Adamantite"), Some((MatchQuality::Prefix, 0)));
+        assert_eq!(match_name("adamentyte", "Adamantite"), Some((MatchQuality::Fuzzy, 2)));
+        assert_eq!(match_name("adementyte", "Adamantite"), None);
+    }
+
+    #[test]
+    fn fuzzy_matches_a_partly_typed_word() {
+        // 名称尚未打完且有拼写错误，与单词开头的片段比较
+        assert_eq!(match_name("chlorofi", "Chlorophyte Ore"), Some((MatchQuality::Fuzzy, 2)));
+        assert_eq!(match_name("hellstome", "Hellstone Bar"), Some((MatchQuality::Fuzzy, 1)));
+        assert_eq!(match_name("stome", "Hellstone Bar"), None);
+    }
+
+    #[test]
+    fn fuzzy_compares_characters_not_bytes() {
+        assert_eq!(match_name("eisenerx", "Eisenerz"), Some((MatchQuality::Fuzzy, 1)));
+        assert_eq!(match_name("ädamant", "Adamant"), Some((MatchQuality::Fuzzy, 1)));
+        let chars = |text: &str| text.chars().collect::<Vec<_>>();
+        assert_eq!(edit_distance_within(&chars("kitten"), &chars("sitting"), 3), Some(3));
+        assert_eq!(edit_distance_within(&chars("kitten"), &chars("sitting"), 2), None);
+    }
+}
@@ -0,0 +1,443 @@
+// 名称注册表
+// 物品、方块、墙体、NPC 的 id ↔ 内部名 ↔ 显示名称双向查询，对应原项目的 names.js 与 *Ids/*Keys.js
+// 可在运行时载入 Terraria 的本地化 JSON，缺少译名时回退到英文
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::name_data::{ITEM_NAMES, NPC_NAMES, TILE_FRAMES, TILE_NAMES, TILE_SIZES, WALL_NAMES};
+use crate::name_match::{match_name, NameMatch};
+use crate::world_loader::WorldLoadError;
+
+pub const DEFAULT_LANGUAGE: &str = "en-US";
+
+// 本地化 JSON 中各区段对应的名称类别
+// ItemName / NPCName 为游戏自带区段，TileName / WallName 供自制翻译使用。
+// 游戏的 MapObject 按地图图例命名，键与方块、墙体的内部名不对应，因此不使用
+const LOCALIZATION_SECTIONS: [(&str, NameKind); 4] = [
+    ("ItemName", NameKind::Item),
+    ("NPCName", NameKind::Npc),
+    ("TileName", NameKind::Tile),
+    ("WallName", NameKind::Wall),
+];
+
+// "{$Section.Key}" 引用的最大展开层数，防止循环引用
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum NameKind {
+    Item,
+    Tile,
+    Wall,
+    Npc,
+}
+
+impl NameKind {
+    pub const ALL: [NameKind; 4] = [NameKind::Item, NameKind::Tile, NameKind::Wall, NameKind::Npc];
+
+    /// 按 id 排序的全部条目
+    pub fn entries(self) -> &'static [NameEntry] {
+        match self {
+            NameKind::Item => ITEM_NAMES,
+            NameKind::Tile => TILE_NAMES,
+            NameKind::Wall => WALL_NAMES,
+            NameKind::Npc => NPC_NAMES,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct NameEntry {
+    pub id: i32,
+    pub key: &'static str,  // 内部名，如 "AdamantiteOre"
+    pub name: &'static str, // 英文显示名称
+}
+
+impl NameEntry {
+    pub const fn new(id: i32, key: &'static str, name: &'static str) -> Self {
+        Self { id, key, name }
+    }
+}
+
+// 方块的帧变体（如箱子中的 "Gold Chest"），u/v 为变体的起始帧坐标
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FrameEntry {
+    pub tile_id: i32,
+    pub u: i32,
+    pub v: i32,
+    pub name: &'static str,
+    pub variety: &'static str,
+}
+
+impl FrameEntry {
+    pub const fn new(tile_id: i32, u: i32, v: i32, name: &'static str, variety: &'static str) -> Self {
+        Self { tile_id, u, v, name, variety }
+    }
+}
+
+// 多格物体（家具、祭坛……）的尺寸，单位为格；cell_width/cell_height 为每格纹理像素，帧坐标步长另加 2
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TileSize {
+    pub tile_id: i32,
+    pub width: i32,
+    pub height: i32,
+    pub cell_width: i32,
+    pub cell_height: i32,
+}
+
+impl TileSize {
+    pub const fn new(tile_id: i32, width: i32, height: i32, cell_width: i32, cell_height: i32) -> Self {
+        Self { tile_id, width, height, cell_width, cell_height }
+    }
+
+    /// 帧坐标为 (u, v) 的格子相对物体左上角的偏移
+    pub fn anchor_offset(&self, u: i32, v: i32) -> (i32, i32) {
+        let (step_u, step_v) = (self.cell_width + 2, self.cell_height + 2);
+        ((u % (self.width * step_u)) / step_u, (v % (self.height * step_v)) / step_v)
+    }
+}
+
+// 内部名与小写显示名称到 id 的反向索引，首次查询时建立
+struct ReverseIndex {
+    by_key: HashMap<&'static str, i32>,
+    by_name: HashMap<String, i32>,
+}
+
+fn reverse_index(kind: NameKind) -> &'static ReverseIndex {
+    static INDEX: OnceLock<Vec<ReverseIndex>> = OnceLock::new();
+    let index = INDEX.get_or_init(|| {
+        NameKind::ALL
+            .iter()
+            .map(|kind| {
+                let mut by_key = HashMap::new();
+                let mut by_name = HashMap::new();
+                // 重名时保留 id 最小的条目
+                for entry in kind.entries() {
+                    by_key.entry(entry.key).or_insert(entry.id);
+                    by_name.entry(entry.name.to_lowercase()).or_insert(entry.id);
+                }
+                ReverseIndex { by_key, by_name }
+            })
+            .collect()
+    });
+    &index[kind.index()]
+}
+
+// 一种语言的译名，按类别与 id 存放
+type Translations = HashMap<(NameKind, i32), String>;
+
+// 一种语言已载入的全部本地化文本：区段 -> 键 -> 文本
+type LocalizationSources = HashMap<String, HashMap<String, String>>;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct NameRegistry {
+    language: String,
+    // 保留原文，引用可能指向之后才载入的文件
+    sources: HashMap<String, LocalizationSources>,
+    translations: HashMap<String, Translations>,
+}
+
+impl Default for NameRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl NameRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            language: DEFAULT_LANGUAGE.to_string(),
+            sources: HashMap::new(),
+            translations: HashMap::new(),
+        }
+    }
+
+    /// 载入某语言的本地化 JSON（如 zh-Hans.Items.json），同一语言可多次载入合并，返回载入的译名数
+    #[wasm_bindgen]
+    pub fn load_localization(&mut self, language: &str, data: &[u8]) -> Result<usize, JsValue> {
+        self.load_translations(language, data)
+            .map_err(|e| JsValue::from_str(&format!("Failed to load localization: {}", e)))
+    }
+
+    /// 切换显示语言，未载入的语言全部回退到英文
+    #[wasm_bindgen]
+    pub fn set_language(&mut self, language: &str) {
+        self.language = language.to_string();
+    }
+
+    #[wasm_bindgen]
+    pub fn language(&self) -> String {
+        self.language.clone()
+    }
+
+    /// 已载入的语言列表
+    #[wasm_bindgen]
+    pub fn languages(&self) -> Vec<String> {
+        let mut languages: Vec<String> = self.translations.keys().cloned().collect();
+        languages.sort();
+        languages
+    }
+
+    /// kind 为 "Item" / "Tile" / "Wall" / "Npc"
+    #[wasm_bindgen]
+    pub fn name_js(&self, kind_js: JsValue, id: i32) -> Result<Option<String>, JsValue> {
+        let kind: NameKind = serde_wasm_bindgen::from_value(kind_js)?;
+        Ok(self.display_name(kind, id))
+    }
+
+    #[wasm_bindgen]
+    pub fn key_js(&self, kind_js: JsValue, id: i32) -> Result<Option<String>, JsValue> {
+        let kind: NameKind = serde_wasm_bindgen::from_value(kind_js)?;
+        Ok(Self::entry(kind, id).map(|entry| entry.key.to_string()))
+    }
+
+    /// 按内部名或显示名称（不区分大小写）查 id
+    #[wasm_bindgen]
+    pub fn id_of_js(&self, kind_js: JsValue, key_or_name: &str) -> Result<Option<i32>, JsValue> {
+        let kind: NameKind = serde_wasm_bindgen::from_value(kind_js)?;
+        Ok(self.id_of(kind, key_or_name))
+    }
+
+    /// 按名称模糊查找，返回排好序的候选（kinds 为类别数组，省略时查找全部类别）
+    #[wasm_bindgen]
+    pub fn suggest_js(&self, query: &str, kinds_js: JsValue, limit: usize) -> Result<JsValue, JsValue> {
+        let kinds: Vec<NameKind> = if kinds_js.is_undefined() || kinds_js.is_null() {
+            NameKind::ALL.to_vec()
+        } else {
+            serde_wasm_bindgen::from_value(kinds_js)?
+        };
+        Ok(serde_wasm_bindgen::to_value(&self.suggest(query, &kinds, limit))?)
+    }
+
+    /// 某类的全部条目（id、key、name），用于下拉列表
+    #[wasm_bindgen]
+    pub fn entries_js(&self, kind_js: JsValue) -> Result<JsValue, JsValue> {
+        let kind: NameKind = serde_wasm_bindgen::from_value(kind_js)?;
+        Ok(serde_wasm_bindgen::to_value(kind.entries())?)
+    }
+}
+
+impl NameRegistry {
+    pub fn entry(kind: NameKind, id: i32) -> Option<&'static NameEntry> {
+        let entries = kind.entries();
+        entries
+            .binary_search_by_key(&id, |entry| entry.id)
+            .ok()
+            .map(|pos| &entries[pos])
+    }
+
+    /// 方块某帧坐标所属的变体，与原项目 getTileInfo 相同：取最后一个 u、v 都不大于帧坐标的变体
+    pub fn frame(tile_id: i32, u: i32, v: i32) -> Option<&'static FrameEntry> {
+        let start = TILE_FRAMES.partition_point(|frame| frame.tile_id < tile_id);
+        let end = TILE_FRAMES.partition_point(|frame| frame.tile_id <= tile_id);
+        TILE_FRAMES[start..end]
+            .iter()
+            .rev()
+            .find(|frame| frame.u <= u && frame.v <= v)
+    }
+
+    /// 多格物体的尺寸，1×1 的方块返回 None
+    pub fn tile_size(tile_id: i32) -> Option<&'static TileSize> {
+        TILE_SIZES
+            .binary_search_by_key(&tile_id, |size| size.tile_id)
+            .ok()
+            .map(|pos| &TILE_SIZES[pos])
+    }
+
+    /// 当前语言的显示名称，缺少译名时回退到英文
+    pub fn display_name(&self, kind: NameKind, id: i32) -> Option<String> {
+        let entry = Self::entry(kind, id)?;
+        let localized = self
+            .translations
+            .get(&self.language)
+            .and_then(|translations| translations.get(&(kind, id)));
+        Some(localized.cloned().unwrap_or_else(|| entry.name.to_string()))
+    }
+
+    /// 当前语言的全部译名
+    pub fn localized_names(&self, kind: NameKind) -> impl Iterator<Item = (i32, &str)> + '_ {
+        self.translations
+            .get(&self.language)
+            .into_iter()
+            .flat_map(move |translations| {
+                translations
+                    .iter()
+                    .filter(move |((entry_kind, _), _)| *entry_kind == kind)
+                    .map(|((_, id), name)| (*id, name.as_str()))
+            })
+    }
+
+    /// 同时匹配当前语言的译名与英文名，内部名只做完全匹配
+    pub fn suggest(&self, query: &str, kinds: &[NameKind], limit: usize) -> Vec<NameMatch> {
+        let query = query.trim().to_lowercase();
+        let mut matches = Vec::new();
+
+        for &kind in kinds {
+            for entry in kind.entries() {
+                let name = self.display_name(kind, entry.id).unwrap_or_default();
+                let best = if entry.key.eq_ignore_ascii_case(&query) {
+                    match_name(&query, &query)
+                } else {
+                    let localized = match_name(&query, &name);
+                    let english = if name == entry.name { None } else { match_name(&query, entry.name) };
+                    localized.into_iter().chain(english).min()
+                };
+                if let Some((quality, distance)) = best {
+                    matches.push(NameMatch {
+                        kind,
+                        id: entry.id,
+                        name,
+                        key: entry.key.to_string(),
+                        quality,
+                        distance,
+                    });
+                }
+            }
+        }
+
+        matches.sort_by_key(NameMatch::rank);
+        matches.truncate(limit);
+        matches
+    }
+
+    pub fn load_translations(&mut self, language: &str, data: &[u8]) -> Result<usize, String> {
+        let root: serde_json::Value = serde_json::from_slice(data).map_err(|e| -> String {
+            WorldLoadError::InvalidFormat {
+                expected: "localization JSON".to_string(),
+                found: e.to_string(),
+            }.into()
+        })?;
+
+        // 保存全部区段的文本，"{$ItemName.X}" 这类引用可能跨文件
+        let sources = self.sources.entry(language.to_string()).or_default();
+        for (section, values) in root.as_object().into_iter().flatten() {
+            let Some(values) = values.as_object() else {
+                continue;
+            };
+            let strings = sources.entry(section.clone()).or_default();
+            for (key, value) in values {
+                if let Some(text) = value.as_str() {
+                    strings.insert(key.clone(), text.to_string());
+                }
+            }
+        }
+
+        let count = LOCALIZATION_SECTIONS
+            .iter()
+            .filter_map(|(section, kind)| Some((root.get(section)?.as_object()?, *kind)))
+            .flat_map(|(values, kind)| values.keys().filter(move |key| Self::id_by_key(kind, key).is_some()))
+            .count();
+        self.resolve_language(language);
+        Ok(count)
+    }
+
+    // 用该语言已载入的全部文本重新生成译名
+    fn resolve_language(&mut self, language: &str) {
+        let Some(sources) = self.sources.get(language) else {
+            return;
+        };
+        let mut translations = Translations::new();
+        for (section, kind) in LOCALIZATION_SECTIONS {
+            for (key, text) in sources.get(section).into_iter().flatten() {
+                if let Some(id) = Self::id_by_key(kind, key) {
+                    translations.insert((kind, id), resolve_references(text, sources, 0));
+                }
+            }
+        }
+        self.translations.insert(language.to_string(), translations);
+    }
+
+    pub fn id_by_key(kind: NameKind, key: &str) -> Option<i32> {
+        reverse_index(kind).by_key.get(key).copied()
+    }
+
+    pub fn id_by_name(kind: NameKind, name: &str) -> Option<i32> {
+        reverse_index(kind).by_name.get(&name.to_lowercase()).copied()
+    }
+
+    /// 按内部名、当前语言的译名或英文名查 id
+    pub fn id_of(&self, kind: NameKind, key_or_name: &str) -> Option<i32> {
+        let lower = key_or_name.to_lowercase();
+        Self::id_by_key(kind, key_or_name)
+            .or_else(|| {
+                self.localized_names(kind)
+                    .filter(|(_, name)| name.to_lowercase() == lower)
+                    .map(|(id, _)| id)
+                    .min()
+            })
+            .or_else(|| Self::id_by_name(kind, key_or_name))
+    }
+}
+
+// 递归展开 "{$Section.Key}" 引用，找不到的引用与超过层数的引用原样保留
+fn resolve_references(text: &str, sources: &LocalizationSources, depth: usize) -> String {
+    if depth >= MAX_REFERENCE_DEPTH {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{$") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        let reference = &rest[start + 2..start + end];
+        let resolved = reference
+            .split_once('.')
+            .and_then(|(section, key)| sources.get(section)?.get(key));
+        result.push_str(&rest[..start]);
+        match resolved {
+            Some(value) => result.push_str(&resolve_references(value, sources, depth + 1)),
+            None => result.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::name_match::MatchQuality;
+
+    fn top(registry: &NameRegistry, query: &str, kinds: &[NameKind]) -> (NameKind, i32, String, MatchQuality, usize) {
+        let found = registry.suggest(query, kinds, 1).remove(0);
+        (found.kind, found.id, found.name, found.quality, found.distance)
+    }
+
+    #[test]
+    fn suggest_ranks_by_quality_then_distance() {
+        let registry = NameRegistry::new();
+        let found = registry.suggest("Iron Ore", &[NameKind::Tile, NameKind::Item], 2);
+        let ids: Vec<_> = found.iter().map(|found| (found.kind, found.id, found.quality)).collect();
+        assert_eq!(ids, [(NameKind::Item, 11, MatchQuality::Exact), (NameKind::Tile, 6, MatchQuality::Exact)]);
+
+        assert_eq!(top(&registry, "IRONORE", &[NameKind::Item]).3, MatchQuality::Exact);
+        assert_eq!(top(&registry, "iron or", &[NameKind::Item]).3, MatchQuality::Prefix);
+        let (_, id, name, quality, distance) = top(&registry, "irn ore", &[NameKind::Item]);
+        assert_eq!((id, name.as_str(), quality, distance), (11, "Iron Ore", MatchQuality::Fuzzy, 1));
+        assert!(registry.suggest("zzzzzzzz", &NameKind::ALL, 5).is_empty());
+    }
+
+    #[test]
+    fn suggest_matches_translated_and_english_names() {
+        let mut registry = NameRegistry::new();
+        let data = br#"{ "ItemName": { "IronOre": "Eisenerz", "IronBar": "{$ItemName.IronOre}barren" } }"#;
+        assert_eq!(registry.load_translations("de-DE", data).unwrap(), 2);
+        registry.set_language("de-DE");
+
+        let (_, id, name, quality, distance) = top(&registry, "eisenerx", &[NameKind::Item]);
+        assert_eq!((id, name.as_str(), quality, distance), (11, "Eisenerz", MatchQuality::Fuzzy, 1));
+        assert_eq!(top(&registry, "Iron Ore", &[NameKind::Item]).2, "Eisenerz");
+        assert_eq!(registry.display_name(NameKind::Item, 22).as_deref(), Some("Eisenerzbarren"));
+        assert_eq!(registry.id_of(NameKind::Item, "eisenerzbarren"), Some(22));
+    }
+}
@@ -0,0 +1,254 @@
+// NBT 风格的标签数据
+// tModLoader 的 TagIO 格式：大端序，根为带名称的 TagCompound
+
+use crate::world_loader::WorldLoadError;
+
+// 嵌套过深视为损坏，防止栈溢出
+const MAX_DEPTH: usize = 512;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<u8>),
+    String(String),
+    List(Vec<Tag>),
+    Compound(Vec<(String, Tag)>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    /// 读取根标签（类型字节、名称、内容）
+    pub fn parse(data: &[u8]) -> Result<Tag, String> {
+        let mut reader = TagReader { data, pos: 0 };
+        let tag_type = reader.byte()?;
+        let _name = reader.string()?;
+        reader.payload(tag_type, 0)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Tag> {
+        match self {
+            Tag::Compound(entries) => entries.iter().find(|(name, _)| name == key).map(|(_, tag)| tag),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> &[Tag] {
+        match self {
+            Tag::List(items) => items,
+            _ => &[],
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Tag::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Tag::ByteArray(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// 整数类标签统一转为 i64
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Tag::Byte(value) => Some(value as i64),
+            Tag::Short(value) => Some(value as i64),
+            Tag::Int(value) => Some(value as i64),
+            Tag::Long(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(Tag::as_i64)
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Tag::as_str)
+    }
+}
+
+struct TagReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl TagReader<'_> {
+    fn bytes(&mut self, count: usize) -> Result<&[u8], String> {
+        if self.pos + count > self.data.len() {
+            return Err(WorldLoadError::CorruptedData {
+                position: self.pos,
+                message: "Unexpected end of tag data".to_string(),
+            }.into());
+        }
+        let bytes = &self.data[self.pos..self.pos + count];
+        self.pos += count;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn short(&mut self) -> Result<i16, String> {
+        let b = self.bytes(2)?;
+        Ok(i16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn int(&mut self) -> Result<i32, String> {
+        let b = self.bytes(4)?;
+        Ok(i32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn long(&mut self) -> Result<i64, String> {
+        let b = self.bytes(8)?;
+        Ok(i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.short()? as u16 as usize;
+        Ok(String::from_utf8_lossy(self.bytes(len)?).into_owned())
+    }
+
+    // 数组长度，负数视为损坏
+    fn length(&mut self) -> Result<usize, String> {
+        let position = self.pos;
+        let len = self.int()?;
+        usize::try_from(len).map_err(|_| -> String {
+            WorldLoadError::CorruptedData {
+                position,
+                message: format!("Negative tag length {}", len),
+            }.into()
+        })
+    }
+
+    fn payload(&mut self, tag_type: u8, depth: usize) -> Result<Tag, String> {
+        if depth > MAX_DEPTH {
+            return Err(WorldLoadError::CorruptedData {
+                position: self.pos,
+                message: "Tag nesting is too deep".to_string(),
+            }.into());
+        }
+
+        Ok(match tag_type {
+            1 => Tag::Byte(self.byte()? as i8),
+            2 => Tag::Short(self.short()?),
+            3 => Tag::Int(self.int()?),
+            4 => Tag::Long(self.long()?),
+            5 => Tag::Float(f32::from_bits(self.int()? as u32)),
+            6 => Tag::Double(f64::from_bits(self.long()? as u64)),
+            7 => {
+                let len = self.length()?;
+                Tag::ByteArray(self.bytes(len)?.to_vec())
+            }
+            8 => Tag::String(self.string()?),
+            9 => {
+                let item_type = self.byte()?;
+                let len = self.length()?;
+                let mut items = Vec::with_capacity(len.min(self.data.len()));
+                for _ in 0..len {
+                    items.push(self.payload(item_type, depth + 1)?);
+                }
+                Tag::List(items)
+            }
+            10 => {
+                let mut entries = Vec::new();
+                loop {
+                    let entry_type = self.byte()?;
+                    if entry_type == 0 {
+                        break;
+                    }
+                    let name = self.string()?;
+                    entries.push((name, self.payload(entry_type, depth + 1)?));
+                }
+                Tag::Compound(entries)
+            }
+            11 => {
+                let len = self.length()?;
+                let mut values = Vec::with_capacity(len.min(self.data.len() / 4));
+                for _ in 0..len {
+                    values.push(self.int()?);
+                }
+                Tag::IntArray(values)
+            }
+            12 => {
+                let len = self.length()?;
+                let mut values = Vec::with_capacity(len.min(self.data.len() / 8));
+                for _ in 0..len {
+                    values.push(self.long()?);
+                }
+                Tag::LongArray(values)
+            }
+            _ => {
+                return Err(WorldLoadError::CorruptedData {
+                    position: self.pos,
+                    message: format!("Unknown tag type {}", tag_type),
+                }.into())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::tag_data;
+
+    fn compound(entries: Vec<(&str, Tag)>) -> Tag {
+        Tag::Compound(entries.into_iter().map(|(name, tag)| (name.to_string(), tag)).collect())
+    }
+
+    #[test]
+    fn reads_every_tag_type() {
+        let root = compound(vec![
+            ("byte", Tag::Byte(-2)),
+            ("short", Tag::Short(-300)),
+            ("int", Tag::Int(70_000)),
+            ("long", Tag::Long(-5_000_000_000)),
+            ("float", Tag::Float(1.5)),
+            ("double", Tag::Double(-0.25)),
+            ("bytes", Tag::ByteArray(vec![1, 2, 255])),
+            ("name", Tag::String("Ünïcode".to_string())),
+            ("list", Tag::List(vec![compound(vec![("id", Tag::Short(3))]), compound(vec![])])),
+            ("ints", Tag::IntArray(vec![-1, 2])),
+            ("longs", Tag::LongArray(vec![i64::MIN])),
+        ]);
+        let parsed = Tag::parse(&tag_data(&root)).unwrap();
+        assert_eq!(parsed, root);
+        assert_eq!(parsed.get_i64("short"), Some(-300));
+        assert_eq!(parsed.get_str("name"), Some("Ünïcode"));
+        assert_eq!(parsed.get("list").map(Tag::as_list).unwrap()[0].get_i64("id"), Some(3));
+        assert_eq!(parsed.get_i64("float"), None);
+        assert!(parsed.get("missing").is_none());
+    }
+
+    #[test]
+    fn rejects_truncated_and_negative_lengths() {
+        let data = tag_data(&compound(vec![("bytes", Tag::ByteArray(vec![1, 2, 3]))]));
+        assert!(Tag::parse(&data[..data.len() - 2]).unwrap_err().contains("Unexpected end of tag data"));
+
+        let mut data = tag_data(&compound(vec![("ints", Tag::IntArray(vec![]))]));
+        let at = data.len() - 5;
+        data[at..at + 4].copy_from_slice(&(-1i32).to_be_bytes());
+        assert!(Tag::parse(&data).unwrap_err().contains("Negative tag length -1"));
+    }
+
+    #[test]
+    fn rejects_deep_nesting() {
+        let nested = |depth: usize| (0..depth).fold(Tag::Byte(0), |tag, _| Tag::List(vec![tag]));
+        assert!(Tag::parse(&tag_data(&nested(MAX_DEPTH))).is_ok());
+        let error = Tag::parse(&tag_data(&nested(MAX_DEPTH + 1))).unwrap_err();
+        assert!(error.contains("Tag nesting is too deep"), "{}", error);
+    }
+}
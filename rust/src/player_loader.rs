@@ -0,0 +1,378 @@
+// 玩家文件加载器
+// 解密 .plr 文件并读取名称、背包、储物空间与每个世界的重生点
+
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use crate::data_stream::DataStream;
+use crate::world_loader::{ChestItem, WorldLoadError};
+use crate::world_parser::WorldParser;
+
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+// 游戏使用的固定密钥 "h3y_gUyZ"（UTF-16LE），同时作为初始向量
+const PLAYER_KEY: [u8; 16] = [
+    b'h', 0, b'3', 0, b'y', 0, b'_', 0, b'g', 0, b'U', 0, b'y', 0, b'Z', 0,
+];
+
+// "relogic" 魔数与 .plr 文件类型
+const MAGIC: u64 = 0x0063_6967_6F6C_6572;
+const PLAYER_FILE_TYPE: u64 = 3;
+
+// 只支持 1.4（Journey's End）及之后的格式
+const MIN_PLAYER_VERSION: i32 = 230;
+
+const ARMOR_SLOTS: usize = 20;
+const DYE_SLOTS: usize = 10;
+const INVENTORY_SLOTS: usize = 58;
+const MISC_SLOTS: usize = 5;
+const BANK_SLOTS: usize = 40;
+const MAX_SPAWN_POINTS: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    pub x: i32,
+    pub y: i32,
+    pub world_id: i32,
+    pub world_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Player {
+    pub name: String,
+    pub version: i32,
+    // 0 经典，1 中核，2 硬核，3 旅行
+    pub difficulty: u8,
+    // 游玩时长（.NET ticks，1 秒 = 10,000,000）
+    pub play_time: i64,
+    pub life: i32,
+    pub max_life: i32,
+    pub mana: i32,
+    pub max_mana: i32,
+    pub armor: Vec<ChestItem>,
+    pub dyes: Vec<ChestItem>,
+    pub inventory: Vec<ChestItem>,
+    pub misc_equips: Vec<ChestItem>,
+    pub piggy_bank: Vec<ChestItem>,
+    pub safe: Vec<ChestItem>,
+    pub defenders_forge: Vec<ChestItem>,
+    pub void_vault: Vec<ChestItem>,
+    pub spawn_points: Vec<SpawnPoint>,
+}
+
+impl Player {
+    /// 玩家在指定世界的重生点（先按 ID，再按名称匹配）
+    pub fn spawn_point(&self, world_id: i32, world_name: &str) -> Option<&SpawnPoint> {
+        self.spawn_points
+            .iter()
+            .find(|point| point.world_id == world_id)
+            .or_else(|| self.spawn_points.iter().find(|point| point.world_name == world_name))
+    }
+}
+
+#[wasm_bindgen]
+pub struct PlayerLoader {}
+
+impl Default for PlayerLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl PlayerLoader {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[wasm_bindgen]
+    pub fn load_from_data(&self, data: &[u8]) -> Result<JsValue, JsValue> {
+        match self.parse_player(data) {
+            Ok(player) => Ok(serde_wasm_bindgen::to_value(&player)?),
+            Err(e) => Err(JsValue::from_str(&format!("Failed to load player: {}", e))),
+        }
+    }
+}
+
+impl PlayerLoader {
+    pub fn parse_player(&self, data: &[u8]) -> Result<Player, String> {
+        if data.is_empty() {
+            return Err(WorldLoadError::InvalidData {
+                message: "Player file is empty".to_string(),
+            }.into());
+        }
+
+        let decrypted = Aes128CbcDec::new(&PLAYER_KEY.into(), &PLAYER_KEY.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(data)
+            .map_err(|_| -> String {
+                WorldLoadError::InvalidFormat {
+                    expected: "encrypted player file".to_string(),
+                    found: format!("{} bytes that could not be decrypted", data.len()),
+                }.into()
+            })?;
+
+        let mut stream = DataStream::new(decrypted);
+        Self::require(&stream, 4)?;
+        let version = stream.read_int32();
+        if version < MIN_PLAYER_VERSION {
+            return Err(WorldLoadError::UnsupportedVersion { version }.into());
+        }
+
+        Self::require(&stream, 8 + 4 + 8)?;
+        let low = stream.read_uint32() as u64;
+        let high = stream.read_uint32() as u64;
+        let metadata = low | (high << 32);
+        if metadata & 0x00FF_FFFF_FFFF_FFFF != MAGIC || metadata >> 56 != PLAYER_FILE_TYPE {
+            return Err(WorldLoadError::InvalidFormat {
+                expected: "relogic player file".to_string(),
+                found: format!("{:#018x}", metadata),
+            }.into());
+        }
+        let _revision = stream.read_uint32();
+        let _favorite = stream.read_int64();
+
+        let name = Self::read_string(&mut stream)?;
+
+        // 难度、游玩时长、发型与外观
+        Self::require(&stream, 1 + 8 + 4 + 1 + 2 + 1 + 1)?;
+        let difficulty = stream.read_byte();
+        let play_time = stream.read_int64();
+        let _hair = stream.read_int32();
+        let _hair_dye = stream.read_byte();
+        stream.skip(2 + 1 + 1); // 隐藏的饰品、隐藏的杂项、皮肤
+
+        Self::require(&stream, 4 * 4)?;
+        let life = stream.read_int32();
+        let max_life = stream.read_int32();
+        let mana = stream.read_int32();
+        let max_mana = stream.read_int32();
+
+        // 已使用的永久增益与统计
+        let mut flags = 1 + 2; // extraAccessory, 生物群系火把
+        if version >= 256 {
+            flags += 1; // ateArtisanBread
+        }
+        if version >= 260 {
+            flags += 6; // 宝石、果实等永久增益
+        }
+        flags += 1; // downedDD2EventAnyDifficulty
+        Self::require(&stream, flags + 4)?;
+        stream.skip(flags);
+        let _tax_money = stream.read_int32();
+        if version >= 254 {
+            Self::require(&stream, 8)?;
+            stream.skip(8); // PVE、PVP 死亡次数
+        }
+
+        // 头发、皮肤、眼睛、衣服等 7 种颜色
+        Self::require(&stream, 7 * 3)?;
+        stream.skip(7 * 3);
+
+        let armor = Self::read_equips(&mut stream, ARMOR_SLOTS)?;
+        let dyes = Self::read_equips(&mut stream, DYE_SLOTS)?;
+        let inventory = Self::read_items(&mut stream, INVENTORY_SLOTS, true)?;
+
+        // 坐骑、宠物等杂项装备与其染料交替存储
+        let mut misc_equips = Vec::with_capacity(MISC_SLOTS);
+        for _ in 0..MISC_SLOTS {
+            misc_equips.extend(Self::read_equips(&mut stream, 1)?);
+            Self::read_equips(&mut stream, 1)?;
+        }
+
+        let piggy_bank = Self::read_items(&mut stream, BANK_SLOTS, false)?;
+        let safe = Self::read_items(&mut stream, BANK_SLOTS, false)?;
+        let defenders_forge = Self::read_items(&mut stream, BANK_SLOTS, false)?;
+        let void_vault = Self::read_items(&mut stream, BANK_SLOTS, version >= 255)?;
+        Self::require(&stream, 1)?;
+        let _void_vault_info = stream.read_byte();
+
+        // 增益：类型与剩余时间
+        let buff_count = if version >= 252 { 44 } else { 22 };
+        Self::require(&stream, buff_count * 8)?;
+        stream.skip(buff_count * 8);
+
+        // 重生点列表以 x = -1 结束
+        let mut spawn_points = Vec::new();
+        for _ in 0..MAX_SPAWN_POINTS {
+            Self::require(&stream, 4)?;
+            let x = stream.read_int32();
+            if x == -1 {
+                break;
+            }
+            Self::require(&stream, 8)?;
+            let y = stream.read_int32();
+            let world_id = stream.read_int32();
+            let world_name = Self::read_string(&mut stream)?;
+            spawn_points.push(SpawnPoint { x, y, world_id, world_name });
+        }
+
+        Ok(Player {
+            name,
+            version,
+            difficulty,
+            play_time,
+            life,
+            max_life,
+            mana,
+            max_mana,
+            armor,
+            dyes,
+            inventory,
+            misc_equips,
+            piggy_bank,
+            safe,
+            defenders_forge,
+            void_vault,
+            spawn_points,
+        })
+    }
+
+    // 装备栏：ID 与前缀，数量固定为 1，空位省略
+    fn read_equips(stream: &mut DataStream, count: usize) -> Result<Vec<ChestItem>, String> {
+        Self::require(stream, count * 5)?;
+        let mut items = Vec::new();
+        for _ in 0..count {
+            let id = stream.read_int32();
+            let prefix = stream.read_byte() as i32;
+            if id > 0 {
+                items.push(ChestItem { id, stack: 1, prefix });
+            }
+        }
+        Ok(items)
+    }
+
+    // 物品栏：ID、数量、前缀，以及可选的收藏标记，空位省略
+    fn read_items(stream: &mut DataStream, count: usize, favorited: bool) -> Result<Vec<ChestItem>, String> {
+        let item_len = if favorited { 10 } else { 9 };
+        Self::require(stream, count * item_len)?;
+        let mut items = Vec::new();
+        for _ in 0..count {
+            let id = stream.read_int32();
+            let stack = stream.read_int32();
+            let prefix = stream.read_byte() as i32;
+            if favorited {
+                let _favorited = stream.read_bool();
+            }
+            if id > 0 && stack > 0 {
+                items.push(ChestItem { id, stack, prefix });
+            }
+        }
+        Ok(items)
+    }
+
+    fn read_string(stream: &mut DataStream) -> Result<String, String> {
+        let len = WorldParser::string_len(stream, 0).ok_or_else(|| Self::truncated(stream))?;
+        Self::require(stream, len)?;
+        Ok(stream.read_string())
+    }
+
+    fn require(stream: &DataStream, count: usize) -> Result<(), String> {
+        if stream.remaining() < count {
+            return Err(Self::truncated(stream));
+        }
+        Ok(())
+    }
+
+    fn truncated(stream: &DataStream) -> String {
+        WorldLoadError::CorruptedData {
+            position: stream.position(),
+            message: "Unexpected end of player data".to_string(),
+        }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::{block_padding::NoPadding, BlockEncryptMut};
+
+    type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
+
+    fn encrypt(plain: &[u8]) -> Vec<u8> {
+        Aes128CbcEnc::new(&PLAYER_KEY.into(), &PLAYER_KEY.into()).encrypt_padded_vec_mut::<Pkcs7>(plain)
+    }
+
+    fn write_string(out: &mut Vec<u8>, text: &str) {
+        out.push(text.len() as u8);
+        out.extend(text.as_bytes());
+    }
+
+    // 版本 279 的玩家文件：背包第一格 1 个魔镜，一个重生点
+    fn player_data() -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend(279i32.to_le_bytes());
+        out.extend((MAGIC | PLAYER_FILE_TYPE << 56).to_le_bytes());
+        out.extend(1u32.to_le_bytes());
+        out.extend(0i64.to_le_bytes());
+        write_string(&mut out, "Guide");
+        out.push(3);
+        out.extend(36_000_000_000i64.to_le_bytes());
+        out.extend([0; 4 + 1 + 4]); // 发型、发色、隐藏标记、皮肤
+        for value in [400i32, 500, 200, 200] {
+            out.extend(value.to_le_bytes());
+        }
+        out.extend([0; 11 + 4 + 8 + 7 * 3]); // 永久增益、税金、死亡次数、颜色
+        out.extend(vec![0; (ARMOR_SLOTS + DYE_SLOTS) * 5]);
+        out.extend(50i32.to_le_bytes());
+        out.extend(1i32.to_le_bytes());
+        out.extend([0, 1]); // 前缀、收藏
+        out.extend(vec![0; (INVENTORY_SLOTS - 1) * 10 + MISC_SLOTS * 10]);
+        out.extend(vec![0; BANK_SLOTS * 9 * 3 + BANK_SLOTS * 10 + 1]);
+        out.extend(vec![0; 44 * 8]);
+        for value in [120i32, 300, 42] {
+            out.extend(value.to_le_bytes());
+        }
+        write_string(&mut out, "Test");
+        out.extend((-1i32).to_le_bytes());
+        out
+    }
+
+    fn error(data: &[u8]) -> String {
+        PlayerLoader::new().parse_player(data).err().unwrap()
+    }
+
+    #[test]
+    fn reads_an_encrypted_player() {
+        let player = PlayerLoader::new().parse_player(&encrypt(&player_data())).unwrap();
+        assert_eq!(player.name, "Guide");
+        assert_eq!((player.difficulty, player.max_life, player.max_mana), (3, 500, 200));
+        assert_eq!(player.inventory.len(), 1);
+        assert_eq!((player.inventory[0].id, player.inventory[0].stack), (50, 1));
+        let spawn = player.spawn_point(42, "Other").unwrap();
+        assert_eq!((spawn.x, spawn.y), (120, 300));
+        assert!(player.spawn_point(7, "Test").is_some());
+        assert!(player.spawn_point(7, "Other").is_none());
+    }
+
+    #[test]
+    fn rejects_data_that_is_not_whole_blocks() {
+        let mut data = encrypt(&player_data());
+        data.pop();
+        assert!(error(&data).contains("could not be decrypted"));
+    }
+
+    #[test]
+    fn rejects_invalid_padding() {
+        // 最后一个字节为 0 不是合法的 PKCS7 填充
+        let mut plain = player_data();
+        plain.resize(plain.len().next_multiple_of(16) + 16, 0);
+        let len = plain.len();
+        let data = Aes128CbcEnc::new(&PLAYER_KEY.into(), &PLAYER_KEY.into())
+            .encrypt_padded_vec_mut::<NoPadding>(&plain[..len]);
+        assert!(error(&data).contains("could not be decrypted"));
+
+        // 填充长度超过块大小
+        plain[len - 1] = 17;
+        let data = Aes128CbcEnc::new(&PLAYER_KEY.into(), &PLAYER_KEY.into())
+            .encrypt_padded_vec_mut::<NoPadding>(&plain);
+        assert!(error(&data).contains("could not be decrypted"));
+    }
+
+    #[test]
+    fn reports_truncated_player_data() {
+        let plain = player_data();
+        let error = error(&encrypt(&plain[..plain.len() - 10]));
+        assert!(error.contains("Unexpected end of player data"), "{}", error);
+    }
+}
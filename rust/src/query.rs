@@ -0,0 +1,936 @@
+// 搜索查询语言
+// 例：tile:"Chlorophyte Ore" AND y>1200 AND NOT wall:"Jungle Wall"
+//
+// 语法（关键字不区分大小写）：
+//   query     := and ("OR" and)*
+//   and       := unary ("AND" unary)*
+//   unary     := "NOT" unary | "(" query ")" | condition
+//   condition := field op value
+//   op        := ":" | "=" | "!=" | "<" | "<=" | ">" | ">="
+//   value     := "带空格的字符串" | 单词 | 数字
+//
+// 每个条件都在方块坐标上求值；箱子、告示牌、NPC 与物块实体的条件只在它们所在的坐标上成立
+// （物块实体为其左上角）。本格式只记录液体的数量、不记录类型，liquid 只能比较数量或为 any / none
+
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::name_registry::{NameKind, NameRegistry};
+use crate::search::TilePosition;
+use crate::search_area::AreaFilter;
+use crate::spatial_index::SpatialIndex;
+use crate::twld_loader::ModEntry;
+use crate::world_loader::{Chest, Sign, TileEntity, World, NPC};
+
+// 支持的字段，用于错误提示
+const FIELDS: [&str; 14] = [
+    "tile", "wall", "liquid", "wire", "paint", "wall.paint", "x", "y",
+    "chest.item", "chest.name", "sign.text", "npc", "entity", "frame.item",
+];
+
+// 物块实体类型名称，下标即类型编号
+const ENTITY_TYPES: [&str; 8] = [
+    "Training Dummy", "Item Frame", "Logic Sensor", "Display Doll", "Weapons Rack", "Hat Rack",
+    "Food Platter", "Teleportation Pylon",
+];
+
+// 物品框的实体类型
+const ITEM_FRAME: i32 = 1;
+
+// 表达式的最大深度（括号、NOT 与 AND/OR 链），限制解析与求值的递归深度
+const MAX_DEPTH: usize = 128;
+
+// 坐标比较可用的符号。本格式的世界头不含地层高度、出生点与地牢入口，只有世界尺寸
+const SYMBOLS: [&str; 2] = ["width", "height"];
+
+const WIRE_COLORS: [&str; 4] = ["red", "blue", "green", "yellow"];
+
+// 油漆编号从 1 开始
+const PAINT_NAMES: [&str; 31] = [
+    "Red", "Orange", "Yellow", "Lime", "Green", "Teal", "Cyan", "Sky Blue", "Blue", "Purple",
+    "Violet", "Pink", "Deep Red", "Deep Orange", "Deep Yellow", "Deep Lime", "Deep Green",
+    "Deep Teal", "Deep Cyan", "Deep Sky Blue", "Deep Blue", "Deep Purple", "Deep Violet",
+    "Deep Pink", "Black", "White", "Gray", "Brown", "Shadow", "Negative", "Illuminant",
+];
+
+#[derive(Debug, Clone)]
+pub struct QueryError {
+    pub column: usize, // 从 1 开始的字符位置
+    pub message: String,
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Query error at column {}: {}", self.column, self.message)
+    }
+}
+
+impl From<QueryError> for String {
+    fn from(error: QueryError) -> Self {
+        error.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub positions: Vec<TilePosition>,
+    pub total: usize, // 匹配的坐标总数，positions 可能被截断
+    pub chests: Vec<Chest>,
+    pub signs: Vec<Sign>,
+    pub npcs: Vec<NPC>,
+    #[serde(default)]
+    pub tile_entities: Vec<TileEntity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CmpOp {
+    Is, // ":"
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    fn compare(self, left: f64, right: f64) -> bool {
+        match self {
+            CmpOp::Is | CmpOp::Eq => left == right,
+            CmpOp::Ne => left != right,
+            CmpOp::Lt => left < right,
+            CmpOp::Le => left <= right,
+            CmpOp::Gt => left > right,
+            CmpOp::Ge => left >= right,
+        }
+    }
+
+    fn is_equality(self) -> bool {
+        matches!(self, CmpOp::Is | CmpOp::Eq | CmpOp::Ne)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Number(f64),
+    Op(CmpOp),
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    End,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Word(word) => format!("'{}'", word),
+            Token::Str(text) => format!("\"{}\"", text),
+            Token::Number(value) => format!("number {}", value),
+            Token::Op(_) => "an operator".to_string(),
+            Token::LParen => "'('".to_string(),
+            Token::RParen => "')'".to_string(),
+            Token::And => "AND".to_string(),
+            Token::Or => "OR".to_string(),
+            Token::Not => "NOT".to_string(),
+            Token::End => "end of query".to_string(),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, QueryError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        let column = pos + 1;
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        let token = match c {
+            '(' => {
+                pos += 1;
+                Token::LParen
+            }
+            ')' => {
+                pos += 1;
+                Token::RParen
+            }
+            ':' | '=' => {
+                pos += 1;
+                Token::Op(if c == ':' { CmpOp::Is } else { CmpOp::Eq })
+            }
+            '!' | '<' | '>' => {
+                let with_eq = chars.get(pos + 1) == Some(&'=');
+                let op = match (c, with_eq) {
+                    ('!', true) => CmpOp::Ne,
+                    ('<', true) => CmpOp::Le,
+                    ('<', false) => CmpOp::Lt,
+                    ('>', true) => CmpOp::Ge,
+                    ('>', false) => CmpOp::Gt,
+                    _ => {
+                        return Err(QueryError {
+                            column,
+                            message: "Expected '!=' (use NOT to negate a condition)".to_string(),
+                        })
+                    }
+                };
+                pos += if with_eq { 2 } else { 1 };
+                Token::Op(op)
+            }
+            '"' => {
+                let mut value = String::new();
+                pos += 1;
+                loop {
+                    match chars.get(pos) {
+                        None => {
+                            return Err(QueryError {
+                                column,
+                                message: "Unterminated string".to_string(),
+                            })
+                        }
+                        Some('"') => break,
+                        Some('\\') if pos + 1 < chars.len() => {
+                            value.push(chars[pos + 1]);
+                            pos += 2;
+                        }
+                        Some(&other) => {
+                            value.push(other);
+                            pos += 1;
+                        }
+                    }
+                }
+                pos += 1;
+                Token::Str(value)
+            }
+            _ if c.is_ascii_digit() || (c == '-' && chars.get(pos + 1).is_some_and(char::is_ascii_digit)) => {
+                let start = pos;
+                pos += 1;
+                while pos < chars.len() && (chars[pos].is_ascii_digit() || chars[pos] == '.') {
+                    pos += 1;
+                }
+                let literal: String = chars[start..pos].iter().collect();
+                let value = literal.parse().map_err(|_| QueryError {
+                    column,
+                    message: format!("Invalid number '{}'", literal),
+                })?;
+                Token::Number(value)
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = pos;
+                while pos < chars.len() && (chars[pos].is_alphanumeric() || matches!(chars[pos], '_' | '.' | '\'')) {
+                    pos += 1;
+                }
+                let word: String = chars[start..pos].iter().collect();
+                match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                }
+            }
+            _ => {
+                return Err(QueryError {
+                    column,
+                    message: format!("Unexpected character '{}'", c),
+                })
+            }
+        };
+        tokens.push((column, token));
+    }
+
+    tokens.push((chars.len() + 1, Token::End));
+    Ok(tokens)
+}
+
+enum Condition {
+    Tile(HashSet<i32>),
+    Wall(HashSet<i32>),
+    Liquid(CmpOp, f64),
+    Wire(usize),
+    AnyWire,
+    Actuator,
+    // None 表示任意油漆
+    Paint { wall: bool, color: Option<i32> },
+    X(CmpOp, f64),
+    Y(CmpOp, f64),
+    // 箱子、告示牌、NPC、物块实体所在的坐标
+    Objects(HashSet<(i32, i32)>),
+}
+
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Condition(Condition),
+}
+
+pub struct Query {
+    expr: Expr,
+}
+
+struct Parser<'a> {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    nesting: usize, // 当前所在的括号与 NOT 层数
+    world: &'a World,
+    names: &'a NameRegistry,
+}
+
+impl Query {
+    /// 解析查询，名称在解析时按注册表与模组数据解析为 ID
+    pub fn parse(text: &str, world: &World, names: &NameRegistry) -> Result<Query, QueryError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+            nesting: 0,
+            world,
+            names,
+        };
+        if parser.peek() == &Token::End {
+            return Err(QueryError {
+                column: 1,
+                message: "Query is empty".to_string(),
+            });
+        }
+        let (expr, _) = parser.parse_or()?;
+        if parser.peek() != &Token::End {
+            let (column, token) = &parser.tokens[parser.pos];
+            return Err(QueryError {
+                column: *column,
+                message: format!("Expected AND, OR or end of query, found {}", token.describe()),
+            });
+        }
+        Ok(Query { expr })
+    }
+
+    /// 在搜索范围内求值，positions 最多返回 limit 个（0 表示不限）
+    pub fn evaluate(&self, world: &World, index: &SpatialIndex, area: &AreaFilter, limit: usize) -> QueryResult {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        let mut positions = Vec::new();
+        let mut total = 0;
+        let mut record = |x: i32, y: i32| {
+            total += 1;
+            if positions.len() < limit {
+                positions.push(TilePosition::new(x, y));
+            }
+        };
+
+        // 能由对象与方块、墙体条件确定候选坐标时只检查这些坐标，否则逐格扫描
+        match candidates(&self.expr, world, index, area) {
+            Some(candidates) => {
+                let mut candidates: Vec<(i32, i32)> = candidates.into_iter().collect();
+                candidates.sort_by_key(|&(x, y)| (y, x));
+                for (x, y) in candidates {
+                    if area.contains(x, y) && self.matches(world, x, y) {
+                        record(x, y);
+                    }
+                }
+            }
+            None => {
+                for (x, y) in area.positions() {
+                    if self.matches(world, x, y) {
+                        record(x, y);
+                    }
+                }
+            }
+        }
+
+        let matches = |x: i32, y: i32| area.contains(x, y) && self.matches(world, x, y);
+        QueryResult {
+            positions,
+            total,
+            chests: world.chests.iter().filter(|c| matches(c.x, c.y)).cloned().collect(),
+            signs: world.signs.iter().filter(|s| matches(s.x, s.y)).cloned().collect(),
+            npcs: world
+                .npcs
+                .iter()
+                .filter(|npc| {
+                    let (x, y) = npc_tile(npc);
+                    matches(x, y)
+                })
+                .cloned()
+                .collect(),
+            tile_entities: world
+                .tile_entities
+                .iter()
+                .filter(|entity| matches(entity.position_x, entity.position_y))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn matches(&self, world: &World, x: i32, y: i32) -> bool {
+        eval(&self.expr, world, x, y)
+    }
+}
+
+// NPC 坐标以像素为单位（每格 16 像素）
+fn npc_tile(npc: &NPC) -> (i32, i32) {
+    ((npc.position_x / 16.0) as i32, (npc.position_y / 16.0) as i32)
+}
+
+fn eval(expr: &Expr, world: &World, x: i32, y: i32) -> bool {
+    match expr {
+        Expr::And(left, right) => eval(left, world, x, y) && eval(right, world, x, y),
+        Expr::Or(left, right) => eval(left, world, x, y) || eval(right, world, x, y),
+        Expr::Not(inner) => !eval(inner, world, x, y),
+        Expr::Condition(condition) => eval_condition(condition, world, x, y),
+    }
+}
+
+fn eval_condition(condition: &Condition, world: &World, x: i32, y: i32) -> bool {
+    match condition {
+        Condition::X(op, value) => return op.compare(x as f64, *value),
+        Condition::Y(op, value) => return op.compare(y as f64, *value),
+        Condition::Objects(positions) => return positions.contains(&(x, y)),
+        _ => {}
+    }
+
+    if x < 0 || y < 0 || x >= world.width || y >= world.height {
+        return false;
+    }
+    let idx = y as usize * world.width as usize + x as usize;
+    let tiles = &world.tiles;
+    match condition {
+        Condition::Tile(ids) => tiles.is_active(idx) && ids.contains(&tiles.tile_id(idx)),
+        Condition::Wall(ids) => ids.contains(&tiles.wall_id(idx)),
+        Condition::Liquid(op, value) => op.compare(tiles.liquid(idx) as f64, *value),
+        Condition::Wire(color) => tiles.wires(idx)[*color],
+        Condition::AnyWire => tiles.wires(idx).contains(&true),
+        Condition::Actuator => tiles.has_actuator(idx),
+        Condition::Paint { wall, color } => {
+            let paint = if *wall {
+                tiles.wall_color(idx)
+            } else if tiles.is_active(idx) {
+                tiles.color(idx)
+            } else {
+                0
+            };
+            match color {
+                Some(color) => paint == *color,
+                None => paint != 0,
+            }
+        }
+        Condition::X(..) | Condition::Y(..) | Condition::Objects(_) => unreachable!(),
+    }
+}
+
+// 可能匹配的坐标集合，方块与墙体条件从索引读取；None 表示需要扫描整个范围
+fn candidates(expr: &Expr, world: &World, index: &SpatialIndex, area: &AreaFilter) -> Option<HashSet<(i32, i32)>> {
+    let ids = |ids: &HashSet<i32>| ids.iter().copied().collect::<Vec<i32>>();
+    match expr {
+        Expr::Condition(Condition::Objects(positions)) => Some(positions.clone()),
+        Expr::Condition(Condition::Tile(tile_ids)) => Some(index.find_tiles(world, &ids(tile_ids), area).into_iter().collect()),
+        // 没有墙（id 0）不在索引中
+        Expr::Condition(Condition::Wall(wall_ids)) if !wall_ids.contains(&0) => {
+            Some(index.find_walls(world, &ids(wall_ids), area).into_iter().collect())
+        }
+        Expr::And(left, right) => match (candidates(left, world, index, area), candidates(right, world, index, area)) {
+            (Some(a), Some(b)) => Some(a.intersection(&b).copied().collect()),
+            (Some(set), None) | (None, Some(set)) => Some(set),
+            (None, None) => None,
+        },
+        Expr::Or(left, right) => {
+            let mut set = candidates(left, world, index, area)?;
+            set.extend(candidates(right, world, index, area)?);
+            Some(set)
+        }
+        _ => None,
+    }
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].1
+    }
+
+    fn next(&mut self) -> (usize, Token) {
+        let token = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    // 各 parse_* 同时返回表达式的深度，超过 MAX_DEPTH 时报错
+
+    fn parse_or(&mut self) -> Result<(Expr, usize), QueryError> {
+        let (mut expr, mut depth) = self.parse_and()?;
+        while self.peek() == &Token::Or {
+            let (column, _) = self.next();
+            let (right, right_depth) = self.parse_and()?;
+            depth = Self::check_depth(column, depth.max(right_depth) + 1)?;
+            expr = Expr::Or(Box::new(expr), Box::new(right));
+        }
+        Ok((expr, depth))
+    }
+
+    fn parse_and(&mut self) -> Result<(Expr, usize), QueryError> {
+        let (mut expr, mut depth) = self.parse_unary()?;
+        while self.peek() == &Token::And {
+            let (column, _) = self.next();
+            let (right, right_depth) = self.parse_unary()?;
+            depth = Self::check_depth(column, depth.max(right_depth) + 1)?;
+            expr = Expr::And(Box::new(expr), Box::new(right));
+        }
+        Ok((expr, depth))
+    }
+
+    fn parse_unary(&mut self) -> Result<(Expr, usize), QueryError> {
+        let (column, token) = self.next();
+        match token {
+            Token::Not => {
+                self.enter(column)?;
+                let (inner, depth) = self.parse_unary()?;
+                self.nesting -= 1;
+                Ok((Expr::Not(Box::new(inner)), Self::check_depth(column, depth + 1)?))
+            }
+            Token::LParen => {
+                self.enter(column)?;
+                let (expr, depth) = self.parse_or()?;
+                self.nesting -= 1;
+                let (close_column, close) = self.next();
+                if close != Token::RParen {
+                    return Err(QueryError {
+                        column: close_column,
+                        message: format!("Expected ')' to close '(' at column {}, found {}", column, close.describe()),
+                    });
+                }
+                Ok((expr, depth))
+            }
+            // 条件可能被 "!=" 包上一层 NOT
+            Token::Word(field) => Ok((self.parse_condition(column, &field)?, 2)),
+            other => Err(QueryError {
+                column,
+                message: format!("Expected a condition such as tile:\"Gold Ore\", found {}", other.describe()),
+            }),
+        }
+    }
+
+    // 进入括号或 NOT 前检查层数，避免解析时递归过深
+    fn enter(&mut self, column: usize) -> Result<(), QueryError> {
+        self.nesting += 1;
+        Self::check_depth(column, self.nesting).map(|_| ())
+    }
+
+    fn check_depth(column: usize, depth: usize) -> Result<usize, QueryError> {
+        if depth > MAX_DEPTH {
+            return Err(QueryError {
+                column,
+                message: format!("Query is nested too deeply (at most {} levels of parentheses, NOT, AND and OR)", MAX_DEPTH),
+            });
+        }
+        Ok(depth)
+    }
+
+    fn parse_condition(&mut self, column: usize, field: &str) -> Result<Expr, QueryError> {
+        let field = field.to_ascii_lowercase();
+        if !FIELDS.contains(&field.as_str()) {
+            return Err(QueryError {
+                column,
+                message: format!("Unknown field '{}', expected one of: {}", field, FIELDS.join(", ")),
+            });
+        }
+
+        let (op_column, op) = match self.next() {
+            (op_column, Token::Op(op)) => (op_column, op),
+            (op_column, other) => {
+                return Err(QueryError {
+                    column: op_column,
+                    message: format!("Expected ':' or a comparison after '{}', found {}", field, other.describe()),
+                })
+            }
+        };
+        let (value_column, value) = self.next();
+        let text = match &value {
+            Token::Word(word) => word.clone(),
+            Token::Str(text) => text.clone(),
+            Token::Number(number) => number.to_string(),
+            other => {
+                return Err(QueryError {
+                    column: value_column,
+                    message: format!("Expected a value after '{}', found {}", field, other.describe()),
+                })
+            }
+        };
+        let number = match value {
+            Token::Number(number) => Some(number),
+            _ => None,
+        };
+
+        let error = |message: String| QueryError {
+            column: value_column,
+            message,
+        };
+        let require_equality = || -> Result<(), QueryError> {
+            if op.is_equality() {
+                Ok(())
+            } else {
+                Err(QueryError {
+                    column: op_column,
+                    message: format!("'{}' only supports ':', '=' and '!='", field),
+                })
+            }
+        };
+
+        let condition = match field.as_str() {
+            "tile" => {
+                require_equality()?;
+                Condition::Tile(self.resolve_ids(NameKind::Tile, &text, number, value_column)?)
+            }
+            "wall" => {
+                require_equality()?;
+                Condition::Wall(self.resolve_ids(NameKind::Wall, &text, number, value_column)?)
+            }
+            "liquid" => match (number, text.to_ascii_lowercase().as_str()) {
+                (Some(amount), _) => Condition::Liquid(op, amount),
+                (None, "any") => Condition::Liquid(if op == CmpOp::Ne { CmpOp::Eq } else { CmpOp::Gt }, 0.0),
+                (None, "none") => Condition::Liquid(if op == CmpOp::Ne { CmpOp::Gt } else { CmpOp::Eq }, 0.0),
+                _ => {
+                    return Err(error(format!(
+                        "Liquid types are not recorded in this world format; use liquid:any, liquid:none or compare the amount (liquid>128), found '{}'",
+                        text
+                    )))
+                }
+            },
+            "wire" => {
+                require_equality()?;
+                let lower = text.to_ascii_lowercase();
+                let condition = match lower.as_str() {
+                    "any" => Condition::AnyWire,
+                    "actuator" => Condition::Actuator,
+                    _ => match WIRE_COLORS.iter().position(|color| *color == lower) {
+                        Some(color) => Condition::Wire(color),
+                        None => {
+                            return Err(error(format!(
+                                "Unknown wire '{}', expected red, blue, green, yellow, actuator or any",
+                                text
+                            )))
+                        }
+                    },
+                };
+                return Ok(negate_if(op, Expr::Condition(condition)));
+            }
+            "paint" | "wall.paint" => {
+                require_equality()?;
+                let color = match (number, text.to_ascii_lowercase().as_str()) {
+                    (Some(id), _) => Some(id as i32),
+                    (None, "any") => None,
+                    (None, "none") => Some(0),
+                    (None, lower) => match PAINT_NAMES.iter().position(|name| name.to_ascii_lowercase() == lower) {
+                        Some(index) => Some(index as i32 + 1),
+                        None => {
+                            return Err(error(format!(
+                                "Unknown paint '{}', expected a paint name such as \"Deep Red\", an id, any or none",
+                                text
+                            )))
+                        }
+                    },
+                };
+                Condition::Paint {
+                    wall: field == "wall.paint",
+                    color,
+                }
+            }
+            "x" | "y" => {
+                let value = match number {
+                    Some(number) => number,
+                    None => self.symbol(&text).map_err(error)?,
+                };
+                // ":" 在坐标比较中等同于 "="
+                if field == "x" {
+                    Condition::X(op, value)
+                } else {
+                    Condition::Y(op, value)
+                }
+            }
+            "chest.item" => {
+                require_equality()?;
+                let ids = self.resolve_ids(NameKind::Item, &text, number, value_column)?;
+                Condition::Objects(
+                    self.world
+                        .chests
+                        .iter()
+                        .filter(|chest| chest.items.iter().any(|item| ids.contains(&item.id)))
+                        .map(|chest| (chest.x, chest.y))
+                        .collect(),
+                )
+            }
+            "chest.name" => {
+                require_equality()?;
+                let matches = text_matcher(op, &text);
+                Condition::Objects(
+                    self.world
+                        .chests
+                        .iter()
+                        .filter(|chest| matches(&chest.name))
+                        .map(|chest| (chest.x, chest.y))
+                        .collect(),
+                )
+            }
+            "sign.text" => {
+                require_equality()?;
+                let matches = text_matcher(op, &text);
+                Condition::Objects(
+                    self.world
+                        .signs
+                        .iter()
+                        .filter(|sign| matches(&sign.text))
+                        .map(|sign| (sign.x, sign.y))
+                        .collect(),
+                )
+            }
+            "npc" => {
+                require_equality()?;
+                let matches = text_matcher(op, &text);
+                Condition::Objects(
+                    self.world
+                        .npcs
+                        .iter()
+                        .filter(|npc| {
+                            let type_name = self.names.display_name(NameKind::Npc, npc.sprite_id).unwrap_or_default();
+                            let english = NameRegistry::entry(NameKind::Npc, npc.sprite_id).map_or("", |entry| entry.name);
+                            matches(&npc.name) || matches(&type_name) || matches(english)
+                        })
+                        .map(npc_tile)
+                        .collect(),
+                )
+            }
+            "entity" => {
+                require_equality()?;
+                let entity_type = match number {
+                    Some(id) => id as i32,
+                    None => entity_type(&text).ok_or_else(|| {
+                        error(format!(
+                            "Unknown tile entity '{}', expected one of: {}",
+                            text,
+                            ENTITY_TYPES.join(", ")
+                        ))
+                    })?,
+                };
+                Condition::Objects(
+                    self.world
+                        .tile_entities
+                        .iter()
+                        .filter(|entity| entity.entity_type == entity_type)
+                        .map(|entity| (entity.position_x, entity.position_y))
+                        .collect(),
+                )
+            }
+            "frame.item" => {
+                require_equality()?;
+                let ids = self.resolve_ids(NameKind::Item, &text, number, value_column)?;
+                Condition::Objects(
+                    self.world
+                        .tile_entities
+                        .iter()
+                        .filter(|entity| entity.entity_type == ITEM_FRAME && entity.items.iter().any(|item| ids.contains(&item.id)))
+                        .map(|entity| (entity.position_x, entity.position_y))
+                        .collect(),
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        let expr = Expr::Condition(condition);
+        // 坐标、液体已按运算符比较，其余字段的 "!=" 取反
+        Ok(match field.as_str() {
+            "x" | "y" | "liquid" => expr,
+            _ => negate_if(op, expr),
+        })
+    }
+
+    // 名称（注册表中的显示名称、内部名或模组的 "ModName:Name"）或数字 ID
+    fn resolve_ids(&self, kind: NameKind, text: &str, number: Option<f64>, column: usize) -> Result<HashSet<i32>, QueryError> {
+        if let Some(id) = number {
+            return Ok(HashSet::from([id as i32]));
+        }
+
+        let mut ids: HashSet<i32> = self.names.id_of(kind, text).into_iter().collect();
+        let mods = &self.world.mods;
+        let entries = match kind {
+            NameKind::Tile => Some(&mods.tiles),
+            NameKind::Wall => Some(&mods.walls),
+            NameKind::Item => Some(&mods.items),
+            NameKind::Npc => Some(&mods.npcs),
+        };
+        let matches_mod = |entry: &ModEntry| entry.label().eq_ignore_ascii_case(text) || entry.name.eq_ignore_ascii_case(text);
+        ids.extend(entries.into_iter().flatten().filter(|(_, entry)| matches_mod(entry)).map(|(&id, _)| id));
+
+        if ids.is_empty() {
+            let suggestions: Vec<String> = self
+                .names
+                .suggest(text, &[kind], 3)
+                .into_iter()
+                .map(|candidate| format!("\"{}\"", candidate.name))
+                .collect();
+            let hint = if suggestions.is_empty() {
+                String::new()
+            } else {
+                format!(", did you mean {}?", suggestions.join(", "))
+            };
+            return Err(QueryError {
+                column,
+                message: format!("Unknown {} \"{}\"{}", format!("{:?}", kind).to_lowercase(), text, hint),
+            });
+        }
+        Ok(ids)
+    }
+
+    fn symbol(&self, name: &str) -> Result<f64, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "width" => Ok(self.world.width as f64),
+            "height" => Ok(self.world.height as f64),
+            _ => Err(format!(
+                "Unknown value '{}', expected a number or one of: {}",
+                name,
+                SYMBOLS.join(", ")
+            )),
+        }
+    }
+}
+
+// 实体类型名称不区分大小写，忽略空格与下划线（"item_frame"、"ItemFrame"）
+fn entity_type(text: &str) -> Option<i32> {
+    let normalize = |name: &str| -> String {
+        name.chars()
+            .filter(|c| !matches!(c, ' ' | '_'))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let wanted = normalize(text);
+    ENTITY_TYPES
+        .iter()
+        .position(|name| normalize(name) == wanted)
+        .map(|index| index as i32)
+}
+
+fn negate_if(op: CmpOp, expr: Expr) -> Expr {
+    if op == CmpOp::Ne {
+        Expr::Not(Box::new(expr))
+    } else {
+        expr
+    }
+}
+
+// ":" 为不区分大小写的包含，"=" 与 "!=" 为不区分大小写的完全相等（"!=" 由调用方取反）
+fn text_matcher(op: CmpOp, text: &str) -> impl Fn(&str) -> bool {
+    let needle = text.to_lowercase();
+    move |haystack: &str| {
+        let haystack = haystack.to_lowercase();
+        if op == CmpOp::Is {
+            haystack.contains(&needle)
+        } else {
+            haystack == needle
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{self, chest, tile, tile_entity};
+
+    // 一行 6 格：方块 1 2 3 1 2 3，第 1、4 格有墙 5，第 2 格有液体
+    fn sample() -> World {
+        let mut tiles: Vec<_> = [1, 2, 3, 1, 2, 3].into_iter().map(tile).collect();
+        tiles[1].wall_id = 5;
+        tiles[4].wall_id = 5;
+        tiles[2].liquid = 200;
+        let mut world = test_fixtures::world(6, 1, &tiles);
+        world.chests.push(chest(0, 0, "Loot", &[(29, 1)]));
+        world.tile_entities.push(tile_entity(1, 3, 0, &[(29, 1)]));
+        world.tile_entities.push(tile_entity(4, 5, 0, &[(29, 1)]));
+        world
+    }
+
+    fn xs(world: &World, text: &str) -> Vec<i32> {
+        let query = Query::parse(text, world, &NameRegistry::new()).unwrap_or_else(|e| panic!("{}: {}", text, e));
+        let index = SpatialIndex::build(world);
+        query
+            .evaluate(world, &index, &AreaFilter::whole(world), 0)
+            .positions
+            .iter()
+            .map(|position| position.x)
+            .collect()
+    }
+
+    fn parse_error(world: &World, text: &str) -> QueryError {
+        match Query::parse(text, world, &NameRegistry::new()) {
+            Ok(_) => panic!("{} should not parse", text),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let world = sample();
+        assert_eq!(xs(&world, "tile:1 OR tile:2 AND wall:5"), vec![0, 1, 3, 4]);
+        assert_eq!(xs(&world, "tile:2 AND wall:5 OR tile:1"), vec![0, 1, 3, 4]);
+        assert_eq!(xs(&world, "(tile:1 OR tile:2) AND wall:5"), vec![1, 4]);
+        assert_eq!(xs(&world, "tile:3 or x<2 and tile:1"), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn not_applies_to_the_next_condition_only() {
+        let world = sample();
+        assert_eq!(xs(&world, "NOT tile:1 AND x<3"), vec![1, 2]);
+        assert_eq!(xs(&world, "NOT (tile:1 OR tile:2)"), vec![2, 5]);
+        assert_eq!(xs(&world, "NOT NOT tile:3"), vec![2, 5]);
+        assert_eq!(xs(&world, "tile!=1 AND wall!=5"), vec![2, 5]);
+    }
+
+    #[test]
+    fn nesting_is_limited_to_max_depth() {
+        let world = sample();
+        let nested = |levels: usize| format!("{}tile:1{}", "(".repeat(levels), ")".repeat(levels));
+        assert_eq!(xs(&world, &nested(MAX_DEPTH)), vec![0, 3]);
+        let error = parse_error(&world, &nested(MAX_DEPTH + 1));
+        assert_eq!(error.column, MAX_DEPTH + 1);
+        assert!(error.message.contains("nested too deeply"));
+
+        let chain = |count: usize| vec!["tile:1"; count].join(" OR ");
+        assert_eq!(xs(&world, &chain(MAX_DEPTH / 2)), vec![0, 3]);
+        assert!(parse_error(&world, &chain(MAX_DEPTH * 2)).message.contains("nested too deeply"));
+    }
+
+    #[test]
+    fn tile_entities_match_at_their_position() {
+        let world = sample();
+        assert_eq!(xs(&world, "entity:\"Item Frame\""), vec![3]);
+        assert_eq!(xs(&world, "entity:weapons_rack OR entity:1"), vec![3, 5]);
+        // 武器架上的同一物品不算
+        assert_eq!(xs(&world, "frame.item:29"), vec![3]);
+        assert_eq!(xs(&world, "chest.item:29 OR frame.item:29"), vec![0, 3]);
+
+        let query = Query::parse("entity:\"Item Frame\"", &world, &NameRegistry::new()).unwrap();
+        let result = query.evaluate(&world, &SpatialIndex::build(&world), &AreaFilter::whole(&world), 0);
+        assert_eq!(result.tile_entities.len(), 1);
+        assert!(parse_error(&world, "entity:Mannequin").message.contains("Display Doll"));
+    }
+
+    #[test]
+    fn liquid_compares_the_amount_only() {
+        let world = sample();
+        assert_eq!(xs(&world, "liquid:any"), vec![2]);
+        assert_eq!(xs(&world, "liquid>100 OR liquid!=any"), vec![0, 1, 2, 3, 4, 5]);
+        assert!(parse_error(&world, "liquid:lava").message.contains("not recorded"));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_column() {
+        let world = sample();
+        assert_eq!(parse_error(&world, "tile:1 AND").column, 11);
+        assert_eq!(parse_error(&world, "colour:red").column, 1);
+        assert_eq!(parse_error(&world, "x > spawn_x").column, 5);
+        assert_eq!(parse_error(&world, "(tile:1").column, 8);
+        assert_eq!(parse_error(&world, "tile < 3").column, 6);
+    }
+}
@@ -0,0 +1,375 @@
+// 损坏世界的恢复模式
+// 按位置表分别解析各个区段，无法读取的方块用哨兵方块填充，并记录警告；
+// 其余区段与正常加载读取相同的内容，见 sections
+// 由 WorldParser 分步执行，可以在两步之间取消
+
+use serde::{Deserialize, Serialize};
+use crate::colors::Rgb;
+use crate::data_stream::DataStream;
+use crate::tile_storage::TileStorage;
+use crate::sections;
+use crate::world_loader::{Tile, World, WorldLoader};
+use crate::world_parser::WorldParser;
+
+// 损坏方块的哨兵 ID，超出所有原版方块 ID
+pub const DAMAGED_TILE_ID: i32 = u16::MAX as i32;
+
+// 损坏区域的显示颜色
+pub const DAMAGED_COLOR: Rgb = Rgb { r: 255, g: 0, b: 255 };
+
+// 位置表中各区段的名称，对应 positions[0..]
+const SECTION_NAMES: [&str; 10] = [
+    "header",
+    "tiles",
+    "chests",
+    "signs",
+    "npcs",
+    "tile_entities",
+    "pressure_plates",
+    "town_manager",
+    "bestiary",
+    "creative_powers",
+];
+
+// 方块区域（方块坐标）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DamagedArea {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseWarning {
+    pub section: String,
+    pub offset: usize,
+    pub reason: String,
+    // 受影响的方块区域，非方块区段为空
+    pub areas: Vec<DamagedArea>,
+}
+
+impl Tile {
+    /// 填充无法读取位置的哨兵方块
+    pub fn damaged() -> Self {
+        Tile {
+            tile_id: DAMAGED_TILE_ID,
+            wall_id: 0,
+            liquid: 0,
+            is_active: true,
+            is_actuated: false,
+            color: 0,
+            u: 0,
+            v: 0,
+            brick_style: 0,
+            full: false,
+            half_brick: false,
+            slope: 0,
+            wire_red: false,
+            wire_blue: false,
+            wire_green: false,
+            wire_yellow: false,
+            actuator: false,
+            in_active: false,
+            wall_color: 0,
+            wall_u: 0,
+            wall_v: 0,
+            wall_full: false,
+            wall_half_brick: false,
+            wall_slope: 0,
+        }
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.is_active && self.tile_id == DAMAGED_TILE_ID
+    }
+}
+
+// 重新对齐时要求连续读出的合理方块记录数
+const RESYNC_WINDOW: usize = 64;
+
+// 恢复模式下的读取状态，由 WorldParser 分步驱动：
+// 方块区段出错时跳到下一处能连续读出合理记录的位置继续，其余区段按位置表各自读取
+pub(crate) struct TileRecovery {
+    version: i32,
+    positions: Vec<i32>,
+    tiles_end: usize,
+    warnings: Vec<ParseWarning>,
+}
+
+impl TileRecovery {
+    /// 在世界头之后开始（与 parse_world 相同），需要完整的文件数据以检查位置表
+    pub(crate) fn begin(stream: &mut DataStream, positions: &[i32]) -> Self {
+        let tiles_start = stream.position();
+        stream.seek(0);
+        let version = stream.read_int32();
+        stream.seek(tiles_start);
+
+        // 位置表指向文件之外的区段无法读取
+        let mut warnings = Vec::new();
+        for (index, &offset) in positions.iter().enumerate() {
+            if offset < 0 || offset as usize > stream.len() {
+                warnings.push(ParseWarning {
+                    section: WorldLoader::section_name(index),
+                    offset: offset.max(0) as usize,
+                    reason: format!("Section offset is outside the file ({} bytes)", stream.len()),
+                    areas: Vec::new(),
+                });
+            }
+        }
+
+        // 方块区段到箱子区段为止
+        let tiles_end = sections::section_range(positions, sections::CHESTS, stream.len())
+            .map(|(start, _)| start)
+            .filter(|&end| end > tiles_start)
+            .unwrap_or(stream.len());
+        Self {
+            version,
+            positions: positions.to_vec(),
+            tiles_end,
+            warnings,
+        }
+    }
+
+    /// 最多读取 budget 个方块，损坏的方块用哨兵方块填充
+    pub(crate) fn read_tiles(
+        &mut self,
+        loader: &WorldLoader,
+        stream: &mut DataStream,
+        tiles: &mut TileStorage,
+        width: i32,
+        height: i32,
+        budget: &mut usize,
+    ) {
+        let tile_count = (width * height) as usize;
+        while *budget > 0 && tiles.len() < tile_count {
+            let idx = tiles.len();
+            let offset = stream.position();
+            match Self::read_tile_checked(loader, stream, self.tiles_end) {
+                Ok(tile) => tiles.push(&tile),
+                Err(reason) => {
+                    let resume = self.resync(stream, offset, idx, tile_count, width as usize);
+                    let end = resume.unwrap_or(tile_count);
+                    self.warnings.push(ParseWarning {
+                        section: WorldLoader::section_name(1),
+                        offset,
+                        reason,
+                        areas: WorldLoader::damaged_areas(idx, end, width),
+                    });
+                    let damaged = Tile::damaged();
+                    while tiles.len() < end {
+                        tiles.push(&damaged);
+                    }
+                }
+            }
+            *budget -= 1;
+        }
+    }
+
+    // 从损坏处之后逐字节寻找能连续读出 RESYNC_WINDOW 个合理记录的位置，定位到该处并返回从哪个方块继续。
+    // 若从该处一直读到区段末尾都合理，按区段末尾对齐：最后一条记录对应最后一个方块，
+    // 多出的记录是错位读出的，跳过（出错的方块本身至少标记一个）；否则从下一行开头继续。
+    // 找不到时返回 None，剩余方块全部视为损坏
+    fn resync(&self, stream: &mut DataStream, offset: usize, idx: usize, tile_count: usize, width: usize) -> Option<usize> {
+        let remaining = tile_count - idx;
+        let candidate = (offset + 1..self.tiles_end).find(|&candidate| {
+            let (count, end) = self.walk(stream, candidate, RESYNC_WINDOW);
+            count == RESYNC_WINDOW || end == self.tiles_end
+        })?;
+
+        let (count, end) = self.walk(stream, candidate, usize::MAX);
+        if end == self.tiles_end {
+            let kept = count.min(remaining - 1);
+            self.walk(stream, candidate, count - kept);
+            return Some(tile_count - kept);
+        }
+        stream.seek(candidate);
+        let next_row = (idx / width + 1) * width;
+        (next_row < tile_count).then_some(next_row)
+    }
+
+    // 从 offset 起连续跳过合理的方块记录，最多 limit 个；返回记录数与停下的位置
+    fn walk(&self, stream: &mut DataStream, offset: usize, limit: usize) -> (usize, usize) {
+        stream.seek(offset);
+        let mut count = 0;
+        while count < limit {
+            match Self::check_record(stream, self.tiles_end) {
+                Ok(len) => stream.skip(len),
+                Err(_) => break,
+            }
+            count += 1;
+        }
+        (count, stream.position())
+    }
+
+    // 读取单个方块前检查记录是否完整、合理
+    fn read_tile_checked(loader: &WorldLoader, stream: &mut DataStream, end: usize) -> Result<Tile, String> {
+        Self::check_record(stream, end)?;
+        Ok(loader.read_tile(stream))
+    }
+
+    // 返回当前位置方块记录的长度：记录必须在区段内，布尔字节只能是 0 或 1，形状不超过 5
+    fn check_record(stream: &DataStream, end: usize) -> Result<usize, String> {
+        let len = WorldParser::tile_len(stream)
+            .filter(|&len| len <= stream.remaining())
+            .ok_or("Unexpected end of data")?;
+        if stream.position() + len > end {
+            return Err("Tile record crosses the end of the tile section".to_string());
+        }
+
+        let byte = |offset: usize| stream.peek_byte(offset).unwrap_or(0);
+        let mut flags = vec![0];
+        let mut shapes = Vec::new();
+        if len > 1 {
+            // 方块与墙 id 之后：是否有液体（及液体量）、促动、是否有颜色、是否有墙体颜色
+            let mut pos = 5;
+            for optional in [2, 0, 1, 1] {
+                flags.push(pos);
+                pos += 1;
+                if optional > 0 && byte(pos - 1) != 0 {
+                    pos += optional;
+                }
+            }
+            // 帧、样式、完整、半砖、斜坡
+            pos += 4;
+            shapes.push(pos);
+            flags.extend([pos + 1, pos + 2]);
+            shapes.push(pos + 3);
+            // 四种电线、促动器、未激活、墙体帧、墙体完整、半砖、斜坡
+            pos += 4;
+            flags.extend(pos..pos + 6);
+            pos += 10;
+            flags.extend([pos, pos + 1]);
+            shapes.push(pos + 2);
+        }
+
+        if let Some(&pos) = flags.iter().find(|&&pos| byte(pos) > 1) {
+            return Err(format!("Invalid flag byte {} at record offset {}", byte(pos), pos));
+        }
+        if let Some(&pos) = shapes.iter().find(|&&pos| byte(pos) > 5) {
+            return Err(format!("Invalid tile shape {} at record offset {}", byte(pos), pos));
+        }
+        Ok(len)
+    }
+
+    /// 方块读取完毕后，按位置表各自读取箱子、告示牌、NPC 与物块实体区段，出错的区段保留已读部分
+    pub(crate) fn finish(mut self, stream: &mut DataStream, world: &mut World) {
+        let warnings = &mut self.warnings;
+        let _ = sections::read_sections(stream, &self.positions, self.version, world, |index, offset, reason| {
+            warnings.push(ParseWarning {
+                section: WorldLoader::section_name(index),
+                offset,
+                reason,
+                areas: Vec::new(),
+            });
+            Ok(())
+        });
+        world.warnings = self.warnings;
+    }
+}
+
+impl WorldLoader {
+    /// 一次性完成恢复模式解析；需要分步解析或取消时使用 WorldParser::set_recovery_mode
+    pub fn parse_world_lossy(&self, data: Vec<u8>) -> Result<World, String> {
+        let mut parser = WorldParser::with_data(data, true);
+        parser.step_internal(usize::MAX)?;
+        parser
+            .take_world()
+            .ok_or_else(|| "World parsing did not finish".to_string())
+    }
+
+    // 第 start 到 end 个方块（行优先）覆盖的区域：首行剩余部分、中间的整行、末行开头部分
+    fn damaged_areas(start: usize, end: usize, width: i32) -> Vec<DamagedArea> {
+        let width = width as usize;
+        let mut areas = Vec::new();
+        let mut row = |x0: usize, y0: usize, x1: usize, rows: usize| {
+            if x1 > x0 && rows > 0 {
+                areas.push(DamagedArea {
+                    x: x0 as i32,
+                    y: y0 as i32,
+                    width: (x1 - x0) as i32,
+                    height: rows as i32,
+                });
+            }
+        };
+        let (first_row, last_row) = (start / width, end / width);
+        if first_row == last_row {
+            row(start % width, first_row, end % width, 1);
+            return areas;
+        }
+        let mut full_start = first_row;
+        if !start.is_multiple_of(width) {
+            row(start % width, first_row, width, 1);
+            full_start += 1;
+        }
+        row(0, full_start, width, last_row - full_start);
+        row(0, last_row, end % width, 1);
+        areas
+    }
+
+    pub(crate) fn section_name(index: usize) -> String {
+        SECTION_NAMES
+            .get(index)
+            .map_or_else(|| format!("section_{}", index), |name| name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{self, chest, npc, sign, tile};
+
+    const WIDTH: i32 = 80;
+    const HEIGHT: i32 = 4;
+
+    fn sample() -> (World, Vec<Tile>) {
+        let tiles: Vec<Tile> = (0..WIDTH * HEIGHT).map(|i| tile(1 + i % 7)).collect();
+        let mut world = test_fixtures::world(WIDTH, HEIGHT, &tiles);
+        world.chests.push(chest(3, 1, "Loot", &[(29, 1), (75, 20)]));
+        world.signs.push(sign(10, 2, "Hello"));
+        world.npcs.push(npc(22, "Andrew", 40, 3));
+        (world, tiles)
+    }
+
+    #[test]
+    fn resyncs_after_a_corrupted_tile_record() {
+        let (world, tiles) = sample();
+        let mut file = test_fixtures::world_file(&world, &tiles);
+        // 第 90 个方块（第 1 行 x = 10）的液体标志改为非法值
+        file.bytes[file.tile_offsets[90] + 5] = 7;
+
+        let world = WorldLoader::new().parse_world_lossy(file.bytes).unwrap();
+        assert_eq!(world.tiles.len(), tiles.len());
+        // 从损坏处之后重新对齐到区段末尾，只有出错的方块标记为损坏
+        assert!(world.tiles.get(90).unwrap().is_damaged());
+        for (idx, tile) in tiles.iter().enumerate().filter(|&(idx, _)| idx != 90) {
+            assert_eq!(world.tiles.tile_id(idx), tile.tile_id, "tile {}", idx);
+        }
+
+        assert_eq!(world.warnings.len(), 1);
+        let warning = &world.warnings[0];
+        assert_eq!(warning.section, "tiles");
+        assert_eq!(warning.offset, file.tile_offsets[90]);
+        assert_eq!(warning.areas, vec![DamagedArea { x: 10, y: 1, width: 1, height: 1 }]);
+        // 方块之后的区段按位置表读取，不受影响
+        assert_eq!(world.chests.len(), 1);
+        assert_eq!(world.signs.len(), 1);
+        assert_eq!(world.npcs.len(), 1);
+    }
+
+    #[test]
+    fn damaged_section_keeps_what_was_read() {
+        let (mut world, tiles) = sample();
+        world.signs.push(sign(11, 2, "Second"));
+        let mut file = test_fixtures::world_file(&world, &tiles);
+        // 第二个告示牌的 x 坐标改到世界之外
+        let signs_end = file.positions[4] as usize;
+        file.bytes[signs_end - 8..signs_end - 4].copy_from_slice(&(WIDTH + 5).to_le_bytes());
+
+        let world = WorldLoader::new().parse_world_lossy(file.bytes).unwrap();
+        assert_eq!(world.signs.len(), 1);
+        assert_eq!(world.warnings.len(), 1);
+        assert_eq!(world.warnings[0].section, "signs");
+        assert_eq!(world.npcs.len(), 1);
+    }
+}
@@ -1,16 +1,138 @@
 // 渲染器
 // 对应原项目的 MapHelper.js 渲染部分
 
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::*;
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+use wasm_bindgen::Clamped;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+use serde::{Deserialize, Serialize};
+use crate::cancellation::{cancelled_error, CancellationToken};
+use crate::chunk_cache::{CacheKey, ChunkCache, CHUNK_SIZE};
+use crate::colors::{Rgba, TileColors};
+use crate::map_file::MapFile;
+use crate::mipmap::{MipLevel, MipmapPyramid, ReductionMode};
+use crate::world_handle::WorldHandle;
 use crate::world_loader::{World, Tile};
 
+// 电线颜色，对应 settings.js GlobalColors 中的 Wire / Wire1 / Wire2（alpha 0x70）
+const WIRE_RED_COLOR: Rgba = Rgba::new(255, 0, 0, 0x70);
+const WIRE_BLUE_COLOR: Rgba = Rgba::new(0, 0, 255, 0x70);
+const WIRE_GREEN_COLOR: Rgba = Rgba::new(0, 255, 0, 0x70);
+// settings.js 中没有黄色电线，使用相同透明度的黄色
+const WIRE_YELLOW_COLOR: Rgba = Rgba::new(255, 255, 0, 0x70);
+const ACTUATOR_COLOR: Rgba = Rgba::new(200, 200, 200, 230);
+const ACTUATED_DIM_COLOR: Rgba = Rgba::new(0, 0, 0, 128);
+const HIGHLIGHT_FILL_COLOR: Rgba = Rgba::new(255, 255, 0, 128);
+const DAMAGED_OUTLINE_COLOR: Rgba = Rgba::new(255, 0, 255, 230);
+
+// 覆盖层的绘制顺序，下标即 overlay_mask 中的位
+const OVERLAY_ORDER: [Rgba; 6] = [
+    ACTUATED_DIM_COLOR,
+    WIRE_RED_COLOR,
+    WIRE_BLUE_COLOR,
+    WIRE_GREEN_COLOR,
+    WIRE_YELLOW_COLOR,
+    ACTUATOR_COLOR,
+];
+
+// 缩放达到该值后绘制斜坡与半砖的实际形状
+const SHAPE_DETAIL_SCALE: f64 = 4.0;
+
+// 方块外形
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileShape {
+    Full,
+    HalfBrick,
+    // 斜坡：1 右上缺角，2 左上缺角，3 右下缺角，4 左下缺角
+    Slope(i32),
+}
+
+impl TileShape {
+    pub fn from_tile(tile: &Tile) -> Self {
+        if tile.slope > 0 {
+            TileShape::Slope(tile.slope)
+        } else if tile.half_brick || tile.brick_style == 1 {
+            TileShape::HalfBrick
+        } else if (2..=5).contains(&tile.brick_style) {
+            TileShape::Slope(tile.brick_style - 1)
+        } else {
+            TileShape::Full
+        }
+    }
+}
+
+// 电线与促动器覆盖层开关
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WireOverlay {
+    pub red: bool,
+    pub blue: bool,
+    pub green: bool,
+    pub yellow: bool,
+    pub actuators: bool,
+}
+
+impl WireOverlay {
+    pub fn is_enabled(&self) -> bool {
+        self.red || self.blue || self.green || self.yellow || self.actuators
+    }
+
+    // 开启的覆盖层，按 OVERLAY_ORDER 的位排列；促动器开关同时控制变暗与标记
+    fn mask(&self) -> u8 {
+        [self.actuators, self.red, self.blue, self.green, self.yellow, self.actuators]
+            .iter()
+            .enumerate()
+            .fold(0, |mask, (bit, &enabled)| mask | (enabled as u8) << bit)
+    }
+}
+
+#[wasm_bindgen]
 pub struct Renderer {
     ctx: CanvasRenderingContext2d,
     scale: f64,
+    wire_overlay: WireOverlay,
+    chunk_cache: RefCell<ChunkCache>,
+    cancellation: Option<CancellationToken>,
+    mipmaps: RefCell<Option<MipCache>>,
+    // 金字塔各层对应的离屏画布，按 factor 懒加载
+    mip_canvases: RefCell<HashMap<usize, HtmlCanvasElement>>,
+    map_overlay: Option<MapOverlay>,
+    fog_of_war: bool,
+}
+
+// 金字塔与生成它时的世界版本；编辑过的区域在下次渲染时重新计算。
+// 金字塔只含方块颜色，电线覆盖层在生成各层画布时叠加，切换覆盖层不需要重建
+struct MipCache {
+    key: CacheKey,
+    pyramid: MipmapPyramid,
+    dirty: Vec<(i32, i32, i32, i32)>,
+    // 带覆盖层的方块：方块索引与 overlay_mask
+    overlays: Vec<(usize, u8)>,
+    // 当前 mip_canvases 叠加的覆盖层
+    wire_overlay: WireOverlay,
 }
 
+// 由 .map 文件生成的离屏画布：已探索区域图像与战争迷雾遮罩
+struct MapOverlay {
+    name: String,
+    world_id: i32,
+    width: i32,
+    height: i32,
+    explored: HtmlCanvasElement,
+    fog: HtmlCanvasElement,
+}
+
+impl MapOverlay {
+    // 与 MapFile::matches 相同：名称、ID 与尺寸都一致才是同一个世界
+    fn matches(&self, world: &World) -> bool {
+        self.name == world.name && self.world_id == world.world_id && self.width == world.width && self.height == world.height
+    }
+}
+
+#[wasm_bindgen]
 impl Renderer {
+    #[wasm_bindgen(constructor)]
     pub fn new(canvas: &HtmlCanvasElement) -> Result<Renderer, JsValue> {
         let ctx = canvas
             .get_context("2d")?
@@ -20,7 +142,17 @@ impl Renderer {
         // 禁用图像平滑，保持像素风格
         ctx.set_image_smoothing_enabled(false);
 
-        Ok(Self { ctx, scale: 1.0 })
+        Ok(Self {
+            ctx,
+            scale: 1.0,
+            wire_overlay: WireOverlay::default(),
+            chunk_cache: RefCell::new(ChunkCache::new()),
+            cancellation: None,
+            mipmaps: RefCell::new(None),
+            mip_canvases: RefCell::new(HashMap::new()),
+            map_overlay: None,
+            fog_of_war: false,
+        })
     }
 
     pub fn set_scale(&mut self, scale: f64) {
@@ -31,9 +163,41 @@ impl Renderer {
         self.scale
     }
 
+    pub fn set_wire_overlay_js(&mut self, overlay_js: JsValue) -> Result<(), JsValue> {
+        let overlay: WireOverlay = serde_wasm_bindgen::from_value(overlay_js)?;
+        self.set_wire_overlay(overlay);
+        Ok(())
+    }
+
+    pub fn build_mipmaps_js(&mut self, world_js: JsValue, mode_js: JsValue) -> Result<(), JsValue> {
+        let world: World = serde_wasm_bindgen::from_value(world_js)?;
+        let mode: ReductionMode = if mode_js.is_undefined() || mode_js.is_null() {
+            ReductionMode::default()
+        } else {
+            serde_wasm_bindgen::from_value(mode_js)?
+        };
+        self.build_mipmaps(&world, Self::content_generation(&world), mode);
+        Ok(())
+    }
+
+    pub fn build_mipmaps_handle(&mut self, handle: &WorldHandle, mode_js: JsValue) -> Result<(), JsValue> {
+        let mode: ReductionMode = if mode_js.is_undefined() || mode_js.is_null() {
+            ReductionMode::default()
+        } else {
+            serde_wasm_bindgen::from_value(mode_js)?
+        };
+        self.build_mipmaps(handle.world(), handle.generation(), mode);
+        Ok(())
+    }
+
+    pub fn clear_mipmaps(&mut self) {
+        *self.mipmaps.borrow_mut() = None;
+        self.mip_canvases.borrow_mut().clear();
+    }
+
     pub fn render_world_js(&self, world_js: JsValue) -> Result<(), JsValue> {
         let world: World = serde_wasm_bindgen::from_value(world_js)?;
-        self.render_world(&world, None, false, None)
+        self.render_world(&world, Self::content_generation(&world), None, false, None)
     }
 
     pub fn render_world_with_highlight_js(
@@ -43,22 +207,8 @@ impl Renderer {
         highlight_all: bool,
     ) -> Result<(), JsValue> {
         let world: World = serde_wasm_bindgen::from_value(world_js)?;
-        let highlight_positions: Option<Vec<(i32, i32)>> =
-            if highlight_positions_js.is_undefined() || highlight_positions_js.is_null() {
-                None
-            } else {
-                let positions: Vec<i32> = serde_wasm_bindgen::from_value(highlight_positions_js)?;
-                if positions.len() % 2 != 0 {
-                    return Err(JsValue::from_str("Invalid highlight positions"));
-                }
-                Some(
-                    positions
-                        .chunks(2)
-                        .map(|chunk| (chunk[0], chunk[1]))
-                        .collect(),
-                )
-            };
-        self.render_world(&world, highlight_positions, highlight_all, None)
+        let highlight_positions = Self::parse_highlight_positions(highlight_positions_js)?;
+        self.render_world(&world, Self::content_generation(&world), highlight_positions, highlight_all, None)
     }
 
     pub fn render_world_visible_js(
@@ -69,39 +219,241 @@ impl Renderer {
         highlight_all: bool,
     ) -> Result<(), JsValue> {
         let world: World = serde_wasm_bindgen::from_value(world_js)?;
-        let visible_area: Option<(i32, i32, i32, i32)> =
-            if visible_area_js.is_undefined() || visible_area_js.is_null() {
-                None
-            } else {
-                let area: Vec<i32> = serde_wasm_bindgen::from_value(visible_area_js)?;
-                if area.len() != 4 {
-                    return Err(JsValue::from_str("Invalid visible area"));
-                }
-                Some((area[0], area[1], area[2], area[3])) // x, y, width, height
-            };
+        let visible_area = Self::parse_visible_area(visible_area_js)?;
+        let highlight_positions = Self::parse_highlight_positions(highlight_positions_js)?;
 
-        let highlight_positions: Option<Vec<(i32, i32)>> =
-            if highlight_positions_js.is_undefined() || highlight_positions_js.is_null() {
-                None
-            } else {
-                let positions: Vec<i32> = serde_wasm_bindgen::from_value(highlight_positions_js)?;
-                if positions.len() % 2 != 0 {
-                    return Err(JsValue::from_str("Invalid highlight positions"));
+        self.render_world(&world, Self::content_generation(&world), highlight_positions, highlight_all, visible_area)
+    }
+
+    /// 直接渲染 wasm 内存中的世界，不经过 JS 序列化
+    pub fn render_handle(
+        &self,
+        handle: &WorldHandle,
+        visible_area_js: JsValue,
+        highlight_positions_js: JsValue,
+        highlight_all: bool,
+    ) -> Result<(), JsValue> {
+        let visible_area = Self::parse_visible_area(visible_area_js)?;
+        let highlight_positions = Self::parse_highlight_positions(highlight_positions_js)?;
+        self.render_world(handle.world(), handle.generation(), highlight_positions, highlight_all, visible_area)
+    }
+
+    /// 使覆盖给定方块区域的缓存区块与金字塔像素失效（编辑世界后调用）
+    pub fn invalidate_region(&self, x: i32, y: i32, width: i32, height: i32) {
+        self.chunk_cache.borrow_mut().invalidate_region(x, y, width, height);
+        if let Some(cache) = self.mipmaps.borrow_mut().as_mut() {
+            cache.dirty.push((x, y, width, height));
+        }
+    }
+
+    /// 清空全部缓存区块
+    pub fn clear_cache(&self) {
+        self.chunk_cache.borrow_mut().clear();
+    }
+
+    pub fn cached_chunk_count(&self) -> usize {
+        self.chunk_cache.borrow().len()
+    }
+
+    /// 设置取消令牌，每次调用 prepare_chunks 前检查，取消后返回 CancelledError
+    pub fn set_cancellation_token(&mut self, token: &CancellationToken) {
+        self.cancellation = Some(token.clone());
+    }
+
+    /// 分步生成可见范围内缺失的区块，每次最多 budget 个，全部就绪时返回 true；
+    /// JS 在两次调用之间让出事件循环，就绪后调用 render_handle 贴图。
+    /// 当前缩放不使用区块缓存时直接返回 true
+    pub fn prepare_chunks(
+        &self,
+        handle: &WorldHandle,
+        visible_area_js: JsValue,
+        highlight_positions_js: JsValue,
+        highlight_all: bool,
+        budget: u32,
+    ) -> Result<bool, JsValue> {
+        if self.is_cancelled() {
+            return Err(cancelled_error());
+        }
+        let world = handle.world();
+        let generation = handle.generation();
+        self.sync_mipmaps(world, generation);
+        if self.scale >= SHAPE_DETAIL_SCALE || self.mip_level_active() {
+            return Ok(true);
+        }
+
+        let visible_area = Self::parse_visible_area(visible_area_js)?;
+        let highlight_set: HashSet<(i32, i32)> = Self::parse_highlight_positions(highlight_positions_js)?
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        let range = Self::clamp_area(visible_area, world.width, world.height);
+
+        let mut cache = self.chunk_cache.borrow_mut();
+        Self::prepare_cache(&mut cache, self.cache_key(world, generation), &highlight_set, highlight_all);
+        let mut budget = budget.max(1);
+        for (chunk_x, chunk_y) in Self::chunks_in(range) {
+            if cache.get(chunk_x, chunk_y).is_some() {
+                continue;
+            }
+            if budget == 0 {
+                return Ok(false);
+            }
+            let canvas = self.build_chunk(world, chunk_x, chunk_y, &highlight_set, highlight_all)?;
+            cache.insert(chunk_x, chunk_y, canvas);
+            budget -= 1;
+        }
+        Ok(true)
+    }
+
+    /// 载入玩家的小地图，用于战争迷雾与只绘制已探索区域
+    pub fn set_map_file(&mut self, map: &MapFile) -> Result<(), JsValue> {
+        let width = map.width() as usize;
+        let height = map.height() as usize;
+        self.map_overlay = Some(MapOverlay {
+            name: map.name(),
+            world_id: map.world_id(),
+            width: map.width(),
+            height: map.height(),
+            explored: Self::create_canvas(&map.explored_pixels(), width, height)?,
+            fog: Self::create_canvas(&map.fog_pixels(), width, height)?,
+        });
+        Ok(())
+    }
+
+    pub fn clear_map_file(&mut self) {
+        self.map_overlay = None;
+    }
+
+    /// 开启后渲染世界时遮住小地图中未探索的区域（需先 set_map_file）
+    pub fn set_fog_of_war(&mut self, enabled: bool) {
+        self.fog_of_war = enabled;
+    }
+
+    /// 只绘制小地图中已探索的区域，不需要世界文件
+    pub fn render_map_file(&self, visible_area_js: JsValue) -> Result<(), JsValue> {
+        let overlay = self
+            .map_overlay
+            .as_ref()
+            .ok_or_else(|| JsValue::from_str("No map file loaded"))?;
+        let visible_area = Self::parse_visible_area(visible_area_js)?;
+
+        let width = self.ctx.canvas().unwrap().width();
+        let height = self.ctx.canvas().unwrap().height();
+        self.ctx.clear_rect(0.0, 0.0, width as f64, height as f64);
+
+        let range = Self::clamp_area(visible_area, overlay.width, overlay.height);
+        self.draw_tile_canvas(&overlay.explored, range)
+    }
+
+    pub fn render_tile_js(&self, x: f64, y: f64, tile_js: JsValue) -> Result<(), JsValue> {
+        let tile: Tile = serde_wasm_bindgen::from_value(tile_js)?;
+        self.render_tile(x, y, &tile, false, false)
+    }
+}
+
+impl Renderer {
+    pub fn set_wire_overlay(&mut self, overlay: WireOverlay) {
+        self.wire_overlay = overlay;
+    }
+
+    pub fn get_wire_overlay(&self) -> WireOverlay {
+        self.wire_overlay
+    }
+
+    /// 加载世界后生成多分辨率金字塔，缩放小于 1 时从金字塔渲染；
+    /// 之后世界版本变化时在渲染时按同样的降采样方式重建，电线覆盖层贴图时叠加
+    pub fn build_mipmaps(&mut self, world: &World, generation: u64, mode: ReductionMode) {
+        let cache = MipCache {
+            key: Self::mip_key(world, generation),
+            pyramid: MipmapPyramid::build(world, mode),
+            dirty: Vec::new(),
+            overlays: Self::collect_overlays(world, (0, 0, world.width.max(0) as usize, world.height.max(0) as usize)),
+            wire_overlay: self.wire_overlay,
+        };
+        *self.mipmaps.borrow_mut() = Some(cache);
+        self.mip_canvases.borrow_mut().clear();
+    }
+
+    // JS 传入的世界每次都是新反序列化的副本，以方块内容代替版本号，编辑后缓存随之失效
+    fn content_generation(world: &World) -> u64 {
+        world.tiles.content_hash()
+    }
+
+    fn cache_key(&self, world: &World, generation: u64) -> CacheKey {
+        CacheKey {
+            world_id: world.world_id,
+            width: world.width,
+            height: world.height,
+            generation,
+            wire_overlay: self.wire_overlay,
+        }
+    }
+
+    // 金字塔不含电线覆盖层，与覆盖层配置无关
+    fn mip_key(world: &World, generation: u64) -> CacheKey {
+        CacheKey {
+            world_id: world.world_id,
+            width: world.width,
+            height: world.height,
+            generation,
+            wire_overlay: WireOverlay::default(),
+        }
+    }
+
+    // 区域 [x0, x1) × [y0, y1) 内带覆盖层的方块，通常只占世界的很小一部分
+    fn collect_overlays(world: &World, (x0, y0, x1, y1): (usize, usize, usize, usize)) -> Vec<(usize, u8)> {
+        let mut overlays = Vec::new();
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let idx = y * world.width as usize + x;
+                if let Some(mask) = world.tiles.get(idx).map(|tile| Self::overlay_mask(&tile)).filter(|&mask| mask != 0) {
+                    overlays.push((idx, mask));
                 }
-                Some(
-                    positions
-                        .chunks(2)
-                        .map(|chunk| (chunk[0], chunk[1]))
-                        .collect(),
-                )
-            };
+            }
+        }
+        overlays
+    }
 
-        self.render_world(&world, highlight_positions, highlight_all, visible_area)
+    // 渲染前让金字塔与世界保持一致：版本变化时重建，编辑过的区域重新计算；
+    // 覆盖层配置变化时只丢弃已叠加的画布
+    fn sync_mipmaps(&self, world: &World, generation: u64) {
+        let mut mipmaps = self.mipmaps.borrow_mut();
+        let Some(cache) = mipmaps.as_mut() else {
+            return;
+        };
+        let key = Self::mip_key(world, generation);
+        if cache.key != key {
+            cache.pyramid = MipmapPyramid::build(world, cache.pyramid.mode);
+            cache.overlays = Self::collect_overlays(world, (0, 0, world.width.max(0) as usize, world.height.max(0) as usize));
+            cache.key = key;
+            cache.dirty.clear();
+        } else if !cache.dirty.is_empty() {
+            let width = world.width.max(0) as usize;
+            for (x, y, w, h) in cache.dirty.drain(..) {
+                let (x0, y0) = (x.max(0), y.max(0));
+                let (x1, y1) = ((x + w).min(world.width), (y + h).min(world.height));
+                if x0 < x1 && y0 < y1 {
+                    let region = (x0 as usize, y0 as usize, x1 as usize, y1 as usize);
+                    cache.pyramid.refresh_region(world, region.0, region.1, region.2 - region.0, region.3 - region.1);
+                    cache.overlays.retain(|&(idx, _)| {
+                        let (tx, ty) = (idx % width, idx / width);
+                        tx < region.0 || tx >= region.2 || ty < region.1 || ty >= region.3
+                    });
+                    cache.overlays.extend(Self::collect_overlays(world, region));
+                }
+            }
+        } else if cache.wire_overlay == self.wire_overlay {
+            return;
+        }
+        cache.wire_overlay = self.wire_overlay;
+        self.mip_canvases.borrow_mut().clear();
     }
 
+    /// generation 为 WorldHandle 的版本号或方块内容的哈希，用于判断缓存是否过期
     pub fn render_world(
         &self,
         world: &World,
+        generation: u64,
         highlight_positions: Option<Vec<(i32, i32)>>,
         highlight_all: bool,
         visible_area: Option<(i32, i32, i32, i32)>,
@@ -112,55 +464,372 @@ impl Renderer {
         self.ctx.clear_rect(0.0, 0.0, width as f64, height as f64);
 
         // 创建高亮位置集合
-        let highlight_set: std::collections::HashSet<(i32, i32)> = highlight_positions
+        let highlight_set: HashSet<(i32, i32)> = highlight_positions
             .unwrap_or_default()
             .into_iter()
             .collect();
 
         // 确定渲染范围
-        let (start_x, start_y, end_x, end_y) = match visible_area {
-            Some((x, y, w, h)) => {
-                let start_x = x.max(0) as usize;
-                let start_y = y.max(0) as usize;
-                let end_x = (x + w).min(world.width) as usize;
-                let end_y = (y + h).min(world.height) as usize;
-                (start_x, start_y, end_x, end_y)
+        let range = Self::clamp_area(visible_area, world.width, world.height);
+        let (start_x, start_y, end_x, end_y) = range;
+
+        // 缩小视图从金字塔贴图；低缩放时从区块缓存贴图；高缩放时可见方块很少，直接逐块绘制外形
+        self.sync_mipmaps(world, generation);
+        let mipmaps = self.mipmaps.borrow();
+        let mip_level = mipmaps
+            .as_ref()
+            .and_then(|cache| Some((cache, cache.pyramid.level_for_scale(self.scale)?)));
+        if let Some((cache, level)) = mip_level {
+            self.render_mip_level(cache, level, range)?;
+            if highlight_all {
+                self.fill_highlights(world, &highlight_set, range);
             }
-            None => (0, 0, world.width as usize, world.height as usize),
+        } else if self.scale < SHAPE_DETAIL_SCALE {
+            self.render_chunks(world, generation, &highlight_set, highlight_all, range)?;
+        } else {
+            self.render_tiles_direct(world, &highlight_set, highlight_all, range)?;
+        }
+
+        // 战争迷雾盖在方块之上、高亮边框之下
+        if self.fog_of_war {
+            if let Some(overlay) = self.map_overlay.as_ref().filter(|overlay| overlay.matches(world)) {
+                self.draw_tile_canvas(&overlay.fog, range)?;
+            }
+        }
+
+        // 最后一遍：绘制单个高亮的边框
+        if !highlight_all {
+            let size = self.scale;
+            self.ctx.set_stroke_style_str("rgba(255, 255, 0, 0.8)");
+            self.ctx.set_line_width(2.0 / self.scale);
+            for &(x, y) in &highlight_set {
+                if x < start_x as i32 || x >= end_x as i32 || y < start_y as i32 || y >= end_y as i32 {
+                    continue;
+                }
+                let idx = y as usize * world.width as usize + x as usize;
+                if world.tiles.is_active(idx) {
+                    self.ctx.stroke_rect(x as f64 * size, y as f64 * size, size, size);
+                }
+            }
+        }
+
+        self.stroke_damaged_areas(world, range);
+
+        Ok(())
+    }
+
+    // 恢复模式下损坏的区域：方块已用哨兵颜色填充，这里再描出边框
+    fn stroke_damaged_areas(&self, world: &World, range: (usize, usize, usize, usize)) {
+        let (start_x, start_y, end_x, end_y) = range;
+        let size = self.scale;
+        self.ctx.set_stroke_style_str(&DAMAGED_OUTLINE_COLOR.to_css_string());
+        self.ctx.set_line_width(2.0 / self.scale);
+        for area in world.warnings.iter().flat_map(|warning| &warning.areas) {
+            let left = (area.x.max(0) as usize).max(start_x);
+            let top = (area.y.max(0) as usize).max(start_y);
+            let right = ((area.x + area.width).max(0) as usize).min(end_x);
+            let bottom = ((area.y + area.height).max(0) as usize).min(end_y);
+            if left >= right || top >= bottom {
+                continue;
+            }
+            self.ctx.stroke_rect(
+                left as f64 * size,
+                top as f64 * size,
+                (right - left) as f64 * size,
+                (bottom - top) as f64 * size,
+            );
+        }
+    }
+
+    fn render_chunks(
+        &self,
+        world: &World,
+        generation: u64,
+        highlight_set: &HashSet<(i32, i32)>,
+        highlight_all: bool,
+        (start_x, start_y, end_x, end_y): (usize, usize, usize, usize),
+    ) -> Result<(), JsValue> {
+        if start_x >= end_x || start_y >= end_y {
+            return Ok(());
+        }
+
+        let mut cache = self.chunk_cache.borrow_mut();
+        Self::prepare_cache(&mut cache, self.cache_key(world, generation), highlight_set, highlight_all);
+
+        // 未经 prepare_chunks 准备的区块在这里同步生成
+        let size = self.scale;
+        for (chunk_x, chunk_y) in Self::chunks_in((start_x, start_y, end_x, end_y)) {
+            if cache.get(chunk_x, chunk_y).is_none() {
+                let canvas = self.build_chunk(world, chunk_x, chunk_y, highlight_set, highlight_all)?;
+                cache.insert(chunk_x, chunk_y, canvas);
+            }
+
+            if let Some(canvas) = cache.get(chunk_x, chunk_y) {
+                let dx = (chunk_x * CHUNK_SIZE) as f64 * size;
+                let dy = (chunk_y * CHUNK_SIZE) as f64 * size;
+                self.ctx.draw_image_with_html_canvas_element_and_dw_and_dh(
+                    canvas,
+                    dx,
+                    dy,
+                    canvas.width() as f64 * size,
+                    canvas.height() as f64 * size,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 缓存与当前世界、图层保持一致；只有全部高亮模式会把高亮填充烘焙进区块
+    fn prepare_cache(cache: &mut ChunkCache, key: CacheKey, highlight_set: &HashSet<(i32, i32)>, highlight_all: bool) {
+        cache.validate(key);
+        let baked_highlights = if highlight_all {
+            highlight_set.clone()
+        } else {
+            HashSet::new()
+        };
+        cache.update_highlights(baked_highlights);
+    }
+
+    // 覆盖渲染范围的区块坐标，范围为空时没有区块
+    fn chunks_in((start_x, start_y, end_x, end_y): (usize, usize, usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (columns, rows) = match start_x < end_x && start_y < end_y {
+            true => (start_x / CHUNK_SIZE..(end_x - 1) / CHUNK_SIZE + 1, start_y / CHUNK_SIZE..(end_y - 1) / CHUNK_SIZE + 1),
+            false => (0..0, 0..0),
+        };
+        rows.flat_map(move |chunk_y| columns.clone().map(move |chunk_x| (chunk_x, chunk_y)))
+    }
+
+    fn mip_level_active(&self) -> bool {
+        self.mipmaps
+            .borrow()
+            .as_ref()
+            .is_some_and(|cache| cache.pyramid.level_for_scale(self.scale).is_some())
+    }
+
+    // 从金字塔层级贴图，耗时与世界大小无关
+    fn render_mip_level(
+        &self,
+        cache: &MipCache,
+        level: &MipLevel,
+        (start_x, start_y, end_x, end_y): (usize, usize, usize, usize),
+    ) -> Result<(), JsValue> {
+        if start_x >= end_x || start_y >= end_y {
+            return Ok(());
+        }
+
+        let mut canvases = self.mip_canvases.borrow_mut();
+        let canvas = match canvases.entry(level.factor) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let pixels = self.composite_overlays(cache, level);
+                entry.insert(Self::create_canvas(&pixels, level.width, level.height)?)
+            }
+        };
+
+        // 源矩形按层级像素对齐，目标矩形按方块坐标缩放
+        let factor = level.factor;
+        let sx = start_x / factor;
+        let sy = start_y / factor;
+        let sw = (end_x.div_ceil(factor)).min(level.width) - sx;
+        let sh = (end_y.div_ceil(factor)).min(level.height) - sy;
+        let tile_size = self.scale * factor as f64;
+
+        self.ctx.draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+            &*canvas,
+            sx as f64,
+            sy as f64,
+            sw as f64,
+            sh as f64,
+            sx as f64 * tile_size,
+            sy as f64 * tile_size,
+            sw as f64 * tile_size,
+            sh as f64 * tile_size,
+        )
+    }
+
+    // 将开启的覆盖层叠加到层级像素上：像素覆盖的方块中有某种覆盖层，就按绘制顺序叠加一次该颜色，
+    // 细电线缩小后仍然可见
+    fn composite_overlays(&self, cache: &MipCache, level: &MipLevel) -> Vec<u8> {
+        let mut pixels = level.pixels.clone();
+        let enabled = self.wire_overlay.mask();
+        if enabled == 0 {
+            return pixels;
+        }
+        let width = cache.pyramid.width;
+        let mut masks: HashMap<usize, u8> = HashMap::new();
+        for &(idx, mask) in &cache.overlays {
+            if mask & enabled != 0 {
+                let (x, y) = (idx % width / level.factor, idx / width / level.factor);
+                *masks.entry(y * level.width + x).or_default() |= mask & enabled;
+            }
+        }
+        for (pixel, mask) in masks {
+            let offset = pixel * 4;
+            let mut color: [u8; 4] = pixels[offset..offset + 4].try_into().unwrap();
+            for layer in Self::overlay_layers(mask) {
+                color = layer.blend_over(color);
+            }
+            pixels[offset..offset + 4].copy_from_slice(&color);
+        }
+        pixels
+    }
+
+    // 将每像素对应一个方块的离屏画布按当前缩放贴到可见范围
+    fn draw_tile_canvas(
+        &self,
+        canvas: &HtmlCanvasElement,
+        (start_x, start_y, end_x, end_y): (usize, usize, usize, usize),
+    ) -> Result<(), JsValue> {
+        if start_x >= end_x || start_y >= end_y {
+            return Ok(());
+        }
+
+        let size = self.scale;
+        let (w, h) = ((end_x - start_x) as f64, (end_y - start_y) as f64);
+        self.ctx.draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+            canvas,
+            start_x as f64,
+            start_y as f64,
+            w,
+            h,
+            start_x as f64 * size,
+            start_y as f64 * size,
+            w * size,
+            h * size,
+        )
+    }
+
+    // 在贴图之上直接填充全部高亮的方块
+    fn fill_highlights(
+        &self,
+        world: &World,
+        highlight_set: &HashSet<(i32, i32)>,
+        (start_x, start_y, end_x, end_y): (usize, usize, usize, usize),
+    ) {
+        let size = self.scale;
+        self.ctx.set_fill_style_str(&HIGHLIGHT_FILL_COLOR.to_css_string());
+        for &(x, y) in highlight_set {
+            if x < start_x as i32 || x >= end_x as i32 || y < start_y as i32 || y >= end_y as i32 {
+                continue;
+            }
+            let idx = y as usize * world.width as usize + x as usize;
+            if world.tiles.is_active(idx) {
+                self.ctx.fill_rect(x as f64 * size, y as f64 * size, size, size);
+            }
+        }
+    }
+
+    // 将一个区块按 1 像素/方块渲染到离屏画布
+    fn build_chunk(
+        &self,
+        world: &World,
+        chunk_x: usize,
+        chunk_y: usize,
+        highlight_set: &HashSet<(i32, i32)>,
+        highlight_all: bool,
+    ) -> Result<HtmlCanvasElement, JsValue> {
+        let origin_x = chunk_x * CHUNK_SIZE;
+        let origin_y = chunk_y * CHUNK_SIZE;
+        let chunk_width = CHUNK_SIZE.min(world.width as usize - origin_x);
+        let chunk_height = CHUNK_SIZE.min(world.height as usize - origin_y);
+
+        let mut pixels = vec![0u8; chunk_width * chunk_height * 4];
+        for y in 0..chunk_height {
+            for x in 0..chunk_width {
+                let (wx, wy) = (origin_x + x, origin_y + y);
+                let highlighted = highlight_all && highlight_set.contains(&(wx as i32, wy as i32));
+                let offset = (y * chunk_width + x) * 4;
+                pixels[offset..offset + 4].copy_from_slice(&self.tile_pixel(world, wx, wy, highlighted));
+            }
+        }
+
+        Self::create_canvas(&pixels, chunk_width, chunk_height)
+    }
+
+    // 用 RGBA 像素创建离屏画布
+    fn create_canvas(pixels: &[u8], width: usize, height: usize) -> Result<HtmlCanvasElement, JsValue> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or_else(|| JsValue::from_str("Failed to get document"))?;
+        let canvas = document
+            .create_element("canvas")?
+            .dyn_into::<HtmlCanvasElement>()?;
+        canvas.set_width(width as u32);
+        canvas.set_height(height as u32);
+
+        let ctx = canvas
+            .get_context("2d")?
+            .ok_or_else(|| JsValue::from_str("Failed to get 2d context"))?
+            .dyn_into::<CanvasRenderingContext2d>()?;
+        let image_data = ImageData::new_with_u8_clamped_array_and_sh(
+            Clamped(pixels),
+            width as u32,
+            height as u32,
+        )?;
+        ctx.put_image_data(&image_data, 0.0, 0.0)?;
+
+        Ok(canvas)
+    }
+
+    // 计算单个方块在区块图像中的像素颜色
+    fn tile_pixel(&self, world: &World, x: usize, y: usize, highlighted: bool) -> [u8; 4] {
+        let idx = y * world.width as usize + x;
+        let Some(tile) = world.tiles.get(idx) else {
+            return [0, 0, 0, 0];
         };
 
+        let mut pixel = [0, 0, 0, 0];
+        if tile.is_active {
+            pixel = if highlighted {
+                HIGHLIGHT_FILL_COLOR.to_array()
+            } else {
+                Rgba::from(TileColors::get_world_color(world, x, y)).to_array()
+            };
+        }
+        for color in self.overlay_colors(&tile) {
+            pixel = color.blend_over(pixel);
+        }
+
+        pixel
+    }
+
+    fn render_tiles_direct(
+        &self,
+        world: &World,
+        highlight_set: &HashSet<(i32, i32)>,
+        highlight_all: bool,
+        (start_x, start_y, end_x, end_y): (usize, usize, usize, usize),
+    ) -> Result<(), JsValue> {
         // 性能优化：按颜色分组批量渲染
-        let mut color_groups: std::collections::HashMap<String, Vec<(f64, f64)>> = std::collections::HashMap::new();
-        let mut highlight_positions: Vec<(f64, f64)> = Vec::new();
+        let mut color_groups: HashMap<String, Vec<(f64, f64, TileShape)>> = HashMap::new();
+        let mut overlay_groups: HashMap<Rgba, Vec<(f64, f64)>> = HashMap::new();
 
         // 第一遍：收集所有方块位置并按颜色分组
         for y in start_y..end_y {
             for x in start_x..end_x {
-                let idx = (y * world.width as usize + x) as usize;
-                if idx >= world.tiles.len() {
+                let idx = y * world.width as usize + x;
+                let Some(tile) = world.tiles.get(idx) else {
                     continue;
+                };
+                if self.wire_overlay.is_enabled() {
+                    for color in self.overlay_colors(&tile) {
+                        overlay_groups.entry(color).or_default().push((x as f64, y as f64));
+                    }
                 }
 
-                let tile = &world.tiles[idx];
                 if tile.is_active {
+                    let shape = self.tile_shape(&tile);
                     let is_highlighted = highlight_set.contains(&(x as i32, y as i32));
-                    
-                    if is_highlighted && highlight_all {
+
+                    let color = if is_highlighted && highlight_all {
                         // 全部高亮模式：直接绘制高亮方块
-                        let color = "rgba(255, 255, 0, 0.5)".to_string();
-                        color_groups.entry(color).or_insert_with(Vec::new).push((x as f64, y as f64));
-                    } else if is_highlighted {
-                        // 单个高亮模式：记录位置，稍后单独绘制
-                        highlight_positions.push((x as f64, y as f64));
-                        
-                        // 同时绘制正常颜色的方块
-                        let color = crate::colors::TileColors::get_color(tile.tile_id).to_css_string();
-                        color_groups.entry(color).or_insert_with(Vec::new).push((x as f64, y as f64));
+                        HIGHLIGHT_FILL_COLOR.to_css_string()
                     } else {
-                        // 正常模式：按颜色分组
-                        let color = crate::colors::TileColors::get_color(tile.tile_id).to_css_string();
-                        color_groups.entry(color).or_insert_with(Vec::new).push((x as f64, y as f64));
-                    }
+                        // 正常模式（单个高亮的边框稍后单独绘制）：按颜色分组
+                        TileColors::get_world_color(world, x, y).to_css_string()
+                    };
+                    color_groups.entry(color).or_default().push((x as f64, y as f64, shape));
                 }
             }
         }
@@ -169,26 +838,30 @@ impl Renderer {
         let size = self.scale;
         for (color, positions) in color_groups {
             self.ctx.set_fill_style_str(&color);
-            for (x, y) in positions {
-                self.ctx.fill_rect(x * size, y * size, size, size);
+            for (x, y, shape) in positions {
+                self.fill_tile_shape(x, y, shape);
             }
         }
 
-        // 第三遍：绘制单个高亮的边框
-        if !highlight_all {
-            self.ctx.set_stroke_style_str("rgba(255, 255, 0, 0.8)");
-            self.ctx.set_line_width(2.0 / self.scale);
-            for (x, y) in highlight_positions {
-                self.ctx.stroke_rect(x * size, y * size, size, size);
+        // 电线覆盖层：变暗被促动的方块，叠加电线颜色，标记促动器
+        for color in OVERLAY_ORDER {
+            if let Some(positions) = overlay_groups.remove(&color) {
+                self.ctx.set_fill_style_str(&color.to_css_string());
+                for (x, y) in positions {
+                    if color == ACTUATOR_COLOR {
+                        self.draw_actuator_mark(x, y);
+                    } else {
+                        self.ctx.fill_rect(x * size, y * size, size, size);
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
-    pub fn render_tile_js(&self, x: f64, y: f64, tile_js: JsValue) -> Result<(), JsValue> {
-        let tile: Tile = serde_wasm_bindgen::from_value(tile_js)?;
-        self.render_tile(x, y, &tile, false, false)
+    fn is_cancelled(&self) -> bool {
+        self.cancellation.as_ref().is_some_and(|token| token.is_cancelled())
     }
 
     pub fn render_tile(
@@ -200,7 +873,7 @@ impl Renderer {
         highlight_all: bool,
     ) -> Result<(), JsValue> {
         // 获取方块颜色
-        let color = crate::colors::TileColors::get_color(tile.tile_id);
+        let color = TileColors::get_frame_color(tile.tile_id, tile.u, tile.v);
 
         // 设置填充颜色
         let color_string = if is_highlighted && highlight_all {
@@ -218,7 +891,17 @@ impl Renderer {
 
         // 绘制方块
         let size = self.scale;
-        self.ctx.fill_rect(x * size, y * size, size, size);
+        self.fill_tile_shape(x, y, self.tile_shape(tile));
+
+        // 电线覆盖层
+        for color in self.overlay_colors(tile) {
+            self.ctx.set_fill_style_str(&color.to_css_string());
+            if color == ACTUATOR_COLOR {
+                self.draw_actuator_mark(x, y);
+            } else {
+                self.ctx.fill_rect(x * size, y * size, size, size);
+            }
+        }
 
         // 如果高亮但不是全部高亮，绘制边框
         if is_highlighted && !highlight_all {
@@ -229,4 +912,125 @@ impl Renderer {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    // 低缩放下一律按整块绘制
+    fn tile_shape(&self, tile: &Tile) -> TileShape {
+        if self.scale >= SHAPE_DETAIL_SCALE {
+            TileShape::from_tile(tile)
+        } else {
+            TileShape::Full
+        }
+    }
+
+    // 使用当前填充颜色绘制方块外形
+    fn fill_tile_shape(&self, x: f64, y: f64, shape: TileShape) {
+        let size = self.scale;
+        let (left, top) = (x * size, y * size);
+        let (right, bottom) = (left + size, top + size);
+
+        let corners = match shape {
+            TileShape::Full => {
+                self.ctx.fill_rect(left, top, size, size);
+                return;
+            }
+            TileShape::HalfBrick => {
+                self.ctx.fill_rect(left, top + size / 2.0, size, size / 2.0);
+                return;
+            }
+            TileShape::Slope(1) => [(left, top), (left, bottom), (right, bottom)],
+            TileShape::Slope(2) => [(right, top), (right, bottom), (left, bottom)],
+            TileShape::Slope(3) => [(left, top), (right, top), (left, bottom)],
+            TileShape::Slope(4) => [(left, top), (right, top), (right, bottom)],
+            TileShape::Slope(_) => {
+                self.ctx.fill_rect(left, top, size, size);
+                return;
+            }
+        };
+
+        self.ctx.begin_path();
+        self.ctx.move_to(corners[0].0, corners[0].1);
+        self.ctx.line_to(corners[1].0, corners[1].1);
+        self.ctx.line_to(corners[2].0, corners[2].1);
+        self.ctx.close_path();
+        self.ctx.fill();
+    }
+
+    // 按绘制顺序返回方块需要叠加的覆盖层颜色
+    fn overlay_colors(&self, tile: &Tile) -> Vec<Rgba> {
+        Self::overlay_layers(Self::overlay_mask(tile) & self.wire_overlay.mask()).collect()
+    }
+
+    // 方块带有的覆盖层，与开关无关，按 OVERLAY_ORDER 的位排列
+    fn overlay_mask(tile: &Tile) -> u8 {
+        [
+            tile.is_active && tile.in_active,
+            tile.wire_red,
+            tile.wire_blue,
+            tile.wire_green,
+            tile.wire_yellow,
+            tile.actuator,
+        ]
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (bit, &present)| mask | (present as u8) << bit)
+    }
+
+    fn overlay_layers(mask: u8) -> impl Iterator<Item = Rgba> {
+        OVERLAY_ORDER
+            .into_iter()
+            .enumerate()
+            .filter(move |(bit, _)| mask >> bit & 1 != 0)
+            .map(|(_, color)| color)
+    }
+
+    // 促动器标记：方块中心的小方块
+    fn draw_actuator_mark(&self, x: f64, y: f64) {
+        let size = self.scale;
+        let mark = size / 2.0;
+        let offset = (size - mark) / 2.0;
+        self.ctx.fill_rect(x * size + offset, y * size + offset, mark, mark);
+    }
+
+    // 解析 [x, y, width, height]，undefined / null 表示整个世界
+    // 可见区域限制在世界范围内，返回 (start_x, start_y, end_x, end_y)
+    fn clamp_area(visible_area: Option<(i32, i32, i32, i32)>, width: i32, height: i32) -> (usize, usize, usize, usize) {
+        match visible_area {
+            Some((x, y, w, h)) => {
+                let start_x = x.max(0) as usize;
+                let start_y = y.max(0) as usize;
+                let end_x = (x + w).min(width).max(0) as usize;
+                let end_y = (y + h).min(height).max(0) as usize;
+                (start_x, start_y, end_x, end_y)
+            }
+            None => (0, 0, width as usize, height as usize),
+        }
+    }
+
+    fn parse_visible_area(visible_area_js: JsValue) -> Result<Option<(i32, i32, i32, i32)>, JsValue> {
+        if visible_area_js.is_undefined() || visible_area_js.is_null() {
+            return Ok(None);
+        }
+        let area: Vec<i32> = serde_wasm_bindgen::from_value(visible_area_js)?;
+        if area.len() != 4 {
+            return Err(JsValue::from_str("Invalid visible area"));
+        }
+        Ok(Some((area[0], area[1], area[2], area[3]))) // x, y, width, height
+    }
+
+    // 解析扁平的 [x0, y0, x1, y1, ...] 高亮坐标
+    fn parse_highlight_positions(highlight_positions_js: JsValue) -> Result<Option<Vec<(i32, i32)>>, JsValue> {
+        if highlight_positions_js.is_undefined() || highlight_positions_js.is_null() {
+            return Ok(None);
+        }
+        let positions: Vec<i32> = serde_wasm_bindgen::from_value(highlight_positions_js)?;
+        if !positions.len().is_multiple_of(2) {
+            return Err(JsValue::from_str("Invalid highlight positions"));
+        }
+        Ok(Some(
+            positions
+                .chunks(2)
+                .map(|chunk| (chunk[0], chunk[1]))
+                .collect(),
+        ))
+    }
+}
@@ -1,28 +1,103 @@
 // 搜索功能
 // 对应原项目的方块、物品、NPC 查找逻辑
 
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use crate::world_loader::{World, Chest, NPC};
+use crate::bearing::{Bearing, Located, Reference, ReferenceOptions};
+use crate::clusters::{cluster_tiles, Neighborhood};
+use crate::name_match::{match_name, NameMatch};
+use crate::name_registry::{NameKind, NameRegistry};
+use crate::player_loader::Player;
+use crate::query::Query;
+use crate::search_area::{AreaFilter, SearchArea};
+use crate::sets::TileSet;
+use crate::spatial_index::SpatialIndex;
+use crate::text_search::{TextMatch, TextMatcher, TextSearchOptions, TextSource};
+use crate::world_handle::WorldHandle;
+use crate::world_loader::{World, Chest, ChestItem, NPC};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
     pub tile_positions: Vec<TilePosition>,
     pub chest_results: Vec<ChestResult>,
     pub npc_results: Vec<NPCResult>,
+    #[serde(default)]
+    pub wall_positions: Vec<TilePosition>,
 }
 
+// 按名称搜索的结果：排好序的候选，以及最佳方块与最佳物品候选的搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameSearchResult {
+    pub candidates: Vec<NameMatch>,
+    pub tile: Option<NameMatch>,
+    pub item: Option<NameMatch>,
+    pub tile_positions: Vec<TilePosition>,
+    pub chest_results: Vec<ChestResult>,
+}
+
+// 名称搜索返回的候选数量
+const NAME_CANDIDATE_LIMIT: usize = 20;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TilePosition {
     pub x: i32,
     pub y: i32,
+    // 相对参考点的方位，未给出参考点时为空
+    #[serde(default)]
+    pub bearing: Option<Bearing>,
+}
+
+impl TilePosition {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y, bearing: None }
+    }
+}
+
+impl Located for TilePosition {
+    fn location(&self) -> Option<(f64, f64)> {
+        Some((self.x as f64, self.y as f64))
+    }
+
+    fn set_bearing(&mut self, bearing: Bearing) {
+        self.bearing = Some(bearing);
+    }
+}
+
+// 物品所在的储物空间
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChestSource {
+    #[default]
+    World,
+    Inventory,
+    PiggyBank,
+    Safe,
+    DefendersForge,
+    VoidVault,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChestResult {
     pub chest: Chest,
     pub matching_items: Vec<ItemInfo>,
+    #[serde(default)]
+    pub source: ChestSource,
+    #[serde(default)]
+    pub bearing: Option<Bearing>,
+}
+
+impl Located for ChestResult {
+    // 玩家的储物空间记在重生点上，没有重生点时坐标为 (-1, -1)，不算位置
+    fn location(&self) -> Option<(f64, f64)> {
+        let placed = self.source == ChestSource::World || (self.chest.x >= 0 && self.chest.y >= 0);
+        placed.then_some((self.chest.x as f64, self.chest.y as f64))
+    }
+
+    fn set_bearing(&mut self, bearing: Bearing) {
+        self.bearing = Some(bearing);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,12 +110,32 @@ pub struct ItemInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NPCResult {
     pub npc: NPC,
-    pub distance: f32, // 到玩家的距离
+    pub distance: f32, // 到参考点的距离（方块），未给出参考点时为到玩家重生点的距离
+    #[serde(default)]
+    pub type_name: String, // 当前语言的 NPC 种类名，如 "Merchant"
+    #[serde(default)]
+    pub bearing: Option<Bearing>,
+}
+
+impl Located for NPCResult {
+    // NPC 坐标以像素为单位（每格 16 像素）
+    fn location(&self) -> Option<(f64, f64)> {
+        Some((self.npc.position_x as f64 / 16.0, self.npc.position_y as f64 / 16.0))
+    }
+
+    fn set_bearing(&mut self, bearing: Bearing) {
+        self.distance = bearing.distance as f32;
+        self.bearing = Some(bearing);
+    }
 }
 
 #[wasm_bindgen]
 pub struct Searcher {
-    world: World,
+    world: Rc<World>,
+    player: Option<Player>,
+    names: NameRegistry,
+    custom_sets: Vec<TileSet>,
+    index: OnceCell<SpatialIndex>,
 }
 
 #[wasm_bindgen]
@@ -48,110 +143,505 @@ impl Searcher {
     #[wasm_bindgen(constructor)]
     pub fn new(world: JsValue) -> Result<Searcher, JsValue> {
         let world: World = serde_wasm_bindgen::from_value(world)?;
-        Ok(Self { world })
+        Ok(Self {
+            world: Rc::new(world),
+            player: None,
+            names: NameRegistry::new(),
+            custom_sets: Vec::new(),
+            index: OnceCell::new(),
+        })
     }
 
+    /// 与句柄共享世界数据，不复制也不反序列化；共享期间句柄上的世界不能再修改
     #[wasm_bindgen]
-    pub fn find_tiles(&self, tile_ids: JsValue) -> Result<JsValue, JsValue> {
+    pub fn from_handle(handle: &WorldHandle) -> Searcher {
+        Self {
+            world: handle.shared(),
+            player: None,
+            names: NameRegistry::new(),
+            custom_sets: Vec::new(),
+            index: OnceCell::new(),
+        }
+    }
+
+    /// 设置玩家（PlayerLoader 的结果）：NPC 结果带上到重生点的距离，箱子搜索包含玩家的储物空间
+    #[wasm_bindgen]
+    pub fn set_player(&mut self, player_js: JsValue) -> Result<(), JsValue> {
+        let player: Player = serde_wasm_bindgen::from_value(player_js)?;
+        self.player = Some(player);
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_player(&mut self) {
+        self.player = None;
+    }
+
+    /// 使用注册表的当前语言显示物品与 NPC 名称（复制一份，之后对注册表的修改需要重新设置）
+    #[wasm_bindgen]
+    pub fn set_names(&mut self, names: &NameRegistry) {
+        self.names = names.clone();
+    }
+
+    /// 各搜索的 area_js 为搜索范围 { rect?, polygon?, within? }，各项同时成立，
+    /// 省略时为整个世界；within 以玩家重生点为参考时需先 set_player
+    /// reference_js 为参考点 { point, sort?, nearest? }：结果带上相对参考点的距离与方位，
+    /// 默认按距离排序，nearest 只保留最近的 N 个；point 为 "Player"（需先 set_player）或 { x, y }
+    #[wasm_bindgen]
+    pub fn find_tiles(&self, tile_ids: JsValue, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
         let tile_ids: Vec<i32> = serde_wasm_bindgen::from_value(tile_ids)?;
-        let results = self.find_tiles_internal(&tile_ids);
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut results = self.find_tiles_internal(&tile_ids, area.as_ref());
+        arrange(reference.as_ref(), &mut results);
         Ok(serde_wasm_bindgen::to_value(&results)?)
     }
 
     #[wasm_bindgen]
-    pub fn find_chests_with_item(&self, item_id: i32) -> Result<JsValue, JsValue> {
-        let results = self.find_chests_with_item_internal(item_id);
+    pub fn find_walls(&self, wall_ids: JsValue, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let wall_ids: Vec<i32> = serde_wasm_bindgen::from_value(wall_ids)?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut results = self.find_walls_internal(&wall_ids, area.as_ref());
+        arrange(reference.as_ref(), &mut results);
         Ok(serde_wasm_bindgen::to_value(&results)?)
     }
 
+    /// 方块总数；不限范围时直接读取索引
     #[wasm_bindgen]
-    pub fn find_npcs(&self, npc_name: &str) -> Result<JsValue, JsValue> {
-        let results = self.find_npcs_internal(npc_name);
+    pub fn count_tiles(&self, tile_ids: JsValue, area_js: JsValue) -> Result<usize, JsValue> {
+        let tile_ids: Vec<i32> = serde_wasm_bindgen::from_value(tile_ids)?;
+        Ok(match self.parse_area(area_js)? {
+            Some(area) => self.find_tiles_internal(&tile_ids, Some(&area)).len(),
+            None => tile_ids.iter().map(|&id| self.index().tile_count(id)).sum(),
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn count_walls(&self, wall_ids: JsValue, area_js: JsValue) -> Result<usize, JsValue> {
+        let wall_ids: Vec<i32> = serde_wasm_bindgen::from_value(wall_ids)?;
+        Ok(match self.parse_area(area_js)? {
+            Some(area) => self.find_walls_internal(&wall_ids, Some(&area)).len(),
+            None => wall_ids.iter().map(|&id| self.index().wall_count(id)).sum(),
+        })
+    }
+
+    /// 立即建立空间索引（否则在第一次搜索时建立），适合在加载完成后调用
+    #[wasm_bindgen]
+    pub fn build_index(&self) {
+        self.index();
+    }
+
+    /// 与 find_tiles 相同，但相连的同种方块合并为一簇，多格物体合并为一个；neighborhood 为 "Four" 或 "Eight"（默认）
+    #[wasm_bindgen]
+    pub fn find_tile_clusters(&self, tile_ids: JsValue, neighborhood_js: JsValue, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let tile_ids: Vec<i32> = serde_wasm_bindgen::from_value(tile_ids)?;
+        let neighborhood: Option<Neighborhood> = serde_wasm_bindgen::from_value(neighborhood_js)?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let positions = self.find_tiles_internal(&tile_ids, area.as_ref());
+        let mut clusters = cluster_tiles(&self.world, &positions, neighborhood.unwrap_or_default());
+        arrange(reference.as_ref(), &mut clusters);
+        Ok(serde_wasm_bindgen::to_value(&clusters)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn find_chests_with_item(&self, item_id: i32, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut results = self.find_chests_with_item_internal(item_id, area.as_ref());
+        arrange(reference.as_ref(), &mut results);
         Ok(serde_wasm_bindgen::to_value(&results)?)
     }
 
     #[wasm_bindgen]
-    pub fn find_all(&self, tile_ids: JsValue, item_ids: JsValue, npc_names: JsValue) -> Result<JsValue, JsValue> {
+    pub fn find_npcs(&self, npc_name: &str, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut results = self.find_npcs_internal(npc_name, area.as_ref());
+        arrange(reference.as_ref(), &mut results);
+        Ok(serde_wasm_bindgen::to_value(&results)?)
+    }
+
+    /// 按告示牌文字与箱子名称搜索 { pattern, regex?, case_sensitive?, signs?, chests? }，
+    /// 每个匹配的告示牌 / 箱子返回一条结果，带第一处匹配的高亮片段
+    #[wasm_bindgen]
+    pub fn find_text(&self, options_js: JsValue, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let options: TextSearchOptions = serde_wasm_bindgen::from_value(options_js)?;
+        let matcher = TextMatcher::new(&options).map_err(|e| JsValue::from_str(&e))?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut results = self.find_text_internal(&matcher, &options, area.as_ref());
+        arrange(reference.as_ref(), &mut results);
+        Ok(serde_wasm_bindgen::to_value(&results)?)
+    }
+
+    /// 全部集合：自定义集合在前，其后为 sets.js 中的预定义集合
+    #[wasm_bindgen]
+    pub fn sets(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.all_sets())?)
+    }
+
+    /// 注册自定义集合 { name, entries: [{ kind, id, u?, v? }] }，同名集合会被替换
+    #[wasm_bindgen]
+    pub fn add_custom_set(&mut self, set_js: JsValue) -> Result<(), JsValue> {
+        let set: TileSet = serde_wasm_bindgen::from_value(set_js)?;
+        if set.name.trim().is_empty() {
+            return Err(JsValue::from_str("Set name must not be empty"));
+        }
+        self.custom_sets.retain(|existing| !existing.name.eq_ignore_ascii_case(&set.name));
+        self.custom_sets.push(set);
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub fn remove_custom_set(&mut self, set_name: &str) -> bool {
+        let count = self.custom_sets.len();
+        self.custom_sets.retain(|set| !set.name.eq_ignore_ascii_case(set_name));
+        self.custom_sets.len() != count
+    }
+
+    /// 按集合名称（不区分大小写）搜索集合中的全部方块、墙体与箱子物品
+    #[wasm_bindgen]
+    pub fn find_set(&self, set_name: &str, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let set = self
+            .all_sets()
+            .into_iter()
+            .find(|set| set.name.eq_ignore_ascii_case(set_name.trim()))
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown set: {}", set_name)))?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut result = self.find_set_internal(&set, area.as_ref());
+        arrange_result(reference.as_ref(), &mut result);
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// 按查询语言搜索，如 tile:"Chlorophyte Ore" AND y>1200 AND NOT wall:"Jungle Wall"
+    /// 返回匹配坐标（最多 limit 个，0 为不限）与位于匹配坐标上的箱子、告示牌、NPC、物块实体
+    #[wasm_bindgen]
+    pub fn query(&self, text: &str, limit: usize, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let query = Query::parse(text, &self.world, &self.names).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let area = self.area_or_whole(area.as_ref());
+        // 按距离排序时先取全部匹配，排序后再截断
+        let mut result = match reference {
+            Some(_) => query.evaluate(&self.world, self.index(), &area, 0),
+            None => query.evaluate(&self.world, self.index(), &area, limit),
+        };
+        arrange(reference.as_ref(), &mut result.positions);
+        if limit > 0 {
+            result.positions.truncate(limit);
+        }
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// 方块与物品名称的自动补全候选，不执行搜索
+    #[wasm_bindgen]
+    pub fn suggest_names(&self, query: &str, limit: usize) -> Result<JsValue, JsValue> {
+        let candidates = self.name_candidates(query, limit);
+        Ok(serde_wasm_bindgen::to_value(&candidates)?)
+    }
+
+    /// 按名称查找（不区分大小写，支持前缀、子串与拼写容错），并对最佳的方块与物品候选执行搜索
+    #[wasm_bindgen]
+    pub fn find_by_name(&self, query: &str, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let mut result = self.find_by_name_internal(query, area.as_ref());
+        arrange(reference.as_ref(), &mut result.tile_positions);
+        arrange(reference.as_ref(), &mut result.chest_results);
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn find_all(&self, tile_ids: JsValue, item_ids: JsValue, npc_names: JsValue, area_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsValue> {
         let tile_ids: Vec<i32> = serde_wasm_bindgen::from_value(tile_ids)?;
         let item_ids: Vec<i32> = serde_wasm_bindgen::from_value(item_ids)?;
         let npc_names: Vec<String> = serde_wasm_bindgen::from_value(npc_names)?;
+        let area = self.parse_area(area_js)?;
+        let reference = self.parse_reference(reference_js)?;
+        let area = area.as_ref();
 
         let mut result = SearchResult {
             tile_positions: Vec::new(),
             chest_results: Vec::new(),
             npc_results: Vec::new(),
+            wall_positions: Vec::new(),
         };
 
         // 查找方块
         if !tile_ids.is_empty() {
-            result.tile_positions = self.find_tiles_internal(&tile_ids);
+            result.tile_positions = self.find_tiles_internal(&tile_ids, area);
         }
 
         // 查找箱子
         for item_id in &item_ids {
-            let chests = self.find_chests_with_item_internal(*item_id);
+            let chests = self.find_chests_with_item_internal(*item_id, area);
             result.chest_results.extend(chests);
         }
 
         // 查找 NPC
         for npc_name in &npc_names {
-            let npcs = self.find_npcs_internal(npc_name);
+            let npcs = self.find_npcs_internal(npc_name, area);
             result.npc_results.extend(npcs);
         }
 
+        arrange_result(reference.as_ref(), &mut result);
         Ok(serde_wasm_bindgen::to_value(&result)?)
     }
 }
 
 impl Searcher {
-    fn find_tiles_internal(&self, tile_ids: &[i32]) -> Vec<TilePosition> {
-        let id_set: HashSet<i32> = tile_ids.iter().cloned().collect();
-        let mut results = Vec::new();
+    // 每次调用时解析搜索范围，within 的参考点随当前玩家变化；undefined / null 为不限范围
+    fn parse_area(&self, area_js: JsValue) -> Result<Option<AreaFilter>, JsValue> {
+        let area: Option<SearchArea> = serde_wasm_bindgen::from_value(area_js)?;
+        area.map(|area| {
+            AreaFilter::new(&area, &self.world, self.player.as_ref())
+                .map_err(|e| JsValue::from_str(&format!("Invalid search area: {}", e)))
+        })
+        .transpose()
+    }
 
-        for (idx, tile) in self.world.tiles.iter().enumerate() {
-            if tile.is_active && id_set.contains(&tile.tile_id) {
-                let x = idx as i32 % self.world.width;
-                let y = idx as i32 / self.world.width;
-                results.push(TilePosition { x, y });
+    // 给定的搜索范围，不限范围时为整个世界
+    fn area_or_whole<'a>(&self, area: Option<&'a AreaFilter>) -> Cow<'a, AreaFilter> {
+        match area {
+            Some(area) => Cow::Borrowed(area),
+            None => Cow::Owned(AreaFilter::whole(&self.world)),
+        }
+    }
+
+    // 每次调用时解析参考点，"Player" 随当前玩家变化；undefined / null 为不标注方位
+    fn parse_reference(&self, reference_js: JsValue) -> Result<Option<Reference>, JsValue> {
+        let options: Option<ReferenceOptions> = serde_wasm_bindgen::from_value(reference_js)?;
+        options
+            .map(|options| {
+                let origin = options
+                    .point
+                    .resolve(&self.world, self.player.as_ref())
+                    .map_err(|e| JsValue::from_str(&format!("Invalid reference point: {}", e)))?;
+                Ok(Reference {
+                    origin,
+                    sort: options.sort,
+                    nearest: options.nearest,
+                })
+            })
+            .transpose()
+    }
+
+    fn index(&self) -> &SpatialIndex {
+        self.index.get_or_init(|| SpatialIndex::build(&self.world))
+    }
+
+    fn all_sets(&self) -> Vec<TileSet> {
+        let mut sets = self.custom_sets.clone();
+        sets.extend(TileSet::builtin());
+        sets
+    }
+
+    pub fn find_set_internal(&self, set: &TileSet, area: Option<&AreaFilter>) -> SearchResult {
+        let tiles = &self.world.tiles;
+        let tile_ids: Vec<i32> = set.ids(NameKind::Tile).collect();
+        let wall_ids: Vec<i32> = set.ids(NameKind::Wall).collect();
+        let mut tile_positions = Vec::new();
+
+        for (x, y) in self.index().find_tiles(&self.world, &tile_ids, &self.area_or_whole(area)) {
+            let idx = y as usize * self.world.width as usize + x as usize;
+            let [u, v, ..] = tiles.frame(idx);
+            if set.entries.iter().any(|entry| entry.matches_tile(tiles.tile_id(idx), u as i32, v as i32)) {
+                tile_positions.push(TilePosition::new(x, y));
             }
         }
+        let wall_positions = self.find_walls_internal(&wall_ids, area);
 
-        results
+        // 同一个箱子含多个集合物品时合并为一条结果
+        let mut chest_results: Vec<ChestResult> = Vec::new();
+        for item_id in set.ids(NameKind::Item) {
+            for result in self.find_chests_with_item_internal(item_id, area) {
+                let existing = chest_results.iter_mut().find(|existing| {
+                    existing.source == result.source && existing.chest.x == result.chest.x && existing.chest.y == result.chest.y
+                });
+                match existing {
+                    Some(existing) => existing.matching_items.extend(result.matching_items),
+                    None => chest_results.push(result),
+                }
+            }
+        }
+
+        SearchResult {
+            tile_positions,
+            chest_results,
+            npc_results: Vec::new(),
+            wall_positions,
+        }
     }
 
-    fn find_chests_with_item_internal(&self, item_id: i32) -> Vec<ChestResult> {
+    pub fn find_by_name_internal(&self, query: &str, area: Option<&AreaFilter>) -> NameSearchResult {
+        let candidates = self.name_candidates(query, usize::MAX);
+        let tile = candidates.iter().find(|c| c.kind == NameKind::Tile).cloned();
+        let item = candidates.iter().find(|c| c.kind == NameKind::Item).cloned();
+
+        NameSearchResult {
+            tile_positions: tile.as_ref().map_or_else(Vec::new, |tile| self.find_tiles_internal(&[tile.id], area)),
+            chest_results: item.as_ref().map_or_else(Vec::new, |item| self.find_chests_with_item_internal(item.id, area)),
+            candidates: candidates.into_iter().take(NAME_CANDIDATE_LIMIT).collect(),
+            tile,
+            item,
+        }
+    }
+
+    // 原版与模组的方块、物品名称候选，按匹配程度排序
+    fn name_candidates(&self, query: &str, limit: usize) -> Vec<NameMatch> {
+        let mut candidates = self.names.suggest(query, &[NameKind::Tile, NameKind::Item], usize::MAX);
+
+        // 模组条目按 "ModName:Name" 与 "Name" 匹配
+        let lower = query.trim().to_lowercase();
+        let mods = &self.world.mods;
+        for (kind, entries) in [(NameKind::Tile, &mods.tiles), (NameKind::Item, &mods.items)] {
+            for (&id, entry) in entries {
+                let label = entry.label();
+                let best = match_name(&lower, &label).into_iter().chain(match_name(&lower, &entry.name)).min();
+                if let Some((quality, distance)) = best {
+                    candidates.push(NameMatch {
+                        kind,
+                        id,
+                        name: label.clone(),
+                        key: label,
+                        quality,
+                        distance,
+                    });
+                }
+            }
+        }
+
+        candidates.sort_by_key(NameMatch::rank);
+        candidates.truncate(limit);
+        candidates
+    }
+
+    fn find_tiles_internal(&self, tile_ids: &[i32], area: Option<&AreaFilter>) -> Vec<TilePosition> {
+        self.index()
+            .find_tiles(&self.world, tile_ids, &self.area_or_whole(area))
+            .into_iter()
+            .map(|(x, y)| TilePosition::new(x, y))
+            .collect()
+    }
+
+    fn find_walls_internal(&self, wall_ids: &[i32], area: Option<&AreaFilter>) -> Vec<TilePosition> {
+        self.index()
+            .find_walls(&self.world, wall_ids, &self.area_or_whole(area))
+            .into_iter()
+            .map(|(x, y)| TilePosition::new(x, y))
+            .collect()
+    }
+
+    fn find_text_internal(&self, matcher: &TextMatcher, options: &TextSearchOptions, area: Option<&AreaFilter>) -> Vec<TextMatch> {
         let mut results = Vec::new();
+        if options.signs {
+            for sign in self.world.signs.iter().filter(|sign| in_area(area, sign.x, sign.y)) {
+                results.extend(matcher.search(TextSource::Sign, sign.x, sign.y, &sign.text));
+            }
+        }
+        if options.chests {
+            for chest in self.world.chests.iter().filter(|chest| in_area(area, chest.x, chest.y)) {
+                results.extend(matcher.search(TextSource::Chest, chest.x, chest.y, &chest.name));
+            }
+        }
+        results
+    }
 
-        for chest in &self.world.chests {
-            let matching_items: Vec<ItemInfo> = chest.items
-                .iter()
-                .filter(|item| item.id == item_id)
-                .map(|item| ItemInfo {
-                    id: item.id,
-                    name: self.get_item_name(item.id),
-                    count: item.stack,
-                })
-                .collect();
+    fn find_chests_with_item_internal(&self, item_id: i32, area: Option<&AreaFilter>) -> Vec<ChestResult> {
+        let mut results = Vec::new();
 
+        for chest in self.world.chests.iter().filter(|chest| in_area(area, chest.x, chest.y)) {
+            let matching_items = self.matching_items(&chest.items, item_id);
             if !matching_items.is_empty() {
                 results.push(ChestResult {
                     chest: chest.clone(),
                     matching_items,
+                    source: ChestSource::World,
+                    bearing: None,
                 });
             }
         }
 
+        // 玩家自己的储物空间没有世界坐标，位置记为重生点；设置了搜索范围时按重生点判断
+        if let Some(player) = &self.player {
+            let (x, y) = self.player_spawn().unwrap_or((-1, -1));
+            if !in_area(area, x, y) {
+                return results;
+            }
+            for (source, name, items) in [
+                (ChestSource::Inventory, "Inventory", &player.inventory),
+                (ChestSource::PiggyBank, "Piggy Bank", &player.piggy_bank),
+                (ChestSource::Safe, "Safe", &player.safe),
+                (ChestSource::DefendersForge, "Defender's Forge", &player.defenders_forge),
+                (ChestSource::VoidVault, "Void Vault", &player.void_vault),
+            ] {
+                let matching_items = self.matching_items(items, item_id);
+                if !matching_items.is_empty() {
+                    results.push(ChestResult {
+                        chest: Chest {
+                            x,
+                            y,
+                            name: name.to_string(),
+                            items: items.clone(),
+                        },
+                        matching_items,
+                        source,
+                        bearing: None,
+                    });
+                }
+            }
+        }
+
         results
     }
 
-    fn find_npcs_internal(&self, npc_name: &str) -> Vec<NPCResult> {
+    fn matching_items(&self, items: &[ChestItem], item_id: i32) -> Vec<ItemInfo> {
+        items
+            .iter()
+            .filter(|item| item.id == item_id)
+            .map(|item| ItemInfo {
+                id: item.id,
+                name: self.get_item_name(item.id),
+                count: item.stack,
+            })
+            .collect()
+    }
+
+    // 玩家在当前世界的重生点（方块坐标）
+    fn player_spawn(&self) -> Option<(i32, i32)> {
+        let player = self.player.as_ref()?;
+        let point = player.spawn_point(self.world.world_id, &self.world.name)?;
+        Some((point.x, point.y))
+    }
+
+    fn find_npcs_internal(&self, npc_name: &str, area: Option<&AreaFilter>) -> Vec<NPCResult> {
         let mut results = Vec::new();
+        let spawn = self.player_spawn();
+
+        let query = npc_name.to_lowercase();
 
         for npc in &self.world.npcs {
-            if npc.name.to_lowercase().contains(&npc_name.to_lowercase()) {
+            // 既可按城镇 NPC 的名字，也可按种类名（当前语言或英文）查找
+            let type_name = self.get_npc_type_name(npc);
+            let english = NameRegistry::entry(NameKind::Npc, npc.sprite_id).map(|entry| entry.name);
+            let matched = npc.name.to_lowercase().contains(&query)
+                || type_name.to_lowercase().contains(&query)
+                || english.is_some_and(|name| name.to_lowercase().contains(&query));
+            if matched && in_area(area, (npc.position_x / 16.0) as i32, (npc.position_y / 16.0) as i32) {
+                // NPC 坐标以像素为单位（每格 16 像素），未设置玩家时距离为 0
+                let distance = spawn.map_or(0.0, |(x, y)| {
+                    let dx = npc.position_x / 16.0 - x as f32;
+                    let dy = npc.position_y / 16.0 - y as f32;
+                    (dx * dx + dy * dy).sqrt()
+                });
                 results.push(NPCResult {
                     npc: npc.clone(),
-                    distance: 0.0, // TODO: 计算到玩家的距离
+                    distance,
+                    type_name,
+                    bearing: None,
                 });
             }
         }
@@ -160,19 +650,109 @@ impl Searcher {
     }
 
     fn get_item_name(&self, item_id: i32) -> String {
-        // 简化版物品名称映射
-        // 实际实现需要从 names.js 迁移完整的名称映射
-        match item_id {
-            0 => "Unknown".to_string(),
-            1 => "Copper Ore".to_string(),
-            2 => "Tin Ore".to_string(),
-            3 => "Iron Ore".to_string(),
-            4 => "Lead Ore".to_string(),
-            5 => "Silver Ore".to_string(),
-            6 => "Tungsten Ore".to_string(),
-            7 => "Gold Ore".to_string(),
-            8 => "Platinum Ore".to_string(),
-            _ => format!("Item {}", item_id),
-        }
-    }
-}
\ No newline at end of file
+        // 模组物品使用 "ModName:ItemName"
+        if let Some(entry) = self.world.mods.item(item_id) {
+            return entry.label();
+        }
+
+        self.names
+            .display_name(NameKind::Item, item_id)
+            .unwrap_or_else(|| format!("Item {}", item_id))
+    }
+
+    fn get_npc_type_name(&self, npc: &NPC) -> String {
+        self.names
+            .display_name(NameKind::Npc, npc.sprite_id)
+            .unwrap_or_else(|| npc.name.clone())
+    }
+}
+
+// 不限范围时任何位置都算在内（包括没有世界坐标的玩家储物空间）
+fn in_area(area: Option<&AreaFilter>, x: i32, y: i32) -> bool {
+    area.is_none_or(|area| area.contains(x, y))
+}
+
+// 给出参考点时标注方位并排序、截断
+fn arrange<T: Located>(reference: Option<&Reference>, results: &mut Vec<T>) {
+    if let Some(reference) = reference {
+        reference.apply(results);
+    }
+}
+
+fn arrange_result(reference: Option<&Reference>, result: &mut SearchResult) {
+    arrange(reference, &mut result.tile_positions);
+    arrange(reference, &mut result.wall_positions);
+    arrange(reference, &mut result.chest_results);
+    arrange(reference, &mut result.npc_results);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{self, chest, sign, tile};
+    use crate::world_loader::{Tile, WorldLoader};
+
+    // 经由正常加载路径得到的搜索器，箱子与告示牌来自文件中的区段
+    fn loaded_searcher(world: &World, tiles: &[Tile]) -> Searcher {
+        let file = test_fixtures::world_file(world, tiles);
+        let handle = WorldLoader::new().load_handle(file.bytes).unwrap();
+        Searcher::from_handle(&handle)
+    }
+
+    fn area(json: &str, searcher: &Searcher) -> AreaFilter {
+        let area: SearchArea = serde_json::from_str(json).unwrap();
+        AreaFilter::new(&area, &searcher.world, None).unwrap()
+    }
+
+    #[test]
+    fn text_search_finds_signs_and_chests_of_a_loaded_world() {
+        let tiles: Vec<Tile> = (0..32 * 32).map(|_| tile(1)).collect();
+        let mut world = test_fixtures::world(32, 32, &tiles);
+        world.signs.push(sign(2, 3, "Zur Höhle →"));
+        world.signs.push(sign(20, 20, "nothing here"));
+        world.chests.push(chest(5, 6, "HÖHLE loot", &[]));
+        let searcher = loaded_searcher(&world, &tiles);
+
+        let options = TextSearchOptions {
+            pattern: "höhle".to_string(),
+            regex: false,
+            case_sensitive: false,
+            signs: true,
+            chests: true,
+        };
+        let matcher = TextMatcher::new(&options).unwrap();
+        let results = searcher.find_text_internal(&matcher, &options, None);
+        let found: Vec<(TextSource, i32, i32)> = results.iter().map(|m| (m.source, m.x, m.y)).collect();
+        assert_eq!(found, vec![(TextSource::Sign, 2, 3), (TextSource::Chest, 5, 6)]);
+        assert_eq!(results[0].matched, "Höhle");
+        assert_eq!(results[1].matched, "HÖHLE");
+
+        let left = area(r#"{ "rect": { "x": 0, "y": 0, "width": 4, "height": 32 } }"#, &searcher);
+        assert_eq!(searcher.find_text_internal(&matcher, &options, Some(&left)).len(), 1);
+    }
+    #[test]
+    fn find_set_merges_chest_items_of_a_loaded_world() {
+        let mut tiles: Vec<Tile> = (0..32 * 32).map(|_| tile(1)).collect();
+        tiles[4 * 32 + 7] = tile(21);
+        let mut world = test_fixtures::world(32, 32, &tiles);
+        world.chests.push(chest(7, 4, "", &[(29, 1), (3, 50), (1291, 2)]));
+        world.chests.push(chest(20, 4, "", &[(3, 10)]));
+        let searcher = loaded_searcher(&world, &tiles);
+
+        let set: TileSet = serde_json::from_str(
+            r#"{ "name": "Life", "entries": [
+                { "kind": "Tile", "id": 21 },
+                { "kind": "Item", "id": 29 },
+                { "kind": "Item", "id": 1291 }
+            ] }"#,
+        )
+        .unwrap();
+        let result = searcher.find_set_internal(&set, None);
+        assert_eq!(result.tile_positions.len(), 1);
+        assert_eq!(result.chest_results.len(), 1);
+        let chest = &result.chest_results[0];
+        assert_eq!((chest.chest.x, chest.chest.y), (7, 4));
+        let ids: Vec<i32> = chest.matching_items.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![29, 1291]);
+    }
+}
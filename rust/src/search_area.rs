@@ -0,0 +1,168 @@
+// 搜索范围
+// 矩形、多边形与到参考点的距离，给出的各项条件同时成立
+
+use serde::{Deserialize, Serialize};
+use crate::player_loader::Player;
+use crate::world_loader::World;
+
+// 参考点：玩家在该世界的重生点，或指定的方块坐标。
+// 本格式的世界头不含出生点与地牢入口，因此没有这两种参考点
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReferencePoint {
+    Landmark(Landmark),
+    Tile { x: i32, y: i32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Landmark {
+    Player,
+}
+
+impl ReferencePoint {
+    /// 解析为方块坐标，玩家数据中缺少该世界的重生点时报错
+    pub fn resolve(&self, world: &World, player: Option<&Player>) -> Result<(i32, i32), String> {
+        match self {
+            ReferencePoint::Tile { x, y } => Ok((*x, *y)),
+            ReferencePoint::Landmark(Landmark::Player) => player
+                .and_then(|player| player.spawn_point(world.world_id, &world.name))
+                .map(|point| (point.x, point.y))
+                .ok_or_else(|| "No player spawn point for this world (set a player first)".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Within {
+    pub point: ReferencePoint,
+    pub distance: f64, // 方块
+}
+
+/// JS 传入的搜索范围，如 { rect: { x: 0, y: 400, width: 1200, height: 600 }, within: { point: "Player", distance: 300 } }
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchArea {
+    #[serde(default)]
+    pub rect: Option<Rect>,
+    #[serde(default)]
+    pub polygon: Option<Vec<Point>>,
+    #[serde(default)]
+    pub within: Option<Within>,
+}
+
+// 针对具体世界解析后的范围
+#[derive(Debug, Clone)]
+pub struct AreaFilter {
+    // 包围盒 [x0, x1) × [y0, y1)，已裁剪到世界范围
+    bounds: (i32, i32, i32, i32),
+    polygon: Option<Vec<Point>>,
+    circle: Option<(i32, i32, f64)>,
+}
+
+impl AreaFilter {
+    /// 不限范围
+    pub fn whole(world: &World) -> Self {
+        Self {
+            bounds: (0, 0, world.width, world.height),
+            polygon: None,
+            circle: None,
+        }
+    }
+
+    pub fn new(area: &SearchArea, world: &World, player: Option<&Player>) -> Result<Self, String> {
+        let mut filter = Self::whole(world);
+        let mut clip = |x0: f64, y0: f64, x1: f64, y1: f64| {
+            let (bx0, by0, bx1, by1) = &mut filter.bounds;
+            *bx0 = (*bx0).max(x0.floor() as i32);
+            *by0 = (*by0).max(y0.floor() as i32);
+            *bx1 = (*bx1).min(x1.ceil() as i32);
+            *by1 = (*by1).min(y1.ceil() as i32);
+        };
+
+        if let Some(rect) = area.rect {
+            clip(rect.x as f64, rect.y as f64, (rect.x + rect.width) as f64, (rect.y + rect.height) as f64);
+        }
+        if let Some(points) = &area.polygon {
+            if points.len() < 3 {
+                return Err("A search polygon needs at least 3 points".to_string());
+            }
+            let min_x = points.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+            let min_y = points.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+            let max_x = points.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
+            let max_y = points.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max);
+            clip(min_x, min_y, max_x, max_y);
+        }
+        let circle = match &area.within {
+            Some(within) => {
+                let (x, y) = within.point.resolve(world, player)?;
+                Some((x, y, within.distance))
+            }
+            None => None,
+        };
+        if let Some((x, y, distance)) = circle {
+            clip(x as f64 - distance, y as f64 - distance, x as f64 + distance + 1.0, y as f64 + distance + 1.0);
+        }
+
+        filter.polygon = area.polygon.clone();
+        filter.circle = circle;
+        Ok(filter)
+    }
+
+    /// 包围盒 (x0, y0, x1, y1)，不含右下边界
+    pub fn bounds(&self) -> (i32, i32, i32, i32) {
+        self.bounds
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let (x0, y0, x1, y1) = self.bounds;
+        if x < x0 || y < y0 || x >= x1 || y >= y1 {
+            return false;
+        }
+        if let Some((cx, cy, distance)) = self.circle {
+            let dx = (x - cx) as f64;
+            let dy = (y - cy) as f64;
+            if dx * dx + dy * dy > distance * distance {
+                return false;
+            }
+        }
+        match &self.polygon {
+            Some(points) => point_in_polygon(points, x as f64 + 0.5, y as f64 + 0.5),
+            None => true,
+        }
+    }
+
+    /// 范围内的全部方块坐标，按行优先顺序
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        let (x0, y0, x1, y1) = self.bounds;
+        (y0..y1)
+            .flat_map(move |y| (x0..x1).map(move |x| (x, y)))
+            .filter(move |&(x, y)| self.contains(x, y))
+    }
+}
+
+// 射线法判断点是否在多边形内（以方块中心为准）
+fn point_in_polygon(points: &[Point], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut j = points.len() - 1;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[j]);
+        if (a.y > y) != (b.y > y) && x < (b.x - a.x) * (y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
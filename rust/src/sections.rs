@@ -0,0 +1,281 @@
+// 方块之后的世界区段
+// 箱子、告示牌、NPC 与物块实体按原版布局存储，由位置表定位；正常加载与恢复模式共用这些读取函数，
+// 区别只在于出错时整体报错还是记录警告后继续
+
+use crate::data_stream::DataStream;
+use crate::world_loader::{Chest, ChestItem, Sign, TileEntity, World, NPC};
+use crate::world_parser::WorldParser;
+
+// 位置表中的区段序号
+pub(crate) const CHESTS: usize = 2;
+pub(crate) const SIGNS: usize = 3;
+pub(crate) const NPCS: usize = 4;
+pub(crate) const TILE_ENTITIES: usize = 5;
+
+type SectionReader = fn(&mut Section, i32, &mut World) -> Result<(), String>;
+
+const READERS: [(usize, SectionReader); 4] = [
+    (CHESTS, read_chests),
+    (SIGNS, read_signs),
+    (NPCS, read_npcs),
+    (TILE_ENTITIES, read_tile_entities),
+];
+
+/// 第 index 个区段在文件中的范围：到下一个区段为止，位置表缺项或偏移为 0 时返回 None
+pub(crate) fn section_range(positions: &[i32], index: usize, len: usize) -> Option<(usize, usize)> {
+    let offset = |index: usize| {
+        positions
+            .get(index)
+            .filter(|&&offset| offset > 0 && offset as usize <= len)
+            .map(|&offset| offset as usize)
+    };
+    let start = offset(index)?;
+    let end = offset(index + 1).filter(|&end| end > start).unwrap_or(len);
+    Some((start, end))
+}
+
+/// 读取区段前需要的数据长度：没有区段时为 0；位置表中没有最后一个区段的结尾时为 None，需要完整文件
+pub(crate) fn sections_end(positions: &[i32]) -> Option<usize> {
+    let offset = |index: usize| positions.get(index).copied().filter(|&offset| offset > 0);
+    if offset(CHESTS).is_none() {
+        return Some(0);
+    }
+    offset(TILE_ENTITIES + 1).map(|offset| offset as usize)
+}
+
+/// 依次读取箱子、告示牌、NPC 与物块实体区段；出错时以区段序号、偏移与原因调用 on_error，
+/// on_error 返回错误则停止，否则保留该区段已读部分并继续
+pub(crate) fn read_sections(
+    stream: &mut DataStream,
+    positions: &[i32],
+    version: i32,
+    world: &mut World,
+    mut on_error: impl FnMut(usize, usize, String) -> Result<(), String>,
+) -> Result<(), String> {
+    for (index, read) in READERS {
+        let Some((start, end)) = section_range(positions, index, stream.len()) else {
+            continue;
+        };
+        stream.seek(start);
+        let mut section = Section { stream, end, world_width: world.width, world_height: world.height };
+        if let Err(reason) = read(&mut section, version, world) {
+            on_error(index, section.stream.position(), reason)?;
+        }
+    }
+    Ok(())
+}
+
+// 原版格式：数量、每个箱子的格数，之后为坐标、名称与各格物品（数量为 0 的格子没有 id 与前缀）
+fn read_chests(section: &mut Section, _version: i32, world: &mut World) -> Result<(), String> {
+    let count = section.int16()?;
+    let slots = section.int16()?;
+    for _ in 0..count {
+        let (x, y) = section.position()?;
+        let name = section.string()?;
+        let mut items = Vec::new();
+        for _ in 0..slots {
+            let stack = section.int16()? as i32;
+            if stack > 0 {
+                let id = section.int32()?;
+                let prefix = section.byte()? as i32;
+                items.push(ChestItem { id, stack, prefix });
+            }
+        }
+        world.chests.push(Chest { x, y, name, items });
+    }
+    Ok(())
+}
+
+// 原版格式：数量，之后为文字与坐标
+fn read_signs(section: &mut Section, _version: i32, world: &mut World) -> Result<(), String> {
+    let count = section.int16()?;
+    for _ in 0..count {
+        let text = section.string()?;
+        let (x, y) = section.position()?;
+        world.signs.push(Sign { x, y, text });
+    }
+    Ok(())
+}
+
+// 原版格式（版本 ≥ 213）：微光 NPC 列表（版本 ≥ 268），之后是以布尔值开头的城镇 NPC 记录。
+// 更早版本的记录布局不同，不读取
+fn read_npcs(section: &mut Section, version: i32, world: &mut World) -> Result<(), String> {
+    if version < 213 {
+        return Ok(());
+    }
+    if version >= 268 {
+        let shimmered = section.int32()?;
+        if shimmered < 0 {
+            return Err(format!("Invalid shimmered NPC count {}", shimmered));
+        }
+        for _ in 0..shimmered {
+            section.int32()?;
+        }
+    }
+    while section.flag()? {
+        let sprite_id = section.int32()?;
+        let name = section.string()?;
+        let position_x = section.float()?;
+        let position_y = section.float()?;
+        let is_homeless = section.flag()?;
+        let (home_x, home_y) = (section.int32()?, section.int32()?);
+        // 位 0：带城镇 NPC 变体编号
+        if section.byte()? & 1 != 0 {
+            section.int32()?;
+        }
+        world.npcs.push(NPC {
+            id: world.npcs.len() as i32,
+            name,
+            sprite_id,
+            position_x,
+            position_y,
+            home_x,
+            home_y,
+            direction: 0,
+            is_homeless,
+        });
+    }
+    Ok(())
+}
+
+// 原版格式：数量，之后每个实体为类型、id、坐标（16 位）与按类型不同的内容
+fn read_tile_entities(section: &mut Section, _version: i32, world: &mut World) -> Result<(), String> {
+    let count = section.int32()?;
+    if count < 0 {
+        return Err(format!("Invalid tile entity count {}", count));
+    }
+    for _ in 0..count {
+        let entity_type = section.byte()?;
+        let id = section.int32()?;
+        let (x, y) = section.short_position()?;
+        let mut items = Vec::new();
+        match entity_type {
+            // 训练假人：对应的 NPC 序号
+            0 => section.skip(2)?,
+            // 物品框、武器架、餐盘：一个物品
+            1 | 4 | 6 => items.extend(section.item()?),
+            // 逻辑感应器：检测类型与开关状态
+            2 => section.skip(2)?,
+            // 人体模型：物品与染料各 8 格，由两个位图标记
+            3 => {
+                let (item_mask, dye_mask) = (section.byte()?, section.byte()?);
+                for mask in [item_mask, dye_mask] {
+                    for slot in 0..8 {
+                        if mask >> slot & 1 != 0 {
+                            items.extend(section.item()?);
+                        }
+                    }
+                }
+            }
+            // 帽架：2 个物品与 2 个染料共用一个位图
+            5 => {
+                let mask = section.byte()?;
+                for slot in 0..4 {
+                    if mask >> slot & 1 != 0 {
+                        items.extend(section.item()?);
+                    }
+                }
+            }
+            // 晶塔：没有内容
+            7 => {}
+            other => return Err(format!("Unknown tile entity type {}", other)),
+        }
+        world.tile_entities.push(TileEntity {
+            id,
+            position_x: x,
+            position_y: y,
+            entity_type: entity_type as i32,
+            items,
+        });
+    }
+    Ok(())
+}
+
+// 限定在一个区段内的读取，越界或取值不合理时返回错误而不是 panic
+struct Section<'a> {
+    stream: &'a mut DataStream,
+    end: usize,
+    world_width: i32,
+    world_height: i32,
+}
+
+impl Section<'_> {
+    fn need(&self, count: usize) -> Result<(), String> {
+        if self.stream.position() + count > self.end {
+            return Err("Unexpected end of section".to_string());
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, count: usize) -> Result<(), String> {
+        self.need(count)?;
+        self.stream.skip(count);
+        Ok(())
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        self.need(1)?;
+        Ok(self.stream.read_byte())
+    }
+
+    fn flag(&mut self) -> Result<bool, String> {
+        match self.byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(format!("Invalid flag byte {}", other)),
+        }
+    }
+
+    fn int16(&mut self) -> Result<i16, String> {
+        self.need(2)?;
+        match self.stream.read_int16() {
+            value if value < 0 => Err(format!("Invalid count {}", value)),
+            value => Ok(value),
+        }
+    }
+
+    fn int32(&mut self) -> Result<i32, String> {
+        self.need(4)?;
+        Ok(self.stream.read_int32())
+    }
+
+    fn float(&mut self) -> Result<f32, String> {
+        self.need(4)?;
+        Ok(self.stream.read_float())
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = WorldParser::string_len(self.stream, 0).ok_or("Unexpected end of section")?;
+        self.need(len)?;
+        Ok(self.stream.read_string())
+    }
+
+    // 世界内的方块坐标
+    fn position(&mut self) -> Result<(i32, i32), String> {
+        let (x, y) = (self.int32()?, self.int32()?);
+        self.in_world(x, y)
+    }
+
+    // 以 16 位存储的方块坐标（物块实体）
+    fn short_position(&mut self) -> Result<(i32, i32), String> {
+        self.need(4)?;
+        let (x, y) = (self.stream.read_int16() as i32, self.stream.read_int16() as i32);
+        self.in_world(x, y)
+    }
+
+    fn in_world(&self, x: i32, y: i32) -> Result<(i32, i32), String> {
+        if x < 0 || y < 0 || x >= self.world_width || y >= self.world_height {
+            return Err(format!("Position ({}, {}) is outside the world", x, y));
+        }
+        Ok((x, y))
+    }
+
+    // 物块实体中的物品：16 位 id、前缀、16 位数量；空格子（id 0）返回 None
+    fn item(&mut self) -> Result<Option<ChestItem>, String> {
+        self.need(5)?;
+        let id = self.stream.read_int16() as i32;
+        let prefix = self.stream.read_byte() as i32;
+        let stack = self.stream.read_int16() as i32;
+        Ok((id > 0).then_some(ChestItem { id, stack, prefix }))
+    }
+}
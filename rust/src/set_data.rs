@@ -0,0 +1,66 @@
+// 预定义集合
+// 从 sets.js 迁移（由 scripts/extract_sets.cjs 自动生成）
+
+use crate::name_registry::NameKind;
+use crate::sets::{SetDef, SetEntryDef};
+
+pub const BUILTIN_SETS: &[SetDef] = &[
+    SetDef::new("Chests", &[
+        SetEntryDef::new(NameKind::Tile, 21, "Chest", None, None),
+        SetEntryDef::new(NameKind::Tile, 467, "Chest (Group 2)", None, None),
+    ]),
+    SetDef::new("Corruption Blocks", &[
+        SetEntryDef::new(NameKind::Tile, 23, "Corrupted Grass", None, None),
+        SetEntryDef::new(NameKind::Tile, 24, "Corrupted Weeds", None, None),
+        SetEntryDef::new(NameKind::Tile, 25, "Ebonstone", None, None),
+        SetEntryDef::new(NameKind::Tile, 32, "Corruption Thorns", None, None),
+        SetEntryDef::new(NameKind::Tile, 112, "Ebonsand", None, None),
+        SetEntryDef::new(NameKind::Tile, 163, "Purple Ice", None, None),
+        SetEntryDef::new(NameKind::Tile, 398, "Corrupt Hardened Sand", None, None),
+        SetEntryDef::new(NameKind::Tile, 400, "Corrupt Sandstone", None, None),
+        SetEntryDef::new(NameKind::Tile, 636, "Corrupt Vines", None, None),
+        SetEntryDef::new(NameKind::Tile, 661, "CorruptJungleGrass", None, None),
+    ]),
+    SetDef::new("Crimson Blocks", &[
+        SetEntryDef::new(NameKind::Tile, 199, "Flesh Grass", None, None),
+        SetEntryDef::new(NameKind::Tile, 200, "Red Ice", None, None),
+        SetEntryDef::new(NameKind::Tile, 201, "Flesh Weeds", None, None),
+        SetEntryDef::new(NameKind::Tile, 203, "Block", None, None),
+        SetEntryDef::new(NameKind::Tile, 205, "Crimstone Vines", None, None),
+        SetEntryDef::new(NameKind::Tile, 234, "Crimsand", None, None),
+        SetEntryDef::new(NameKind::Tile, 352, "Crimtane Thorns", None, None),
+        SetEntryDef::new(NameKind::Tile, 399, "Crimson Hardened Sand", None, None),
+        SetEntryDef::new(NameKind::Tile, 401, "Crimson Sandstone", None, None),
+        SetEntryDef::new(NameKind::Tile, 662, "CrimsonJungleGrass", None, None),
+    ]),
+    SetDef::new("Enchanted Items", &[
+        SetEntryDef::new(NameKind::Item, 55, "Enchanted Boomerang", None, None),
+        SetEntryDef::new(NameKind::Tile, 187, "3x2 Decos - Enchanted Sword", Some(918), None),
+        SetEntryDef::new(NameKind::Item, 989, "Enchanted Sword", None, None),
+    ]),
+    SetDef::new("Hallow Blocks", &[
+        SetEntryDef::new(NameKind::Tile, 109, "Hallowed Grass", None, None),
+        SetEntryDef::new(NameKind::Tile, 110, "Hallowed Plants", None, None),
+        SetEntryDef::new(NameKind::Tile, 113, "Hallowed Weeds", None, None),
+        SetEntryDef::new(NameKind::Tile, 115, "Hallowed Vines", None, None),
+        SetEntryDef::new(NameKind::Tile, 116, "Pearlsand", None, None),
+        SetEntryDef::new(NameKind::Tile, 117, "Pearlstone", None, None),
+        SetEntryDef::new(NameKind::Tile, 164, "Pink Ice", None, None),
+        SetEntryDef::new(NameKind::Tile, 402, "Hallow Hardened Sand", None, None),
+        SetEntryDef::new(NameKind::Tile, 403, "Hallow Sandstone", None, None),
+        SetEntryDef::new(NameKind::Tile, 492, "Golf Grass Hallowed", None, None),
+    ]),
+    SetDef::new("Item Spawning Statues", &[
+        SetEntryDef::new(NameKind::Tile, 105, "Bomb Statue", Some(612), None),
+        SetEntryDef::new(NameKind::Tile, 105, "Bomb Statue", Some(612), Some(162)),
+        SetEntryDef::new(NameKind::Tile, 105, "Heart Statue", Some(1332), None),
+        SetEntryDef::new(NameKind::Tile, 105, "Heart Statue", Some(1332), Some(162)),
+        SetEntryDef::new(NameKind::Tile, 105, "Star Statue", Some(72), None),
+        SetEntryDef::new(NameKind::Tile, 105, "Star Statue", Some(72), Some(162)),
+    ]),
+    SetDef::new("Spider Caves", &[
+        SetEntryDef::new(NameKind::Wall, 62, "Spider Cave Wall", None, None),
+        SetEntryDef::new(NameKind::Tile, 21, "Web Covered Chest", Some(540), None),
+        SetEntryDef::new(NameKind::Item, 939, "Web Slinger", None, None),
+    ]),
+];
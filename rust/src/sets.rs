@@ -0,0 +1,93 @@
+// 搜索集合
+// 对应原项目 sets.js 中的预定义集合（箱子、腐化方块、附魔物品……），可混合方块、墙体与物品条目
+
+use serde::{Deserialize, Serialize};
+use crate::name_registry::{NameKind, NameRegistry};
+use crate::set_data::BUILTIN_SETS;
+
+// 生成代码使用的静态定义
+pub struct SetDef {
+    pub name: &'static str,
+    pub entries: &'static [SetEntryDef],
+}
+
+impl SetDef {
+    pub const fn new(name: &'static str, entries: &'static [SetEntryDef]) -> Self {
+        Self { name, entries }
+    }
+}
+
+pub struct SetEntryDef {
+    pub kind: NameKind,
+    pub id: i32,
+    pub name: &'static str,
+    pub u: Option<i32>,
+    pub v: Option<i32>,
+}
+
+impl SetEntryDef {
+    pub const fn new(kind: NameKind, id: i32, name: &'static str, u: Option<i32>, v: Option<i32>) -> Self {
+        Self { kind, id, name, u, v }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetEntry {
+    pub kind: NameKind,
+    pub id: i32,
+    #[serde(default)]
+    pub name: String,
+    // 方块帧变体的起始帧坐标，只对方块条目有效
+    #[serde(default)]
+    pub u: Option<i32>,
+    #[serde(default)]
+    pub v: Option<i32>,
+}
+
+impl SetEntry {
+    /// 方块是否属于该条目，带 u/v 时比较帧坐标所属变体的起始坐标
+    pub fn matches_tile(&self, tile_id: i32, u: i32, v: i32) -> bool {
+        if self.kind != NameKind::Tile || self.id != tile_id {
+            return false;
+        }
+        if self.u.is_none() && self.v.is_none() {
+            return true;
+        }
+        let (start_u, start_v) = NameRegistry::frame(tile_id, u, v).map_or((u, v), |frame| (frame.u, frame.v));
+        self.u.is_none_or(|entry_u| entry_u == start_u) && self.v.is_none_or(|entry_v| entry_v == start_v)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TileSet {
+    pub name: String,
+    pub entries: Vec<SetEntry>,
+}
+
+impl TileSet {
+    fn from_def(def: &SetDef) -> Self {
+        Self {
+            name: def.name.to_string(),
+            entries: def
+                .entries
+                .iter()
+                .map(|entry| SetEntry {
+                    kind: entry.kind,
+                    id: entry.id,
+                    name: entry.name.to_string(),
+                    u: entry.u,
+                    v: entry.v,
+                })
+                .collect(),
+        }
+    }
+
+    /// sets.js 中的全部预定义集合
+    pub fn builtin() -> Vec<TileSet> {
+        BUILTIN_SETS.iter().map(Self::from_def).collect()
+    }
+
+    pub fn ids(&self, kind: NameKind) -> impl Iterator<Item = i32> + '_ {
+        self.entries.iter().filter(move |entry| entry.kind == kind).map(|entry| entry.id)
+    }
+}
@@ -0,0 +1,257 @@
+// 方块与墙体的空间索引
+// 世界按 32×32 分块，记录每种方块 / 墙体出现在哪些分块、分块内的哪些行及各自的数量；
+// 查找只扫描含有该 id 的行，找够分块内的数量即停止，开销与结果数成正比；总数直接读取。
+// 相比逐格记录坐标，内存只有其几十分之一
+
+use std::collections::HashMap;
+use crate::search_area::AreaFilter;
+use crate::world_loader::World;
+
+const INDEX_CHUNK_SIZE: i32 = 32;
+
+#[derive(Debug, Clone, Copy)]
+struct ChunkEntry {
+    chunk: u32,
+    rows: u32, // 含有该 id 的行，第 i 位对应分块内第 i 行
+    count: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Occurrences {
+    total: usize,
+    chunks: Vec<ChunkEntry>, // 按分块序号递增
+}
+
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    width: i32,
+    height: i32,
+    chunks_x: i32,
+    tiles: HashMap<i32, Occurrences>,
+    walls: HashMap<i32, Occurrences>,
+}
+
+impl SpatialIndex {
+    pub fn build(world: &World) -> Self {
+        let chunks_x = (world.width + INDEX_CHUNK_SIZE - 1) / INDEX_CHUNK_SIZE;
+        let chunks_y = (world.height + INDEX_CHUNK_SIZE - 1) / INDEX_CHUNK_SIZE;
+        let mut index = Self {
+            width: world.width,
+            height: world.height,
+            chunks_x,
+            tiles: HashMap::new(),
+            walls: HashMap::new(),
+        };
+
+        let tiles = &world.tiles;
+        let mut tile_counts: HashMap<i32, (u32, u32)> = HashMap::new();
+        let mut wall_counts: HashMap<i32, (u32, u32)> = HashMap::new();
+        for chunk in 0..chunks_x * chunks_y {
+            let (_, cy) = index.chunk_origin(chunk);
+            for (x, y) in index.chunk_positions(chunk) {
+                let idx = y as usize * world.width as usize + x as usize;
+                let row = 1 << (y - cy);
+                if tiles.is_active(idx) {
+                    let (rows, count) = tile_counts.entry(tiles.tile_id(idx)).or_insert((0, 0));
+                    *rows |= row;
+                    *count += 1;
+                }
+                let wall_id = tiles.wall_id(idx);
+                if wall_id > 0 {
+                    let (rows, count) = wall_counts.entry(wall_id).or_insert((0, 0));
+                    *rows |= row;
+                    *count += 1;
+                }
+            }
+            for (counts, target) in [(&mut tile_counts, &mut index.tiles), (&mut wall_counts, &mut index.walls)] {
+                for (id, (rows, count)) in counts.drain() {
+                    let occurrences = target.entry(id).or_default();
+                    occurrences.total += count as usize;
+                    occurrences.chunks.push(ChunkEntry {
+                        chunk: chunk as u32,
+                        rows,
+                        count,
+                    });
+                }
+            }
+        }
+        index
+    }
+
+    pub fn tile_count(&self, tile_id: i32) -> usize {
+        self.tiles.get(&tile_id).map_or(0, |occurrences| occurrences.total)
+    }
+
+    pub fn wall_count(&self, wall_id: i32) -> usize {
+        self.walls.get(&wall_id).map_or(0, |occurrences| occurrences.total)
+    }
+
+    /// 范围内这些方块的坐标，按行优先顺序
+    pub fn find_tiles(&self, world: &World, tile_ids: &[i32], area: &AreaFilter) -> Vec<(i32, i32)> {
+        let tiles = &world.tiles;
+        self.find(&self.tiles, tile_ids, area, |idx| {
+            tiles.is_active(idx) && tile_ids.contains(&tiles.tile_id(idx))
+        })
+    }
+
+    /// 范围内这些墙体的坐标，按行优先顺序；没有墙（id 0）不在索引中
+    pub fn find_walls(&self, world: &World, wall_ids: &[i32], area: &AreaFilter) -> Vec<(i32, i32)> {
+        let tiles = &world.tiles;
+        self.find(&self.walls, wall_ids, area, |idx| wall_ids.contains(&tiles.wall_id(idx)))
+    }
+
+    fn find(&self, map: &HashMap<i32, Occurrences>, ids: &[i32], area: &AreaFilter, matches: impl Fn(usize) -> bool) -> Vec<(i32, i32)> {
+        // 同一分块中多个 id 的行合并、数量相加
+        let mut entries: Vec<ChunkEntry> = ids
+            .iter()
+            .filter_map(|id| map.get(id))
+            .flat_map(|occurrences| occurrences.chunks.iter().copied())
+            .collect();
+        entries.sort_unstable_by_key(|entry| entry.chunk);
+        entries.dedup_by(|entry, merged| {
+            let same = entry.chunk == merged.chunk;
+            if same {
+                merged.rows |= entry.rows;
+                merged.count += entry.count;
+            }
+            same
+        });
+
+        let (x0, y0, x1, y1) = area.bounds();
+        let mut positions = Vec::new();
+        for entry in entries {
+            let (cx, cy) = self.chunk_origin(entry.chunk as i32);
+            if cx >= x1 || cy >= y1 || cx + INDEX_CHUNK_SIZE <= x0 || cy + INDEX_CHUNK_SIZE <= y0 {
+                continue;
+            }
+            let mut found = 0;
+            let mut rows = entry.rows;
+            while rows != 0 && found < entry.count {
+                let y = cy + rows.trailing_zeros() as i32;
+                rows &= rows - 1;
+                if y < y0 || y >= y1 {
+                    continue;
+                }
+                for x in cx..(cx + INDEX_CHUNK_SIZE).min(self.width) {
+                    if matches(y as usize * self.width as usize + x as usize) {
+                        found += 1;
+                        if area.contains(x, y) {
+                            positions.push((x, y));
+                        }
+                    }
+                }
+            }
+        }
+        positions.sort_unstable_by_key(|&(x, y)| (y, x));
+        positions
+    }
+
+    fn chunk_origin(&self, chunk: i32) -> (i32, i32) {
+        ((chunk % self.chunks_x) * INDEX_CHUNK_SIZE, (chunk / self.chunks_x) * INDEX_CHUNK_SIZE)
+    }
+
+    // 分块内的全部坐标（裁剪到世界范围）
+    fn chunk_positions(&self, chunk: i32) -> impl Iterator<Item = (i32, i32)> {
+        let (cx, cy) = self.chunk_origin(chunk);
+        let (x1, y1) = ((cx + INDEX_CHUNK_SIZE).min(self.width), (cy + INDEX_CHUNK_SIZE).min(self.height));
+        (cy..y1).flat_map(move |y| (cx..x1).map(move |x| (x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::name_registry::NameRegistry;
+    use crate::query::Query;
+    use crate::search_area::SearchArea;
+    use crate::test_fixtures::{self, tile};
+    use crate::world_loader::Tile;
+
+    // 伪随机的方块与墙体，尺寸不是分块大小的整数倍
+    fn sample() -> World {
+        let (width, height) = (101, 75);
+        let mut seed = 12345u64;
+        let tiles: Vec<Tile> = (0..width * height)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                let mut tile = tile(if seed.is_multiple_of(97) { 211 } else { (seed % 5) as i32 });
+                tile.is_active = !seed.is_multiple_of(3);
+                tile.wall_id = ((seed >> 8) % 4) as i32;
+                tile
+            })
+            .collect();
+        test_fixtures::world(width, height, &tiles)
+    }
+
+    fn areas(world: &World) -> Vec<AreaFilter> {
+        let area: SearchArea = serde_json::from_str(
+            r#"{ "rect": { "x": 13, "y": 5, "width": 60, "height": 50 },
+                 "polygon": [{ "x": 10, "y": 0 }, { "x": 90, "y": 20 }, { "x": 40, "y": 70 }] }"#,
+        )
+        .unwrap();
+        vec![AreaFilter::whole(world), AreaFilter::new(&area, world, None).unwrap()]
+    }
+
+    #[test]
+    fn lookups_match_a_full_scan() {
+        let world = sample();
+        let index = SpatialIndex::build(&world);
+        let tiles = &world.tiles;
+        let at = |x: i32, y: i32| (y * world.width + x) as usize;
+        for area in areas(&world) {
+            for ids in [vec![211], vec![1, 211], vec![0], vec![42]] {
+                let scanned: Vec<(i32, i32)> = area
+                    .positions()
+                    .filter(|&(x, y)| tiles.is_active(at(x, y)) && ids.contains(&tiles.tile_id(at(x, y))))
+                    .collect();
+                assert_eq!(index.find_tiles(&world, &ids, &area), scanned, "tiles {:?}", ids);
+            }
+            for ids in [vec![3], vec![1, 2]] {
+                let scanned: Vec<(i32, i32)> = area
+                    .positions()
+                    .filter(|&(x, y)| ids.contains(&tiles.wall_id(at(x, y))))
+                    .collect();
+                assert_eq!(index.find_walls(&world, &ids, &area), scanned, "walls {:?}", ids);
+            }
+        }
+    }
+
+    #[test]
+    fn counts_match_a_full_scan() {
+        let world = sample();
+        let index = SpatialIndex::build(&world);
+        let tiles = &world.tiles;
+        let count = (0..tiles.len()).filter(|&idx| tiles.is_active(idx) && tiles.tile_id(idx) == 211).count();
+        assert!(count > 0);
+        assert_eq!(index.tile_count(211), count);
+        assert_eq!(index.wall_count(2), (0..tiles.len()).filter(|&idx| tiles.wall_id(idx) == 2).count());
+        assert_eq!(index.wall_count(0), 0);
+    }
+
+    #[test]
+    fn indexed_queries_match_a_full_scan() {
+        let world = sample();
+        let index = SpatialIndex::build(&world);
+        let names = NameRegistry::new();
+        for area in areas(&world) {
+            for text in [
+                "tile:211",
+                "tile:211 AND NOT wall:2",
+                "tile:211 OR wall:3",
+                "(tile:1 OR tile:2) AND wall:3",
+                "wall:0 AND tile:211",
+                "NOT tile:211 AND wall:3",
+                "liquid>0 OR tile:211",
+            ] {
+                let query = Query::parse(text, &world, &names).unwrap();
+                let result = query.evaluate(&world, &index, &area, 0);
+                let scanned: Vec<(i32, i32)> = area.positions().filter(|&(x, y)| query.matches(&world, x, y)).collect();
+                let found: Vec<(i32, i32)> = result.positions.iter().map(|p| (p.x, p.y)).collect();
+                assert_eq!(found, scanned, "{}", text);
+                assert_eq!(result.total, scanned.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,320 @@
+// 测试用的小型世界：按 WorldLoader 的读取顺序写出文件，或直接组装 World
+
+use miniz_oxide::deflate::compress_to_vec;
+use crate::nbt::Tag;
+use crate::tile_storage::TileStorage;
+use crate::twld_loader::ModData;
+use crate::world_loader::{Chest, ChestItem, Sign, Tile, TileEntity, World, NPC};
+
+pub(crate) const VERSION: i32 = 279;
+
+// 箱子区段中每个箱子的格数
+const CHEST_SLOTS: usize = 4;
+
+/// 实心方块
+pub(crate) fn tile(tile_id: i32) -> Tile {
+    Tile { tile_id, ..Tile::damaged() }
+}
+
+pub(crate) fn world(width: i32, height: i32, tiles: &[Tile]) -> World {
+    World {
+        name: "Test".to_string(),
+        width,
+        height,
+        world_id: 1,
+        tiles: TileStorage::from_tiles(tiles),
+        chests: Vec::new(),
+        npcs: Vec::new(),
+        signs: Vec::new(),
+        tile_entities: Vec::new(),
+        warnings: Vec::new(),
+        mods: ModData::default(),
+    }
+}
+
+// 世界文件的各部分，偏移均相对文件开头
+pub(crate) struct WorldFile {
+    pub bytes: Vec<u8>,
+    // 每个方块记录的起始偏移
+    pub tile_offsets: Vec<usize>,
+    // 位置表：世界头、方块、箱子、告示牌、NPC、物块实体、区段结尾
+    pub positions: Vec<i32>,
+}
+
+/// 写出世界文件；物品格数固定为 CHEST_SLOTS，超出的物品被忽略
+pub(crate) fn world_file(world: &World, tiles: &[Tile]) -> WorldFile {
+    let section_count = 7;
+    let mut out = Vec::new();
+    out.extend(VERSION.to_le_bytes());
+    out.extend([0; 8]); // 元数据
+    out.extend(1u32.to_le_bytes()); // 修订号
+    out.extend([0; 8]); // 收藏
+    out.extend((section_count as i16).to_le_bytes());
+    let positions_at = out.len();
+    out.extend(vec![0; section_count * 4]);
+    out.extend(0i16.to_le_bytes()); // 重要性位图
+
+    let mut positions = vec![out.len() as i32];
+    write_string(&mut out, &world.name);
+    write_string(&mut out, "seed");
+    out.extend([0; 8 + 16]); // 生成器版本、UUID
+    out.extend(world.world_id.to_le_bytes());
+    for bound in [0, world.width * 16, 0, world.height * 16] {
+        out.extend(bound.to_le_bytes());
+    }
+    out.extend(world.height.to_le_bytes());
+    out.extend(world.width.to_le_bytes());
+    out.extend(1i32.to_le_bytes()); // 专家模式
+
+    positions.push(out.len() as i32);
+    let mut tile_offsets = Vec::with_capacity(tiles.len());
+    for tile in tiles {
+        tile_offsets.push(out.len());
+        write_tile(&mut out, tile);
+    }
+
+    positions.push(out.len() as i32);
+    out.extend((world.chests.len() as i16).to_le_bytes());
+    out.extend((CHEST_SLOTS as i16).to_le_bytes());
+    for chest in &world.chests {
+        out.extend(chest.x.to_le_bytes());
+        out.extend(chest.y.to_le_bytes());
+        write_string(&mut out, &chest.name);
+        for slot in 0..CHEST_SLOTS {
+            match chest.items.get(slot) {
+                Some(item) => {
+                    out.extend((item.stack as i16).to_le_bytes());
+                    out.extend(item.id.to_le_bytes());
+                    out.push(item.prefix as u8);
+                }
+                None => out.extend(0i16.to_le_bytes()),
+            }
+        }
+    }
+
+    positions.push(out.len() as i32);
+    out.extend((world.signs.len() as i16).to_le_bytes());
+    for sign in &world.signs {
+        write_string(&mut out, &sign.text);
+        out.extend(sign.x.to_le_bytes());
+        out.extend(sign.y.to_le_bytes());
+    }
+
+    positions.push(out.len() as i32);
+    out.extend(0i32.to_le_bytes()); // 微光 NPC
+    for npc in &world.npcs {
+        out.push(1);
+        out.extend(npc.sprite_id.to_le_bytes());
+        write_string(&mut out, &npc.name);
+        out.extend(npc.position_x.to_le_bytes());
+        out.extend(npc.position_y.to_le_bytes());
+        out.push(npc.is_homeless as u8);
+        out.extend(npc.home_x.to_le_bytes());
+        out.extend(npc.home_y.to_le_bytes());
+        out.push(0);
+    }
+    out.push(0);
+
+    positions.push(out.len() as i32);
+    out.extend((world.tile_entities.len() as i32).to_le_bytes());
+    for entity in &world.tile_entities {
+        write_tile_entity(&mut out, entity);
+    }
+
+    positions.push(out.len() as i32);
+    for (i, position) in positions.iter().enumerate() {
+        out[positions_at + i * 4..positions_at + i * 4 + 4].copy_from_slice(&position.to_le_bytes());
+    }
+    WorldFile { bytes: out, tile_offsets, positions }
+}
+
+pub(crate) fn chest(x: i32, y: i32, name: &str, items: &[(i32, i32)]) -> Chest {
+    Chest {
+        x,
+        y,
+        name: name.to_string(),
+        items: items
+            .iter()
+            .map(|&(id, stack)| ChestItem { id, stack, prefix: 0 })
+            .collect(),
+    }
+}
+
+pub(crate) fn tile_entity(entity_type: i32, x: i32, y: i32, items: &[(i32, i32)]) -> TileEntity {
+    TileEntity {
+        id: 0,
+        position_x: x,
+        position_y: y,
+        entity_type,
+        items: items
+            .iter()
+            .map(|&(id, stack)| ChestItem { id, stack, prefix: 0 })
+            .collect(),
+    }
+}
+
+pub(crate) fn sign(x: i32, y: i32, text: &str) -> Sign {
+    Sign { x, y, text: text.to_string() }
+}
+
+pub(crate) fn npc(sprite_id: i32, name: &str, home_x: i32, home_y: i32) -> NPC {
+    NPC {
+        id: 0,
+        name: name.to_string(),
+        sprite_id,
+        position_x: home_x as f32 * 16.0,
+        position_y: home_y as f32 * 16.0,
+        home_x,
+        home_y,
+        direction: 0,
+        is_homeless: false,
+    }
+}
+
+// 人体模型与帽架的物品依次放入前几格
+fn write_tile_entity(out: &mut Vec<u8>, entity: &TileEntity) {
+    let write_item = |out: &mut Vec<u8>, item: Option<&ChestItem>| {
+        let (id, prefix, stack) = item.map_or((0, 0, 0), |item| (item.id, item.prefix, item.stack));
+        out.extend((id as i16).to_le_bytes());
+        out.push(prefix as u8);
+        out.extend((stack as i16).to_le_bytes());
+    };
+    out.push(entity.entity_type as u8);
+    out.extend(entity.id.to_le_bytes());
+    out.extend((entity.position_x as i16).to_le_bytes());
+    out.extend((entity.position_y as i16).to_le_bytes());
+    let mask = ((1u32 << entity.items.len()) - 1) as u8;
+    match entity.entity_type {
+        0 | 2 => out.extend([0, 0]),
+        1 | 4 | 6 => write_item(out, entity.items.first()),
+        3 => out.extend([mask, 0]),
+        5 => out.push(mask),
+        _ => {}
+    }
+    if matches!(entity.entity_type, 3 | 5) {
+        for item in &entity.items {
+            write_item(out, Some(item));
+        }
+    }
+}
+
+fn write_string(out: &mut Vec<u8>, text: &str) {
+    let mut len = text.len();
+    while len >= 0x80 {
+        out.push((len as u8 & 0x7F) | 0x80);
+        len >>= 7;
+    }
+    out.push(len as u8);
+    out.extend(text.as_bytes());
+}
+
+// 与 WorldLoader::read_tile 的读取顺序一致
+fn write_tile(out: &mut Vec<u8>, tile: &Tile) {
+    out.push(tile.is_active as u8);
+    if !tile.is_active {
+        return;
+    }
+    out.extend((tile.tile_id as u16).to_le_bytes());
+    out.extend((tile.wall_id as u16).to_le_bytes());
+    out.push((tile.liquid > 0) as u8);
+    if tile.liquid > 0 {
+        out.extend((tile.liquid as u16).to_le_bytes());
+    }
+    out.push(tile.is_actuated as u8);
+    for color in [tile.color, tile.wall_color] {
+        out.push((color > 0) as u8);
+        if color > 0 {
+            out.push(color as u8);
+        }
+    }
+    out.extend((tile.u as u16).to_le_bytes());
+    out.extend((tile.v as u16).to_le_bytes());
+    out.push(tile.brick_style as u8);
+    out.push(tile.full as u8);
+    out.push(tile.half_brick as u8);
+    out.push(tile.slope as u8);
+    for flag in [tile.wire_red, tile.wire_blue, tile.wire_green, tile.wire_yellow, tile.actuator, tile.in_active] {
+        out.push(flag as u8);
+    }
+    out.extend((tile.wall_u as u16).to_le_bytes());
+    out.extend((tile.wall_v as u16).to_le_bytes());
+    out.push(tile.wall_full as u8);
+    out.push(tile.wall_half_brick as u8);
+    out.push(tile.wall_slope as u8);
+}
+
+/// 单个成员的 gzip 数据；CRC 不校验，写 0
+pub(crate) fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x1F, 0x8B, 8, 0, 0, 0, 0, 0, 0, 0];
+    out.extend(compress_to_vec(data, 6));
+    out.extend([0; 4]);
+    out.extend((data.len() as u32).to_le_bytes());
+    out
+}
+
+/// 写出以 root 为根、名称为空的 TagIO 数据
+pub(crate) fn tag_data(root: &Tag) -> Vec<u8> {
+    let mut out = vec![tag_type(root)];
+    out.extend(0u16.to_be_bytes());
+    write_tag(&mut out, root);
+    out
+}
+
+fn tag_type(tag: &Tag) -> u8 {
+    match tag {
+        Tag::Byte(_) => 1,
+        Tag::Short(_) => 2,
+        Tag::Int(_) => 3,
+        Tag::Long(_) => 4,
+        Tag::Float(_) => 5,
+        Tag::Double(_) => 6,
+        Tag::ByteArray(_) => 7,
+        Tag::String(_) => 8,
+        Tag::List(_) => 9,
+        Tag::Compound(_) => 10,
+        Tag::IntArray(_) => 11,
+        Tag::LongArray(_) => 12,
+    }
+}
+
+// 大端序，与 TagReader 的读取顺序一致
+fn write_tag(out: &mut Vec<u8>, tag: &Tag) {
+    let write_str = |out: &mut Vec<u8>, text: &str| {
+        out.extend((text.len() as u16).to_be_bytes());
+        out.extend(text.as_bytes());
+    };
+    match tag {
+        Tag::Byte(value) => out.push(*value as u8),
+        Tag::Short(value) => out.extend(value.to_be_bytes()),
+        Tag::Int(value) => out.extend(value.to_be_bytes()),
+        Tag::Long(value) => out.extend(value.to_be_bytes()),
+        Tag::Float(value) => out.extend(value.to_be_bytes()),
+        Tag::Double(value) => out.extend(value.to_be_bytes()),
+        Tag::ByteArray(bytes) => {
+            out.extend((bytes.len() as i32).to_be_bytes());
+            out.extend(bytes);
+        }
+        Tag::String(text) => write_str(out, text),
+        Tag::List(items) => {
+            out.push(items.first().map_or(0, tag_type));
+            out.extend((items.len() as i32).to_be_bytes());
+            items.iter().for_each(|item| write_tag(out, item));
+        }
+        Tag::Compound(entries) => {
+            for (name, entry) in entries {
+                out.push(tag_type(entry));
+                write_str(out, name);
+                write_tag(out, entry);
+            }
+            out.push(0);
+        }
+        Tag::IntArray(values) => {
+            out.extend((values.len() as i32).to_be_bytes());
+            values.iter().for_each(|value| out.extend(value.to_be_bytes()));
+        }
+        Tag::LongArray(values) => {
+            out.extend((values.len() as i32).to_be_bytes());
+            values.iter().for_each(|value| out.extend(value.to_be_bytes()));
+        }
+    }
+}
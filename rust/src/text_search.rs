@@ -0,0 +1,207 @@
+// 告示牌文字与箱子名称的全文搜索
+// 子串匹配不区分大小写（Unicode），正则使用 regex-lite 语法，(?i) 只对 ASCII 字母生效
+
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use crate::bearing::{Bearing, Located};
+
+// 片段中匹配前后保留的字符数
+const SNIPPET_CONTEXT: usize = 24;
+
+/// JS 传入的搜索选项，如 { pattern: "iron", regex: false, signs: true, chests: true }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextSearchOptions {
+    pub pattern: String,
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(default = "default_true")]
+    pub signs: bool,
+    #[serde(default = "default_true")]
+    pub chests: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextSource {
+    Sign,
+    Chest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextMatch {
+    pub source: TextSource,
+    pub x: i32,
+    pub y: i32,
+    pub text: String, // 完整文字
+    // 第一处匹配的片段：before + matched + after，界面高亮 matched
+    pub before: String,
+    pub matched: String,
+    pub after: String,
+    pub match_count: usize,
+    #[serde(default)]
+    pub bearing: Option<Bearing>,
+}
+
+impl Located for TextMatch {
+    fn location(&self) -> Option<(f64, f64)> {
+        Some((self.x as f64, self.y as f64))
+    }
+
+    fn set_bearing(&mut self, bearing: Bearing) {
+        self.bearing = Some(bearing);
+    }
+}
+
+pub enum TextMatcher {
+    Substring { needle: String, case_sensitive: bool },
+    Regex(Regex),
+}
+
+impl TextMatcher {
+    pub fn new(options: &TextSearchOptions) -> Result<Self, String> {
+        if options.pattern.is_empty() {
+            return Err("Search text must not be empty".to_string());
+        }
+        if options.regex {
+            let pattern = match options.case_sensitive {
+                true => options.pattern.clone(),
+                false => format!("(?i){}", options.pattern),
+            };
+            return Regex::new(&pattern)
+                .map(TextMatcher::Regex)
+                .map_err(|e| format!("Invalid regular expression: {}", e));
+        }
+        Ok(TextMatcher::Substring {
+            needle: options.pattern.clone(),
+            case_sensitive: options.case_sensitive,
+        })
+    }
+
+    /// 全部不重叠匹配的字节范围；空匹配不计
+    pub fn find_all(&self, text: &str) -> Vec<(usize, usize)> {
+        match self {
+            TextMatcher::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|m| !m.is_empty())
+                .map(|m| (m.start(), m.end()))
+                .collect(),
+            TextMatcher::Substring { needle, case_sensitive: true } => text
+                .match_indices(needle.as_str())
+                .map(|(start, m)| (start, start + m.len()))
+                .collect(),
+            TextMatcher::Substring { needle, case_sensitive: false } => find_ignore_case(text, needle),
+        }
+    }
+
+    /// 匹配时返回带高亮片段的结果
+    pub fn search(&self, source: TextSource, x: i32, y: i32, text: &str) -> Option<TextMatch> {
+        let ranges = self.find_all(text);
+        let &(start, end) = ranges.first()?;
+        let before: String = text[..start].chars().rev().take(SNIPPET_CONTEXT).collect::<Vec<_>>().into_iter().rev().collect();
+        let after: String = text[end..].chars().take(SNIPPET_CONTEXT).collect();
+        let ellipsis_before = if before.len() < start { "…" } else { "" };
+        let ellipsis_after = if after.len() < text.len() - end { "…" } else { "" };
+        Some(TextMatch {
+            source,
+            x,
+            y,
+            text: text.to_string(),
+            before: single_line(&format!("{}{}", ellipsis_before, before)),
+            matched: single_line(&text[start..end]),
+            after: single_line(&format!("{}{}", after, ellipsis_after)),
+            match_count: ranges.len(),
+            bearing: None,
+        })
+    }
+}
+
+// 逐字符比较小写形式，返回原文中的字节范围
+fn find_ignore_case(text: &str, needle: &str) -> Vec<(usize, usize)> {
+    let needle: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        // 从第 i 个字符开始逐个展开小写形式与 needle 比较
+        let mut matched = 0;
+        let mut j = i;
+        while matched < needle.len() && j < chars.len() {
+            let lower: Vec<char> = chars[j].1.to_lowercase().collect();
+            if needle[matched..].starts_with(&lower) {
+                matched += lower.len();
+                j += 1;
+            } else {
+                break;
+            }
+        }
+        if matched == needle.len() {
+            let end = chars.get(j).map_or(text.len(), |&(pos, _)| pos);
+            ranges.push((chars[i].0, end));
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+// 告示牌文字可能有多行，片段中换行显示为空格
+fn single_line(text: &str) -> String {
+    text.replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(pattern: &str, case_sensitive: bool) -> TextMatcher {
+        TextMatcher::new(&TextSearchOptions {
+            pattern: pattern.to_string(),
+            regex: false,
+            case_sensitive,
+            signs: true,
+            chests: true,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn ignore_case_ranges_are_byte_offsets_into_the_original_text() {
+        let text = "Ärger und ÄRGER";
+        let ranges = find_ignore_case(text, "ärger");
+        assert_eq!(ranges, vec![(0, 6), (11, 17)]);
+        for (start, end) in ranges {
+            assert_eq!(text[start..end].to_lowercase(), "ärger");
+        }
+    }
+
+    #[test]
+    fn ignore_case_follows_multi_char_lowercase() {
+        // İ 的小写形式是两个字符 i̇
+        let text = "Istanbul İstanbul";
+        assert_eq!(find_ignore_case(text, "i\u{307}stanbul"), vec![(9, text.len())]);
+        assert!(find_ignore_case(text, "xyz").is_empty());
+    }
+
+    #[test]
+    fn snippet_keeps_character_boundaries() {
+        let found = matcher("テキ", false)
+            .search(TextSource::Sign, 1, 2, "日本語のテキスト\n二行目")
+            .unwrap();
+        assert_eq!(found.before, "日本語の");
+        assert_eq!(found.matched, "テキ");
+        assert_eq!(found.after, "スト 二行目");
+        assert_eq!(found.match_count, 1);
+    }
+
+    #[test]
+    fn case_sensitive_substring_only_matches_exact_case() {
+        assert_eq!(matcher("Iron", true).find_all("iron Iron IRON"), vec![(5, 9)]);
+        assert_eq!(matcher("Iron", false).find_all("iron Iron IRON").len(), 3);
+    }
+}
@@ -0,0 +1,302 @@
+// 紧凑的方块存储
+// 按字段拆分为多个数组（结构数组），布尔标志按位打包，帧坐标只为有帧的方块稀疏存储
+// 墙体帧坐标不保存：游戏加载时按相邻墙体重新计算，渲染与搜索也不使用，还原的方块中为 0
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use serde::de::Deserializer;
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use crate::world_loader::Tile;
+
+// 标志位
+const FLAG_ACTIVE: u16 = 1 << 0;
+const FLAG_ACTUATED: u16 = 1 << 1;
+const FLAG_FULL: u16 = 1 << 2;
+const FLAG_HALF_BRICK: u16 = 1 << 3;
+const FLAG_WIRE_RED: u16 = 1 << 4;
+const FLAG_WIRE_BLUE: u16 = 1 << 5;
+const FLAG_WIRE_GREEN: u16 = 1 << 6;
+const FLAG_WIRE_YELLOW: u16 = 1 << 7;
+const FLAG_ACTUATOR: u16 = 1 << 8;
+const FLAG_IN_ACTIVE: u16 = 1 << 9;
+const FLAG_WALL_FULL: u16 = 1 << 10;
+const FLAG_WALL_HALF_BRICK: u16 = 1 << 11;
+
+// 方块帧坐标：u, v
+type Frame = [u16; 2];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MemoryUsage {
+    pub tile_count: usize,
+    pub framed_tile_count: usize,
+    pub total_bytes: usize,
+    // 等量 Vec<Tile> 所需的字节数，用于对比
+    pub unpacked_bytes: usize,
+}
+
+#[derive(Debug, Clone, Default, Hash)]
+pub struct TileStorage {
+    tile_ids: Vec<u16>,
+    wall_ids: Vec<u16>,
+    liquids: Vec<u8>,
+    colors: Vec<u8>,
+    wall_colors: Vec<u8>,
+    // 低 4 位为方块斜坡，高 4 位为墙体斜坡
+    slopes: Vec<u8>,
+    brick_styles: Vec<u8>,
+    flags: Vec<u16>,
+    // 按索引升序排列的稀疏帧坐标
+    frames: Vec<(u32, Frame)>,
+}
+
+impl TileStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            tile_ids: Vec::with_capacity(capacity),
+            wall_ids: Vec::with_capacity(capacity),
+            liquids: Vec::with_capacity(capacity),
+            colors: Vec::with_capacity(capacity),
+            wall_colors: Vec::with_capacity(capacity),
+            slopes: Vec::with_capacity(capacity),
+            brick_styles: Vec::with_capacity(capacity),
+            flags: Vec::with_capacity(capacity),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn from_tiles(tiles: &[Tile]) -> Self {
+        let mut storage = Self::with_capacity(tiles.len());
+        for tile in tiles {
+            storage.push(tile);
+        }
+        storage
+    }
+
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    /// 全部方块内容的哈希，内容相同时相同；用于判断 JS 传入的世界是否被修改过
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+
+    pub fn push(&mut self, tile: &Tile) {
+        let idx = self.len();
+        self.tile_ids.push(tile.tile_id as u16);
+        self.wall_ids.push(tile.wall_id as u16);
+        self.liquids.push(tile.liquid.clamp(0, u8::MAX as i32) as u8);
+        self.colors.push(tile.color as u8);
+        self.wall_colors.push(tile.wall_color as u8);
+        self.slopes.push(Self::pack_slopes(tile));
+        self.brick_styles.push(tile.brick_style as u8);
+        self.flags.push(Self::pack_flags(tile));
+
+        let frame = Self::pack_frame(tile);
+        if frame != [0; 2] {
+            self.frames.push((idx as u32, frame));
+        }
+    }
+
+    /// 覆盖指定位置的方块（编辑世界时使用）
+    pub fn set(&mut self, idx: usize, tile: &Tile) {
+        if idx >= self.len() {
+            return;
+        }
+        self.tile_ids[idx] = tile.tile_id as u16;
+        self.wall_ids[idx] = tile.wall_id as u16;
+        self.liquids[idx] = tile.liquid.clamp(0, u8::MAX as i32) as u8;
+        self.colors[idx] = tile.color as u8;
+        self.wall_colors[idx] = tile.wall_color as u8;
+        self.slopes[idx] = Self::pack_slopes(tile);
+        self.brick_styles[idx] = tile.brick_style as u8;
+        self.flags[idx] = Self::pack_flags(tile);
+
+        let frame = Self::pack_frame(tile);
+        match self.frames.binary_search_by_key(&(idx as u32), |&(i, _)| i) {
+            Ok(pos) if frame == [0; 2] => {
+                self.frames.remove(pos);
+            }
+            Ok(pos) => self.frames[pos].1 = frame,
+            Err(pos) if frame != [0; 2] => self.frames.insert(pos, (idx as u32, frame)),
+            Err(_) => {}
+        }
+    }
+
+    /// 还原完整的方块视图
+    pub fn get(&self, idx: usize) -> Option<Tile> {
+        if idx >= self.len() {
+            return None;
+        }
+
+        let flags = self.flags[idx];
+        let [u, v] = self.frame(idx);
+        let slopes = self.slopes[idx];
+        Some(Tile {
+            tile_id: self.tile_ids[idx] as i32,
+            wall_id: self.wall_ids[idx] as i32,
+            liquid: self.liquids[idx] as i32,
+            is_active: flags & FLAG_ACTIVE != 0,
+            is_actuated: flags & FLAG_ACTUATED != 0,
+            color: self.colors[idx] as i32,
+            u: u as i32,
+            v: v as i32,
+            brick_style: self.brick_styles[idx] as i32,
+            full: flags & FLAG_FULL != 0,
+            half_brick: flags & FLAG_HALF_BRICK != 0,
+            slope: (slopes & 0x0F) as i32,
+            wire_red: flags & FLAG_WIRE_RED != 0,
+            wire_blue: flags & FLAG_WIRE_BLUE != 0,
+            wire_green: flags & FLAG_WIRE_GREEN != 0,
+            wire_yellow: flags & FLAG_WIRE_YELLOW != 0,
+            actuator: flags & FLAG_ACTUATOR != 0,
+            in_active: flags & FLAG_IN_ACTIVE != 0,
+            wall_color: self.wall_colors[idx] as i32,
+            wall_u: 0,
+            wall_v: 0,
+            wall_full: flags & FLAG_WALL_FULL != 0,
+            wall_half_brick: flags & FLAG_WALL_HALF_BRICK != 0,
+            wall_slope: (slopes >> 4) as i32,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Tile> + '_ {
+        (0..self.len()).filter_map(move |idx| self.get(idx))
+    }
+
+    // 常用字段的快速访问，避免还原整个方块
+
+    pub fn is_active(&self, idx: usize) -> bool {
+        self.flags.get(idx).is_some_and(|flags| flags & FLAG_ACTIVE != 0)
+    }
+
+    pub fn tile_id(&self, idx: usize) -> i32 {
+        self.tile_ids.get(idx).map_or(0, |&id| id as i32)
+    }
+
+    pub fn wall_id(&self, idx: usize) -> i32 {
+        self.wall_ids.get(idx).map_or(0, |&id| id as i32)
+    }
+
+    pub fn liquid(&self, idx: usize) -> i32 {
+        self.liquids.get(idx).map_or(0, |&amount| amount as i32)
+    }
+
+    pub fn color(&self, idx: usize) -> i32 {
+        self.colors.get(idx).map_or(0, |&color| color as i32)
+    }
+
+    pub fn wall_color(&self, idx: usize) -> i32 {
+        self.wall_colors.get(idx).map_or(0, |&color| color as i32)
+    }
+
+    /// 红、蓝、绿、黄四色电线
+    pub fn wires(&self, idx: usize) -> [bool; 4] {
+        let flags = self.flags.get(idx).copied().unwrap_or(0);
+        [FLAG_WIRE_RED, FLAG_WIRE_BLUE, FLAG_WIRE_GREEN, FLAG_WIRE_YELLOW].map(|flag| flags & flag != 0)
+    }
+
+    pub fn has_actuator(&self, idx: usize) -> bool {
+        self.flags.get(idx).is_some_and(|flags| flags & FLAG_ACTUATOR != 0)
+    }
+
+    pub fn frame(&self, idx: usize) -> Frame {
+        match self.frames.binary_search_by_key(&(idx as u32), |&(i, _)| i) {
+            Ok(pos) => self.frames[pos].1,
+            Err(_) => [0; 2],
+        }
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let per_tile = std::mem::size_of::<u16>() * 3 + std::mem::size_of::<u8>() * 5;
+        let frame_bytes = self.frames.len() * std::mem::size_of::<(u32, Frame)>();
+        MemoryUsage {
+            tile_count: self.len(),
+            framed_tile_count: self.frames.len(),
+            total_bytes: self.len() * per_tile + frame_bytes,
+            unpacked_bytes: self.len() * std::mem::size_of::<Tile>(),
+        }
+    }
+
+    fn pack_flags(tile: &Tile) -> u16 {
+        let mut flags = 0;
+        for (set, flag) in [
+            (tile.is_active, FLAG_ACTIVE),
+            (tile.is_actuated, FLAG_ACTUATED),
+            (tile.full, FLAG_FULL),
+            (tile.half_brick, FLAG_HALF_BRICK),
+            (tile.wire_red, FLAG_WIRE_RED),
+            (tile.wire_blue, FLAG_WIRE_BLUE),
+            (tile.wire_green, FLAG_WIRE_GREEN),
+            (tile.wire_yellow, FLAG_WIRE_YELLOW),
+            (tile.actuator, FLAG_ACTUATOR),
+            (tile.in_active, FLAG_IN_ACTIVE),
+            (tile.wall_full, FLAG_WALL_FULL),
+            (tile.wall_half_brick, FLAG_WALL_HALF_BRICK),
+        ] {
+            if set {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+
+    fn pack_slopes(tile: &Tile) -> u8 {
+        (tile.slope as u8 & 0x0F) | ((tile.wall_slope as u8 & 0x0F) << 4)
+    }
+
+    fn pack_frame(tile: &Tile) -> Frame {
+        [tile.u as u16, tile.v as u16]
+    }
+}
+
+// 序列化为方块数组，与原先 Vec<Tile> 的 JS 格式保持一致
+impl Serialize for TileStorage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for tile in self.iter() {
+            seq.serialize_element(&tile)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TileStorage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tiles: Vec<Tile> = Vec::deserialize(deserializer)?;
+        Ok(Self::from_tiles(&tiles))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::tile;
+
+    #[test]
+    fn content_hash_follows_tile_edits() {
+        let tiles = vec![tile(1), tile(2), tile(3)];
+        let mut storage = TileStorage::from_tiles(&tiles);
+        let original = storage.content_hash();
+        assert_eq!(TileStorage::from_tiles(&tiles).content_hash(), original);
+
+        storage.set(1, &Tile { wire_red: true, ..tile(2) });
+        assert_ne!(storage.content_hash(), original);
+        storage.set(1, &Tile { u: 18, ..tile(2) });
+        let framed = storage.content_hash();
+        assert_ne!(framed, original);
+        storage.set(1, &tile(2));
+        assert_eq!(storage.content_hash(), original);
+    }
+}
@@ -0,0 +1,484 @@
+// tModLoader 附属文件加载器
+// .twld 为 gzip 压缩的 TagCompound，保存模组方块、墙体、箱子物品与 NPC，合并到原版 World 中
+
+use std::collections::{BTreeSet, HashMap};
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::colors::Rgb;
+use crate::compression::gunzip;
+use crate::nbt::Tag;
+use crate::world_handle::WorldHandle;
+use crate::world_loader::{Chest, ChestItem, World, WorldLoadError, NPC};
+
+// 原版方块、墙体数量，之后的 ID 属于模组
+pub const VANILLA_TILE_COUNT: i32 = 753;
+pub const VANILLA_WALL_COUNT: i32 = 367;
+
+// 模组物品与 NPC 没有固定 ID，从该值起按出现顺序分配
+pub const MOD_ITEM_ID_BASE: i32 = 1_000_000;
+pub const MOD_NPC_ID_BASE: i32 = 1_000_000;
+
+const VANILLA_MOD_NAME: &str = "Terraria";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModEntry {
+    pub mod_name: String,
+    pub name: String,
+}
+
+impl ModEntry {
+    /// "ModName:TileName" 形式的标签
+    pub fn label(&self) -> String {
+        format!("{}:{}", self.mod_name, self.name)
+    }
+
+    /// 由标签哈希得到的稳定颜色，亮度保持在中等范围以便辨认
+    pub fn color(&self) -> Rgb {
+        // FNV-1a
+        let mut hash: u32 = 0x811C_9DC5;
+        for byte in self.label().bytes() {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+        Rgb::new(
+            64 + (hash & 0x7F) as u8 + (hash >> 7 & 0x3F) as u8,
+            64 + (hash >> 13 & 0x7F) as u8 + (hash >> 20 & 0x3F) as u8,
+            64 + (hash >> 26 & 0x3F) as u8 * 2,
+        )
+    }
+}
+
+// 合并到 World 的模组信息，键为方块、墙体、物品或 NPC 的 ID
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModData {
+    pub mods: Vec<String>,
+    pub tiles: HashMap<i32, ModEntry>,
+    pub walls: HashMap<i32, ModEntry>,
+    pub items: HashMap<i32, ModEntry>,
+    pub npcs: HashMap<i32, ModEntry>,
+}
+
+impl ModData {
+    pub fn is_empty(&self) -> bool {
+        self.mods.is_empty()
+    }
+
+    pub fn tile(&self, tile_id: i32) -> Option<&ModEntry> {
+        if tile_id < VANILLA_TILE_COUNT {
+            return None;
+        }
+        self.tiles.get(&tile_id)
+    }
+
+    /// 模组方块的颜色，类型表中缺失的 ID 按 "Unknown:<ID>" 计算
+    pub fn tile_color(&self, tile_id: i32) -> Option<Rgb> {
+        if tile_id < VANILLA_TILE_COUNT {
+            return None;
+        }
+        Some(match self.tiles.get(&tile_id) {
+            Some(entry) => entry.color(),
+            None => Self::unknown(tile_id).color(),
+        })
+    }
+
+    /// 模组方块的 "ModName:TileName" 标签
+    pub fn tile_label(&self, tile_id: i32) -> Option<String> {
+        if tile_id < VANILLA_TILE_COUNT {
+            return None;
+        }
+        Some(match self.tiles.get(&tile_id) {
+            Some(entry) => entry.label(),
+            None => Self::unknown(tile_id).label(),
+        })
+    }
+
+    fn unknown(id: i32) -> ModEntry {
+        ModEntry {
+            mod_name: "Unknown".to_string(),
+            name: id.to_string(),
+        }
+    }
+
+    pub fn wall(&self, wall_id: i32) -> Option<&ModEntry> {
+        if wall_id < VANILLA_WALL_COUNT {
+            return None;
+        }
+        self.walls.get(&wall_id)
+    }
+
+    pub fn item(&self, item_id: i32) -> Option<&ModEntry> {
+        self.items.get(&item_id)
+    }
+
+    fn item_id(&mut self, entry: ModEntry) -> i32 {
+        if let Some((&id, _)) = self.items.iter().find(|(_, existing)| **existing == entry) {
+            return id;
+        }
+        let id = MOD_ITEM_ID_BASE + self.items.len() as i32;
+        self.items.insert(id, entry);
+        id
+    }
+}
+
+#[wasm_bindgen]
+pub struct TwldLoader {}
+
+impl Default for TwldLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl TwldLoader {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 将 .twld 中的模组数据合并到已加载的世界，需在 Searcher::from_handle 之前调用
+    #[wasm_bindgen]
+    pub fn apply_to_handle(&self, handle: &mut WorldHandle, data: &[u8]) -> Result<(), JsValue> {
+        handle
+            .world_mut()
+            .and_then(|world| self.apply(world, data))
+            .map_err(|e| JsValue::from_str(&format!("Failed to load mod data: {}", e)))
+    }
+
+    /// 合并到 JS 侧的世界对象，返回新的世界对象
+    #[wasm_bindgen]
+    pub fn apply_to_world_js(&self, world_js: JsValue, data: &[u8]) -> Result<JsValue, JsValue> {
+        let mut world: World = serde_wasm_bindgen::from_value(world_js)?;
+        self.apply(&mut world, data)
+            .map_err(|e| JsValue::from_str(&format!("Failed to load mod data: {}", e)))?;
+        Ok(serde_wasm_bindgen::to_value(&world)?)
+    }
+}
+
+impl TwldLoader {
+    pub fn apply(&self, world: &mut World, data: &[u8]) -> Result<(), String> {
+        if data.is_empty() {
+            return Err(WorldLoadError::InvalidData {
+                message: "Mod data file is empty".to_string(),
+            }.into());
+        }
+
+        let root = Tag::parse(&gunzip(data)?)?;
+        let mut mods = BTreeSet::new();
+
+        if let Some(tiles) = root.get("tiles") {
+            let entries = Self::read_entries(tiles, &mut mods);
+            Self::apply_blocks(world, tiles, false)?;
+            world.mods.tiles.extend(entries);
+        }
+        if let Some(walls) = root.get("walls") {
+            let entries = Self::read_entries(walls, &mut mods);
+            Self::apply_blocks(world, walls, true)?;
+            world.mods.walls.extend(entries);
+        }
+        if let Some(chests) = root.get("chests") {
+            Self::apply_chests(world, chests, &mut mods);
+        }
+        if let Some(npcs) = root.get("npcs") {
+            Self::apply_npcs(world, npcs, &mut mods);
+        }
+
+        // 存档中记录的已启用模组
+        for name in root.get("usedMods").map(Tag::as_list).unwrap_or_default() {
+            if let Some(name) = name.as_str() {
+                mods.insert(name.to_string());
+            }
+        }
+        mods.remove(VANILLA_MOD_NAME);
+        for name in mods {
+            if !world.mods.mods.contains(&name) {
+                world.mods.mods.push(name);
+            }
+        }
+
+        Ok(())
+    }
+
+    // 方块或墙体的类型表：{ value, mod, name }，value 为保存时的类型 ID
+    fn read_entries(section: &Tag, mods: &mut BTreeSet<String>) -> HashMap<i32, ModEntry> {
+        let mut entries = HashMap::new();
+        for entry in section.get("entries").map(Tag::as_list).unwrap_or_default() {
+            let (Some(value), Some(mod_name), Some(name)) =
+                (entry.get_i64("value"), entry.get_str("mod"), entry.get_str("name"))
+            else {
+                continue;
+            };
+            mods.insert(mod_name.to_string());
+            entries.insert(
+                value as u16 as i32,
+                ModEntry {
+                    mod_name: mod_name.to_string(),
+                    name: name.to_string(),
+                },
+            );
+        }
+        entries
+    }
+
+    // 方块数据按列（x 外层、y 内层）存储：跳过的原版方块数（字节，255 表示继续累加）后跟 u16 类型
+    fn apply_blocks(
+        world: &mut World,
+        section: &Tag,
+        walls: bool,
+    ) -> Result<(), String> {
+        let Some(data) = section.get("data").and_then(Tag::as_bytes) else {
+            return Ok(());
+        };
+
+        let width = world.width.max(0) as usize;
+        let height = world.height.max(0) as usize;
+        let total = width * height;
+        let mut pos = 0;
+        let mut index = 0usize;
+        while pos < data.len() {
+            loop {
+                let skip = data[pos];
+                pos += 1;
+                index += skip as usize;
+                if skip != 255 {
+                    break;
+                }
+                if pos >= data.len() {
+                    return Ok(());
+                }
+            }
+
+            let Some(bytes) = data.get(pos..pos + 2) else {
+                return Err(WorldLoadError::CorruptedData {
+                    position: pos,
+                    message: "Unexpected end of mod tile data".to_string(),
+                }.into());
+            };
+            pos += 2;
+            let value = u16::from_le_bytes([bytes[0], bytes[1]]) as i32;
+            if index >= total {
+                return Err(WorldLoadError::CorruptedData {
+                    position: pos,
+                    message: format!("Mod tile index {} is outside the world", index),
+                }.into());
+            }
+
+            // 未在类型表中的值保留原样，显示为 "Unknown:<ID>"
+            let (x, y) = (index / height, index % height);
+            let idx = y * width + x;
+            if let Some(mut tile) = world.tiles.get(idx) {
+                if walls {
+                    tile.wall_id = value;
+                } else {
+                    tile.tile_id = value;
+                    tile.is_active = true;
+                }
+                world.tiles.set(idx, &tile);
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    // 模组箱子物品：{ x, y, items: [{ slot, mod, name | id, stack, prefix }] }
+    fn apply_chests(world: &mut World, chests: &Tag, mods: &mut BTreeSet<String>) {
+        for chest_tag in chests.as_list() {
+            let (Some(x), Some(y)) = (chest_tag.get_i64("x"), chest_tag.get_i64("y")) else {
+                continue;
+            };
+            let (x, y) = (x as i32, y as i32);
+
+            let mut items = Vec::new();
+            for item in chest_tag.get("items").map(Tag::as_list).unwrap_or_default() {
+                let mod_name = item.get_str("mod").unwrap_or(VANILLA_MOD_NAME);
+                let id = if mod_name == VANILLA_MOD_NAME {
+                    match item.get_i64("id") {
+                        Some(id) => id as i32,
+                        None => continue,
+                    }
+                } else {
+                    let Some(name) = item.get_str("name") else {
+                        continue;
+                    };
+                    mods.insert(mod_name.to_string());
+                    world.mods.item_id(ModEntry {
+                        mod_name: mod_name.to_string(),
+                        name: name.to_string(),
+                    })
+                };
+                items.push(ChestItem {
+                    id,
+                    stack: item.get_i64("stack").unwrap_or(1) as i32,
+                    prefix: item.get_i64("prefix").unwrap_or(0) as i32,
+                });
+            }
+
+            match world.chests.iter_mut().find(|chest| chest.x == x && chest.y == y) {
+                Some(chest) => chest.items.extend(items),
+                None => world.chests.push(Chest {
+                    x,
+                    y,
+                    name: chest_tag.get_str("name").unwrap_or_default().to_string(),
+                    items,
+                }),
+            }
+        }
+    }
+
+    // 模组 NPC：{ mod, name, x, y, homeless, homeTileX, homeTileY }
+    fn apply_npcs(world: &mut World, npcs: &Tag, mods: &mut BTreeSet<String>) {
+        for npc_tag in npcs.as_list() {
+            let (Some(mod_name), Some(name)) = (npc_tag.get_str("mod"), npc_tag.get_str("name")) else {
+                continue;
+            };
+            mods.insert(mod_name.to_string());
+
+            let entry = ModEntry {
+                mod_name: mod_name.to_string(),
+                name: name.to_string(),
+            };
+            let id = MOD_NPC_ID_BASE + world.mods.npcs.len() as i32;
+            let position = |key: &str| match npc_tag.get(key) {
+                Some(Tag::Float(value)) => *value,
+                Some(Tag::Double(value)) => *value as f32,
+                Some(tag) => tag.as_i64().unwrap_or(0) as f32,
+                None => 0.0,
+            };
+
+            world.npcs.push(NPC {
+                id,
+                name: entry.label(),
+                sprite_id: -1,
+                position_x: position("x"),
+                position_y: position("y"),
+                home_x: npc_tag.get_i64("homeTileX").unwrap_or(0) as i32,
+                home_y: npc_tag.get_i64("homeTileY").unwrap_or(0) as i32,
+                direction: 1,
+                is_homeless: npc_tag.get_i64("homeless").unwrap_or(1) != 0,
+            });
+            world.mods.npcs.insert(id, entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{chest, gzip, tag_data, tile, world};
+
+    fn compound(entries: Vec<(&str, Tag)>) -> Tag {
+        Tag::Compound(entries.into_iter().map(|(name, tag)| (name.to_string(), tag)).collect())
+    }
+
+    fn string(text: &str) -> Tag {
+        Tag::String(text.to_string())
+    }
+
+    fn entry(value: i16, mod_name: &str, name: &str) -> Tag {
+        compound(vec![("value", Tag::Short(value)), ("mod", string(mod_name)), ("name", string(name))])
+    }
+
+    fn mod_item(mod_name: &str, name: &str, stack: i32) -> Tag {
+        compound(vec![("mod", string(mod_name)), ("name", string(name)), ("stack", Tag::Int(stack))])
+    }
+
+    fn apply(world: &mut World, root: &Tag) -> Result<(), String> {
+        TwldLoader::new().apply(world, &gzip(&tag_data(root)))
+    }
+
+    // 3×2 的世界，方块均为泥土
+    fn test_world() -> World {
+        world(3, 2, &vec![tile(0); 6])
+    }
+
+    #[test]
+    fn merges_mod_tiles_by_column() {
+        let mut world = test_world();
+        // 按列计数：索引 0 为 (0, 0)，跳过 2 个后索引 3 为 (1, 1)
+        let data = vec![0, 0x20, 0x03, 2, 0x21, 0x03];
+        let root = compound(vec![
+            ("tiles", compound(vec![
+                ("entries", Tag::List(vec![entry(800, "Calamity", "AstralOre")])),
+                ("data", Tag::ByteArray(data)),
+            ])),
+            ("walls", compound(vec![
+                ("entries", Tag::List(vec![entry(400, "Thorium", "MarineWall")])),
+                ("data", Tag::ByteArray(vec![5, 0x90, 0x01])),
+            ])),
+        ]);
+        apply(&mut world, &root).unwrap();
+
+        assert_eq!(world.tiles.get(0).unwrap().tile_id, 800);
+        assert_eq!(world.tiles.get(4).unwrap().tile_id, 801);
+        assert_eq!(world.tiles.get(1).unwrap().tile_id, 0);
+        assert_eq!(world.tiles.get(5).unwrap().wall_id, 400);
+        assert_eq!(world.mods.tile_label(800).as_deref(), Some("Calamity:AstralOre"));
+        assert_eq!(world.mods.tile_label(801).as_deref(), Some("Unknown:801"));
+        assert_eq!(world.mods.wall(400).map(ModEntry::label).as_deref(), Some("Thorium:MarineWall"));
+        assert_eq!(world.mods.mods, ["Calamity", "Thorium"]);
+    }
+
+    #[test]
+    fn rejects_mod_tiles_outside_the_world() {
+        let mut world = test_world();
+        let root = compound(vec![("tiles", compound(vec![("data", Tag::ByteArray(vec![6, 0x20, 0x03]))]))]);
+        assert!(apply(&mut world, &root).unwrap_err().contains("Mod tile index 6 is outside the world"));
+
+        let root = compound(vec![("tiles", compound(vec![("data", Tag::ByteArray(vec![0, 0x20]))]))]);
+        assert!(apply(&mut world, &root).unwrap_err().contains("Unexpected end of mod tile data"));
+    }
+
+    #[test]
+    fn merges_chest_items_and_npcs() {
+        let mut world = test_world();
+        world.chests.push(chest(1, 0, "Loot", &[(29, 1)]));
+        let root = compound(vec![
+            ("chests", Tag::List(vec![
+                compound(vec![
+                    ("x", Tag::Int(1)),
+                    ("y", Tag::Int(0)),
+                    ("items", Tag::List(vec![
+                        mod_item("Calamity", "AuricBar", 5),
+                        compound(vec![("id", Tag::Int(74)), ("stack", Tag::Int(2))]),
+                    ])),
+                ]),
+                compound(vec![
+                    ("x", Tag::Int(2)),
+                    ("y", Tag::Int(1)),
+                    ("name", string("Modded")),
+                    ("items", Tag::List(vec![mod_item("Calamity", "AuricBar", 3)])),
+                ]),
+            ])),
+            ("npcs", Tag::List(vec![compound(vec![
+                ("mod", string("Fargo")),
+                ("name", string("Mutant")),
+                ("x", Tag::Float(32.0)),
+                ("y", Tag::Double(16.0)),
+                ("homeless", Tag::Byte(0)),
+                ("homeTileX", Tag::Int(2)),
+                ("homeTileY", Tag::Int(1)),
+            ])])),
+            ("usedMods", Tag::List(vec![string("Terraria"), string("Calamity"), string("Boss")])),
+        ]);
+        apply(&mut world, &root).unwrap();
+
+        let items: Vec<_> = world.chests[0].items.iter().map(|item| (item.id, item.stack)).collect();
+        assert_eq!(items, [(29, 1), (MOD_ITEM_ID_BASE, 5), (74, 2)]);
+        assert_eq!(world.chests[1].name, "Modded");
+        assert_eq!(world.chests[1].items[0].id, MOD_ITEM_ID_BASE);
+        assert_eq!(world.mods.item(MOD_ITEM_ID_BASE).map(ModEntry::label).as_deref(), Some("Calamity:AuricBar"));
+
+        let npc = &world.npcs[0];
+        assert_eq!((npc.id, npc.name.as_str()), (MOD_NPC_ID_BASE, "Fargo:Mutant"));
+        assert_eq!((npc.position_x, npc.position_y, npc.home_x, npc.home_y, npc.is_homeless), (32.0, 16.0, 2, 1, false));
+        assert_eq!(world.mods.mods, ["Boss", "Calamity", "Fargo"]);
+    }
+
+    #[test]
+    fn rejects_empty_or_uncompressed_data() {
+        let mut world = test_world();
+        assert!(TwldLoader::new().apply(&mut world, &[]).unwrap_err().contains("Mod data file is empty"));
+        let plain = tag_data(&compound(vec![]));
+        assert!(TwldLoader::new().apply(&mut world, &plain).unwrap_err().contains("gzip"));
+    }
+}
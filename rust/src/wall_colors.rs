@@ -0,0 +1,238 @@
+// 墙体颜色定义
+// 从 settings.js 的 Walls 列表迁移（由 scripts/extract_wall_colors.cjs 自动生成）
+
+use crate::colors::Rgb;
+
+pub struct WallColors;
+
+impl WallColors {
+    pub fn get_color(wall_id: i32) -> Option<Rgb> {
+        match wall_id {
+            1 => Some(Rgb::new(52, 52, 52)),
+            2 => Some(Rgb::new(88, 61, 46)),
+            3 => Some(Rgb::new(61, 58, 78)),
+            4 => Some(Rgb::new(73, 51, 36)),
+            5 => Some(Rgb::new(59, 59, 59)),
+            6 => Some(Rgb::new(91, 30, 30)),
+            7 => Some(Rgb::new(39, 45, 57)),
+            8 => Some(Rgb::new(37, 49, 31)),
+            9 => Some(Rgb::new(59, 37, 52)),
+            10 => Some(Rgb::new(74, 62, 12)),
+            11 => Some(Rgb::new(46, 56, 59)),
+            12 => Some(Rgb::new(75, 32, 11)),
+            13 => Some(Rgb::new(67, 37, 37)),
+            14 => Some(Rgb::new(15, 15, 15)),
+            15 => Some(Rgb::new(52, 43, 45)),
+            16 => Some(Rgb::new(88, 61, 46)),
+            17 => Some(Rgb::new(39, 45, 57)),
+            18 => Some(Rgb::new(37, 49, 31)),
+            19 => Some(Rgb::new(59, 37, 52)),
+            20 => Some(Rgb::new(15, 15, 15)),
+            21 => Some(Rgb::new(141, 178, 254)),
+            22 => Some(Rgb::new(113, 99, 99)),
+            23 => Some(Rgb::new(38, 38, 43)),
+            24 => Some(Rgb::new(53, 39, 41)),
+            25 => Some(Rgb::new(11, 35, 62)),
+            26 => Some(Rgb::new(21, 63, 70)),
+            27 => Some(Rgb::new(62, 51, 44)),
+            28 => Some(Rgb::new(81, 84, 101)),
+            29 => Some(Rgb::new(88, 23, 23)),
+            30 => Some(Rgb::new(28, 88, 23)),
+            31 => Some(Rgb::new(78, 87, 99)),
+            32 => Some(Rgb::new(86, 17, 40)),
+            33 => Some(Rgb::new(49, 47, 83)),
+            34 => Some(Rgb::new(69, 67, 41)),
+            35 => Some(Rgb::new(51, 51, 70)),
+            36 => Some(Rgb::new(87, 59, 55)),
+            37 => Some(Rgb::new(69, 67, 41)),
+            38 => Some(Rgb::new(49, 57, 49)),
+            39 => Some(Rgb::new(78, 79, 73)),
+            40 => Some(Rgb::new(85, 102, 103)),
+            41 => Some(Rgb::new(52, 50, 62)),
+            42 => Some(Rgb::new(71, 42, 44)),
+            43 => Some(Rgb::new(73, 66, 50)),
+            44 => Some(Rgb::new(52, 52, 52)),
+            45 => Some(Rgb::new(60, 59, 51)),
+            46 => Some(Rgb::new(48, 57, 47)),
+            47 => Some(Rgb::new(71, 77, 85)),
+            48 => Some(Rgb::new(64, 29, 75)),
+            49 => Some(Rgb::new(75, 56, 29)),
+            50 => Some(Rgb::new(29, 48, 75)),
+            51 => Some(Rgb::new(29, 75, 49)),
+            52 => Some(Rgb::new(75, 29, 38)),
+            53 => Some(Rgb::new(29, 71, 75)),
+            54 => Some(Rgb::new(40, 56, 50)),
+            55 => Some(Rgb::new(49, 48, 36)),
+            56 => Some(Rgb::new(43, 33, 32)),
+            57 => Some(Rgb::new(31, 40, 49)),
+            58 => Some(Rgb::new(48, 35, 52)),
+            59 => Some(Rgb::new(68, 47, 36)),
+            60 => Some(Rgb::new(1, 52, 20)),
+            61 => Some(Rgb::new(55, 39, 26)),
+            62 => Some(Rgb::new(39, 33, 26)),
+            63 => Some(Rgb::new(30, 80, 48)),
+            64 => Some(Rgb::new(53, 80, 30)),
+            65 => Some(Rgb::new(34, 90, 54)),
+            66 => Some(Rgb::new(30, 80, 48)),
+            67 => Some(Rgb::new(53, 80, 30)),
+            68 => Some(Rgb::new(30, 80, 48)),
+            69 => Some(Rgb::new(43, 42, 68)),
+            70 => Some(Rgb::new(30, 70, 80)),
+            71 => Some(Rgb::new(78, 105, 135)),
+            72 => Some(Rgb::new(52, 84, 12)),
+            73 => Some(Rgb::new(190, 204, 223)),
+            74 => Some(Rgb::new(64, 62, 80)),
+            75 => Some(Rgb::new(65, 65, 35)),
+            76 => Some(Rgb::new(20, 46, 104)),
+            77 => Some(Rgb::new(61, 13, 16)),
+            78 => Some(Rgb::new(63, 39, 26)),
+            79 => Some(Rgb::new(51, 47, 96)),
+            80 => Some(Rgb::new(64, 62, 80)),
+            81 => Some(Rgb::new(101, 51, 51)),
+            82 => Some(Rgb::new(77, 64, 34)),
+            83 => Some(Rgb::new(62, 38, 41)),
+            84 => Some(Rgb::new(48, 78, 93)),
+            85 => Some(Rgb::new(54, 63, 69)),
+            86 => Some(Rgb::new(138, 73, 38)),
+            87 => Some(Rgb::new(50, 15, 8)),
+            88 => Some(Rgb::new(203, 143, 253)),
+            89 => Some(Rgb::new(253, 207, 143)),
+            90 => Some(Rgb::new(166, 193, 230)),
+            91 => Some(Rgb::new(143, 253, 191)),
+            92 => Some(Rgb::new(253, 143, 163)),
+            93 => Some(Rgb::new(253, 143, 253)),
+            94 => Some(Rgb::new(42, 58, 66)),
+            95 => Some(Rgb::new(50, 50, 78)),
+            96 => Some(Rgb::new(69, 50, 78)),
+            97 => Some(Rgb::new(78, 50, 78)),
+            98 => Some(Rgb::new(41, 63, 45)),
+            99 => Some(Rgb::new(50, 78, 69)),
+            100 => Some(Rgb::new(42, 58, 66)),
+            101 => Some(Rgb::new(50, 50, 78)),
+            102 => Some(Rgb::new(69, 50, 78)),
+            103 => Some(Rgb::new(78, 50, 78)),
+            104 => Some(Rgb::new(41, 63, 45)),
+            105 => Some(Rgb::new(50, 78, 69)),
+            106 => Some(Rgb::new(85, 64, 43)),
+            107 => Some(Rgb::new(64, 64, 64)),
+            108 => Some(Rgb::new(138, 73, 38)),
+            109 => Some(Rgb::new(94, 25, 17)),
+            110 => Some(Rgb::new(125, 36, 122)),
+            111 => Some(Rgb::new(51, 35, 27)),
+            112 => Some(Rgb::new(50, 15, 8)),
+            113 => Some(Rgb::new(135, 58, 0)),
+            114 => Some(Rgb::new(65, 52, 15)),
+            115 => Some(Rgb::new(39, 42, 51)),
+            116 => Some(Rgb::new(51, 42, 34)),
+            117 => Some(Rgb::new(82, 70, 50)),
+            118 => Some(Rgb::new(68, 65, 65)),
+            119 => Some(Rgb::new(53, 48, 40)),
+            120 => Some(Rgb::new(102, 90, 105)),
+            121 => Some(Rgb::new(181, 155, 132)),
+            122 => Some(Rgb::new(117, 123, 153)),
+            123 => Some(Rgb::new(133, 118, 104)),
+            124 => Some(Rgb::new(26, 50, 51)),
+            125 => Some(Rgb::new(105, 111, 68)),
+            126 => Some(Rgb::new(124, 104, 136)),
+            127 => Some(Rgb::new(116, 141, 214)),
+            128 => Some(Rgb::new(109, 90, 121)),
+            129 => Some(Rgb::new(95, 53, 160)),
+            130 => Some(Rgb::new(128, 95, 137)),
+            131 => Some(Rgb::new(129, 118, 169)),
+            132 => Some(Rgb::new(124, 106, 125)),
+            133 => Some(Rgb::new(100, 113, 179)),
+            134 => Some(Rgb::new(143, 75, 25)),
+            135 => Some(Rgb::new(119, 138, 131)),
+            136 => Some(Rgb::new(75, 105, 140)),
+            137 => Some(Rgb::new(108, 101, 88)),
+            138 => Some(Rgb::new(129, 102, 142)),
+            139 => Some(Rgb::new(140, 78, 101)),
+            140 => Some(Rgb::new(175, 138, 138)),
+            141 => Some(Rgb::new(110, 103, 104)),
+            142 => Some(Rgb::new(165, 145, 135)),
+            143 => Some(Rgb::new(158, 141, 123)),
+            144 => Some(Rgb::new(103, 81, 73)),
+            145 => Some(Rgb::new(148, 121, 149)),
+            146 => Some(Rgb::new(120, 62, 54)),
+            147 => Some(Rgb::new(88, 67, 89)),
+            148 => Some(Rgb::new(201, 172, 154)),
+            149 => Some(Rgb::new(96, 71, 82)),
+            150 => Some(Rgb::new(135, 118, 128)),
+            151 => Some(Rgb::new(113, 76, 61)),
+            152 => Some(Rgb::new(115, 110, 54)),
+            153 => Some(Rgb::new(159, 59, 33)),
+            154 => Some(Rgb::new(109, 34, 159)),
+            155 => Some(Rgb::new(138, 142, 160)),
+            156 => Some(Rgb::new(47, 158, 36)),
+            157 => Some(Rgb::new(122, 64, 38)),
+            158 => Some(Rgb::new(85, 40, 122)),
+            159 => Some(Rgb::new(68, 69, 92)),
+            160 => Some(Rgb::new(38, 122, 50)),
+            161 => Some(Rgb::new(122, 40, 60)),
+            162 => Some(Rgb::new(38, 40, 122)),
+            163 => Some(Rgb::new(122, 116, 38)),
+            164 => Some(Rgb::new(159, 34, 49)),
+            165 => Some(Rgb::new(33, 66, 159)),
+            166 => Some(Rgb::new(157, 138, 33)),
+            167 => Some(Rgb::new(100, 78, 86)),
+            168 => Some(Rgb::new(88, 122, 123)),
+            169 => Some(Rgb::new(87, 124, 125)),
+            170 => Some(Rgb::new(108, 74, 68)),
+            171 => Some(Rgb::new(100, 63, 66)),
+            172 => Some(Rgb::new(163, 96, 0)),
+            173 => Some(Rgb::new(94, 163, 46)),
+            174 => Some(Rgb::new(117, 32, 59)),
+            175 => Some(Rgb::new(20, 11, 203)),
+            176 => Some(Rgb::new(74, 69, 88)),
+            177 => Some(Rgb::new(60, 30, 30)),
+            178 => Some(Rgb::new(111, 117, 135)),
+            179 => Some(Rgb::new(111, 117, 135)),
+            180 => Some(Rgb::new(25, 23, 54)),
+            181 => Some(Rgb::new(25, 23, 54)),
+            182 => Some(Rgb::new(74, 71, 129)),
+            183 => Some(Rgb::new(111, 117, 135)),
+            184 => Some(Rgb::new(25, 23, 54)),
+            185 => Some(Rgb::new(52, 52, 52)),
+            186 => Some(Rgb::new(38, 9, 66)),
+            187 => Some(Rgb::new(149, 80, 51)),
+            188 => Some(Rgb::new(82, 63, 80)),
+            189 => Some(Rgb::new(65, 61, 77)),
+            190 => Some(Rgb::new(64, 65, 92)),
+            191 => Some(Rgb::new(76, 53, 84)),
+            192 => Some(Rgb::new(144, 67, 52)),
+            193 => Some(Rgb::new(149, 48, 48)),
+            194 => Some(Rgb::new(111, 32, 36)),
+            195 => Some(Rgb::new(147, 48, 55)),
+            196 => Some(Rgb::new(97, 67, 51)),
+            197 => Some(Rgb::new(112, 80, 62)),
+            198 => Some(Rgb::new(88, 61, 46)),
+            199 => Some(Rgb::new(127, 94, 76)),
+            200 => Some(Rgb::new(143, 50, 123)),
+            201 => Some(Rgb::new(136, 120, 131)),
+            202 => Some(Rgb::new(219, 92, 143)),
+            203 => Some(Rgb::new(113, 64, 150)),
+            204 => Some(Rgb::new(74, 67, 60)),
+            205 => Some(Rgb::new(60, 78, 59)),
+            206 => Some(Rgb::new(0, 54, 21)),
+            207 => Some(Rgb::new(74, 97, 72)),
+            208 => Some(Rgb::new(40, 37, 35)),
+            209 => Some(Rgb::new(77, 63, 66)),
+            210 => Some(Rgb::new(111, 6, 6)),
+            211 => Some(Rgb::new(88, 67, 59)),
+            212 => Some(Rgb::new(88, 87, 80)),
+            213 => Some(Rgb::new(71, 71, 67)),
+            214 => Some(Rgb::new(76, 52, 60)),
+            215 => Some(Rgb::new(89, 48, 59)),
+            216 => Some(Rgb::new(158, 100, 64)),
+            217 => Some(Rgb::new(62, 45, 75)),
+            218 => Some(Rgb::new(57, 14, 12)),
+            219 => Some(Rgb::new(96, 72, 133)),
+            220 => Some(Rgb::new(67, 55, 80)),
+            221 => Some(Rgb::new(64, 37, 29)),
+            222 => Some(Rgb::new(70, 51, 91)),
+            223 => Some(Rgb::new(51, 18, 4)),
+            224 => Some(Rgb::new(57, 55, 52)),
+            _ => None,
+        }
+    }
+}
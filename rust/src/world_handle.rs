@@ -0,0 +1,230 @@
+// 世界句柄
+// 解析后的世界保存在 wasm 内存中，JS 只持有不透明句柄并按需获取少量元数据
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::name_registry::{NameKind, NameRegistry};
+use crate::tile_storage::MemoryUsage;
+use crate::world_loader::World;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldMetadata {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub world_id: i32,
+    pub chest_count: usize,
+    pub npc_count: usize,
+    pub sign_count: usize,
+    pub tile_entity_count: usize,
+    pub warning_count: usize,
+    pub memory: MemoryUsage,
+}
+
+// 全局递增的世界版本号：每个句柄创建时与每次修改后取新值，重新加载同一世界也不会重复
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+#[wasm_bindgen]
+pub struct WorldHandle {
+    world: Rc<World>,
+    generation: u64,
+}
+
+#[wasm_bindgen]
+impl WorldHandle {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.world.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> i32 {
+        self.world.width
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> i32 {
+        self.world.height
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn world_id(&self) -> i32 {
+        self.world.world_id
+    }
+
+    #[wasm_bindgen]
+    pub fn metadata(&self) -> Result<JsValue, JsValue> {
+        let metadata = WorldMetadata {
+            name: self.world.name.clone(),
+            width: self.world.width,
+            height: self.world.height,
+            world_id: self.world.world_id,
+            chest_count: self.world.chests.len(),
+            npc_count: self.world.npcs.len(),
+            sign_count: self.world.signs.len(),
+            tile_entity_count: self.world.tile_entities.len(),
+            warning_count: self.world.warnings.len(),
+            memory: self.world.tiles.memory_usage(),
+        };
+        Ok(serde_wasm_bindgen::to_value(&metadata)?)
+    }
+
+    /// 方块存储占用的内存（字节）
+    #[wasm_bindgen]
+    pub fn memory_usage(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.tiles.memory_usage())?)
+    }
+
+    /// 获取单个方块（用于鼠标悬停信息），越界时返回 undefined
+    #[wasm_bindgen]
+    pub fn tile_at(&self, x: i32, y: i32) -> Result<JsValue, JsValue> {
+        if x < 0 || y < 0 || x >= self.world.width || y >= self.world.height {
+            return Ok(JsValue::UNDEFINED);
+        }
+        let idx = y as usize * self.world.width as usize + x as usize;
+        match self.world.tiles.get(idx) {
+            Some(tile) => Ok(serde_wasm_bindgen::to_value(&tile)?),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// 模组方块的 "ModName:TileName" 标签，原版方块或越界时返回 undefined
+    #[wasm_bindgen]
+    pub fn tile_label(&self, x: i32, y: i32) -> Option<String> {
+        if x < 0 || y < 0 || x >= self.world.width || y >= self.world.height {
+            return None;
+        }
+        let idx = y as usize * self.world.width as usize + x as usize;
+        if !self.world.tiles.is_active(idx) {
+            return None;
+        }
+        self.world.mods.tile_label(self.world.tiles.tile_id(idx))
+    }
+
+    /// 方块显示名称（模组方块为 "ModName:TileName"），空气或越界时返回 undefined
+    #[wasm_bindgen]
+    pub fn tile_name(&self, x: i32, y: i32) -> Option<String> {
+        self.tile_name_in(x, y, &NameRegistry::new())
+    }
+
+    /// 使用注册表当前语言的方块名称
+    #[wasm_bindgen]
+    pub fn tile_name_in(&self, x: i32, y: i32, names: &NameRegistry) -> Option<String> {
+        let idx = self.index(x, y)?;
+        if !self.world.tiles.is_active(idx) {
+            return None;
+        }
+        let tile_id = self.world.tiles.tile_id(idx);
+        self.world
+            .mods
+            .tile(tile_id)
+            .map(|entry| entry.label())
+            .or_else(|| names.display_name(NameKind::Tile, tile_id))
+    }
+
+    /// 墙体显示名称，没有墙或越界时返回 undefined
+    #[wasm_bindgen]
+    pub fn wall_name(&self, x: i32, y: i32) -> Option<String> {
+        self.wall_name_in(x, y, &NameRegistry::new())
+    }
+
+    /// 使用注册表当前语言的墙体名称
+    #[wasm_bindgen]
+    pub fn wall_name_in(&self, x: i32, y: i32, names: &NameRegistry) -> Option<String> {
+        let idx = self.index(x, y)?;
+        let wall_id = self.world.tiles.wall_id(idx);
+        if wall_id == 0 {
+            return None;
+        }
+        self.world
+            .mods
+            .wall(wall_id)
+            .map(|entry| entry.label())
+            .or_else(|| names.display_name(NameKind::Wall, wall_id))
+    }
+
+    /// 加载的模组数据（模组列表与模组方块、墙体、物品、NPC 的名称）
+    #[wasm_bindgen]
+    pub fn mods(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.mods)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn chests(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.chests)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn npcs(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.npcs)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn signs(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.signs)?)
+    }
+
+    #[wasm_bindgen]
+    pub fn tile_entities(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.tile_entities)?)
+    }
+
+    /// 加载警告（区段、偏移、原因、损坏区域）：恢复模式下的损坏区段与加载了备份文件
+    #[wasm_bindgen]
+    pub fn warnings(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.world.warnings)?)
+    }
+
+    /// 导出完整世界（兼容旧接口，大世界开销很大）
+    #[wasm_bindgen]
+    pub fn to_js(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&*self.world)?)
+    }
+}
+
+impl WorldHandle {
+    pub fn new(world: World) -> Self {
+        Self {
+            world: Rc::new(world),
+            generation: next_generation(),
+        }
+    }
+
+    /// 世界数据的版本号，渲染缓存以此判断是否过期
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    // 坐标在世界范围内时返回方块下标
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x >= self.world.width || y >= self.world.height {
+            return None;
+        }
+        Some(y as usize * self.world.width as usize + x as usize)
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    // 修改世界（合并模组数据等）。仍与搜索器共享时报错而不是复制一份，
+    // 否则搜索器会继续使用旧数据
+    pub fn world_mut(&mut self) -> Result<&mut World, String> {
+        let world = Rc::get_mut(&mut self.world).ok_or_else(|| {
+            "World is shared with a Searcher; modify it before creating searchers, or free them first".to_string()
+        })?;
+        self.generation = next_generation();
+        Ok(world)
+    }
+
+    // 与搜索器等共享同一份世界数据，不复制方块
+    pub fn shared(&self) -> Rc<World> {
+        Rc::clone(&self.world)
+    }
+}
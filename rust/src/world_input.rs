@@ -0,0 +1,188 @@
+// 世界文件输入层
+// 在解析前识别 gzip / zip 压缩包与 Steam 云存档的备份后缀，交给加载器的始终是原始 .wld 数据
+
+use crate::compression::{gunzip, is_gzip, is_zip, zip_entries, zip_extract};
+use crate::world_loader::WorldLoadError;
+
+// 按优先级排列：正式存档优先于备份，备份优先于游戏标记的损坏存档
+const WORLD_SUFFIXES: [&str; 3] = [".wld", ".wld.bak", ".wld.bad"];
+
+// 常被误传的其他 Terraria 文件
+const OTHER_FILES: [(&str, &str); 3] = [
+    (".twld", "a tModLoader mod data file (load the matching .wld first)"),
+    (".plr", "a player file"),
+    (".map", "a map file"),
+];
+
+pub struct WorldInput {
+    pub data: Vec<u8>,
+    pub file_name: String, // 实际解析的文件名（压缩包内为条目名）
+    pub backup: bool,      // 来自 .wld.bak / .wld.bad
+}
+
+/// 解开压缩层并检查文件名，返回可直接解析的世界数据
+pub fn unpack_world_data(file_name: &str, data: Vec<u8>) -> Result<WorldInput, String> {
+    if is_gzip(&data) {
+        let inner = strip_suffix_ignore_case(file_name, ".gz").unwrap_or(file_name);
+        let data = gunzip(&data)?;
+        // Discord 上常见 .zip.gz 之类的双重压缩，只再解一层
+        if is_zip(&data) {
+            return unpack_zip(&data);
+        }
+        return plain_world(inner, data);
+    }
+
+    if is_zip(&data) {
+        return unpack_zip(&data);
+    }
+
+    plain_world(file_name, data)
+}
+
+fn plain_world(file_name: &str, data: Vec<u8>) -> Result<WorldInput, String> {
+    let lower = file_name.to_lowercase();
+    if let Some((_, kind)) = OTHER_FILES.iter().find(|(suffix, _)| lower.ends_with(suffix)) {
+        return Err(WorldLoadError::InvalidFormat {
+            expected: "world file (.wld)".to_string(),
+            found: format!("{} is {}", file_name, kind),
+        }.into());
+    }
+
+    Ok(WorldInput {
+        backup: world_suffix_rank(&lower).is_some_and(|rank| rank > 0),
+        file_name: file_name.to_string(),
+        data,
+    })
+}
+
+fn unpack_zip(data: &[u8]) -> Result<WorldInput, String> {
+    let entries = zip_entries(data)?;
+
+    // 忽略 macOS 打包附带的资源分支
+    let candidates = entries
+        .iter()
+        .filter(|entry| !entry.name.starts_with("__MACOSX/") && !entry.name.ends_with('/'));
+    let best = candidates
+        .clone()
+        .filter_map(|entry| world_suffix_rank(&entry.name.to_lowercase()).map(|rank| (rank, entry)))
+        .min_by_key(|(rank, _)| *rank);
+
+    let Some((rank, entry)) = best else {
+        let names: Vec<&str> = candidates.map(|entry| entry.name.as_str()).collect();
+        let found = if names.is_empty() {
+            "an empty archive".to_string()
+        } else {
+            format!("only {}", names.join(", "))
+        };
+        return Err(WorldLoadError::InvalidFormat {
+            expected: "zip archive containing a world file (.wld, .wld.bak or .wld.bad)".to_string(),
+            found,
+        }.into());
+    };
+
+    Ok(WorldInput {
+        data: zip_extract(data, entry)?,
+        file_name: entry.name.clone(),
+        backup: rank > 0,
+    })
+}
+
+// 世界文件后缀的优先级，非世界文件返回 None
+fn world_suffix_rank(lower_name: &str) -> Option<usize> {
+    WORLD_SUFFIXES.iter().position(|suffix| lower_name.ends_with(suffix))
+}
+
+fn strip_suffix_ignore_case<'a>(name: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = name.len().checked_sub(suffix.len())?;
+    if name.is_char_boundary(split) && name[split..].eq_ignore_ascii_case(suffix) {
+        Some(&name[..split])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compression::{ZIP_CENTRAL_HEADER, ZIP_END_OF_DIRECTORY, ZIP_LOCAL_HEADER};
+    use crate::test_fixtures::gzip;
+
+    // 只存储不压缩的 zip，CRC 不校验所以写 0
+    fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut directory = Vec::new();
+        for (name, data) in files {
+            let offset = out.len() as u32;
+            let sizes = [0u32, data.len() as u32, data.len() as u32]; // CRC、压缩前后长度
+            out.extend(ZIP_LOCAL_HEADER.to_le_bytes());
+            out.extend([20, 0, 0, 0, 0, 0, 0, 0, 0, 0]); // 版本、标志、方法、时间、日期
+            sizes.iter().for_each(|size| out.extend(size.to_le_bytes()));
+            out.extend((name.len() as u16).to_le_bytes());
+            out.extend([0, 0]);
+            out.extend(name.as_bytes());
+            out.extend(*data);
+
+            directory.extend(ZIP_CENTRAL_HEADER.to_le_bytes());
+            directory.extend([20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            sizes.iter().for_each(|size| directory.extend(size.to_le_bytes()));
+            directory.extend((name.len() as u16).to_le_bytes());
+            directory.extend([0; 12]); // 扩展字段、注释、磁盘号、属性
+            directory.extend(offset.to_le_bytes());
+            directory.extend(name.as_bytes());
+        }
+        let directory_offset = out.len() as u32;
+        out.extend(&directory);
+        out.extend(ZIP_END_OF_DIRECTORY.to_le_bytes());
+        out.extend([0; 4]);
+        out.extend((files.len() as u16).to_le_bytes());
+        out.extend((files.len() as u16).to_le_bytes());
+        out.extend((directory.len() as u32).to_le_bytes());
+        out.extend(directory_offset.to_le_bytes());
+        out.extend([0, 0]);
+        out
+    }
+
+    fn error(file_name: &str, data: Vec<u8>) -> String {
+        unpack_world_data(file_name, data).err().unwrap()
+    }
+
+    #[test]
+    fn zip_without_a_world_lists_its_entries() {
+        let data = zip(&[("Player.plr", b"player"), ("readme.txt", b"hello")]);
+        let error = error("upload.zip", data);
+        assert!(error.contains("zip archive containing a world file"), "{}", error);
+        assert!(error.contains("only Player.plr, readme.txt"), "{}", error);
+    }
+
+    #[test]
+    fn zip_without_files_is_reported_as_empty() {
+        let data = zip(&[("__MACOSX/World.wld", b"fork"), ("Worlds/", b"")]);
+        assert!(error("upload.zip", data).contains("an empty archive"));
+        assert!(error("upload.zip", zip(&[])).contains("an empty archive"));
+    }
+
+    #[test]
+    fn zip_prefers_the_main_world_over_backups() {
+        let data = zip(&[
+            ("World.wld.bak", b"backup"),
+            ("__MACOSX/World.wld", b"fork"),
+            ("Worlds/World.WLD", b"world"),
+        ]);
+        let input = unpack_world_data("upload.zip", data).unwrap();
+        assert_eq!((input.file_name.as_str(), input.data.as_slice(), input.backup), ("Worlds/World.WLD", &b"world"[..], false));
+
+        let input = unpack_world_data("upload.zip", zip(&[("World.wld.bak", b"backup")])).unwrap();
+        assert!(input.backup);
+    }
+
+    #[test]
+    fn gzip_is_unwrapped_once() {
+        let input = unpack_world_data("World.wld.GZ", gzip(b"world")).unwrap();
+        assert_eq!((input.file_name.as_str(), input.data.as_slice()), ("World.wld", &b"world"[..]));
+
+        let input = unpack_world_data("upload.zip.gz", gzip(&zip(&[("World.wld", b"world")]))).unwrap();
+        assert_eq!(input.data, b"world");
+
+        assert!(error("World.twld.gz", gzip(b"mods")).contains("tModLoader"));
+    }
+}
@@ -4,6 +4,13 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::data_stream::DataStream;
+use crate::recovery::ParseWarning;
+use crate::sections;
+use crate::tile_storage::TileStorage;
+use crate::twld_loader::ModData;
+use crate::world_handle::WorldHandle;
+use crate::world_input::unpack_world_data;
+use crate::world_parser::WorldParser;
 
 // 错误类型
 #[derive(Debug)]
@@ -12,6 +19,7 @@ pub enum WorldLoadError {
     UnsupportedVersion { version: i32 },
     CorruptedData { position: usize, message: String },
     InvalidFormat { expected: String, found: String },
+    Cancelled,
 }
 
 impl std::fmt::Display for WorldLoadError {
@@ -29,6 +37,9 @@ impl std::fmt::Display for WorldLoadError {
             WorldLoadError::InvalidFormat { expected, found } => {
                 write!(f, "Invalid format: expected '{}', found '{}'", expected, found)
             }
+            WorldLoadError::Cancelled => {
+                write!(f, "Operation cancelled")
+            }
         }
     }
 }
@@ -62,7 +73,7 @@ pub struct Tile {
     pub actuator: bool,
     pub in_active: bool,
     pub wall_color: i32,
-    pub wall_u: i32,
+    pub wall_u: i32, // 墙体帧坐标只在解析时读取，TileStorage 不保存
     pub wall_v: i32,
     pub wall_full: bool,
     pub wall_half_brick: bool,
@@ -103,11 +114,17 @@ pub struct World {
     pub width: i32,
     pub height: i32,
     pub world_id: i32,
-    pub tiles: Vec<Tile>,
+    pub tiles: TileStorage,
     pub chests: Vec<Chest>,
     pub npcs: Vec<NPC>,
     pub signs: Vec<Sign>,
     pub tile_entities: Vec<TileEntity>,
+    // 加载警告：恢复模式下的损坏区段，以及加载了备份文件（.wld.bak / .wld.bad）
+    #[serde(default)]
+    pub warnings: Vec<ParseWarning>,
+    // tModLoader 附属文件中的模组信息
+    #[serde(default)]
+    pub mods: ModData,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -122,32 +139,210 @@ pub struct TileEntity {
     pub id: i32,
     pub position_x: i32,
     pub position_y: i32,
-    pub entity_type: i32,
-    // 其他字段待添加
+    pub entity_type: i32, // 0 训练假人 1 物品框 2 逻辑感应器 3 人体模型 4 武器架 5 帽架 6 餐盘 7 晶塔
+    // 展示的物品（人体模型与帽架含染料），其他类型为空
+    #[serde(default)]
+    pub items: Vec<ChestItem>,
+}
+
+// 世界文件摘要，只读取文件格式头与世界头。
+// 本格式的世界头以 gameMode 结尾、紧接方块数据，不含邪恶类型、特殊种子标记与游戏时长
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldSummary {
+    pub name: String,
+    pub seed: String,
+    pub version: i32,
+    pub world_id: i32,
+    pub width: i32,
+    pub height: i32,
+    pub game_mode: i32,
+    pub difficulty: String,
+}
+
+// 世界头中读取的字段
+#[derive(Debug, Clone)]
+pub(crate) struct WorldHeader {
+    pub name: String,
+    pub seed: String,
+    pub world_id: i32,
+    pub width: i32,
+    pub height: i32,
+    pub game_mode: i32,
 }
 
+// 摘要读取的最大前缀长度，世界头远小于该值
+const PEEK_PREFIX_LEN: usize = 64 * 1024;
+
 #[wasm_bindgen]
 pub struct WorldLoader {
-    // 可以在这里添加状态
+    recovery: bool,
+}
+
+impl Default for WorldLoader {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
 impl WorldLoader {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self {}
+        Self {
+            recovery: false,
+        }
+    }
+
+    /// 恢复模式：数据损坏时返回部分世界与 warnings，而不是报错。
+    /// WorldLoader 一次性解析，无法中途取消；需要取消时使用 WorldParser
+    #[wasm_bindgen]
+    pub fn set_recovery_mode(&mut self, enabled: bool) {
+        self.recovery = enabled;
     }
 
     #[wasm_bindgen]
     pub fn load_from_data(&self, data: Vec<u8>) -> Result<JsValue, JsValue> {
-        match self.parse_world(data) {
+        match self.load(data) {
+            Ok(world) => Ok(serde_wasm_bindgen::to_value(&world)?),
+            Err(e) => Err(Self::load_error(e)),
+        }
+    }
+
+    /// 只读取文件头与世界头，返回 WorldSummary（用于最近世界列表）
+    #[wasm_bindgen]
+    pub fn peek_header(&self, data: &[u8]) -> Result<JsValue, JsValue> {
+        match self.peek_summary(data) {
+            Ok(summary) => Ok(serde_wasm_bindgen::to_value(&summary)?),
+            Err(e) => Err(JsValue::from_str(&format!("Failed to read world header: {}", e))),
+        }
+    }
+
+    /// 解析世界并保留在 wasm 内存中，只返回句柄
+    #[wasm_bindgen]
+    pub fn load_handle(&self, data: Vec<u8>) -> Result<WorldHandle, JsValue> {
+        match self.load(data) {
+            Ok(world) => Ok(WorldHandle::new(world)),
+            Err(e) => Err(Self::load_error(e)),
+        }
+    }
+
+    /// 按文件名加载用户上传的文件：自动解开 gzip / zip，接受 .wld.bak 与 .wld.bad（加载备份时在 warnings 中提示）
+    #[wasm_bindgen]
+    pub fn load_from_file(&self, file_name: &str, data: Vec<u8>) -> Result<JsValue, JsValue> {
+        match self.load_file(file_name, data) {
             Ok(world) => Ok(serde_wasm_bindgen::to_value(&world)?),
-            Err(e) => Err(JsValue::from_str(&format!("Failed to load world: {}", e))),
+            Err(e) => Err(Self::load_error(e)),
+        }
+    }
+
+    /// load_from_file 的句柄版本
+    #[wasm_bindgen]
+    pub fn load_handle_from_file(&self, file_name: &str, data: Vec<u8>) -> Result<WorldHandle, JsValue> {
+        match self.load_file(file_name, data) {
+            Ok(world) => Ok(WorldHandle::new(world)),
+            Err(e) => Err(Self::load_error(e)),
         }
     }
 }
 
 impl WorldLoader {
+    // 按当前模式解析
+    fn load(&self, data: Vec<u8>) -> Result<World, String> {
+        if self.recovery {
+            self.parse_world_lossy(data)
+        } else {
+            self.parse_world(data)
+        }
+    }
+
+    fn load_file(&self, file_name: &str, data: Vec<u8>) -> Result<World, String> {
+        let input = unpack_world_data(file_name, data)?;
+        let mut world = self.load(input.data)?;
+        if input.backup {
+            world.warnings.insert(0, ParseWarning {
+                section: "file".to_string(),
+                offset: 0,
+                reason: format!("Loaded the backup file {}, which may be older than the world save", input.file_name),
+                areas: Vec::new(),
+            });
+        }
+        Ok(world)
+    }
+
+    pub(crate) fn load_error(message: String) -> JsValue {
+        JsValue::from_str(&format!("Failed to load world: {}", message))
+    }
+
+    pub fn peek_summary(&self, data: &[u8]) -> Result<WorldSummary, String> {
+        if data.is_empty() {
+            return Err(WorldLoadError::InvalidData {
+                message: "World file is empty".to_string(),
+            }.into());
+        }
+
+        // 只复制文件开头的一小段，不触碰方块数据
+        let prefix = &data[..data.len().min(PEEK_PREFIX_LEN)];
+        let mut stream = DataStream::new(prefix.to_vec());
+        let truncated = || -> String {
+            WorldLoadError::CorruptedData {
+                position: 0,
+                message: "File is too short to contain a world header".to_string(),
+            }.into()
+        };
+
+        let header_len = WorldParser::file_header_len(&stream).ok_or_else(truncated)?;
+        if stream.remaining() < header_len {
+            return Err(truncated());
+        }
+        let version = i32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+        let positions = self.read_file_format_header(&mut stream)?;
+
+        // 位置表给出世界头与方块区段的偏移，世界头必须在方块区段之前结束；
+        // 位置表缺项时世界头紧接文件格式头
+        let section = |index: usize| {
+            positions
+                .get(index)
+                .filter(|&&offset| offset > 0)
+                .map(|&offset| offset as usize)
+        };
+        let header_start = section(0).unwrap_or(stream.position());
+        if header_start >= prefix.len() {
+            return Err(truncated());
+        }
+        stream.seek(header_start);
+        let header_end = header_start + WorldParser::world_header_len(&stream).ok_or_else(truncated)?;
+        if header_end > prefix.len() {
+            return Err(truncated());
+        }
+        if let Some(tiles_start) = section(1).filter(|&tiles_start| header_end > tiles_start) {
+            return Err(WorldLoadError::CorruptedData {
+                position: header_start,
+                message: format!("World header runs past the tile section at offset {}", tiles_start),
+            }.into());
+        }
+        let header = self.read_header(&mut stream)?;
+
+        let summary = WorldSummary {
+            name: header.name,
+            seed: header.seed,
+            version,
+            world_id: header.world_id,
+            width: header.width,
+            height: header.height,
+            game_mode: header.game_mode,
+            difficulty: match header.game_mode {
+                0 => "Classic",
+                1 => "Expert",
+                2 => "Master",
+                3 => "Journey",
+                _ => "Unknown",
+            }
+            .to_string(),
+        };
+
+        Ok(summary)
+    }
+
     fn parse_world(&self, data: Vec<u8>) -> Result<World, String> {
         // 验证数据不为空
         if data.is_empty() {
@@ -157,30 +352,21 @@ impl WorldLoader {
         }
 
         let mut stream = DataStream::new(data);
-        let _initial_position = stream.position();
+        let version = stream.read_int32();
+        stream.seek(0);
 
         // 读取文件格式头
-        let _positions = self.read_file_format_header(&mut stream).map_err(|e| {
+        let positions = self.read_file_format_header(&mut stream).map_err(|e| {
             format!("Failed to read file format header: {}", e)
         })?;
 
         // 读取世界头
-        let (width, height, world_id, name) = self.read_header(&mut stream).map_err(|e| {
+        let WorldHeader { width, height, world_id, name, .. } = self.read_header(&mut stream).map_err(|e| {
             format!("Failed to read world header: {}", e)
         })?;
 
         // 验证世界尺寸
-        if width <= 0 || height <= 0 {
-            return Err(WorldLoadError::InvalidData {
-                message: format!("Invalid world dimensions: {} x {}", width, height),
-            }.into());
-        }
-
-        if width > 10000 || height > 10000 {
-            return Err(WorldLoadError::InvalidData {
-                message: format!("World dimensions too large: {} x {}", width, height),
-            }.into());
-        }
+        Self::validate_dimensions(width, height)?;
 
         // 验证数据长度是否足够
         let expected_tile_count = (width * height) as usize;
@@ -199,10 +385,10 @@ impl WorldLoader {
         }
 
         // 读取方块数据
-        let mut tiles = Vec::with_capacity(expected_tile_count);
+        let mut tiles = TileStorage::with_capacity(expected_tile_count);
         for i in 0..(width * height) {
             let tile_pos = stream.position();
-            tiles.push(self.read_tile(&mut stream));
+            tiles.push(&self.read_tile(&mut stream));
             
             // 定期检查是否有足够数据
             if i % 1000000 == 0 {
@@ -220,12 +406,6 @@ impl WorldLoader {
             }
         }
 
-        // 读取其他数据（简化版）
-        let chests: Vec<Chest> = Vec::new();
-        let npcs: Vec<NPC> = Vec::new();
-        let signs: Vec<Sign> = Vec::new();
-        let tile_entities: Vec<TileEntity> = Vec::new();
-
         // 验证读取的方块数量
         if tiles.len() != expected_tile_count {
             return Err(WorldLoadError::CorruptedData {
@@ -237,20 +417,51 @@ impl WorldLoader {
             }.into());
         }
 
-        Ok(World {
+        let mut world = World {
             name,
             width,
             height,
             world_id,
             tiles,
-            chests,
-            npcs,
-            signs,
-            tile_entities,
-        })
+            chests: Vec::new(),
+            npcs: Vec::new(),
+            signs: Vec::new(),
+            tile_entities: Vec::new(),
+            warnings: Vec::new(),
+            mods: ModData::default(),
+        };
+
+        // 按位置表读取箱子、告示牌、NPC 与物块实体
+        sections::read_sections(&mut stream, &positions, version, &mut world, Self::section_error)?;
+
+        Ok(world)
+    }
+
+    // 正常加载时区段损坏即整体报错
+    pub(crate) fn section_error(index: usize, offset: usize, reason: String) -> Result<(), String> {
+        Err(WorldLoadError::CorruptedData {
+            position: offset,
+            message: format!("Failed to read {} section: {}", Self::section_name(index), reason),
+        }.into())
     }
 
-    fn read_file_format_header(&self, stream: &mut DataStream) -> Result<Vec<i32>, String> {
+    pub(crate) fn validate_dimensions(width: i32, height: i32) -> Result<(), String> {
+        if width <= 0 || height <= 0 {
+            return Err(WorldLoadError::InvalidData {
+                message: format!("Invalid world dimensions: {} x {}", width, height),
+            }.into());
+        }
+
+        if width > 10000 || height > 10000 {
+            return Err(WorldLoadError::InvalidData {
+                message: format!("World dimensions too large: {} x {}", width, height),
+            }.into());
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn read_file_format_header(&self, stream: &mut DataStream) -> Result<Vec<i32>, String> {
         // 读取版本号
         let _version = stream.read_int32();
 
@@ -284,7 +495,7 @@ impl WorldLoader {
                 _b = stream.read_byte();
                 b2 = 1;
             } else {
-                b2 = b2 << 1;
+                b2 <<= 1;
             }
             // 不存储重要性值，只读取
         }
@@ -292,12 +503,12 @@ impl WorldLoader {
         Ok(positions)
     }
 
-    fn read_header(&self, stream: &mut DataStream) -> Result<(i32, i32, i32, String), String> {
-// name
+    pub(crate) fn read_header(&self, stream: &mut DataStream) -> Result<WorldHeader, String> {
+        // name
         let name = stream.read_string();
 
         // seed
-        let _seed = stream.read_string();
+        let seed = stream.read_string();
 
         // worldGeneratorVersion
         let _ver1 = stream.read_uint32();
@@ -325,12 +536,19 @@ impl WorldLoader {
         let width = stream.read_int32();
 
         // gameMode (version >= 209)
-        let _game_mode = stream.read_int32();
+        let game_mode = stream.read_int32();
 
-        Ok((width, height, world_id, name))
+        Ok(WorldHeader {
+            name,
+            seed,
+            world_id,
+            width,
+            height,
+            game_mode,
+        })
     }
 
-    fn read_tile(&self, stream: &mut DataStream) -> Tile {
+    pub(crate) fn read_tile(&self, stream: &mut DataStream) -> Tile {
         let is_active = stream.read_bool();
 
         if !is_active {
@@ -438,4 +656,81 @@ impl WorldLoader {
             wall_slope,
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{self, chest, npc, sign, tile, tile_entity};
+
+    fn sample() -> (World, Vec<Tile>) {
+        let tiles: Vec<Tile> = (0..40 * 30).map(|i| tile(i % 5)).collect();
+        let mut world = test_fixtures::world(40, 30, &tiles);
+        world.chests.push(chest(3, 4, "Loot", &[(29, 1), (75, 20)]));
+        world.signs.push(sign(10, 2, "Hello"));
+        world.npcs.push(npc(22, "Andrew", 20, 25));
+        world.tile_entities.push(tile_entity(1, 6, 7, &[(3031, 1)]));
+        world.tile_entities.push(tile_entity(3, 8, 7, &[(88, 1), (410, 1)]));
+        world.tile_entities.push(tile_entity(7, 30, 20, &[]));
+        (world, tiles)
+    }
+
+    #[test]
+    fn normal_and_recovery_loads_read_the_same_sections() {
+        let (world, tiles) = sample();
+        let file = test_fixtures::world_file(&world, &tiles);
+        let loader = WorldLoader::new();
+        let mut parser = WorldParser::with_data(file.bytes.clone(), false);
+        assert!(parser.step_internal(usize::MAX).unwrap());
+
+        let loaded = [
+            loader.parse_world(file.bytes.clone()).unwrap(),
+            parser.take_world().unwrap(),
+            loader.parse_world_lossy(file.bytes).unwrap(),
+        ];
+        for loaded in &loaded {
+            assert!(loaded.warnings.is_empty());
+            assert_eq!(loaded.chests.len(), 1);
+            assert_eq!((loaded.chests[0].x, loaded.chests[0].y), (3, 4));
+            assert_eq!(loaded.chests[0].items.len(), 2);
+            assert_eq!(loaded.chests[0].items[1].stack, 20);
+            assert_eq!(loaded.signs[0].text, "Hello");
+            assert_eq!(loaded.npcs[0].name, "Andrew");
+            assert_eq!((loaded.npcs[0].home_x, loaded.npcs[0].home_y), (20, 25));
+            let entities: Vec<(i32, i32, usize)> = loaded
+                .tile_entities
+                .iter()
+                .map(|entity| (entity.entity_type, entity.position_x, entity.items.len()))
+                .collect();
+            assert_eq!(entities, vec![(1, 6, 1), (3, 8, 2), (7, 30, 0)]);
+            assert_eq!(loaded.tile_entities[0].items[0].id, 3031);
+        }
+    }
+
+    #[test]
+    fn streamed_sections_wait_for_their_data() {
+        let (world, tiles) = sample();
+        let file = test_fixtures::world_file(&world, &tiles);
+        let chests_start = file.positions[2] as usize;
+        let mut parser = WorldParser::new();
+        parser.feed(&file.bytes[..chests_start + 3]);
+        assert!(!parser.step_internal(usize::MAX).unwrap());
+        parser.feed(&file.bytes[chests_start + 3..]);
+        assert!(parser.step_internal(usize::MAX).unwrap());
+        assert_eq!(parser.take_world().unwrap().chests.len(), 1);
+    }
+
+    #[test]
+    fn normal_load_rejects_a_damaged_section() {
+        let (world, tiles) = sample();
+        let mut file = test_fixtures::world_file(&world, &tiles);
+        // 箱子数量改为负数
+        let chests_start = file.positions[2] as usize;
+        file.bytes[chests_start..chests_start + 2].copy_from_slice(&(-1i16).to_le_bytes());
+
+        let error = WorldLoader::new().parse_world(file.bytes.clone()).unwrap_err();
+        assert!(error.contains("chests section"), "{}", error);
+        let recovered = WorldLoader::new().parse_world_lossy(file.bytes).unwrap();
+        assert!(recovered.chests.is_empty());
+        assert_eq!(recovered.signs.len(), 1);
+    }
+}
@@ -0,0 +1,453 @@
+// 流式世界解析器
+// 支持分块喂入数据、按预算分步解析，并通过 JS 回调报告进度，解析大世界时可以让出事件循环
+
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::cancellation::{cancelled_error, CancellationToken};
+use crate::data_stream::DataStream;
+use crate::recovery::TileRecovery;
+use crate::sections;
+use crate::tile_storage::TileStorage;
+use crate::twld_loader::ModData;
+use crate::world_handle::WorldHandle;
+use crate::world_loader::{WorldHeader, WorldLoadError, World, WorldLoader};
+
+// 世界头中除两个字符串外的固定长度：生成器版本、UUID、id、边界、宽高、游戏模式
+const WORLD_HEADER_FIXED_LEN: usize = 8 + 16 + 4 + 16 + 4 + 4 + 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParseSection {
+    FileHeader,
+    WorldHeader,
+    Tiles,
+    Done,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseProgress {
+    pub section: ParseSection,
+    // 已读取的方块数与方块总数
+    pub tiles_read: usize,
+    pub tiles_total: usize,
+    // 按行计的进度：方块按行存储（idx = y * width + x），已读完的整行数与世界高度
+    pub rows_read: usize,
+    pub rows_total: usize,
+    pub bytes_read: usize,
+    pub percent: f64,
+}
+
+enum ParseState {
+    FileHeader,
+    WorldHeader,
+    Tiles {
+        header: WorldHeader,
+        tiles: Box<TileStorage>,
+        // 恢复模式下的方块读取状态
+        recovery: Option<Box<TileRecovery>>,
+    },
+    Finished(Option<Box<World>>),
+    Failed(String),
+    Cancelled,
+}
+
+#[wasm_bindgen]
+pub struct WorldParser {
+    loader: WorldLoader,
+    stream: DataStream,
+    // 所有数据都已喂入，之后数据不足视为文件损坏
+    input_complete: bool,
+    state: ParseState,
+    cancellation: Option<CancellationToken>,
+    recovery: bool,
+    // 文件格式头中的版本号与区段位置表
+    version: i32,
+    positions: Vec<i32>,
+    progress_callback: Option<js_sys::Function>,
+    last_reported_row: Option<usize>,
+}
+
+impl Default for WorldParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl WorldParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            loader: WorldLoader::new(),
+            stream: DataStream::new(Vec::new()),
+            input_complete: false,
+            state: ParseState::FileHeader,
+            cancellation: None,
+            recovery: false,
+            version: 0,
+            positions: Vec::new(),
+            progress_callback: None,
+            last_reported_row: None,
+        }
+    }
+
+    /// 设置进度回调，参数为 ParseProgress 对象
+    #[wasm_bindgen]
+    pub fn set_progress_callback(&mut self, callback: js_sys::Function) {
+        self.progress_callback = Some(callback);
+    }
+
+    /// 设置取消令牌，每次 step 开始前检查；取消后丢弃已解析的数据并返回 CancelledError
+    #[wasm_bindgen]
+    pub fn set_cancellation_token(&mut self, token: &CancellationToken) {
+        self.cancellation = Some(token.clone());
+    }
+
+    /// 恢复模式：数据损坏时返回部分世界与 warnings，而不是报错。
+    /// 恢复需要按位置表定位区段，方块在 finish_input 之后才开始读取
+    #[wasm_bindgen]
+    pub fn set_recovery_mode(&mut self, enabled: bool) {
+        self.recovery = enabled;
+    }
+
+    /// 追加一段文件数据
+    #[wasm_bindgen]
+    pub fn feed(&mut self, chunk: &[u8]) {
+        self.stream.extend(chunk);
+    }
+
+    /// 标记文件数据已全部喂入
+    #[wasm_bindgen]
+    pub fn finish_input(&mut self) {
+        self.input_complete = true;
+    }
+
+    /// 最多解析 budget 个方块后返回；解析完成时返回 true，数据不足时返回 false 等待更多数据
+    #[wasm_bindgen]
+    pub fn step(&mut self, budget: u32) -> Result<bool, JsValue> {
+        self.step_internal(budget as usize).map_err(|e| match self.state {
+            ParseState::Cancelled => cancelled_error(),
+            _ => WorldLoader::load_error(e),
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn is_done(&self) -> bool {
+        matches!(self.state, ParseState::Finished(_))
+    }
+
+    #[wasm_bindgen]
+    pub fn progress(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.current_progress())?)
+    }
+
+    /// 取出解析完成的世界句柄
+    #[wasm_bindgen]
+    pub fn take_handle(&mut self) -> Result<WorldHandle, JsValue> {
+        self.take_world()
+            .map(WorldHandle::new)
+            .ok_or_else(|| JsValue::from_str("World parsing has not finished"))
+    }
+}
+
+impl WorldParser {
+    /// 用完整的文件数据创建解析器，供一次性解析使用
+    pub(crate) fn with_data(data: Vec<u8>, recovery: bool) -> Self {
+        let mut parser = Self::new();
+        parser.stream = DataStream::new(data);
+        parser.input_complete = true;
+        parser.recovery = recovery;
+        parser
+    }
+
+    pub fn take_world(&mut self) -> Option<World> {
+        match &mut self.state {
+            ParseState::Finished(world) => world.take().map(|world| *world),
+            _ => None,
+        }
+    }
+
+    pub fn step_internal(&mut self, budget: usize) -> Result<bool, String> {
+        match &self.state {
+            ParseState::Failed(message) => return Err(message.clone()),
+            ParseState::Cancelled => return Err(WorldLoadError::Cancelled.into()),
+            _ => {}
+        }
+        // wasm 是单线程的，令牌只能在两次 step 之间被 JS 取消
+        if self.cancellation.as_ref().is_some_and(|token| token.is_cancelled()) {
+            // 丢弃已解析的部分数据
+            self.state = ParseState::Cancelled;
+            self.stream = DataStream::new(Vec::new());
+            return Err(WorldLoadError::Cancelled.into());
+        }
+
+        let result = self.advance(budget.max(1));
+        if let Err(message) = &result {
+            self.state = ParseState::Failed(message.clone());
+        }
+        result
+    }
+
+    fn advance(&mut self, mut budget: usize) -> Result<bool, String> {
+        while budget > 0 {
+            match &mut self.state {
+                ParseState::FileHeader => {
+                    let Some(len) = Self::file_header_len(&self.stream) else {
+                        return self.wait_for_data();
+                    };
+                    if self.stream.remaining() < len {
+                        return self.wait_for_data();
+                    }
+
+                    self.version = self.stream.read_int32();
+                    self.stream.seek(self.stream.position() - 4);
+                    self.positions = self.loader.read_file_format_header(&mut self.stream).map_err(|e| {
+                        format!("Failed to read file format header: {}", e)
+                    })?;
+                    self.state = ParseState::WorldHeader;
+                    budget -= 1;
+                    self.report_progress();
+                }
+                ParseState::WorldHeader => {
+                    let Some(len) = Self::world_header_len(&self.stream) else {
+                        return self.wait_for_data();
+                    };
+                    if self.stream.remaining() < len {
+                        return self.wait_for_data();
+                    }
+                    // 恢复模式需要知道文件长度才能检查位置表
+                    if self.recovery && !self.input_complete {
+                        return Ok(false);
+                    }
+
+                    let header = self.loader.read_header(&mut self.stream).map_err(|e| {
+                        format!("Failed to read world header: {}", e)
+                    })?;
+                    WorldLoader::validate_dimensions(header.width, header.height)?;
+
+                    let tile_count = (header.width * header.height) as usize;
+                    let recovery = self
+                        .recovery
+                        .then(|| Box::new(TileRecovery::begin(&mut self.stream, &self.positions)));
+                    self.state = ParseState::Tiles {
+                        header,
+                        tiles: Box::new(TileStorage::with_capacity(tile_count)),
+                        recovery,
+                    };
+                    budget -= 1;
+                    self.report_progress();
+                }
+                ParseState::Tiles { header, tiles, recovery } => {
+                    let tile_count = (header.width * header.height) as usize;
+                    if let Some(recovery) = recovery {
+                        recovery.read_tiles(&self.loader, &mut self.stream, tiles, header.width, header.height, &mut budget);
+                    }
+                    while budget > 0 && tiles.len() < tile_count {
+                        let available = Self::tile_len(&self.stream)
+                            .is_some_and(|len| self.stream.remaining() >= len);
+                        if !available {
+                            break;
+                        }
+                        tiles.push(&self.loader.read_tile(&mut self.stream));
+                        budget -= 1;
+                    }
+
+                    let remaining = tile_count - tiles.len();
+                    if remaining > 0 {
+                        self.report_progress();
+                        // 预算未用完却停下，说明数据不足
+                        if budget > 0 && self.input_complete {
+                            return Err(WorldLoadError::CorruptedData {
+                                position: self.stream.position(),
+                                message: format!(
+                                    "Unexpected end of data while reading tiles: {} tiles remaining",
+                                    remaining
+                                ),
+                            }.into());
+                        }
+                        return Ok(false);
+                    }
+
+                    // 箱子等区段在方块之后，数据齐全后才读取
+                    let sections_ready = self.input_complete
+                        || sections::sections_end(&self.positions).is_some_and(|end| self.stream.len() >= end);
+                    if !sections_ready {
+                        return Ok(false);
+                    }
+                    self.finish_tiles()?;
+                    self.report_progress();
+                    return Ok(true);
+                }
+                ParseState::Finished(_) => return Ok(true),
+                ParseState::Failed(message) => return Err(message.clone()),
+                ParseState::Cancelled => return Err(WorldLoadError::Cancelled.into()),
+            }
+        }
+
+        Ok(self.is_done())
+    }
+
+    // 方块读取完毕，读取其余区段并组装世界；恢复模式下区段错误记为警告
+    fn finish_tiles(&mut self) -> Result<(), String> {
+        let state = std::mem::replace(&mut self.state, ParseState::Finished(None));
+        if let ParseState::Tiles { header, tiles, recovery } = state {
+            let mut world = World {
+                name: header.name,
+                width: header.width,
+                height: header.height,
+                world_id: header.world_id,
+                tiles: *tiles,
+                chests: Vec::new(),
+                npcs: Vec::new(),
+                signs: Vec::new(),
+                tile_entities: Vec::new(),
+                warnings: Vec::new(),
+                mods: ModData::default(),
+            };
+            match recovery {
+                Some(recovery) => recovery.finish(&mut self.stream, &mut world),
+                None => sections::read_sections(&mut self.stream, &self.positions, self.version, &mut world, WorldLoader::section_error)?,
+            }
+            self.state = ParseState::Finished(Some(Box::new(world)));
+        }
+        Ok(())
+    }
+
+    // 数据不足：输入已结束则报错，否则等待下一次 feed
+    fn wait_for_data(&self) -> Result<bool, String> {
+        if !self.input_complete {
+            return Ok(false);
+        }
+        if self.stream.is_empty() {
+            return Err(WorldLoadError::InvalidData {
+                message: "World file is empty".to_string(),
+            }.into());
+        }
+        Err(WorldLoadError::CorruptedData {
+            position: self.stream.position(),
+            message: "Unexpected end of data while reading header".to_string(),
+        }.into())
+    }
+
+    fn current_progress(&self) -> ParseProgress {
+        let (section, tiles_read, tiles_total, width, height) = match &self.state {
+            ParseState::FileHeader | ParseState::Failed(_) | ParseState::Cancelled => {
+                (ParseSection::FileHeader, 0, 0, 0, 0)
+            }
+            ParseState::WorldHeader => (ParseSection::WorldHeader, 0, 0, 0, 0),
+            ParseState::Tiles { header, tiles, .. } => (
+                ParseSection::Tiles,
+                tiles.len(),
+                (header.width * header.height) as usize,
+                header.width as usize,
+                header.height as usize,
+            ),
+            ParseState::Finished(world) => {
+                let total = world.as_ref().map_or(0, |w| w.tiles.len());
+                let (width, height) = world.as_ref().map_or((0, 0), |w| (w.width as usize, w.height as usize));
+                (ParseSection::Done, total, total, width, height)
+            }
+        };
+
+        let percent = match section {
+            ParseSection::Done => 100.0,
+            _ if tiles_total > 0 => tiles_read as f64 / tiles_total as f64 * 100.0,
+            _ => 0.0,
+        };
+
+        ParseProgress {
+            section,
+            tiles_read,
+            tiles_total,
+            rows_read: tiles_read.checked_div(width).unwrap_or(0).min(height),
+            rows_total: height,
+            bytes_read: self.stream.position(),
+            percent,
+        }
+    }
+
+    // 每读完新的一行或切换区段时回调一次
+    fn report_progress(&mut self) {
+        let Some(callback) = &self.progress_callback else {
+            return;
+        };
+
+        let progress = self.current_progress();
+        let row = match progress.section {
+            ParseSection::Tiles => Some(progress.rows_read),
+            _ => None,
+        };
+        if row.is_some() && row == self.last_reported_row {
+            return;
+        }
+        self.last_reported_row = row;
+
+        if let Ok(value) = serde_wasm_bindgen::to_value(&progress) {
+            let _ = callback.call1(&JsValue::NULL, &value);
+        }
+    }
+
+    // 文件格式头长度：版本、元数据、修订号、收藏、位置表、重要性位图
+    pub(crate) fn file_header_len(stream: &DataStream) -> Option<usize> {
+        let read_i16 = |offset: usize| -> Option<usize> {
+            let low = stream.peek_byte(offset)?;
+            let high = stream.peek_byte(offset + 1)?;
+            Some(i16::from_le_bytes([low, high]).max(0) as usize)
+        };
+
+        let positions_offset = 4 + 8 + 4 + 8;
+        let positions_length = read_i16(positions_offset)?;
+        let importance_offset = positions_offset + 2 + positions_length * 4;
+        let importance_length = read_i16(importance_offset)?;
+        Some(importance_offset + 2 + importance_length.div_ceil(8))
+    }
+
+    // 世界头长度：名称、种子两个字符串加上固定字段
+    pub(crate) fn world_header_len(stream: &DataStream) -> Option<usize> {
+        let name_len = Self::string_len(stream, 0)?;
+        let seed_len = Self::string_len(stream, name_len)?;
+        Some(name_len + seed_len + WORLD_HEADER_FIXED_LEN)
+    }
+
+    // 7 位变长前缀字符串的总长度
+    pub(crate) fn string_len(stream: &DataStream, offset: usize) -> Option<usize> {
+        let mut length = 0u64;
+        let mut step = 0;
+        loop {
+            // 7 位编码的长度最多 5 字节
+            if step == 5 {
+                return None;
+            }
+            let part = stream.peek_byte(offset + step)?;
+            length += ((part & 0x7F) as u64) << (step * 7);
+            step += 1;
+            if part >> 7 == 0 {
+                return usize::try_from(length).ok()?.checked_add(step);
+            }
+        }
+    }
+
+    // 单个方块记录的长度，与 WorldLoader::read_tile 的读取顺序一致
+    pub(crate) fn tile_len(stream: &DataStream) -> Option<usize> {
+        if stream.peek_byte(0)? == 0 {
+            return Some(1);
+        }
+
+        // 激活、方块、墙、是否有液体
+        let mut len = 1 + 2 + 2 + 1;
+        if stream.peek_byte(5)? != 0 {
+            len += 2;
+        }
+        // 促动、是否有颜色
+        len += 1 + 1;
+        if stream.peek_byte(len - 1)? != 0 {
+            len += 1;
+        }
+        // 是否有墙体颜色
+        len += 1;
+        if stream.peek_byte(len - 1)? != 0 {
+            len += 1;
+        }
+        // 帧、样式、电线、促动器、墙体帧与样式
+        Some(len + 2 + 2 + 1 + 1 + 1 + 1 + 4 + 1 + 1 + 2 + 2 + 1 + 1 + 1)
+    }
+}
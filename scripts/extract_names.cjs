@@ -0,0 +1,144 @@
+/**
+ * 名称表提取脚本
+ * 从 names.js、itemKeys.js、tileKeys.js、wallKeys.js 与 settings.js 生成 rust/src/name_data.rs
+ * 同时导出 settings.js 中方块的 Frames（帧变体的起始 U/V 与名称）与多格物体的 Size / TextureGrid
+ * 物品显示名称取 names[内部名]，缺失时用 settings.js 中的 Name
+ * names.js 只含物品名，方块与墙体的内部名常与物品重名（如墙体 Wood），因此优先用 settings.js 的 Name
+ */
+
+const fs = require('fs');
+const path = require('path');
+
+const legacyDir = path.join(__dirname, '../static/js/legacy');
+const settingsPath = path.join(__dirname, '../resources/js/settings.js');
+
+// 读取 `const <name> = { ... };` 形式的对象字面量
+function readObject(file, name) {
+    const content = fs.readFileSync(path.join(legacyDir, file), 'utf-8');
+    const start = content.indexOf(`const ${name} = {`);
+    // 对象以单独一行的 "}" 或 "};" 结束
+    const end = content.indexOf('\n}', start);
+    if (start < 0 || end < 0) {
+        console.error(`错误: ${file} 中找不到 ${name}`);
+        process.exit(1);
+    }
+    const literal = content.slice(content.indexOf('{', start), end + 2);
+    return new Function(`return ${literal};`)();
+}
+
+const names = readObject('names.js', 'names');
+const itemKeys = readObject('itemKeys.js', 'itemKeys');
+const tileKeys = readObject('tileKeys.js', 'tileKeys');
+const wallKeys = readObject('wallKeys.js', 'wallKeys');
+
+// 部分名称引用其他物品，如 "{$ItemName.DemonAltar}"
+function resolveName(key) {
+    const name = names[key];
+    if (name === undefined) {
+        return undefined;
+    }
+    return name.replace(/\{\$ItemName\.(\w+)\}/g, (_, ref) => names[ref] ?? ref);
+}
+
+// settings.js 中各列表的 Id → Name（同一 Id 只取第一个）
+const settingsContent = fs.readFileSync(settingsPath, 'utf-8');
+const settingsStart = settingsContent.indexOf('var settings = {');
+const settingsEnd = settingsContent.indexOf('\n};', settingsStart);
+const settings = new Function(
+    `return ${settingsContent.slice(settingsContent.indexOf('{', settingsStart), settingsEnd + 2)};`
+)();
+
+function settingsNames(list) {
+    const result = {};
+    for (const entry of settings[list]) {
+        if (entry.Name !== undefined && !(entry.Id in result)) {
+            result[entry.Id] = entry.Name;
+        }
+    }
+    return result;
+}
+
+function buildEntries(keys, settingsList, preferSettings) {
+    return Object.keys(keys)
+        .map((id) => {
+            const key = keys[id];
+            const name = preferSettings
+                ? settingsList[id] ?? resolveName(key) ?? key
+                : resolveName(key) ?? settingsList[id] ?? key;
+            return { id: parseInt(id), key, name };
+        })
+        .sort((a, b) => a.id - b.id);
+}
+
+const items = buildEntries(itemKeys, settingsNames('Items'), false);
+const tiles = buildEntries(tileKeys, settingsNames('Tiles'), true);
+const walls = buildEntries(wallKeys, settingsNames('Walls'), true);
+
+// NPC 没有单独的内部名表，用去掉空格与符号的英文名代替
+const npcNames = settingsNames('Npcs');
+const npcs = Object.keys(npcNames)
+    .map((id) => ({
+        id: parseInt(id),
+        key: npcNames[id].replace(/[^A-Za-z0-9]/g, ''),
+        name: npcNames[id],
+    }))
+    .sort((a, b) => a.id - b.id);
+
+// 方块帧变体，保持 settings.js 中的顺序（查找时取最后一个不大于方块帧坐标的变体）
+const frames = [];
+settings.Tiles.forEach((tile) => {
+    (tile.Frames || []).forEach((frame) => {
+        frames.push({
+            tileId: parseInt(tile.Id),
+            u: frame.U ? parseInt(frame.U) : 0,
+            v: frame.V ? parseInt(frame.V) : 0,
+            name: frame.Name ?? tile.Name,
+            variety: frame.Variety ?? '',
+        });
+    });
+});
+frames.sort((a, b) => a.tileId - b.tileId);
+
+// 多格物体的尺寸（格）与每格纹理大小（像素，帧坐标步长为其加 2 像素间隔），1×1 的方块不导出
+const sizes = settings.Tiles
+    .filter((tile) => tile.Size && tile.Size !== '1,1')
+    .map((tile) => {
+        const [width, height] = tile.Size.split(',').map((value) => parseInt(value));
+        const [cellWidth, cellHeight] = (tile.TextureGrid || '16,16').split(',').map((value) => parseInt(value));
+        return { tileId: parseInt(tile.Id), width, height, cellWidth, cellHeight };
+    })
+    .sort((a, b) => a.tileId - b.tileId);
+
+// 生成 Rust 代码
+function generateTable(constName, entries) {
+    let code = `pub const ${constName}: &[NameEntry] = &[\n`;
+    for (const entry of entries) {
+        code += `    NameEntry::new(${entry.id}, ${JSON.stringify(entry.key)}, ${JSON.stringify(entry.name)}),\n`;
+    }
+    code += '];\n';
+    return code;
+}
+
+let code = '// 名称表\n';
+code += '// 从 names.js 与 itemKeys/tileKeys/wallKeys.js 迁移（由 scripts/extract_names.cjs 自动生成），按 id 排序\n\n';
+code += 'use crate::name_registry::{FrameEntry, NameEntry, TileSize};\n\n';
+code += generateTable('ITEM_NAMES', items) + '\n';
+code += generateTable('TILE_NAMES', tiles) + '\n';
+code += generateTable('WALL_NAMES', walls) + '\n';
+code += generateTable('NPC_NAMES', npcs) + '\n';
+code += 'pub const TILE_FRAMES: &[FrameEntry] = &[\n';
+for (const frame of frames) {
+    code += `    FrameEntry::new(${frame.tileId}, ${frame.u}, ${frame.v}, ${JSON.stringify(frame.name)}, ${JSON.stringify(frame.variety)}),\n`;
+}
+code += '];\n\n';
+code += 'pub const TILE_SIZES: &[TileSize] = &[\n';
+for (const size of sizes) {
+    code += `    TileSize::new(${size.tileId}, ${size.width}, ${size.height}, ${size.cellWidth}, ${size.cellHeight}),\n`;
+}
+code += '];\n';
+
+const outputPath = path.join(__dirname, '../rust/src/name_data.rs');
+fs.writeFileSync(outputPath, code);
+
+console.log('名称表已提取并保存到:', outputPath);
+console.log(`物品 ${items.length}，方块 ${tiles.length}，墙体 ${walls.length}，NPC ${npcs.length}，帧变体 ${frames.length}，多格物体 ${sizes.length}`);
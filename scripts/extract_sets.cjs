@@ -0,0 +1,49 @@
+/**
+ * 预定义集合提取脚本
+ * 从 sets.js 生成 rust/src/set_data.rs
+ * 与原项目 addSetListItems 一致：带 U/V 的条目一律视为方块的帧变体
+ */
+
+const fs = require('fs');
+const path = require('path');
+
+const setsPath = path.join(__dirname, '../static/js/legacy/sets.js');
+const content = fs.readFileSync(setsPath, 'utf-8');
+const start = content.indexOf('var sets = [');
+if (start < 0) {
+    console.error('错误: sets.js 中找不到 sets');
+    process.exit(1);
+}
+const sets = new Function(`return ${content.slice(content.indexOf('[', start))};`)();
+
+function entryKind(entry) {
+    if (entry.U || entry.V || entry.isTile) return 'Tile';
+    if (entry.isWall) return 'Wall';
+    return 'Item';
+}
+
+function optional(value) {
+    return value ? `Some(${parseInt(value)})` : 'None';
+}
+
+// 生成 Rust 代码
+let code = '// 预定义集合\n';
+code += '// 从 sets.js 迁移（由 scripts/extract_sets.cjs 自动生成）\n\n';
+code += 'use crate::name_registry::NameKind;\n';
+code += 'use crate::sets::{SetDef, SetEntryDef};\n\n';
+code += 'pub const BUILTIN_SETS: &[SetDef] = &[\n';
+for (const set of sets) {
+    code += `    SetDef::new(${JSON.stringify(set.Name)}, &[\n`;
+    for (const entry of set.Entries) {
+        const name = entry.Name.trim();
+        code += `        SetEntryDef::new(NameKind::${entryKind(entry)}, ${parseInt(entry.Id)}, ${JSON.stringify(name)}, ${optional(entry.U)}, ${optional(entry.V)}),\n`;
+    }
+    code += '    ]),\n';
+}
+code += '];\n';
+
+const outputPath = path.join(__dirname, '../rust/src/set_data.rs');
+fs.writeFileSync(outputPath, code);
+
+console.log('预定义集合已提取并保存到:', outputPath);
+console.log('集合数量:', sets.length);
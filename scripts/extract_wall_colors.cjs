@@ -0,0 +1,80 @@
+/**
+ * 墙体颜色提取脚本
+ * 从 settings.js 的 Walls 列表中提取墙体颜色，生成 rust/src/wall_colors.rs
+ */
+
+const fs = require('fs');
+const path = require('path');
+
+// 读取 settings.js 文件
+const settingsPath = path.join(__dirname, '../resources/js/settings.js');
+const settingsContent = fs.readFileSync(settingsPath, 'utf-8');
+
+// 截取 Walls 列表（到下一个顶层列表 Items 为止）
+const wallsStart = settingsContent.indexOf('Walls: [');
+const wallsEnd = settingsContent.indexOf('Items: [', wallsStart);
+if (wallsStart < 0 || wallsEnd < 0) {
+    console.error('错误: settings.js 中找不到 Walls 列表');
+    process.exit(1);
+}
+const wallsContent = settingsContent.slice(wallsStart, wallsEnd);
+
+// 每个墙体对象包含 Id 与 Color（#AARRGGBB）
+const wallColors = {};
+const objectPattern = /\{([^{}]*)\}/g;
+let match;
+
+while ((match = objectPattern.exec(wallsContent)) !== null) {
+    const body = match[1];
+    const idMatch = body.match(/Id:\s*"(\d+)"/);
+    const colorMatch = body.match(/Color:\s*"#([0-9A-Fa-f]{8})"/);
+    if (!idMatch || !colorMatch) {
+        continue;
+    }
+
+    const argb = colorMatch[1];
+    const alpha = parseInt(argb.slice(0, 2), 16);
+    // 透明的墙体（如天空）不绘制
+    if (alpha === 0) {
+        continue;
+    }
+
+    wallColors[parseInt(idMatch[1])] = {
+        r: parseInt(argb.slice(2, 4), 16),
+        g: parseInt(argb.slice(4, 6), 16),
+        b: parseInt(argb.slice(6, 8), 16),
+    };
+}
+
+// 生成 Rust 代码
+function generateRustCode(wallColors) {
+    let code = '// 墙体颜色定义\n';
+    code += '// 从 settings.js 的 Walls 列表迁移（由 scripts/extract_wall_colors.cjs 自动生成）\n\n';
+    code += 'use crate::colors::Rgb;\n\n';
+
+    code += 'pub struct WallColors;\n\n';
+    code += 'impl WallColors {\n';
+    code += '    pub fn get_color(wall_id: i32) -> Option<Rgb> {\n';
+    code += '        match wall_id {\n';
+
+    const allIds = Object.keys(wallColors).map(id => parseInt(id)).sort((a, b) => a - b);
+    for (const id of allIds) {
+        const color = wallColors[id];
+        code += `            ${id} => Some(Rgb::new(${color.r}, ${color.g}, ${color.b})),\n`;
+    }
+
+    code += '            _ => None,\n';
+    code += '        }\n';
+    code += '    }\n';
+    code += '}\n';
+
+    return code;
+}
+
+const rustCode = generateRustCode(wallColors);
+
+const outputPath = path.join(__dirname, '../rust/src/wall_colors.rs');
+fs.writeFileSync(outputPath, rustCode);
+
+console.log('墙体颜色已提取并保存到:', outputPath);
+console.log('提取的墙体数量:', Object.keys(wallColors).length);